@@ -2544,6 +2544,32 @@ pub mod test_constrain {
         )
     }
 
+    #[test]
+    fn tuple_annotation_is_inferred() {
+        infer_eq(
+            indoc!(
+                r#"
+                first : (Str, Str) -> Str
+                first = \_ -> "a"
+                first
+                "#
+            ),
+            "* -> Str",
+        )
+    }
+
+    #[test]
+    fn tuple_pattern_is_unsupported() {
+        infer_eq(
+            indoc!(
+                r#"
+                \(a, b) -> "a"
+                "#
+            ),
+            "* -> Str",
+        )
+    }
+
     #[test]
     #[ignore = "TODO: Type2::substitute"]
     fn inference_var_inside_ctor() {
@@ -440,6 +440,9 @@ pub fn to_pattern2<'a>(
             })
         }
 
+        // Pattern2 has no tuples yet, so report them instead of panicking on valid syntax.
+        Tuple(_) => unsupported_pattern(env, pattern_type, region),
+
         RequiredField(_name, _loc_pattern) => {
             unreachable!("should have been handled in RecordDestructure");
         }
@@ -577,6 +577,12 @@ pub fn to_type2<'a>(
             Type2::AsAlias(symbol, vars, alias.actual)
         }
         Where { .. } => todo_abilities!(),
+        Tuple { .. } => {
+            // Type2 has no tuples yet, so infer the type instead, as for `_`.
+            let var = env.var_store.fresh();
+
+            Type2::Variable(var)
+        }
         SpaceBefore(nested, _) | SpaceAfter(nested, _) => {
             to_type2(env, scope, references, nested, region)
         }
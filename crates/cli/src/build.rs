@@ -18,6 +18,8 @@ use std::{path::PathBuf, thread::JoinHandle};
 use target_lexicon::Triple;
 use tempfile::Builder;
 
+use crate::watch::{self, Watched};
+
 fn report_timing(buf: &mut String, label: &str, duration: Duration) {
     use std::fmt::Write;
 
@@ -36,6 +38,7 @@ pub struct BuiltFile {
     pub total_time: Duration,
     pub expectations: VecMap<ModuleId, Expectations>,
     pub interns: Interns,
+    pub loaded_files: Vec<PathBuf>,
 }
 
 pub enum BuildOrdering {
@@ -69,6 +72,7 @@ pub fn build_file<'a>(
     threading: Threading,
     wasm_dev_stack_bytes: Option<u32>,
     order: BuildOrdering,
+    render: RenderTarget,
 ) -> Result<BuiltFile, BuildFileError<'a>> {
     let compilation_start = Instant::now();
     let target_info = TargetInfo::from(target);
@@ -83,10 +87,10 @@ pub fn build_file<'a>(
 
     let load_config = LoadConfig {
         target_info,
-        // TODO: expose this from CLI?
-        render: RenderTarget::ColorTerminal,
+        render,
         threading,
         exec_mode,
+        cache_dir: roc_load::cache::default_cache_dir(),
     };
     let load_result = roc_load::load_and_monomorphize(
         arena,
@@ -240,11 +244,12 @@ pub fn build_file<'a>(
     // This only needs to be mutable for report_problems. This can't be done
     // inside a nested scope without causing a borrow error!
     let mut loaded = loaded;
-    let problems = program::report_problems_monomorphized(&mut loaded);
+    let problems = program::report_problems_monomorphized(&mut loaded, render);
     let expectations = std::mem::take(&mut loaded.expectations);
     let loaded = loaded;
 
     let interns = loaded.interns.clone();
+    let loaded_files = watch::source_files(&loaded.sources);
 
     enum HostRebuildTiming {
         BeforeApp(u128),
@@ -353,10 +358,13 @@ pub fn build_file<'a>(
                 inputs.push(&str_host_obj_path);
             }
 
-            let (mut child, _) =  // TODO use lld
+            let (mut child, linked_path) =  // TODO use lld
             link(target, binary_path.clone(), &inputs, link_type)
                 .map_err(|_| todo!("gracefully handle `ld` failing to spawn."))?;
 
+            // Linking a library can change the extension, e.g. to .so.1.0 or .dylib
+            binary_path = linked_path;
+
             let exit_status = child
                 .wait()
                 .map_err(|_| todo!("gracefully handle error after `ld` spawned"))?;
@@ -388,6 +396,7 @@ pub fn build_file<'a>(
         total_time,
         interns,
         expectations,
+        loaded_files,
     })
 }
 
@@ -462,6 +471,8 @@ pub fn check_file(
     roc_file_path: PathBuf,
     emit_timings: bool,
     threading: Threading,
+    render: RenderTarget,
+    watched: Option<&mut Watched>,
 ) -> Result<(program::Problems, Duration), LoadingProblem> {
     let compilation_start = Instant::now();
 
@@ -474,14 +485,18 @@ pub fn check_file(
 
     let load_config = LoadConfig {
         target_info,
-        // TODO: expose this from CLI?
-        render: RenderTarget::ColorTerminal,
+        render,
         threading,
         exec_mode: ExecutionMode::Check,
+        cache_dir: roc_load::cache::default_cache_dir(),
     };
     let mut loaded =
         roc_load::load_and_typecheck(arena, roc_file_path, subs_by_module, load_config)?;
 
+    if let Some(watched) = watched {
+        watched.loaded(watch::source_files(&loaded.sources));
+    }
+
     let buf = &mut String::with_capacity(1024);
 
     let mut it = loaded.timings.iter().peekable();
@@ -501,10 +516,17 @@ pub fn check_file(
 
         report_timing(buf, "Read .roc file from disk", module_timing.read_roc_file);
         report_timing(buf, "Parse header", module_timing.parse_header);
-        report_timing(buf, "Parse body", module_timing.parse_body);
-        report_timing(buf, "Canonicalize", module_timing.canonicalize);
-        report_timing(buf, "Constrain", module_timing.constrain);
-        report_timing(buf, "Solve", module_timing.solve);
+        if module_timing.cache_hit {
+            report_timing(buf, "Parse body (cache hit)", module_timing.parse_body);
+            report_timing(buf, "Canonicalize (cache hit)", module_timing.canonicalize);
+            report_timing(buf, "Constrain (cache hit)", module_timing.constrain);
+            report_timing(buf, "Solve (cache hit)", module_timing.solve);
+        } else {
+            report_timing(buf, "Parse body", module_timing.parse_body);
+            report_timing(buf, "Canonicalize", module_timing.canonicalize);
+            report_timing(buf, "Constrain", module_timing.constrain);
+            report_timing(buf, "Solve", module_timing.solve);
+        }
         report_timing(buf, "Other", module_timing.other());
         buf.push('\n');
         report_timing(buf, "Total", module_timing.total());
@@ -526,7 +548,7 @@ pub fn check_file(
     }
 
     Ok((
-        program::report_problems_typechecked(&mut loaded),
+        program::report_problems_typechecked(&mut loaded, render),
         compilation_end,
     ))
 }
@@ -0,0 +1,231 @@
+use crate::build::{build_file, BuildFileError, BuildOrdering, BuiltFile};
+use bumpalo::Bump;
+use libloading::Library;
+use roc_build::link::{LinkType, LinkingStrategy};
+use roc_glue::glue::{File, Types};
+use roc_glue::load::{load_types, IgnoreErrors};
+use roc_load::{LoadingProblem, Threading};
+use roc_mono::ir::OptLevel;
+use roc_reporting::report::RenderTarget;
+use roc_std::{RocList, RocResult, RocStr};
+use std::io::{self, ErrorKind};
+use std::mem::ManuallyDrop;
+use std::path::{Component, Path};
+use std::process;
+use target_lexicon::Triple;
+
+/// The symbol a glue spec's `makeGlueForHost` is exposed under.
+const MAKE_GLUE_SYMBOL: &[u8] = b"roc__makeGlueForHost_1_exposed_generic";
+
+type MakeGlue = unsafe extern "C" fn(*mut RocResult<RocList<File>, RocStr>, &RocList<Types>);
+
+/// Generate glue for the given platform by running a glue spec: a Roc application,
+/// built against crates/glue/platform/main.roc, which is handed the platform's types
+/// (once per target architecture) and returns the files to write into `output_dir`.
+pub fn glue(platform_path: &Path, spec_path: &Path, output_dir: &Path) -> io::Result<i32> {
+    let types_and_targets = match load_types(
+        platform_path.to_path_buf(),
+        Threading::AllAvailable,
+        IgnoreErrors::NONE,
+    ) {
+        Ok(types_and_targets) => types_and_targets,
+        Err(err) => match err.kind() {
+            ErrorKind::NotFound => {
+                eprintln!(
+                    "Platform module file not found: {}",
+                    platform_path.display()
+                );
+                process::exit(1);
+            }
+            error => {
+                eprintln!(
+                    "Error loading platform module file {} - {:?}",
+                    platform_path.display(),
+                    error
+                );
+                process::exit(1);
+            }
+        },
+    };
+
+    let arena = Bump::new();
+    let triple = Triple::host();
+
+    let spec_dylib_path = match build_file(
+        &arena,
+        &triple,
+        spec_path.to_path_buf(),
+        OptLevel::Normal,
+        false,
+        false,
+        LinkType::Dylib,
+        LinkingStrategy::Legacy,
+        false,
+        Threading::AllAvailable,
+        None,
+        BuildOrdering::BuildIfChecks,
+        RenderTarget::ColorTerminal,
+    ) {
+        Ok(BuiltFile {
+            binary_path,
+            problems,
+            ..
+        }) => {
+            if problems.errors > 0 {
+                eprintln!(
+                    "Unable to build the glue spec {}; see the errors above.",
+                    spec_path.display()
+                );
+
+                return Ok(problems.exit_code());
+            }
+
+            binary_path
+        }
+        Err(BuildFileError::ErrorModule { mut module, .. }) => {
+            let problems = roc_build::program::report_problems_typechecked(
+                &mut module,
+                RenderTarget::ColorTerminal,
+            );
+
+            return Ok(problems.exit_code());
+        }
+        Err(BuildFileError::LoadingProblem(LoadingProblem::FormattedReport(report))) => {
+            print!("{}", report);
+
+            return Ok(1);
+        }
+        Err(other) => {
+            panic!("build_file failed with error:\n{:?}", other);
+        }
+    };
+
+    // Roc may free its arguments once it's done with them, so don't drop these here as well.
+    let types = ManuallyDrop::new(
+        types_and_targets
+            .iter()
+            .map(|(types, _target_info)| Types::from(types))
+            .collect::<RocList<Types>>(),
+    );
+
+    let lib = unsafe { Library::new(&spec_dylib_path) }.unwrap_or_else(|err| {
+        eprintln!(
+            "Unable to load the glue spec library {} - {:?}",
+            spec_dylib_path.display(),
+            err
+        );
+
+        process::exit(1);
+    });
+
+    let files = unsafe {
+        let make_glue = lib.get::<MakeGlue>(MAKE_GLUE_SYMBOL).unwrap_or_else(|err| {
+            eprintln!(
+                "The glue spec {} does not expose makeGlueForHost - {:?}",
+                spec_path.display(),
+                err
+            );
+
+            process::exit(1);
+        });
+
+        let mut files = RocResult::err(RocStr::empty());
+
+        make_glue(&mut files, &*types);
+
+        files
+    };
+
+    // The files may point into the library's static data, so keep it loaded.
+    std::mem::forget(lib);
+
+    match Result::from(files) {
+        Ok(files) => {
+            // The spec decides the file names, so don't let it write outside of output_dir.
+            if let Some(file) = files
+                .iter()
+                .find(|file| !is_relative_to_output_dir(&file.name))
+            {
+                eprintln!(
+                    "The glue spec {} tried to write to {:?}, but glue files must be relative paths to a file, without `..` in them.",
+                    spec_path.display(),
+                    file.name.as_str()
+                );
+
+                return Ok(1);
+            }
+
+            println!("🎉 Generated glue files in {}:\n", output_dir.display());
+
+            for file in files.iter() {
+                let path = output_dir.join(file.name.as_str());
+
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+
+                std::fs::write(&path, file.content.as_str()).unwrap_or_else(|err| {
+                    eprintln!(
+                        "Unable to write glue to output file {} - {:?}",
+                        path.display(),
+                        err
+                    );
+
+                    process::exit(1);
+                });
+
+                println!("\t{}", path.display());
+            }
+
+            Ok(0)
+        }
+        Err(msg) => {
+            eprintln!(
+                "The glue spec {} reported an error:\n\n    {}",
+                spec_path.display(),
+                msg
+            );
+
+            Ok(1)
+        }
+    }
+}
+
+/// Whether this names a file inside the output directory. An empty name or one that is only `.`
+/// would be the output directory itself.
+fn is_relative_to_output_dir(name: &str) -> bool {
+    let mut has_file_name = false;
+
+    for component in Path::new(name).components() {
+        match component {
+            Component::Normal(_) => has_file_name = true,
+            Component::CurDir => {}
+            _ => return false,
+        }
+    }
+
+    has_file_name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_relative_to_output_dir;
+
+    #[test]
+    fn glue_file_names() {
+        assert!(is_relative_to_output_dir("roc_app.h"));
+        assert!(is_relative_to_output_dir("./src/roc_app.rs"));
+
+        assert!(!is_relative_to_output_dir("/etc/passwd"));
+        assert!(!is_relative_to_output_dir("../roc_app.h"));
+        assert!(!is_relative_to_output_dir("src/../../roc_app.h"));
+    }
+
+    #[test]
+    fn glue_file_names_must_name_a_file() {
+        assert!(!is_relative_to_output_dir(""));
+        assert!(!is_relative_to_output_dir("."));
+        assert!(!is_relative_to_output_dir("./"));
+        assert!(!is_relative_to_output_dir("./."));
+    }
+}
@@ -10,6 +10,7 @@ use roc_error_macros::{internal_error, user_error};
 use roc_load::{Expectations, LoadingProblem, Threading};
 use roc_module::symbol::{Interns, ModuleId};
 use roc_mono::ir::OptLevel;
+use roc_reporting::report::RenderTarget;
 use std::env;
 use std::ffi::{CString, OsStr};
 use std::io;
@@ -26,9 +27,13 @@ use tempfile::TempDir;
 
 pub mod build;
 mod format;
+mod glue;
+pub mod watch;
 pub use format::format;
+pub use glue::glue;
 
 use crate::build::{BuildFileError, BuildOrdering};
+use crate::watch::{self, Watched};
 
 const DEFAULT_ROC_FILENAME: &str = "main.roc";
 
@@ -43,6 +48,7 @@ pub const CMD_VERSION: &str = "version";
 pub const CMD_FORMAT: &str = "format";
 pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
+pub const CMD_LSP: &str = "lsp";
 
 pub const FLAG_DEBUG: &str = "debug";
 pub const FLAG_DEV: &str = "dev";
@@ -57,9 +63,18 @@ pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_PREBUILT: &str = "prebuilt-platform";
 pub const FLAG_CHECK: &str = "check";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
+pub const FLAG_WATCH: &str = "watch";
+pub const FLAG_FORMAT: &str = "format";
+pub const FLAG_FILTER: &str = "filter";
+pub const FLAG_LIST: &str = "list";
+pub const FLAG_REPORT: &str = "report";
+pub const FLAG_ISOLATE: &str = "isolate";
+pub const FLAG_TIMEOUT_MS: &str = "timeout-ms";
+pub const FLAG_SEED: &str = "seed";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
-pub const GLUE_FILE: &str = "GLUE_FILE";
+pub const GLUE_SPEC: &str = "GLUE_SPEC";
+pub const GLUE_DIR: &str = "GLUE_DIR";
 pub const DIRECTORY_OR_FILES: &str = "DIRECTORY_OR_FILES";
 pub const ARGS_FOR_APP: &str = "ARGS_FOR_APP";
 
@@ -117,6 +132,21 @@ pub fn build_app<'a>() -> Command<'a> {
         .validator(|s| s.parse::<u32>())
         .required(false);
 
+    let flag_watch = Arg::new(FLAG_WATCH)
+        .long(FLAG_WATCH)
+        .help("Run again whenever one of the .roc files this loaded changes")
+        .required(false);
+
+    let flag_format = Arg::new(FLAG_FORMAT)
+        .long(FLAG_FORMAT)
+        .help(
+            "How to print problems: as text, or as one line of JSON per problem for tools to read",
+        )
+        .takes_value(true)
+        .possible_values(["text", "json"])
+        .default_value("text")
+        .required(false);
+
     let roc_file_to_run = Arg::new(ROC_FILE)
         .help("The .roc file of an app to run")
         .allow_invalid_utf8(true)
@@ -136,6 +166,7 @@ pub fn build_app<'a>() -> Command<'a> {
         .about("Run the given .roc file, if there are no compilation errors.\nYou can use one of the SUBCOMMANDS below to do something else!")
         .subcommand(Command::new(CMD_BUILD)
             .about("Build a binary from the given .roc file, but don't run it")
+            .arg(flag_format.clone())
             .arg(flag_optimize.clone())
             .arg(flag_max_threads.clone())
             .arg(flag_opt_size.clone())
@@ -183,6 +214,50 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_watch.clone())
+            .arg(
+                Arg::new(FLAG_FILTER)
+                    .long(FLAG_FILTER)
+                    .help("Only run the expects whose names contain this text")
+                    .takes_value(true)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_LIST)
+                    .long(FLAG_LIST)
+                    .help("List the names of the expects that would run, without running them")
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_REPORT)
+                    .long(FLAG_REPORT)
+                    .help("Also write the results to a file, as `junit=<path>` or `json=<path>`")
+                    .takes_value(true)
+                    .multiple_occurrences(true)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_ISOLATE)
+                    .long(FLAG_ISOLATE)
+                    .help("Run each expect in its own process, so a crash or hang fails only that expect")
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_TIMEOUT_MS)
+                    .long(FLAG_TIMEOUT_MS)
+                    .help("Fail any expect that runs longer than this many milliseconds\n(This implies --isolate.)")
+                    .takes_value(true)
+                    .validator(|s| s.parse::<u64>())
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_SEED)
+                    .long(FLAG_SEED)
+                    .help("The seed of the random values that properties are checked with by `Gen.check`")
+                    .takes_value(true)
+                    .validator(|s| s.parse::<u64>())
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -218,6 +293,7 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_watch.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone())
         )
@@ -242,6 +318,8 @@ pub fn build_app<'a>() -> Command<'a> {
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
             .arg(flag_max_threads.clone())
+            .arg(flag_watch.clone())
+            .arg(flag_format.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -260,6 +338,8 @@ pub fn build_app<'a>() -> Command<'a> {
                     .allow_invalid_utf8(true)
                 )
         )
+        .subcommand(Command::new(CMD_LSP)
+            .about("Run a language server, which editors talk to over stdin and stdout"))
         .subcommand(Command::new(CMD_GLUE)
             .about("Generate glue code between a platform's Roc API and its host language")
             .arg(
@@ -269,11 +349,17 @@ pub fn build_app<'a>() -> Command<'a> {
                     .required(true)
             )
             .arg(
-                Arg::new(GLUE_FILE)
-                    .help("The filename for the generated glue code\n(Currently, this must be a .rs file because only Rust glue generation is supported so far.)")
+                Arg::new(GLUE_SPEC)
+                    .help("The .roc file for the glue spec, which generates the glue files\n(For the built-in Rust or C glue, give the filename of the .rs or .h file to generate instead.)")
                     .allow_invalid_utf8(true)
                     .required(true)
             )
+            .arg(
+                Arg::new(GLUE_DIR)
+                    .help("The directory to write the glue spec's files into")
+                    .allow_invalid_utf8(true)
+                    .required(false)
+            )
         )
         .trailing_var_arg(true)
         .arg(flag_optimize)
@@ -316,15 +402,25 @@ pub enum FormatMode {
 }
 
 #[cfg(windows)]
-pub fn test(_matches: &ArgMatches, _triple: Triple) -> io::Result<i32> {
+pub fn test(
+    _matches: &ArgMatches,
+    _triple: Triple,
+    _watched: Option<&mut Watched>,
+) -> io::Result<i32> {
     todo!("running tests does not work on windows right now")
 }
 
 #[cfg(not(windows))]
-pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
+pub fn test(
+    matches: &ArgMatches,
+    triple: Triple,
+    watched: Option<&mut Watched>,
+) -> io::Result<i32> {
     use roc_gen_llvm::llvm::build::LlvmBackendMode;
-    use roc_load::{ExecutionMode, LoadConfig};
+    use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError};
+    use roc_repl_expect::report::ReportFormat;
     use roc_target::TargetInfo;
+    use std::io::Write;
     use std::time::Instant;
 
     let start_time = Instant::now();
@@ -352,6 +448,16 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
         Some(n) => Threading::AtMost(n),
     };
 
+    let reports: Vec<(ReportFormat, PathBuf)> = matches
+        .values_of(FLAG_REPORT)
+        .into_iter()
+        .flatten()
+        .map(|arg| match ReportFormat::parse_arg(arg) {
+            Ok(report) => report,
+            Err(problem) => user_error!("invalid --{}: {}", FLAG_REPORT, problem),
+        })
+        .collect();
+
     let path = Path::new(filename);
 
     // Spawn the root task
@@ -386,10 +492,26 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
         render: roc_reporting::report::RenderTarget::ColorTerminal,
         threading,
         exec_mode: ExecutionMode::Test,
+        cache_dir: roc_load::cache::default_cache_dir(),
+    };
+    let load_result =
+        roc_load::load_and_monomorphize(arena, path.to_path_buf(), subs_by_module, load_config);
+
+    let loaded = match load_result {
+        Ok(loaded) => loaded,
+        Err(LoadMonomorphizedError::LoadingProblem(LoadingProblem::FormattedReport(report))) => {
+            print!("{}", report);
+
+            return Ok(1);
+        }
+        Err(other) => {
+            panic!("loading the tests failed with error:\n{:?}", other);
+        }
     };
-    let loaded =
-        roc_load::load_and_monomorphize(arena, path.to_path_buf(), subs_by_module, load_config)
-            .unwrap();
+
+    if let Some(watched) = watched {
+        watched.loaded(watch::source_files(&loaded.sources));
+    }
 
     let mut loaded = loaded;
     let mut expectations = std::mem::take(&mut loaded.expectations);
@@ -397,7 +519,7 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
 
     let interns = loaded.interns.clone();
 
-    let (lib, expects, layout_interner) = roc_repl_expect::run::expect_mono_module_to_dylib(
+    let (lib, mut expects, layout_interner) = roc_repl_expect::run::expect_mono_module_to_dylib(
         arena,
         target.clone(),
         loaded,
@@ -406,12 +528,38 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     )
     .unwrap();
 
+    let found = expects.len();
+
+    if let Some(filter) = matches.value_of(FLAG_FILTER) {
+        expects.retain(|expect| expect.test_name.contains(filter));
+    }
+
+    let filtered_out = found - expects.len();
+
+    if matches.is_present(FLAG_LIST) {
+        for expect in expects.iter() {
+            println!("{}", expect.test_name);
+        }
+
+        return Ok(0);
+    }
+
+    let timeout = matches
+        .value_of(FLAG_TIMEOUT_MS)
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(std::time::Duration::from_millis);
+
+    let seed = matches
+        .value_of(FLAG_SEED)
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(0);
+
     let arena = &bumpalo::Bump::new();
     let interns = arena.alloc(interns);
 
     let mut writer = std::io::stdout();
 
-    let (failed, passed) = roc_repl_expect::run::run_expects(
+    let results = roc_repl_expect::run::run_expects(
         &mut writer,
         roc_reporting::report::RenderTarget::ColorTerminal,
         arena,
@@ -420,12 +568,47 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
         &lib,
         &mut expectations,
         expects,
+        roc_repl_expect::run::RunOptions {
+            report_each: true,
+            isolate: matches.is_present(FLAG_ISOLATE) || timeout.is_some(),
+            timeout,
+            seed,
+        },
     )
     .unwrap();
 
     let total_time = start_time.elapsed();
 
-    if failed == 0 && passed == 0 {
+    let passed = results.iter().filter(|result| result.passed()).count();
+    let failed = results.len() - passed;
+
+    if !reports.is_empty() {
+        let records = roc_repl_expect::report::test_records(interns, &expectations, &results);
+
+        for (format, report_path) in reports.iter() {
+            let mut file = std::io::BufWriter::new(std::fs::File::create(report_path)?);
+
+            match format {
+                ReportFormat::Junit => {
+                    roc_repl_expect::report::write_junit(&mut file, &records, total_time)?
+                }
+                ReportFormat::Json => {
+                    roc_repl_expect::report::write_json(&mut file, &records, total_time)?
+                }
+            }
+
+            file.flush()?;
+        }
+    }
+
+    if results.is_empty() && filtered_out > 0 {
+        println!(
+            "No expectations matched `{}`, out of the {found} that were found.",
+            matches.value_of(FLAG_FILTER).unwrap_or_default()
+        );
+
+        Ok(2)
+    } else if results.is_empty() {
         // TODO print this in a more nicely formatted way!
         println!("No expectations were found.");
 
@@ -442,8 +625,13 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
             31 // red
         };
 
+        let filtered_out = match filtered_out {
+            0 => String::new(),
+            n => format!(" ({n} filtered out)"),
+        };
+
         println!(
-            "\n\x1B[{failed_color}m{failed}\x1B[39m failed and \x1B[32m{passed}\x1B[39m passed in {} ms.\n",
+            "\n\x1B[{failed_color}m{failed}\x1B[39m failed and \x1B[32m{passed}\x1B[39m passed{filtered_out} in {} ms.\n",
             total_time.as_millis(),
         );
 
@@ -451,11 +639,20 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     }
 }
 
+/// How to print problems, as chosen by `--format` (which only some subcommands accept)
+pub fn render_target(matches: &ArgMatches) -> RenderTarget {
+    match matches.try_get_one::<String>(FLAG_FORMAT) {
+        Ok(Some(format)) if format == "json" => RenderTarget::Json,
+        _ => RenderTarget::ColorTerminal,
+    }
+}
+
 pub fn build(
     matches: &ArgMatches,
     config: BuildConfig,
     triple: Triple,
     link_type: LinkType,
+    mut watched: Option<&mut Watched>,
 ) -> io::Result<i32> {
     use build::build_file;
     use BuildConfig::*;
@@ -538,6 +735,7 @@ pub fn build(
         BuildAndRunIfNoErrors => BuildOrdering::BuildIfChecks,
         _ => BuildOrdering::AlwaysBuild,
     };
+    let render = render_target(matches);
     let res_binary_path = build_file(
         &arena,
         &triple,
@@ -551,6 +749,7 @@ pub fn build(
         threading,
         wasm_dev_stack_bytes,
         build_ordering,
+        render,
     );
 
     match res_binary_path {
@@ -560,7 +759,12 @@ pub fn build(
             total_time,
             expectations,
             interns,
+            loaded_files,
         }) => {
+            if let Some(watched) = watched.as_deref_mut() {
+                watched.loaded(loaded_files);
+            }
+
             match config {
                 BuildOnly => {
                     // If possible, report the generated executable name relative to the current dir.
@@ -572,6 +776,11 @@ pub fn build(
                     // since the process is about to exit anyway.
                     std::mem::forget(arena);
 
+                    // Only the problems themselves are printed as JSON.
+                    if let RenderTarget::Json = render {
+                        return Ok(problems.exit_code());
+                    }
+
                     println!(
                         "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms while successfully building:\n\n    {}",
                         if problems.errors == 0 {
@@ -671,6 +880,14 @@ pub fn build(
 
                     let args = matches.values_of_os(ARGS_FOR_APP).unwrap_or_default();
 
+                    // In watch mode we need to stay around to run the app again after a change, so
+                    // run it in a child process instead of replacing this process with it.
+                    if let Some(watched) = watched {
+                        watched.spawned(process::Command::new(&binary_path).args(args).spawn()?);
+
+                        return Ok(0);
+                    }
+
                     // ManuallyDrop will leak the bytes because we don't drop manually
                     let bytes = &ManuallyDrop::new(std::fs::read(&binary_path).unwrap());
 
@@ -684,7 +901,11 @@ pub fn build(
         }) => {
             debug_assert!(module.total_problems() > 0);
 
-            let problems = roc_build::program::report_problems_typechecked(&mut module);
+            if let Some(watched) = watched {
+                watched.loaded(watch::source_files(&module.sources));
+            }
+
+            let problems = roc_build::program::report_problems_typechecked(&mut module, render);
 
             let mut output = format!(
                 "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.\n\nYou can run the program anyway with \x1B[32mroc run",
@@ -724,7 +945,10 @@ pub fn build(
             Ok(problems.exit_code())
         }
         Err(BuildFileError::LoadingProblem(LoadingProblem::FormattedReport(report))) => {
-            print!("{}", report);
+            match render {
+                RenderTarget::Json => println!("{}", report),
+                _ => print!("{}", report),
+            }
 
             Ok(1)
         }
@@ -858,6 +1082,13 @@ fn roc_run_native<I: IntoIterator<Item = S>, S: AsRef<OsStr>>(
             OptLevel::Development => {
                 roc_run_native_debug(executable, &argv, &envp, expectations, interns)
             }
+            OptLevel::Normal
+                if expectations
+                    .values()
+                    .any(|data| !data.dbgs.is_empty() || !data.crashes.is_empty()) =>
+            {
+                return roc_run_native_dbg(&arena, executable, &argv, &envp, expectations, interns);
+            }
             OptLevel::Normal | OptLevel::Size | OptLevel::Optimize => {
                 roc_run_native_fast(executable, &argv, &envp);
             }
@@ -882,6 +1113,88 @@ unsafe fn roc_run_native_fast(
     }
 }
 
+/// Run the program in a child process, printing the values that its `dbg`s report,
+/// and the message and location of a `crash`.
+///
+/// The default `roc_dbg` sends each frame over the socket named by `ROC_DBG_FD`,
+/// and waits for us to acknowledge it, so that the output stays in order.
+#[cfg(target_family = "unix")]
+unsafe fn roc_run_native_dbg(
+    arena: &Bump,
+    executable: ExecutableFile,
+    argv: &[*const c_char],
+    envp: &[*const c_char],
+    mut expectations: VecMap<ModuleId, Expectations>,
+    interns: Interns,
+) -> std::io::Result<i32> {
+    use bumpalo::collections::CollectIn;
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::net::UnixStream;
+
+    let (mut parent_socket, child_socket) = UnixStream::pair()?;
+
+    match libc::fork() {
+        0 => {
+            // we are the child
+            drop(parent_socket);
+
+            // the program must inherit its end of the socket
+            let fd = child_socket.as_raw_fd();
+            libc::fcntl(fd, libc::F_SETFD, 0);
+
+            // `roc_dbg` only reads the start of the environment, so this must come first
+            let dbg_fd = CString::new(format!("ROC_DBG_FD={}", fd)).unwrap();
+            let envp: bumpalo::collections::Vec<*const c_char> = std::iter::once(dbg_fd.as_ptr())
+                .chain(envp.iter().copied())
+                .collect_in(arena);
+
+            roc_run_native_fast(executable, argv, &envp);
+
+            std::process::exit(1)
+        }
+        -1 => Err(std::io::Error::last_os_error()),
+        child_pid => {
+            drop(child_socket);
+
+            let interns = arena.alloc(interns);
+            let layout_interner = roc_intern::GlobalInterner::with_capacity(128);
+
+            let mut writer = std::io::stdout();
+            let mut length_bytes = [0u8; std::mem::size_of::<usize>()];
+            let mut frame = Vec::new();
+
+            // the socket is closed once the program exits
+            while parent_socket.read_exact(&mut length_bytes).is_ok() {
+                frame.resize(usize::from_ne_bytes(length_bytes), 0);
+                parent_socket.read_exact(&mut frame)?;
+
+                roc_repl_expect::run::roc_dev_dbg(
+                    &mut writer,
+                    arena,
+                    &mut expectations,
+                    interns,
+                    &layout_interner,
+                    frame.as_ptr(),
+                )?;
+                writer.flush()?;
+
+                // let the program continue
+                parent_socket.write_all(&[0])?;
+            }
+
+            let mut status = 0;
+            libc::waitpid(child_pid, &mut status, 0);
+
+            if libc::WIFEXITED(status) {
+                Ok(libc::WEXITSTATUS(status))
+            } else {
+                Ok(1)
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 enum ExecutableFile {
     #[cfg(target_os = "linux")]
@@ -1200,27 +1513,27 @@ impl std::str::FromStr for Target {
     }
 }
 
-// These functions don't end up in the final Roc binary but Windows linker needs a definition inside the crate.
-// On Windows, there seems to be less dead-code-elimination than on Linux or MacOS, or maybe it's done later.
-#[cfg(windows)]
+// These functions don't end up in the final Roc binary, but `roc glue` allocates the
+// Roc values it passes to glue specs with roc_std, which needs them.
+// The Windows linker also needs a definition inside the crate regardless; on Windows,
+// there seems to be less dead-code-elimination than on Linux or MacOS, or maybe it's done later.
 #[allow(unused_imports)]
-use windows_roc_platform_functions::*;
+use roc_platform_functions::*;
 
-#[cfg(windows)]
-mod windows_roc_platform_functions {
+mod roc_platform_functions {
     use core::ffi::c_void;
 
     /// # Safety
     /// The Roc application needs this.
     #[no_mangle]
-    pub unsafe fn roc_alloc(size: usize, _alignment: u32) -> *mut c_void {
+    pub unsafe extern "C" fn roc_alloc(size: usize, _alignment: u32) -> *mut c_void {
         libc::malloc(size)
     }
 
     /// # Safety
     /// The Roc application needs this.
     #[no_mangle]
-    pub unsafe fn roc_realloc(
+    pub unsafe extern "C" fn roc_realloc(
         c_ptr: *mut c_void,
         new_size: usize,
         _old_size: usize,
@@ -1232,7 +1545,7 @@ mod windows_roc_platform_functions {
     /// # Safety
     /// The Roc application needs this.
     #[no_mangle]
-    pub unsafe fn roc_dealloc(c_ptr: *mut c_void, _alignment: u32) {
+    pub unsafe extern "C" fn roc_dealloc(c_ptr: *mut c_void, _alignment: u32) {
         libc::free(c_ptr)
     }
 }
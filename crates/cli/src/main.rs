@@ -1,14 +1,17 @@
+use clap::ArgMatches;
 use roc_build::link::LinkType;
 use roc_cli::build::check_file;
+use roc_cli::watch::{watch, Watched};
 use roc_cli::{
-    build_app, format, test, BuildConfig, FormatMode, Target, CMD_BUILD, CMD_CHECK, CMD_DEV,
-    CMD_DOCS, CMD_EDIT, CMD_FORMAT, CMD_GLUE, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION,
-    DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_LIB, FLAG_NO_LINK, FLAG_TARGET, FLAG_TIME, GLUE_FILE,
-    ROC_FILE,
+    build_app, format, glue, render_target, test, BuildConfig, FormatMode, Target, CMD_BUILD,
+    CMD_CHECK, CMD_DEV, CMD_DOCS, CMD_EDIT, CMD_FORMAT, CMD_GLUE, CMD_LSP, CMD_REPL, CMD_RUN,
+    CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_LIB, FLAG_NO_LINK, FLAG_TARGET,
+    FLAG_TIME, FLAG_WATCH, GLUE_DIR, GLUE_SPEC, ROC_FILE,
 };
 use roc_docs::generate_docs_html;
 use roc_error_macros::user_error;
 use roc_load::{LoadingProblem, Threading};
+use roc_reporting::report::RenderTarget;
 use std::fs::{self, FileType};
 use std::io;
 use std::path::{Path, PathBuf};
@@ -37,6 +40,7 @@ fn main() -> io::Result<()> {
                     BuildConfig::BuildAndRunIfNoErrors,
                     Triple::host(),
                     LinkType::Executable,
+                    None,
                 )
             } else {
                 launch_editor(None)?;
@@ -51,6 +55,7 @@ fn main() -> io::Result<()> {
                     BuildConfig::BuildAndRun,
                     Triple::host(),
                     LinkType::Executable,
+                    None,
                 )
             } else {
                 eprintln!("What .roc file do you want to run? Specify it at the end of the `roc run` command.");
@@ -59,38 +64,67 @@ fn main() -> io::Result<()> {
             }
         }
         Some((CMD_TEST, matches)) => {
-            if matches.is_present(ROC_FILE) {
-                test(matches, Triple::host())
-            } else {
+            if !matches.is_present(ROC_FILE) {
                 eprintln!("What .roc file do you want to test? Specify it at the end of the `roc test` command.");
 
                 Ok(1)
+            } else if matches.is_present(FLAG_WATCH) {
+                let roc_file_path = Path::new(matches.value_of_os(ROC_FILE).unwrap());
+
+                watch(roc_file_path, |watched| {
+                    test(matches, Triple::host(), Some(watched))
+                })
+            } else {
+                test(matches, Triple::host(), None)
             }
         }
         Some((CMD_DEV, matches)) => {
-            if matches.is_present(ROC_FILE) {
+            if !matches.is_present(ROC_FILE) {
+                eprintln!("What .roc file do you want to build? Specify it at the end of the `roc run` command.");
+
+                Ok(1)
+            } else if matches.is_present(FLAG_WATCH) {
+                let roc_file_path = Path::new(matches.value_of_os(ROC_FILE).unwrap());
+
+                watch(roc_file_path, |watched| {
+                    build(
+                        matches,
+                        BuildConfig::BuildAndRunIfNoErrors,
+                        Triple::host(),
+                        LinkType::Executable,
+                        Some(watched),
+                    )
+                })
+            } else {
                 build(
                     matches,
                     BuildConfig::BuildAndRunIfNoErrors,
                     Triple::host(),
                     LinkType::Executable,
+                    None,
                 )
-            } else {
-                eprintln!("What .roc file do you want to build? Specify it at the end of the `roc run` command.");
-
-                Ok(1)
             }
         }
         Some((CMD_GLUE, matches)) => {
             let input_path = Path::new(matches.value_of_os(ROC_FILE).unwrap());
-            let output_path = Path::new(matches.value_of_os(GLUE_FILE).unwrap());
+            let spec_path = Path::new(matches.value_of_os(GLUE_SPEC).unwrap());
 
-            if Some("rs") == output_path.extension().and_then(OsStr::to_str) {
-                roc_glue::generate(input_path, output_path)
-            } else {
-                eprintln!("Currently, `roc glue` only supports generating Rust glue files (with the .rs extension). In the future, the plan is to decouple `roc glue` from any particular output format, by having it accept a second .roc file which gets executed as a plugin to generate glue code for any desired language. However, this has not yet been implemented, and for now only .rs is supported.");
+            match spec_path.extension().and_then(OsStr::to_str) {
+                // A .rs or .h file is where to write the built-in Rust or C glue, rather than a spec.
+                Some("rs") | Some("h") => roc_glue::generate(input_path, spec_path),
+                Some("roc") => match matches.value_of_os(GLUE_DIR) {
+                    Some(output_dir) => glue(input_path, spec_path, Path::new(output_dir)),
+                    None => {
+                        eprintln!("What directory should the glue files go in? Specify it after the glue spec, e.g. `roc glue platform.roc glue-spec.roc glue/`");
 
-                Ok(1)
+                        Ok(1)
+                    }
+                },
+                _ => {
+                    eprintln!("`roc glue` expects either a .roc glue spec, which gets run as a plugin to generate glue files, or the name of a .rs or .h file to generate the built-in Rust or C glue into.");
+
+                    Ok(1)
+                }
             }
         }
         Some((CMD_BUILD, matches)) => {
@@ -111,64 +145,16 @@ fn main() -> io::Result<()> {
                 BuildConfig::BuildOnly,
                 target.to_triple(),
                 link_type,
+                None,
             )?)
         }
         Some((CMD_CHECK, matches)) => {
-            let arena = bumpalo::Bump::new();
-
-            let emit_timings = matches.is_present(FLAG_TIME);
-            let filename = matches.value_of_os(ROC_FILE).unwrap();
-            let roc_file_path = PathBuf::from(filename);
-            let threading = match matches
-                .value_of(roc_cli::FLAG_MAX_THREADS)
-                .and_then(|s| s.parse::<usize>().ok())
-            {
-                None => Threading::AllAvailable,
-                Some(0) => user_error!("cannot build with at most 0 threads"),
-                Some(1) => Threading::Single,
-                Some(n) => Threading::AtMost(n),
-            };
-
-            match check_file(&arena, roc_file_path, emit_timings, threading) {
-                Ok((problems, total_time)) => {
-                    println!(
-                        "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.",
-                        if problems.errors == 0 {
-                            32 // green
-                        } else {
-                            33 // yellow
-                        },
-                        problems.errors,
-                        if problems.errors == 1 {
-                            "error"
-                        } else {
-                            "errors"
-                        },
-                        if problems.warnings == 0 {
-                            32 // green
-                        } else {
-                            33 // yellow
-                        },
-                        problems.warnings,
-                        if problems.warnings == 1 {
-                            "warning"
-                        } else {
-                            "warnings"
-                        },
-                        total_time.as_millis(),
-                    );
-
-                    Ok(problems.exit_code())
-                }
+            if matches.is_present(FLAG_WATCH) {
+                let roc_file_path = Path::new(matches.value_of_os(ROC_FILE).unwrap());
 
-                Err(LoadingProblem::FormattedReport(report)) => {
-                    print!("{}", report);
-
-                    Ok(1)
-                }
-                Err(other) => {
-                    panic!("build_file failed with error:\n{:?}", other);
-                }
+                watch(roc_file_path, |watched| check(matches, Some(watched)))
+            } else {
+                check(matches, None)
             }
         }
         Some((CMD_REPL, _)) => {
@@ -276,6 +262,7 @@ fn main() -> io::Result<()> {
 
             Ok(format_exit_code)
         }
+        Some((CMD_LSP, _)) => roc_language_server::run(),
         Some((CMD_VERSION, _)) => {
             print!(
                 "{}",
@@ -328,6 +315,81 @@ fn roc_files_recursive<P: AsRef<Path>>(
     Ok(())
 }
 
+fn check(matches: &ArgMatches, watched: Option<&mut Watched>) -> io::Result<i32> {
+    let arena = bumpalo::Bump::new();
+
+    let emit_timings = matches.is_present(FLAG_TIME);
+    let filename = matches.value_of_os(ROC_FILE).unwrap();
+    let roc_file_path = PathBuf::from(filename);
+    let threading = match matches
+        .value_of(roc_cli::FLAG_MAX_THREADS)
+        .and_then(|s| s.parse::<usize>().ok())
+    {
+        None => Threading::AllAvailable,
+        Some(0) => user_error!("cannot build with at most 0 threads"),
+        Some(1) => Threading::Single,
+        Some(n) => Threading::AtMost(n),
+    };
+
+    let render = render_target(matches);
+
+    match check_file(
+        &arena,
+        roc_file_path,
+        emit_timings,
+        threading,
+        render,
+        watched,
+    ) {
+        Ok((problems, _)) if matches!(render, RenderTarget::Json) => {
+            // Only the problems themselves are printed as JSON.
+            Ok(problems.exit_code())
+        }
+        Ok((problems, total_time)) => {
+            println!(
+                "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.",
+                if problems.errors == 0 {
+                    32 // green
+                } else {
+                    33 // yellow
+                },
+                problems.errors,
+                if problems.errors == 1 {
+                    "error"
+                } else {
+                    "errors"
+                },
+                if problems.warnings == 0 {
+                    32 // green
+                } else {
+                    33 // yellow
+                },
+                problems.warnings,
+                if problems.warnings == 1 {
+                    "warning"
+                } else {
+                    "warnings"
+                },
+                total_time.as_millis(),
+            );
+
+            Ok(problems.exit_code())
+        }
+
+        Err(LoadingProblem::FormattedReport(report)) => {
+            match render {
+                RenderTarget::Json => println!("{}", report),
+                _ => print!("{}", report),
+            }
+
+            Ok(1)
+        }
+        Err(other) => {
+            panic!("build_file failed with error:\n{:?}", other);
+        }
+    }
+}
+
 #[cfg(feature = "editor")]
 fn launch_editor(project_dir_path: Option<&Path>) -> io::Result<()> {
     roc_editor::launch(project_dir_path)
@@ -0,0 +1,247 @@
+//! `--watch` mode: run a command again whenever one of the files it loaded changes.
+
+use roc_collections::MutMap;
+use roc_module::symbol::ModuleId;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Child;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often to check whether any of the watched files changed
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long the watched files have to stay the same before we run again, so that saving several
+/// files at once (or an editor writing a file in several steps) only causes one run.
+const DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// What one run in watch mode loaded, and the app it started (for `roc dev`).
+#[derive(Debug, Default)]
+pub struct Watched {
+    loaded_files: Vec<PathBuf>,
+    child: Option<Child>,
+}
+
+impl Watched {
+    pub fn loaded(&mut self, files: Vec<PathBuf>) {
+        self.loaded_files = files;
+    }
+
+    /// Kill this process before running again.
+    pub fn spawned(&mut self, child: Child) {
+        self.child = Some(child);
+    }
+}
+
+/// The source files of every module the loader read, other than the builtins
+pub fn source_files(sources: &MutMap<ModuleId, (PathBuf, Box<str>)>) -> Vec<PathBuf> {
+    sources
+        .iter()
+        .filter(|(module_id, _)| !module_id.is_builtin())
+        .map(|(_, (path, _))| path.clone())
+        .collect()
+}
+
+/// Run `run`, and then run it again whenever one of the files it loaded changes. This only
+/// returns if something goes wrong while watching.
+///
+/// Every run loads all modules from scratch, but only the modules that changed (or whose
+/// dependencies changed) are parsed, canonicalized and solved again: the others come from the
+/// disk cache in `roc_load::cache`. Specializing and generating code is still done for the whole
+/// program by `roc test` and `roc dev`.
+pub fn watch(root: &Path, mut run: impl FnMut(&mut Watched) -> io::Result<i32>) -> io::Result<i32> {
+    let mut files = vec![root.to_path_buf()];
+
+    loop {
+        // Clear the screen, so that only the problems of this run are shown.
+        print!("\x1B[2J\x1B[H");
+        io::stdout().flush()?;
+
+        // Taken before running, so that a file saved while we're compiling counts as a change.
+        let started = SystemTime::now();
+        let before: MutMap<PathBuf, Option<SystemTime>> = files
+            .iter()
+            .map(|file| (file.clone(), modified_time(file)))
+            .collect();
+
+        let mut watched = Watched::default();
+
+        run(&mut watched)?;
+
+        // If loading failed before we knew which files were involved (e.g. because of a syntax
+        // error in a module header), keep watching the files of the previous run.
+        if !watched.loaded_files.is_empty() {
+            files = watched.loaded_files;
+
+            if !files.iter().any(|file| file == root) {
+                files.push(root.to_path_buf());
+            }
+        }
+
+        println!(
+            "\n\x1B[36mWatching {} {} for changes…\x1B[39m",
+            files.len(),
+            if files.len() == 1 { "file" } else { "files" }
+        );
+
+        wait_for_change(
+            &baseline(&files, &before, started),
+            || modified_times(&files),
+            thread::sleep,
+        );
+
+        if let Some(mut child) = watched.child {
+            // The app may well have exited on its own already, in which case there's nothing
+            // to kill.
+            let _ = child.kill();
+            child.wait()?;
+        }
+    }
+}
+
+/// The modification times to compare against after a run that started at `started`. Files we
+/// only learned about during the run weren't in the `before` snapshot, so if one of those was
+/// modified after the run started, it gets no time at all, and will count as changed.
+fn baseline(
+    files: &[PathBuf],
+    before: &MutMap<PathBuf, Option<SystemTime>>,
+    started: SystemTime,
+) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| match before.get(file) {
+            Some(time) => *time,
+            None => modified_time(file).filter(|time| *time < started),
+        })
+        .collect()
+}
+
+/// Wait until `modified_times` differs from `baseline`, and then until it stays the same for
+/// [DEBOUNCE].
+fn wait_for_change(
+    baseline: &[Option<SystemTime>],
+    mut modified_times: impl FnMut() -> Vec<Option<SystemTime>>,
+    mut sleep: impl FnMut(Duration),
+) {
+    let mut latest = modified_times();
+
+    while latest == baseline {
+        sleep(POLL_INTERVAL);
+
+        latest = modified_times();
+    }
+
+    loop {
+        sleep(DEBOUNCE);
+
+        let now = modified_times();
+
+        if now == latest {
+            return;
+        }
+
+        latest = now;
+    }
+}
+
+fn modified_times(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files.iter().map(|file| modified_time(file)).collect()
+}
+
+/// A file that can't be read (e.g. because an editor is in the middle of replacing it) has no
+/// modification time, so it counts as a change when it appears or disappears.
+fn modified_time(file: &Path) -> Option<SystemTime> {
+    fs::metadata(file)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{baseline, wait_for_change, DEBOUNCE, POLL_INTERVAL};
+    use roc_collections::MutMap;
+    use std::time::{Duration, SystemTime};
+
+    /// Runs `wait_for_change` against the given polls, returning how long it slept in between.
+    fn sleeps(baseline: &[Option<SystemTime>], polls: &[&[Option<SystemTime>]]) -> Vec<Duration> {
+        let mut polls = polls.iter();
+        let mut sleeps = Vec::new();
+
+        wait_for_change(
+            baseline,
+            || polls.next().expect("polled after the last change").to_vec(),
+            |duration| sleeps.push(duration),
+        );
+
+        assert_eq!(polls.next(), None, "stopped before the last poll");
+
+        sleeps
+    }
+
+    fn time(secs: u64) -> Option<SystemTime> {
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    #[test]
+    fn polls_until_a_file_changes() {
+        assert_eq!(
+            sleeps(
+                &[time(1), time(1)],
+                &[
+                    &[time(1), time(1)],
+                    &[time(1), time(1)],
+                    &[time(1), time(2)],
+                    &[time(1), time(2)],
+                ]
+            ),
+            [POLL_INTERVAL, POLL_INTERVAL, DEBOUNCE]
+        );
+    }
+
+    #[test]
+    fn change_during_run_is_seen_right_away() {
+        assert_eq!(sleeps(&[time(1)], &[&[time(2)], &[time(2)]]), [DEBOUNCE]);
+    }
+
+    #[test]
+    fn waits_until_files_stop_changing() {
+        assert_eq!(
+            sleeps(
+                &[time(1), time(1)],
+                &[
+                    &[time(2), time(1)],
+                    &[time(2), time(2)],
+                    &[time(3), time(2)],
+                    &[time(3), time(2)],
+                ]
+            ),
+            [DEBOUNCE, DEBOUNCE, DEBOUNCE]
+        );
+    }
+
+    #[test]
+    fn file_disappearing_is_a_change() {
+        assert_eq!(
+            sleeps(&[time(1)], &[&[time(1)], &[None], &[time(2)], &[time(2)]]),
+            [POLL_INTERVAL, DEBOUNCE, DEBOUNCE]
+        );
+    }
+
+    #[test]
+    fn file_first_loaded_during_run() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let files = [file.path().to_path_buf()];
+        let modified = file.as_file().metadata().unwrap().modified().unwrap();
+        let before = MutMap::default();
+
+        // Saved before the run started: only a later save is a change.
+        assert_eq!(
+            baseline(&files, &before, modified + Duration::from_secs(1)),
+            [Some(modified)]
+        );
+
+        // Saved while the run was going: that's a change already.
+        assert_eq!(baseline(&files, &before, modified), [None]);
+    }
+}
@@ -6,6 +6,7 @@ use roc_load::{EntryPoint, LoadedModule, MonomorphizedModule};
 use roc_module::symbol::{Interns, ModuleId};
 use roc_mono::ir::OptLevel;
 use roc_region::all::LineInfo;
+use roc_reporting::report::RenderTarget;
 use roc_solve_problem::TypeError;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
@@ -20,23 +21,28 @@ pub struct CodeGenTiming {
     pub code_gen: Duration,
 }
 
-pub fn report_problems_monomorphized(loaded: &mut MonomorphizedModule) -> Problems {
+pub fn report_problems_monomorphized(
+    loaded: &mut MonomorphizedModule,
+    render: RenderTarget,
+) -> Problems {
     report_problems_help(
         loaded.total_problems(),
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        render,
     )
 }
 
-pub fn report_problems_typechecked(loaded: &mut LoadedModule) -> Problems {
+pub fn report_problems_typechecked(loaded: &mut LoadedModule, render: RenderTarget) -> Problems {
     report_problems_help(
         loaded.total_problems(),
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        render,
     )
 }
 
@@ -63,6 +69,7 @@ fn report_problems_help(
     interns: &Interns,
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    render: RenderTarget,
 ) -> Problems {
     use roc_reporting::report::{
         can_problem, type_problem, Report, RocDocAllocator, Severity::*, DEFAULT_PALETTE,
@@ -87,11 +94,18 @@ fn report_problems_help(
         let problems = can_problems.remove(home).unwrap_or_default();
 
         for problem in problems.into_iter() {
+            let region = problem.region().map(|region| lines.convert_region(region));
             let report = can_problem(&alloc, &lines, module_path.clone(), problem);
             let severity = report.severity;
             let mut buf = String::new();
 
-            report.render_color_terminal(&mut buf, &alloc, &palette);
+            match render {
+                RenderTarget::ColorTerminal => {
+                    report.render_color_terminal(&mut buf, &alloc, &palette)
+                }
+                RenderTarget::Generic => report.render_ci(&mut buf, &alloc),
+                RenderTarget::Json => report.render_json(&mut buf, region),
+            }
 
             match severity {
                 Warning => {
@@ -106,11 +120,19 @@ fn report_problems_help(
         let problems = type_problems.remove(home).unwrap_or_default();
 
         for problem in problems {
+            let region = problem.region().map(|region| lines.convert_region(region));
+
             if let Some(report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
                 let severity = report.severity;
                 let mut buf = String::new();
 
-                report.render_color_terminal(&mut buf, &alloc, &palette);
+                match render {
+                    RenderTarget::ColorTerminal => {
+                        report.render_color_terminal(&mut buf, &alloc, &palette)
+                    }
+                    RenderTarget::Generic => report.render_ci(&mut buf, &alloc),
+                    RenderTarget::Json => report.render_json(&mut buf, region),
+                }
 
                 match severity {
                     Warning => {
@@ -126,8 +148,16 @@ fn report_problems_help(
 
     let problems_reported;
 
-    // Only print warnings if there are no errors
-    if errors.is_empty() {
+    if let RenderTarget::Json = render {
+        // Tools reading the JSON can filter by severity themselves, so print everything, one
+        // report per line.
+        for report in errors.iter().chain(warnings.iter()) {
+            println!("{}", report);
+        }
+
+        problems_reported = 0;
+    } else if errors.is_empty() {
+        // Only print warnings if there are no errors
         problems_reported = warnings.len();
 
         for warning in warnings.iter() {
@@ -277,12 +307,16 @@ fn gen_from_mono_module_llvm(
         interns: loaded.interns,
         module,
         target_info,
-        mode: LlvmBackendMode::Binary,
+        mode: match opt_level {
+            // `dbg` is for development; optimized builds strip it
+            OptLevel::Normal => LlvmBackendMode::BinaryDev,
+            OptLevel::Size | OptLevel::Optimize | OptLevel::Development => LlvmBackendMode::Binary,
+        },
         exposed_to_host: loaded.exposed_to_host.values.keys().copied().collect(),
     };
 
-    // does not add any externs for this mode (we have a host) but cleans up some functions around
-    // expects that would confuse the surgical linker
+    // does not add any externs for these modes (we have a host) but cleans up some functions around
+    // expects and dbg that would confuse the surgical linker
     add_default_roc_externs(&env);
 
     let opt_entry_point = match loaded.entry_point {
@@ -0,0 +1,175 @@
+#!/usr/bin/env python3
+"""Generates src/helpers/unicode_tables.zig from Python's bundled Unicode database.
+
+Run this from the bitcode directory to regenerate the tables:
+
+    python3 gen_unicode_tables.py > src/helpers/unicode_tables.zig
+
+CI runs it with `--check`, which fails if the committed file is out of date:
+
+    python3 gen_unicode_tables.py --check
+
+The tables come from the Unicode database that ships with the Python interpreter
+(see `unicodedata.unidata_version`), so the script refuses to run on any other
+version than UNICODE_VERSION. To bump it, change UNICODE_VERSION along with the
+Python version in .github/workflows/ubuntu_x86_64.yml and regenerate the file.
+"""
+
+import io
+import sys
+import unicodedata
+
+# Python 3.11 bundles Unicode 14.0.0
+UNICODE_VERSION = "14.0.0"
+TABLES_PATH = "src/helpers/unicode_tables.zig"
+
+MAX_CODEPOINT = 0x10FFFF
+SIGMA = "Σ"
+
+
+def scalars():
+    for cp in range(MAX_CODEPOINT + 1):
+        if 0xD800 <= cp <= 0xDFFF:
+            continue
+        yield cp
+
+
+def case_mappings(convert):
+    # Final_Sigma is context-sensitive, so it is handled in code; the table
+    # only holds the non-final lowercase form of capital sigma.
+    entries = []
+    for cp in scalars():
+        mapped = convert(chr(cp))
+        if mapped != chr(cp):
+            assert len(mapped) <= 3, hex(cp)
+            entries.append([cp] + [ord(c) for c in mapped] + [0] * (3 - len(mapped)))
+    return entries
+
+
+def ranges(predicate):
+    result = []
+    start = None
+    for cp in range(MAX_CODEPOINT + 2):
+        matches = cp <= MAX_CODEPOINT and not (0xD800 <= cp <= 0xDFFF) and predicate(cp)
+        if matches and start is None:
+            start = cp
+        elif not matches and start is not None:
+            result.append((start, cp - 1))
+            start = None
+    return result
+
+
+def is_cased(cp):
+    # Python's str.lower() implements Final_Sigma using the Cased and
+    # Case_Ignorable derived properties, so we recover them by probing it.
+    return ("A" + SIGMA + chr(cp)).lower()[1] == "σ"
+
+
+def is_case_ignorable(cp):
+    return ("A" + chr(cp) + SIGMA).lower()[-1] == "ς" and not is_cased(cp)
+
+
+def combining_classes():
+    result = []
+    start = None
+    current = 0
+    for cp in range(MAX_CODEPOINT + 2):
+        ccc = unicodedata.combining(chr(cp)) if cp <= MAX_CODEPOINT else 0
+        if ccc != current:
+            if current != 0:
+                result.append((start, cp - 1, current))
+            start = cp
+            current = ccc
+    return result
+
+
+def canonical_decompositions():
+    entries = []
+    for cp in scalars():
+        decomposition = unicodedata.decomposition(chr(cp))
+        if decomposition == "" or decomposition.startswith("<"):
+            continue
+        parts = [int(part, 16) for part in decomposition.split()]
+        assert len(parts) <= 2, hex(cp)
+        entries.append([cp] + parts + [0] * (2 - len(parts)))
+    return entries
+
+
+def compositions(decompositions):
+    entries = []
+    for cp, first, second in decompositions:
+        if second == 0:
+            # singletons never recompose
+            continue
+        if unicodedata.normalize("NFC", chr(cp)) != chr(cp):
+            # composition exclusions and non-starter decompositions
+            continue
+        entries.append([first, second, cp])
+    entries.sort()
+    return entries
+
+
+def emit_table(out, name, entries, width):
+    out.write(f"pub const {name} = [_][{width}]u21{{\n")
+    for entry in entries:
+        out.write("    .{ " + ", ".join(f"0x{value:X}" for value in entry) + " },\n")
+    out.write("};\n\n")
+
+
+def generate(out):
+    upper = case_mappings(str.upper)
+    lower = case_mappings(str.lower)
+    decompositions = canonical_decompositions()
+
+    out.write("// THIS FILE IS GENERATED BY gen_unicode_tables.py - DO NOT EDIT BY HAND!\n")
+    out.write("//\n")
+    out.write(f"// Unicode version: {unicodedata.unidata_version}\n")
+    out.write("//\n")
+    out.write("// Every table is sorted by its leading columns so it can be binary searched.\n\n")
+
+    out.write(f'pub const unicode_version = "{unicodedata.unidata_version}";\n\n')
+
+    out.write("// { codepoint, mapping_0, mapping_1, mapping_2 }, unused slots are 0\n")
+    emit_table(out, "upper_mappings", upper, 4)
+    emit_table(out, "lower_mappings", lower, 4)
+
+    out.write("// { first, last } inclusive ranges of the Cased property\n")
+    emit_table(out, "cased_ranges", ranges(is_cased), 2)
+    out.write("// { first, last } inclusive ranges of the Case_Ignorable property\n")
+    emit_table(out, "case_ignorable_ranges", ranges(is_case_ignorable), 2)
+
+    out.write("// { first, last, canonical_combining_class } for every non-zero class\n")
+    emit_table(out, "combining_classes", combining_classes(), 3)
+
+    out.write("// { codepoint, decomposition_0, decomposition_1 }, one level deep; Hangul is algorithmic\n")
+    emit_table(out, "canonical_decompositions", decompositions, 3)
+
+    out.write("// { first, second, composite } for every primary composite\n")
+    emit_table(out, "canonical_compositions", compositions(decompositions), 3)
+
+
+def main():
+    if unicodedata.unidata_version != UNICODE_VERSION:
+        sys.exit(
+            f"This Python bundles Unicode {unicodedata.unidata_version}, but the tables are "
+            f"pinned to Unicode {UNICODE_VERSION}. Run this script with Python 3.11."
+        )
+
+    if sys.argv[1:] == ["--check"]:
+        out = io.StringIO()
+        generate(out)
+
+        with open(TABLES_PATH, encoding="utf-8") as committed:
+            if committed.read() != out.getvalue():
+                sys.exit(
+                    f"{TABLES_PATH} is out of date, regenerate it with:\n\n"
+                    f"    python3 gen_unicode_tables.py > {TABLES_PATH}"
+                )
+    elif sys.argv[1:] == []:
+        generate(sys.stdout)
+    else:
+        sys.exit("usage: gen_unicode_tables.py [--check]")
+
+
+if __name__ == "__main__":
+    main()
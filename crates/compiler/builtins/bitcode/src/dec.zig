@@ -44,6 +44,10 @@ pub const RocDec = extern struct {
         return ret;
     }
 
+    pub fn toF64(self: RocDec) f64 {
+        return @intToFloat(f64, self.num) / comptime @intToFloat(f64, one_point_zero_i128);
+    }
+
     pub fn fromStr(roc_str: RocStr) ?RocDec {
         if (roc_str.isEmpty()) {
             return null;
@@ -222,6 +226,26 @@ pub const RocDec = extern struct {
         return if (negated) |n| .{ .num = n } else null;
     }
 
+    // Dec has no negative zero, so zero stays zero whatever the sign.
+    pub fn copySign(self: RocDec, sign: RocDec) ?RocDec {
+        if ((self.num < 0) == (sign.num < 0)) {
+            return self;
+        }
+
+        return self.negate();
+    }
+
+    // There is no fixed-point way to compute these, so they go through f64. The
+    // answer has the 15 to 17 significant digits of an f64 rather than the 38 of a Dec.
+    pub fn atan2(y: RocDec, x: RocDec) RocDec {
+        // always between -π and π, so it fits
+        return RocDec.fromF64(math.atan2(f64, y.toF64(), x.toF64())).?;
+    }
+
+    pub fn hypot(self: RocDec, other: RocDec) ?RocDec {
+        return RocDec.fromF64(math.hypot(f64, self.toF64(), other.toF64()));
+    }
+
     pub fn addWithOverflow(self: RocDec, other: RocDec) WithOverflow(RocDec) {
         var answer: i128 = undefined;
         const overflowed = @addWithOverflow(i128, self.num, other.num, &answer);
@@ -410,8 +434,162 @@ pub const RocDec = extern struct {
 
         return RocDec{ .num = if (is_answer_negative) -unsigned_answer else unsigned_answer };
     }
+
+    // Divides the backing i128 by 10^(decimal_places - places), rounding according to `mode`.
+    // The answer is the number scaled so it has exactly `places` decimal places, e.g. 1.235
+    // with 2 places and half-even rounding gives 124. This can't overflow, because the
+    // rounded quotient is always at least one step away from the i128 limits.
+    fn roundedQuotient(self: RocDec, places: u8, mode: RoundingMode) i128 {
+        if (places >= decimal_places) {
+            return self.num;
+        }
+
+        const unit = powerOfTen(decimal_places - places).?;
+        const half = @divExact(unit, 2);
+
+        var quotient = @divTrunc(self.num, unit);
+        const remainder = @rem(self.num, unit);
+        const remainder_abs = if (remainder < 0) -remainder else remainder;
+
+        const away_from_zero = switch (mode) {
+            .half_even => remainder_abs > half or (remainder_abs == half and @rem(quotient, 2) != 0),
+            .half_up => remainder_abs >= half,
+            .toward_zero => false,
+        };
+
+        if (away_from_zero) {
+            quotient += if (self.num < 0) @as(i128, -1) else @as(i128, 1);
+        }
+
+        return quotient;
+    }
+
+    pub fn roundToWithOverflow(self: RocDec, places: u8, mode: RoundingMode) WithOverflow(RocDec) {
+        if (places >= decimal_places) {
+            return .{ .value = self, .has_overflowed = false };
+        }
+
+        const quotient = self.roundedQuotient(places, mode);
+        const unit = powerOfTen(decimal_places - places).?;
+
+        var answer: i128 = undefined;
+        const overflowed = @mulWithOverflow(i128, quotient, unit, &answer);
+
+        return .{ .value = RocDec{ .num = answer }, .has_overflowed = overflowed };
+    }
+
+    // Converts to an integer scaled by 10^places, e.g. 12.345 with 2 places gives 1234.
+    // Digits beyond the requested places are dropped, rounding toward zero.
+    pub fn toScaledI128WithOverflow(self: RocDec, places: u8) WithOverflow(i128) {
+        if (places <= decimal_places) {
+            return .{ .value = self.roundedQuotient(places, .toward_zero), .has_overflowed = false };
+        }
+
+        if (powerOfTen(places - decimal_places)) |unit| {
+            var answer: i128 = undefined;
+            const overflowed = @mulWithOverflow(i128, self.num, unit, &answer);
+
+            return .{ .value = answer, .has_overflowed = overflowed };
+        } else {
+            return .{ .value = 0, .has_overflowed = self.num != 0 };
+        }
+    }
+
+    // The inverse of toScaledI128WithOverflow, e.g. 1234 with 2 places gives 12.34.
+    pub fn fromScaledI128WithOverflow(scaled: i128, places: u8) WithOverflow(RocDec) {
+        if (places <= decimal_places) {
+            const unit = powerOfTen(decimal_places - places).?;
+
+            var answer: i128 = undefined;
+            const overflowed = @mulWithOverflow(i128, scaled, unit, &answer);
+
+            return .{ .value = RocDec{ .num = answer }, .has_overflowed = overflowed };
+        }
+
+        if (powerOfTen(places - decimal_places)) |unit| {
+            return .{ .value = RocDec{ .num = @divTrunc(scaled, unit) }, .has_overflowed = false };
+        } else {
+            return .{ .value = RocDec{ .num = 0 }, .has_overflowed = false };
+        }
+    }
+
+    // Like toStr, but always prints exactly `places` digits after the decimal point
+    // (and no decimal point at all for 0 places), rounding half-even if needed.
+    pub fn toStrFixed(self: RocDec, places: u8) RocStr {
+        const scaled = self.roundedQuotient(places, .half_even);
+        const padding_zeros: usize = if (places > decimal_places) places - decimal_places else 0;
+        const scaled_places: usize = places - padding_zeros;
+
+        // Format the scaled i128 into an array of digit (ascii) characters (u8s)
+        var digit_bytes_storage: [max_digits + 1]u8 = undefined;
+        const num_chars = std.fmt.formatIntBuf(digit_bytes_storage[0..], scaled, 10, .lower, .{});
+        const is_negative = scaled < 0;
+        const digits = digit_bytes_storage[@boolToInt(is_negative)..num_chars];
+
+        // sign + up to 39 digits + point + up to 255 places
+        var str_bytes: [@as(usize, max_str_length) + math.maxInt(u8)]u8 = undefined;
+        var position: usize = 0;
+
+        if (is_negative) {
+            str_bytes[position] = '-';
+            position += 1;
+        }
+
+        // the digits before the decimal point, or a single '0' if there are none
+        var before_digits_end: usize = 0;
+        if (digits.len > scaled_places) {
+            before_digits_end = digits.len - scaled_places;
+
+            for (digits[0..before_digits_end]) |c| {
+                str_bytes[position] = c;
+                position += 1;
+            }
+        } else {
+            str_bytes[position] = '0';
+            position += 1;
+        }
+
+        if (places == 0) {
+            return RocStr.init(&str_bytes, position);
+        }
+
+        str_bytes[position] = '.';
+        position += 1;
+
+        // e.g. 0.05 scaled to 2 places is 5, so it needs one zero after the decimal point
+        var i: usize = digits.len;
+        while (i < scaled_places) : (i += 1) {
+            str_bytes[position] = '0';
+            position += 1;
+        }
+
+        for (digits[before_digits_end..]) |c| {
+            str_bytes[position] = c;
+            position += 1;
+        }
+
+        i = 0;
+        while (i < padding_zeros) : (i += 1) {
+            str_bytes[position] = '0';
+            position += 1;
+        }
+
+        return RocStr.init(&str_bytes, position);
+    }
 };
 
+// Must be kept in sync with the tag ordering in Num.roundDecToChecked
+pub const RoundingMode = enum(u8) {
+    half_even = 0,
+    half_up = 1,
+    toward_zero = 2,
+};
+
+// 10^exponent, or null if that doesn't fit in an i128
+fn powerOfTen(exponent: u8) ?i128 {
+    return math.powi(i128, 10, exponent) catch null;
+}
+
 // A number has `k` trailling zeros if `10^k` divides into it cleanly
 inline fn count_trailing_zeros_base10(input: i128) u6 {
     if (input == 0) {
@@ -1055,6 +1233,107 @@ test "div: 10 / 3" {
     try expectEqual(res, numer.div(denom));
 }
 
+test "roundToWithOverflow: half even" {
+    var dec: RocDec = RocDec.fromStr(RocStr.init("2.345", 5)).?;
+    var res: RocDec = RocDec.fromStr(RocStr.init("2.34", 4)).?;
+    try expectEqual(res, dec.roundToWithOverflow(2, .half_even).value);
+
+    dec = RocDec.fromStr(RocStr.init("-2.355", 6)).?;
+    res = RocDec.fromStr(RocStr.init("-2.36", 5)).?;
+    try expectEqual(res, dec.roundToWithOverflow(2, .half_even).value);
+}
+
+test "roundToWithOverflow: half up" {
+    var dec: RocDec = RocDec.fromStr(RocStr.init("2.345", 5)).?;
+    var res: RocDec = RocDec.fromStr(RocStr.init("2.35", 4)).?;
+    try expectEqual(res, dec.roundToWithOverflow(2, .half_up).value);
+
+    dec = RocDec.fromStr(RocStr.init("-2.5", 4)).?;
+    res = RocDec.fromStr(RocStr.init("-3", 2)).?;
+    try expectEqual(res, dec.roundToWithOverflow(0, .half_up).value);
+}
+
+test "roundToWithOverflow: toward zero" {
+    var dec: RocDec = RocDec.fromStr(RocStr.init("-2.349", 6)).?;
+    var res: RocDec = RocDec.fromStr(RocStr.init("-2.34", 5)).?;
+    try expectEqual(res, dec.roundToWithOverflow(2, .toward_zero).value);
+}
+
+test "roundToWithOverflow: overflow" {
+    var res = RocDec.max.roundToWithOverflow(0, .half_up);
+    try expect(res.has_overflowed);
+}
+
+test "toScaledI128WithOverflow" {
+    var dec: RocDec = RocDec.fromStr(RocStr.init("12.345", 6)).?;
+    try expectEqual(@as(i128, 1234), dec.toScaledI128WithOverflow(2).value);
+    try expect(RocDec.max.toScaledI128WithOverflow(20).has_overflowed);
+}
+
+test "fromScaledI128WithOverflow" {
+    var res: RocDec = RocDec.fromStr(RocStr.init("12.34", 5)).?;
+    try expectEqual(res, RocDec.fromScaledI128WithOverflow(1234, 2).value);
+    try expect(RocDec.fromScaledI128WithOverflow(math.maxInt(i128), 0).has_overflowed);
+}
+
+test "copySign" {
+    var three: RocDec = RocDec.fromStr(RocStr.init("3.5", 3)).?;
+    var minus_three: RocDec = RocDec.fromStr(RocStr.init("-3.5", 4)).?;
+    var minus_one: RocDec = RocDec.fromStr(RocStr.init("-1", 2)).?;
+    var zero: RocDec = .{ .num = 0 };
+
+    try expectEqual(minus_three, three.copySign(minus_one).?);
+    try expectEqual(three, minus_three.copySign(zero).?);
+    try expectEqual(three, three.copySign(zero).?);
+    try expectEqual(zero, zero.copySign(minus_one).?);
+    try expectEqual(@as(?RocDec, null), RocDec.min.copySign(zero));
+}
+
+test "atan2" {
+    var one: RocDec = RocDec.one_point_zero;
+    var minus_one: RocDec = RocDec.fromStr(RocStr.init("-1", 2)).?;
+
+    try expect(math.approxEqAbs(f64, 3 * math.pi / 4.0, RocDec.atan2(one, minus_one).toF64(), 1e-15));
+}
+
+test "hypot" {
+    var three: RocDec = RocDec.fromU64(3);
+    var four: RocDec = RocDec.fromU64(4);
+
+    try expectEqual(RocDec.fromU64(5), three.hypot(four).?);
+    try expectEqual(@as(?RocDec, null), RocDec.max.hypot(RocDec.max));
+}
+
+test "toStrFixed: pads with zeros" {
+    var dec: RocDec = RocDec.fromStr(RocStr.init("1.5", 3)).?;
+    var res_roc_str = dec.toStrFixed(2);
+    errdefer res_roc_str.deinit();
+    defer res_roc_str.deinit();
+
+    const res_slice: []const u8 = "1.50"[0..];
+    try expectEqualSlices(u8, res_slice, res_roc_str.asSlice());
+}
+
+test "toStrFixed: rounds half even" {
+    var dec: RocDec = RocDec.fromStr(RocStr.init("-0.125", 6)).?;
+    var res_roc_str = dec.toStrFixed(2);
+    errdefer res_roc_str.deinit();
+    defer res_roc_str.deinit();
+
+    const res_slice: []const u8 = "-0.12"[0..];
+    try expectEqualSlices(u8, res_slice, res_roc_str.asSlice());
+}
+
+test "toStrFixed: no places" {
+    var dec: RocDec = RocDec.fromStr(RocStr.init("41.5", 4)).?;
+    var res_roc_str = dec.toStrFixed(0);
+    errdefer res_roc_str.deinit();
+    defer res_roc_str.deinit();
+
+    const res_slice: []const u8 = "42"[0..];
+    try expectEqualSlices(u8, res_slice, res_roc_str.asSlice());
+}
+
 // exports
 
 pub fn fromStr(arg: RocStr) callconv(.C) num_.NumParseResult(i128) {
@@ -1085,6 +1364,18 @@ pub fn negateC(arg: RocDec) callconv(.C) i128 {
     return if (@call(.{ .modifier = always_inline }, RocDec.negate, .{arg})) |dec| dec.num else @panic("TODO overflow for negating RocDec");
 }
 
+pub fn copySignC(arg1: RocDec, arg2: RocDec) callconv(.C) i128 {
+    return if (@call(.{ .modifier = always_inline }, RocDec.copySign, .{ arg1, arg2 })) |dec| dec.num else @panic("TODO overflow for copying a sign to RocDec");
+}
+
+pub fn atan2C(arg1: RocDec, arg2: RocDec) callconv(.C) i128 {
+    return @call(.{ .modifier = always_inline }, RocDec.atan2, .{ arg1, arg2 }).num;
+}
+
+pub fn hypotC(arg1: RocDec, arg2: RocDec) callconv(.C) i128 {
+    return if (@call(.{ .modifier = always_inline }, RocDec.hypot, .{ arg1, arg2 })) |dec| dec.num else @panic("TODO overflow for hypot of RocDec");
+}
+
 pub fn addC(arg1: RocDec, arg2: RocDec) callconv(.C) WithOverflow(RocDec) {
     return @call(.{ .modifier = always_inline }, RocDec.addWithOverflow, .{ arg1, arg2 });
 }
@@ -1124,3 +1415,22 @@ pub fn mulOrPanicC(arg1: RocDec, arg2: RocDec) callconv(.C) RocDec {
 pub fn mulSaturatedC(arg1: RocDec, arg2: RocDec) callconv(.C) RocDec {
     return @call(.{ .modifier = always_inline }, RocDec.mulSaturated, .{ arg1, arg2 });
 }
+
+pub fn roundToC(arg: RocDec, places: u8, mode: u8) callconv(.C) WithOverflow(RocDec) {
+    return @call(.{ .modifier = always_inline }, RocDec.roundToWithOverflow, .{ arg, places, @intToEnum(RoundingMode, mode) });
+}
+
+pub fn toStrFixedC(arg: RocDec, places: u8) callconv(.C) RocStr {
+    return @call(.{ .modifier = always_inline }, RocDec.toStrFixed, .{ arg, places });
+}
+
+// The scaled integers are passed around as a RocDec, so that they have the same
+// calling convention as every other i128 that goes in and out of these functions.
+pub fn toScaledI128C(arg: RocDec, places: u8) callconv(.C) WithOverflow(RocDec) {
+    const answer = @call(.{ .modifier = always_inline }, RocDec.toScaledI128WithOverflow, .{ arg, places });
+    return .{ .value = RocDec{ .num = answer.value }, .has_overflowed = answer.has_overflowed };
+}
+
+pub fn fromScaledI128C(arg: RocDec, places: u8) callconv(.C) WithOverflow(RocDec) {
+    return @call(.{ .modifier = always_inline }, RocDec.fromScaledI128WithOverflow, .{ arg.num, places });
+}
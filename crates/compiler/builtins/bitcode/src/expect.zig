@@ -1,4 +1,5 @@
 const std = @import("std");
+const builtin = @import("builtin");
 
 const SIGUSR1: c_int = 10;
 
@@ -8,8 +9,15 @@ const O_CREAT: c_int = 64;
 pub const PROT_WRITE: c_int = 2;
 pub const MAP_SHARED: c_int = 0x0001;
 
+// Frames are written after a header of two usizes: the frame count and the next free offset
+const START_OFFSET: usize = 16;
+
+// When no test runner hands us a buffer (e.g. `dbg` in a program built for development),
+// frames are written here instead
+var DEFAULT_BUFFER: [1 << 16]u8 align(@alignOf(usize)) = [_]u8{0} ** (1 << 16);
+
 // IMPORTANT: shared memory object names must begin with / and contain no other slashes!
-var SHARED_BUFFER: []u8 = undefined;
+var SHARED_BUFFER: []u8 = &DEFAULT_BUFFER;
 
 pub fn setSharedBuffer(ptr: [*]u8, length: usize) callconv(.C) usize {
     SHARED_BUFFER = ptr[0..length];
@@ -18,6 +26,125 @@ pub fn setSharedBuffer(ptr: [*]u8, length: usize) callconv(.C) usize {
     return 0;
 }
 
+// The seed for `Gen.check`, which `roc test --seed` sets
+var GEN_SEED: u64 = 0;
+
+pub fn setGenSeed(seed: u64) callconv(.C) usize {
+    GEN_SEED = seed;
+
+    // the rust side expects that a pointer is returned
+    return 0;
+}
+
+pub fn genSeed() callconv(.C) u64 {
+    return GEN_SEED;
+}
+
 pub fn expectFailedStart() callconv(.C) [*]u8 {
+    const header = @ptrCast([*]usize, @alignCast(@alignOf(usize), SHARED_BUFFER.ptr));
+
+    // a next free offset of zero means the header was never written
+    if (header[1] == 0) {
+        header[0] = 0;
+        header[1] = START_OFFSET;
+    }
+
     return SHARED_BUFFER.ptr;
 }
+
+// The default `roc_dbg`, for hosts that do not provide their own.
+//
+// Rendering a value needs its type, which only the compiler knows. So when the program
+// is run by `roc dev` (which sets ROC_DBG_FD), the frame is sent over that socket and we wait
+// until the compiler has printed it, to keep `dbg` output in order with the program's own.
+// Otherwise, the `dbg` is ignored.
+pub fn rocDbg(region_start: u32, region_end: u32, buffer: [*]const u8, length: usize) callconv(.C) void {
+    _ = region_start;
+    _ = region_end;
+
+    if (builtin.os.tag == .windows) return;
+
+    const fd = dbgSocket() orelse return;
+
+    const length_bytes = std.mem.toBytes(length);
+
+    if (!writeAll(fd, &length_bytes, length_bytes.len)) return;
+    if (!writeAll(fd, buffer, length)) return;
+
+    var ack: [1]u8 = undefined;
+    _ = sysRead(fd, &ack, 1);
+}
+
+// On linux, the surgical linker requires that the app does not depend on libc,
+// so we use syscalls directly there.
+extern fn getenv(name: [*:0]const u8) ?[*:0]const u8;
+extern fn read(fd: c_int, buf: [*]u8, count: usize) isize;
+extern fn write(fd: c_int, buf: [*]const u8, count: usize) isize;
+
+fn dbgSocket() ?c_int {
+    const name = "ROC_DBG_FD";
+
+    if (builtin.os.tag == .linux) {
+        const linux = std.os.linux;
+
+        // `roc dev` puts this variable first in the environment, so we only need to read
+        // the start of /proc/self/environ
+        var buffer: [32]u8 = undefined;
+
+        const environ = linux.open("/proc/self/environ", linux.O.RDONLY, 0);
+        if (linux.getErrno(environ) != .SUCCESS) return null;
+        defer _ = linux.close(@intCast(i32, environ));
+
+        const count = linux.read(@intCast(i32, environ), &buffer, buffer.len);
+        if (linux.getErrno(count) != .SUCCESS) return null;
+
+        const prefix = name ++ "=";
+        const contents = buffer[0..count];
+        if (!std.mem.startsWith(u8, contents, prefix)) return null;
+
+        const value = contents[prefix.len..];
+        const end = std.mem.indexOfScalar(u8, value, 0) orelse return null;
+
+        return std.fmt.parseInt(c_int, value[0..end], 10) catch null;
+    } else {
+        const value = getenv(name) orelse return null;
+
+        return std.fmt.parseInt(c_int, std.mem.span(value), 10) catch null;
+    }
+}
+
+fn sysRead(fd: c_int, bytes: [*]u8, length: usize) isize {
+    if (builtin.os.tag == .linux) {
+        const result = std.os.linux.read(fd, bytes, length);
+        if (std.os.linux.getErrno(result) != .SUCCESS) return -1;
+
+        return @intCast(isize, result);
+    } else {
+        return read(fd, bytes, length);
+    }
+}
+
+fn sysWrite(fd: c_int, bytes: [*]const u8, length: usize) isize {
+    if (builtin.os.tag == .linux) {
+        const result = std.os.linux.write(fd, bytes, length);
+        if (std.os.linux.getErrno(result) != .SUCCESS) return -1;
+
+        return @intCast(isize, result);
+    } else {
+        return write(fd, bytes, length);
+    }
+}
+
+fn writeAll(fd: c_int, bytes: [*]const u8, length: usize) bool {
+    var written: usize = 0;
+
+    while (written < length) {
+        const result = sysWrite(fd, bytes + written, length - written);
+
+        if (result <= 0) return false;
+
+        written += @intCast(usize, result);
+    }
+
+    return true;
+}
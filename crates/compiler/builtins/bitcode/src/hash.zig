@@ -16,10 +16,31 @@ pub fn wyhash(seed: u64, bytes: ?[*]const u8, length: usize) callconv(.C) u64 {
     }
 }
 
-pub fn wyhash_rocstr(seed: u64, input: str.RocStr) callconv(.C) u64 {
+pub fn wyhash_rocstr(input: str.RocStr, seed: u64) callconv(.C) u64 {
     return wyhash_hash(seed, input.asSlice());
 }
 
+pub fn exportHashInt(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(value: T, seed: u64) callconv(.C) u64 {
+            return wyhash_hash(seed, mem.asBytes(&value));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportHashFloat(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(value: T, seed: u64) callconv(.C) u64 {
+            // 0.0 and -0.0 are equal, so they must also hash the same
+            const normalized: T = if (value == 0) 0 else value;
+
+            return wyhash_hash(seed, mem.asBytes(&normalized));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 const primes = [_]u64{
     0xa0761d6478bd642f,
     0xe7037ed1a0b428db,
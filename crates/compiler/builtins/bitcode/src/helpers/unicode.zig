@@ -0,0 +1,341 @@
+const std = @import("std");
+const tables = @import("unicode_tables.zig");
+const expectEqual = std.testing.expectEqual;
+const expectEqualSlices = std.testing.expectEqualSlices;
+
+// Case mapping (https://www.unicode.org/versions/latest/ch03.pdf#G33992) and
+// canonical normalization (https://unicode.org/reports/tr15/) on slices of
+// code points. The lookup tables live in unicode_tables.zig, which is generated
+// by gen_unicode_tables.py.
+
+/// The longest full case mapping of a single code point
+pub const max_case_mapping_length = 3;
+
+/// The longest full canonical decomposition of a single code point
+pub const max_decomposition_length = 4;
+
+pub const Case = enum {
+    upper,
+    lower,
+};
+
+fn findEntry(comptime width: usize, table: []const [width]u21, codepoint: u21) ?*const [width]u21 {
+    var low: usize = 0;
+    var high: usize = table.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const key = table[mid][0];
+
+        if (key == codepoint) {
+            return &table[mid];
+        } else if (key < codepoint) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    return null;
+}
+
+fn findRange(comptime width: usize, table: []const [width]u21, codepoint: u21) ?*const [width]u21 {
+    var low: usize = 0;
+    var high: usize = table.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const range = &table[mid];
+
+        if (range[1] < codepoint) {
+            low = mid + 1;
+        } else if (range[0] > codepoint) {
+            high = mid;
+        } else {
+            return range;
+        }
+    }
+
+    return null;
+}
+
+fn isCased(codepoint: u21) bool {
+    return findRange(2, &tables.cased_ranges, codepoint) != null;
+}
+
+fn isCaseIgnorable(codepoint: u21) bool {
+    return findRange(2, &tables.case_ignorable_ranges, codepoint) != null;
+}
+
+pub fn combiningClass(codepoint: u21) u8 {
+    if (findRange(3, &tables.combining_classes, codepoint)) |range| {
+        return @intCast(u8, range[2]);
+    } else {
+        return 0;
+    }
+}
+
+const capital_sigma: u21 = 0x3A3;
+const final_sigma: u21 = 0x3C2;
+
+// https://www.unicode.org/versions/latest/ch03.pdf#G54277
+// The sigma must follow a cased letter and must not be followed by one,
+// ignoring any case-ignorable code points in between.
+fn isFinalSigma(codepoints: []const u21, index: usize) bool {
+    var before = index;
+    while (before > 0 and isCaseIgnorable(codepoints[before - 1])) {
+        before -= 1;
+    }
+
+    if (before == 0 or !isCased(codepoints[before - 1])) {
+        return false;
+    }
+
+    var after = index + 1;
+    while (after < codepoints.len and isCaseIgnorable(codepoints[after])) {
+        after += 1;
+    }
+
+    return after == codepoints.len or !isCased(codepoints[after]);
+}
+
+/// Writes the full case mapping of `codepoints[index]` into `dest`,
+/// and returns the number of code points written.
+pub fn mapCase(case: Case, codepoints: []const u21, index: usize, dest: *[max_case_mapping_length]u21) usize {
+    const codepoint = codepoints[index];
+
+    if (case == .lower and codepoint == capital_sigma and isFinalSigma(codepoints, index)) {
+        dest[0] = final_sigma;
+        return 1;
+    }
+
+    const table: []const [4]u21 = switch (case) {
+        .upper => &tables.upper_mappings,
+        .lower => &tables.lower_mappings,
+    };
+
+    if (findEntry(4, table, codepoint)) |entry| {
+        var written: usize = 0;
+        while (written < max_case_mapping_length and entry[written + 1] != 0) : (written += 1) {
+            dest[written] = entry[written + 1];
+        }
+
+        return written;
+    } else {
+        dest[0] = codepoint;
+        return 1;
+    }
+}
+
+// https://www.unicode.org/versions/latest/ch03.pdf#G56669
+const hangul_s_base: u21 = 0xAC00;
+const hangul_l_base: u21 = 0x1100;
+const hangul_v_base: u21 = 0x1161;
+const hangul_t_base: u21 = 0x11A7;
+const hangul_l_count: u21 = 19;
+const hangul_v_count: u21 = 21;
+const hangul_t_count: u21 = 28;
+const hangul_n_count: u21 = hangul_v_count * hangul_t_count;
+const hangul_s_count: u21 = hangul_l_count * hangul_n_count;
+
+/// Writes the full canonical decomposition of `codepoint` into `dest`,
+/// and returns the number of code points written.
+pub fn decompose(codepoint: u21, dest: []u21) usize {
+    if (codepoint >= hangul_s_base and codepoint < hangul_s_base + hangul_s_count) {
+        const s_index = codepoint - hangul_s_base;
+        const t_index = s_index % hangul_t_count;
+
+        dest[0] = hangul_l_base + s_index / hangul_n_count;
+        dest[1] = hangul_v_base + (s_index % hangul_n_count) / hangul_t_count;
+
+        if (t_index == 0) {
+            return 2;
+        }
+
+        dest[2] = hangul_t_base + t_index;
+        return 3;
+    }
+
+    if (findEntry(3, &tables.canonical_decompositions, codepoint)) |entry| {
+        var written = decompose(entry[1], dest);
+
+        if (entry[2] != 0) {
+            written += decompose(entry[2], dest[written..]);
+        }
+
+        return written;
+    }
+
+    dest[0] = codepoint;
+    return 1;
+}
+
+/// Sorts every run of non-starters by combining class, keeping the relative
+/// order of code points with equal classes.
+pub fn canonicalOrder(codepoints: []u21) void {
+    var i: usize = 1;
+    while (i < codepoints.len) : (i += 1) {
+        const class = combiningClass(codepoints[i]);
+
+        if (class == 0) {
+            continue;
+        }
+
+        var j = i;
+        while (j > 0) : (j -= 1) {
+            const previous_class = combiningClass(codepoints[j - 1]);
+
+            if (previous_class == 0 or previous_class <= class) {
+                break;
+            }
+
+            std.mem.swap(u21, &codepoints[j - 1], &codepoints[j]);
+        }
+    }
+}
+
+fn composePair(first: u21, second: u21) ?u21 {
+    if (first >= hangul_l_base and first < hangul_l_base + hangul_l_count and
+        second >= hangul_v_base and second < hangul_v_base + hangul_v_count)
+    {
+        const l_index = first - hangul_l_base;
+        const v_index = second - hangul_v_base;
+
+        return hangul_s_base + (l_index * hangul_v_count + v_index) * hangul_t_count;
+    }
+
+    if (first >= hangul_s_base and first < hangul_s_base + hangul_s_count and
+        (first - hangul_s_base) % hangul_t_count == 0 and
+        second > hangul_t_base and second < hangul_t_base + hangul_t_count)
+    {
+        return first + (second - hangul_t_base);
+    }
+
+    const table: []const [3]u21 = &tables.canonical_compositions;
+    var low: usize = 0;
+    var high: usize = table.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const entry = &table[mid];
+
+        if (entry[0] == first and entry[1] == second) {
+            return entry[2];
+        } else if (entry[0] < first or (entry[0] == first and entry[1] < second)) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    return null;
+}
+
+/// Canonically composes fully decomposed, canonically ordered code points in
+/// place, and returns the new length.
+pub fn compose(codepoints: []u21) usize {
+    var opt_starter_index: ?usize = null;
+    var last_class: u8 = 0;
+    var length: usize = 0;
+
+    for (codepoints) |codepoint| {
+        const class = combiningClass(codepoint);
+
+        if (opt_starter_index) |starter_index| {
+            // A code point is blocked from the starter by anything between
+            // them that is a starter or has a class at least as high.
+            const blocked = length - 1 != starter_index and (last_class == 0 or last_class >= class);
+
+            if (!blocked) {
+                if (composePair(codepoints[starter_index], codepoint)) |composite| {
+                    codepoints[starter_index] = composite;
+                    continue;
+                }
+            }
+        }
+
+        if (class == 0) {
+            opt_starter_index = length;
+        }
+
+        last_class = class;
+        codepoints[length] = codepoint;
+        length += 1;
+    }
+
+    return length;
+}
+
+test "mapCase: ascii" {
+    var dest: [max_case_mapping_length]u21 = undefined;
+    const codepoints = [_]u21{ 'a', 'B' };
+
+    try expectEqual(mapCase(.upper, &codepoints, 0, &dest), 1);
+    try expectEqual(dest[0], 'A');
+
+    try expectEqual(mapCase(.lower, &codepoints, 1, &dest), 1);
+    try expectEqual(dest[0], 'b');
+}
+
+test "mapCase: expands" {
+    var dest: [max_case_mapping_length]u21 = undefined;
+    const codepoints = [_]u21{ 0xDF, 0xFB03 }; // ß, ﬃ
+
+    try expectEqual(mapCase(.upper, &codepoints, 0, &dest), 2);
+    try expectEqualSlices(u21, &[_]u21{ 'S', 'S' }, dest[0..2]);
+
+    try expectEqual(mapCase(.upper, &codepoints, 1, &dest), 3);
+    try expectEqualSlices(u21, &[_]u21{ 'F', 'F', 'I' }, dest[0..3]);
+}
+
+test "mapCase: final sigma" {
+    var dest: [max_case_mapping_length]u21 = undefined;
+    // ΣΑΣ.
+    const codepoints = [_]u21{ capital_sigma, 0x391, capital_sigma, '.' };
+
+    _ = mapCase(.lower, &codepoints, 0, &dest);
+    try expectEqual(dest[0], 0x3C3);
+
+    _ = mapCase(.lower, &codepoints, 2, &dest);
+    try expectEqual(dest[0], final_sigma);
+}
+
+test "decompose: latin" {
+    var dest: [max_decomposition_length]u21 = undefined;
+
+    // Ǖ decomposes recursively
+    try expectEqual(decompose(0x1D5, &dest), 3);
+    try expectEqualSlices(u21, &[_]u21{ 'U', 0x308, 0x304 }, dest[0..3]);
+}
+
+test "decompose: hangul" {
+    var dest: [max_decomposition_length]u21 = undefined;
+
+    // 각
+    try expectEqual(decompose(0xAC01, &dest), 3);
+    try expectEqualSlices(u21, &[_]u21{ 0x1100, 0x1161, 0x11A8 }, dest[0..3]);
+}
+
+test "canonicalOrder" {
+    // a, dot below (220), grave (230), dot below (220)
+    var codepoints = [_]u21{ 'a', 0x300, 0x323, 0x301, 0x323 };
+    canonicalOrder(&codepoints);
+
+    try expectEqualSlices(u21, &[_]u21{ 'a', 0x323, 0x323, 0x300, 0x301 }, &codepoints);
+}
+
+test "compose" {
+    // e, acute, then a hangul L V T sequence
+    var codepoints = [_]u21{ 'e', 0x301, 0x1100, 0x1161, 0x11A8 };
+    const length = compose(&codepoints);
+
+    try expectEqualSlices(u21, &[_]u21{ 0xE9, 0xAC01 }, codepoints[0..length]);
+}
+
+test "compose: blocked" {
+    // a, ring below (220), ring below (220): the second mark is blocked
+    var codepoints = [_]u21{ 'a', 0x325, 0x325 };
+    const length = compose(&codepoints);
+
+    try expectEqualSlices(u21, &[_]u21{ 0x1E01, 0x325 }, codepoints[0..length]);
+}
@@ -14,6 +14,12 @@ const IncN = fn (?[*]u8, usize) callconv(.C) void;
 const Dec = fn (?[*]u8) callconv(.C) void;
 const HasTagId = fn (u16, ?[*]u8) callconv(.C) extern struct { matched: bool, data: ?[*]u8 };
 
+// A seamless slice is a list that points into the allocation of another list.
+// It sets the top bit of its capacity, and stores the data pointer of that
+// allocation shifted right by one in the remaining bits. The length is left
+// alone, because the backends read it directly.
+const SEAMLESS_SLICE_BIT: usize = @bitCast(usize, @as(isize, std.math.minInt(isize)));
+
 pub const RocList = extern struct {
     bytes: ?[*]u8,
     length: usize,
@@ -31,6 +37,38 @@ pub const RocList = extern struct {
         return RocList{ .bytes = null, .length = 0, .capacity = 0 };
     }
 
+    pub fn isSeamlessSlice(self: RocList) bool {
+        return @bitCast(isize, self.capacity) < 0;
+    }
+
+    pub fn getCapacity(self: RocList) usize {
+        if (self.isSeamlessSlice()) {
+            return self.length;
+        }
+        return self.capacity;
+    }
+
+    // The data pointer of the allocation this list's elements live in,
+    // which is where the refcount is found.
+    fn getAllocationPtr(self: RocList) ?[*]u8 {
+        if (self.isSeamlessSlice()) {
+            return @intToPtr(?[*]u8, self.capacity << 1);
+        }
+        return self.bytes;
+    }
+
+    // The capacity of a seamless slice into the allocation of this list.
+    fn seamlessSliceCapacity(self: RocList) usize {
+        if (self.isSeamlessSlice()) {
+            return self.capacity;
+        }
+        return (@ptrToInt(self.bytes) >> 1) | SEAMLESS_SLICE_BIT;
+    }
+
+    pub fn decref(self: RocList, alignment: u32) void {
+        utils.decref(self.getAllocationPtr(), self.len(), alignment);
+    }
+
     pub fn eql(self: RocList, other: RocList) bool {
         if (self.len() != other.len()) {
             return false;
@@ -75,7 +113,7 @@ pub const RocList = extern struct {
     }
 
     pub fn deinit(self: RocList, comptime T: type) void {
-        utils.decref(self.bytes, self.len(), @alignOf(T));
+        self.decref(@alignOf(T));
     }
 
     pub fn elements(self: RocList, comptime T: type) ?[*]T {
@@ -89,7 +127,7 @@ pub const RocList = extern struct {
         }
 
         // otherwise, check if the refcount is one
-        const ptr: [*]usize = @ptrCast([*]usize, @alignCast(@alignOf(usize), self.bytes));
+        const ptr: [*]usize = @ptrCast([*]usize, @alignCast(@alignOf(usize), self.getAllocationPtr()));
         return (ptr - 1)[0] == utils.REFCOUNT_ONE;
     }
 
@@ -134,8 +172,7 @@ pub const RocList = extern struct {
         @memcpy(new_bytes, old_bytes, number_of_bytes);
 
         // NOTE we fuse an increment of all keys/values with a decrement of the input dict
-        const data_bytes = self.len() * element_width;
-        utils.decref(self.bytes, data_bytes, alignment);
+        self.decref(alignment);
 
         return new_list;
     }
@@ -148,9 +185,9 @@ pub const RocList = extern struct {
     ) RocList {
         if (self.bytes) |source_ptr| {
             if (self.isUnique()) {
-                if (self.capacity >= new_length) {
+                if (self.getCapacity() >= new_length) {
                     return RocList{ .bytes = self.bytes, .length = new_length, .capacity = self.capacity };
-                } else {
+                } else if (!self.isSeamlessSlice()) {
                     const new_source = utils.unsafeReallocate(source_ptr, alignment, self.len(), new_length, element_width);
                     return RocList{ .bytes = new_source, .length = new_length, .capacity = new_length };
                 }
@@ -188,7 +225,7 @@ pub const RocList = extern struct {
             .capacity = new_length,
         };
 
-        utils.decref(self.bytes, old_length * element_width, alignment);
+        self.decref(alignment);
 
         return result;
     }
@@ -425,7 +462,7 @@ pub fn listReserve(
     update_mode: UpdateMode,
 ) callconv(.C) RocList {
     const old_length = list.len();
-    if ((update_mode == .InPlace or list.isUnique()) and list.capacity >= list.len() + spare) {
+    if ((update_mode == .InPlace or list.isUnique()) and list.getCapacity() >= list.len() + spare) {
         return list;
     } else {
         var output = list.reallocate(alignment, old_length + spare, element_width);
@@ -517,6 +554,8 @@ pub fn listSublist(
     len: usize,
     dec: Dec,
 ) callconv(.C) RocList {
+    _ = alignment;
+
     if (len == 0) {
         return RocList.empty();
     }
@@ -532,34 +571,38 @@ pub fn listSublist(
         const drop_start_len = start;
         const drop_end_len = size - (start + keep_len);
 
-        // Decrement the reference counts of elements before `start`.
-        var i: usize = 0;
-        while (i < drop_start_len) : (i += 1) {
-            const element = source_ptr + i * element_width;
-            dec(element);
-        }
-
-        // Decrement the reference counts of elements after `start + keep_len`.
-        i = 0;
-        while (i < drop_end_len) : (i += 1) {
-            const element = source_ptr + (start + keep_len + i) * element_width;
-            dec(element);
-        }
-
-        if (start == 0 and list.isUnique()) {
-            var output = list;
-            output.length = keep_len;
-            return output;
-        } else {
-            const output = RocList.allocate(alignment, keep_len, element_width);
-            const target_ptr = output.bytes orelse unreachable;
-
-            @memcpy(target_ptr, source_ptr + start * element_width, keep_len * element_width);
+        // A shared list's other owners still use the elements outside of the slice,
+        // so only a unique list gives up its references to them.
+        if (list.isUnique()) {
+            // Decrement the reference counts of elements before `start`.
+            var i: usize = 0;
+            while (i < drop_start_len) : (i += 1) {
+                const element = source_ptr + i * element_width;
+                dec(element);
+            }
 
-            utils.decref(list.bytes, size * element_width, alignment);
+            // Decrement the reference counts of elements after `start + keep_len`.
+            i = 0;
+            while (i < drop_end_len) : (i += 1) {
+                const element = source_ptr + (start + keep_len + i) * element_width;
+                dec(element);
+            }
 
-            return output;
+            if (start == 0) {
+                var output = list;
+                output.length = keep_len;
+                return output;
+            }
         }
+
+        // The slice keeps the reference to the allocation that was passed in. When the
+        // list is still used elsewhere, the caller incremented its refcount for this call,
+        // so the allocation stays alive for as long as the slice does.
+        return RocList{
+            .bytes = source_ptr + start * element_width,
+            .length = keep_len,
+            .capacity = list.seamlessSliceCapacity(),
+        };
     }
 
     return RocList.empty();
@@ -579,8 +622,11 @@ pub fn listDropAt(
             return list;
         }
 
-        if (drop_index < size) {
-            const element = source_ptr + drop_index * element_width;
+        // A shared list's other owners still use the dropped element, so only a unique
+        // list gives up its reference to it.
+        const is_unique = list.isUnique();
+        const element = source_ptr + drop_index * element_width;
+        if (is_unique) {
             dec(element);
         }
 
@@ -589,11 +635,19 @@ pub fn listDropAt(
         // because we rely on the pointer field being null if the list is empty
         // which also requires duplicating the utils.decref call to spend the RC token
         if (size < 2) {
-            utils.decref(list.bytes, size * element_width, alignment);
+            list.decref(alignment);
             return RocList.empty();
         }
 
-        if (list.isUnique()) {
+        if (drop_index == 0) {
+            return RocList{
+                .bytes = source_ptr + element_width,
+                .length = size - 1,
+                .capacity = list.seamlessSliceCapacity(),
+            };
+        }
+
+        if (is_unique) {
             var i = drop_index;
             while (i < size - 1) : (i += 1) {
                 const copy_target = source_ptr + i * element_width;
@@ -608,6 +662,17 @@ pub fn listDropAt(
             return new_list;
         }
 
+        // Dropping the last element of a shared list leaves the others in place, so like
+        // dropping the first one it becomes a slice. The slice keeps the reference to the
+        // allocation that was passed in, as in listSublist.
+        if (drop_index == size - 1) {
+            return RocList{
+                .bytes = source_ptr,
+                .length = size - 1,
+                .capacity = list.seamlessSliceCapacity(),
+            };
+        }
+
         const output = RocList.allocate(alignment, size - 1, element_width);
         const target_ptr = output.bytes orelse unreachable;
 
@@ -619,7 +684,7 @@ pub fn listDropAt(
         const tail_size = (size - drop_index - 1) * element_width;
         @memcpy(tail_target, tail_source, tail_size);
 
-        utils.decref(list.bytes, size * element_width, alignment);
+        list.decref(alignment);
 
         return output;
     } else {
@@ -734,24 +799,15 @@ pub fn listConcat(list_a: RocList, list_b: RocList, alignment: u32, element_widt
     } else if (list_a.isUnique()) {
         const total_length: usize = list_a.len() + list_b.len();
 
-        if (list_a.bytes) |source| {
-            const new_source = if (list_a.capacity >= total_length)
-                source
-            else
-                utils.unsafeReallocate(
-                    source,
-                    alignment,
-                    list_a.len(),
-                    total_length,
-                    element_width,
-                );
+        const resized_list_a = list_a.reallocate(alignment, total_length, element_width);
 
+        if (resized_list_a.bytes) |target| {
             if (list_b.bytes) |source_b| {
-                @memcpy(new_source + list_a.len() * element_width, source_b, list_b.len() * element_width);
+                @memcpy(target + list_a.len() * element_width, source_b, list_b.len() * element_width);
             }
-
-            return RocList{ .bytes = new_source, .length = total_length, .capacity = total_length };
         }
+
+        return resized_list_a;
     }
     const total_length: usize = list_a.len() + list_b.len();
 
@@ -826,3 +882,9 @@ pub fn listIsUnique(
 ) callconv(.C) bool {
     return list.isEmpty() or list.isUnique();
 }
+
+pub fn listCapacity(
+    list: RocList,
+) callconv(.C) usize {
+    return list.getCapacity();
+}
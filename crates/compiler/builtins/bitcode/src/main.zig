@@ -7,6 +7,7 @@ const expect = @import("expect.zig");
 const ROC_BUILTINS = "roc_builtins";
 const NUM = "num";
 const STR = "str";
+const DICT = "dict";
 
 // Dec Module
 const dec = @import("dec.zig");
@@ -19,6 +20,9 @@ comptime {
     exportDecFn(dec.neqC, "neq");
     exportDecFn(dec.negateC, "negate");
     exportDecFn(dec.divC, "div");
+    exportDecFn(dec.copySignC, "copy_sign");
+    exportDecFn(dec.atan2C, "atan2");
+    exportDecFn(dec.hypotC, "hypot");
 
     exportDecFn(dec.addC, "add_with_overflow");
     exportDecFn(dec.addOrPanicC, "add_or_panic");
@@ -31,6 +35,11 @@ comptime {
     exportDecFn(dec.mulC, "mul_with_overflow");
     exportDecFn(dec.mulOrPanicC, "mul_or_panic");
     exportDecFn(dec.mulSaturatedC, "mul_saturated");
+
+    exportDecFn(dec.roundToC, "round_to");
+    exportDecFn(dec.toStrFixedC, "to_str_fixed");
+    exportDecFn(dec.toScaledI128C, "to_scaled_i128");
+    exportDecFn(dec.fromScaledI128C, "from_scaled_i128");
 }
 
 // List Module
@@ -53,6 +62,22 @@ comptime {
     exportListFn(list.listReplaceInPlace, "replace_in_place");
     exportListFn(list.listSwap, "swap");
     exportListFn(list.listIsUnique, "is_unique");
+    exportListFn(list.listCapacity, "capacity");
+}
+
+// Dict Module
+const hash = @import("hash.zig");
+
+comptime {
+    exportDictFn(hash.wyhash_rocstr, "hash_str");
+
+    inline for (INTEGERS) |T| {
+        hash.exportHashInt(T, ROC_BUILTINS ++ "." ++ DICT ++ ".hash_int.");
+    }
+
+    inline for (FLOATS) |T| {
+        hash.exportHashFloat(T, ROC_BUILTINS ++ "." ++ DICT ++ ".hash_float.");
+    }
 }
 
 // Num Module
@@ -73,6 +98,8 @@ comptime {
 
         num.exportRoundF32(T, ROC_BUILTINS ++ "." ++ NUM ++ ".round_f32.");
         num.exportRoundF64(T, ROC_BUILTINS ++ "." ++ NUM ++ ".round_f64.");
+        num.exportTruncF32(T, ROC_BUILTINS ++ "." ++ NUM ++ ".trunc_f32.");
+        num.exportTruncF64(T, ROC_BUILTINS ++ "." ++ NUM ++ ".trunc_f64.");
 
         num.exportAddWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_with_overflow.");
         num.exportAddOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_or_panic.");
@@ -85,6 +112,13 @@ comptime {
         num.exportMulWithOverflow(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_with_overflow.");
         num.exportMulOrPanic(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_or_panic.");
         num.exportMulSaturatedInt(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_saturated.");
+
+        num.exportCountLeadingZeroBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_leading_zero_bits.");
+        num.exportCountTrailingZeroBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_trailing_zero_bits.");
+        num.exportCountOneBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_one_bits.");
+        num.exportRotateLeftBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".rotate_left_by.");
+        num.exportRotateRightBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".rotate_right_by.");
+        num.exportSwapBytes(T, ROC_BUILTINS ++ "." ++ NUM ++ ".swap_bytes.");
     }
 
     inline for (INTEGERS) |FROM| {
@@ -99,18 +133,32 @@ comptime {
         num.exportAsin(T, ROC_BUILTINS ++ "." ++ NUM ++ ".asin.");
         num.exportAcos(T, ROC_BUILTINS ++ "." ++ NUM ++ ".acos.");
         num.exportAtan(T, ROC_BUILTINS ++ "." ++ NUM ++ ".atan.");
+        num.exportAtan2(T, ROC_BUILTINS ++ "." ++ NUM ++ ".atan2.");
 
         num.exportSin(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sin.");
         num.exportCos(T, ROC_BUILTINS ++ "." ++ NUM ++ ".cos.");
+        num.exportSinh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sinh.");
+        num.exportCosh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".cosh.");
+        num.exportTanh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".tanh.");
 
         num.exportPow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".pow.");
         num.exportLog(T, ROC_BUILTINS ++ "." ++ NUM ++ ".log.");
+        num.exportLog2(T, ROC_BUILTINS ++ "." ++ NUM ++ ".log2.");
+        num.exportLog10(T, ROC_BUILTINS ++ "." ++ NUM ++ ".log10.");
+        num.exportExp(T, ROC_BUILTINS ++ "." ++ NUM ++ ".exp.");
+        num.exportExp2(T, ROC_BUILTINS ++ "." ++ NUM ++ ".exp2.");
+
+        num.exportHypot(T, ROC_BUILTINS ++ "." ++ NUM ++ ".hypot.");
+        num.exportFma(T, ROC_BUILTINS ++ "." ++ NUM ++ ".fma.");
+        num.exportCopySign(T, ROC_BUILTINS ++ "." ++ NUM ++ ".copysign.");
 
         num.exportAddWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_with_overflow.");
         num.exportSubWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_with_overflow.");
         num.exportMulWithOverflow(T, T, ROC_BUILTINS ++ "." ++ NUM ++ ".mul_with_overflow.");
 
         num.exportIsFinite(T, ROC_BUILTINS ++ "." ++ NUM ++ ".is_finite.");
+        num.exportIsNan(T, ROC_BUILTINS ++ "." ++ NUM ++ ".is_nan.");
+        num.exportIsInfinite(T, ROC_BUILTINS ++ "." ++ NUM ++ ".is_infinite.");
     }
 }
 
@@ -137,6 +185,7 @@ comptime {
     exportStrFn(str.getScalarUnsafe, "get_scalar_unsafe");
     exportStrFn(str.appendScalar, "append_scalar");
     exportStrFn(str.strToUtf8C, "to_utf8");
+    exportStrFn(str.strCrashC, "crash");
     exportStrFn(str.fromUtf8C, "from_utf8");
     exportStrFn(str.fromUtf8RangeC, "from_utf8_range");
     exportStrFn(str.repeat, "repeat");
@@ -144,6 +193,12 @@ comptime {
     exportStrFn(str.strTrimLeft, "trim_left");
     exportStrFn(str.strTrimRight, "trim_right");
     exportStrFn(str.strCloneTo, "clone_to");
+    exportStrFn(str.strGraphemes, "graphemes");
+    exportStrFn(str.graphemeByteOffset, "grapheme_byte_offset");
+    exportStrFn(str.strToUpper, "to_upper");
+    exportStrFn(str.strToLower, "to_lower");
+    exportStrFn(str.strToNfc, "to_nfc");
+    exportStrFn(str.strToNfd, "to_nfd");
 
     inline for (INTEGERS) |T| {
         str.exportFromInt(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_int.");
@@ -163,6 +218,7 @@ comptime {
     exportUtilsFn(utils.decrefC, "decref");
     exportUtilsFn(utils.decrefCheckNullC, "decref_check_null");
     exportUtilsFn(utils.allocateWithRefcountC, "allocate_with_refcount");
+    exportUtilsFn(expect.genSeed, "gen_seed");
 
     @export(utils.panic, .{ .name = "roc_builtins.utils." ++ "panic", .linkage = .Weak });
 
@@ -171,6 +227,12 @@ comptime {
 
         // sets the buffer used for expect failures
         @export(expect.setSharedBuffer, .{ .name = "set_shared_buffer", .linkage = .Weak });
+
+        // sets the seed for `Gen.check`
+        @export(expect.setGenSeed, .{ .name = "set_gen_seed", .linkage = .Weak });
+
+        // reports a `dbg` to the host; hosts can provide their own
+        @export(expect.rocDbg, .{ .name = "roc_dbg", .linkage = .Weak });
     }
 
     if (builtin.target.cpu.arch == .aarch64) {
@@ -21,6 +21,12 @@ const MASK: usize = @bitCast(usize, MASK_ISIZE);
 const SMALL_STR_MAX_LENGTH = SMALL_STRING_SIZE - 1;
 const SMALL_STRING_SIZE = @sizeOf(RocStr);
 
+// A seamless slice is a big string that points into the allocation of another
+// big string. It sets the top bit of its length, and stores the data pointer of
+// that allocation shifted right by one in its capacity, which keeps the
+// capacity positive so the slice is never mistaken for a small string.
+const SEAMLESS_SLICE_BIT: usize = MASK;
+
 fn init_blank_small_string(comptime n: usize) [n]u8 {
     var prime_list: [n]u8 = undefined;
 
@@ -85,9 +91,21 @@ pub const RocStr = extern struct {
         }
     }
 
+    // Takes over the caller's reference to the allocation of `parent`, and
+    // returns a string that shares it.
+    fn initSeamlessSlice(parent: RocStr, start: usize, length: usize) RocStr {
+        const allocation_ptr = @ptrToInt(parent.getAllocationPtr());
+
+        return RocStr{
+            .str_bytes = parent.asU8ptr() + start,
+            .str_len = length | SEAMLESS_SLICE_BIT,
+            .str_capacity = allocation_ptr >> 1,
+        };
+    }
+
     pub fn deinit(self: RocStr) void {
         if (!self.isSmallStr()) {
-            utils.decref(self.str_bytes, self.str_len, RocStr.alignment);
+            utils.decref(self.getAllocationPtr(), self.len(), RocStr.alignment);
         }
     }
 
@@ -128,14 +146,17 @@ pub const RocStr = extern struct {
         const self_bytes = self.asU8ptr();
         const other_bytes = other.asU8ptr();
 
-        // It's faster to compare pointer-sized words rather than bytes, as far as possible
-        // The bytes are always pointer-size aligned due to the refcount
-        const self_words = @ptrCast([*]const usize, @alignCast(@alignOf(usize), self_bytes));
-        const other_words = @ptrCast([*]const usize, @alignCast(@alignOf(usize), other_bytes));
+        // It's faster to compare pointer-sized words rather than bytes, as far as possible.
+        // The bytes are pointer-size aligned due to the refcount, unless one of the
+        // strings is a seamless slice that starts in the middle of its allocation.
         var w: usize = 0;
-        while (w < self_len / @sizeOf(usize)) : (w += 1) {
-            if (self_words[w] != other_words[w]) {
-                return false;
+        if ((@ptrToInt(self_bytes) | @ptrToInt(other_bytes)) % @alignOf(usize) == 0) {
+            const self_words = @ptrCast([*]const usize, @alignCast(@alignOf(usize), self_bytes));
+            const other_words = @ptrCast([*]const usize, @alignCast(@alignOf(usize), other_bytes));
+            while (w < self_len / @sizeOf(usize)) : (w += 1) {
+                if (self_words[w] != other_words[w]) {
+                    return false;
+                }
             }
         }
 
@@ -155,12 +176,13 @@ pub const RocStr = extern struct {
             // just return the bytes
             return str;
         } else {
-            var new_str = RocStr.allocateBig(str.str_len, str.str_len);
+            const length = str.len();
+            var new_str = RocStr.allocateBig(length, length);
 
             var old_bytes: [*]u8 = @ptrCast([*]u8, str.str_bytes);
             var new_bytes: [*]u8 = @ptrCast([*]u8, new_str.str_bytes);
 
-            @memcpy(new_bytes, old_bytes, str.str_len);
+            @memcpy(new_bytes, old_bytes, length);
 
             return new_str;
         }
@@ -175,7 +197,7 @@ pub const RocStr = extern struct {
         const old_capacity = self.getCapacity();
 
         if (self.str_bytes) |source_ptr| {
-            if (self.isUnique() and !self.isSmallStr()) {
+            if (self.isUnique() and !self.isSmallStr() and !self.isSeamlessSlice()) {
                 const new_source = utils.unsafeReallocate(
                     source_ptr,
                     RocStr.alignment,
@@ -223,6 +245,24 @@ pub const RocStr = extern struct {
         try expect(isSmallStr(RocStr.empty()));
     }
 
+    pub fn isSeamlessSlice(self: RocStr) bool {
+        return !self.isSmallStr() and @bitCast(isize, self.str_len) < 0;
+    }
+
+    // The data pointer of the allocation this big string's refcount belongs to
+    fn getAllocationPtr(self: RocStr) ?[*]u8 {
+        if (self.isSeamlessSlice()) {
+            return @intToPtr(?[*]u8, self.str_capacity << 1);
+        } else {
+            return self.str_bytes;
+        }
+    }
+
+    // Sets the length of a big string, keeping it a seamless slice if it was one
+    fn setLen(self: *RocStr, length: usize) void {
+        self.str_len = length | (self.str_len & SEAMLESS_SLICE_BIT);
+    }
+
     fn asArray(self: RocStr) [@sizeOf(RocStr)]u8 {
         const as_ptr = @ptrCast([*]const u8, &self);
         const slice = as_ptr[0..@sizeOf(RocStr)];
@@ -234,13 +274,16 @@ pub const RocStr = extern struct {
         if (self.isSmallStr()) {
             return self.asArray()[@sizeOf(RocStr) - 1] ^ 0b1000_0000;
         } else {
-            return self.str_len;
+            return self.str_len & ~SEAMLESS_SLICE_BIT;
         }
     }
 
     pub fn getCapacity(self: RocStr) usize {
         if (self.isSmallStr()) {
             return SMALL_STR_MAX_LENGTH;
+        } else if (self.isSeamlessSlice()) {
+            // the bytes after the end of a slice belong to someone else
+            return self.len();
         } else {
             return self.str_capacity;
         }
@@ -287,6 +330,10 @@ pub const RocStr = extern struct {
             // then the next byte is off the end of the struct;
             // in that case, we are also not null-terminated!
             return length != 0 and length != longest_small_str;
+        } else if (self.isSeamlessSlice()) {
+            // The bytes after the end of a slice may belong to the string it
+            // was sliced from, so we can't assume anything about them.
+            return false;
         } else {
             // This is a big string, and it's not empty, so we can safely
             // dereference the pointer.
@@ -318,7 +365,7 @@ pub const RocStr = extern struct {
     }
 
     fn isRefcountOne(self: RocStr) bool {
-        const ptr: [*]usize = @ptrCast([*]usize, @alignCast(@alignOf(usize), self.str_bytes));
+        const ptr: [*]usize = @ptrCast([*]usize, @alignCast(@alignOf(usize), self.getAllocationPtr()));
         return (ptr - 1)[0] == utils.REFCOUNT_ONE;
     }
 
@@ -1216,6 +1263,7 @@ test "countSegments: string equals delimiter" {
 
 // Str.countGraphemeClusters
 const grapheme = @import("helpers/grapheme.zig");
+const unicode_helpers = @import("helpers/unicode.zig");
 pub fn countGraphemeClusters(string: RocStr) callconv(.C) usize {
     if (string.isEmpty()) {
         return 0;
@@ -1306,6 +1354,302 @@ test "countGraphemeClusters: emojis, ut8, and ascii characters" {
     try expectEqual(count, 10);
 }
 
+// Returns the byte index at which the grapheme starting at `start` ends.
+fn nextGraphemeBoundary(bytes: []const u8, start: usize) usize {
+    var iter = unicode.Utf8Iterator{ .bytes = bytes, .i = start };
+
+    var grapheme_break_state: ?grapheme.BoundClass = null;
+    var opt_last_codepoint: ?u21 = null;
+    var boundary = start;
+    while (iter.nextCodepointSlice()) |codepoint_bytes| {
+        const cur_codepoint = unicode.utf8Decode(codepoint_bytes) catch unreachable;
+
+        if (opt_last_codepoint) |last_codepoint| {
+            if (grapheme.isGraphemeBreak(last_codepoint, cur_codepoint, &grapheme_break_state)) {
+                return boundary;
+            }
+        }
+
+        opt_last_codepoint = cur_codepoint;
+        boundary = iter.i;
+    }
+
+    return bytes.len;
+}
+
+// Str.graphemes
+pub fn strGraphemes(string: RocStr) callconv(.C) RocList {
+    if (string.isEmpty()) {
+        return RocList.empty();
+    }
+
+    const count = countGraphemeClusters(string);
+    const list = RocList.allocate(@alignOf(RocStr), count, @sizeOf(RocStr));
+
+    if (list.bytes) |bytes| {
+        const strings = @ptrCast([*]RocStr, @alignCast(@alignOf(RocStr), bytes));
+        const str_bytes = string.asSlice();
+
+        var start: usize = 0;
+        var index: usize = 0;
+        while (index < count) : (index += 1) {
+            const end = nextGraphemeBoundary(str_bytes, start);
+
+            strings[index] = RocStr.fromSlice(str_bytes[start..end]);
+            start = end;
+        }
+    }
+
+    return list;
+}
+
+// Str.splitAt
+pub fn graphemeByteOffset(string: RocStr, grapheme_index: usize) callconv(.C) usize {
+    const bytes = string.asSlice();
+
+    var offset: usize = 0;
+    var index: usize = 0;
+    while (index < grapheme_index and offset < bytes.len) : (index += 1) {
+        offset = nextGraphemeBoundary(bytes, offset);
+    }
+
+    return offset;
+}
+
+fn expectGraphemes(actual: RocList, expected: []const []const u8) !void {
+    try expectEqual(actual.len(), expected.len);
+
+    if (actual.elements(RocStr)) |strings| {
+        for (expected) |expected_bytes, index| {
+            const expected_str = RocStr.fromSlice(expected_bytes);
+            defer expected_str.deinit();
+
+            try expect(strings[index].eq(expected_str));
+            strings[index].deinit();
+        }
+    }
+
+    actual.deinit(RocStr);
+}
+
+test "strGraphemes: empty string" {
+    const list = strGraphemes(RocStr.empty());
+    try expect(list.isEmpty());
+}
+
+test "strGraphemes: ascii characters" {
+    const str = RocStr.fromSlice("abc");
+    defer str.deinit();
+
+    try expectGraphemes(strGraphemes(str), &[_][]const u8{ "a", "b", "c" });
+}
+
+test "strGraphemes: combining marks and emojis" {
+    const str = RocStr.fromSlice("e\u{301}👩‍👩‍👦‍👦\r\n🇺🇸");
+    defer str.deinit();
+
+    try expectGraphemes(strGraphemes(str), &[_][]const u8{ "e\u{301}", "👩‍👩‍👦‍👦", "\r\n", "🇺🇸" });
+}
+
+test "graphemeByteOffset" {
+    const str = RocStr.fromSlice("e\u{301}x🤔");
+    defer str.deinit();
+
+    try expectEqual(graphemeByteOffset(str, 0), 0);
+    try expectEqual(graphemeByteOffset(str, 1), 3);
+    try expectEqual(graphemeByteOffset(str, 2), 4);
+    try expectEqual(graphemeByteOffset(str, 3), 8);
+    try expectEqual(graphemeByteOffset(str, 100), 8);
+}
+
+fn isAscii(bytes: []const u8) bool {
+    for (bytes) |byte| {
+        if (byte > 127) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+fn allocateCodepoints(length: usize) []u21 {
+    const bytes = utils.alloc(length * @sizeOf(u21), @alignOf(u21)) orelse unreachable;
+
+    return @ptrCast([*]u21, @alignCast(@alignOf(u21), bytes))[0..length];
+}
+
+fn deallocateCodepoints(codepoints: []u21) void {
+    utils.dealloc(@ptrCast([*]u8, codepoints.ptr), @alignOf(u21));
+}
+
+fn strFromCodepoints(codepoints: []const u21) RocStr {
+    var length: usize = 0;
+    for (codepoints) |codepoint| {
+        length += unicode.utf8CodepointSequenceLength(codepoint) catch unreachable;
+    }
+
+    var result = RocStr.allocate(length, length);
+    var result_ptr = result.asU8ptr();
+
+    var offset: usize = 0;
+    for (codepoints) |codepoint| {
+        offset += unicode.utf8Encode(codepoint, result_ptr[offset..length]) catch unreachable;
+    }
+
+    return result;
+}
+
+// Str.toUpper
+pub fn strToUpper(string: RocStr) callconv(.C) RocStr {
+    return mapCase(string, .upper);
+}
+
+// Str.toLower
+pub fn strToLower(string: RocStr) callconv(.C) RocStr {
+    return mapCase(string, .lower);
+}
+
+fn mapCase(string: RocStr, comptime case: unicode_helpers.Case) RocStr {
+    const bytes = string.asSlice();
+
+    if (isAscii(bytes)) {
+        var result = RocStr.allocate(bytes.len, bytes.len);
+        var result_ptr = result.asU8ptr();
+
+        for (bytes) |byte, index| {
+            result_ptr[index] = switch (case) {
+                .upper => std.ascii.toUpper(byte),
+                .lower => std.ascii.toLower(byte),
+            };
+        }
+
+        return result;
+    }
+
+    // Final_Sigma needs to look at the surrounding code points, so decode
+    // the whole string before mapping it.
+    const input = allocateCodepoints(unicode.utf8CountCodepoints(bytes) catch unreachable);
+    defer deallocateCodepoints(input);
+
+    var iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    var input_index: usize = 0;
+    while (iter.nextCodepoint()) |codepoint| : (input_index += 1) {
+        input[input_index] = codepoint;
+    }
+
+    var mapped: [unicode_helpers.max_case_mapping_length]u21 = undefined;
+
+    var output_length: usize = 0;
+    for (input) |_, index| {
+        output_length += unicode_helpers.mapCase(case, input, index, &mapped);
+    }
+
+    const output = allocateCodepoints(output_length);
+    defer deallocateCodepoints(output);
+
+    var output_index: usize = 0;
+    for (input) |_, index| {
+        const written = unicode_helpers.mapCase(case, input, index, &mapped);
+
+        mem.copy(u21, output[output_index .. output_index + written], mapped[0..written]);
+        output_index += written;
+    }
+
+    return strFromCodepoints(output);
+}
+
+// Str.toNfd
+pub fn strToNfd(string: RocStr) callconv(.C) RocStr {
+    return normalize(string, false);
+}
+
+// Str.toNfc
+pub fn strToNfc(string: RocStr) callconv(.C) RocStr {
+    return normalize(string, true);
+}
+
+fn normalize(string: RocStr, comptime composed: bool) RocStr {
+    const bytes = string.asSlice();
+
+    // ASCII is unaffected by normalization
+    if (isAscii(bytes)) {
+        return RocStr.fromSlice(bytes);
+    }
+
+    var decomposed: [unicode_helpers.max_decomposition_length]u21 = undefined;
+
+    var length: usize = 0;
+    var count_iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    while (count_iter.nextCodepoint()) |codepoint| {
+        length += unicode_helpers.decompose(codepoint, &decomposed);
+    }
+
+    const codepoints = allocateCodepoints(length);
+    defer deallocateCodepoints(codepoints);
+
+    var offset: usize = 0;
+    var iter = unicode.Utf8View.initUnchecked(bytes).iterator();
+    while (iter.nextCodepoint()) |codepoint| {
+        offset += unicode_helpers.decompose(codepoint, codepoints[offset..]);
+    }
+
+    unicode_helpers.canonicalOrder(codepoints);
+
+    if (composed) {
+        length = unicode_helpers.compose(codepoints);
+    }
+
+    return strFromCodepoints(codepoints[0..length]);
+}
+
+fn expectStrTransform(transform: fn (RocStr) callconv(.C) RocStr, input: []const u8, expected: []const u8) !void {
+    const input_str = RocStr.fromSlice(input);
+    defer input_str.deinit();
+
+    const expected_str = RocStr.fromSlice(expected);
+    defer expected_str.deinit();
+
+    const actual = transform(input_str);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected_str));
+}
+
+test "strToUpper: ascii" {
+    try expectStrTransform(strToUpper, "hello, World!", "HELLO, WORLD!");
+}
+
+test "strToUpper: unicode" {
+    try expectStrTransform(strToUpper, "straße ǆ ﬃ", "STRASSE Ǆ FFI");
+}
+
+test "strToLower: unicode" {
+    try expectStrTransform(strToLower, "ÀÉÎ İ", "àéî i\u{307}");
+}
+
+test "strToLower: final sigma" {
+    try expectStrTransform(strToLower, "ΟΔΟΣ ΣΑΣ.", "οδος σας.");
+}
+
+test "strToNfd" {
+    try expectStrTransform(strToNfd, "é각Å", "e\u{301}\u{1100}\u{1161}\u{11A8}A\u{30A}");
+}
+
+test "strToNfc" {
+    try expectStrTransform(strToNfc, "e\u{301}\u{1100}\u{1161}\u{11A8}A\u{30A}", "é각Å");
+}
+
+test "strToNfc: reorders marks" {
+    // dot below (220) sorts before dot above (230), then composes with the q
+    try expectStrTransform(strToNfc, "q\u{307}\u{323}", "q\u{323}\u{307}");
+    try expectStrTransform(strToNfc, "s\u{307}\u{323}", "\u{1E69}");
+}
+
+test "strToNfc: singleton" {
+    // the Angstrom sign normalizes to A with ring above
+    try expectStrTransform(strToNfc, "\u{212B}", "Å");
+}
+
 pub fn countUtf8Bytes(string: RocStr) callconv(.C) usize {
     return string.len();
 }
@@ -1314,9 +1658,31 @@ pub fn getCapacity(string: RocStr) callconv(.C) usize {
     return string.getCapacity();
 }
 
+// Takes ownership of `string`. Big results share the allocation of `string`
+// as a seamless slice instead of copying its bytes.
 pub fn substringUnsafe(string: RocStr, start: usize, length: usize) callconv(.C) RocStr {
-    const slice = string.asSlice()[start .. start + length];
-    return RocStr.fromSlice(slice);
+    if (string.isSmallStr()) {
+        return RocStr.fromSlice(string.asSlice()[start .. start + length]);
+    }
+
+    if (length == 0) {
+        string.deinit();
+        return RocStr.empty();
+    }
+
+    if (length <= SMALL_STR_MAX_LENGTH) {
+        const result = RocStr.fromSlice(string.asSlice()[start .. start + length]);
+        string.deinit();
+        return result;
+    }
+
+    if (start == 0 and string.isUnique()) {
+        var result = string;
+        result.setLen(length);
+        return result;
+    }
+
+    return RocStr.initSeamlessSlice(string, start, length);
 }
 
 pub fn getUnsafe(string: RocStr, index: usize) callconv(.C) u8 {
@@ -1349,16 +1715,68 @@ test "substringUnsafe: middle" {
 
 test "substringUnsafe: end" {
     const str = RocStr.fromSlice("a string so long it is heap-allocated");
-    defer str.deinit();
 
     const expected = RocStr.fromSlice("heap-allocated");
     defer expected.deinit();
 
     const actual = substringUnsafe(str, 23, 37 - 23);
+    defer actual.deinit();
 
     try expect(RocStr.eq(actual, expected));
 }
 
+test "substringUnsafe: seamless slice" {
+    const str = RocStr.fromSlice("a string so long it is heap-allocated, and so is this part");
+
+    const expected = RocStr.fromSlice("heap-allocated, and so is this");
+    defer expected.deinit();
+
+    const actual = substringUnsafe(str, 23, 30);
+    defer actual.deinit();
+
+    try expect(actual.isSeamlessSlice());
+    try expectEqual(actual.len(), 30);
+    try expectEqual(actual.getCapacity(), 30);
+    try expect(RocStr.eq(actual, expected));
+}
+
+test "substringUnsafe: slice of a seamless slice" {
+    const str = RocStr.fromSlice("a string so long it is heap-allocated, and so is this part");
+
+    const slice = substringUnsafe(str, 2, 56);
+    const actual = substringUnsafe(slice, 21, 30);
+    defer actual.deinit();
+
+    const expected = RocStr.fromSlice("heap-allocated, and so is this");
+    defer expected.deinit();
+
+    try expect(actual.isSeamlessSlice());
+    try expectEqual(actual.getAllocationPtr(), str.str_bytes);
+    try expect(RocStr.eq(actual, expected));
+}
+
+test "seamless slice: round trip through a list of bytes" {
+    const str = RocStr.fromSlice("a string so long it is heap-allocated, and so is this part");
+
+    const slice = substringUnsafe(str, 23, 30);
+    const bytes = strToBytes(slice);
+
+    try expect(bytes.isSeamlessSlice());
+    try expectEqual(bytes.len(), 30);
+    try expectEqual(bytes.bytes, slice.str_bytes);
+
+    const result = fromUtf8(bytes, .Immutable);
+    defer result.string.deinit();
+
+    const expected = RocStr.fromSlice("heap-allocated, and so is this");
+    defer expected.deinit();
+
+    try expectOk(result);
+    try expect(result.string.isSeamlessSlice());
+    try expectEqual(result.string.getAllocationPtr(), str.str_bytes);
+    try expect(RocStr.eq(result.string, expected));
+}
+
 // Str.startsWith
 pub fn startsWith(string: RocStr, prefix: RocStr) callconv(.C) bool {
     const bytes_len = string.len();
@@ -1660,6 +2078,21 @@ pub fn strToUtf8C(arg: RocStr) callconv(.C) RocList {
     return strToBytes(arg);
 }
 
+// The tag roc_panic gets for a `crash` in user code; see PanicTagId in gen_llvm
+const USER_CRASH_TAG: u32 = 1;
+
+// crash "message"
+// roc_panic wants a null-terminated string, so the message is copied into one first
+pub fn strCrashC(message: RocStr) callconv(.C) void {
+    const length = message.len();
+    const c_str = utils.alloc(length + 1, @alignOf(u8)) orelse unreachable;
+
+    @memcpy(c_str, message.asU8ptr(), length);
+    c_str[length] = 0;
+
+    utils.panic(@ptrCast(*const anyopaque, c_str), USER_CRASH_TAG);
+}
+
 inline fn strToBytes(arg: RocStr) RocList {
     const length = arg.len();
     if (length == 0) {
@@ -1670,11 +2103,31 @@ inline fn strToBytes(arg: RocStr) RocList {
         @memcpy(ptr, arg.asU8ptr(), length);
 
         return RocList{ .length = length, .bytes = ptr, .capacity = length };
+    } else if (arg.isSeamlessSlice()) {
+        // seamless slices of lists use the same encoding, but mark the capacity instead
+        return RocList{ .length = length, .bytes = arg.str_bytes, .capacity = arg.str_capacity | SEAMLESS_SLICE_BIT };
     } else {
         return RocList{ .length = length, .bytes = arg.str_bytes, .capacity = arg.str_capacity };
     }
 }
 
+// Reuses the allocation of a list of bytes that is too long to be a small string
+fn bigStrFromByteList(byte_list: RocList) RocStr {
+    if (byte_list.isSeamlessSlice()) {
+        return RocStr{
+            .str_bytes = byte_list.bytes,
+            .str_len = byte_list.length | SEAMLESS_SLICE_BIT,
+            .str_capacity = byte_list.capacity & ~SEAMLESS_SLICE_BIT,
+        };
+    } else {
+        return RocStr{
+            .str_bytes = byte_list.bytes,
+            .str_len = byte_list.length,
+            .str_capacity = byte_list.capacity,
+        };
+    }
+}
+
 const FromUtf8Result = extern struct {
     byte_index: usize,
     string: RocStr,
@@ -1701,8 +2154,7 @@ inline fn fromUtf8(arg: RocList, update_mode: UpdateMode) FromUtf8Result {
             const string = RocStr.init(@ptrCast([*]u8, arg.bytes), arg.len());
 
             // then decrement the input list
-            const data_bytes = arg.len();
-            utils.decref(arg.bytes, data_bytes, RocStr.alignment);
+            arg.decref(RocStr.alignment);
 
             return FromUtf8Result{
                 .is_ok = true,
@@ -1713,11 +2165,7 @@ inline fn fromUtf8(arg: RocList, update_mode: UpdateMode) FromUtf8Result {
         } else {
             const byte_list = arg.makeUniqueExtra(RocStr.alignment, @sizeOf(u8), update_mode);
 
-            const string = RocStr{
-                .str_bytes = byte_list.bytes,
-                .str_len = byte_list.length,
-                .str_capacity = byte_list.capacity,
-            };
+            const string = bigStrFromByteList(byte_list);
 
             return FromUtf8Result{
                 .is_ok = true,
@@ -1730,8 +2178,7 @@ inline fn fromUtf8(arg: RocList, update_mode: UpdateMode) FromUtf8Result {
         const temp = errorToProblem(@ptrCast([*]u8, arg.bytes), arg.length);
 
         // consume the input list
-        const data_bytes = arg.len();
-        utils.decref(arg.bytes, data_bytes, RocStr.alignment);
+        arg.decref(RocStr.alignment);
 
         return FromUtf8Result{
             .is_ok = false,
@@ -1761,11 +2208,7 @@ pub fn fromUtf8Range(arg: RocList, start: usize, count: usize, update_mode: Upda
         if (count == arg.len() and count > SMALL_STR_MAX_LENGTH) {
             const byte_list = arg.makeUniqueExtra(RocStr.alignment, @sizeOf(u8), update_mode);
 
-            const string = RocStr{
-                .str_bytes = byte_list.bytes,
-                .str_len = byte_list.length,
-                .str_capacity = byte_list.capacity,
-            };
+            const string = bigStrFromByteList(byte_list);
 
             return FromUtf8Result{
                 .is_ok = true,
@@ -1778,7 +2221,7 @@ pub fn fromUtf8Range(arg: RocList, start: usize, count: usize, update_mode: Upda
             const string = RocStr.init(@ptrCast([*]const u8, bytes), count);
 
             // decref the list
-            utils.decref(arg.bytes, arg.len(), 1);
+            arg.decref(RocStr.alignment);
 
             return FromUtf8Result{
                 .is_ok = true,
@@ -1791,7 +2234,7 @@ pub fn fromUtf8Range(arg: RocList, start: usize, count: usize, update_mode: Upda
         const temp = errorToProblem(@ptrCast([*]u8, arg.bytes), arg.length);
 
         // decref the list
-        utils.decref(arg.bytes, arg.len(), 1);
+        arg.decref(RocStr.alignment);
 
         return FromUtf8Result{
             .is_ok = false,
@@ -2087,7 +2530,7 @@ pub fn strTrim(string: RocStr) callconv(.C) RocStr {
             }
 
             var new_string = string;
-            new_string.str_len = new_len;
+            new_string.setLen(new_len);
 
             return new_string;
         }
@@ -2127,7 +2570,7 @@ pub fn strTrimLeft(string: RocStr) callconv(.C) RocStr {
             }
 
             var new_string = string;
-            new_string.str_len = new_len;
+            new_string.setLen(new_len);
 
             return new_string;
         }
@@ -2163,7 +2606,7 @@ pub fn strTrimRight(string: RocStr) callconv(.C) RocStr {
         }
 
         var new_string = string;
-        new_string.str_len = new_len;
+        new_string.setLen(new_len);
 
         return new_string;
     }
@@ -2635,8 +3078,12 @@ pub fn strCloneTo(
     } else {
         const slice = string.asSlice();
 
-        var relative = string;
-        relative.str_bytes = @intToPtr(?[*]u8, extra_offset); // i.e. just after the string struct
+        // a seamless slice is written out as a plain big string
+        var relative = RocStr{
+            .str_bytes = @intToPtr(?[*]u8, extra_offset), // i.e. just after the string struct
+            .str_len = slice.len,
+            .str_capacity = slice.len,
+        };
 
         // write the string struct
         const array = relative.asArray();
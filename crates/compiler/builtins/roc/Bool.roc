@@ -1,8 +1,30 @@
 interface Bool
-    exposes [Bool, true, false, and, or, not, isEq, isNotEq]
+    exposes [Bool, Eq, true, false, and, or, not, isEq, isNotEq]
     imports []
 
-Bool := [True, False]
+## A type that can be compared for equality with `==` and `!=`.
+##
+## `a == b` is shorthand for `Bool.isEq a b`
+##
+## Records, tag unions, lists, strings, numbers and [Bool] have [Eq] automatically, as long as
+## everything inside them does too. They are compared *structurally*:
+##
+## 1. Tags are equal if they have the same tag name, and also their contents (if any) are equal.
+## 2. Records are equal if all their fields are equal.
+## 3. Collections ([Str], [List], [Dict], and [Set]) are equal if they are the same length, and also all their corresponding elements are equal.
+## 4. [Num](Num#Num) values are equal if their numbers are equal, with one exception: if both arguments to `isEq` are *NaN*, then `isEq` returns `Bool.false`. See `Num.isNaN` for more about *NaN*.
+##
+## Functions do not have [Eq], and neither does any type that contains a function.
+##
+## Opaque types can implement [Eq] themselves to choose their own notion of equality, for
+## example a case-insensitive string wrapper, or derive it with `has [Eq]` to compare the values
+## they wrap. Either way, records, tag unions and lists containing them use that [Eq] to compare them.
+Eq has
+    isEq : a, a -> Bool | a has Eq
+
+Bool := [True, False] has [Eq { isEq: boolIsEq }]
+
+boolIsEq = \@Bool b1, @Bool b2 -> structuralEq b1 b2
 
 ## The boolean true value.
 true : Bool
@@ -67,25 +89,13 @@ or : Bool, Bool -> Bool
 ## Returns `Bool.false` when given `Bool.true`, and vice versa.
 not : Bool -> Bool
 
-## Returns `Bool.true` if the two values are *structurally equal*, and `Bool.false` otherwise.
-##
-## `a == b` is shorthand for `Bool.isEq a b`
-##
-## Structural equality works as follows:
-##
-## 1. Tags are equal if they have the same tag name, and also their contents (if any) are equal.
-## 2. Records are equal if all their fields are equal.
-## 3. Collections ([Str], [List], [Dict], and [Set]) are equal if they are the same length, and also all their corresponding elements are equal.
-## 4. [Num](Num#Num) values are equal if their numbers are equal, with one exception: if both arguments to `isEq` are *NaN*, then `isEq` returns `Bool.false`. See `Num.isNaN` for more about *NaN*.
-##
-## Note that `isEq` takes `'val` instead of `val`, which means `isEq` does not
-## accept arguments whose types contain functions.
-isEq : a, a -> Bool
-
 ## Calls [isEq] on the given values, then calls [not] on the result.
 ##
 ## `a != b` is shorthand for `Bool.isNotEq a b`
-##
-## Note that `isNotEq` takes `'val` instead of `val`, which means `isNotEq` does not
-## accept arguments whose types contain functions.
-isNotEq : a, a -> Bool
+isNotEq : a, a -> Bool | a has Eq
+isNotEq = \a, b -> not (isEq a b)
+
+# Compares two values structurally, ignoring the custom Eq implementations of any opaque types
+# they contain. It is not exposed, so it can't be used to compare functions or to get around an
+# opaque type's isEq. The derived Eq of numbers and strings calls it directly.
+structuralEq : a, a -> Bool
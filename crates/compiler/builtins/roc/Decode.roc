@@ -23,6 +23,7 @@ interface Decode
         string,
         list,
         record,
+        tag,
         custom,
         decodeWith,
         fromBytesPartial,
@@ -59,6 +60,7 @@ DecoderFormatting has
     string : Decoder Str fmt | fmt has DecoderFormatting
     list : Decoder elem fmt -> Decoder (List elem) fmt | fmt has DecoderFormatting
     record : state, (state, Str -> [Keep (Decoder state fmt), Skip]), (state -> Result val DecodeError) -> Decoder val fmt | fmt has DecoderFormatting
+    tag : state, (state, Str, Nat -> [Next (Decoder state fmt), TooLong]), (state, Str -> Result val DecodeError) -> Decoder val fmt | fmt has DecoderFormatting
 
 custom : (List U8, fmt -> DecodeResult val) -> Decoder val fmt | fmt has DecoderFormatting
 custom = \decode -> @Decoder decode
@@ -20,6 +20,7 @@ interface Dict
         Bool.{ Bool },
         Result.{ Result },
         List,
+        Hash.{ Hasher, Hash },
     ]
 
 ## A [dictionary](https://en.wikipedia.org/wiki/Associative_array) that lets you can associate keys with values.
@@ -63,118 +64,472 @@ interface Dict
 ## [Dict.remove] does: it removes an element and moves the most recent insertion into the vacated spot.
 ##
 ## This move is done as a performance optimization, and it lets [remove] have
-## [constant time complexity](https://en.wikipedia.org/wiki/Time_complexity#Constant_time). ##
+## [constant time complexity](https://en.wikipedia.org/wiki/Time_complexity#Constant_time).
+##
+## ### Implementation
+##
+## A dictionary is an open-addressing hash table using
+## [Robin Hood hashing](https://en.wikipedia.org/wiki/Hash_table#Robin_Hood_hashing) with backward shift
+## deletion. Its entries are stored contiguously in insertion order, and a separate list of buckets maps
+## hashes to indices into those entries. This is what lets [Dict.keys], [Dict.values] and [Dict.walk] visit
+## entries in insertion order, while [Dict.get], [Dict.insert] and [Dict.remove] take constant time on average.
+##
+## Keys are hashed with their [Hash] implementation and compared with their [Eq] implementation, so any type
+## that has both [Hash] and [Eq] can be used as a key. Keys that are `==` must produce the same hash, or the
+## dictionary will not be able to find them.
 ##
 ## ### Equality
 ##
 ## When comparing two dictionaries for equality, they are `==` only if their both their contents and their
 ## orderings match. This preserves the property that if `dict1 == dict2`, you should be able to rely on
 ## `fn dict1 == fn dict2` also being `Bool.true`, even if `fn` relies on the dictionary's ordering.
-Dict k v := List [Pair k v]
+Dict k v := { buckets : List Bucket, data : List [Pair k v], maxBucketCapacity : Nat, shifts : U8 } has [Eq { isEq: dictIsEq }]
+
+dictIsEq : Dict k v, Dict k v -> Bool | k has Eq, v has Eq
+dictIsEq = \@Dict a, @Dict b -> a.data == b.data
+
+Bucket : { distAndFingerprint : U32, dataIndex : U32 }
 
 ## An empty dictionary.
 empty : Dict k v
-empty = @Dict []
+empty = @Dict { buckets: [], data: [], maxBucketCapacity: 0, shifts: initialShifts }
 
+## Returns a dictionary with space allocated for a number of entries. This
+## may provide a performance optimisation if you know how many entries will be
+## inserted.
 withCapacity : Nat -> Dict k v
-withCapacity = \n -> @Dict (List.withCapacity n)
+withCapacity = \requested ->
+    if requested == 0 then
+        empty
+    else
+        shifts = calcShiftsForCapacity requested initialShifts
+
+        @Dict {
+            buckets: List.repeat emptyBucket (calcNumBuckets shifts),
+            data: List.withCapacity requested,
+            maxBucketCapacity: calcMaxBucketCapacity shifts,
+            shifts,
+        }
+
+## Get the value for a given key. If there is a value for the specified key it
+## will return [Ok value], otherwise return [Err KeyNotFound].
+get : Dict k v, k -> Result v [KeyNotFound]* | k has Hash & Eq
+get = \@Dict { buckets, data, shifts }, key ->
+    when findDataIndex buckets data shifts key is
+        Ok dataIndex ->
+            when List.get data dataIndex is
+                Ok (Pair _ value) ->
+                    Ok value
 
-get : Dict k v, k -> Result v [KeyNotFound]*
-get = \@Dict list, needle ->
-    when List.findFirst list (\Pair key _ -> key == needle) is
-        Ok (Pair _ v) ->
-            Ok v
+                Err OutOfBounds ->
+                    Err KeyNotFound
 
         Err NotFound ->
             Err KeyNotFound
 
+## Iterate through the keys and values in the dictionary and call the provided
+## function with signature `state, k, v -> state` for each value, with an
+## initial `state` value provided for the first call.
 walk : Dict k v, state, (state, k, v -> state) -> state
-walk = \@Dict list, initialState, transform ->
-    List.walk list initialState (\state, Pair k v -> transform state k v)
+walk = \@Dict { data }, initialState, transform ->
+    List.walk data initialState (\state, Pair k v -> transform state k v)
 
-insert : Dict k v, k, v -> Dict k v
-insert = \@Dict list, k, v ->
-    when List.findFirstIndex list (\Pair key _ -> key == k) is
-        Err NotFound ->
-            insertFresh (@Dict list) k v
+## Insert a value into the dictionary at a specified key.
+insert : Dict k v, k, v -> Dict k v | k has Hash & Eq
+insert = \dict, key, value ->
+    when growIfNeeded dict is
+        @Dict { buckets, data, maxBucketCapacity, shifts } ->
+            hash = hashKey key
+            distAndFingerprint = distAndFingerprintFromHash hash
+            bucketIndex = bucketIndexFromHash hash shifts
+
+            when findBucket buckets data key distAndFingerprint bucketIndex is
+                Found foundIndex ->
+                    bucket = getBucket buckets foundIndex
 
-        Ok index ->
-            list
-            |> List.set index (Pair k v)
-            |> @Dict
+                    @Dict {
+                        buckets,
+                        data: List.set data (Num.toNat bucket.dataIndex) (Pair key value),
+                        maxBucketCapacity,
+                        shifts,
+                    }
 
+                Vacant vacantIndex vacantDistAndFingerprint ->
+                    newBucket = {
+                        distAndFingerprint: vacantDistAndFingerprint,
+                        dataIndex: Num.toU32 (List.len data),
+                    }
+
+                    @Dict {
+                        buckets: placeAndShiftUp buckets newBucket vacantIndex,
+                        data: List.append data (Pair key value),
+                        maxBucketCapacity,
+                        shifts,
+                    }
+
+## Returns the number of values in the dictionary.
 len : Dict k v -> Nat
-len = \@Dict list ->
-    List.len list
+len = \@Dict { data } ->
+    List.len data
 
-remove : Dict k v, k -> Dict k v
-remove = \@Dict list, key ->
-    when List.findFirstIndex list (\Pair k _ -> k == key) is
-        Err NotFound ->
-            @Dict list
+## Remove a value from the dictionary for a specified key.
+remove : Dict k v, k -> Dict k v | k has Hash & Eq
+remove = \dict, key ->
+    when rehashIfMissingBuckets dict is
+        @Dict { buckets, data, maxBucketCapacity, shifts } ->
+            hash = hashKey key
+            distAndFingerprint = distAndFingerprintFromHash hash
+            bucketIndex = bucketIndexFromHash hash shifts
+
+            when findBucket buckets data key distAndFingerprint bucketIndex is
+                Vacant _ _ ->
+                    @Dict { buckets, data, maxBucketCapacity, shifts }
+
+                Found foundIndex ->
+                    removedBucket = getBucket buckets foundIndex
+                    removedDataIndex = removedBucket.dataIndex
+                    lastDataIndex = Num.toU32 (List.len data - 1)
+                    bucketsWithoutKey = removeBucket buckets foundIndex
 
-        Ok index ->
-            lastIndex = List.len list - 1
+                    if removedDataIndex == lastDataIndex then
+                        @Dict {
+                            buckets: bucketsWithoutKey,
+                            data: List.dropLast data,
+                            maxBucketCapacity,
+                            shifts,
+                        }
+                    else
+                        # Move the last entry into the vacated spot, so the
+                        # bucket pointing at it must now point at the new index.
+                        movedBuckets =
+                            when List.get data (Num.toNat lastDataIndex) is
+                                Ok (Pair lastKey _) ->
+                                    lastHash = hashKey lastKey
+                                    movedIndex = findBucketForDataIndex bucketsWithoutKey (bucketIndexFromHash lastHash shifts) lastDataIndex
+                                    movedBucket = getBucket bucketsWithoutKey movedIndex
 
-            list
-            |> List.swap index lastIndex
-            |> List.dropLast
-            |> @Dict
+                                    List.set bucketsWithoutKey movedIndex { distAndFingerprint: movedBucket.distAndFingerprint, dataIndex: removedDataIndex }
 
-contains : Dict k v, k -> Bool
-contains = \@Dict list, needle ->
-    step = \_, Pair key _val ->
-        if key == needle then
-            Break {}
-        else
-            Continue {}
+                                Err OutOfBounds ->
+                                    bucketsWithoutKey
 
-    when List.iterate list {} step is
-        Continue _ -> Bool.false
-        Break _ -> Bool.true
+                        @Dict {
+                            buckets: movedBuckets,
+                            data: List.swap data (Num.toNat removedDataIndex) (Num.toNat lastDataIndex) |> List.dropLast,
+                            maxBucketCapacity,
+                            shifts,
+                        }
 
-single : k, v -> Dict k v
+## Check if the dictionary has a value for a specified key.
+contains : Dict k v, k -> Bool | k has Hash & Eq
+contains = \@Dict { buckets, data, shifts }, key ->
+    when findDataIndex buckets data shifts key is
+        Ok _ -> Bool.true
+        Err NotFound -> Bool.false
+
+## Returns a dictionary containing the key and value provided as input.
+single : k, v -> Dict k v | k has Hash & Eq
 single = \key, value ->
-    @Dict [Pair key value]
+    insert empty key value
 
 ## Returns a [List] of the dictionary's keys.
 keys : Dict k v -> List k
-keys = \@Dict list ->
-    List.map list (\Pair k _ -> k)
+keys = \@Dict { data } ->
+    List.map data (\Pair k _ -> k)
 
 ## Returns a [List] of the Dict's values
 values : Dict k v -> List v
-values = \@Dict list ->
-    List.map list (\Pair _ v -> v)
+values = \@Dict { data } ->
+    List.map data (\Pair _ v -> v)
 
 # union : Dict k v, Dict k v -> Dict k v
-insertAll : Dict k v, Dict k v -> Dict k v
-insertAll = \xs, @Dict ys ->
-    List.walk ys xs (\state, Pair k v -> Dict.insertIfVacant state k v)
+insertAll : Dict k v, Dict k v -> Dict k v | k has Hash & Eq
+insertAll = \xs, ys ->
+    walk ys xs insertIfVacant
 
 # intersection : Dict k v, Dict k v -> Dict k v
-keepShared : Dict k v, Dict k v -> Dict k v
-keepShared = \@Dict xs, ys ->
-    List.keepIf xs (\Pair k _ -> Dict.contains ys k)
-    |> @Dict
+keepShared : Dict k v, Dict k v -> Dict k v | k has Hash & Eq
+keepShared = \xs, ys ->
+    walk
+        xs
+        empty
+        (\state, k, v ->
+            if contains ys k then
+                insert state k v
+            else
+                state)
 
 # difference : Dict k v, Dict k v -> Dict k v
-removeAll : Dict k v, Dict k v -> Dict k v
-removeAll = \xs, @Dict ys ->
-    List.walk ys xs (\state, Pair k _ -> Dict.remove state k)
-
-## Internal helper function to insert a new association
-##
-## Precondition: `k` should not exist in the Dict yet.
-insertFresh : Dict k v, k, v -> Dict k v
-insertFresh = \@Dict list, k, v ->
-    list
-    |> List.append (Pair k v)
-    |> @Dict
+removeAll : Dict k v, Dict k v -> Dict k v | k has Hash & Eq
+removeAll = \xs, ys ->
+    walk ys xs (\state, k, _ -> remove state k)
 
-insertIfVacant : Dict k v, k, v -> Dict k v
+insertIfVacant : Dict k v, k, v -> Dict k v | k has Hash & Eq
 insertIfVacant = \dict, key, value ->
-    if Dict.contains dict key then
+    if contains dict key then
         dict
     else
-        Dict.insert dict key value
+        insert dict key value
+
+# Implementation details
+#
+# Each bucket stores the index of its entry in `data`, together with a combined
+# distance and fingerprint. The upper 24 bits hold how far (plus one) the bucket
+# is from the ideal bucket of its key, and the lower 8 bits hold a fingerprint of
+# the key's hash. A `distAndFingerprint` of zero marks an empty bucket.
+#
+# The number of buckets is always a power of two, and the ideal bucket of a key
+# is found by shifting its hash right by `shifts`.
+hashKey : k -> U64 | k has Hash
+hashKey = \key ->
+    Hash.hash (@LowLevelHasher seed) key |> complete
+
+seed : U64
+seed = 0x526F_6344_6963_7421
+
+# The hasher used for keys. Its state is a seed, and every value added to it is
+# hashed with the builtin wyhash using the current seed, producing the next seed.
+LowLevelHasher := U64 has [Hasher { addBytes, addU8, addU16, addU32, addU64, addU128, complete }]
+
+# Hashes a value with the given seed. The value must not contain functions.
+hashLowLevel : a, U64 -> U64
+
+addBytes : LowLevelHasher, List U8 -> LowLevelHasher
+addBytes = \@LowLevelHasher state, bytes -> @LowLevelHasher (hashLowLevel bytes state)
+
+addU8 : LowLevelHasher, U8 -> LowLevelHasher
+addU8 = \@LowLevelHasher state, n -> @LowLevelHasher (hashLowLevel n state)
+
+addU16 : LowLevelHasher, U16 -> LowLevelHasher
+addU16 = \@LowLevelHasher state, n -> @LowLevelHasher (hashLowLevel n state)
+
+addU32 : LowLevelHasher, U32 -> LowLevelHasher
+addU32 = \@LowLevelHasher state, n -> @LowLevelHasher (hashLowLevel n state)
+
+addU64 : LowLevelHasher, U64 -> LowLevelHasher
+addU64 = \@LowLevelHasher state, n -> @LowLevelHasher (hashLowLevel n state)
+
+addU128 : LowLevelHasher, U128 -> LowLevelHasher
+addU128 = \@LowLevelHasher state, n -> @LowLevelHasher (hashLowLevel n state)
+
+complete : LowLevelHasher -> U64
+complete = \@LowLevelHasher state -> state
+
+emptyBucket : Bucket
+emptyBucket = { distAndFingerprint: 0, dataIndex: 0 }
+
+distInc : U32
+distInc = 256
+
+fingerprintMask : U32
+fingerprintMask = 255
+
+# 64 - 3, which means the first allocation has 8 buckets
+initialShifts : U8
+initialShifts = 61
+
+# Below this the bucket count would no longer fit a U32 data index
+minShifts : U8
+minShifts = 32
+
+calcNumBuckets : U8 -> Nat
+calcNumBuckets = \shifts ->
+    Num.shiftLeftBy 1 (64 - Num.toNat shifts)
+
+# The table grows once it is 80% full.
+calcMaxBucketCapacity : U8 -> Nat
+calcMaxBucketCapacity = \shifts ->
+    Num.divTrunc (calcNumBuckets shifts * 4) 5
+
+calcShiftsForCapacity : Nat, U8 -> U8
+calcShiftsForCapacity = \capacity, shifts ->
+    if shifts > minShifts && calcMaxBucketCapacity shifts < capacity then
+        calcShiftsForCapacity capacity (shifts - 1)
+    else
+        shifts
+
+distAndFingerprintFromHash : U64 -> U32
+distAndFingerprintFromHash = \hash ->
+    Num.bitwiseOr distInc (Num.bitwiseAnd (Num.toU32 hash) fingerprintMask)
+
+bucketIndexFromHash : U64, U8 -> Nat
+bucketIndexFromHash = \hash, shifts ->
+    Num.toNat (Num.shiftRightZfBy hash (Num.toU64 shifts))
+
+nextBucketIndex : Nat, Nat -> Nat
+nextBucketIndex = \index, numBuckets ->
+    if index + 1 == numBuckets then
+        0
+    else
+        index + 1
+
+getBucket : List Bucket, Nat -> Bucket
+getBucket = \buckets, index ->
+    when List.get buckets index is
+        Ok bucket -> bucket
+        Err OutOfBounds -> emptyBucket
+
+# Finds the index of the entry for a key in `data`.
+findDataIndex : List Bucket, List [Pair k v], U8, k -> Result Nat [NotFound]* | k has Hash & Eq
+findDataIndex = \buckets, data, shifts, key ->
+    if List.isEmpty buckets then
+        # A dictionary built by the host may only have its data filled in
+        List.findFirstIndex data (\Pair k _ -> k == key)
+    else
+        hash = hashKey key
+        distAndFingerprint = distAndFingerprintFromHash hash
+        bucketIndex = bucketIndexFromHash hash shifts
+
+        when findBucket buckets data key distAndFingerprint bucketIndex is
+            Found foundIndex ->
+                bucket = getBucket buckets foundIndex
+
+                Ok (Num.toNat bucket.dataIndex)
+
+            Vacant _ _ ->
+                Err NotFound
+
+# Probes from a key's ideal bucket until the key is found, or until reaching a
+# bucket that is closer to its own ideal bucket than the key would be. Because of
+# the Robin Hood invariant the key cannot be further along, so that bucket is
+# where the key should be inserted.
+findBucket : List Bucket, List [Pair k v], k, U32, Nat -> [Found Nat, Vacant Nat U32] | k has Eq
+findBucket = \buckets, data, key, distAndFingerprint, bucketIndex ->
+    bucket = getBucket buckets bucketIndex
+    next = \{} ->
+        findBucket buckets data key (distAndFingerprint + distInc) (nextBucketIndex bucketIndex (List.len buckets))
+
+    if bucket.distAndFingerprint == distAndFingerprint then
+        when List.get data (Num.toNat bucket.dataIndex) is
+            Ok (Pair k _) ->
+                if k == key then
+                    Found bucketIndex
+                else
+                    next {}
+
+            Err OutOfBounds ->
+                next {}
+    else if bucket.distAndFingerprint < distAndFingerprint then
+        Vacant bucketIndex distAndFingerprint
+    else
+        next {}
+
+findBucketForDataIndex : List Bucket, Nat, U32 -> Nat
+findBucketForDataIndex = \buckets, bucketIndex, dataIndex ->
+    bucket = getBucket buckets bucketIndex
+
+    if bucket.dataIndex == dataIndex && bucket.distAndFingerprint != 0 then
+        bucketIndex
+    else
+        findBucketForDataIndex buckets (nextBucketIndex bucketIndex (List.len buckets)) dataIndex
+
+# Puts a bucket at the given index, moving every bucket after it one step further
+# from its ideal spot until an empty bucket is reached.
+placeAndShiftUp : List Bucket, Bucket, Nat -> List Bucket
+placeAndShiftUp = \buckets, bucket, bucketIndex ->
+    existing = getBucket buckets bucketIndex
+
+    if existing.distAndFingerprint == 0 then
+        List.set buckets bucketIndex bucket
+    else
+        shifted = { existing & distAndFingerprint: existing.distAndFingerprint + distInc }
+
+        placeAndShiftUp
+            (List.set buckets bucketIndex bucket)
+            shifted
+            (nextBucketIndex bucketIndex (List.len buckets))
+
+# Empties the bucket at the given index, moving every following bucket that is not
+# in its ideal spot one step back (backward shift deletion).
+removeBucket : List Bucket, Nat -> List Bucket
+removeBucket = \buckets, bucketIndex ->
+    nextIndex = nextBucketIndex bucketIndex (List.len buckets)
+    nextBucket = getBucket buckets nextIndex
+
+    if nextBucket.distAndFingerprint >= distInc * 2 then
+        shifted = { nextBucket & distAndFingerprint: nextBucket.distAndFingerprint - distInc }
+
+        removeBucket (List.set buckets bucketIndex shifted) nextIndex
+    else
+        List.set buckets bucketIndex emptyBucket
+
+growIfNeeded : Dict k v -> Dict k v | k has Hash
+growIfNeeded = \@Dict { buckets, data, maxBucketCapacity, shifts } ->
+    if List.isEmpty buckets then
+        rehash data (calcShiftsForCapacity (List.len data + 1) initialShifts)
+    else if List.len data >= maxBucketCapacity && shifts > minShifts then
+        rehash data (shifts - 1)
+    else
+        @Dict { buckets, data, maxBucketCapacity, shifts }
+
+rehashIfMissingBuckets : Dict k v -> Dict k v | k has Hash
+rehashIfMissingBuckets = \@Dict { buckets, data, maxBucketCapacity, shifts } ->
+    if List.isEmpty buckets && !(List.isEmpty data) then
+        rehash data (calcShiftsForCapacity (List.len data) initialShifts)
+    else
+        @Dict { buckets, data, maxBucketCapacity, shifts }
+
+# Builds fresh buckets for the given entries, keeping them in the same order.
+rehash : List [Pair k v], U8 -> Dict k v | k has Hash
+rehash = \data, shifts ->
+    initialBuckets = List.repeat emptyBucket (calcNumBuckets shifts)
+
+    rehashed =
+        List.walk
+            data
+            { buckets: initialBuckets, dataIndex: 0 }
+            (\state, Pair k _ ->
+                hash = hashKey k
+                bucketIndex = bucketIndexFromHash hash shifts
+                bucket = { distAndFingerprint: distAndFingerprintFromHash hash, dataIndex: state.dataIndex }
+
+                { buckets: placeAt state.buckets bucket bucketIndex, dataIndex: state.dataIndex + 1 })
+
+    @Dict {
+        buckets: rehashed.buckets,
+        data,
+        maxBucketCapacity: calcMaxBucketCapacity shifts,
+        shifts,
+    }
+
+# Finds the spot for a bucket whose key is known not to be in the table yet.
+placeAt : List Bucket, Bucket, Nat -> List Bucket
+placeAt = \buckets, bucket, bucketIndex ->
+    existing = getBucket buckets bucketIndex
+
+    if bucket.distAndFingerprint > existing.distAndFingerprint then
+        placeAndShiftUp buckets bucket bucketIndex
+    else
+        placeAt
+            buckets
+            { bucket & distAndFingerprint: bucket.distAndFingerprint + distInc }
+            (nextBucketIndex bucketIndex (List.len buckets))
+
+expect
+    dict =
+        empty
+        |> insert "foo" 1
+        |> insert "bar" 2
+        |> insert "foo" 3
+
+    get dict "foo" == Ok 3 && get dict "bar" == Ok 2 && len dict == 2
+
+expect
+    dict =
+        List.range 0 100
+        |> List.walk empty (\state, n -> insert state n (n * 2))
+        |> remove 10
+        |> remove 50
+
+    len dict == 98 && get dict 11 == Ok 22 && !(contains dict 50)
+
+expect
+    dict =
+        empty
+        |> insert "London" 1
+        |> insert "Philadelphia" 2
+        |> insert "Shanghai" 3
+        |> insert "Amsterdam" 4
+        |> remove "Philadelphia"
+
+    keys dict == ["London", "Amsterdam", "Shanghai"]
@@ -0,0 +1,525 @@
+interface Gen
+    exposes [
+        Gen,
+        Choices,
+        Arbitrary,
+        arbitrary,
+        custom,
+        generateWith,
+        constant,
+        map,
+        map2,
+        andThen,
+        oneOf,
+        natBelow,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        nat,
+        f32,
+        f64,
+        dec,
+        bool,
+        str,
+        list,
+        check,
+        checkWith,
+    ]
+    imports [
+        Bool.{ Bool },
+        List,
+        Str,
+    ]
+
+## A generator of values of type `a`, for checking properties with [check].
+##
+## A generator builds its value out of [Choices]. Smaller choices make simpler values, and
+## the choice `0` makes the simplest value a generator has, like `0`, `""` or `[]`. That is
+## what lets [check] shrink a value that falsifies a property: it shrinks the choices the
+## value was made from, and generates the value again.
+Gen a := Choices -> { value : a, choices : Choices }
+
+## The choices a [Gen] makes while generating a value.
+##
+## Choices are random while [check] is looking for a value that falsifies a property, and
+## are replayed while it shrinks that value.
+Choices := {
+    drawn : List U64,
+    replay : List U64,
+    state : U64,
+    budget : Nat,
+}
+
+## A type whose values can be generated.
+##
+## Records, tag unions, lists, strings, booleans and numbers implement [Arbitrary]
+## automatically, as long as everything inside them does too. Opaque types can
+## implement [Arbitrary] with a generator of their own:
+##
+## ```
+## Percent := U8 has [Arbitrary { arbitrary: percent }]
+##
+## percent = Gen.map (Gen.natBelow 101) \n -> @Percent (Num.toU8 n)
+## ```
+Arbitrary has
+    arbitrary : Gen a | a has Arbitrary
+
+## Makes a generator out of a function that generates values, usually by running other
+## generators with [generateWith].
+custom : (Choices -> { value : a, choices : Choices }) -> Gen a
+custom = \generate -> @Gen generate
+
+## Generates a value, making these choices.
+generateWith : Choices, Gen a -> { value : a, choices : Choices }
+generateWith = \choices, @Gen generate -> generate choices
+
+## A generator that always generates the same value.
+constant : a -> Gen a
+constant = \value -> @Gen \choices -> { value, choices }
+
+## Transforms the values a generator generates.
+map : Gen a, (a -> b) -> Gen b
+map = \@Gen generate, transform ->
+    @Gen \choices ->
+        generated = generate choices
+
+        { value: transform generated.value, choices: generated.choices }
+
+## Combines the values of two generators.
+##
+## >>> Gen.map2 Gen.u8 Gen.str \count, name -> { count, name }
+map2 : Gen a, Gen b, (a, b -> c) -> Gen c
+map2 = \@Gen generateA, @Gen generateB, combine ->
+    @Gen \choices ->
+        a = generateA choices
+        b = generateB a.choices
+
+        { value: combine a.value b.value, choices: b.choices }
+
+## Generates a value, and then uses it to pick the generator to generate the final value with.
+andThen : Gen a, (a -> Gen b) -> Gen b
+andThen = \@Gen generate, next ->
+    @Gen \choices ->
+        generated = generate choices
+
+        generateWith generated.choices (next generated.value)
+
+## Generates a value with one of the given generators. The first generator is the one that
+## makes the simplest values.
+oneOf : Gen a, List (Gen a) -> Gen a
+oneOf = \first, others ->
+    @Gen \choices ->
+        index = drawBelow choices (Num.toU64 (List.len others) + 1)
+        chosen =
+            if index.value == 0 then
+                first
+            else
+                when List.get others (Num.toNat (index.value - 1)) is
+                    Ok other -> other
+                    Err OutOfBounds -> first
+
+        generateWith index.choices chosen
+
+## Generates a [Nat] that is less than the given bound; `natBelow 0` always generates `0`.
+natBelow : Nat -> Gen Nat
+natBelow = \bound ->
+    @Gen \choices ->
+        index = drawBelow choices (Num.toU64 bound)
+
+        { value: Num.toNat index.value, choices: index.choices }
+
+u8 : Gen U8
+u8 = custom \choices -> unsignedAs choices 8 Num.toU8
+
+u16 : Gen U16
+u16 = custom \choices -> unsignedAs choices 16 Num.toU16
+
+u32 : Gen U32
+u32 = custom \choices -> unsignedAs choices 32 Num.toU32
+
+u64 : Gen U64
+u64 = custom \choices -> unsignedAs choices 64 Num.toU64
+
+u128 : Gen U128
+u128 = custom \choices -> unsigned128 choices
+
+i8 : Gen I8
+i8 = custom \choices -> signedAs choices 8 Num.toI8
+
+i16 : Gen I16
+i16 = custom \choices -> signedAs choices 16 Num.toI16
+
+i32 : Gen I32
+i32 = custom \choices -> signedAs choices 32 Num.toI32
+
+i64 : Gen I64
+i64 = custom \choices -> signedAs choices 64 Num.toI64
+
+i128 : Gen I128
+i128 = custom \choices ->
+    drawn = unsigned128 choices
+    half = Num.toI128 (Num.shiftRightZfBy drawn.value 1)
+    value = if Num.bitwiseAnd drawn.value 1 == 0 then half else Num.neg half - 1
+
+    { value, choices: drawn.choices }
+
+nat : Gen Nat
+nat = custom \choices -> unsignedAs choices 64 Num.toNat
+
+## Generates finite numbers, which are a whole number divided by a power of two.
+f32 : Gen F32
+f32 = custom \choices -> fraction choices
+
+## Generates finite numbers, which are a whole number divided by a power of two.
+f64 : Gen F64
+f64 = custom \choices -> fraction choices
+
+## Generates numbers that are a whole number divided by a power of two.
+dec : Gen Dec
+dec = custom \choices -> fraction choices
+
+bool : Gen Bool
+bool = custom \choices ->
+    drawn = drawBelow choices 2
+
+    { value: drawn.value == 1, choices: drawn.choices }
+
+## Generates strings of any Unicode scalar values, although mostly ASCII ones.
+str : Gen Str
+str = custom \choices -> strHelp "" choices
+
+strHelp : Str, Choices -> { value : Str, choices : Choices }
+strHelp = \string, choices ->
+    more = drawBelow choices listContinues
+
+    if more.value == 0 then
+        { value: string, choices: more.choices }
+    else
+        next = scalar more.choices
+
+        when Str.appendScalar string next.value is
+            Ok appended -> strHelp appended next.choices
+            Err InvalidScalar -> strHelp string next.choices
+
+## Generates lists of values from the given generator.
+list : Gen a -> Gen (List a)
+list = \@Gen generate ->
+    @Gen \choices -> listHelp generate [] choices
+
+listHelp : (Choices -> { value : a, choices : Choices }), List a, Choices -> { value : List a, choices : Choices }
+listHelp = \generate, elems, choices ->
+    more = drawBelow choices listContinues
+
+    if more.value == 0 then
+        { value: elems, choices: more.choices }
+    else
+        elem = generate more.choices
+
+        listHelp generate (List.append elems elem.value) elem.choices
+
+## Checks that a property holds for 100 values from the generator. When it does not,
+## returns the simplest value that [check] could find that falsifies it.
+##
+## The values are random, but the same every time unless `roc test` is given a
+## different `--seed`.
+##
+## ```roc test
+## reversedTwice : List U8 -> Bool
+## reversedTwice = \elems -> List.reverse (List.reverse elems) == elems
+##
+## result = Gen.check Gen.arbitrary reversedTwice
+##
+## result == Ok {}
+## ```
+##
+## If a property fails, an `expect` like the one above shows the counterexample as
+## the value of `result`, e.g. `Err (Counterexample [0, 1])`.
+check : Gen a, (a -> Bool) -> Result {} [Counterexample a]*
+check = \gen, property ->
+    checkWith gen property { runs: 100, seed: seedLowLevel {} }
+
+## Like [check], with a given number of runs and a given seed.
+checkWith : Gen a, (a -> Bool), { runs : Nat, seed : U64 } -> Result {} [Counterexample a]*
+checkWith = \@Gen generate, property, { runs, seed } ->
+    checkHelp generate property runs 0 seed
+
+checkHelp : (Choices -> { value : a, choices : Choices }), (a -> Bool), Nat, Nat, U64 -> Result {} [Counterexample a]*
+checkHelp = \generate, property, runs, run, state ->
+    if run >= runs then
+        Ok {}
+    else
+        # Early runs may make only a few choices, so that simple values are tried first.
+        budget = if run < maxChoices // 8 then 8 * (run + 1) else maxChoices
+        generated = generate (@Choices { drawn: [], replay: [], state, budget })
+
+        if property generated.value then
+            checkHelp generate property runs (run + 1) (stateOf generated.choices)
+        else
+            shrinking = { choices: drawnChoices generated.choices, value: generated.value, attempts: 0 }
+            shrunk = shrink shrinking generate property
+
+            Err (Counterexample shrunk.value)
+
+# The seed `roc test --seed` asked for, or 0.
+seedLowLevel : {} -> U64
+
+# ## Shrinking
+#
+# A value that falsifies a property is shrunk by shrinking the choices it was made from:
+# removing some of them, or making one smaller. The shrunk choices are replayed to generate a
+# new value, and if that still falsifies the property, the shrunk choices are kept.
+#
+# Shrinking only keeps choices that are "simpler": fewer choices, or the same number of
+# choices with smaller ones first. So it ends, even if it runs out of attempts before then.
+Shrinking a : { choices : List U64, value : a, attempts : Nat }
+
+shrink : Shrinking a, (Choices -> { value : a, choices : Choices }), (a -> Bool) -> Shrinking a
+shrink = \shrinking, generate, property ->
+    deleted = List.walk [8, 4, 2, 1] shrinking \current, size ->
+        deleteChunks current generate property size
+    shrunk = minimizeChoices deleted generate property 0
+
+    if shrunk.choices != shrinking.choices && shrunk.attempts < maxShrinkAttempts then
+        shrink shrunk generate property
+    else
+        shrunk
+
+# Tries removing every run of `size` choices, starting from the end.
+deleteChunks : Shrinking a, (Choices -> { value : a, choices : Choices }), (a -> Bool), Nat -> Shrinking a
+deleteChunks = \shrinking, generate, property, size ->
+    length = List.len shrinking.choices
+
+    if length < size then
+        shrinking
+    else
+        deleteChunksHelp shrinking generate property size (length - size)
+
+deleteChunksHelp : Shrinking a, (Choices -> { value : a, choices : Choices }), (a -> Bool), Nat, Nat -> Shrinking a
+deleteChunksHelp = \shrinking, generate, property, size, index ->
+    { before, others } = List.split shrinking.choices index
+    next = shrinkTo shrinking generate property (List.concat before (List.drop others size))
+
+    if index == 0 || next.attempts >= maxShrinkAttempts then
+        next
+    else
+        # deleting may have made the choices shorter
+        lastIndex = Num.subSaturated (List.len next.choices) size
+        nextIndex = if index - 1 < lastIndex then index - 1 else lastIndex
+
+        deleteChunksHelp next generate property size nextIndex
+
+# Tries making every choice smaller, starting from the first.
+minimizeChoices : Shrinking a, (Choices -> { value : a, choices : Choices }), (a -> Bool), Nat -> Shrinking a
+minimizeChoices = \shrinking, generate, property, index ->
+    when List.get shrinking.choices index is
+        Err OutOfBounds ->
+            shrinking
+
+        Ok choice ->
+            next =
+                List.walkUntil (smallerChoices choice) shrinking \current, smaller ->
+                    tried = shrinkTo current generate property (List.set current.choices index smaller)
+
+                    if tried.choices == current.choices then
+                        Continue tried
+                    else
+                        Break tried
+
+            if next.attempts >= maxShrinkAttempts then
+                next
+            else
+                minimizeChoices next generate property (index + 1)
+
+smallerChoices : U64 -> List U64
+smallerChoices = \choice ->
+    half = Num.shiftRightZfBy choice 1
+
+    if choice == 0 then
+        []
+    else if half == 0 then
+        [0]
+    else if half == choice - 1 then
+        [0, half]
+    else
+        [0, half, choice - 1]
+
+# Keeps these choices if they are simpler, and still make a value that falsifies the property.
+shrinkTo : Shrinking a, (Choices -> { value : a, choices : Choices }), (a -> Bool), List U64 -> Shrinking a
+shrinkTo = \shrinking, generate, property, candidate ->
+    if isSimpler candidate shrinking.choices then
+        generated = generate (@Choices { drawn: [], replay: candidate, state: 0, budget: 0 })
+        # the choices the generator actually made; any it made past the end of the
+        # candidate are zeros, which replaying makes anyway
+        choices = trimZeros (drawnChoices generated.choices)
+        attempts = shrinking.attempts + 1
+
+        if isSimpler choices shrinking.choices && !(property generated.value) then
+            { choices, value: generated.value, attempts }
+        else
+            { shrinking & attempts: attempts }
+    else
+        shrinking
+
+isSimpler : List U64, List U64 -> Bool
+isSimpler = \a, b ->
+    if List.len a == List.len b then
+        isSimplerHelp a b 0
+    else
+        List.len a < List.len b
+
+isSimplerHelp : List U64, List U64, Nat -> Bool
+isSimplerHelp = \a, b, index ->
+    when List.get a index is
+        Err OutOfBounds ->
+            Bool.false
+
+        Ok x ->
+            when List.get b index is
+                Ok y if x == y -> isSimplerHelp a b (index + 1)
+                Ok y -> x < y
+                Err OutOfBounds -> Bool.false
+
+trimZeros : List U64 -> List U64
+trimZeros = \choices ->
+    when List.last choices is
+        Ok 0 -> trimZeros (List.dropLast choices)
+        _ -> choices
+
+maxShrinkAttempts : Nat
+maxShrinkAttempts = 1000
+
+# The most choices a value may be made from. Past it, every choice is 0, which ends lists and
+# strings and picks the first tag of a tag union, so that even recursive types stay finite.
+maxChoices : Nat
+maxChoices = 4096
+
+# ## Making choices
+#
+# Makes the next choice: replays it if it is being replayed, or makes a random one if there
+# is budget for it, or else chooses 0. Choices are reduced to the range that is asked for
+# before they are recorded, so that shrinking only looks at choices that make a difference.
+draw : Choices, (U64 -> U64) -> { value : U64, choices : Choices }
+draw = \@Choices { drawn, replay, state, budget }, reduce ->
+    index = List.len drawn
+    next =
+        when List.get replay index is
+            Ok choice ->
+                { raw: choice, state }
+
+            Err OutOfBounds ->
+                if index < budget then
+                    nextState = Num.addWrap state 0x9E3779B97F4A7C15
+
+                    { raw: mix nextState, state: nextState }
+                else
+                    { raw: 0, state }
+    value = reduce next.raw
+
+    { value, choices: @Choices { drawn: List.append drawn value, replay, state: next.state, budget } }
+
+# The output function of the SplitMix64 random number generator
+mix : U64 -> U64
+mix = \z ->
+    a = Num.mulWrap (Num.bitwiseXor z (Num.shiftRightZfBy z 30)) 0xBF58476D1CE4E5B9
+    b = Num.mulWrap (Num.bitwiseXor a (Num.shiftRightZfBy a 27)) 0x94D049BB133111EB
+
+    Num.bitwiseXor b (Num.shiftRightZfBy b 31)
+
+drawBelow : Choices, U64 -> { value : U64, choices : Choices }
+drawBelow = \choices, bound ->
+    draw choices \raw -> if bound == 0 then 0 else Num.rem raw bound
+
+drawMasked : Choices, U64 -> { value : U64, choices : Choices }
+drawMasked = \choices, mask ->
+    draw choices \raw -> Num.bitwiseAnd raw mask
+
+drawnChoices : Choices -> List U64
+drawnChoices = \@Choices { drawn } -> drawn
+
+stateOf : Choices -> U64
+stateOf = \@Choices { state } -> state
+
+# One in this many choices of whether a list goes on ends it.
+listContinues : U64
+listContinues = 8
+
+# An unsigned number of at most `width` bits. It first chooses how many bits it uses, and then
+# what they are, so small numbers come up far more often than they would if all numbers were
+# equally likely, and the number of bits shrinks along with the number.
+unsigned : Choices, U64 -> { value : U64, choices : Choices }
+unsigned = \choices, width ->
+    bits = drawBelow choices (width + 1)
+
+    drawMasked bits.choices (lowBits bits.value)
+
+unsignedAs : Choices, U64, (U64 -> a) -> { value : a, choices : Choices }
+unsignedAs = \choices, width, convert ->
+    drawn = unsigned choices width
+
+    { value: convert drawn.value, choices: drawn.choices }
+
+unsigned128 : Choices -> { value : U128, choices : Choices }
+unsigned128 = \choices ->
+    bits = drawBelow choices 129
+
+    if bits.value <= 64 then
+        low = drawMasked bits.choices (lowBits bits.value)
+
+        { value: Num.toU128 low.value, choices: low.choices }
+    else
+        high = drawMasked bits.choices (lowBits (bits.value - 64))
+        low = drawMasked high.choices Num.maxU64
+        value = Num.bitwiseOr (Num.shiftLeftBy (Num.toU128 high.value) 64) (Num.toU128 low.value)
+
+        { value, choices: low.choices }
+
+lowBits : U64 -> U64
+lowBits = \count ->
+    if count >= 64 then
+        Num.maxU64
+    else
+        Num.shiftLeftBy 1 count - 1
+
+# A signed number of at most `width` bits, which alternates between positive and negative
+# numbers as the unsigned number it is made from grows: 0, -1, 1, -2, 2, and so on.
+signedAs : Choices, U64, (I64 -> a) -> { value : a, choices : Choices }
+signedAs = \choices, width, convert ->
+    drawn = unsigned choices width
+    half = Num.toI64 (Num.shiftRightZfBy drawn.value 1)
+    value = if Num.bitwiseAnd drawn.value 1 == 0 then half else Num.neg half - 1
+
+    { value: convert value, choices: drawn.choices }
+
+fraction : Choices -> { value : Frac a, choices : Choices }
+fraction = \choices ->
+    numerator = signedAs choices 53 Num.toFrac
+    exponent = drawBelow numerator.choices 53
+    denominator = Num.toFrac (Num.shiftLeftBy 1 exponent.value)
+
+    { value: numerator.value / denominator, choices: exponent.choices }
+
+# Mostly printable ASCII, starting from `a`, but sometimes any Unicode scalar value.
+scalar : Choices -> { value : U32, choices : Choices }
+scalar = \choices ->
+    kind = drawBelow choices 4
+    drawn =
+        when kind.value is
+            0 -> drawBelow kind.choices 95
+            1 -> drawBelow kind.choices 128
+            2 -> drawBelow kind.choices 0x800
+            # all the scalar values, which are the code points that are not surrogates
+            _ -> drawBelow kind.choices 0x10F800
+    value =
+        when kind.value is
+            0 -> 32 + Num.rem (drawn.value + 65) 95
+            1 | 2 -> drawn.value
+            _ -> if drawn.value < 0xD800 then drawn.value else drawn.value + 0x800
+
+    { value: Num.toU32 value, choices: drawn.choices }
@@ -0,0 +1,102 @@
+interface Hash
+    exposes [
+        Hash,
+        Hasher,
+        hash,
+        addBytes,
+        addU8,
+        addU16,
+        addU32,
+        addU64,
+        addU128,
+        complete,
+        hashBool,
+        hashI8,
+        hashI16,
+        hashI32,
+        hashI64,
+        hashI128,
+        hashNat,
+        hashStr,
+        hashList,
+    ]
+    imports [
+        List,
+        Str,
+    ]
+
+## A value that can be hashed.
+##
+## Hashing a value feeds it into a [Hasher]; it does not produce a hash by itself.
+## The hash is extracted from the hasher with [complete].
+##
+## Records, tag unions, lists, strings, booleans and integers implement [Hash]
+## automatically, as long as everything inside them does too. Opaque types can
+## implement [Hash] themselves, and should feed in exactly the parts of the value
+## that their notion of equality looks at: values that are `==` must hash the same.
+Hash has
+    hash : hasher, a -> hasher | a has Hash, hasher has Hasher
+
+## A hashing algorithm that is fed bytes and integers, and produces a [U64] hash.
+##
+## Hashers are general-purpose; they are not suitable for cryptographic hashing.
+Hasher has
+    addBytes : a, List U8 -> a | a has Hasher
+    addU8 : a, U8 -> a | a has Hasher
+    addU16 : a, U16 -> a | a has Hasher
+    addU32 : a, U32 -> a | a has Hasher
+    addU64 : a, U64 -> a | a has Hasher
+    addU128 : a, U128 -> a | a has Hasher
+    complete : a -> U64 | a has Hasher
+
+## Adds a [Bool] to a hasher.
+hashBool : a, Bool -> a | a has Hasher
+hashBool = \hasher, b ->
+    asU8 = if b then 1 else 0
+
+    addU8 hasher asU8
+
+## Adds an [I8] to a hasher.
+hashI8 : a, I8 -> a | a has Hasher
+hashI8 = \hasher, n -> addU8 hasher (Num.toU8 n)
+
+## Adds an [I16] to a hasher.
+hashI16 : a, I16 -> a | a has Hasher
+hashI16 = \hasher, n -> addU16 hasher (Num.toU16 n)
+
+## Adds an [I32] to a hasher.
+hashI32 : a, I32 -> a | a has Hasher
+hashI32 = \hasher, n -> addU32 hasher (Num.toU32 n)
+
+## Adds an [I64] to a hasher.
+hashI64 : a, I64 -> a | a has Hasher
+hashI64 = \hasher, n -> addU64 hasher (Num.toU64 n)
+
+## Adds an [I128] to a hasher.
+hashI128 : a, I128 -> a | a has Hasher
+hashI128 = \hasher, n -> addU128 hasher (Num.toU128 n)
+
+## Adds a [Nat] to a hasher. A [Nat] is always hashed as a [U64], so the hash
+## does not depend on the target's pointer width.
+hashNat : a, Nat -> a | a has Hasher
+hashNat = \hasher, n -> addU64 hasher (Num.toU64 n)
+
+## Adds the UTF-8 bytes of a [Str] to a hasher, preceded by their length.
+hashStr : a, Str -> a | a has Hasher
+hashStr = \hasher, s ->
+    bytes = Str.toUtf8 s
+
+    hasher
+    |> addU64 (Num.toU64 (List.len bytes))
+    |> addBytes bytes
+
+## Adds every element of a [List] to a hasher, preceded by the list's length.
+##
+## The length keeps nested lists apart: `[[1], [2, 3]]` and `[[1, 2], [3]]`
+## feed different inputs to the hasher.
+hashList : a, List b -> a | a has Hasher, b has Hash
+hashList = \hasher, lst ->
+    List.walk
+        lst
+        (addU64 hasher (Num.toU64 (List.len lst)))
+        (\accumHasher, elem -> hash accumHasher elem)
@@ -59,6 +59,7 @@ Json := {} has [
              string: decodeString,
              list: decodeList,
              record: decodeRecord,
+             tag: decodeTag,
          },
      ]
 
@@ -398,6 +399,12 @@ openBrace = \bytes -> parseExactChar bytes (asciiByte '{')
 closingBrace : List U8 -> DecodeResult {}
 closingBrace = \bytes -> parseExactChar bytes (asciiByte '}')
 
+openBracket : List U8 -> DecodeResult {}
+openBracket = \bytes -> parseExactChar bytes (asciiByte '[')
+
+closingBracket : List U8 -> DecodeResult {}
+closingBracket = \bytes -> parseExactChar bytes (asciiByte ']')
+
 recordKey : List U8 -> DecodeResult Str
 recordKey = \bytes -> jsonString bytes
 
@@ -447,3 +454,41 @@ decodeRecord = \initialState, stepField, finalizer -> Decode.custom \bytes, @Jso
         when finalizer endStateResult is
             Ok val -> { result: Ok val, rest: afterRecordBytes }
             Err e -> { result: Err e, rest: afterRecordBytes }
+
+decodeTag = \initialState, stepPayload, finalizer -> Decode.custom \bytes, @Json {} ->
+        # Idea: decode `{"A": [v1, v2]}` as `A v1 v2`, the inverse of `encodeTag`
+        # NB: the stepper function must be passed explicitly until #2894 is resolved.
+        decodePayload = \stepper, state, name, index, payloadBytes ->
+            when stepper state name index is
+                TooLong ->
+                    { result: Err TooShort, rest: payloadBytes }
+
+                Next decoder ->
+                    { val: newState, rest: beforeCommaOrBreak } <- Decode.decodeWith payloadBytes decoder (@Json {}) |> tryDecode
+                    { result: commaResult, rest: nextBytes } = comma beforeCommaOrBreak
+
+                    when commaResult is
+                        Ok {} -> decodePayload stepPayload newState name (index + 1) nextBytes
+                        Err _ -> { result: Ok newState, rest: nextBytes }
+
+        { rest: afterBraceBytes } <- bytes |> openBrace |> tryDecode
+        { val: name, rest: afterNameBytes } <- jsonString afterBraceBytes |> tryDecode
+        { rest: afterColonBytes } <- colon afterNameBytes |> tryDecode
+        { rest: afterBracketBytes } <- afterColonBytes |> openBracket |> tryDecode
+
+        { val: endState, rest: beforeClosingBracketBytes } <- tryDecode
+                (
+                    when List.first afterBracketBytes is
+                        Ok 93 -> # 93 = ]
+                            { result: Ok initialState, rest: afterBracketBytes }
+
+                        _ ->
+                            decodePayload stepPayload initialState name 0 afterBracketBytes
+                )
+
+        { rest: afterClosingBracketBytes } <- beforeClosingBracketBytes |> closingBracket |> tryDecode
+        { rest: afterTagBytes } <- afterClosingBracketBytes |> closingBrace |> tryDecode
+
+        when finalizer endState name is
+            Ok val -> { result: Ok val, rest: afterTagBytes }
+            Err e -> { result: Err e, rest: afterTagBytes }
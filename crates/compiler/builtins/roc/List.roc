@@ -60,6 +60,7 @@ interface List
         sortAsc,
         sortDesc,
         reserve,
+        isEq,
     ]
     imports [
         Bool.{ Bool },
@@ -351,10 +352,31 @@ join = \lists ->
 
     List.walk lists (List.withCapacity totalLength) (\state, list -> List.concat state list)
 
-contains : List a, a -> Bool
+contains : List a, a -> Bool | a has Eq
 contains = \list, needle ->
     List.any list (\x -> x == needle)
 
+## Returns `Bool.true` if the two lists have the same length, and all their
+## corresponding elements are `==`.
+##
+## This is how `==` compares lists whose elements could have their own [Eq] implementation.
+isEq : List a, List a -> Bool | a has Eq
+isEq = \a, b ->
+    if List.len a == List.len b then
+        isEqHelp a b 0 (List.len a)
+    else
+        Bool.false
+
+isEqHelp : List a, List a, Nat, Nat -> Bool | a has Eq
+isEqHelp = \a, b, index, length ->
+    if index < length then
+        if List.getUnsafe a index == List.getUnsafe b index then
+            isEqHelp a b (index + 1) length
+        else
+            Bool.false
+    else
+        Bool.true
+
 ## Build a value using each element in the list.
 ##
 ## Starting with a given `state` value, this walks through each element in the
@@ -881,11 +903,11 @@ intersperse = \list, sep ->
 ## is considered to "start with" an empty list.
 ##
 ## If the first list is empty, this only returns `Bool.true` if the second list is empty.
-startsWith : List elem, List elem -> Bool
+startsWith : List elem, List elem -> Bool | elem has Eq
 startsWith = \list, prefix ->
     # TODO once we have seamless slices, verify that this wouldn't
     # have better performance with a function like List.compareSublists
-    prefix == List.sublist list { start: 0, len: List.len prefix }
+    List.isEq prefix (List.sublist list { start: 0, len: List.len prefix })
 
 ## Returns `Bool.true` if the first list ends with the second list.
 ##
@@ -893,14 +915,14 @@ startsWith = \list, prefix ->
 ## is considered to "end with" an empty list.
 ##
 ## If the first list is empty, this only returns `Bool.true` if the second list is empty.
-endsWith : List elem, List elem -> Bool
+endsWith : List elem, List elem -> Bool | elem has Eq
 endsWith = \list, suffix ->
     # TODO once we have seamless slices, verify that this wouldn't
     # have better performance with a function like List.compareSublists
     length = List.len suffix
     start = Num.subSaturated (List.len list) length
 
-    suffix == List.sublist list { start, len: length }
+    List.isEq suffix (List.sublist list { start, len: length })
 
 ## Splits the list into two lists, around the given index.
 ##
@@ -922,7 +944,7 @@ split = \elements, userSplitIndex ->
 ## remaining elements after that occurrence. If the delimiter is not found, returns `Err`.
 ##
 ##     List.splitFirst [Foo, Z, Bar, Z, Baz] Z == Ok { before: [Foo], after: [Bar, Baz] }
-splitFirst : List elem, elem -> Result { before : List elem, after : List elem } [NotFound]*
+splitFirst : List elem, elem -> Result { before : List elem, after : List elem } [NotFound]* | elem has Eq
 splitFirst = \list, delimiter ->
     when List.findFirstIndex list (\elem -> elem == delimiter) is
         Ok index ->
@@ -937,7 +959,7 @@ splitFirst = \list, delimiter ->
 ## remaining elements after that occurrence. If the delimiter is not found, returns `Err`.
 ##
 ##     List.splitLast [Foo, Z, Bar, Z, Baz] Z == Ok { before: [Foo, Bar], after: [Baz] }
-splitLast : List elem, elem -> Result { before : List elem, after : List elem } [NotFound]*
+splitLast : List elem, elem -> Result { before : List elem, after : List elem } [NotFound]* | elem has Eq
 splitLast = \list, delimiter ->
     when List.findLastIndex list (\elem -> elem == delimiter) is
         Ok index ->
@@ -48,6 +48,22 @@ interface Num
         atan,
         acos,
         asin,
+        atan2,
+        sinh,
+        cosh,
+        tanh,
+        exp,
+        exp2,
+        log2,
+        log10,
+        hypot,
+        fma,
+        copySign,
+        trunc,
+        isNaN,
+        isInfinite,
+        e,
+        pi,
         isZero,
         isEven,
         isOdd,
@@ -77,6 +93,12 @@ interface Num
         shiftLeftBy,
         shiftRightBy,
         shiftRightZfBy,
+        rotateLeftBy,
+        rotateRightBy,
+        countLeadingZeroBits,
+        countTrailingZeroBits,
+        countOneBits,
+        swapBytes,
         subWrap,
         subChecked,
         subSaturated,
@@ -86,11 +108,22 @@ interface Num
         intCast,
         bytesToU16,
         bytesToU32,
+        toBytesLe,
+        toBytesBe,
+        fromBytesLe,
+        fromBytesBe,
         divCeil,
         divCeilChecked,
         divTrunc,
         divTruncChecked,
         toStr,
+        toStrFixed,
+        roundDecTo,
+        roundDecToChecked,
+        toScaledI128,
+        toScaledI128Checked,
+        fromScaledI128,
+        fromScaledI128Checked,
         isMultipleOf,
         minI8,
         maxI8,
@@ -507,6 +540,110 @@ Dec : Num (FloatingPoint Decimal)
 ##
 ## To get strings in hexadecimal, octal, or binary format, use `Num.format`.
 toStr : Num * -> Str
+
+## Convert a [Dec] to a [Str] with exactly the given number of digits after
+## the decimal point, padding with zeros or rounding half-even as needed.
+##
+## Unlike [toStr], which drops trailing zeros, this keeps the precision you ask
+## for, which is useful for amounts of money and other fixed-precision values.
+##
+## >>> Num.toStrFixed 1.5dec 2 == "1.50"
+##
+## >>> Num.toStrFixed 2.675dec 2 == "2.68"
+##
+## >>> Num.toStrFixed 41.5dec 0 == "42"
+toStrFixed : Dec, U8 -> Str
+
+## Round a [Dec] to the given number of decimal places, using one of these modes:
+## * `HalfEven` rounds halfway cases to the nearest even digit (so-called "banker's rounding"), so 2.345 becomes 2.34 and 2.355 becomes 2.36.
+## * `HalfUp` rounds halfway cases away from zero, so 2.345 becomes 2.35 and -2.345 becomes -2.35.
+## * `TowardZero` drops the extra digits, so 2.349 becomes 2.34.
+##
+## A [Dec] has 18 decimal places, so asking for 18 or more returns the number unchanged.
+##
+## This crashes if rounding goes past the highest or lowest number a [Dec] can
+## hold. Use [roundDecToChecked] to handle that case instead.
+##
+## >>> Num.roundDecTo 2.345dec 2 HalfEven
+roundDecTo : Dec, U8, [HalfEven, HalfUp, TowardZero] -> Dec
+roundDecTo = \dec, places, mode ->
+    when roundDecToChecked dec places mode is
+        Ok rounded -> rounded
+        Err Overflow -> crash "Decimal rounding overflowed!"
+
+## Round a [Dec] to the given number of decimal places like [roundDecTo], but
+## return `Err Overflow` if the answer doesn't fit in a [Dec].
+roundDecToChecked : Dec, U8, [HalfEven, HalfUp, TowardZero] -> Result Dec [Overflow]*
+roundDecToChecked = \dec, places, mode ->
+    # These must match the RoundingMode enum in dec.zig
+    modeCode =
+        when mode is
+            HalfEven -> 0
+            HalfUp -> 1
+            TowardZero -> 2
+
+    result = roundDecToLowlevel dec places modeCode
+
+    if result.b then
+        Err Overflow
+    else
+        Ok result.a
+
+roundDecToLowlevel : Dec, U8, U8 -> { b : Bool, a : Dec }
+
+## Convert a [Dec] to an integer that counts units of the given number of decimal
+## places. For example, with 2 places the answer is a number of hundredths, like cents.
+##
+## Digits beyond the requested places are dropped, rounding toward zero. To round
+## another way, call [roundDecTo] first.
+##
+## This crashes if the answer doesn't fit in an [I128], which can only happen
+## when asking for more than 18 places. Use [toScaledI128Checked] to handle that
+## case instead.
+##
+## >>> Num.toScaledI128 12.345dec 2 == 1234
+toScaledI128 : Dec, U8 -> I128
+toScaledI128 = \dec, places ->
+    when toScaledI128Checked dec places is
+        Ok scaled -> scaled
+        Err Overflow -> crash "Decimal conversion to a scaled integer overflowed!"
+
+toScaledI128Checked : Dec, U8 -> Result I128 [Overflow]*
+toScaledI128Checked = \dec, places ->
+    result = toScaledI128Lowlevel dec places
+
+    if result.b then
+        Err Overflow
+    else
+        Ok result.a
+
+toScaledI128Lowlevel : Dec, U8 -> { b : Bool, a : I128 }
+
+## Convert an integer that counts units of the given number of decimal places
+## into a [Dec]. This is the opposite of [toScaledI128].
+##
+## This crashes if the answer doesn't fit in a [Dec], which can happen when
+## asking for fewer than 18 places. Use [fromScaledI128Checked] to handle that
+## case instead.
+##
+## >>> Num.fromScaledI128 1234 2 == 12.34dec
+fromScaledI128 : I128, U8 -> Dec
+fromScaledI128 = \scaled, places ->
+    when fromScaledI128Checked scaled places is
+        Ok dec -> dec
+        Err Overflow -> crash "Decimal conversion from a scaled integer overflowed!"
+
+fromScaledI128Checked : I128, U8 -> Result Dec [Overflow]*
+fromScaledI128Checked = \scaled, places ->
+    result = fromScaledI128Lowlevel scaled places
+
+    if result.b then
+        Err Overflow
+    else
+        Ok result.a
+
+fromScaledI128Lowlevel : I128, U8 -> { b : Bool, a : Dec }
+
 intCast : Int a -> Int b
 
 bytesToU16Lowlevel : List U8, Nat -> U16
@@ -532,6 +669,79 @@ bytesToU32 = \bytes, index ->
     else
         Err OutOfBounds
 
+## The bytes of an integer, least significant byte first.
+##
+## >>> Num.toBytesLe 0x1234u16 == [0x34, 0x12]
+toBytesLe : Int * -> List U8
+toBytesLe = \n ->
+    byteCount = byteWidth n
+
+    toBytesLeHelp (List.withCapacity byteCount) n byteCount
+
+toBytesLeHelp : List U8, Int *, Nat -> List U8
+toBytesLeHelp = \bytes, n, remaining ->
+    withByte = List.appendUnsafe bytes (toU8 n)
+
+    if remaining <= 1 then
+        withByte
+    else
+        toBytesLeHelp withByte (shiftRightZfBy n 8) (remaining - 1)
+
+## The bytes of an integer, most significant byte first.
+##
+## >>> Num.toBytesBe 0x1234u16 == [0x12, 0x34]
+toBytesBe : Int * -> List U8
+toBytesBe = \n -> toBytesLe (swapBytes n)
+
+## Reads an integer from the bytes starting at the given index, least
+## significant byte first. As many bytes are read as the integer type has.
+##
+## >>> Num.fromBytesLe [0x34, 0x12] 0 == Ok 0x1234u16
+fromBytesLe : List U8, Nat -> Result (Int a) [OutOfBounds]
+fromBytesLe = \bytes, index ->
+    # the type of this `0` is the integer type being read,
+    # which tells us how many bytes we need
+    fromBytesLeHelp bytes index 0
+
+fromBytesLeHelp : List U8, Nat, Int a -> Result (Int a) [OutOfBounds]
+fromBytesLeHelp = \bytes, index, zero ->
+    byteCount = byteWidth zero
+
+    if index + byteCount <= List.len bytes then
+        Ok (fromBytesLeUnsafe bytes index 0 byteCount zero)
+    else
+        Err OutOfBounds
+
+fromBytesLeUnsafe : List U8, Nat, Nat, Nat, Int a -> Int a
+fromBytesLeUnsafe = \bytes, index, offset, byteCount, answer ->
+    if offset < byteCount then
+        # casting to a signed type may sign-extend the byte, so shift it all the
+        # way up to drop those bits, and then down into place with zeros shifted in
+        byte =
+            intCast (List.getUnsafe bytes (index + offset))
+            |> shiftLeftBy (intCast ((byteCount - 1) * 8))
+            |> shiftRightZfBy (intCast ((byteCount - 1 - offset) * 8))
+
+        fromBytesLeUnsafe bytes index (offset + 1) byteCount (bitwiseOr answer byte)
+    else
+        answer
+
+## Reads an integer from the bytes starting at the given index, most
+## significant byte first. As many bytes are read as the integer type has.
+##
+## >>> Num.fromBytesBe [0x12, 0x34] 0 == Ok 0x1234u16
+fromBytesBe : List U8, Nat -> Result (Int a) [OutOfBounds]
+fromBytesBe = \bytes, index ->
+    when fromBytesLe bytes index is
+        Ok n -> Ok (swapBytes n)
+        Err OutOfBounds -> Err OutOfBounds
+
+## The number of bytes in the type of the given integer
+byteWidth : Int * -> Nat
+byteWidth = \n ->
+    # only the type of `n` matters; the bits of 0 are all leading zeros
+    toNat (countLeadingZeroBits (bitwiseXor n n)) // 8
+
 compare : Num a, Num a -> [LT, EQ, GT]
 
 ## Returns `Bool.true` if the first number is less than the second.
@@ -717,6 +927,81 @@ asin : Frac a -> Frac a
 acos : Frac a -> Frac a
 atan : Frac a -> Frac a
 
+## Returns the angle, in radians, between the positive x-axis and the point
+## `(x, y)`. Note that the `y` coordinate comes first, as in C's `atan2`.
+##
+## Unlike `Num.atan (y / x)`, this takes the signs of both arguments into account
+## and so returns the angle in the correct quadrant, from -π to π.
+##
+## >>> Num.atan2 1.0 -1.0
+## == 2.356194490192345
+atan2 : Frac a, Frac a -> Frac a
+
+sinh : Frac a -> Frac a
+cosh : Frac a -> Frac a
+tanh : Frac a -> Frac a
+
+## Raises [e] to the power of the given [Frac].
+##
+## For [F64] and [F32], passing -∞ returns 0, passing ∞ returns ∞, and
+## passing [*NaN*](Num.isNaN) returns [*NaN*](Num.isNaN). Answers too large
+## for the type overflow to ∞.
+##
+## >>> Num.exp 1.0
+## == 2.718281828459045
+exp : Frac a -> Frac a
+
+## Raises 2 to the power of the given [Frac].
+##
+## >>> Num.exp2 10.0
+## == 1024.0
+exp2 : Frac a -> Frac a
+
+## Returns the base 2 logarithm of a [Frac].
+##
+## Like [log], passing zero to an [F64] or [F32] returns -∞, and passing a
+## negative number returns [*NaN*](Num.isNaN).
+##
+## >>> Num.log2 1024.0
+## == 10.0
+log2 : Frac a -> Frac a
+
+## Returns the base 10 logarithm of a [Frac].
+##
+## Like [log], passing zero to an [F64] or [F32] returns -∞, and passing a
+## negative number returns [*NaN*](Num.isNaN).
+##
+## >>> Num.log10 1000.0
+## == 3.0
+log10 : Frac a -> Frac a
+
+## Returns the length of the hypotenuse of a right triangle with sides of the
+## given lengths, that is `sqrt (x * x + y * y)`, without overflowing or
+## underflowing in the intermediate steps.
+##
+## If either argument is ∞ or -∞, the answer is ∞, even if the other
+## argument is [*NaN*](Num.isNaN).
+##
+## >>> Num.hypot 3.0 4.0
+## == 5.0
+hypot : Frac a, Frac a -> Frac a
+
+## Computes `(a * b) + c` with a single rounding step at the end, which is
+## both faster and more accurate than a separate [mul] and [add] on hardware
+## that supports it.
+##
+## >>> Num.fma 2.0 3.0 1.0
+## == 7.0
+fma : Frac a, Frac a, Frac a -> Frac a
+
+## Returns a [Frac] with the magnitude of the first argument and the sign of
+## the second. This also works on zero, ∞ and [*NaN*](Num.isNaN), so
+## `Num.copySign 0.0 -1.0` is -0.0.
+##
+## >>> Num.copySign 3.0 -1.0
+## == -3.0
+copySign : Frac a, Frac a -> Frac a
+
 ## Returns an approximation of the absolute value of a [Frac]'s square root.
 ##
 ## The square root of a negative number is an irrational number, and [Frac] only
@@ -897,11 +1182,71 @@ shiftRightBy : Int a, Int a -> Int a
 ## In some languages `shiftRightBy` is implemented as a binary operator `>>`.
 shiftRightZfBy : Int a, Int a -> Int a
 
+## Bitwise rotation of a number to the left by another
+##
+## The bits shifted out of the most significant end come back in at the least
+## significant end. The amount is taken modulo the number of bits in the type.
+##
+## >>> rotateLeftBy 0b1000_0001u8 1 == 0b0000_0011
+##
+## >>> 0b0110_0000u8 |> rotateLeftBy 9 == 0b1100_0000
+rotateLeftBy : Int a, Int a -> Int a
+
+## Bitwise rotation of a number to the right by another
+##
+## The bits shifted out of the least significant end come back in at the most
+## significant end. The amount is taken modulo the number of bits in the type.
+##
+## >>> rotateRightBy 0b1000_0001u8 1 == 0b1100_0000
+##
+## >>> 0b0000_0110u8 |> rotateRightBy 9 == 0b0000_0011
+rotateRightBy : Int a, Int a -> Int a
+
+## The number of zero bits before the most significant one bit.
+##
+## This is the number of bits in the type when given 0.
+##
+## >>> countLeadingZeroBits 0b0001_1100u8 == 3
+##
+## >>> countLeadingZeroBits 0u32 == 32
+countLeadingZeroBits : Int * -> U8
+
+## The number of zero bits after the least significant one bit.
+##
+## This is the number of bits in the type when given 0.
+##
+## >>> countTrailingZeroBits 0b0001_1100u8 == 2
+##
+## >>> countTrailingZeroBits 0u32 == 32
+countTrailingZeroBits : Int * -> U8
+
+## The number of one bits in a number.
+##
+## >>> countOneBits 0b0001_1100u8 == 3
+##
+## >>> countOneBits -1i16 == 16
+countOneBits : Int * -> U8
+
+## Reverses the order of the bytes in a number, which converts it between
+## little-endian and big-endian.
+##
+## >>> swapBytes 0x1234u16 == 0x3412
+swapBytes : Int a -> Int a
+
 ## Round off the given fraction to the nearest integer.
 round : Frac * -> Int *
 floor : Frac * -> Int *
 ceiling : Frac * -> Int *
 
+## Drops the fractional part of the given [Frac], rounding it towards zero.
+##
+## >>> Num.trunc 2.7
+## == 2
+##
+## >>> Num.trunc -2.7
+## == -2
+trunc : Frac * -> Int *
+
 ## Raises a [Frac] to the power of another [Frac].
 ##
 ## For an [Int] alternative to this function, see [Num.powInt]
@@ -913,15 +1258,19 @@ pow : Frac a, Frac a -> Frac a
 ## This process is known as [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring).
 ##
 ## For a [Frac] alternative to this function, which supports negative exponents,
-## see #Num.exp.
+## see [Num.pow].
 ##
-## >>> Num.exp 5 0
+## >>> Num.powInt 5 0
+## == 1
 ##
-## >>> Num.exp 5 1
+## >>> Num.powInt 5 1
+## == 5
 ##
-## >>> Num.exp 5 2
+## >>> Num.powInt 5 2
+## == 25
 ##
-## >>> Num.exp 5 6
+## >>> Num.powInt 5 6
+## == 15625
 ##
 ## ## Performance Notes
 ##
@@ -1209,6 +1558,16 @@ minF64 = -1.7976931348623157e308
 maxF64 : F64
 maxF64 = 1.7976931348623157e308
 
+## Euler's number, the base of the natural logarithm, as precisely as the
+## [Frac] type can represent it.
+e : Frac *
+e = 2.718281828459045
+
+## The ratio of a circle's circumference to its diameter, as precisely as the
+## [Frac] type can represent it.
+pi : Frac *
+pi = 3.141592653589793
+
 ## Converts an [Int] to an [I8]. If the given number can't be precisely represented in an [I8],
 ## the returned number may be different from the given number.
 toI8 : Int * -> I8
@@ -1280,15 +1639,18 @@ toF64Checked : Num * -> Result F64 [OutOfBounds]*
 ##
 ## This is the opposite of #isFinite, except when given [*NaN*](Num.isNaN). Both
 ## #isFinite and #isInfinite return `Bool.false` for [*NaN*](Num.isNaN).
-# isInfinite : Frac * -> Bool
+isInfinite : Frac * -> Bool
+
 ## When given a [F64] or [F32] value, returns `Bool.true` if that value is
 ## *NaN* ([not a number](https://en.wikipedia.org/wiki/NaN)), and `Bool.false` otherwise.
 ##
 ## Always returns `Bool.false` when given a [Dec].
 ##
 ## >>> Num.isNaN 12.3
+## == Bool.false
 ##
 ## >>> Num.isNaN (Num.pow -1 0.5)
+## == Bool.true
 ##
 ## *NaN* is unusual from other numberic values in that:
 ## * *NaN* is not equal to any other number, even itself. [Bool.isEq] always returns `Bool.false` if either argument is *NaN*.
@@ -1303,7 +1665,8 @@ toF64Checked : Num * -> Result F64 [OutOfBounds]*
 ## Note that you should never put a *NaN* into a [Set], or use it as the key in
 ## a [Dict]. The result is entries that can never be removed from those
 ## collections! See the documentation for [Set.insert] and [Dict.insert] for details.
-# isNaN : Frac * -> Bool
+isNaN : Frac * -> Bool
+
 ## Returns the higher of two numbers.
 ##
 ## If either argument is [*NaN*](Num.isNaN), returns `Bool.false` no matter what. (*NaN*
@@ -14,9 +14,12 @@ interface Set
         intersection,
         difference,
     ]
-    imports [List, Bool.{ Bool }, Dict.{ Dict }]
+    imports [List, Bool.{ Bool }, Dict.{ Dict }, Hash.{ Hash }]
 
-Set k := Dict.Dict k {}
+Set k := Dict.Dict k {} has [Eq { isEq: setIsEq }]
+
+setIsEq : Set k, Set k -> Bool | k has Eq
+setIsEq = \@Set a, @Set b -> a == b
 
 fromDict : Dict k {} -> Set k
 fromDict = \dict -> @Set dict
@@ -28,14 +31,14 @@ toDict = \@Set dict -> dict
 empty : Set k
 empty = fromDict Dict.empty
 
-single : k -> Set k
+single : k -> Set k | k has Hash & Eq
 single = \key ->
     @Set (Dict.single key {})
 
 ## Make sure never to insert a *NaN* to a [Set]! Because *NaN* is defined to be
 ## unequal to *NaN*, adding a *NaN* results in an entry that can never be
 ## retrieved or removed from the [Set].
-insert : Set k, k -> Set k
+insert : Set k, k -> Set k | k has Hash & Eq
 insert = \@Set dict, key ->
     dict
     |> Dict.insert key {}
@@ -75,11 +78,11 @@ expect
     actual == 3
 
 ## Drops the given element from the set.
-remove : Set k, k -> Set k
+remove : Set k, k -> Set k | k has Hash & Eq
 remove = \@Set dict, key ->
     @Set (Dict.remove dict key)
 
-contains : Set k, k -> Bool
+contains : Set k, k -> Bool | k has Hash & Eq
 contains = \set, key ->
     set
     |> Set.toDict
@@ -89,21 +92,21 @@ toList : Set k -> List k
 toList = \@Set dict ->
     Dict.keys dict
 
-fromList : List k -> Set k
+fromList : List k -> Set k | k has Hash & Eq
 fromList = \list ->
     initial = @Set (Dict.withCapacity (List.len list))
 
     List.walk list initial \set, key -> Set.insert set key
 
-union : Set k, Set k -> Set k
+union : Set k, Set k -> Set k | k has Hash & Eq
 union = \@Set dict1, @Set dict2 ->
     @Set (Dict.insertAll dict1 dict2)
 
-intersection : Set k, Set k -> Set k
+intersection : Set k, Set k -> Set k | k has Hash & Eq
 intersection = \@Set dict1, @Set dict2 ->
     @Set (Dict.keepShared dict1 dict2)
 
-difference : Set k, Set k -> Set k
+difference : Set k, Set k -> Set k | k has Hash & Eq
 difference = \@Set dict1, @Set dict2 ->
     @Set (Dict.removeAll dict1 dict2)
 
@@ -39,10 +39,20 @@ interface Str
         splitFirst,
         splitLast,
         walkUtf8WithIndex,
+        walkUtf8,
         reserve,
         appendScalar,
         walkScalars,
         walkScalarsUntil,
+        graphemes,
+        splitAt,
+        toUpper,
+        toLower,
+        toNfc,
+        toNfd,
+        contains,
+        indexOf,
+        sliceUtf8,
     ]
     imports [Bool.{ Bool }, Result.{ Result }, List]
 
@@ -166,6 +176,60 @@ repeat : Str, Nat -> Str
 ##     Str.countGraphemes "üïä"     # 1
 countGraphemes : Str -> Nat
 
+## Split a string into its [extended grapheme clusters](http://www.unicode.org/glossary/#extended_grapheme_cluster).
+##
+##     Str.graphemes "Roc!"     # ["R", "o", "c", "!"]
+##     Str.graphemes "e\u(301)🕊" # ["é", "🕊"]
+graphemes : Str -> List Str
+
+expect Str.graphemes "" == []
+expect Str.graphemes "Roc" == ["R", "o", "c"]
+
+## Split a string into the graphemes before the given grapheme index, and the
+## graphemes from that index onwards. If the index is past the end of the
+## string, everything ends up in `before`.
+##
+##     Str.splitAt "Roc👩‍👩‍👦‍👦!" 3 == { before: "Roc", after: "👩‍👩‍👦‍👦!" }
+splitAt : Str, Nat -> { before : Str, after : Str }
+splitAt = \string, graphemeIndex ->
+    byteIndex = graphemeByteOffsetLowlevel string graphemeIndex
+    before = Str.substringUnsafe string 0 byteIndex
+    after = Str.substringUnsafe string byteIndex (Str.countUtf8Bytes string - byteIndex)
+
+    { before, after }
+
+expect Str.splitAt "hello" 2 == { before: "he", after: "llo" }
+expect Str.splitAt "hello" 10 == { before: "hello", after: "" }
+
+graphemeByteOffsetLowlevel : Str, Nat -> Nat
+
+## Convert every character in the string to uppercase, using the full Unicode
+## case mappings. This can change the length of the string.
+##
+##     Str.toUpper "Straße" == "STRASSE"
+toUpper : Str -> Str
+
+## Convert every character in the string to lowercase, using the full Unicode
+## case mappings. A capital sigma at the end of a word becomes a final sigma.
+##
+##     Str.toLower "ΟΔΟΣ" == "οδος"
+toLower : Str -> Str
+
+## Convert the string to Unicode [Normalization Form C](https://unicode.org/reports/tr15/#Norm_Forms),
+## where characters are canonically decomposed and then recomposed.
+##
+## Strings which look the same can be made of different code points; normalizing
+## both to the same form first makes comparing them meaningful.
+##
+##     Str.toNfc "e\u(301)" == "é"
+toNfc : Str -> Str
+
+## Convert the string to Unicode [Normalization Form D](https://unicode.org/reports/tr15/#Norm_Forms),
+## where characters are canonically decomposed.
+##
+##     Str.toNfd "é" == "e\u(301)"
+toNfd : Str -> Str
+
 ## If the string begins with a [Unicode code point](http://www.unicode.org/glossary/#code_point)
 ## equal to the given [U32], return `Bool.true`. Otherwise return `Bool.false`.
 ##
@@ -279,6 +343,37 @@ countUtf8Bytes : Str -> Nat
 ## string slice that does not do bounds checking or utf-8 verification
 substringUnsafe : Str, Nat, Nat -> Str
 
+## Returns the part of the string made of `count` bytes, starting at the byte index `start`.
+## Big strings are not copied; the result shares the original string's memory.
+##
+## Returns `Err OutOfBounds` if the range goes past the end of the string, and
+## `Err NotCharBoundary` if either end of the range is in the middle of a character.
+##
+##     Str.sliceUtf8 "Roc!" { start: 1, count: 2 } == Ok "oc"
+sliceUtf8 : Str, { start : Nat, count : Nat } -> Result Str [OutOfBounds, NotCharBoundary]*
+sliceUtf8 = \string, { start, count } ->
+    length = Str.countUtf8Bytes string
+
+    if start > length || count > length - start then
+        Err OutOfBounds
+    else if isCharBoundary string start && isCharBoundary string (start + count) then
+        Ok (Str.substringUnsafe string start count)
+    else
+        Err NotCharBoundary
+
+expect Str.sliceUtf8 "hello" { start: 1, count: 3 } == Ok "ell"
+expect Str.sliceUtf8 "hello" { start: 5, count: 0 } == Ok ""
+expect Str.sliceUtf8 "hello" { start: 4, count: 2 } == Err OutOfBounds
+expect Str.sliceUtf8 "héllo" { start: 0, count: 2 } == Err NotCharBoundary
+
+# UTF-8 continuation bytes all look like 0b10xx_xxxx
+isCharBoundary : Str, Nat -> Bool
+isCharBoundary = \string, index ->
+    if index == Str.countUtf8Bytes string then
+        Bool.true
+    else
+        Num.bitwiseAnd (Str.getUnsafe string index) 0b1100_0000 != 0b1000_0000
+
 ## Returns the string with each occurrence of a substring replaced with a replacement.
 ## If the substring is not found, returns `Err NotFound`.
 ##
@@ -389,6 +484,33 @@ firstMatchHelp = \haystack, needle, index, lastPossible ->
     else
         None
 
+## Returns `Bool.true` if the first string contains the second one.
+## Every string contains the empty string.
+##
+##     Str.contains "hullabaloo" "lab" == Bool.true
+contains : Str, Str -> Bool
+contains = \haystack, needle ->
+    when firstMatch haystack needle is
+        Some _ -> Bool.true
+        None -> Bool.false
+
+expect Str.contains "hullabaloo" "lab"
+expect Str.contains "hullabaloo" "bulb" == Bool.false
+expect Str.contains "" ""
+
+## Returns the byte index of the first occurrence of a substring, or `Err NotFound`
+## if the substring does not occur in the string.
+##
+##     Str.indexOf "foo/bar/baz" "/" == Ok 3
+indexOf : Str, Str -> Result Nat [NotFound]*
+indexOf = \haystack, needle ->
+    when firstMatch haystack needle is
+        Some index -> Ok index
+        None -> Err NotFound
+
+expect Str.indexOf "foo/bar/baz" "/" == Ok 3
+expect Str.indexOf "foo" "z" == Err NotFound
+
 ## Returns the string before the last occurrence of a delimiter, as well as the
 ## rest of the string after that occurrence. If the delimiter is not found, returns `Err`.
 ##
@@ -494,6 +616,26 @@ walkUtf8WithIndexHelp = \string, state, step, index, length ->
     else
         state
 
+## Walks over the string's UTF-8 bytes, calling a function which updates a state using each
+## UTF-8 `U8` byte.
+##
+##     Str.walkUtf8 "abc" 0 (\total, byte -> total + Num.toNat byte) == 294
+walkUtf8 : Str, state, (state, U8 -> state) -> state
+walkUtf8 = \string, state, step ->
+    walkUtf8Help string state step 0 (Str.countUtf8Bytes string)
+
+walkUtf8Help : Str, state, (state, U8 -> state), Nat, Nat -> state
+walkUtf8Help = \string, state, step, index, length ->
+    if index < length then
+        byte = Str.getUnsafe string index
+        newState = step state byte
+
+        walkUtf8Help string newState step (index + 1) length
+    else
+        state
+
+expect Str.walkUtf8 "abc" [] List.append == [97, 98, 99]
+
 ## Make sure at least some number of bytes fit in this string without reallocating
 reserve : Str, Nat -> Str
 
@@ -300,14 +300,37 @@ pub const NUM_COS: IntrinsicName = float_intrinsic!("roc_builtins.num.cos");
 pub const NUM_ASIN: IntrinsicName = float_intrinsic!("roc_builtins.num.asin");
 pub const NUM_ACOS: IntrinsicName = float_intrinsic!("roc_builtins.num.acos");
 pub const NUM_ATAN: IntrinsicName = float_intrinsic!("roc_builtins.num.atan");
+pub const NUM_ATAN2: IntrinsicName = float_intrinsic!("roc_builtins.num.atan2");
+pub const NUM_SINH: IntrinsicName = float_intrinsic!("roc_builtins.num.sinh");
+pub const NUM_COSH: IntrinsicName = float_intrinsic!("roc_builtins.num.cosh");
+pub const NUM_TANH: IntrinsicName = float_intrinsic!("roc_builtins.num.tanh");
 pub const NUM_IS_FINITE: IntrinsicName = float_intrinsic!("roc_builtins.num.is_finite");
+pub const NUM_IS_NAN: IntrinsicName = float_intrinsic!("roc_builtins.num.is_nan");
+pub const NUM_IS_INFINITE: IntrinsicName = float_intrinsic!("roc_builtins.num.is_infinite");
 pub const NUM_LOG: IntrinsicName = float_intrinsic!("roc_builtins.num.log");
+pub const NUM_LOG2: IntrinsicName = float_intrinsic!("roc_builtins.num.log2");
+pub const NUM_LOG10: IntrinsicName = float_intrinsic!("roc_builtins.num.log10");
+pub const NUM_EXP: IntrinsicName = float_intrinsic!("roc_builtins.num.exp");
+pub const NUM_EXP2: IntrinsicName = float_intrinsic!("roc_builtins.num.exp2");
 pub const NUM_POW: IntrinsicName = float_intrinsic!("roc_builtins.num.pow");
+pub const NUM_HYPOT: IntrinsicName = float_intrinsic!("roc_builtins.num.hypot");
+pub const NUM_FMA: IntrinsicName = float_intrinsic!("roc_builtins.num.fma");
+pub const NUM_COPY_SIGN: IntrinsicName = float_intrinsic!("roc_builtins.num.copysign");
 
 pub const NUM_POW_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.pow_int");
 pub const NUM_DIV_CEIL: IntrinsicName = int_intrinsic!("roc_builtins.num.div_ceil");
 pub const NUM_ROUND_F32: IntrinsicName = int_intrinsic!("roc_builtins.num.round_f32");
 pub const NUM_ROUND_F64: IntrinsicName = int_intrinsic!("roc_builtins.num.round_f64");
+pub const NUM_TRUNC_F32: IntrinsicName = int_intrinsic!("roc_builtins.num.trunc_f32");
+pub const NUM_TRUNC_F64: IntrinsicName = int_intrinsic!("roc_builtins.num.trunc_f64");
+pub const NUM_COUNT_LEADING_ZERO_BITS: IntrinsicName =
+    int_intrinsic!("roc_builtins.num.count_leading_zero_bits");
+pub const NUM_COUNT_TRAILING_ZERO_BITS: IntrinsicName =
+    int_intrinsic!("roc_builtins.num.count_trailing_zero_bits");
+pub const NUM_COUNT_ONE_BITS: IntrinsicName = int_intrinsic!("roc_builtins.num.count_one_bits");
+pub const NUM_ROTATE_LEFT_BY: IntrinsicName = int_intrinsic!("roc_builtins.num.rotate_left_by");
+pub const NUM_ROTATE_RIGHT_BY: IntrinsicName = int_intrinsic!("roc_builtins.num.rotate_right_by");
+pub const NUM_SWAP_BYTES: IntrinsicName = int_intrinsic!("roc_builtins.num.swap_bytes");
 
 pub const NUM_ADD_OR_PANIC_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_or_panic");
 pub const NUM_ADD_SATURATED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_saturated");
@@ -351,6 +374,7 @@ pub const STR_TO_DECIMAL: &str = "roc_builtins.str.to_decimal";
 pub const STR_EQUAL: &str = "roc_builtins.str.equal";
 pub const STR_SUBSTRING_UNSAFE: &str = "roc_builtins.str.substring_unsafe";
 pub const STR_TO_UTF8: &str = "roc_builtins.str.to_utf8";
+pub const STR_CRASH: &str = "roc_builtins.str.crash";
 pub const STR_FROM_UTF8_RANGE: &str = "roc_builtins.str.from_utf8_range";
 pub const STR_REPEAT: &str = "roc_builtins.str.repeat";
 pub const STR_TRIM: &str = "roc_builtins.str.trim";
@@ -361,6 +385,16 @@ pub const STR_RESERVE: &str = "roc_builtins.str.reserve";
 pub const STR_APPEND_SCALAR: &str = "roc_builtins.str.append_scalar";
 pub const STR_GET_SCALAR_UNSAFE: &str = "roc_builtins.str.get_scalar_unsafe";
 pub const STR_CLONE_TO: &str = "roc_builtins.str.clone_to";
+pub const STR_GRAPHEMES: &str = "roc_builtins.str.graphemes";
+pub const STR_GRAPHEME_BYTE_OFFSET: &str = "roc_builtins.str.grapheme_byte_offset";
+pub const STR_TO_UPPER: &str = "roc_builtins.str.to_upper";
+pub const STR_TO_LOWER: &str = "roc_builtins.str.to_lower";
+pub const STR_TO_NFC: &str = "roc_builtins.str.to_nfc";
+pub const STR_TO_NFD: &str = "roc_builtins.str.to_nfd";
+
+pub const DICT_HASH_STR: &str = "roc_builtins.dict.hash_str";
+pub const DICT_HASH_INT: IntrinsicName = int_intrinsic!("roc_builtins.dict.hash_int");
+pub const DICT_HASH_FLOAT: IntrinsicName = float_intrinsic!("roc_builtins.dict.hash_float");
 
 pub const LIST_MAP: &str = "roc_builtins.list.map";
 pub const LIST_MAP2: &str = "roc_builtins.list.map2";
@@ -378,6 +412,7 @@ pub const LIST_IS_UNIQUE: &str = "roc_builtins.list.is_unique";
 pub const LIST_PREPEND: &str = "roc_builtins.list.prepend";
 pub const LIST_APPEND_UNSAFE: &str = "roc_builtins.list.append_unsafe";
 pub const LIST_RESERVE: &str = "roc_builtins.list.reserve";
+pub const LIST_CAPACITY: &str = "roc_builtins.list.capacity";
 
 pub const DEC_FROM_STR: &str = "roc_builtins.dec.from_str";
 pub const DEC_TO_STR: &str = "roc_builtins.dec.to_str";
@@ -387,6 +422,9 @@ pub const DEC_NEQ: &str = "roc_builtins.dec.neq";
 pub const DEC_NEGATE: &str = "roc_builtins.dec.negate";
 pub const DEC_MUL_WITH_OVERFLOW: &str = "roc_builtins.dec.mul_with_overflow";
 pub const DEC_DIV: &str = "roc_builtins.dec.div";
+pub const DEC_COPY_SIGN: &str = "roc_builtins.dec.copy_sign";
+pub const DEC_ATAN2: &str = "roc_builtins.dec.atan2";
+pub const DEC_HYPOT: &str = "roc_builtins.dec.hypot";
 pub const DEC_ADD_WITH_OVERFLOW: &str = "roc_builtins.dec.add_with_overflow";
 pub const DEC_ADD_OR_PANIC: &str = "roc_builtins.dec.add_or_panic";
 pub const DEC_ADD_SATURATED: &str = "roc_builtins.dec.add_saturated";
@@ -395,6 +433,10 @@ pub const DEC_SUB_OR_PANIC: &str = "roc_builtins.dec.sub_or_panic";
 pub const DEC_SUB_SATURATED: &str = "roc_builtins.dec.sub_saturated";
 pub const DEC_MUL_OR_PANIC: &str = "roc_builtins.dec.mul_or_panic";
 pub const DEC_MUL_SATURATED: &str = "roc_builtins.dec.mul_saturated";
+pub const DEC_ROUND_TO: &str = "roc_builtins.dec.round_to";
+pub const DEC_TO_STR_FIXED: &str = "roc_builtins.dec.to_str_fixed";
+pub const DEC_TO_SCALED_I128: &str = "roc_builtins.dec.to_scaled_i128";
+pub const DEC_FROM_SCALED_I128: &str = "roc_builtins.dec.from_scaled_i128";
 
 pub const UTILS_TEST_PANIC: &str = "roc_builtins.utils.test_panic";
 pub const UTILS_ALLOCATE_WITH_REFCOUNT: &str = "roc_builtins.utils.allocate_with_refcount";
@@ -404,6 +446,7 @@ pub const UTILS_DECREF_CHECK_NULL: &str = "roc_builtins.utils.decref_check_null"
 
 pub const UTILS_EXPECT_FAILED_START: &str = "roc_builtins.utils.expect_failed_start";
 pub const UTILS_EXPECT_FAILED_FINALIZE: &str = "roc_builtins.utils.expect_failed_finalize";
+pub const UTILS_GEN_SEED: &str = "roc_builtins.utils.gen_seed";
 
 pub const UTILS_LONGJMP: &str = "longjmp";
 pub const UTILS_SETJMP: &str = "setjmp";
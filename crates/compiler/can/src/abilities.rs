@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::num::NonZeroU32;
 
 use roc_collections::{all::MutMap, VecMap, VecSet};
@@ -9,7 +10,7 @@ use roc_types::{
     types::{MemberImpl, Type},
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MemberVariables {
     pub able_vars: Vec<Variable>,
     /// This includes - named rigid vars, lambda sets, wildcards. See
@@ -20,11 +21,11 @@ pub struct MemberVariables {
 
 /// The member and its signature is defined locally, in the module the store is created for.
 /// We need to instantiate and introduce this during solving.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResolvedMemberType(Variable);
 
 /// Member type information that needs to be resolved from imports.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PendingMemberType {
     /// The member and its signature is defined locally, in the module the store is created for.
     /// We need to instantiate and introduce this during solving.
@@ -39,7 +40,7 @@ pub enum PendingMemberType {
 }
 
 pub trait ResolvePhase: std::fmt::Debug + Clone + Copy {
-    type MemberType: std::fmt::Debug + Clone;
+    type MemberType: std::fmt::Debug + Clone + Serialize + for<'de> Deserialize<'de>;
 }
 
 #[derive(Default, Debug, Clone, Copy)]
@@ -57,7 +58,8 @@ impl ResolvePhase for Resolved {
 /// Stores information about an ability member definition, including the parent ability, the
 /// defining type, and what type variables need to be instantiated with instances of the ability.
 // TODO: SoA and put me in an arena
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct AbilityMemberData<Phase: ResolvePhase> {
     pub parent_ability: Symbol,
     pub region: Region,
@@ -82,7 +84,8 @@ impl AbilityMemberData<Resolved> {
 pub type SpecializationLambdaSets = VecMap<u8, Variable>;
 
 /// A particular specialization of an ability member.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct MemberSpecializationInfo<Phase: ResolvePhase> {
     _phase: std::marker::PhantomData<Phase>,
     pub symbol: Symbol,
@@ -99,7 +102,7 @@ impl MemberSpecializationInfo<Resolved> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SpecializationId(NonZeroU32);
 
 static_assertions::assert_eq_size!(SpecializationId, Option<SpecializationId>);
@@ -107,7 +110,7 @@ static_assertions::assert_eq_size!(SpecializationId, Option<SpecializationId>);
 pub enum SpecializationLambdaSetError {}
 
 /// A key into a particular implementation of an ability member for an opaque type.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
 pub struct ImplKey {
     pub opaque: Symbol,
     pub ability_member: Symbol,
@@ -127,7 +130,8 @@ pub enum ResolvedImpl {
 // TODO(abilities): this should probably go on the Scope, I don't put it there for now because we
 // are only dealing with intra-module abilities for now.
 // TODO(abilities): many of these should be `VecMap`s. Do some benchmarking.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct IAbilitiesStore<Phase: ResolvePhase> {
     /// Maps an ability to the members defining it.
     members_of_ability: MutMap<Symbol, Vec<Symbol>>,
@@ -482,9 +486,11 @@ impl IAbilitiesStore<Pending> {
             ResolvedImpl::Error => MemberImpl::Error,
         };
 
+        // An ability exposed by the module that implements it for its own opaque types is imported
+        // both with the ability's closure and with the module's implementations.
         let old_declared_impl = self.declared_implementations.insert(impl_key, member_impl);
         debug_assert!(
-            old_declared_impl.is_none(),
+            old_declared_impl.is_none() || old_declared_impl == Some(member_impl),
             "Replacing existing declared impl!"
         );
     }
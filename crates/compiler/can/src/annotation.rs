@@ -9,9 +9,10 @@ use roc_problem::can::ShadowKind;
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{VarStore, Variable};
 use roc_types::types::{
-    name_type_var, Alias, AliasCommon, AliasKind, AliasVar, LambdaSet, OptAbleType, OptAbleVar,
-    Problem, RecordField, Type, TypeExtension,
+    name_type_var, AbilitySet, Alias, AliasCommon, AliasKind, AliasVar, LambdaSet, OptAbleType,
+    OptAbleVar, Problem, RecordField, Type, TypeExtension,
 };
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug)]
 pub struct Annotation {
@@ -105,16 +106,16 @@ impl OwnedNamedOrAble {
         }
     }
 
-    pub fn opt_ability(&self) -> Option<Symbol> {
+    pub fn opt_abilities(&self) -> Option<&AbilitySet> {
         match self {
             OwnedNamedOrAble::Named(_) => None,
-            OwnedNamedOrAble::Able(av) => Some(av.ability),
+            OwnedNamedOrAble::Able(av) => Some(&av.abilities),
         }
     }
 }
 
 /// A named type variable, not bound to an ability.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct NamedVariable {
     pub variable: Variable,
     pub name: Lowercase,
@@ -122,17 +123,17 @@ pub struct NamedVariable {
     pub first_seen: Region,
 }
 
-/// A type variable bound to an ability, like "a has Hash".
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// A type variable bound to one or more abilities, like "a has Hash & Eq".
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct AbleVariable {
     pub variable: Variable,
     pub name: Lowercase,
-    pub ability: Symbol,
+    pub abilities: AbilitySet,
     // NB: there may be multiple occurrences of a variable
     pub first_seen: Region,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct IntroducedVariables {
     pub wildcards: Vec<Loc<Variable>>,
     pub lambda_sets: Vec<Variable>,
@@ -166,12 +167,12 @@ impl IntroducedVariables {
         self.named.insert(named_variable);
     }
 
-    pub fn insert_able(&mut self, name: Lowercase, var: Loc<Variable>, ability: Symbol) {
+    pub fn insert_able(&mut self, name: Lowercase, var: Loc<Variable>, abilities: AbilitySet) {
         self.debug_assert_not_already_present(var.value);
 
         let able_variable = AbleVariable {
             name,
-            ability,
+            abilities,
             variable: var.value,
             first_seen: var.region,
         };
@@ -418,6 +419,15 @@ pub fn find_type_def_symbols(
                     stack.push(&t.value);
                 }
             }
+            Tuple { elems, ext } => {
+                for t in elems.iter() {
+                    stack.push(&t.value);
+                }
+
+                for t in ext.iter() {
+                    stack.push(&t.value);
+                }
+            }
             TagUnion { ext, tags } => {
                 let mut inner_stack = Vec::with_capacity(tags.items.len());
 
@@ -450,7 +460,9 @@ pub fn find_type_def_symbols(
                 stack.push(&annotation.value);
 
                 for has_clause in clauses.iter() {
-                    stack.push(&has_clause.value.ability.value);
+                    for ability in has_clause.value.abilities.iter() {
+                        stack.push(&ability.value);
+                    }
                 }
             }
             Inferred | Wildcard | Malformed(_) => {}
@@ -537,7 +549,11 @@ fn can_annotation_help(
 
                 // Generate an variable bound to the ability so we can keep compiling.
                 let var = var_store.fresh();
-                introduced_variables.insert_able(fresh_ty_var, Loc::at(region, var), symbol);
+                introduced_variables.insert_able(
+                    fresh_ty_var,
+                    Loc::at(region, var),
+                    AbilitySet::singleton(symbol),
+                );
                 return Type::Variable(var);
             }
 
@@ -667,7 +683,7 @@ fn can_annotation_help(
                         AliasVar {
                             name: var_name,
                             var,
-                            opt_bound_ability: None,
+                            opt_bound_abilities: None,
                         },
                     ));
                 } else {
@@ -682,7 +698,7 @@ fn can_annotation_help(
                         AliasVar {
                             name: var_name,
                             var,
-                            opt_bound_ability: None,
+                            opt_bound_abilities: None,
                         },
                     ));
                 }
@@ -764,7 +780,7 @@ fn can_annotation_help(
                         .into_iter()
                         .map(|typ| OptAbleType {
                             typ,
-                            opt_ability: None,
+                            opt_abilities: None,
                         })
                         .collect(),
                     lambda_set_variables: alias.lambda_set_variables.clone(),
@@ -775,7 +791,7 @@ fn can_annotation_help(
         }
 
         Record { fields, ext } => {
-            let ext_type = can_extension_type(
+            let ext = can_extension_type(
                 env,
                 scope,
                 var_store,
@@ -788,14 +804,14 @@ fn can_annotation_help(
 
             if fields.is_empty() {
                 match ext {
-                    Some(_) => {
+                    TypeExtension::Open(_) => {
                         // just `a` does not mean the same as `{}a`, so even
                         // if there are no fields, still make this a `Record`,
                         // not an EmptyRec
-                        Type::Record(Default::default(), TypeExtension::from_type(ext_type))
+                        Type::Record(Default::default(), ext)
                     }
 
-                    None => Type::EmptyRec,
+                    TypeExtension::Closed => Type::EmptyRec,
                 }
             } else {
                 let field_types = can_assigned_fields(
@@ -809,11 +825,44 @@ fn can_annotation_help(
                     references,
                 );
 
-                Type::Record(field_types, TypeExtension::from_type(ext_type))
+                Type::Record(field_types, ext)
             }
         }
+        Tuple { elems, ext } => {
+            let ext = can_extension_type(
+                env,
+                scope,
+                var_store,
+                introduced_variables,
+                local_aliases,
+                references,
+                ext,
+                roc_problem::can::ExtensionTypeKind::Tuple,
+            );
+
+            let elem_types = elems
+                .iter()
+                .enumerate()
+                .map(|(index, loc_elem)| {
+                    let elem_type = can_annotation_help(
+                        env,
+                        &loc_elem.value,
+                        loc_elem.region,
+                        scope,
+                        var_store,
+                        introduced_variables,
+                        local_aliases,
+                        references,
+                    );
+
+                    (index, elem_type)
+                })
+                .collect();
+
+            Type::Tuple(elem_types, ext)
+        }
         TagUnion { tags, ext, .. } => {
-            let ext_type = can_extension_type(
+            let ext = can_extension_type(
                 env,
                 scope,
                 var_store,
@@ -826,14 +875,14 @@ fn can_annotation_help(
 
             if tags.is_empty() {
                 match ext {
-                    Some(_) => {
+                    TypeExtension::Open(_) => {
                         // just `a` does not mean the same as `{}a`, so even
                         // if there are no fields, still make this a `Record`,
                         // not an EmptyRec
-                        Type::TagUnion(Default::default(), TypeExtension::from_type(ext_type))
+                        Type::TagUnion(Default::default(), ext)
                     }
 
-                    None => Type::EmptyTagUnion,
+                    TypeExtension::Closed => Type::EmptyTagUnion,
                 }
             } else {
                 let mut tag_types = can_tags(
@@ -852,7 +901,7 @@ fn can_annotation_help(
                 // in theory we save a lot of time by sorting once here
                 insertion_sort_by(&mut tag_types, |a, b| a.0.cmp(&b.0));
 
-                Type::TagUnion(tag_types, TypeExtension::from_type(ext_type))
+                Type::TagUnion(tag_types, ext)
             }
         }
         SpaceBefore(nested, _) | SpaceAfter(nested, _) => can_annotation_help(
@@ -914,7 +963,7 @@ fn canonicalize_has_clause(
 ) -> Result<(), Type> {
     let Loc {
         region,
-        value: roc_parse::ast::HasClause { var, ability },
+        value: roc_parse::ast::HasClause { var, abilities },
     } = clause;
     let region = *region;
 
@@ -925,29 +974,33 @@ fn canonicalize_has_clause(
     );
     let var_name = Lowercase::from(var_name);
 
-    let ability = match ability.value {
-        TypeAnnotation::Apply(module_name, ident, _type_arguments) => {
-            let symbol = make_apply_symbol(env, ability.region, scope, module_name, ident)?;
+    let mut can_abilities = AbilitySet::with_capacity(abilities.len());
+    for ability in abilities.iter() {
+        let ability = match ability.value {
+            TypeAnnotation::Apply(module_name, ident, _type_arguments) => {
+                let symbol = make_apply_symbol(env, ability.region, scope, module_name, ident)?;
 
-            // Ability defined locally, whose members we are constructing right now...
-            if !pending_abilities_in_scope.contains_key(&symbol)
+                // Ability defined locally, whose members we are constructing right now...
+                if !pending_abilities_in_scope.contains_key(&symbol)
                 // or an ability that was imported from elsewhere
                 && !scope.abilities_store.is_ability(symbol)
-            {
+                {
+                    let region = ability.region;
+                    env.problem(roc_problem::can::Problem::HasClauseIsNotAbility { region });
+                    return Err(Type::Erroneous(Problem::HasClauseIsNotAbility(region)));
+                }
+                symbol
+            }
+            _ => {
                 let region = ability.region;
                 env.problem(roc_problem::can::Problem::HasClauseIsNotAbility { region });
                 return Err(Type::Erroneous(Problem::HasClauseIsNotAbility(region)));
             }
-            symbol
-        }
-        _ => {
-            let region = ability.region;
-            env.problem(roc_problem::can::Problem::HasClauseIsNotAbility { region });
-            return Err(Type::Erroneous(Problem::HasClauseIsNotAbility(region)));
-        }
-    };
+        };
 
-    references.insert(ability);
+        references.insert(ability);
+        can_abilities.insert(ability);
+    }
 
     if let Some(shadowing) = introduced_variables.named_var_by_name(&var_name) {
         let var_name_ident = var_name.to_string().into();
@@ -965,7 +1018,7 @@ fn canonicalize_has_clause(
 
     let var = var_store.fresh();
 
-    introduced_variables.insert_able(var_name, Loc::at(region, var), ability);
+    introduced_variables.insert_able(var_name, Loc::at(region, var), can_abilities);
 
     Ok(())
 }
@@ -980,7 +1033,7 @@ fn can_extension_type<'a>(
     references: &mut VecSet<Symbol>,
     opt_ext: &Option<&Loc<TypeAnnotation<'a>>>,
     ext_problem_kind: roc_problem::can::ExtensionTypeKind,
-) -> Type {
+) -> TypeExtension {
     fn valid_record_ext_type(typ: &Type) -> bool {
         // Include erroneous types so that we don't overreport errors.
         matches!(
@@ -994,12 +1047,19 @@ fn can_extension_type<'a>(
             Type::EmptyTagUnion | Type::TagUnion(..) | Type::Variable(..) | Type::Erroneous(..)
         )
     }
+    fn valid_tuple_ext_type(typ: &Type) -> bool {
+        matches!(
+            typ,
+            Type::Tuple(..) | Type::Variable(..) | Type::Erroneous(..)
+        )
+    }
 
     use roc_problem::can::ExtensionTypeKind;
 
-    let (empty_ext_type, valid_extension_type): (_, fn(&Type) -> bool) = match ext_problem_kind {
-        ExtensionTypeKind::Record => (Type::EmptyRec, valid_record_ext_type),
-        ExtensionTypeKind::TagUnion => (Type::EmptyTagUnion, valid_tag_ext_type),
+    let valid_extension_type: fn(&Type) -> bool = match ext_problem_kind {
+        ExtensionTypeKind::Record => valid_record_ext_type,
+        ExtensionTypeKind::TagUnion => valid_tag_ext_type,
+        ExtensionTypeKind::Tuple => valid_tuple_ext_type,
     };
 
     match opt_ext {
@@ -1015,7 +1075,7 @@ fn can_extension_type<'a>(
                 references,
             );
             if valid_extension_type(shallow_dealias_with_scope(scope, &ext_type)) {
-                ext_type
+                TypeExtension::from_type(ext_type)
             } else {
                 // Report an error but mark the extension variable to be inferred
                 // so that we're as permissive as possible.
@@ -1031,10 +1091,10 @@ fn can_extension_type<'a>(
 
                 introduced_variables.insert_inferred(Loc::at_zero(var));
 
-                Type::Variable(var)
+                TypeExtension::from_type(Type::Variable(var))
             }
         }
-        None => empty_ext_type,
+        None => TypeExtension::Closed,
     }
 }
 
@@ -1117,7 +1177,7 @@ pub fn freshen_opaque_def(
         .iter()
         .map(|alias_var| OptAbleVar {
             var: var_store.fresh(),
-            opt_ability: alias_var.value.opt_bound_ability,
+            opt_abilities: alias_var.value.opt_bound_abilities.clone(),
         })
         .collect();
 
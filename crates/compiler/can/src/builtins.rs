@@ -80,10 +80,10 @@ macro_rules! map_symbol_to_lowlevel_and_arity {
                 LowLevel::NumToFloatChecked => unreachable!(),
 
                 // these are used internally and not tied to a symbol
-                LowLevel::Hash => unimplemented!(),
                 LowLevel::PtrCast => unimplemented!(),
                 LowLevel::RefCountInc => unimplemented!(),
                 LowLevel::RefCountDec => unimplemented!(),
+                LowLevel::NotEq => unimplemented!(),
 
                 // these are not implemented, not sure why
                 LowLevel::StrFromInt => unimplemented!(),
@@ -121,6 +121,12 @@ map_symbol_to_lowlevel_and_arity! {
     StrGetScalarUnsafe; STR_GET_SCALAR_UNSAFE; 2,
     StrToNum; STR_TO_NUM; 1,
     StrGetCapacity; STR_CAPACITY; 1,
+    StrGraphemes; STR_GRAPHEMES; 1,
+    StrGraphemeByteOffset; STR_GRAPHEME_BYTE_OFFSET_LOWLEVEL; 2,
+    StrToUpper; STR_TO_UPPER; 1,
+    StrToLower; STR_TO_LOWER; 1,
+    StrToNfc; STR_TO_NFC; 1,
+    StrToNfd; STR_TO_NFD; 1,
 
     ListLen; LIST_LEN; 1,
     ListWithCapacity; LIST_WITH_CAPACITY; 1,
@@ -178,6 +184,24 @@ map_symbol_to_lowlevel_and_arity! {
     NumAtan; NUM_ATAN; 1,
     NumAcos; NUM_ACOS; 1,
     NumAsin; NUM_ASIN; 1,
+    NumExp; NUM_EXP; 1,
+    NumExp2; NUM_EXP2; 1,
+    NumLog2; NUM_LOG2; 1,
+    NumLog10; NUM_LOG10; 1,
+    NumSinh; NUM_SINH; 1,
+    NumCosh; NUM_COSH; 1,
+    NumTanh; NUM_TANH; 1,
+    NumAtan2; NUM_ATAN2; 2,
+    NumHypot; NUM_HYPOT; 2,
+    NumTrunc; NUM_TRUNC; 1,
+    NumFma; NUM_FMA; 3,
+    NumIsNan; NUM_IS_NAN; 1,
+    NumIsInfinite; NUM_IS_INFINITE; 1,
+    NumCopySign; NUM_COPY_SIGN; 2,
+    NumDecRoundTo; NUM_ROUND_DEC_TO_LOWLEVEL; 3,
+    NumDecToStrFixed; NUM_TO_STR_FIXED; 2,
+    NumDecToScaledI128; NUM_TO_SCALED_I128_LOWLEVEL; 2,
+    NumDecFromScaledI128; NUM_FROM_SCALED_I128_LOWLEVEL; 2,
     NumBytesToU16; NUM_BYTES_TO_U16_LOWLEVEL; 2,
     NumBytesToU32; NUM_BYTES_TO_U32_LOWLEVEL; 2,
     NumBitwiseAnd; NUM_BITWISE_AND; 2,
@@ -186,13 +210,20 @@ map_symbol_to_lowlevel_and_arity! {
     NumShiftLeftBy; NUM_SHIFT_LEFT; 2,
     NumShiftRightBy; NUM_SHIFT_RIGHT; 2,
     NumShiftRightZfBy; NUM_SHIFT_RIGHT_ZERO_FILL; 2,
+    NumRotateLeftBy; NUM_ROTATE_LEFT; 2,
+    NumRotateRightBy; NUM_ROTATE_RIGHT; 2,
+    NumCountLeadingZeroBits; NUM_COUNT_LEADING_ZERO_BITS; 1,
+    NumCountTrailingZeroBits; NUM_COUNT_TRAILING_ZERO_BITS; 1,
+    NumCountOneBits; NUM_COUNT_ONE_BITS; 1,
+    NumSwapBytes; NUM_SWAP_BYTES; 1,
     NumToStr; NUM_TO_STR; 1,
 
-    Eq; BOOL_EQ; 2,
-    NotEq; BOOL_NEQ; 2,
+    Eq; BOOL_STRUCTURAL_EQ; 2,
     And; BOOL_AND; 2,
     Or; BOOL_OR; 2,
     Not; BOOL_NOT; 1,
+    Hash; DICT_HASH_LOW_LEVEL; 2,
+    GenSeed; GEN_SEED_LOW_LEVEL; 1,
     BoxExpr; BOX_BOX_FUNCTION; 1,
     UnboxExpr; BOX_UNBOX; 1,
     Unreachable; LIST_UNREACHABLE; 1,
@@ -1,7 +1,7 @@
 use crate::{
     def::Def,
     expr::{AccessorData, ClosureData, Expr, Field, OpaqueWrapFunctionData, WhenBranchPattern},
-    pattern::{DestructType, Pattern, RecordDestruct},
+    pattern::{DestructType, Pattern, RecordDestruct, TupleDestruct},
 };
 use roc_module::{
     ident::{Lowercase, TagName},
@@ -10,7 +10,7 @@ use roc_module::{
 use roc_types::{
     subs::{
         self, AliasVariables, Descriptor, GetSubsSlice, OptVariable, RecordFields, Subs, SubsIndex,
-        SubsSlice, UnionLambdas, UnionTags, Variable, VariableSubsSlice,
+        SubsSlice, TupleElems, UnionLambdas, UnionTags, Variable, VariableSubsSlice,
     },
     types::{RecordField, Uls},
 };
@@ -66,12 +66,14 @@ trait CopyEnv {
 
     fn clone_tag_names(&mut self, tag_names: SubsSlice<TagName>) -> SubsSlice<TagName>;
 
-    fn clone_lambda_names(&mut self, lambda_names: SubsSlice<Symbol>) -> SubsSlice<Symbol>;
+    fn clone_symbol_names(&mut self, symbol_names: SubsSlice<Symbol>) -> SubsSlice<Symbol>;
 
     fn clone_record_fields(
         &mut self,
         record_fields: SubsSlice<RecordField<()>>,
     ) -> SubsSlice<RecordField<()>>;
+
+    fn clone_tuple_elem_indices(&mut self, elem_indices: SubsSlice<usize>) -> SubsSlice<usize>;
 }
 
 impl CopyEnv for Subs {
@@ -111,8 +113,8 @@ impl CopyEnv for Subs {
     }
 
     #[inline(always)]
-    fn clone_lambda_names(&mut self, lambda_names: SubsSlice<Symbol>) -> SubsSlice<Symbol> {
-        lambda_names
+    fn clone_symbol_names(&mut self, symbol_names: SubsSlice<Symbol>) -> SubsSlice<Symbol> {
+        symbol_names
     }
 
     #[inline(always)]
@@ -122,6 +124,11 @@ impl CopyEnv for Subs {
     ) -> SubsSlice<RecordField<()>> {
         record_fields
     }
+
+    #[inline(always)]
+    fn clone_tuple_elem_indices(&mut self, elem_indices: SubsSlice<usize>) -> SubsSlice<usize> {
+        elem_indices
+    }
 }
 
 struct AcrossSubs<'a> {
@@ -172,10 +179,10 @@ impl<'a> CopyEnv for AcrossSubs<'a> {
     }
 
     #[inline(always)]
-    fn clone_lambda_names(&mut self, lambda_names: SubsSlice<Symbol>) -> SubsSlice<Symbol> {
+    fn clone_symbol_names(&mut self, symbol_names: SubsSlice<Symbol>) -> SubsSlice<Symbol> {
         SubsSlice::extend_new(
-            &mut self.target.closure_names,
-            self.source.get_subs_slice(lambda_names).iter().cloned(),
+            &mut self.target.symbol_names,
+            self.source.get_subs_slice(symbol_names).iter().cloned(),
         )
     }
 
@@ -189,6 +196,14 @@ impl<'a> CopyEnv for AcrossSubs<'a> {
             self.source.get_subs_slice(record_fields).iter().copied(),
         )
     }
+
+    #[inline(always)]
+    fn clone_tuple_elem_indices(&mut self, elem_indices: SubsSlice<usize>) -> SubsSlice<usize> {
+        SubsSlice::extend_new(
+            &mut self.target.tuple_elem_indices,
+            self.source.get_subs_slice(elem_indices).iter().copied(),
+        )
+    }
 }
 
 pub fn deep_copy_type_vars_into_expr(
@@ -483,6 +498,28 @@ fn deep_copy_expr_help<C: CopyEnv>(env: &mut C, copied: &mut Vec<Variable>, expr
             field: field.clone(),
         },
 
+        Tuple { tuple_var, elems } => Tuple {
+            tuple_var: sub!(*tuple_var),
+            elems: elems
+                .iter()
+                .map(|(var, loc_expr)| (sub!(*var), Box::new(loc_expr.map(|e| go_help!(e)))))
+                .collect(),
+        },
+
+        TupleAccess {
+            tuple_var,
+            ext_var,
+            elem_var,
+            loc_expr,
+            index,
+        } => TupleAccess {
+            tuple_var: sub!(*tuple_var),
+            ext_var: sub!(*ext_var),
+            elem_var: sub!(*elem_var),
+            loc_expr: Box::new(loc_expr.map(|e| go_help!(e))),
+            index: *index,
+        },
+
         Accessor(AccessorData {
             name,
             function_var,
@@ -623,6 +660,19 @@ fn deep_copy_expr_help<C: CopyEnv>(env: &mut C, copied: &mut Vec<Variable>, expr
             lookups_in_cond: lookups_in_cond.to_vec(),
         },
 
+        Dbg {
+            loc_value,
+            variable,
+        } => Dbg {
+            loc_value: Box::new(loc_value.map(|e| go_help!(e))),
+            variable: sub!(*variable),
+        },
+
+        Crash { msg, ret_var } => Crash {
+            msg: Box::new(msg.map(|m| go_help!(m))),
+            ret_var: sub!(*ret_var),
+        },
+
         TypedHole(v) => TypedHole(sub!(*v)),
 
         RuntimeError(err) => RuntimeError(err.clone()),
@@ -719,6 +769,30 @@ fn deep_copy_pattern_help<C: CopyEnv>(
                 })
                 .collect(),
         },
+        TupleDestructure {
+            whole_var,
+            ext_var,
+            destructs,
+        } => TupleDestructure {
+            whole_var: sub!(*whole_var),
+            ext_var: sub!(*ext_var),
+            destructs: destructs
+                .iter()
+                .map(|lrd| {
+                    lrd.map(
+                        |TupleDestruct {
+                             destruct_index,
+                             var,
+                             typ: (tyvar, pat),
+                         }| TupleDestruct {
+                            destruct_index: *destruct_index,
+                            var: sub!(*var),
+                            typ: (sub!(*tyvar), pat.map(|p| go_help!(p))),
+                        },
+                    )
+                })
+                .collect(),
+        },
         NumLiteral(var, s, n, bound) => NumLiteral(sub!(*var), s.clone(), *n, *bound),
         IntLiteral(v1, v2, s, n, bound) => IntLiteral(sub!(*v1), sub!(*v2), s.clone(), *n, *bound),
         FloatLiteral(v1, v2, s, n, bound) => {
@@ -833,15 +907,18 @@ fn deep_copy_type_vars<C: CopyEnv>(
         let new_content = match content {
             // The vars for which we want to do something interesting.
             FlexVar(opt_name) => FlexVar(opt_name.map(|n| env.clone_name(n))),
-            FlexAbleVar(opt_name, ability) => {
-                FlexAbleVar(opt_name.map(|n| env.clone_name(n)), ability)
-            }
+            FlexAbleVar(opt_name, abilities) => FlexAbleVar(
+                opt_name.map(|n| env.clone_name(n)),
+                env.clone_symbol_names(abilities),
+            ),
             RigidVar(name) => RigidVar(env.clone_name(name)),
-            RigidAbleVar(name, ability) => RigidAbleVar(env.clone_name(name), ability),
+            RigidAbleVar(name, abilities) => {
+                RigidAbleVar(env.clone_name(name), env.clone_symbol_names(abilities))
+            }
 
             // Everything else is a mechanical descent.
             Structure(flat_type) => match flat_type {
-                EmptyRecord | EmptyTagUnion | Erroneous(_) => Structure(flat_type),
+                EmptyRecord | EmptyTuple | EmptyTagUnion | Erroneous(_) => Structure(flat_type),
                 Apply(symbol, arguments) => {
                     descend_slice!(arguments);
 
@@ -883,6 +960,26 @@ fn deep_copy_type_vars<C: CopyEnv>(
                         Structure(Record(new_fields, new_ext_var))
                     })
                 }
+                Tuple(elems, ext_var) => {
+                    let new_ext_var = descend_var!(ext_var);
+
+                    descend_slice!(elems.variables());
+
+                    perform_clone!({
+                        let new_variables = clone_var_slice!(elems.variables());
+                        let new_elem_indices = env.clone_tuple_elem_indices(elems.elem_indices());
+
+                        let new_elems = {
+                            TupleElems {
+                                length: elems.length,
+                                elem_index_start: new_elem_indices.start,
+                                variables_start: new_variables.start,
+                            }
+                        };
+
+                        Structure(Tuple(new_elems, new_ext_var))
+                    })
+                }
                 TagUnion(tags, ext_var) => {
                     let new_ext_var = descend_var!(ext_var);
 
@@ -1004,7 +1101,7 @@ fn deep_copy_type_vars<C: CopyEnv>(
                         env.target().variable_slices[target_index] = new_variables;
                     }
 
-                    let new_solved_labels = env.clone_lambda_names(solved.labels());
+                    let new_solved_labels = env.clone_symbol_names(solved.labels());
 
                     let new_solved =
                         UnionLambdas::from_slices(new_solved_labels, new_variable_slices);
@@ -1055,8 +1152,8 @@ mod test {
     use roc_region::all::Loc;
     use roc_types::{
         subs::{
-            self, Content, Content::*, Descriptor, FlatType, Mark, OptVariable, Rank, Subs,
-            SubsIndex, SubsSlice, Variable,
+            self, Content, Content::*, Descriptor, FlatType, GetSubsSlice, Mark, OptVariable, Rank,
+            Subs, SubsIndex, SubsSlice, Variable,
         },
         types::Uls,
     };
@@ -1118,7 +1215,8 @@ mod test {
         let mut subs = Subs::new();
 
         let field_name = SubsIndex::push_new(&mut subs.field_names, "a".into());
-        let var = new_var(&mut subs, FlexAbleVar(Some(field_name), Symbol::UNDERSCORE));
+        let abilities = SubsSlice::extend_new(&mut subs.symbol_names, [Symbol::UNDERSCORE]);
+        let var = new_var(&mut subs, FlexAbleVar(Some(field_name), abilities));
 
         let mut copied = vec![];
 
@@ -1127,8 +1225,9 @@ mod test {
         assert_ne!(var, copy);
 
         match subs.get_content_without_compacting(var) {
-            FlexAbleVar(Some(name), Symbol::UNDERSCORE) => {
+            FlexAbleVar(Some(name), abilities) => {
                 assert_eq!(subs[*name].as_str(), "a");
+                assert_eq!(subs.get_subs_slice(*abilities), [Symbol::UNDERSCORE]);
             }
             it => unreachable!("{:?}", it),
         }
@@ -1139,7 +1238,8 @@ mod test {
         let mut subs = Subs::new();
 
         let field_name = SubsIndex::push_new(&mut subs.field_names, "a".into());
-        let var = new_var(&mut subs, RigidAbleVar(field_name, Symbol::UNDERSCORE));
+        let abilities = SubsSlice::extend_new(&mut subs.symbol_names, [Symbol::UNDERSCORE]);
+        let var = new_var(&mut subs, RigidAbleVar(field_name, abilities));
 
         let mut copied = vec![];
 
@@ -1147,8 +1247,9 @@ mod test {
 
         assert_ne!(var, copy);
         match subs.get_content_without_compacting(var) {
-            RigidAbleVar(name, Symbol::UNDERSCORE) => {
+            RigidAbleVar(name, abilities) => {
                 assert_eq!(subs[*name].as_str(), "a");
+                assert_eq!(subs.get_subs_slice(*abilities), [Symbol::UNDERSCORE]);
             }
             it => internal_error!("{:?}", it),
         }
@@ -7,6 +7,7 @@ use crate::annotation::find_type_def_symbols;
 use crate::annotation::make_apply_symbol;
 use crate::annotation::IntroducedVariables;
 use crate::annotation::OwnedNamedOrAble;
+use crate::derive;
 use crate::env::Env;
 use crate::expr::AccessorData;
 use crate::expr::AnnotatedMark;
@@ -46,9 +47,10 @@ use roc_types::types::LambdaSet;
 use roc_types::types::MemberImpl;
 use roc_types::types::OptAbleType;
 use roc_types::types::{Alias, Type};
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Def {
     pub loc_pattern: Loc<Pattern>,
     pub loc_expr: Loc<Expr>,
@@ -75,7 +77,7 @@ impl Def {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Annotation {
     pub signature: Type,
     pub introduced_variables: IntroducedVariables,
@@ -97,6 +99,7 @@ pub struct Expects {
     pub conditions: Vec<Expr>,
     pub regions: Vec<Region>,
     pub preceding_comment: Vec<Region>,
+    pub is_doctest: Vec<bool>,
 }
 
 impl Expects {
@@ -105,13 +108,15 @@ impl Expects {
             conditions: Vec::with_capacity(capacity),
             regions: Vec::with_capacity(capacity),
             preceding_comment: Vec::with_capacity(capacity),
+            is_doctest: Vec::with_capacity(capacity),
         }
     }
 
-    fn push(&mut self, loc_can_condition: Loc<Expr>, preceding_comment: Region) {
+    fn push(&mut self, loc_can_condition: Loc<Expr>, preceding_comment: Region, is_doctest: bool) {
         self.conditions.push(loc_can_condition.value);
         self.regions.push(loc_can_condition.region);
         self.preceding_comment.push(preceding_comment);
+        self.is_doctest.push(is_doctest);
     }
 }
 
@@ -358,14 +363,14 @@ fn canonicalize_alias<'a>(
                 // This is a valid lowercase rigid var for the type def.
                 let named_variable = named.swap_remove(index);
                 let var = named_variable.variable();
-                let opt_bound_ability = named_variable.opt_ability();
+                let opt_bound_abilities = named_variable.opt_abilities().cloned();
                 let name = named_variable.name();
 
                 can_vars.push(Loc {
                     value: AliasVar {
                         name,
                         var,
-                        opt_bound_ability,
+                        opt_bound_abilities,
                     },
                     region: loc_lowercase.region,
                 });
@@ -387,7 +392,7 @@ fn canonicalize_alias<'a>(
                         value: AliasVar {
                             name: loc_lowercase.value.clone(),
                             var: var_store.fresh(),
-                            opt_bound_ability: None,
+                            opt_bound_abilities: None,
                         },
                         region: loc_lowercase.region,
                     });
@@ -654,6 +659,7 @@ fn canonicalize_opaque<'a>(
     ann: &'a Loc<ast::TypeAnnotation<'a>>,
     vars: &[Loc<Lowercase>],
     has_abilities: Option<&'a Loc<ast::HasAbilities<'a>>>,
+    derived_defs: &mut Vec<Loc<PendingValue<'a>>>,
 ) -> Result<Alias, ()> {
     let alias = canonicalize_alias(
         env,
@@ -808,7 +814,32 @@ fn canonicalize_opaque<'a>(
                     .abilities_store
                     .register_declared_implementations(name.value, impls);
             } else if let Some((_, members)) = ability.derivable_ability() {
-                let impls = members.iter().map(|member| (*member, MemberImpl::Derived));
+                let mut impls = Vec::with_capacity(members.len());
+                for &member in members.iter() {
+                    if derive::is_synthesized(member) {
+                        let opaque_name = scope
+                            .locals
+                            .ident_ids
+                            .get_name(name.value.ident_id())
+                            .unwrap()
+                            .to_owned();
+                        let (impl_symbol, ast_pattern, impl_pattern, impl_body) =
+                            derive::synthesize_member_impl(env, scope, &opaque_name, member);
+
+                        derived_defs.push(Loc::at(
+                            derive::DERIVED_REGION,
+                            PendingValue::Def(PendingValueDef::Body(
+                                ast_pattern,
+                                impl_pattern,
+                                impl_body,
+                            )),
+                        ));
+
+                        impls.push((member, MemberImpl::Impl(impl_symbol)));
+                    } else {
+                        impls.push((member, MemberImpl::Derived));
+                    }
+                }
                 scope
                     .abilities_store
                     .register_declared_implementations(name.value, impls);
@@ -921,6 +952,8 @@ pub(crate) fn canonicalize_defs<'a>(
         scope.register_debug_idents();
     }
 
+    // Implementations of derived abilities are synthesized as value defs of their own, which are
+    // added to `pending_value_defs`.
     let (aliases, symbols_introduced) = canonicalize_type_defs(
         env,
         &mut output,
@@ -928,6 +961,7 @@ pub(crate) fn canonicalize_defs<'a>(
         scope,
         &pending_abilities_in_scope,
         pending_type_defs,
+        &mut pending_value_defs,
     );
 
     // Now that we have the scope completely assembled, and shadowing resolved,
@@ -1048,7 +1082,11 @@ fn canonicalize_value_defs<'a>(
             &pending.condition.value,
         );
 
-        expects.push(loc_can_condition, pending.preceding_comment);
+        expects.push(
+            loc_can_condition,
+            pending.preceding_comment,
+            pending.is_doctest,
+        );
 
         output.union(can_output);
     }
@@ -1062,7 +1100,11 @@ fn canonicalize_value_defs<'a>(
             &pending.condition.value,
         );
 
-        expects_fx.push(loc_can_condition, pending.preceding_comment);
+        expects_fx.push(
+            loc_can_condition,
+            pending.preceding_comment,
+            pending.is_doctest,
+        );
 
         output.union(can_output);
     }
@@ -1085,6 +1127,7 @@ fn canonicalize_type_defs<'a>(
     scope: &mut Scope,
     pending_abilities_in_scope: &PendingAbilitiesInScope,
     pending_type_defs: Vec<PendingTypeDef<'a>>,
+    derived_defs: &mut Vec<Loc<PendingValue<'a>>>,
 ) -> (VecMap<Symbol, Alias>, MutMap<Symbol, Region>) {
     enum TypeDef<'a> {
         Alias(
@@ -1191,6 +1234,7 @@ fn canonicalize_type_defs<'a>(
                     ann,
                     &vars,
                     derived,
+                    derived_defs,
                 );
 
                 if let Ok(alias) = alias_and_derives {
@@ -1278,7 +1322,7 @@ fn resolve_abilities<'a>(
                 .introduced_variables
                 .able
                 .iter()
-                .partition(|av| av.ability == ability);
+                .partition(|av| av.abilities.contains(&ability));
 
             let var_bound_to_ability = match variables_bound_to_ability.as_slice() {
                 [one] => one.variable,
@@ -1476,13 +1520,19 @@ pub(crate) fn sort_can_defs_new(
         .conditions
         .into_iter()
         .zip(expects.regions)
-        .zip(expects.preceding_comment);
+        .zip(expects.preceding_comment)
+        .zip(expects.is_doctest);
 
-    for ((condition, region), preceding_comment) in it {
+    for (((condition, region), preceding_comment), is_doctest) in it {
         // an `expect` does not have a user-defined name, but we'll need a name to call the expectation
         let name = scope.gen_unique_symbol();
 
-        declarations.push_expect(preceding_comment, name, Loc::at(region, condition));
+        declarations.push_expect(
+            preceding_comment,
+            name,
+            Loc::at(region, condition),
+            is_doctest,
+        );
     }
 
     let it = expects_fx
@@ -1855,6 +1905,16 @@ fn pattern_to_vars_by_symbol(
             }
         }
 
+        TupleDestructure { destructs, .. } => {
+            for destruct in destructs {
+                pattern_to_vars_by_symbol(
+                    vars_by_symbol,
+                    &destruct.value.typ.1.value,
+                    destruct.value.typ.0,
+                );
+            }
+        }
+
         NumLiteral(..)
         | IntLiteral(..)
         | FloatLiteral(..)
@@ -2493,6 +2553,7 @@ enum PendingValue<'a> {
 struct PendingExpect<'a> {
     condition: &'a Loc<ast::Expr<'a>>,
     preceding_comment: Region,
+    is_doctest: bool,
 }
 
 fn to_pending_value_def<'a>(
@@ -2595,9 +2656,11 @@ fn to_pending_value_def<'a>(
         Expect {
             condition,
             preceding_comment,
+            is_doctest,
         } => PendingValue::Expect(PendingExpect {
             condition,
             preceding_comment: *preceding_comment,
+            is_doctest: *is_doctest,
         }),
 
         ExpectFx {
@@ -2606,6 +2669,7 @@ fn to_pending_value_def<'a>(
         } => PendingValue::ExpectFx(PendingExpect {
             condition,
             preceding_comment: *preceding_comment,
+            is_doctest: false,
         }),
     }
 }
@@ -2823,7 +2887,7 @@ fn make_tag_union_of_alias_recursive<'a>(
 
     let alias_opt_able_vars = alias.type_variables.iter().map(|l| OptAbleType {
         typ: Type::Variable(l.value.var),
-        opt_ability: l.value.opt_bound_ability,
+        opt_abilities: l.value.opt_bound_abilities.clone(),
     });
 
     let lambda_set_vars = alias.lambda_set_variables.iter();
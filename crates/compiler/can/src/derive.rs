@@ -0,0 +1,95 @@
+//! Implementations of derived abilities for opaque types, which are synthesized as regular defs
+//! in the opaque's module.
+
+use roc_error_macros::internal_error;
+use roc_module::called_via::CalledVia;
+use roc_module::symbol::Symbol;
+use roc_parse::ast;
+use roc_region::all::{Loc, Region};
+
+use crate::env::Env;
+use crate::pattern::Pattern;
+use crate::scope::Scope;
+
+pub(crate) const DERIVED_REGION: Region = Region::zero();
+
+/// Whether a derived implementation of the ability member is synthesized by
+/// [`synthesize_member_impl`], rather than left to the backend to derive structurally.
+pub(crate) fn is_synthesized(ability_member: Symbol) -> bool {
+    ability_member == Symbol::BOOL_IS_EQ
+}
+
+/// Synthesizes the def implementing an ability member for an opaque type, returning the symbol
+/// the def is bound to, its pattern (both parsed and canonical), and its body.
+pub(crate) fn synthesize_member_impl<'a>(
+    env: &mut Env<'a>,
+    scope: &mut Scope,
+    opaque_name: &str,
+    ability_member: Symbol,
+) -> (
+    Symbol,
+    &'a Loc<ast::Pattern<'a>>,
+    Loc<Pattern>,
+    &'a Loc<ast::Expr<'a>>,
+) {
+    let at_opaque = env.arena.alloc_str(&format!("@{}", opaque_name));
+
+    let (impl_name, def_body) = match ability_member {
+        Symbol::BOOL_IS_EQ => (format!("#{}_isEq", opaque_name), is_eq(env, at_opaque)),
+        _ => internal_error!("{:?} is not synthesized", ability_member),
+    };
+
+    let impl_symbol = scope
+        .introduce_str(&impl_name, DERIVED_REGION)
+        .expect("derived implementation names are unique");
+
+    let impl_name = env.arena.alloc_str(&impl_name);
+
+    (
+        impl_symbol,
+        env.arena
+            .alloc(Loc::at(DERIVED_REGION, ast::Pattern::Identifier(impl_name))),
+        Loc::at(DERIVED_REGION, Pattern::Identifier(impl_symbol)),
+        env.arena.alloc(Loc::at(DERIVED_REGION, def_body)),
+    )
+}
+
+fn is_eq<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
+    let alloc_pat = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
+    let alloc_expr = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
+
+    let payload1 = "#payload1";
+    let payload2 = "#payload2";
+
+    let opaque_ref = alloc_pat(ast::Pattern::OpaqueRef(at_opaque));
+
+    // \@Opaq payload1, @Opaq payload2
+    let opaque_patterns = env.arena.alloc([payload1, payload2].map(|payload| {
+        Loc::at(
+            DERIVED_REGION,
+            ast::Pattern::Apply(
+                opaque_ref,
+                env.arena
+                    .alloc([Loc::at(DERIVED_REGION, ast::Pattern::Identifier(payload))]),
+            ),
+        )
+    }));
+
+    // Bool.isEq payload1 payload2
+    let call_member = alloc_expr(ast::Expr::Apply(
+        alloc_expr(ast::Expr::Var {
+            module_name: "Bool",
+            ident: "isEq",
+        }),
+        env.arena.alloc([payload1, payload2].map(|payload| {
+            &*alloc_expr(ast::Expr::Var {
+                module_name: "",
+                ident: payload,
+            })
+        })),
+        CalledVia::Space,
+    ));
+
+    // \@Opaq payload1, @Opaq payload2 -> Bool.isEq payload1 payload2
+    ast::Expr::Closure(opaque_patterns, call_member)
+}
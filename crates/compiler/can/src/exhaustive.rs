@@ -69,6 +69,8 @@ enum IndexCtor<'a> {
     Opaque,
     /// Index a record type. The arguments are the types of the record fields.
     Record,
+    /// Index a tuple type. The arguments are the types of the tuple elements.
+    Tuple,
     /// Index a guard constructor. The arguments are a faux guard pattern, and then the real
     /// pattern being guarded. E.g. `A B if g` becomes Guard { [True, (A B)] }.
     Guard,
@@ -139,6 +141,14 @@ fn index_var(
 
                     return field_types;
                 }
+                FlatType::Tuple(elems, ext) => {
+                    let elem_types = elems
+                        .sorted_iterator(subs, *ext)
+                        .map(|(_, elem)| elem)
+                        .collect();
+
+                    return elem_types;
+                }
                 FlatType::TagUnion(tags, ext) | FlatType::RecursiveTagUnion(_, tags, ext) => {
                     let tag_ctor = match ctor {
                         IndexCtor::Tag(name) => name,
@@ -170,6 +180,9 @@ fn index_var(
                     };
                     return std::iter::repeat(Variable::NULL).take(num_fields).collect();
                 }
+                FlatType::EmptyTuple => {
+                    internal_error!("empty tuples are not indexable")
+                }
                 FlatType::EmptyTagUnion => {
                     internal_error!("empty tag unions are not indexable")
                 }
@@ -286,6 +299,31 @@ fn sketch_pattern(pattern: &crate::pattern::Pattern) -> SketchedPattern {
             SP::KnownCtor(union, IndexCtor::Record, tag_id, patterns)
         }
 
+        TupleDestructure { destructs, .. } => {
+            let tag_id = TagId(0);
+            let mut patterns = std::vec::Vec::with_capacity(destructs.len());
+
+            for Loc {
+                value: destruct,
+                region: _,
+            } in destructs
+            {
+                let (_, guard) = &destruct.typ;
+                patterns.push(sketch_pattern(&guard.value));
+            }
+
+            let union = Union {
+                render_as: RenderAs::Tuple,
+                alternatives: vec![Ctor {
+                    name: CtorName::Tag(TagName("#Tuple".into())),
+                    tag_id,
+                    arity: destructs.len(),
+                }],
+            };
+
+            SP::KnownCtor(union, IndexCtor::Tuple, tag_id, patterns)
+        }
+
         AppliedTag {
             tag_name,
             arguments,
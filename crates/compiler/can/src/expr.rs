@@ -24,6 +24,7 @@ use roc_problem::can::{PrecedenceProblem, Problem, RuntimeError};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{ExhaustiveMark, IllegalCycleMark, RedundantMark, VarStore, Variable};
 use roc_types::types::{Alias, Category, LambdaSet, OptAbleVar, Type};
+use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display};
 use std::{char, u32};
 
@@ -64,7 +65,7 @@ impl Output {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Copy)]
+#[derive(Clone, Debug, PartialEq, Copy, Serialize, Deserialize)]
 pub enum IntValue {
     I128([u8; 16]),
     U128([u8; 16]),
@@ -79,7 +80,7 @@ impl Display for IntValue {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Expr {
     // Literals
 
@@ -175,6 +176,21 @@ pub enum Expr {
     /// field accessor as a function, e.g. (.foo) expr
     Accessor(AccessorData),
 
+    /// A tuple, e.g. (a, b)
+    Tuple {
+        tuple_var: Variable,
+        elems: Vec<(Variable, Box<Loc<Expr>>)>,
+    },
+
+    /// Look up exactly one element of a tuple, e.g. (expr).0
+    TupleAccess {
+        tuple_var: Variable,
+        ext_var: Variable,
+        elem_var: Variable,
+        loc_expr: Box<Loc<Expr>>,
+        index: usize,
+    },
+
     Update {
         record_var: Variable,
         ext_var: Variable,
@@ -238,10 +254,23 @@ pub enum Expr {
         lookups_in_cond: Vec<(Symbol, Variable)>,
     },
 
+    /// `dbg value`; evaluates to `value`, reporting it to the host first
+    Dbg {
+        loc_value: Box<Loc<Expr>>,
+        variable: Variable,
+    },
+
+    /// `crash msg`; stops the program, reporting `msg` to the host
+    Crash {
+        msg: Box<Loc<Expr>>,
+        ret_var: Variable,
+    },
+
     /// Rendered as empty box in editor
     TypedHole(Variable),
 
     /// Compiles, but will crash if reached
+    #[serde(skip)]
     RuntimeError(RuntimeError),
 }
 
@@ -269,6 +298,8 @@ impl Expr {
             Self::Access { field, .. } => Category::Access(field.clone()),
             Self::Accessor(data) => Category::Accessor(data.field.clone()),
             Self::Update { .. } => Category::Record,
+            Self::Tuple { .. } => Category::Tuple,
+            Self::TupleAccess { index, .. } => Category::TupleAccess(*index),
             Self::Tag {
                 name, arguments, ..
             } => Category::TagApply {
@@ -285,6 +316,8 @@ impl Expr {
             }
             Self::Expect { .. } => Category::Expect,
             Self::ExpectFx { .. } => Category::Expect,
+            Self::Dbg { loc_value, .. } => loc_value.value.category(),
+            Self::Crash { .. } => Category::Crash,
 
             // these nodes place no constraints on the expression's type
             Self::TypedHole(_) | Self::RuntimeError(..) => Category::Unknown,
@@ -294,7 +327,7 @@ impl Expr {
 
 /// Stores exhaustiveness-checking metadata for a closure argument that may
 /// have an annotated type.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct AnnotatedMark {
     pub annotation_var: Variable,
     pub exhaustive: ExhaustiveMark,
@@ -318,7 +351,7 @@ impl AnnotatedMark {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ClosureData {
     pub function_type: Variable,
     pub closure_type: Variable,
@@ -336,7 +369,7 @@ pub struct ClosureData {
 ///
 /// We distinguish them from closures so we can have better error messages
 /// during constraint generation.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AccessorData {
     pub name: Symbol,
     pub function_var: Variable,
@@ -398,7 +431,7 @@ impl AccessorData {
 /// An opaque wrapper like `@Foo`, which is equivalent to `\p -> @Foo p`
 /// These are desugared to closures, but we distinguish them so we can have
 /// better error messages during constraint generation.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OpaqueWrapFunctionData {
     pub opaque_name: Symbol,
     pub opaque_var: Variable,
@@ -465,7 +498,7 @@ impl OpaqueWrapFunctionData {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Field {
     pub var: Variable,
     // The region of the full `foo: f bar`, rather than just `f bar`
@@ -473,7 +506,7 @@ pub struct Field {
     pub loc_expr: Box<Loc<Expr>>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Recursive {
     NotRecursive = 0,
     Recursive = 1,
@@ -489,7 +522,7 @@ impl Recursive {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WhenBranchPattern {
     pub pattern: Loc<Pattern>,
     /// Degenerate branch patterns are those that don't fully bind symbols that the branch body
@@ -498,7 +531,7 @@ pub struct WhenBranchPattern {
     pub degenerate: bool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WhenBranch {
     pub patterns: Vec<WhenBranchPattern>,
     pub value: Loc<Expr>,
@@ -744,6 +777,26 @@ pub fn canonicalize_expr<'a>(
                         }
                     }
                 }
+            } else if let ast::Expr::Crash = loc_fn.value {
+                // `crash` is not a function; it takes exactly one argument, the message.
+
+                debug_assert!(!args.is_empty());
+
+                if args.len() > 1 {
+                    let problem =
+                        roc_problem::can::RuntimeError::CrashAppliedToMultipleArgs(region);
+                    env.problem(Problem::RuntimeError(problem.clone()));
+                    (RuntimeError(problem), output)
+                } else {
+                    let (_, msg) = args.pop().unwrap();
+
+                    let crash = Crash {
+                        msg: Box::new(msg),
+                        ret_var: var_store.fresh(),
+                    };
+
+                    (crash, output)
+                }
             } else {
                 // Canonicalize the function expression and its arguments
                 let (fn_expr, fn_expr_output) =
@@ -834,6 +887,30 @@ pub fn canonicalize_expr<'a>(
 
             (RuntimeError(problem), Output::default())
         }
+        ast::Expr::Crash => {
+            // a `crash` that is not applied to a message
+            let problem = roc_problem::can::RuntimeError::CrashNotApplied(region);
+
+            env.problem(Problem::RuntimeError(problem.clone()));
+
+            (RuntimeError(problem), Output::default())
+        }
+        ast::Expr::MultipleRecordBuilders(sub_expr) => {
+            use roc_problem::can::RuntimeError::*;
+
+            let problem = MultipleRecordBuilders(sub_expr.region);
+            env.problem(Problem::RuntimeError(problem.clone()));
+
+            (RuntimeError(problem), Output::default())
+        }
+        ast::Expr::UnappliedRecordBuilder(sub_expr) => {
+            use roc_problem::can::RuntimeError::*;
+
+            let problem = UnappliedRecordBuilder(sub_expr.region);
+            env.problem(Problem::RuntimeError(problem.clone()));
+
+            (RuntimeError(problem), Output::default())
+        }
         ast::Expr::Defs(loc_defs, loc_ret) => {
             // The body expression gets a new scope for canonicalization,
             scope.inner_scope(|inner_scope| {
@@ -912,6 +989,51 @@ pub fn canonicalize_expr<'a>(
                 output,
             )
         }
+        ast::Expr::Tuple(fields) => {
+            let mut can_elems = Vec::with_capacity(fields.len());
+            let mut references = References::new();
+
+            for loc_elem in fields.iter() {
+                let (can_expr, elem_out) =
+                    canonicalize_expr(env, var_store, scope, loc_elem.region, &loc_elem.value);
+
+                references.union_mut(&elem_out.references);
+
+                can_elems.push((var_store.fresh(), Box::new(can_expr)));
+            }
+
+            let output = Output {
+                references,
+                tail_call: None,
+                ..Default::default()
+            };
+
+            (
+                Tuple {
+                    tuple_var: var_store.fresh(),
+                    elems: can_elems,
+                },
+                output,
+            )
+        }
+        ast::Expr::TupleAccess(tuple_expr, index) => {
+            let (loc_expr, output) = canonicalize_expr(env, var_store, scope, region, tuple_expr);
+
+            // an index too large for a usize can never be in bounds, so leave it to the type
+            // checker to report it like any other out-of-bounds index
+            let index = index.parse().unwrap_or(usize::MAX);
+
+            (
+                TupleAccess {
+                    tuple_var: var_store.fresh(),
+                    ext_var: var_store.fresh(),
+                    elem_var: var_store.fresh(),
+                    loc_expr: Box::new(loc_expr),
+                    index,
+                },
+                output,
+            )
+        }
         ast::Expr::AccessorFunction(field) => (
             Accessor(AccessorData {
                 name: scope.gen_unique_symbol(),
@@ -1006,6 +1128,18 @@ pub fn canonicalize_expr<'a>(
                 output,
             )
         }
+        ast::Expr::Dbg(value) => {
+            let (loc_value, output) =
+                canonicalize_expr(env, var_store, scope, value.region, &value.value);
+
+            (
+                Dbg {
+                    loc_value: Box::new(loc_value),
+                    variable: var_store.fresh(),
+                },
+                output,
+            )
+        }
         ast::Expr::If(if_thens, final_else_branch) => {
             let mut branches = Vec::with_capacity(if_thens.len());
             let mut output = Output::default();
@@ -1144,6 +1278,12 @@ pub fn canonicalize_expr<'a>(
                 bad_expr
             );
         }
+        bad_expr @ ast::Expr::RecordBuilder(_) => {
+            panic!(
+                "A record builder did not get desugared somehow: {:#?}",
+                bad_expr
+            );
+        }
     };
 
     // At the end, diff used_idents and defined_idents to see which were unused.
@@ -1802,6 +1942,33 @@ pub fn inline_calls(var_store: &mut VarStore, scope: &mut Scope, expr: Expr) ->
             }
         }
 
+        Dbg {
+            loc_value,
+            variable,
+        } => {
+            let loc_value = Loc {
+                region: loc_value.region,
+                value: inline_calls(var_store, scope, loc_value.value),
+            };
+
+            Dbg {
+                loc_value: Box::new(loc_value),
+                variable,
+            }
+        }
+
+        Crash { msg, ret_var } => {
+            let msg = Loc {
+                region: msg.region,
+                value: inline_calls(var_store, scope, msg.value),
+            };
+
+            Crash {
+                msg: Box::new(msg),
+                ret_var,
+            }
+        }
+
         LetRec(defs, loc_expr, mark) => {
             let mut new_defs = Vec::with_capacity(defs.len());
 
@@ -1892,6 +2059,24 @@ pub fn inline_calls(var_store: &mut VarStore, scope: &mut Scope, expr: Expr) ->
             todo!("Inlining for Access with record_var {:?}, ext_var {:?}, field_var {:?}, loc_expr {:?}, field {:?}", record_var, ext_var, field_var, loc_expr, field);
         }
 
+        Tuple { tuple_var, elems } => {
+            todo!(
+                "Inlining for Tuple with tuple_var {:?} and elems {:?}",
+                tuple_var,
+                elems
+            );
+        }
+
+        TupleAccess {
+            tuple_var,
+            ext_var,
+            elem_var,
+            loc_expr,
+            index,
+        } => {
+            todo!("Inlining for TupleAccess with tuple_var {:?}, ext_var {:?}, elem_var {:?}, loc_expr {:?}, index {:?}", tuple_var, ext_var, elem_var, loc_expr, index);
+        }
+
         Tag {
             tag_union_var: variant_var,
             ext_var,
@@ -2042,7 +2227,9 @@ fn flatten_str_literal<'a>(
 pub fn is_valid_interpolation(expr: &ast::Expr<'_>) -> bool {
     match expr {
         ast::Expr::Var { .. } => true,
-        ast::Expr::Access(sub_expr, _) => is_valid_interpolation(sub_expr),
+        ast::Expr::Access(sub_expr, _) | ast::Expr::TupleAccess(sub_expr, _) => {
+            is_valid_interpolation(sub_expr)
+        }
         _ => false,
     }
 }
@@ -2196,7 +2383,7 @@ pub fn unescape_char(escaped: &EscapedChar) -> char {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Declarations {
     pub declarations: Vec<DeclarationTag>,
 
@@ -2208,6 +2395,9 @@ pub struct Declarations {
     // used for ability member specializatons.
     pub specializes: VecMap<usize, Symbol>,
 
+    /// the expectations that were generated from examples in doc comments
+    pub doctests: VecSet<usize>,
+
     pub function_bodies: Vec<Loc<FunctionDef>>,
     pub expressions: Vec<Loc<Expr>>,
     pub destructs: Vec<DestructureDef>,
@@ -2232,7 +2422,8 @@ impl Declarations {
             function_bodies: Vec::with_capacity(capacity),
             expressions: Vec::with_capacity(capacity),
             specializes: VecMap::default(), // number of specializations is probably low
-            destructs: Vec::new(),          // number of destructs is probably low
+            doctests: VecSet::default(),
+            destructs: Vec::new(), // number of destructs is probably low
         }
     }
 
@@ -2336,6 +2527,7 @@ impl Declarations {
         preceding_comment: Region,
         name: Symbol,
         loc_expr: Loc<Expr>,
+        is_doctest: bool,
     ) -> usize {
         let index = self.declarations.len();
 
@@ -2346,6 +2538,10 @@ impl Declarations {
 
         self.expressions.push(loc_expr);
 
+        if is_doctest {
+            self.doctests.insert(index);
+        }
+
         index
     }
 
@@ -2521,9 +2717,11 @@ impl Declarations {
             })
     }
 
-    pub fn expects(&self) -> VecMap<Region, Vec<(Symbol, Variable)>> {
+    pub fn expects(&self) -> ExpectCollector {
         let mut collector = ExpectCollector {
             expects: VecMap::default(),
+            dbgs: VecMap::default(),
+            crashes: VecSet::default(),
         };
 
         let var = Variable::EMPTY_RECORD;
@@ -2556,13 +2754,13 @@ impl Declarations {
             }
         }
 
-        collector.expects
+        collector
     }
 }
 
 roc_error_macros::assert_sizeof_default!(DeclarationTag, 8);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum DeclarationTag {
     Value,
     Expectation,
@@ -2591,7 +2789,7 @@ impl DeclarationTag {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FunctionDef {
     pub closure_type: Variable,
     pub return_type: Variable,
@@ -2599,7 +2797,7 @@ pub struct FunctionDef {
     pub arguments: Vec<(Variable, AnnotatedMark, Loc<Pattern>)>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DestructureDef {
     pub loc_pattern: Loc<Pattern>,
     pub pattern_vars: VecMap<Symbol, Variable>,
@@ -2678,6 +2876,12 @@ fn get_lookup_symbols(expr: &Expr, var_store: &mut VarStore) -> Vec<(Symbol, Var
                 stack.push(&argument.1.value);
             }
             Expr::Access { loc_expr, .. }
+            | Expr::TupleAccess { loc_expr, .. }
+            | Expr::Dbg {
+                loc_value: loc_expr,
+                ..
+            }
+            | Expr::Crash { msg: loc_expr, .. }
             | Expr::Closure(ClosureData {
                 loc_body: loc_expr, ..
             }) => {
@@ -2686,6 +2890,9 @@ fn get_lookup_symbols(expr: &Expr, var_store: &mut VarStore) -> Vec<(Symbol, Var
             Expr::Record { fields, .. } => {
                 stack.extend(fields.iter().map(|(_, field)| &field.loc_expr.value));
             }
+            Expr::Tuple { elems, .. } => {
+                stack.extend(elems.iter().map(|(_, elem)| &elem.value));
+            }
             Expr::Expect {
                 loc_continuation, ..
             }
@@ -2807,8 +3014,11 @@ fn toplevel_expect_to_inline_expect_help(mut loc_expr: Loc<Expr>, has_effects: b
     loc_expr
 }
 
-struct ExpectCollector {
-    expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+#[derive(Debug)]
+pub struct ExpectCollector {
+    pub expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+    pub dbgs: VecMap<Region, Variable>,
+    pub crashes: VecSet<Region>,
 }
 
 impl crate::traverse::Visitor for ExpectCollector {
@@ -2827,6 +3037,15 @@ impl crate::traverse::Visitor for ExpectCollector {
                 self.expects
                     .insert(loc_condition.region, lookups_in_cond.to_vec());
             }
+            Expr::Dbg {
+                loc_value,
+                variable,
+            } => {
+                self.dbgs.insert(loc_value.region, *variable);
+            }
+            Expr::Crash { msg, .. } => {
+                self.crashes.insert(msg.region);
+            }
             _ => (),
         }
 
@@ -3,7 +3,7 @@ use crate::annotation::canonicalize_annotation;
 use crate::def::{canonicalize_defs, Def};
 use crate::effect_module::HostedGeneratedFunctions;
 use crate::env::Env;
-use crate::expr::{ClosureData, Declarations, Expr, Output, PendingDerives};
+use crate::expr::{ClosureData, Declarations, ExpectCollector, Expr, Output, PendingDerives};
 use crate::pattern::{BindingsFromPattern, Pattern};
 use crate::scope::Scope;
 use bumpalo::Bump;
@@ -18,7 +18,7 @@ use roc_parse::pattern::PatternType;
 use roc_problem::can::{Problem, RuntimeError};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{ExposedTypesStorageSubs, VarStore, Variable};
-use roc_types::types::{Alias, AliasKind, AliasVar, Type};
+use roc_types::types::{AbilitySet, Alias, AliasKind, AliasVar, Type};
 
 /// The types of all exposed values/functions of a collection of modules
 #[derive(Clone, Debug, Default)]
@@ -131,12 +131,14 @@ pub struct Module {
     pub rigid_variables: RigidVariables,
     pub abilities_store: PendingAbilitiesStore,
     pub loc_expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+    pub loc_dbgs: VecMap<Region, Variable>,
+    pub loc_crashes: VecSet<Region>,
 }
 
 #[derive(Debug, Default)]
 pub struct RigidVariables {
     pub named: MutMap<Variable, Lowercase>,
-    pub able: MutMap<Variable, (Lowercase, Symbol)>,
+    pub able: MutMap<Variable, (Lowercase, AbilitySet)>,
     pub wildcards: VecSet<Variable>,
 }
 
@@ -153,6 +155,8 @@ pub struct ModuleOutput {
     pub pending_derives: PendingDerives,
     pub scope: Scope,
     pub loc_expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+    pub loc_dbgs: VecMap<Region, Variable>,
+    pub loc_crashes: VecSet<Region>,
 }
 
 fn validate_generate_with<'a>(
@@ -386,7 +390,7 @@ pub fn canonicalize_module_defs<'a>(
     for able in output.introduced_variables.able {
         rigid_variables
             .able
-            .insert(able.variable, (able.name, able.ability));
+            .insert(able.variable, (able.name, able.abilities));
     }
 
     for var in output.introduced_variables.wildcards {
@@ -772,7 +776,11 @@ pub fn canonicalize_module_defs<'a>(
         }
     }
 
-    let loc_expects = declarations.expects();
+    let ExpectCollector {
+        expects: loc_expects,
+        dbgs: loc_dbgs,
+        crashes: loc_crashes,
+    } = declarations.expects();
 
     ModuleOutput {
         scope,
@@ -786,6 +794,8 @@ pub fn canonicalize_module_defs<'a>(
         symbols_from_requires,
         pending_derives,
         loc_expects,
+        loc_dbgs,
+        loc_crashes,
     }
 }
 
@@ -894,6 +904,15 @@ fn fix_values_captured_in_closure_pattern(
                 }
             }
         }
+        TupleDestructure { destructs, .. } => {
+            for loc_destruct in destructs.iter_mut() {
+                fix_values_captured_in_closure_pattern(
+                    &mut loc_destruct.value.typ.1.value,
+                    no_capture_symbols,
+                    closure_captures,
+                )
+            }
+        }
         Identifier(_)
         | NumLiteral(..)
         | IntLiteral(..)
@@ -970,6 +989,22 @@ fn fix_values_captured_in_closure_expr(
             );
         }
 
+        Dbg { loc_value, .. } => {
+            fix_values_captured_in_closure_expr(
+                &mut loc_value.value,
+                no_capture_symbols,
+                closure_captures,
+            );
+        }
+
+        Crash { msg, .. } => {
+            fix_values_captured_in_closure_expr(
+                &mut msg.value,
+                no_capture_symbols,
+                closure_captures,
+            );
+        }
+
         Closure(ClosureData {
             captured_symbols,
             name,
@@ -1151,7 +1186,17 @@ fn fix_values_captured_in_closure_expr(
             }
         }
 
-        Access { loc_expr, .. } => {
+        Tuple { elems, .. } => {
+            for (_, elem) in elems.iter_mut() {
+                fix_values_captured_in_closure_expr(
+                    &mut elem.value,
+                    no_capture_symbols,
+                    closure_captures,
+                );
+            }
+        }
+
+        Access { loc_expr, .. } | TupleAccess { loc_expr, .. } => {
             fix_values_captured_in_closure_expr(
                 &mut loc_expr.value,
                 no_capture_symbols,
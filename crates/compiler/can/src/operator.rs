@@ -6,7 +6,9 @@ use roc_module::called_via::BinOp::Pizza;
 use roc_module::called_via::{BinOp, CalledVia};
 use roc_module::ident::ModuleName;
 use roc_parse::ast::Expr::{self, *};
-use roc_parse::ast::{AssignedField, ValueDef, WhenBranch};
+use roc_parse::ast::{
+    AssignedField, Collection, Pattern, RecordBuilderField, ValueDef, WhenBranch,
+};
 use roc_region::all::{Loc, Region};
 
 // BinOp precedence logic adapted from Gluon by Markus Westerlind
@@ -85,11 +87,13 @@ fn desugar_value_def<'a>(arena: &'a Bump, def: &'a ValueDef<'a>) -> ValueDef<'a>
         Expect {
             condition,
             preceding_comment,
+            is_doctest,
         } => {
             let desugared_condition = &*arena.alloc(desugar_expr(arena, condition));
             Expect {
                 condition: desugared_condition,
                 preceding_comment: *preceding_comment,
+                is_doctest: *is_doctest,
             }
         }
         ExpectFx {
@@ -123,9 +127,12 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
         | AccessorFunction(_)
         | Var { .. }
         | Underscore { .. }
+        | Crash
         | MalformedIdent(_, _)
         | MalformedClosure
         | PrecedenceConflict { .. }
+        | MultipleRecordBuilders { .. }
+        | UnappliedRecordBuilder { .. }
         | Tag(_)
         | OpaqueRef(_) => loc_expr,
 
@@ -139,6 +146,16 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
 
             arena.alloc(Loc { region, value })
         }
+        TupleAccess(sub_expr, index) => {
+            let region = loc_expr.region;
+            let loc_sub_expr = Loc {
+                region,
+                value: **sub_expr,
+            };
+            let value = TupleAccess(&desugar_expr(arena, arena.alloc(loc_sub_expr)).value, index);
+
+            arena.alloc(Loc { region, value })
+        }
         List(items) => {
             let mut new_items = Vec::with_capacity_in(items.len(), arena);
 
@@ -153,6 +170,20 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
                 value,
             })
         }
+        Tuple(items) => {
+            let mut new_items = Vec::with_capacity_in(items.len(), arena);
+
+            for item in items.iter() {
+                new_items.push(desugar_expr(arena, item));
+            }
+            let new_items = new_items.into_bump_slice();
+            let value: Expr<'a> = Tuple(items.replace_items(new_items));
+
+            arena.alloc(Loc {
+                region: loc_expr.region,
+                value,
+            })
+        }
         Record(fields) => arena.alloc(Loc {
             region: loc_expr.region,
             value: Record(fields.map_items(arena, |field| {
@@ -164,6 +195,11 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
             })),
         }),
 
+        RecordBuilder(_) => arena.alloc(Loc {
+            value: UnappliedRecordBuilder(loc_expr),
+            region: loc_expr.region,
+        }),
+
         RecordUpdate { fields, update } => {
             // NOTE the `update` field is always a `Var { .. }`, we only desugar it to get rid of
             // any spaces before/after
@@ -237,17 +273,77 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
         }
         Apply(loc_fn, loc_args, called_via) => {
             let mut desugared_args = Vec::with_capacity_in(loc_args.len(), arena);
+            let mut builder_apply_exprs = None;
 
             for loc_arg in loc_args.iter() {
-                desugared_args.push(desugar_expr(arena, loc_arg));
+                let mut current = loc_arg.value;
+                let arg = loop {
+                    match current {
+                        RecordBuilder(fields) => {
+                            if builder_apply_exprs.is_some() {
+                                return arena.alloc(Loc {
+                                    value: MultipleRecordBuilders(loc_expr),
+                                    region: loc_expr.region,
+                                });
+                            }
+
+                            let builder_arg = record_builder_arg(arena, loc_arg.region, fields);
+                            builder_apply_exprs = Some(builder_arg.apply_exprs);
+
+                            break builder_arg.closure;
+                        }
+                        SpaceBefore(expr, _) | SpaceAfter(expr, _) | ParensAround(expr) => {
+                            current = *expr;
+                        }
+                        _ => break loc_arg,
+                    }
+                };
+
+                desugared_args.push(desugar_expr(arena, arg));
             }
 
             let desugared_args = desugared_args.into_bump_slice();
 
-            arena.alloc(Loc {
-                value: Apply(desugar_expr(arena, loc_fn), desugared_args, *called_via),
+            let desugared_fn = desugar_expr(arena, loc_fn);
+
+            let mut apply: &Loc<Expr> = arena.alloc(Loc {
+                value: Apply(desugared_fn, desugared_args, *called_via),
                 region: loc_expr.region,
-            })
+            });
+
+            match builder_apply_exprs {
+                None => {}
+
+                Some(apply_exprs) => {
+                    // `apply` is looked up in the module of the function the builder is given to,
+                    // e.g. `Cli.succeed { ... }` uses `Cli.apply`
+                    let module_name = match desugared_fn.value {
+                        Var { module_name, .. } => module_name,
+                        _ => "",
+                    };
+
+                    for expr in apply_exprs {
+                        let desugared_expr = desugar_expr(arena, expr);
+
+                        let apply_var = arena.alloc(Loc {
+                            value: Var {
+                                module_name,
+                                ident: "apply",
+                            },
+                            region: expr.region,
+                        });
+
+                        let args = arena.alloc_slice_copy(&[apply, desugared_expr]);
+
+                        apply = arena.alloc(Loc {
+                            value: Apply(apply_var, args, CalledVia::RecordBuilder),
+                            region: loc_expr.region,
+                        });
+                    }
+                }
+            }
+
+            apply
         }
         When(loc_cond_expr, branches) => {
             let loc_desugared_cond = &*arena.alloc(desugar_expr(arena, loc_cond_expr));
@@ -344,6 +440,13 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
                 region: loc_expr.region,
             })
         }
+        Dbg(value) => {
+            let desugared_value = &*arena.alloc(desugar_expr(arena, value));
+            arena.alloc(Loc {
+                value: Dbg(desugared_value),
+                region: loc_expr.region,
+            })
+        }
     }
 }
 
@@ -396,6 +499,105 @@ fn desugar_field<'a>(
     }
 }
 
+struct RecordBuilderArg<'a> {
+    closure: &'a Loc<Expr<'a>>,
+    apply_exprs: Vec<'a, &'a Loc<Expr<'a>>>,
+}
+
+/// Turn the fields of a record builder into a curried closure that builds the record,
+/// and the expressions for its `<-` fields, which are given to `apply` in order.
+///
+/// e.g. `{ a: <- x, b: y }` becomes `\#a-2 -> { a: #a-2, b: y }` and `[x]`
+fn record_builder_arg<'a>(
+    arena: &'a Bump,
+    region: Region,
+    fields: Collection<'a, Loc<RecordBuilderField<'a>>>,
+) -> RecordBuilderArg<'a> {
+    let mut record_fields = Vec::with_capacity_in(fields.len(), arena);
+    let mut apply_exprs = Vec::with_capacity_in(fields.len(), arena);
+    let mut apply_field_names = Vec::with_capacity_in(fields.len(), arena);
+
+    // Build the record that the closure will return and gather apply expressions
+
+    for field in fields.iter() {
+        let mut current = field.value;
+
+        let new_field = loop {
+            match current {
+                RecordBuilderField::Value(label, spaces, expr) => {
+                    break AssignedField::RequiredValue(label, spaces, expr)
+                }
+                RecordBuilderField::ApplyValue(label, _, _, expr) => {
+                    // the field label plus its position make for a unique name
+                    // that cannot be written in source code
+                    let name = arena.alloc_str(&format!(
+                        "#{}-{}",
+                        label.value,
+                        label.region.start().offset
+                    ));
+
+                    apply_field_names.push(Loc::at(label.region, &*name));
+                    apply_exprs.push(expr);
+
+                    let var = arena.alloc(Loc {
+                        region: label.region,
+                        value: Var {
+                            module_name: "",
+                            ident: name,
+                        },
+                    });
+
+                    break AssignedField::RequiredValue(label, &[], var);
+                }
+                RecordBuilderField::LabelOnly(label) => break AssignedField::LabelOnly(label),
+                RecordBuilderField::SpaceBefore(sub_field, _) => {
+                    current = *sub_field;
+                }
+                RecordBuilderField::SpaceAfter(sub_field, _) => {
+                    current = *sub_field;
+                }
+                RecordBuilderField::Malformed(malformed) => {
+                    break AssignedField::Malformed(malformed)
+                }
+            }
+        };
+
+        record_fields.push(Loc {
+            value: new_field,
+            region: field.region,
+        });
+    }
+
+    let record_fields = fields.replace_items(record_fields.into_bump_slice());
+
+    let mut body = arena.alloc(Loc {
+        value: Record(record_fields),
+        region,
+    });
+
+    // Construct the builder's closure
+    //
+    // { x: <- apply_x, y: <- apply_y }
+    // \#x -> \#y -> { x: #x, y: #y }
+
+    for name in apply_field_names.iter().rev() {
+        let arg_pattern = arena.alloc(Loc {
+            value: Pattern::Identifier(name.value),
+            region: name.region,
+        });
+
+        body = arena.alloc(Loc {
+            value: Closure(std::slice::from_ref(arg_pattern), body),
+            region,
+        });
+    }
+
+    RecordBuilderArg {
+        closure: body,
+        apply_exprs,
+    }
+}
+
 // TODO move this desugaring to canonicalization, so we can use Symbols instead of strings
 #[inline(always)]
 fn binop_to_function(binop: BinOp) -> (&'static str, &'static str) {
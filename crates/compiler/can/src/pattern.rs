@@ -14,10 +14,11 @@ use roc_problem::can::{MalformedPatternProblem, Problem, RuntimeError, ShadowKin
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{VarStore, Variable};
 use roc_types::types::{LambdaSet, OptAbleVar, PatternCategory, Type};
+use serde::{Deserialize, Serialize};
 
 /// A pattern, including possible problems (e.g. shadowing) so that
 /// codegen can generate a runtime error if this pattern is reached.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Pattern {
     Identifier(Symbol),
     AppliedTag {
@@ -55,6 +56,11 @@ pub enum Pattern {
         ext_var: Variable,
         destructs: Vec<Loc<RecordDestruct>>,
     },
+    TupleDestructure {
+        whole_var: Variable,
+        ext_var: Variable,
+        destructs: Vec<Loc<TupleDestruct>>,
+    },
     NumLiteral(Variable, Box<str>, IntValue, NumBound),
     IntLiteral(Variable, Variable, Box<str>, IntValue, IntBound),
     FloatLiteral(Variable, Variable, Box<str>, f64, FloatBound),
@@ -74,11 +80,15 @@ pub enum Pattern {
     },
 
     // Runtime Exceptions
+    #[serde(skip)]
     Shadowed(Region, Loc<Ident>, Symbol),
+    #[serde(skip)]
     OpaqueNotInScope(Loc<Ident>),
     // Example: (5 = 1 + 2) is an unsupported pattern in an assignment; Int patterns aren't allowed in assignments!
+    #[serde(skip)]
     UnsupportedPattern(Region),
     // parse error patterns
+    #[serde(skip)]
     MalformedPattern(MalformedPatternProblem, Region),
 }
 
@@ -91,6 +101,7 @@ impl Pattern {
             AppliedTag { whole_var, .. } => Some(*whole_var),
             UnwrappedOpaque { whole_var, .. } => Some(*whole_var),
             RecordDestructure { whole_var, .. } => Some(*whole_var),
+            TupleDestructure { whole_var, .. } => Some(*whole_var),
             NumLiteral(var, ..) => Some(*var),
             IntLiteral(var, ..) => Some(*var),
             FloatLiteral(var, ..) => Some(*var),
@@ -118,6 +129,9 @@ impl Pattern {
             | MalformedPattern(..)
             | AbilityMemberSpecialization { .. } => true,
             RecordDestructure { destructs, .. } => destructs.is_empty(),
+            TupleDestructure { destructs, .. } => destructs
+                .iter()
+                .all(|d| d.value.typ.1.value.surely_exhaustive()),
             AppliedTag { .. }
             | NumLiteral(..)
             | IntLiteral(..)
@@ -144,6 +158,7 @@ impl Pattern {
             UnwrappedOpaque { opaque, .. } => C::Opaque(*opaque),
             RecordDestructure { destructs, .. } if destructs.is_empty() => C::EmptyRecord,
             RecordDestructure { .. } => C::Record,
+            TupleDestructure { .. } => C::Tuple,
             NumLiteral(..) => C::Num,
             IntLiteral(..) => C::Int,
             FloatLiteral(..) => C::Float,
@@ -160,7 +175,7 @@ impl Pattern {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RecordDestruct {
     pub var: Variable,
     pub label: Lowercase,
@@ -168,7 +183,14 @@ pub struct RecordDestruct {
     pub typ: DestructType,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TupleDestruct {
+    pub var: Variable,
+    pub destruct_index: usize,
+    pub typ: (Variable, Loc<Pattern>),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum DestructType {
     Required,
     Optional(Variable, Loc<Expr>),
@@ -608,6 +630,40 @@ pub fn canonicalize_pattern<'a>(
             })
         }
 
+        Tuple(patterns) => {
+            let ext_var = var_store.fresh();
+            let whole_var = var_store.fresh();
+            let mut destructs = Vec::with_capacity(patterns.len());
+
+            for (destruct_index, loc_pattern) in patterns.iter().enumerate() {
+                let can_pattern = canonicalize_pattern(
+                    env,
+                    var_store,
+                    scope,
+                    output,
+                    pattern_type,
+                    &loc_pattern.value,
+                    loc_pattern.region,
+                    permit_shadows,
+                );
+
+                destructs.push(Loc {
+                    region: loc_pattern.region,
+                    value: TupleDestruct {
+                        var: var_store.fresh(),
+                        destruct_index,
+                        typ: (var_store.fresh(), can_pattern),
+                    },
+                });
+            }
+
+            Pattern::TupleDestructure {
+                whole_var,
+                ext_var,
+                destructs,
+            }
+        }
+
         RequiredField(_name, _loc_pattern) => {
             unreachable!("should have been handled in RecordDestructure");
         }
@@ -720,6 +776,10 @@ impl<'a> BindingsFromPattern<'a> {
                             let it = destructs.iter().rev().map(Destruct);
                             stack.extend(it);
                         }
+                        TupleDestructure { destructs, .. } => {
+                            let it = destructs.iter().rev().map(|d| Pattern(&d.value.typ.1));
+                            stack.extend(it);
+                        }
                         NumLiteral(..)
                         | IntLiteral(..)
                         | FloatLiteral(..)
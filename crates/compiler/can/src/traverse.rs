@@ -1,7 +1,7 @@
 //! Traversals over the can ast.
 
 use roc_module::{ident::Lowercase, symbol::Symbol};
-use roc_region::all::{Loc, Region};
+use roc_region::all::{Loc, Position, Region};
 use roc_types::{subs::Variable, types::MemberImpl};
 
 use crate::{
@@ -227,12 +227,25 @@ pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr, var: Variable) {
         }
         Expr::EmptyRecord => { /* terminal */ }
         Expr::Access {
-            field_var,
+            field_var: _,
             loc_expr,
             field: _,
-            record_var: _,
+            record_var,
             ext_var: _,
-        } => visitor.visit_expr(&loc_expr.value, loc_expr.region, *field_var),
+        } => visitor.visit_expr(&loc_expr.value, loc_expr.region, *record_var),
+        Expr::Tuple {
+            tuple_var: _,
+            elems,
+        } => elems
+            .iter()
+            .for_each(|(v, le)| visitor.visit_expr(&le.value, le.region, *v)),
+        Expr::TupleAccess {
+            elem_var: _,
+            loc_expr,
+            index: _,
+            tuple_var,
+            ext_var: _,
+        } => visitor.visit_expr(&loc_expr.value, loc_expr.region, *tuple_var),
         Expr::Accessor(AccessorData { .. }) => { /* terminal */ }
         Expr::OpaqueWrapFunction(OpaqueWrapFunctionData { .. }) => { /* terminal */ }
         Expr::Update {
@@ -289,6 +302,15 @@ pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr, var: Variable) {
                 Variable::NULL,
             );
         }
+        Expr::Dbg {
+            loc_value,
+            variable,
+        } => {
+            visitor.visit_expr(&loc_value.value, loc_value.region, *variable);
+        }
+        Expr::Crash { msg, .. } => {
+            visitor.visit_expr(&msg.value, msg.region, Variable::STR);
+        }
         Expr::TypedHole(_) => { /* terminal */ }
         Expr::RuntimeError(..) => { /* terminal */ }
     }
@@ -472,6 +494,10 @@ pub fn walk_pattern<V: Visitor>(visitor: &mut V, pattern: &Pattern) {
         RecordDestructure { destructs, .. } => destructs
             .iter()
             .for_each(|d| visitor.visit_record_destruct(&d.value, d.region)),
+        TupleDestructure { destructs, .. } => destructs.iter().for_each(|d| {
+            let (v, lp) = &d.value.typ;
+            visitor.visit_pattern(&lp.value, lp.region, Some(*v))
+        }),
         NumLiteral(..) => { /* terminal */ }
         IntLiteral(..) => { /* terminal */ }
         FloatLiteral(..) => { /* terminal */ }
@@ -533,6 +559,161 @@ pub fn find_type_at(region: Region, decls: &Declarations) -> Option<Variable> {
     visitor.typ
 }
 
+/// Attempts to find the innermost expression or pattern whose region contains `pos`, returning
+/// that region along with the expression or pattern's type.
+pub fn find_closest_type_at(pos: Position, decls: &Declarations) -> Option<(Region, Variable)> {
+    let mut visitor = Finder {
+        region: Region::new(pos, pos),
+        found: None,
+    };
+    visitor.visit_decls(decls);
+    return visitor.found;
+
+    struct Finder {
+        region: Region,
+        found: Option<(Region, Variable)>,
+    }
+
+    impl Finder {
+        fn record(&mut self, region: Region, var: Variable) {
+            // Synthesized nodes (e.g. the arguments of a low-level call) have no real region,
+            // and expectations are not given a type.
+            if !region.is_empty() && var != Variable::NULL {
+                self.found = Some((region, var));
+            }
+        }
+    }
+
+    impl Visitor for Finder {
+        fn should_visit(&mut self, region: Region) -> bool {
+            region.contains(&self.region)
+        }
+
+        fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+            if self.should_visit(region) {
+                self.record(region, var);
+                walk_expr(self, expr, var);
+            }
+        }
+
+        fn visit_pattern(&mut self, pattern: &Pattern, region: Region, opt_var: Option<Variable>) {
+            if self.should_visit(region) {
+                if let Some(var) = opt_var {
+                    self.record(region, var);
+                }
+                walk_pattern(self, pattern);
+            }
+        }
+
+        fn visit_record_destruct(&mut self, destruct: &RecordDestruct, region: Region) {
+            if self.should_visit(region) {
+                self.record(region, destruct.var);
+                walk_record_destruct(self, destruct);
+            }
+        }
+    }
+}
+
+/// Attempts to find the symbol that is looked up or introduced at `pos`.
+pub fn find_symbol_at(pos: Position, decls: &Declarations) -> Option<Loc<Symbol>> {
+    let mut visitor = Finder {
+        region: Region::new(pos, pos),
+        found: None,
+    };
+    visitor.visit_decls(decls);
+    return visitor.found;
+
+    struct Finder {
+        region: Region,
+        found: Option<Loc<Symbol>>,
+    }
+
+    impl Visitor for Finder {
+        fn should_visit(&mut self, region: Region) -> bool {
+            region.contains(&self.region)
+        }
+
+        fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+            if self.should_visit(region) {
+                match expr {
+                    Expr::Var(symbol) | Expr::AbilityMember(symbol, _, _) => {
+                        self.found = Some(Loc::at(region, *symbol));
+                    }
+                    _ => walk_expr(self, expr, var),
+                }
+            }
+        }
+
+        fn visit_pattern(&mut self, pattern: &Pattern, region: Region, _opt_var: Option<Variable>) {
+            if self.should_visit(region) {
+                match pattern {
+                    Pattern::Identifier(symbol)
+                    | Pattern::Shadowed(_, _, symbol)
+                    | Pattern::AbilityMemberSpecialization { ident: symbol, .. } => {
+                        self.found = Some(Loc::at(region, *symbol));
+                    }
+                    _ => walk_pattern(self, pattern),
+                }
+            }
+        }
+
+        fn visit_record_destruct(&mut self, destruct: &RecordDestruct, region: Region) {
+            if self.should_visit(region) {
+                match &destruct.typ {
+                    DestructType::Guard(..) => walk_record_destruct(self, destruct),
+                    _ => self.found = Some(Loc::at(region, destruct.symbol)),
+                }
+            }
+        }
+    }
+}
+
+/// Attempts to find the region of the pattern that introduces `symbol`.
+pub fn find_declaration(symbol: Symbol, decls: &Declarations) -> Option<Region> {
+    let mut visitor = Finder {
+        symbol,
+        found: None,
+    };
+    visitor.visit_decls(decls);
+    return visitor.found;
+
+    struct Finder {
+        symbol: Symbol,
+        found: Option<Region>,
+    }
+
+    impl Visitor for Finder {
+        fn should_visit(&mut self, _region: Region) -> bool {
+            self.found.is_none()
+        }
+
+        fn visit_pattern(&mut self, pattern: &Pattern, region: Region, _opt_var: Option<Variable>) {
+            match pattern {
+                Pattern::Identifier(symbol)
+                | Pattern::Shadowed(_, _, symbol)
+                | Pattern::AbilityMemberSpecialization { ident: symbol, .. }
+                    if *symbol == self.symbol =>
+                {
+                    self.found = Some(region);
+                }
+                _ => {
+                    if self.should_visit(region) {
+                        walk_pattern(self, pattern);
+                    }
+                }
+            }
+        }
+
+        fn visit_record_destruct(&mut self, destruct: &RecordDestruct, region: Region) {
+            match &destruct.typ {
+                DestructType::Guard(..) => walk_record_destruct(self, destruct),
+                _ if destruct.symbol == self.symbol => self.found = Some(region),
+                _ => walk_record_destruct(self, destruct),
+            }
+        }
+    }
+}
+
 /// Given an ability Foo has foo : ..., returns (T, foo1) if the symbol at the given region is a
 /// symbol foo1 that specializes foo for T. Otherwise if the symbol is foo but the specialization
 /// is unknown, (Foo, foo) is returned. Otherwise [None] is returned.
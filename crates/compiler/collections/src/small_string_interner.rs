@@ -1,7 +1,8 @@
+use serde::{Deserialize, Serialize};
 use std::{fmt::Debug, mem::ManuallyDrop};
 
 /// Collection of small (length < u16::MAX) strings, stored compactly.
-#[derive(Clone, Default, PartialEq, Eq)]
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SmallStringInterner {
     buffer: Vec<u8>,
 
@@ -11,7 +12,7 @@ pub struct SmallStringInterner {
     offsets: Vec<u32>,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(transparent)]
 struct Length(i16);
 
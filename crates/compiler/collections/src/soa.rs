@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
 use std::usize;
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct Index<T> {
     index: u32,
     _marker: std::marker::PhantomData<T>,
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use std::iter::FromIterator;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VecSet<T> {
     elements: Vec<T>,
 }
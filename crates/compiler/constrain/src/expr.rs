@@ -20,6 +20,7 @@ use roc_can::traverse::symbols_introduced_from_pattern;
 use roc_collections::all::{HumanIndex, MutMap, SendMap};
 use roc_collections::soa::Index;
 use roc_collections::VecMap;
+use roc_module::called_via::CalledVia;
 use roc_module::ident::Lowercase;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::{Loc, Region};
@@ -227,6 +228,39 @@ pub fn constrain_expr(
                 constraints.exists(field_vars, and_constraint)
             }
         }
+        Expr::Tuple { tuple_var, elems } => {
+            let mut elem_types = Vec::with_capacity(elems.len());
+            let mut elem_vars = Vec::with_capacity(elems.len() + 1);
+
+            // Constraints need capacity for each element + 1 for the tuple itself
+            let mut tuple_constraints = Vec::with_capacity(1 + elems.len());
+
+            for (index, (elem_var, loc_elem_expr)) in elems.iter().enumerate() {
+                let (elem_type, elem_con) =
+                    constrain_field(constraints, env, *elem_var, loc_elem_expr);
+
+                elem_vars.push(*elem_var);
+                elem_types.push((index, elem_type));
+
+                tuple_constraints.push(elem_con);
+            }
+
+            let tuple_type = Type::Tuple(elem_types, TypeExtension::Closed);
+
+            let tuple_con = constraints.equal_types_with_storage(
+                tuple_type,
+                expected,
+                Category::Tuple,
+                region,
+                *tuple_var,
+            );
+
+            tuple_constraints.push(tuple_con);
+            elem_vars.push(*tuple_var);
+
+            let and_constraint = constraints.and_constraint(tuple_constraints);
+            constraints.exists(elem_vars, and_constraint)
+        }
         Update {
             record_var,
             ext_var,
@@ -381,9 +415,13 @@ pub fn constrain_expr(
                 let region = loc_arg.region;
                 let arg_type = Variable(*arg_var);
 
-                let reason = Reason::FnArg {
-                    name: opt_symbol,
-                    arg_index: HumanIndex::zero_based(index),
+                let reason = if *called_via == CalledVia::RecordBuilder && index == 1 {
+                    Reason::RecordBuilderField
+                } else {
+                    Reason::FnArg {
+                        name: opt_symbol,
+                        arg_index: HumanIndex::zero_based(index),
+                    }
                 };
                 let expected_arg = ForReason(reason, arg_type.clone(), region);
                 let arg_con = constrain_expr(
@@ -567,6 +605,40 @@ pub fn constrain_expr(
             constraints.exists_many(vars, all_constraints)
         }
 
+        Dbg {
+            loc_value,
+            variable,
+        } => {
+            let value_con = constrain_expr(
+                constraints,
+                env,
+                loc_value.region,
+                &loc_value.value,
+                NoExpectation(Type::Variable(*variable)),
+            );
+
+            let dbg_con = constraints.equal_types_var(
+                *variable,
+                expected,
+                loc_value.value.category(),
+                region,
+            );
+
+            constraints.exists_many([*variable], [value_con, dbg_con])
+        }
+
+        Crash { msg, ret_var } => {
+            let expected_msg = Expected::ForReason(Reason::CrashArg, str_type(), msg.region);
+
+            let msg_is_str = constrain_expr(constraints, env, msg.region, &msg.value, expected_msg);
+
+            // a crash never returns, so it can have whatever type is expected of it
+            let crash_con =
+                constraints.equal_types_var(*ret_var, expected, Category::Crash, region);
+
+            constraints.exists_many([*ret_var], [msg_is_str, crash_con])
+        }
+
         If {
             cond_var,
             branch_var,
@@ -937,6 +1009,39 @@ pub fn constrain_expr(
                 [constraint, eq, record_con],
             )
         }
+        TupleAccess {
+            tuple_var,
+            ext_var,
+            elem_var,
+            loc_expr,
+            index,
+        } => {
+            let ext_var = *ext_var;
+            let ext_type = Type::Variable(ext_var);
+            let elem_var = *elem_var;
+            let elem_type = Type::Variable(elem_var);
+
+            let tuple_type = Type::Tuple(
+                vec![(*index, elem_type)],
+                TypeExtension::from_type(ext_type),
+            );
+            let tuple_expected = Expected::NoExpectation(tuple_type);
+
+            let category = Category::TupleAccess(*index);
+
+            let tuple_con = constraints.equal_types_var(
+                *tuple_var,
+                tuple_expected.clone(),
+                category.clone(),
+                region,
+            );
+
+            let constraint =
+                constrain_expr(constraints, env, region, &loc_expr.value, tuple_expected);
+
+            let eq = constraints.equal_types_var(elem_var, expected, category, region);
+            constraints.exists_many([*tuple_var, elem_var, ext_var], [constraint, eq, tuple_con])
+        }
         Accessor(AccessorData {
             name: closure_name,
             function_var,
@@ -1121,7 +1226,7 @@ pub fn constrain_expr(
                     .iter()
                     .map(|v| OptAbleType {
                         typ: Type::Variable(v.var),
-                        opt_ability: v.opt_ability,
+                        opt_abilities: v.opt_abilities.clone(),
                     })
                     .collect(),
                 lambda_set_variables: lambda_set_variables.clone(),
@@ -1186,7 +1291,7 @@ pub fn constrain_expr(
                     .iter()
                     .map(|v| OptAbleType {
                         typ: Type::Variable(v.var),
-                        opt_ability: v.opt_ability,
+                        opt_abilities: v.opt_abilities.clone(),
                     })
                     .collect(),
                 lambda_set_variables: lambda_set_variables.clone(),
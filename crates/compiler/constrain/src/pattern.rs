@@ -3,7 +3,7 @@ use crate::expr::{constrain_expr, Env};
 use roc_can::constraint::{Constraint, Constraints};
 use roc_can::expected::{Expected, PExpected};
 use roc_can::pattern::Pattern::{self, *};
-use roc_can::pattern::{DestructType, RecordDestruct};
+use roc_can::pattern::{DestructType, RecordDestruct, TupleDestruct};
 use roc_collections::all::{HumanIndex, SendMap};
 use roc_collections::VecMap;
 use roc_module::ident::Lowercase;
@@ -101,6 +101,22 @@ fn headers_from_annotation_help(
             _ => false,
         },
 
+        TupleDestructure { destructs, .. } => match annotation.value.shallow_dealias() {
+            Type::Tuple(elems, _) => destructs.iter().all(|loc_destruct| {
+                let destruct = &loc_destruct.value;
+
+                match elems.iter().find(|(index, _)| *index == destruct.destruct_index) {
+                    Some((_, elem_type)) => headers_from_annotation_help(
+                        &destruct.typ.1.value,
+                        &Loc::at(annotation.region, elem_type),
+                        headers,
+                    ),
+                    None => false,
+                }
+            }),
+            _ => false,
+        },
+
         AppliedTag {
             tag_name,
             arguments,
@@ -446,6 +462,71 @@ pub fn constrain_pattern(
             state.constraints.push(whole_con);
             state.constraints.push(record_con);
         }
+        TupleDestructure {
+            whole_var,
+            ext_var,
+            destructs,
+        } => {
+            state.vars.push(*whole_var);
+            state.vars.push(*ext_var);
+            let ext_type = Type::Variable(*ext_var);
+
+            let mut elem_types = Vec::with_capacity(destructs.len());
+
+            for Loc {
+                value:
+                    TupleDestruct {
+                        var,
+                        destruct_index,
+                        typ: (guard_var, loc_guard),
+                    },
+                ..
+            } in destructs
+            {
+                let pat_type = Type::Variable(*var);
+                let expected = PExpected::NoExpectation(pat_type.clone());
+
+                state.constraints.push(constraints.pattern_presence(
+                    Type::Variable(*guard_var),
+                    PExpected::ForReason(PReason::PatternGuard, pat_type.clone(), loc_guard.region),
+                    PatternCategory::PatternGuard,
+                    region,
+                ));
+                state.vars.push(*guard_var);
+
+                constrain_pattern(
+                    constraints,
+                    env,
+                    &loc_guard.value,
+                    loc_guard.region,
+                    expected,
+                    state,
+                );
+
+                elem_types.push((*destruct_index, pat_type));
+
+                state.vars.push(*var);
+            }
+
+            let tuple_type = Type::Tuple(elem_types, TypeExtension::from_type(ext_type));
+
+            let whole_con = constraints.equal_types(
+                Type::Variable(*whole_var),
+                Expected::NoExpectation(tuple_type),
+                Category::Storage(std::file!(), std::line!()),
+                region,
+            );
+
+            let tuple_con = constraints.pattern_presence(
+                Type::Variable(*whole_var),
+                expected,
+                PatternCategory::Tuple,
+                region,
+            );
+
+            state.constraints.push(whole_con);
+            state.constraints.push(tuple_con);
+        }
         AppliedTag {
             whole_var,
             ext_var,
@@ -518,7 +599,7 @@ pub fn constrain_pattern(
                     .iter()
                     .map(|v| OptAbleType {
                         typ: Type::Variable(v.var),
-                        opt_ability: v.opt_ability,
+                        opt_abilities: v.opt_abilities.clone(),
                     })
                     .collect(),
                 lambda_set_variables: lambda_set_variables.clone(),
@@ -0,0 +1,720 @@
+//! Derivers for the `Arbitrary` ability.
+
+use roc_can::expr::{
+    AnnotatedMark, ClosureData, Expr, Field, IntValue, Recursive, WhenBranch, WhenBranchPattern,
+};
+use roc_can::pattern::Pattern;
+use roc_collections::SendMap;
+use roc_derive_key::arbitrary::FlatArbitraryKey;
+use roc_module::called_via::CalledVia;
+use roc_module::ident::{Lowercase, TagName};
+use roc_module::symbol::Symbol;
+use roc_region::all::{Loc, Region};
+use roc_types::num::{IntBound, IntLitWidth};
+use roc_types::subs::{
+    Content, ExhaustiveMark, FlatType, LambdaSet, OptVariable, RecordFields, RedundantMark,
+    SubsSlice, UnionLambdas, UnionTags, Variable, VariableSubsSlice,
+};
+use roc_types::types::RecordField;
+
+use crate::util::{Env, ExtensionKind};
+use crate::{synth_var, DerivedBody};
+
+pub(crate) fn derive_arbitrary(
+    env: &mut Env<'_>,
+    key: FlatArbitraryKey,
+    _def_symbol: Symbol,
+) -> DerivedBody {
+    let (body, body_type) = match key {
+        FlatArbitraryKey::Builtin(generator) => arbitrary_builtin(env, generator),
+        FlatArbitraryKey::List() => arbitrary_list(env),
+        FlatArbitraryKey::Record(fields) => arbitrary_record(env, fields),
+        FlatArbitraryKey::TagUnion(tags) => arbitrary_tag_union(env, tags),
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::GEN_ARBITRARY);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+fn arbitrary_builtin(env: &mut Env<'_>, generator: Symbol) -> (Expr, Variable) {
+    // Build
+    //
+    //   def_symbol : Gen U8
+    //   def_symbol = Gen.custom \choices -> Gen.generateWith choices Gen.u8
+    //
+    // The generators in `Gen` are plain values, so they have their own lambda sets, rather than
+    // the specialization lambda sets of `Gen.arbitrary`.
+    let generator_var = env.import_builtin_symbol_var(generator);
+
+    wrap_in_gen_custom(env, |env, choices_sym, choices_var| {
+        let value_var = env.subs.fresh_unnamed_flex_var();
+        let generated_var = generated_record_var(env, value_var, choices_var);
+
+        let generate_with_call = call_generate_with(
+            env,
+            (Expr::Var(choices_sym), choices_var),
+            (Expr::Var(generator), generator_var),
+            generated_var,
+        );
+
+        (generate_with_call, generated_var)
+    })
+}
+
+fn arbitrary_list(env: &mut Env<'_>) -> (Expr, Variable) {
+    // Build
+    //
+    //   def_symbol : Gen (List elem) | elem has Arbitrary
+    //   def_symbol = Gen.custom \choices -> Gen.generateWith choices (Gen.list Gen.arbitrary)
+    use Expr::*;
+
+    // Gen.list Gen.arbitrary : Gen (List elem)
+    let (gen_list_call, gen_list_ret_var) = {
+        // Gen.arbitrary : Gen elem | elem has Arbitrary
+        let (elem_arbitrary, elem_arbitrary_var) = arbitrary_member(env);
+
+        // Gen a -> Gen (List a)
+        let gen_list_fn_var = env.import_builtin_symbol_var(Symbol::GEN_LIST);
+
+        // Gen elem -a-> b
+        let elem_arbitrary_var_slice = SubsSlice::insert_into_subs(env.subs, [elem_arbitrary_var]);
+        let this_gen_list_clos_var = env.subs.fresh_unnamed_flex_var();
+        let this_gen_list_ret_var = env.subs.fresh_unnamed_flex_var();
+        let this_gen_list_fn_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Func(
+                elem_arbitrary_var_slice,
+                this_gen_list_clos_var,
+                this_gen_list_ret_var,
+            )),
+        );
+
+        //   Gen a    -[clos]-> Gen (List a)
+        // ~ Gen elem -a     -> b
+        env.unify(gen_list_fn_var, this_gen_list_fn_var);
+
+        let gen_list_fn = Box::new((
+            this_gen_list_fn_var,
+            Loc::at_zero(Var(Symbol::GEN_LIST)),
+            this_gen_list_clos_var,
+            this_gen_list_ret_var,
+        ));
+
+        let gen_list_call = Call(
+            gen_list_fn,
+            vec![(elem_arbitrary_var, Loc::at_zero(elem_arbitrary))],
+            CalledVia::Space,
+        );
+
+        (gen_list_call, this_gen_list_ret_var)
+    };
+
+    wrap_in_gen_custom(env, |env, choices_sym, choices_var| {
+        let value_var = env.subs.fresh_unnamed_flex_var();
+        let generated_var = generated_record_var(env, value_var, choices_var);
+
+        let generate_with_call = call_generate_with(
+            env,
+            (Var(choices_sym), choices_var),
+            (gen_list_call, gen_list_ret_var),
+            generated_var,
+        );
+
+        (generate_with_call, generated_var)
+    })
+}
+
+fn arbitrary_record(env: &mut Env<'_>, fields: Vec<Lowercase>) -> (Expr, Variable) {
+    // Suppose fields = { a, b }. Build
+    //
+    //   def_symbol : Gen { a: t1, b: t2 } | t1 has Arbitrary, t2 has Arbitrary
+    //   def_symbol = Gen.custom \choices ->
+    //       when Gen.generateWith choices Gen.arbitrary is
+    //           generated ->
+    //               when Gen.generateWith generated.choices Gen.arbitrary is
+    //                   generated2 ->
+    //                       { value: { a: generated.value, b: generated2.value }, choices: generated2.choices }
+    //
+    // For the empty record, this is just `Gen.custom \choices -> { value: {}, choices }`.
+
+    // Generalized record var so we can reuse this impl between many records:
+    // if fields = { a, b }, this is { a: t1, b: t2 } for fresh t1, t2.
+    let field_vars: Vec<_> = fields
+        .iter()
+        .map(|_| env.subs.fresh_unnamed_flex_var())
+        .collect();
+
+    let record_var = if fields.is_empty() {
+        Variable::EMPTY_RECORD
+    } else {
+        let record_fields = fields
+            .iter()
+            .zip(field_vars.iter())
+            .map(|(name, &var)| (name.clone(), RecordField::Required(var)));
+        let record_fields = RecordFields::insert_into_subs(env.subs, record_fields);
+
+        synth_var(
+            env.subs,
+            Content::Structure(FlatType::Record(record_fields, Variable::EMPTY_RECORD)),
+        )
+    };
+
+    wrap_in_gen_custom(env, |env, choices_sym, choices_var| {
+        let generated_var = generated_record_var(env, record_var, choices_var);
+
+        let body = generate_in_turn(
+            env,
+            (Expr::Var(choices_sym), choices_var),
+            &field_vars,
+            (record_var, generated_var),
+            |_env, values| {
+                if values.is_empty() {
+                    return Expr::EmptyRecord;
+                }
+
+                let mut fields_map = SendMap::default();
+
+                for ((name, field_var), value) in
+                    fields.into_iter().zip(field_vars.iter()).zip(values)
+                {
+                    fields_map.insert(
+                        name,
+                        Field {
+                            var: *field_var,
+                            region: Region::zero(),
+                            loc_expr: Box::new(Loc::at_zero(value)),
+                        },
+                    );
+                }
+
+                Expr::Record {
+                    record_var,
+                    fields: fields_map,
+                }
+            },
+        );
+
+        (body, generated_var)
+    })
+}
+
+fn arbitrary_tag_union(env: &mut Env<'_>, tags: Vec<(TagName, u16)>) -> (Expr, Variable) {
+    // Suppose tags = [ A t1 t2, B, C t3 ]. Build
+    //
+    //   def_symbol : Gen [ A t1 t2, B, C t3 ] | t1 has Arbitrary, t2 has Arbitrary, t3 has Arbitrary
+    //   def_symbol = Gen.custom \choices ->
+    //       when Gen.generateWith choices (Gen.natBelow 3) is
+    //           tag ->
+    //               when tag.value is
+    //                   0 -> { value: B, choices: tag.choices }
+    //                   1 ->
+    //                       when Gen.generateWith tag.choices Gen.arbitrary is
+    //                           generated -> { value: C generated.value, choices: generated.choices }
+    //                   _ ->
+    //                       when Gen.generateWith tag.choices Gen.arbitrary is
+    //                           generated2 ->
+    //                               when Gen.generateWith generated2.choices Gen.arbitrary is
+    //                                   generated3 ->
+    //                                       { value: A generated2.value generated3.value, choices: generated3.choices }
+    //
+    // Tags with fewer payloads come first, because the choice `0` should make the simplest value,
+    // and because that is what ends a recursive tag union once the choices run out.
+    //
+    // There is no value of the empty tag union to generate, so it crashes instead.
+
+    // Generalized tag union var so we can reuse this impl between many unions:
+    // if tags = [ A arity=2, B arity=1 ], this is [ A t1 t2, B t3 ] for fresh t1, t2, t3
+    let payload_vars: Vec<Vec<Variable>> = tags
+        .iter()
+        .map(|(_, arity)| {
+            (0..*arity)
+                .map(|_| env.subs.fresh_unnamed_flex_var())
+                .collect()
+        })
+        .collect();
+
+    let union_tags: Vec<_> = tags
+        .iter()
+        .zip(payload_vars.iter())
+        .map(|((name, _), vars)| {
+            let vars_slice = VariableSubsSlice::insert_into_subs(env.subs, vars.iter().copied());
+            (name.clone(), vars_slice)
+        })
+        .collect();
+    let union_tags = UnionTags::insert_slices_into_subs(env.subs, union_tags);
+    let tag_union_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::TagUnion(union_tags, Variable::EMPTY_TAG_UNION)),
+    );
+
+    let mut generated_tags: Vec<_> = tags
+        .into_iter()
+        .zip(payload_vars)
+        .map(|((name, _), vars)| (name, vars))
+        .collect();
+    generated_tags
+        .sort_by(|(name1, vars1), (name2, vars2)| (vars1.len(), name1).cmp(&(vars2.len(), name2)));
+
+    wrap_in_gen_custom(env, |env, choices_sym, choices_var| {
+        let generated_var = generated_record_var(env, tag_union_var, choices_var);
+
+        let body = match generated_tags.len() {
+            0 => Expr::Crash {
+                msg: Box::new(Loc::at_zero(Expr::Str(
+                    "There are no values of an empty tag union to generate".into(),
+                ))),
+                ret_var: generated_var,
+            },
+            1 => {
+                let (name, vars) = generated_tags.pop().unwrap();
+
+                generate_tag(
+                    env,
+                    (Expr::Var(choices_sym), choices_var),
+                    (tag_union_var, name, &vars),
+                    generated_var,
+                )
+            }
+            num_tags => {
+                // Gen.natBelow 3 : Gen Nat
+                let (nat_below_call, nat_below_var) = {
+                    let nat_below_fn_var = env.import_builtin_symbol_var(Symbol::GEN_NAT_BELOW);
+
+                    let this_nat_below_args =
+                        SubsSlice::insert_into_subs(env.subs, [Variable::NAT]);
+                    let this_nat_below_clos_var = env.subs.fresh_unnamed_flex_var();
+                    let this_nat_below_ret_var = env.subs.fresh_unnamed_flex_var();
+                    let this_nat_below_fn_var = synth_var(
+                        env.subs,
+                        Content::Structure(FlatType::Func(
+                            this_nat_below_args,
+                            this_nat_below_clos_var,
+                            this_nat_below_ret_var,
+                        )),
+                    );
+
+                    //   Nat -[clos]-> Gen Nat
+                    // ~ Nat -a     -> b
+                    env.unify(nat_below_fn_var, this_nat_below_fn_var);
+
+                    let nat_below_fn = Box::new((
+                        this_nat_below_fn_var,
+                        Loc::at_zero(Expr::Var(Symbol::GEN_NAT_BELOW)),
+                        this_nat_below_clos_var,
+                        this_nat_below_ret_var,
+                    ));
+
+                    let num_tags_expr = Expr::Int(
+                        Variable::NAT,
+                        Variable::NATURAL,
+                        num_tags.to_string().into_boxed_str(),
+                        IntValue::I128((num_tags as i128).to_ne_bytes()),
+                        IntBound::Exact(IntLitWidth::Nat),
+                    );
+
+                    let nat_below_call = Expr::Call(
+                        nat_below_fn,
+                        vec![(Variable::NAT, Loc::at_zero(num_tags_expr))],
+                        CalledVia::Space,
+                    );
+
+                    (nat_below_call, this_nat_below_ret_var)
+                };
+
+                // Gen.generateWith choices (Gen.natBelow 3) : { value : Nat, choices : Choices }
+                let tag_sym = env.new_symbol("tag");
+                let tag_var = generated_record_var(env, Variable::NAT, choices_var);
+                let generate_tag_call = call_generate_with(
+                    env,
+                    (Expr::Var(choices_sym), choices_var),
+                    (nat_below_call, nat_below_var),
+                    tag_var,
+                );
+
+                // 0 -> { value: B, choices: tag.choices }
+                // 1 -> ...
+                // _ -> ...
+                let branches = generated_tags
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, (name, vars))| {
+                        let pattern = if index + 1 == num_tags {
+                            Pattern::Underscore
+                        } else {
+                            Pattern::IntLiteral(
+                                Variable::NAT,
+                                Variable::NATURAL,
+                                index.to_string().into_boxed_str(),
+                                IntValue::I128((index as i128).to_ne_bytes()),
+                                IntBound::Exact(IntLitWidth::Nat),
+                            )
+                        };
+
+                        let tag_choices = access(env, (tag_sym, tag_var), "choices", choices_var);
+                        let body = generate_tag(
+                            env,
+                            (tag_choices, choices_var),
+                            (tag_union_var, name, &vars),
+                            generated_var,
+                        );
+
+                        WhenBranch {
+                            patterns: vec![WhenBranchPattern {
+                                pattern: Loc::at_zero(pattern),
+                                degenerate: false,
+                            }],
+                            value: Loc::at_zero(body),
+                            guard: None,
+                            redundant: RedundantMark::known_non_redundant(),
+                        }
+                    })
+                    .collect();
+
+                // when tag.value is
+                let tag_value = access(env, (tag_sym, tag_var), "value", Variable::NAT);
+                let when_tag_value = Expr::When {
+                    loc_cond: Box::new(Loc::at_zero(tag_value)),
+                    cond_var: Variable::NAT,
+                    expr_var: generated_var,
+                    region: Region::zero(),
+                    branches,
+                    branches_cond_var: Variable::NAT,
+                    exhaustive: ExhaustiveMark::known_exhaustive(),
+                };
+
+                bind(
+                    (generate_tag_call, tag_var),
+                    tag_sym,
+                    (when_tag_value, generated_var),
+                )
+            }
+        };
+
+        (body, generated_var)
+    })
+}
+
+// Generates the payloads of a tag in turn, e.g.
+//
+//   when Gen.generateWith choices Gen.arbitrary is
+//       generated -> { value: C generated.value, choices: generated.choices }
+fn generate_tag(
+    env: &mut Env<'_>,
+    choices: (Expr, Variable),
+    (tag_union_var, name, payload_vars): (Variable, TagName, &[Variable]),
+    generated_var: Variable,
+) -> Expr {
+    generate_in_turn(
+        env,
+        choices,
+        payload_vars,
+        (tag_union_var, generated_var),
+        |env, values| Expr::Tag {
+            tag_union_var,
+            ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+            name,
+            arguments: payload_vars
+                .iter()
+                .zip(values)
+                .map(|(var, value)| (*var, Loc::at_zero(value)))
+                .collect(),
+        },
+    )
+}
+
+// Generates a value of each of `value_vars` with `Gen.arbitrary` in turn, each with the choices
+// the one before it left, and finishes with the value made out of them:
+//
+//   when Gen.generateWith choices Gen.arbitrary is
+//       generated ->
+//           when Gen.generateWith generated.choices Gen.arbitrary is
+//               generated2 -> { value: make_value generated.value generated2.value, choices: generated2.choices }
+fn generate_in_turn(
+    env: &mut Env<'_>,
+    (choices, choices_var): (Expr, Variable),
+    value_vars: &[Variable],
+    (value_var, generated_var): (Variable, Variable),
+    make_value: impl FnOnce(&mut Env<'_>, Vec<Expr>) -> Expr,
+) -> Expr {
+    // generated, generated2, ...: what each call to `Gen.generateWith` returns
+    let generated: Vec<_> = value_vars
+        .iter()
+        .map(|&value_var| {
+            let symbol = env.new_symbol("generated");
+            let var = generated_record_var(env, value_var, choices_var);
+
+            (symbol, var)
+        })
+        .collect();
+
+    // { value: make_value generated.value generated2.value, choices: generated2.choices }
+    let body = {
+        let values = generated
+            .iter()
+            .zip(value_vars)
+            .map(|(&generated, &var)| access(env, generated, "value", var))
+            .collect();
+        let value = make_value(env, values);
+
+        let last_choices = match generated.last() {
+            Some(&last) => access(env, last, "choices", choices_var),
+            None => choices.clone(),
+        };
+
+        let mut fields_map = SendMap::default();
+
+        fields_map.insert(
+            "choices".into(),
+            Field {
+                var: choices_var,
+                region: Region::zero(),
+                loc_expr: Box::new(Loc::at_zero(last_choices)),
+            },
+        );
+
+        fields_map.insert(
+            "value".into(),
+            Field {
+                var: value_var,
+                region: Region::zero(),
+                loc_expr: Box::new(Loc::at_zero(value)),
+            },
+        );
+
+        Expr::Record {
+            record_var: generated_var,
+            fields: fields_map,
+        }
+    };
+
+    // when Gen.generateWith generated.choices Gen.arbitrary is generated2 -> body
+    generated
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(body, |body, (index, &(symbol, var))| {
+            let choices = match index.checked_sub(1) {
+                Some(previous) => access(env, generated[previous], "choices", choices_var),
+                None => choices.clone(),
+            };
+
+            let (arbitrary, arbitrary_var) = arbitrary_member(env);
+            let generate_with_call =
+                call_generate_with(env, (choices, choices_var), (arbitrary, arbitrary_var), var);
+
+            bind((generate_with_call, var), symbol, (body, generated_var))
+        })
+}
+
+// Gen.arbitrary : Gen a | a has Arbitrary
+fn arbitrary_member(env: &mut Env<'_>) -> (Expr, Variable) {
+    let arbitrary_var = env.import_builtin_symbol_var(Symbol::GEN_ARBITRARY);
+
+    (
+        Expr::AbilityMember(Symbol::GEN_ARBITRARY, None, arbitrary_var),
+        arbitrary_var,
+    )
+}
+
+// { value : value_var, choices : choices_var }, which is what a generator returns
+fn generated_record_var(env: &mut Env<'_>, value_var: Variable, choices_var: Variable) -> Variable {
+    let fields = RecordFields::insert_into_subs(
+        env.subs,
+        [
+            ("choices".into(), RecordField::Required(choices_var)),
+            ("value".into(), RecordField::Required(value_var)),
+        ],
+    );
+
+    synth_var(
+        env.subs,
+        Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+    )
+}
+
+// generated.value
+fn access(
+    env: &mut Env<'_>,
+    (record_sym, record_var): (Symbol, Variable),
+    field: &str,
+    field_var: Variable,
+) -> Expr {
+    Expr::Access {
+        record_var,
+        ext_var: env.new_ext_var(ExtensionKind::Record),
+        field_var,
+        loc_expr: Box::new(Loc::at_zero(Expr::Var(record_sym))),
+        field: field.into(),
+    }
+}
+
+// when value is symbol -> body
+fn bind(
+    (value, value_var): (Expr, Variable),
+    symbol: Symbol,
+    (body, body_var): (Expr, Variable),
+) -> Expr {
+    let branch = WhenBranch {
+        patterns: vec![WhenBranchPattern {
+            pattern: Loc::at_zero(Pattern::Identifier(symbol)),
+            degenerate: false,
+        }],
+        value: Loc::at_zero(body),
+        guard: None,
+        redundant: RedundantMark::known_non_redundant(),
+    };
+
+    Expr::When {
+        loc_cond: Box::new(Loc::at_zero(value)),
+        cond_var: value_var,
+        expr_var: body_var,
+        region: Region::zero(),
+        branches: vec![branch],
+        branches_cond_var: value_var,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    }
+}
+
+// Gen.generateWith choices generator : { value : a, choices : Choices }
+fn call_generate_with(
+    env: &mut Env<'_>,
+    (choices, choices_var): (Expr, Variable),
+    (generator, generator_var): (Expr, Variable),
+    generated_var: Variable,
+) -> Expr {
+    // Choices, Gen a -> { value : a, choices : Choices }
+    let generate_with_fn_var = env.import_builtin_symbol_var(Symbol::GEN_GENERATE_WITH);
+
+    // choices_var, generator_var -a-> generated_var
+    let this_generate_with_args =
+        SubsSlice::insert_into_subs(env.subs, [choices_var, generator_var]);
+    let this_generate_with_clos_var = env.subs.fresh_unnamed_flex_var();
+    let this_generate_with_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_generate_with_args,
+            this_generate_with_clos_var,
+            generated_var,
+        )),
+    );
+
+    //   Choices,     Gen a         -[clos]-> { value : a, choices : Choices }
+    // ~ choices_var, generator_var -a     -> generated_var
+    env.unify(generate_with_fn_var, this_generate_with_fn_var);
+
+    let generate_with_fn = Box::new((
+        this_generate_with_fn_var,
+        Loc::at_zero(Expr::Var(Symbol::GEN_GENERATE_WITH)),
+        this_generate_with_clos_var,
+        generated_var,
+    ));
+
+    Expr::Call(
+        generate_with_fn,
+        vec![
+            (choices_var, Loc::at_zero(choices)),
+            (generator_var, Loc::at_zero(generator)),
+        ],
+        CalledVia::Space,
+    )
+}
+
+// Wraps the body that `make_body` builds out of the choices in `Gen.custom \choices -> body`.
+// Like the decoding derivers, this is so that the derived generator has the specialization
+// lambda set of `Gen.arbitrary`.
+fn wrap_in_gen_custom(
+    env: &mut Env<'_>,
+    make_body: impl FnOnce(&mut Env<'_>, Symbol, Variable) -> (Expr, Variable),
+) -> (Expr, Variable) {
+    use Expr::*;
+
+    let choices_sym = env.new_symbol("choices");
+    let choices_var = env.subs.fresh_unnamed_flex_var();
+
+    let (body, body_var) = make_body(env, choices_sym, choices_var);
+
+    // \choices -[[fn_name]]-> body
+    let (custom_lambda, custom_var) = {
+        let fn_name = env.new_symbol("custom");
+
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[[fn_name]]->
+        let lambda_set = LambdaSet {
+            solved: UnionLambdas::tag_without_arguments(env.subs, fn_name),
+            recursion_var: OptVariable::NONE,
+            unspecialized: SubsSlice::default(),
+            ambient_function: fn_var,
+        };
+        let fn_clos_var = synth_var(env.subs, Content::LambdaSet(lambda_set));
+
+        // choices -[[fn_name]]-> { value : a, choices : Choices }
+        let args_slice = SubsSlice::insert_into_subs(env.subs, [choices_var]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(args_slice, fn_clos_var, body_var)),
+        );
+
+        let clos = Closure(ClosureData {
+            function_type: fn_var,
+            closure_type: fn_clos_var,
+            return_type: body_var,
+            name: fn_name,
+            captured_symbols: vec![],
+            recursive: Recursive::NotRecursive,
+            arguments: vec![(
+                choices_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(Pattern::Identifier(choices_sym)),
+            )],
+            loc_body: Box::new(Loc::at_zero(body)),
+        });
+
+        (clos, fn_var)
+    };
+
+    // Gen.custom \choices -> body
+    let (gen_custom_call, gen_var) = {
+        // (Choices -> { value : a, choices : Choices }) -> Gen a
+        let gen_custom_type = env.import_builtin_symbol_var(Symbol::GEN_CUSTOM);
+
+        let this_gen_custom_args = SubsSlice::insert_into_subs(env.subs, [custom_var]);
+        let this_gen_custom_clos_var = env.subs.fresh_unnamed_flex_var();
+        let this_gen_custom_ret_var = env.subs.fresh_unnamed_flex_var();
+        let this_gen_custom_fn_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Func(
+                this_gen_custom_args,
+                this_gen_custom_clos_var,
+                this_gen_custom_ret_var,
+            )),
+        );
+
+        //   (Choices     -> { value : a, choices : Choices }) -> Gen a
+        // ~ (choices_var -> body_var)                         -> b
+        env.unify(gen_custom_type, this_gen_custom_fn_var);
+
+        let gen_custom_fn = Box::new((
+            this_gen_custom_fn_var,
+            Loc::at_zero(Var(Symbol::GEN_CUSTOM)),
+            this_gen_custom_clos_var,
+            this_gen_custom_ret_var,
+        ));
+        let gen_custom_call = Call(
+            gen_custom_fn,
+            vec![(custom_var, Loc::at_zero(custom_lambda))],
+            CalledVia::Space,
+        );
+
+        (gen_custom_call, this_gen_custom_ret_var)
+    };
+
+    (gen_custom_call, gen_var)
+}
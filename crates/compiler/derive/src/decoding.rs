@@ -1,16 +1,17 @@
 //! Derivers for the `Decoding` ability.
 
 use roc_can::expr::{
-    AnnotatedMark, ClosureData, Expr, Field, Recursive, WhenBranch, WhenBranchPattern,
+    AnnotatedMark, ClosureData, Expr, Field, IntValue, Recursive, WhenBranch, WhenBranchPattern,
 };
 use roc_can::pattern::Pattern;
 use roc_collections::SendMap;
 use roc_derive_key::decoding::FlatDecodableKey;
 use roc_error_macros::internal_error;
 use roc_module::called_via::CalledVia;
-use roc_module::ident::Lowercase;
+use roc_module::ident::{Lowercase, TagName};
 use roc_module::symbol::Symbol;
 use roc_region::all::{Loc, Region};
+use roc_types::num::{IntBound, IntLitWidth};
 use roc_types::subs::{
     Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
     RedundantMark, SubsSlice, UnionLambdas, UnionTags, Variable,
@@ -28,6 +29,7 @@ pub(crate) fn derive_decoder(
     let (body, body_type) = match key {
         FlatDecodableKey::List() => decoder_list(env, def_symbol),
         FlatDecodableKey::Record(fields) => decoder_record(env, def_symbol, fields),
+        FlatDecodableKey::TagUnion(tags) => decoder_tag_union(env, def_symbol, tags),
     };
 
     let specialization_lambda_sets =
@@ -236,378 +238,14 @@ fn decoder_record_step_field(
         //                 }
         //     )
 
-        let this_custom_callback_var;
-        let custom_callback_ret_var;
-        let custom_callback = {
-            // \bytes, fmt ->
-            //     when Decode.decodeWith bytes Decode.decoder fmt is
-            //         rec ->
-            //             {
-            //                 rest: rec.rest,
-            //                 result: when rec.result is
-            //                     Ok val -> Ok {state & first: Ok val},
-            //                     Err err -> Err err
-            //             }
-            let bytes_arg_symbol = env.new_symbol("bytes");
-            let fmt_arg_symbol = env.new_symbol("fmt");
-            let bytes_arg_var = env.subs.fresh_unnamed_flex_var();
-            let fmt_arg_var = env.subs.fresh_unnamed_flex_var();
-
-            // rec.result : [Ok field_var, Err DecodeError]
-            let rec_dot_result = {
-                let tag_union = FlatType::TagUnion(
-                    UnionTags::for_result(env.subs, field_var, decode_err_var),
-                    Variable::EMPTY_TAG_UNION,
-                );
-
-                synth_var(env.subs, Content::Structure(tag_union))
-            };
-
-            // rec : { rest: List U8, result: (typeof rec.result) }
-            let rec_var = {
-                let fields = RecordFields::insert_into_subs(
-                    env.subs,
-                    [
-                        ("rest".into(), RecordField::Required(Variable::LIST_U8)),
-                        ("result".into(), RecordField::Required(rec_dot_result)),
-                    ],
-                );
-                let record = FlatType::Record(fields, Variable::EMPTY_RECORD);
-
-                synth_var(env.subs, Content::Structure(record))
-            };
-
-            // `Decode.decoder` for the field's value
-            let decoder_var = env.import_builtin_symbol_var(Symbol::DECODE_DECODER);
-            let decode_with_var = env.import_builtin_symbol_var(Symbol::DECODE_DECODE_WITH);
-            let lambda_set_var = env.subs.fresh_unnamed_flex_var();
-            let this_decode_with_var = {
-                let subs_slice = SubsSlice::insert_into_subs(
-                    env.subs,
-                    [bytes_arg_var, decoder_var, fmt_arg_var],
-                );
-                let this_decode_with_var = synth_var(
-                    env.subs,
-                    Content::Structure(FlatType::Func(subs_slice, lambda_set_var, rec_var)),
-                );
-
-                env.unify(decode_with_var, this_decode_with_var);
-
-                this_decode_with_var
-            };
-
-            // The result of decoding this field's value - either the updated state, or a decoding error.
-            let when_expr_var = {
-                let flat_type = FlatType::TagUnion(
-                    UnionTags::for_result(env.subs, state_record_var, decode_err_var),
-                    Variable::EMPTY_TAG_UNION,
-                );
-
-                synth_var(env.subs, Content::Structure(flat_type))
-            };
-
-            // What our decoder passed to `Decode.custom` returns - the result of decoding the
-            // field's value, and the remaining bytes.
-            custom_callback_ret_var = {
-                let rest_field = RecordField::Required(Variable::LIST_U8);
-                let result_field = RecordField::Required(when_expr_var);
-                let flat_type = FlatType::Record(
-                    RecordFields::insert_into_subs(
-                        env.subs,
-                        [("rest".into(), rest_field), ("result".into(), result_field)],
-                    ),
-                    Variable::EMPTY_RECORD,
-                );
-
-                synth_var(env.subs, Content::Structure(flat_type))
-            };
-
-            let custom_callback_body = {
-                let rec_symbol = env.new_symbol("rec");
-
-                // # Uses a single-branch `when` because `let` is more expensive to monomorphize
-                // # due to checks for polymorphic expressions, and `rec` would be polymorphic.
-                // when Decode.decodeWith bytes Decode.decoder fmt is
-                //     rec ->
-                //         {
-                //             rest: rec.rest,
-                //             result: when rec.result is
-                //                 Ok val -> Ok {state & first: Ok val},
-                //                 Err err -> Err err
-                //         }
-                let branch_body = {
-                    let result_val = {
-                        // result: when rec.result is
-                        //     Ok val -> Ok {state & first: Ok val},
-                        //     Err err -> Err err
-                        let ok_val_symbol = env.new_symbol("val");
-                        let err_val_symbol = env.new_symbol("err");
-                        let ok_branch_expr = {
-                            // Ok {state & first: Ok val},
-                            let mut updates = SendMap::default();
-
-                            updates.insert(
-                                field_name.clone(),
-                                Field {
-                                    var: result_field_var,
-                                    region: Region::zero(),
-                                    loc_expr: Box::new(Loc::at_zero(Expr::Tag {
-                                        tag_union_var: result_field_var,
-                                        ext_var: env.new_ext_var(ExtensionKind::TagUnion),
-                                        name: "Ok".into(),
-                                        arguments: vec![(
-                                            field_var,
-                                            Loc::at_zero(Expr::Var(ok_val_symbol)),
-                                        )],
-                                    })),
-                                },
-                            );
-
-                            let updated_record = Expr::Update {
-                                record_var: state_record_var,
-                                ext_var: env.new_ext_var(ExtensionKind::Record),
-                                symbol: state_arg_symbol,
-                                updates,
-                            };
-
-                            Expr::Tag {
-                                tag_union_var: when_expr_var,
-                                ext_var: env.new_ext_var(ExtensionKind::TagUnion),
-                                name: "Ok".into(),
-                                arguments: vec![(state_record_var, Loc::at_zero(updated_record))],
-                            }
-                        };
-
-                        let branches = vec![
-                            // Ok val -> Ok {state & first: Ok val},
-                            WhenBranch {
-                                patterns: vec![WhenBranchPattern {
-                                    pattern: Loc::at_zero(Pattern::AppliedTag {
-                                        whole_var: rec_dot_result,
-                                        ext_var: Variable::EMPTY_TAG_UNION,
-                                        tag_name: "Ok".into(),
-                                        arguments: vec![(
-                                            field_var,
-                                            Loc::at_zero(Pattern::Identifier(ok_val_symbol)),
-                                        )],
-                                    }),
-                                    degenerate: false,
-                                }],
-                                value: Loc::at_zero(ok_branch_expr),
-                                guard: None,
-                                redundant: RedundantMark::known_non_redundant(),
-                            },
-                            // Err err -> Err err
-                            WhenBranch {
-                                patterns: vec![WhenBranchPattern {
-                                    pattern: Loc::at_zero(Pattern::AppliedTag {
-                                        whole_var: rec_dot_result,
-                                        ext_var: Variable::EMPTY_TAG_UNION,
-                                        tag_name: "Err".into(),
-                                        arguments: vec![(
-                                            decode_err_var,
-                                            Loc::at_zero(Pattern::Identifier(err_val_symbol)),
-                                        )],
-                                    }),
-                                    degenerate: false,
-                                }],
-                                value: Loc::at_zero(Expr::Tag {
-                                    tag_union_var: when_expr_var,
-                                    ext_var: env.new_ext_var(ExtensionKind::TagUnion),
-                                    name: "Err".into(),
-                                    arguments: vec![(
-                                        decode_err_var,
-                                        Loc::at_zero(Expr::Var(err_val_symbol)),
-                                    )],
-                                }),
-                                guard: None,
-                                redundant: RedundantMark::known_non_redundant(),
-                            },
-                        ];
-
-                        // when rec.result is
-                        //     Ok val -> Ok {state & first: Ok val},
-                        //     Err err -> Err err
-                        Expr::When {
-                            loc_cond: Box::new(Loc::at_zero(Expr::Access {
-                                record_var: rec_var,
-                                ext_var: env.new_ext_var(ExtensionKind::Record),
-                                field_var: rec_dot_result,
-                                loc_expr: Box::new(Loc::at_zero(Expr::Var(rec_symbol))),
-                                field: "result".into(),
-                            })),
-                            cond_var: rec_dot_result,
-                            expr_var: when_expr_var,
-                            region: Region::zero(),
-                            branches,
-                            branches_cond_var: rec_dot_result,
-                            exhaustive: ExhaustiveMark::known_exhaustive(),
-                        }
-                    };
-
-                    // {
-                    //     rest: rec.rest,
-                    //     result: when rec.result is
-                    //         Ok val -> Ok {state & first: Ok val},
-                    //         Err err -> Err err
-                    // }
-                    let mut fields_map = SendMap::default();
-
-                    fields_map.insert(
-                        "rest".into(),
-                        Field {
-                            var: Variable::LIST_U8,
-                            region: Region::zero(),
-                            loc_expr: Box::new(Loc::at_zero(Expr::Access {
-                                record_var: rec_var,
-                                ext_var: env.new_ext_var(ExtensionKind::Record),
-                                field_var: Variable::LIST_U8,
-                                loc_expr: Box::new(Loc::at_zero(Expr::Var(rec_symbol))),
-                                field: "rest".into(),
-                            })),
-                        },
-                    );
-
-                    // result: when rec.result is
-                    //     Ok val -> Ok {state & first: Ok val},
-                    //     Err err -> Err err
-                    fields_map.insert(
-                        "result".into(),
-                        Field {
-                            var: when_expr_var,
-                            region: Region::zero(),
-                            loc_expr: Box::new(Loc::at_zero(result_val)),
-                        },
-                    );
-
-                    Expr::Record {
-                        record_var: custom_callback_ret_var,
-                        fields: fields_map,
-                    }
-                };
-
-                let branch = WhenBranch {
-                    patterns: vec![WhenBranchPattern {
-                        pattern: Loc::at_zero(Pattern::Identifier(rec_symbol)),
-                        degenerate: false,
-                    }],
-                    value: Loc::at_zero(branch_body),
-                    guard: None,
-                    redundant: RedundantMark::known_non_redundant(),
-                };
-
-                let condition_expr = Expr::Call(
-                    Box::new((
-                        this_decode_with_var,
-                        Loc::at_zero(Expr::Var(Symbol::DECODE_DECODE_WITH)),
-                        lambda_set_var,
-                        rec_var,
-                    )),
-                    vec![
-                        (Variable::LIST_U8, Loc::at_zero(Expr::Var(bytes_arg_symbol))),
-                        (
-                            decoder_var,
-                            Loc::at_zero(Expr::AbilityMember(
-                                Symbol::DECODE_DECODER,
-                                None,
-                                decoder_var,
-                            )),
-                        ),
-                        (fmt_arg_var, Loc::at_zero(Expr::Var(fmt_arg_symbol))),
-                    ],
-                    CalledVia::Space,
-                );
-
-                // when Decode.decodeWith bytes Decode.decoder fmt is
-                Expr::When {
-                    loc_cond: Box::new(Loc::at_zero(condition_expr)),
-                    cond_var: rec_var,
-                    expr_var: custom_callback_ret_var,
-                    region: Region::zero(),
-                    branches: vec![branch],
-                    branches_cond_var: rec_var,
-                    exhaustive: ExhaustiveMark::known_exhaustive(),
-                }
-            };
-
-            let custom_closure_symbol = env.new_symbol("customCallback");
-            this_custom_callback_var = env.subs.fresh_unnamed_flex_var();
-            let custom_callback_lambda_set_var = {
-                let content = Content::LambdaSet(LambdaSet {
-                    solved: UnionLambdas::insert_into_subs(
-                        env.subs,
-                        [(custom_closure_symbol, [state_record_var])],
-                    ),
-                    recursion_var: OptVariable::NONE,
-                    unspecialized: Default::default(),
-                    ambient_function: this_custom_callback_var,
-                });
-                let custom_callback_lambda_set_var = synth_var(env.subs, content);
-                let subs_slice =
-                    SubsSlice::insert_into_subs(env.subs, [bytes_arg_var, fmt_arg_var]);
-
-                env.subs.set_content(
-                    this_custom_callback_var,
-                    Content::Structure(FlatType::Func(
-                        subs_slice,
-                        custom_callback_lambda_set_var,
-                        custom_callback_ret_var,
-                    )),
-                );
-
-                custom_callback_lambda_set_var
-            };
-
-            // \bytes, fmt -> …
-            Expr::Closure(ClosureData {
-                function_type: this_custom_callback_var,
-                closure_type: custom_callback_lambda_set_var,
-                return_type: custom_callback_ret_var,
-                name: custom_closure_symbol,
-                captured_symbols: vec![(state_arg_symbol, state_record_var)],
-                recursive: Recursive::NotRecursive,
-                arguments: vec![
-                    (
-                        bytes_arg_var,
-                        AnnotatedMark::known_exhaustive(),
-                        Loc::at_zero(Pattern::Identifier(bytes_arg_symbol)),
-                    ),
-                    (
-                        fmt_arg_var,
-                        AnnotatedMark::known_exhaustive(),
-                        Loc::at_zero(Pattern::Identifier(fmt_arg_symbol)),
-                    ),
-                ],
-                loc_body: Box::new(Loc::at_zero(custom_callback_body)),
-            })
-        };
-
-        let decode_custom_ret_var = env.subs.fresh_unnamed_flex_var();
-        let decode_custom = {
-            let decode_custom_var = env.import_builtin_symbol_var(Symbol::DECODE_CUSTOM);
-            let decode_custom_closure_var = env.subs.fresh_unnamed_flex_var();
-            let this_decode_custom_var = {
-                let subs_slice = SubsSlice::insert_into_subs(env.subs, [this_custom_callback_var]);
-                let flat_type =
-                    FlatType::Func(subs_slice, decode_custom_closure_var, decode_custom_ret_var);
-
-                synth_var(env.subs, Content::Structure(flat_type))
-            };
-
-            env.unify(decode_custom_var, this_decode_custom_var);
-
-            // Decode.custom \bytes, fmt -> …
-            Expr::Call(
-                Box::new((
-                    this_decode_custom_var,
-                    Loc::at_zero(Expr::Var(Symbol::DECODE_CUSTOM)),
-                    decode_custom_closure_var,
-                    decode_custom_ret_var,
-                )),
-                vec![(this_custom_callback_var, Loc::at_zero(custom_callback))],
-                CalledVia::Space,
-            )
-        };
+        let (decode_custom, decode_custom_ret_var) = custom_decoder_into_state(
+            env,
+            (state_arg_symbol, state_record_var),
+            &field_name,
+            field_var,
+            result_field_var,
+            decode_err_var,
+        );
 
         env.unify(keep_payload_var, decode_custom_ret_var);
 
@@ -732,6 +370,399 @@ fn decoder_record_step_field(
     (expr, function_type)
 }
 
+// Example, for a value stored in the `first` field of the decoding state:
+// Decode.custom \bytes, fmt ->
+//     # Uses a single-branch `when` because `let` is more expensive to monomorphize
+//     # due to checks for polymorphic expressions, and `rec` would be polymorphic.
+//     when Decode.decodeWith bytes Decode.decoder fmt is
+//         rec ->
+//             {
+//                 rest: rec.rest,
+//                 result: when rec.result is
+//                     Ok val -> Ok {state & first: Ok val},
+//                     Err err -> Err err
+//             }
+fn custom_decoder_into_state(
+    env: &mut Env,
+    (state_arg_symbol, state_record_var): (Symbol, Variable),
+    field_name: &Lowercase,
+    field_var: Variable,
+    result_field_var: Variable,
+    decode_err_var: Variable,
+) -> (Expr, Variable) {
+    let this_custom_callback_var;
+    let custom_callback_ret_var;
+    let custom_callback = {
+        // \bytes, fmt ->
+        //     when Decode.decodeWith bytes Decode.decoder fmt is
+        //         rec ->
+        //             {
+        //                 rest: rec.rest,
+        //                 result: when rec.result is
+        //                     Ok val -> Ok {state & first: Ok val},
+        //                     Err err -> Err err
+        //             }
+        let bytes_arg_symbol = env.new_symbol("bytes");
+        let fmt_arg_symbol = env.new_symbol("fmt");
+        let bytes_arg_var = env.subs.fresh_unnamed_flex_var();
+        let fmt_arg_var = env.subs.fresh_unnamed_flex_var();
+
+        // rec.result : [Ok field_var, Err DecodeError]
+        let rec_dot_result = {
+            let tag_union = FlatType::TagUnion(
+                UnionTags::for_result(env.subs, field_var, decode_err_var),
+                Variable::EMPTY_TAG_UNION,
+            );
+
+            synth_var(env.subs, Content::Structure(tag_union))
+        };
+
+        // rec : { rest: List U8, result: (typeof rec.result) }
+        let rec_var = {
+            let fields = RecordFields::insert_into_subs(
+                env.subs,
+                [
+                    ("rest".into(), RecordField::Required(Variable::LIST_U8)),
+                    ("result".into(), RecordField::Required(rec_dot_result)),
+                ],
+            );
+            let record = FlatType::Record(fields, Variable::EMPTY_RECORD);
+
+            synth_var(env.subs, Content::Structure(record))
+        };
+
+        // `Decode.decoder` for the field's value
+        let decoder_var = env.import_builtin_symbol_var(Symbol::DECODE_DECODER);
+        let decode_with_var = env.import_builtin_symbol_var(Symbol::DECODE_DECODE_WITH);
+        let lambda_set_var = env.subs.fresh_unnamed_flex_var();
+        let this_decode_with_var = {
+            let subs_slice =
+                SubsSlice::insert_into_subs(env.subs, [bytes_arg_var, decoder_var, fmt_arg_var]);
+            let this_decode_with_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Func(subs_slice, lambda_set_var, rec_var)),
+            );
+
+            env.unify(decode_with_var, this_decode_with_var);
+
+            this_decode_with_var
+        };
+
+        // The result of decoding this field's value - either the updated state, or a decoding error.
+        let when_expr_var = {
+            let flat_type = FlatType::TagUnion(
+                UnionTags::for_result(env.subs, state_record_var, decode_err_var),
+                Variable::EMPTY_TAG_UNION,
+            );
+
+            synth_var(env.subs, Content::Structure(flat_type))
+        };
+
+        // What our decoder passed to `Decode.custom` returns - the result of decoding the
+        // field's value, and the remaining bytes.
+        custom_callback_ret_var = {
+            let rest_field = RecordField::Required(Variable::LIST_U8);
+            let result_field = RecordField::Required(when_expr_var);
+            let flat_type = FlatType::Record(
+                RecordFields::insert_into_subs(
+                    env.subs,
+                    [("rest".into(), rest_field), ("result".into(), result_field)],
+                ),
+                Variable::EMPTY_RECORD,
+            );
+
+            synth_var(env.subs, Content::Structure(flat_type))
+        };
+
+        let custom_callback_body = {
+            let rec_symbol = env.new_symbol("rec");
+
+            // # Uses a single-branch `when` because `let` is more expensive to monomorphize
+            // # due to checks for polymorphic expressions, and `rec` would be polymorphic.
+            // when Decode.decodeWith bytes Decode.decoder fmt is
+            //     rec ->
+            //         {
+            //             rest: rec.rest,
+            //             result: when rec.result is
+            //                 Ok val -> Ok {state & first: Ok val},
+            //                 Err err -> Err err
+            //         }
+            let branch_body = {
+                let result_val = {
+                    // result: when rec.result is
+                    //     Ok val -> Ok {state & first: Ok val},
+                    //     Err err -> Err err
+                    let ok_val_symbol = env.new_symbol("val");
+                    let err_val_symbol = env.new_symbol("err");
+                    let ok_branch_expr = {
+                        // Ok {state & first: Ok val},
+                        let mut updates = SendMap::default();
+
+                        updates.insert(
+                            field_name.clone(),
+                            Field {
+                                var: result_field_var,
+                                region: Region::zero(),
+                                loc_expr: Box::new(Loc::at_zero(Expr::Tag {
+                                    tag_union_var: result_field_var,
+                                    ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+                                    name: "Ok".into(),
+                                    arguments: vec![(
+                                        field_var,
+                                        Loc::at_zero(Expr::Var(ok_val_symbol)),
+                                    )],
+                                })),
+                            },
+                        );
+
+                        let updated_record = Expr::Update {
+                            record_var: state_record_var,
+                            ext_var: env.new_ext_var(ExtensionKind::Record),
+                            symbol: state_arg_symbol,
+                            updates,
+                        };
+
+                        Expr::Tag {
+                            tag_union_var: when_expr_var,
+                            ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+                            name: "Ok".into(),
+                            arguments: vec![(state_record_var, Loc::at_zero(updated_record))],
+                        }
+                    };
+
+                    let branches = vec![
+                        // Ok val -> Ok {state & first: Ok val},
+                        WhenBranch {
+                            patterns: vec![WhenBranchPattern {
+                                pattern: Loc::at_zero(Pattern::AppliedTag {
+                                    whole_var: rec_dot_result,
+                                    ext_var: Variable::EMPTY_TAG_UNION,
+                                    tag_name: "Ok".into(),
+                                    arguments: vec![(
+                                        field_var,
+                                        Loc::at_zero(Pattern::Identifier(ok_val_symbol)),
+                                    )],
+                                }),
+                                degenerate: false,
+                            }],
+                            value: Loc::at_zero(ok_branch_expr),
+                            guard: None,
+                            redundant: RedundantMark::known_non_redundant(),
+                        },
+                        // Err err -> Err err
+                        WhenBranch {
+                            patterns: vec![WhenBranchPattern {
+                                pattern: Loc::at_zero(Pattern::AppliedTag {
+                                    whole_var: rec_dot_result,
+                                    ext_var: Variable::EMPTY_TAG_UNION,
+                                    tag_name: "Err".into(),
+                                    arguments: vec![(
+                                        decode_err_var,
+                                        Loc::at_zero(Pattern::Identifier(err_val_symbol)),
+                                    )],
+                                }),
+                                degenerate: false,
+                            }],
+                            value: Loc::at_zero(Expr::Tag {
+                                tag_union_var: when_expr_var,
+                                ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+                                name: "Err".into(),
+                                arguments: vec![(
+                                    decode_err_var,
+                                    Loc::at_zero(Expr::Var(err_val_symbol)),
+                                )],
+                            }),
+                            guard: None,
+                            redundant: RedundantMark::known_non_redundant(),
+                        },
+                    ];
+
+                    // when rec.result is
+                    //     Ok val -> Ok {state & first: Ok val},
+                    //     Err err -> Err err
+                    Expr::When {
+                        loc_cond: Box::new(Loc::at_zero(Expr::Access {
+                            record_var: rec_var,
+                            ext_var: env.new_ext_var(ExtensionKind::Record),
+                            field_var: rec_dot_result,
+                            loc_expr: Box::new(Loc::at_zero(Expr::Var(rec_symbol))),
+                            field: "result".into(),
+                        })),
+                        cond_var: rec_dot_result,
+                        expr_var: when_expr_var,
+                        region: Region::zero(),
+                        branches,
+                        branches_cond_var: rec_dot_result,
+                        exhaustive: ExhaustiveMark::known_exhaustive(),
+                    }
+                };
+
+                // {
+                //     rest: rec.rest,
+                //     result: when rec.result is
+                //         Ok val -> Ok {state & first: Ok val},
+                //         Err err -> Err err
+                // }
+                let mut fields_map = SendMap::default();
+
+                fields_map.insert(
+                    "rest".into(),
+                    Field {
+                        var: Variable::LIST_U8,
+                        region: Region::zero(),
+                        loc_expr: Box::new(Loc::at_zero(Expr::Access {
+                            record_var: rec_var,
+                            ext_var: env.new_ext_var(ExtensionKind::Record),
+                            field_var: Variable::LIST_U8,
+                            loc_expr: Box::new(Loc::at_zero(Expr::Var(rec_symbol))),
+                            field: "rest".into(),
+                        })),
+                    },
+                );
+
+                // result: when rec.result is
+                //     Ok val -> Ok {state & first: Ok val},
+                //     Err err -> Err err
+                fields_map.insert(
+                    "result".into(),
+                    Field {
+                        var: when_expr_var,
+                        region: Region::zero(),
+                        loc_expr: Box::new(Loc::at_zero(result_val)),
+                    },
+                );
+
+                Expr::Record {
+                    record_var: custom_callback_ret_var,
+                    fields: fields_map,
+                }
+            };
+
+            let branch = WhenBranch {
+                patterns: vec![WhenBranchPattern {
+                    pattern: Loc::at_zero(Pattern::Identifier(rec_symbol)),
+                    degenerate: false,
+                }],
+                value: Loc::at_zero(branch_body),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            };
+
+            let condition_expr = Expr::Call(
+                Box::new((
+                    this_decode_with_var,
+                    Loc::at_zero(Expr::Var(Symbol::DECODE_DECODE_WITH)),
+                    lambda_set_var,
+                    rec_var,
+                )),
+                vec![
+                    (Variable::LIST_U8, Loc::at_zero(Expr::Var(bytes_arg_symbol))),
+                    (
+                        decoder_var,
+                        Loc::at_zero(Expr::AbilityMember(
+                            Symbol::DECODE_DECODER,
+                            None,
+                            decoder_var,
+                        )),
+                    ),
+                    (fmt_arg_var, Loc::at_zero(Expr::Var(fmt_arg_symbol))),
+                ],
+                CalledVia::Space,
+            );
+
+            // when Decode.decodeWith bytes Decode.decoder fmt is
+            Expr::When {
+                loc_cond: Box::new(Loc::at_zero(condition_expr)),
+                cond_var: rec_var,
+                expr_var: custom_callback_ret_var,
+                region: Region::zero(),
+                branches: vec![branch],
+                branches_cond_var: rec_var,
+                exhaustive: ExhaustiveMark::known_exhaustive(),
+            }
+        };
+
+        let custom_closure_symbol = env.new_symbol("customCallback");
+        this_custom_callback_var = env.subs.fresh_unnamed_flex_var();
+        let custom_callback_lambda_set_var = {
+            let content = Content::LambdaSet(LambdaSet {
+                solved: UnionLambdas::insert_into_subs(
+                    env.subs,
+                    [(custom_closure_symbol, [state_record_var])],
+                ),
+                recursion_var: OptVariable::NONE,
+                unspecialized: Default::default(),
+                ambient_function: this_custom_callback_var,
+            });
+            let custom_callback_lambda_set_var = synth_var(env.subs, content);
+            let subs_slice = SubsSlice::insert_into_subs(env.subs, [bytes_arg_var, fmt_arg_var]);
+
+            env.subs.set_content(
+                this_custom_callback_var,
+                Content::Structure(FlatType::Func(
+                    subs_slice,
+                    custom_callback_lambda_set_var,
+                    custom_callback_ret_var,
+                )),
+            );
+
+            custom_callback_lambda_set_var
+        };
+
+        // \bytes, fmt -> …
+        Expr::Closure(ClosureData {
+            function_type: this_custom_callback_var,
+            closure_type: custom_callback_lambda_set_var,
+            return_type: custom_callback_ret_var,
+            name: custom_closure_symbol,
+            captured_symbols: vec![(state_arg_symbol, state_record_var)],
+            recursive: Recursive::NotRecursive,
+            arguments: vec![
+                (
+                    bytes_arg_var,
+                    AnnotatedMark::known_exhaustive(),
+                    Loc::at_zero(Pattern::Identifier(bytes_arg_symbol)),
+                ),
+                (
+                    fmt_arg_var,
+                    AnnotatedMark::known_exhaustive(),
+                    Loc::at_zero(Pattern::Identifier(fmt_arg_symbol)),
+                ),
+            ],
+            loc_body: Box::new(Loc::at_zero(custom_callback_body)),
+        })
+    };
+
+    let decode_custom_ret_var = env.subs.fresh_unnamed_flex_var();
+    let decode_custom = {
+        let decode_custom_var = env.import_builtin_symbol_var(Symbol::DECODE_CUSTOM);
+        let decode_custom_closure_var = env.subs.fresh_unnamed_flex_var();
+        let this_decode_custom_var = {
+            let subs_slice = SubsSlice::insert_into_subs(env.subs, [this_custom_callback_var]);
+            let flat_type =
+                FlatType::Func(subs_slice, decode_custom_closure_var, decode_custom_ret_var);
+
+            synth_var(env.subs, Content::Structure(flat_type))
+        };
+
+        env.unify(decode_custom_var, this_decode_custom_var);
+
+        // Decode.custom \bytes, fmt -> …
+        Expr::Call(
+            Box::new((
+                this_decode_custom_var,
+                Loc::at_zero(Expr::Var(Symbol::DECODE_CUSTOM)),
+                decode_custom_closure_var,
+                decode_custom_ret_var,
+            )),
+            vec![(this_custom_callback_var, Loc::at_zero(custom_callback))],
+            CalledVia::Space,
+        )
+    };
+
+    (decode_custom, decode_custom_ret_var)
+}
+
 // Example:
 // finalizer = \rec ->
 //     when rec.first is
@@ -777,7 +808,7 @@ fn decoder_record_finalizer(
     // The bottom of the happy path - return the decoded record {first: a, second: b} wrapped with
     // "Ok".
     let return_type_var;
-    let mut body = {
+    let body = {
         let subs = &mut env.subs;
         let record_field_iter = fields
             .iter()
@@ -815,13 +846,76 @@ fn decoder_record_finalizer(
     // when rec.first is
     //     Ok first -> ...happy path...
     //     Err NoField -> Err TooShort
-    for (((symbol, field_name), &field_var), &result_field_var) in pattern_symbols
+    let unwrapped_fields: Vec<_> = pattern_symbols
         .iter()
-        .rev()
-        .zip(fields.iter().rev())
-        .zip(field_vars.iter().rev())
-        .zip(result_field_vars.iter().rev())
-    {
+        .zip(fields.iter())
+        .zip(field_vars.iter())
+        .zip(result_field_vars.iter())
+        .map(|(((&symbol, field_name), &field_var), &result_field_var)| {
+            (symbol, field_name, field_var, result_field_var)
+        })
+        .collect();
+    let body = unwrap_state_results(
+        env,
+        (state_arg_symbol, state_record_var),
+        (return_type_var, decode_err_var),
+        &unwrapped_fields,
+        body,
+    );
+
+    let function_var = synth_var(env.subs, Content::Error); // We'll fix this up in subs later.
+    let function_symbol = env.new_symbol("finalizer");
+    let lambda_set = LambdaSet {
+        solved: UnionLambdas::tag_without_arguments(env.subs, function_symbol),
+        recursion_var: OptVariable::NONE,
+        unspecialized: Default::default(),
+        ambient_function: function_var,
+    };
+    let closure_type = synth_var(env.subs, Content::LambdaSet(lambda_set));
+    let flat_type = FlatType::Func(
+        SubsSlice::insert_into_subs(env.subs, [state_record_var]),
+        closure_type,
+        return_type_var,
+    );
+
+    // Fix up function_var so it's not Content::Error anymore
+    env.subs
+        .set_content(function_var, Content::Structure(flat_type));
+
+    let finalizer = Expr::Closure(ClosureData {
+        function_type: function_var,
+        closure_type,
+        return_type: return_type_var,
+        name: function_symbol,
+        captured_symbols: Vec::new(),
+        recursive: Recursive::NotRecursive,
+        arguments: vec![(
+            state_record_var,
+            AnnotatedMark::known_exhaustive(),
+            Loc::at_zero(Pattern::Identifier(state_arg_symbol)),
+        )],
+        loc_body: Box::new(Loc::at_zero(body)),
+    });
+
+    (finalizer, function_var, decode_err_var)
+}
+
+// Wraps the happy path `body` in a `when` for each result stored in the decoding state, in order.
+//
+// when rec.first is
+//     Ok first ->
+//         when rec.second is
+//             Ok second -> body
+//             _ -> Err TooShort
+//     _ -> Err TooShort
+fn unwrap_state_results(
+    env: &mut Env,
+    (state_arg_symbol, state_record_var): (Symbol, Variable),
+    (return_type_var, decode_err_var): (Variable, Variable),
+    unwrapped_fields: &[(Symbol, &Lowercase, Variable, Variable)],
+    mut body: Expr,
+) -> Expr {
+    for &(symbol, field_name, field_var, result_field_var) in unwrapped_fields.iter().rev() {
         // when rec.first is
         let cond_expr = Expr::Access {
             record_var: state_record_var,
@@ -838,7 +932,7 @@ fn decoder_record_finalizer(
                     whole_var: result_field_var,
                     ext_var: Variable::EMPTY_TAG_UNION,
                     tag_name: "Ok".into(),
-                    arguments: vec![(field_var, Loc::at_zero(Pattern::Identifier(*symbol)))],
+                    arguments: vec![(field_var, Loc::at_zero(Pattern::Identifier(symbol)))],
                 }),
                 degenerate: false,
             }],
@@ -853,20 +947,7 @@ fn decoder_record_finalizer(
                 pattern: Loc::at_zero(Pattern::Underscore),
                 degenerate: false,
             }],
-            value: Loc::at_zero(Expr::Tag {
-                tag_union_var: return_type_var,
-                ext_var: env.new_ext_var(ExtensionKind::TagUnion),
-                name: "Err".into(),
-                arguments: vec![(
-                    decode_err_var,
-                    Loc::at_zero(Expr::Tag {
-                        tag_union_var: decode_err_var,
-                        ext_var: Variable::EMPTY_TAG_UNION,
-                        name: "TooShort".into(),
-                        arguments: Vec::new(),
-                    }),
-                )],
-            }),
+            value: Loc::at_zero(err_too_short(env, return_type_var, decode_err_var)),
             guard: None,
             redundant: RedundantMark::known_non_redundant(),
         };
@@ -882,41 +963,25 @@ fn decoder_record_finalizer(
         };
     }
 
-    let function_var = synth_var(env.subs, Content::Error); // We'll fix this up in subs later.
-    let function_symbol = env.new_symbol("finalizer");
-    let lambda_set = LambdaSet {
-        solved: UnionLambdas::tag_without_arguments(env.subs, function_symbol),
-        recursion_var: OptVariable::NONE,
-        unspecialized: Default::default(),
-        ambient_function: function_var,
-    };
-    let closure_type = synth_var(env.subs, Content::LambdaSet(lambda_set));
-    let flat_type = FlatType::Func(
-        SubsSlice::insert_into_subs(env.subs, [state_record_var]),
-        closure_type,
-        return_type_var,
-    );
-
-    // Fix up function_var so it's not Content::Error anymore
-    env.subs
-        .set_content(function_var, Content::Structure(flat_type));
+    body
+}
 
-    let finalizer = Expr::Closure(ClosureData {
-        function_type: function_var,
-        closure_type,
-        return_type: return_type_var,
-        name: function_symbol,
-        captured_symbols: Vec::new(),
-        recursive: Recursive::NotRecursive,
+// Err TooShort
+fn err_too_short(env: &mut Env, return_type_var: Variable, decode_err_var: Variable) -> Expr {
+    Expr::Tag {
+        tag_union_var: return_type_var,
+        ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+        name: "Err".into(),
         arguments: vec![(
-            state_record_var,
-            AnnotatedMark::known_exhaustive(),
-            Loc::at_zero(Pattern::Identifier(state_arg_symbol)),
+            decode_err_var,
+            Loc::at_zero(Expr::Tag {
+                tag_union_var: decode_err_var,
+                ext_var: Variable::EMPTY_TAG_UNION,
+                name: "TooShort".into(),
+                arguments: Vec::new(),
+            }),
         )],
-        loc_body: Box::new(Loc::at_zero(body)),
-    });
-
-    (finalizer, function_var, decode_err_var)
+    }
 }
 
 // Example:
@@ -991,6 +1056,526 @@ fn decoder_record_initial_state(
     )
 }
 
+// A tag, along with each of its payload values as (field in the decoding state, decoded type, type
+// in the decoding state).
+type TagPayloadFields = (TagName, Vec<(Lowercase, Variable, Variable)>);
+
+// Implements decoding of a tag union. For example, for
+//
+//   [A a, B b c, C]
+//
+// we'd like to generate an impl like
+//
+// decoder : Decoder [A a, B b c, C] fmt | a has Decoding, b has Decoding, c has Decoding, fmt has DecoderFormatting
+// decoder =
+//     initialState : {a_0: Result a [NoField], b_0: Result b [NoField], b_1: Result c [NoField]}
+//     initialState = {a_0: Err NoField, b_0: Err NoField, b_1: Err NoField}
+//
+//     stepPayload = \state, tag, index ->
+//         when tag is
+//             "A" ->
+//                 when index is
+//                     0 -> Next (Decode.custom \bytes, fmt -> ... {state & a_0: Ok val} ...)
+//                     _ -> TooLong
+//             "B" ->
+//                 when index is
+//                     0 -> Next (Decode.custom \bytes, fmt -> ... {state & b_0: Ok val} ...)
+//                     1 -> Next (Decode.custom \bytes, fmt -> ... {state & b_1: Ok val} ...)
+//                     _ -> TooLong
+//             _ -> TooLong
+//
+//     finalizer = \state, tag ->
+//         when tag is
+//             "A" ->
+//                 when state.a_0 is
+//                     Ok a_0 -> Ok (A a_0)
+//                     _ -> Err TooShort
+//             "B" -> ...
+//             "C" -> Ok C
+//             _ -> Err TooShort
+//
+//     Decode.custom \bytes, fmt -> Decode.decodeWith bytes (Decode.tag initialState stepPayload finalizer) fmt
+fn decoder_tag_union(
+    env: &mut Env,
+    _def_symbol: Symbol,
+    tags: Vec<(TagName, u16)>,
+) -> (Expr, Variable) {
+    // Each payload value is decoded into its own field of the decoding state; `B`'s second
+    // payload lives in `b_1`.
+    let payload_field_name = |tag_name: &TagName, index: u16| -> Lowercase {
+        let (first, rest) = tag_name.0.as_str().split_at(1);
+
+        format!("{}{}_{}", first.to_ascii_lowercase(), rest, index).into()
+    };
+
+    let mut fields: Vec<Lowercase> = tags
+        .iter()
+        .flat_map(|(tag_name, arity)| (0..*arity).map(|i| payload_field_name(tag_name, i)))
+        .collect();
+    fields.sort();
+
+    let mut field_vars = Vec::with_capacity(fields.len());
+    let mut result_field_vars = Vec::with_capacity(fields.len());
+
+    // initialState = ...
+    let (initial_state_var, initial_state) =
+        decoder_record_initial_state(env, &fields, &mut field_vars, &mut result_field_vars);
+
+    let payloads: Vec<TagPayloadFields> = tags
+        .iter()
+        .map(|(tag_name, arity)| {
+            let payload = (0..*arity)
+                .map(|i| {
+                    let field_name = payload_field_name(tag_name, i);
+                    let index = fields.binary_search(&field_name).unwrap();
+
+                    (field_name, field_vars[index], result_field_vars[index])
+                })
+                .collect();
+
+            (tag_name.clone(), payload)
+        })
+        .collect();
+
+    // [A a, B b c, C]
+    let union_var = {
+        let tags_and_payloads: Vec<_> = payloads
+            .iter()
+            .map(|(tag_name, payload)| {
+                let payload_vars = payload.iter().map(|&(_, field_var, _)| field_var);
+
+                (
+                    tag_name.clone(),
+                    SubsSlice::insert_into_subs(env.subs, payload_vars),
+                )
+            })
+            .collect();
+        let union_tags = UnionTags::insert_slices_into_subs(env.subs, tags_and_payloads);
+
+        synth_var(
+            env.subs,
+            Content::Structure(FlatType::TagUnion(union_tags, Variable::EMPTY_TAG_UNION)),
+        )
+    };
+
+    // finalizer = ...
+    let (finalizer, finalizer_var, decode_err_var) =
+        decoder_tag_union_finalizer(env, initial_state_var, union_var, &payloads);
+
+    // stepPayload = ...
+    let (step_payload, step_var) =
+        decoder_tag_union_step_payload(env, &payloads, initial_state_var, decode_err_var);
+
+    // Build up the type of `Decode.tag` we expect
+    let tag_decoder_var = env.subs.fresh_unnamed_flex_var();
+    let decode_tag_lambda_set = env.subs.fresh_unnamed_flex_var();
+    let decode_tag_var = env.import_builtin_symbol_var(Symbol::DECODE_TAG);
+    let this_decode_tag_var = {
+        let flat_type = FlatType::Func(
+            SubsSlice::insert_into_subs(env.subs, [initial_state_var, step_var, finalizer_var]),
+            decode_tag_lambda_set,
+            tag_decoder_var,
+        );
+
+        synth_var(env.subs, Content::Structure(flat_type))
+    };
+
+    env.unify(decode_tag_var, this_decode_tag_var);
+
+    // Decode.tag initialState stepPayload finalizer
+    let call_decode_tag = Expr::Call(
+        Box::new((
+            this_decode_tag_var,
+            Loc::at_zero(Expr::AbilityMember(
+                Symbol::DECODE_TAG,
+                None,
+                this_decode_tag_var,
+            )),
+            decode_tag_lambda_set,
+            tag_decoder_var,
+        )),
+        vec![
+            (initial_state_var, Loc::at_zero(initial_state)),
+            (step_var, Loc::at_zero(step_payload)),
+            (finalizer_var, Loc::at_zero(finalizer)),
+        ],
+        CalledVia::Space,
+    );
+
+    let bytes_sym = env.new_symbol("bytes");
+    let fmt_sym = env.new_symbol("fmt");
+    let fmt_var = env.subs.fresh_unnamed_flex_var();
+
+    wrap_in_decode_custom_decode_with(
+        env,
+        bytes_sym,
+        (fmt_sym, fmt_var),
+        vec![],
+        (call_decode_tag, tag_decoder_var),
+    )
+}
+
+// Example:
+// stepPayload = \state, tag, index ->
+//     when tag is
+//         "A" ->
+//             when index is
+//                 0 ->
+//                     Next (Decode.custom \bytes, fmt ->
+//                         when Decode.decodeWith bytes Decode.decoder fmt is
+//                             rec ->
+//                                 {
+//                                     rest: rec.rest,
+//                                     result: when rec.result is
+//                                         Ok val -> Ok {state & a_0: Ok val},
+//                                         Err err -> Err err
+//                                 })
+//
+//                 _ -> TooLong
+//
+//         _ -> TooLong
+fn decoder_tag_union_step_payload(
+    env: &mut Env,
+    payloads: &[TagPayloadFields],
+    state_record_var: Variable,
+    decode_err_var: Variable,
+) -> (Expr, Variable) {
+    let state_arg_symbol = env.new_symbol("stateRecord");
+    let tag_arg_symbol = env.new_symbol("tag");
+    let index_arg_symbol = env.new_symbol("index");
+
+    let next_payload_var = env.subs.fresh_unnamed_flex_var();
+    let next_or_too_long_var = {
+        let next_payload_subs_slice = SubsSlice::insert_into_subs(env.subs, [next_payload_var]);
+        let flat_type = FlatType::TagUnion(
+            UnionTags::insert_slices_into_subs(
+                env.subs,
+                [
+                    ("Next".into(), next_payload_subs_slice),
+                    ("TooLong".into(), Default::default()),
+                ],
+            ),
+            Variable::EMPTY_TAG_UNION,
+        );
+
+        synth_var(env.subs, Content::Structure(flat_type))
+    };
+
+    // Example: `_ -> TooLong`
+    let too_long_branch = |env: &mut Env| WhenBranch {
+        patterns: vec![WhenBranchPattern {
+            pattern: Loc::at_zero(Pattern::Underscore),
+            degenerate: false,
+        }],
+        value: Loc::at_zero(Expr::Tag {
+            tag_union_var: next_or_too_long_var,
+            ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+            name: "TooLong".into(),
+            arguments: Vec::new(),
+        }),
+        guard: None,
+        redundant: RedundantMark::known_non_redundant(),
+    };
+
+    // +1 because of the default branch.
+    let mut tag_branches = Vec::with_capacity(payloads.len() + 1);
+
+    // Tags without a payload never ask for a payload decoder, so they fall into the default
+    // branch.
+    for (tag_name, payload) in payloads.iter().filter(|(_, payload)| !payload.is_empty()) {
+        // +1 because of the default branch.
+        let mut index_branches = Vec::with_capacity(payload.len() + 1);
+
+        for (index, (field_name, field_var, result_field_var)) in payload.iter().enumerate() {
+            let (decode_custom, decode_custom_ret_var) = custom_decoder_into_state(
+                env,
+                (state_arg_symbol, state_record_var),
+                field_name,
+                *field_var,
+                *result_field_var,
+                decode_err_var,
+            );
+
+            env.unify(next_payload_var, decode_custom_ret_var);
+
+            // 0 -> Next (Decode.custom \bytes, fmt -> ...)
+            let next = Expr::Tag {
+                tag_union_var: next_or_too_long_var,
+                ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+                name: "Next".into(),
+                arguments: vec![(decode_custom_ret_var, Loc::at_zero(decode_custom))],
+            };
+
+            let index_pattern = Pattern::IntLiteral(
+                Variable::NAT,
+                Variable::NATURAL,
+                index.to_string().into_boxed_str(),
+                IntValue::I128((index as i128).to_ne_bytes()),
+                IntBound::Exact(IntLitWidth::Nat),
+            );
+
+            index_branches.push(WhenBranch {
+                patterns: vec![WhenBranchPattern {
+                    pattern: Loc::at_zero(index_pattern),
+                    degenerate: false,
+                }],
+                value: Loc::at_zero(next),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            });
+        }
+
+        index_branches.push(too_long_branch(env));
+
+        // when index is
+        let when_index = Expr::When {
+            loc_cond: Box::new(Loc::at_zero(Expr::Var(index_arg_symbol))),
+            cond_var: Variable::NAT,
+            expr_var: next_or_too_long_var,
+            region: Region::zero(),
+            branches: index_branches,
+            branches_cond_var: Variable::NAT,
+            exhaustive: ExhaustiveMark::known_exhaustive(),
+        };
+
+        tag_branches.push(WhenBranch {
+            patterns: vec![WhenBranchPattern {
+                pattern: Loc::at_zero(Pattern::StrLiteral(tag_name.0.as_str().into())),
+                degenerate: false,
+            }],
+            value: Loc::at_zero(when_index),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        });
+    }
+
+    tag_branches.push(too_long_branch(env));
+
+    // when tag is
+    let body = Expr::When {
+        loc_cond: Box::new(Loc::at_zero(Expr::Var(tag_arg_symbol))),
+        cond_var: Variable::STR,
+        expr_var: next_or_too_long_var,
+        region: Region::zero(),
+        branches: tag_branches,
+        branches_cond_var: Variable::STR,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    let step_payload_closure = env.new_symbol("stepPayload");
+    let function_type = env.subs.fresh_unnamed_flex_var();
+    let closure_type = {
+        let lambda_set = LambdaSet {
+            solved: UnionLambdas::tag_without_arguments(env.subs, step_payload_closure),
+            recursion_var: OptVariable::NONE,
+            unspecialized: Default::default(),
+            ambient_function: function_type,
+        };
+
+        synth_var(env.subs, Content::LambdaSet(lambda_set))
+    };
+
+    {
+        let args_slice =
+            SubsSlice::insert_into_subs(env.subs, [state_record_var, Variable::STR, Variable::NAT]);
+
+        env.subs.set_content(
+            function_type,
+            Content::Structure(FlatType::Func(
+                args_slice,
+                closure_type,
+                next_or_too_long_var,
+            )),
+        )
+    };
+
+    let expr = Expr::Closure(ClosureData {
+        function_type,
+        closure_type,
+        return_type: next_or_too_long_var,
+        name: step_payload_closure,
+        captured_symbols: Vec::new(),
+        recursive: Recursive::NotRecursive,
+        arguments: vec![
+            (
+                state_record_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(Pattern::Identifier(state_arg_symbol)),
+            ),
+            (
+                Variable::STR,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(Pattern::Identifier(tag_arg_symbol)),
+            ),
+            (
+                Variable::NAT,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(Pattern::Identifier(index_arg_symbol)),
+            ),
+        ],
+        loc_body: Box::new(Loc::at_zero(body)),
+    });
+
+    (expr, function_type)
+}
+
+// Example:
+// finalizer = \state, tag ->
+//     when tag is
+//         "A" ->
+//             when state.a_0 is
+//                 Ok a_0 -> Ok (A a_0)
+//                 _ -> Err TooShort
+//         "C" -> Ok C
+//         _ -> Err TooShort
+fn decoder_tag_union_finalizer(
+    env: &mut Env,
+    state_record_var: Variable,
+    union_var: Variable,
+    payloads: &[TagPayloadFields],
+) -> (Expr, Variable, Variable) {
+    let state_arg_symbol = env.new_symbol("stateRecord");
+    let tag_arg_symbol = env.new_symbol("tag");
+    let decode_err_var = {
+        let flat_type = FlatType::TagUnion(
+            UnionTags::tag_without_arguments(env.subs, "TooShort".into()),
+            Variable::EMPTY_TAG_UNION,
+        );
+
+        synth_var(env.subs, Content::Structure(flat_type))
+    };
+    let return_type_var = {
+        let flat_type = FlatType::TagUnion(
+            UnionTags::for_result(env.subs, union_var, decode_err_var),
+            Variable::EMPTY_TAG_UNION,
+        );
+
+        synth_var(env.subs, Content::Structure(flat_type))
+    };
+
+    // +1 because of the default branch.
+    let mut branches = Vec::with_capacity(payloads.len() + 1);
+
+    for (tag_name, payload) in payloads {
+        let pattern_symbols: Vec<_> = payload
+            .iter()
+            .map(|(field_name, _, _)| env.new_symbol(field_name.as_str()))
+            .collect();
+
+        // The bottom of the happy path - return the decoded tag wrapped with "Ok".
+        let body = {
+            let arguments = pattern_symbols
+                .iter()
+                .zip(payload.iter())
+                .map(|(&symbol, &(_, field_var, _))| (field_var, Loc::at_zero(Expr::Var(symbol))))
+                .collect();
+            let done_tag = Expr::Tag {
+                tag_union_var: union_var,
+                ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+                name: tag_name.clone(),
+                arguments,
+            };
+
+            Expr::Tag {
+                tag_union_var: return_type_var,
+                ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+                name: "Ok".into(),
+                arguments: vec![(union_var, Loc::at_zero(done_tag))],
+            }
+        };
+
+        let unwrapped_fields: Vec<_> = pattern_symbols
+            .iter()
+            .zip(payload.iter())
+            .map(|(&symbol, (field_name, field_var, result_field_var))| {
+                (symbol, field_name, *field_var, *result_field_var)
+            })
+            .collect();
+        let body = unwrap_state_results(
+            env,
+            (state_arg_symbol, state_record_var),
+            (return_type_var, decode_err_var),
+            &unwrapped_fields,
+            body,
+        );
+
+        branches.push(WhenBranch {
+            patterns: vec![WhenBranchPattern {
+                pattern: Loc::at_zero(Pattern::StrLiteral(tag_name.0.as_str().into())),
+                degenerate: false,
+            }],
+            value: Loc::at_zero(body),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        });
+    }
+
+    // Example: `_ -> Err TooShort`
+    branches.push(WhenBranch {
+        patterns: vec![WhenBranchPattern {
+            pattern: Loc::at_zero(Pattern::Underscore),
+            degenerate: false,
+        }],
+        value: Loc::at_zero(err_too_short(env, return_type_var, decode_err_var)),
+        guard: None,
+        redundant: RedundantMark::known_non_redundant(),
+    });
+
+    // when tag is
+    let body = Expr::When {
+        loc_cond: Box::new(Loc::at_zero(Expr::Var(tag_arg_symbol))),
+        cond_var: Variable::STR,
+        expr_var: return_type_var,
+        region: Region::zero(),
+        branches,
+        branches_cond_var: Variable::STR,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    let function_var = synth_var(env.subs, Content::Error); // We'll fix this up in subs later.
+    let function_symbol = env.new_symbol("finalizer");
+    let lambda_set = LambdaSet {
+        solved: UnionLambdas::tag_without_arguments(env.subs, function_symbol),
+        recursion_var: OptVariable::NONE,
+        unspecialized: Default::default(),
+        ambient_function: function_var,
+    };
+    let closure_type = synth_var(env.subs, Content::LambdaSet(lambda_set));
+    let flat_type = FlatType::Func(
+        SubsSlice::insert_into_subs(env.subs, [state_record_var, Variable::STR]),
+        closure_type,
+        return_type_var,
+    );
+
+    // Fix up function_var so it's not Content::Error anymore
+    env.subs
+        .set_content(function_var, Content::Structure(flat_type));
+
+    let finalizer = Expr::Closure(ClosureData {
+        function_type: function_var,
+        closure_type,
+        return_type: return_type_var,
+        name: function_symbol,
+        captured_symbols: Vec::new(),
+        recursive: Recursive::NotRecursive,
+        arguments: vec![
+            (
+                state_record_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(Pattern::Identifier(state_arg_symbol)),
+            ),
+            (
+                Variable::STR,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(Pattern::Identifier(tag_arg_symbol)),
+            ),
+        ],
+        loc_body: Box::new(Loc::at_zero(body)),
+    });
+
+    (finalizer, function_var, decode_err_var)
+}
+
 fn decoder_list(env: &mut Env<'_>, _def_symbol: Symbol) -> (Expr, Variable) {
     // Build
     //
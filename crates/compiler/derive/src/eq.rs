@@ -0,0 +1,404 @@
+//! Derivers for the `Eq` ability.
+
+use std::iter::once;
+
+use roc_can::expr::{AnnotatedMark, ClosureData, Expr, Recursive, WhenBranch, WhenBranchPattern};
+use roc_can::pattern::Pattern;
+use roc_derive_key::eq::FlatEqKey;
+use roc_module::called_via::CalledVia;
+use roc_module::ident::{Lowercase, TagName};
+use roc_module::symbol::Symbol;
+use roc_region::all::{Loc, Region};
+use roc_types::subs::{
+    Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
+    RedundantMark, SubsSlice, UnionLambdas, UnionTags, Variable, VariableSubsSlice,
+};
+use roc_types::types::RecordField;
+
+use crate::util::Env;
+use crate::{synth_var, DerivedBody};
+
+pub(crate) fn derive_is_eq(env: &mut Env<'_>, key: FlatEqKey, def_symbol: Symbol) -> DerivedBody {
+    let (body, body_type) = match key {
+        FlatEqKey::Record(fields) => {
+            // Generalized record var so we can reuse this impl between many records:
+            // if fields = { a, b }, this is { a: t1, b: t2 } for fresh t1, t2.
+            let flex_fields = fields
+                .into_iter()
+                .map(|name| {
+                    (
+                        name,
+                        RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                    )
+                })
+                .collect::<Vec<(Lowercase, _)>>();
+            let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+            let record_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+            );
+
+            is_eq_record(env, record_var, fields, def_symbol)
+        }
+        FlatEqKey::TagUnion(tags) => {
+            // Generalized tag union var so we can reuse this impl between many unions:
+            // if tags = [ A arity=2, B arity=1 ], this is [ A t1 t2, B t3 ] for fresh t1, t2, t3
+            let flex_tag_labels = tags
+                .into_iter()
+                .map(|(label, arity)| {
+                    let variables_slice =
+                        VariableSubsSlice::reserve_into_subs(env.subs, arity.into());
+                    for var_index in variables_slice {
+                        env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                    }
+                    (label, variables_slice)
+                })
+                .collect::<Vec<_>>();
+            let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+            let tag_union_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::TagUnion(union_tags, Variable::EMPTY_TAG_UNION)),
+            );
+
+            is_eq_tag_union(env, tag_union_var, union_tags, def_symbol)
+        }
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::BOOL_IS_EQ);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+fn is_eq_record(
+    env: &mut Env<'_>,
+    record_var: Variable,
+    fields: RecordFields,
+    fn_name: Symbol,
+) -> (Expr, Variable) {
+    // Suppose rcd = { a: t1, b: t2 }. Build
+    //
+    // \rcd1, rcd2 ->
+    //     if Bool.isEq rcd1.a rcd2.a then Bool.isEq rcd1.b rcd2.b else Bool.false
+    //
+    // For the empty record, this is just \rcd1, rcd2 -> Bool.true
+
+    let rcd1_sym = env.new_symbol("rcd1");
+    let rcd2_sym = env.new_symbol("rcd2");
+
+    let bool_var = env.import_builtin_symbol_var(Symbol::BOOL_TRUE);
+
+    use Expr::*;
+
+    let comparisons = fields
+        .iter_all()
+        .map(|(field_name_index, field_var_index, _)| {
+            let field_name = env.subs[field_name_index].clone();
+            let field_var = env.subs[field_var_index];
+
+            // rcd1.a
+            let mut field_access = |rcd_sym| Access {
+                record_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                field_var,
+                loc_expr: Box::new(Loc::at_zero(Var(rcd_sym))),
+                field: field_name.clone(),
+            };
+            let field1 = field_access(rcd1_sym);
+            let field2 = field_access(rcd2_sym);
+
+            // Bool.isEq rcd1.a rcd2.a
+            call_is_eq(env, bool_var, field_var, field1, field2)
+        })
+        .collect();
+
+    let body = all_true(bool_var, comparisons);
+
+    // \rcd1, rcd2 -> if Bool.isEq rcd1.a rcd2.a then Bool.isEq rcd1.b rcd2.b else Bool.false
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (record_var, rcd1_sym, rcd2_sym),
+        (bool_var, body),
+    )
+}
+
+fn is_eq_tag_union(
+    env: &mut Env<'_>,
+    tag_union_var: Variable,
+    tags: UnionTags,
+    fn_name: Symbol,
+) -> (Expr, Variable) {
+    // Suppose tags = [ A t1 t2, B t3 ]. Build
+    //
+    // \union1, union2 -> when union1 is
+    //     A x1 x2 -> when union2 is
+    //         A y1 y2 -> if Bool.isEq x1 y1 then Bool.isEq x2 y2 else Bool.false
+    //         _ -> Bool.false
+    //     B x3 -> when union2 is
+    //         B y3 -> Bool.isEq x3 y3
+    //         _ -> Bool.false
+    //
+    // The `_ -> Bool.false` branches are left out when there is only one tag.
+
+    let union1_sym = env.new_symbol("union1");
+    let union2_sym = env.new_symbol("union2");
+
+    let bool_var = env.import_builtin_symbol_var(Symbol::BOOL_TRUE);
+
+    use Expr::*;
+
+    let num_tags = tags.len();
+
+    let branches = tags
+        .iter_all()
+        .map(|(tag_name_index, tag_vars_slice_index)| {
+            // A
+            let tag_name = env.subs[tag_name_index].clone();
+            let vars_slice = env.subs[tag_vars_slice_index];
+            // t1 t2
+            let payload_vars = env.subs.get_subs_slice(vars_slice).to_vec();
+            // x1 x2
+            let payload_syms1: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+            // y1 y2
+            let payload_syms2: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+
+            // Bool.isEq x1 y1, Bool.isEq x2 y2
+            let comparisons = (payload_vars.iter())
+                .zip(payload_syms1.iter().zip(payload_syms2.iter()))
+                .map(|(var, (sym1, sym2))| call_is_eq(env, bool_var, *var, Var(*sym1), Var(*sym2)))
+                .collect();
+
+            // A y1 y2 -> if Bool.isEq x1 y1 then Bool.isEq x2 y2 else Bool.false
+            let mut inner_branches = vec![WhenBranch {
+                patterns: vec![tag_branch_pattern(
+                    tag_union_var,
+                    tag_name.clone(),
+                    &payload_vars,
+                    &payload_syms2,
+                )],
+                value: Loc::at_zero(all_true(bool_var, comparisons)),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }];
+
+            // _ -> Bool.false
+            if num_tags > 1 {
+                inner_branches.push(WhenBranch {
+                    patterns: vec![WhenBranchPattern {
+                        pattern: Loc::at_zero(Pattern::Underscore),
+                        degenerate: false,
+                    }],
+                    value: Loc::at_zero(Var(Symbol::BOOL_FALSE)),
+                    guard: None,
+                    redundant: RedundantMark::known_non_redundant(),
+                });
+            }
+
+            // when union2 is ...
+            let inner_when = When {
+                loc_cond: Box::new(Loc::at_zero(Var(union2_sym))),
+                cond_var: tag_union_var,
+                expr_var: bool_var,
+                region: Region::zero(),
+                branches: inner_branches,
+                branches_cond_var: tag_union_var,
+                exhaustive: ExhaustiveMark::known_exhaustive(),
+            };
+
+            // A x1 x2 -> when union2 is ...
+            WhenBranch {
+                patterns: vec![tag_branch_pattern(
+                    tag_union_var,
+                    tag_name,
+                    &payload_vars,
+                    &payload_syms1,
+                )],
+                value: Loc::at_zero(inner_when),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let body = if branches.is_empty() {
+        // An empty union has no values to compare.
+        Var(Symbol::BOOL_TRUE)
+    } else {
+        // when union1 is ...
+        When {
+            loc_cond: Box::new(Loc::at_zero(Var(union1_sym))),
+            cond_var: tag_union_var,
+            expr_var: bool_var,
+            region: Region::zero(),
+            branches,
+            branches_cond_var: tag_union_var,
+            exhaustive: ExhaustiveMark::known_exhaustive(),
+        }
+    };
+
+    // \union1, union2 -> when union1 is ...
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (tag_union_var, union1_sym, union2_sym),
+        (bool_var, body),
+    )
+}
+
+/// Builds the pattern `A x1 x2` of a branch of a `when` on a derived tag union.
+fn tag_branch_pattern(
+    tag_union_var: Variable,
+    tag_name: TagName,
+    payload_vars: &[Variable],
+    payload_syms: &[Symbol],
+) -> WhenBranchPattern {
+    let pattern = Pattern::AppliedTag {
+        whole_var: tag_union_var,
+        tag_name,
+        ext_var: Variable::EMPTY_TAG_UNION,
+        // (t1, x1) (t2, x2)
+        arguments: (payload_vars.iter())
+            .zip(payload_syms.iter())
+            .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+            .collect(),
+    };
+
+    WhenBranchPattern {
+        pattern: Loc::at_zero(pattern),
+        degenerate: false,
+    }
+}
+
+/// Builds `if c1 then (if c2 then c3 else Bool.false) else Bool.false` from the comparisons
+/// `c1`, `c2`, `c3`, so that later comparisons are skipped once one of them fails.
+///
+/// With no comparisons to make, this is `Bool.true`.
+fn all_true(bool_var: Variable, comparisons: Vec<Expr>) -> Expr {
+    let mut comparisons = comparisons.into_iter().rev();
+
+    let last = match comparisons.next() {
+        Some(last) => last,
+        None => return Expr::Var(Symbol::BOOL_TRUE),
+    };
+
+    comparisons.fold(last, |then, cond| Expr::If {
+        cond_var: bool_var,
+        branch_var: bool_var,
+        branches: vec![(Loc::at_zero(cond), Loc::at_zero(then))],
+        final_else: Box::new(Loc::at_zero(Expr::Var(Symbol::BOOL_FALSE))),
+    })
+}
+
+/// Builds a call to `Bool.isEq val1 val2`, whose result has type `bool_var`.
+fn call_is_eq(
+    env: &mut Env<'_>,
+    bool_var: Variable,
+    val_var: Variable,
+    val1_expr: Expr,
+    val2_expr: Expr,
+) -> Expr {
+    // build `Bool.isEq val1 val2` type
+    // expected: a, a -[uls]-> Bool | a has Eq
+    let is_eq_fn_var = env.import_builtin_symbol_var(Symbol::BOOL_IS_EQ);
+
+    // wanted: val_var, val_var -[clos]-> bool_var
+    let this_arguments_slice = VariableSubsSlice::insert_into_subs(env.subs, [val_var, val_var]);
+    let this_is_eq_clos_var = env.subs.fresh_unnamed_flex_var(); // clos
+    let this_is_eq_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_is_eq_clos_var,
+            bool_var,
+        )),
+    );
+
+    //   a,       a       -[uls]->  Bool | a has Eq
+    // ~ val_var, val_var -[clos]-> bool_var
+    env.unify(is_eq_fn_var, this_is_eq_fn_var);
+
+    // Bool.isEq : val_var, val_var -[clos]-> Bool | val_var has Eq
+    let is_eq_var = Expr::AbilityMember(Symbol::BOOL_IS_EQ, None, this_is_eq_fn_var);
+    let is_eq_fn = Box::new((
+        this_is_eq_fn_var,
+        Loc::at_zero(is_eq_var),
+        this_is_eq_clos_var,
+        bool_var,
+    ));
+
+    // Bool.isEq val1 val2
+    Expr::Call(
+        is_eq_fn,
+        vec![
+            (val_var, Loc::at_zero(val1_expr)),
+            (val_var, Loc::at_zero(val2_expr)),
+        ],
+        CalledVia::Space,
+    )
+}
+
+/// Builds the outer closure `\val1, val2 -> body` of a derived equality implementation.
+fn build_outer_derived_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    (val_var, val1_sym, val2_sym): (Variable, Symbol, Symbol),
+    (body_var, body): (Variable, Expr),
+) -> (Expr, Variable) {
+    let (fn_var, fn_clos_var) = {
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[fn_name]->
+        let fn_captures = vec![];
+        let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, fn_captures)));
+        let fn_clos_var = synth_var(
+            env.subs,
+            Content::LambdaSet(LambdaSet {
+                solved: fn_name_labels,
+                recursion_var: OptVariable::NONE,
+                unspecialized: SubsSlice::default(),
+                ambient_function: fn_var,
+            }),
+        );
+
+        // val, val -[fn_name]-> Bool
+        let args_slice = SubsSlice::insert_into_subs(env.subs, vec![val_var, val_var]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(args_slice, fn_clos_var, body_var)),
+        );
+
+        (fn_var, fn_clos_var)
+    };
+
+    let clos_expr = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: body_var,
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: [val1_sym, val2_sym]
+            .into_iter()
+            .map(|sym| {
+                (
+                    val_var,
+                    AnnotatedMark::known_exhaustive(),
+                    Loc::at_zero(Pattern::Identifier(sym)),
+                )
+            })
+            .collect(),
+        loc_body: Box::new(Loc::at_zero(body)),
+    });
+
+    (clos_expr, fn_var)
+}
@@ -0,0 +1,422 @@
+//! Derivers for the `Hash` ability.
+
+use std::iter::once;
+
+use roc_can::expr::{
+    AnnotatedMark, ClosureData, Expr, IntValue, Recursive, WhenBranch, WhenBranchPattern,
+};
+use roc_can::pattern::Pattern;
+use roc_derive_key::hash::FlatHashKey;
+use roc_module::called_via::CalledVia;
+use roc_module::ident::Lowercase;
+use roc_module::symbol::Symbol;
+use roc_region::all::{Loc, Region};
+use roc_types::num::{IntBound, IntLitWidth};
+use roc_types::subs::{
+    Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
+    RedundantMark, SubsSlice, UnionLambdas, UnionTags, Variable, VariableSubsSlice,
+};
+use roc_types::types::RecordField;
+
+use crate::util::Env;
+use crate::{synth_var, DerivedBody};
+
+pub(crate) fn derive_hash(env: &mut Env<'_>, key: FlatHashKey, def_symbol: Symbol) -> DerivedBody {
+    let (body, body_type) = match key {
+        FlatHashKey::Record(fields) => {
+            // Generalized record var so we can reuse this impl between many records:
+            // if fields = { a, b }, this is { a: t1, b: t2 } for fresh t1, t2.
+            let flex_fields = fields
+                .into_iter()
+                .map(|name| {
+                    (
+                        name,
+                        RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                    )
+                })
+                .collect::<Vec<(Lowercase, _)>>();
+            let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+            let record_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+            );
+
+            hash_record(env, record_var, fields, def_symbol)
+        }
+        FlatHashKey::TagUnion(tags) => {
+            // Generalized tag union var so we can reuse this impl between many unions:
+            // if tags = [ A arity=2, B arity=1 ], this is [ A t1 t2, B t3 ] for fresh t1, t2, t3
+            let flex_tag_labels = tags
+                .into_iter()
+                .map(|(label, arity)| {
+                    let variables_slice =
+                        VariableSubsSlice::reserve_into_subs(env.subs, arity.into());
+                    for var_index in variables_slice {
+                        env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                    }
+                    (label, variables_slice)
+                })
+                .collect::<Vec<_>>();
+            let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+            let tag_union_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::TagUnion(union_tags, Variable::EMPTY_TAG_UNION)),
+            );
+
+            hash_tag_union(env, tag_union_var, union_tags, def_symbol)
+        }
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::HASH_HASH);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+fn hash_record(
+    env: &mut Env<'_>,
+    record_var: Variable,
+    fields: RecordFields,
+    fn_name: Symbol,
+) -> (Expr, Variable) {
+    // Suppose rcd = { a: t1, b: t2 }. Build
+    //
+    // \hasher, rcd -> Hash.hash (Hash.hash hasher rcd.a) rcd.b
+    //
+    // For the empty record, this is just \hasher, rcd -> hasher
+
+    let hasher_sym = env.new_symbol("hasher");
+    let hasher_abilities = SubsSlice::extend_new(&mut env.subs.symbol_names, [Symbol::HASH_HASHER]);
+    let hasher_var = synth_var(env.subs, Content::FlexAbleVar(None, hasher_abilities));
+
+    let rcd_sym = env.new_symbol("rcd");
+
+    use Expr::*;
+
+    let (body_var, body) = fields.iter_all().fold(
+        (hasher_var, Var(hasher_sym)),
+        |(hasher_var, hasher_expr), (field_name_index, field_var_index, _)| {
+            let field_name = env.subs[field_name_index].clone();
+            let field_var = env.subs[field_var_index];
+
+            // rcd.a
+            let field_access = Access {
+                record_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                field_var,
+                loc_expr: Box::new(Loc::at_zero(Var(rcd_sym))),
+                field: field_name,
+            };
+
+            // Hash.hash hasher rcd.a
+            call_hash_hash(env, hasher_var, hasher_expr, field_var, field_access)
+        },
+    );
+
+    // \hasher, rcd -> Hash.hash (Hash.hash hasher rcd.a) rcd.b
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (hasher_var, hasher_sym),
+        (record_var, Pattern::Identifier(rcd_sym)),
+        (body_var, body),
+    )
+}
+
+fn hash_tag_union(
+    env: &mut Env<'_>,
+    tag_union_var: Variable,
+    tags: UnionTags,
+    fn_name: Symbol,
+) -> (Expr, Variable) {
+    // Suppose tags = [ A t1 t2, B t3 ]. Build
+    //
+    // \hasher, union -> when union is
+    //     A x1 x2 -> Hash.hash (Hash.hash (Hash.addU8 hasher 0) x1) x2
+    //     B x3 -> Hash.hash (Hash.addU8 hasher 1) x3
+    //
+    // The discriminant is only added if there is more than one tag, and it uses the
+    // smallest unsigned integer that fits all of them.
+
+    let hasher_sym = env.new_symbol("hasher");
+    let hasher_abilities = SubsSlice::extend_new(&mut env.subs.symbol_names, [Symbol::HASH_HASHER]);
+    let hasher_var = synth_var(env.subs, Content::FlexAbleVar(None, hasher_abilities));
+
+    let union_sym = env.new_symbol("union");
+
+    let num_tags = tags.len();
+    let discriminant_adder = if num_tags <= 1 {
+        None
+    } else if num_tags <= u8::MAX as usize + 1 {
+        Some((Symbol::HASH_ADD_U8, Variable::U8, IntLitWidth::U8))
+    } else if num_tags <= u16::MAX as usize + 1 {
+        Some((Symbol::HASH_ADD_U16, Variable::U16, IntLitWidth::U16))
+    } else {
+        Some((Symbol::HASH_ADD_U32, Variable::U32, IntLitWidth::U32))
+    };
+
+    use Expr::*;
+
+    let branches = tags
+        .iter_all()
+        .enumerate()
+        .map(|(discriminant, (tag_name_index, tag_vars_slice_index))| {
+            // A
+            let tag_name = env.subs[tag_name_index].clone();
+            let vars_slice = env.subs[tag_vars_slice_index];
+            // t1 t2
+            let payload_vars = env.subs.get_subs_slice(vars_slice).to_vec();
+            // x1 x2
+            let payload_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+
+            // `A x1 x2` pattern
+            let pattern = Pattern::AppliedTag {
+                whole_var: tag_union_var,
+                tag_name,
+                ext_var: Variable::EMPTY_TAG_UNION,
+                // (t1, x1) (t2, x2)
+                arguments: (payload_vars.iter())
+                    .zip(payload_syms.iter())
+                    .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+                    .collect(),
+            };
+            let branch_pattern = WhenBranchPattern {
+                pattern: Loc::at_zero(pattern),
+                degenerate: false,
+            };
+
+            // Hash.addU8 hasher 0
+            let (start_var, start_expr) = match discriminant_adder {
+                Some((adder, discriminant_var, width)) => call_hash_add_discriminant(
+                    env,
+                    (hasher_var, Var(hasher_sym)),
+                    adder,
+                    (discriminant_var, width, discriminant),
+                ),
+                None => (hasher_var, Var(hasher_sym)),
+            };
+
+            // Hash.hash (Hash.hash (Hash.addU8 hasher 0) x1) x2
+            let (body_var, body) = (payload_vars.iter()).zip(payload_syms.iter()).fold(
+                (start_var, start_expr),
+                |(hasher_var, hasher_expr), (var, sym)| {
+                    call_hash_hash(env, hasher_var, hasher_expr, *var, Var(*sym))
+                },
+            );
+
+            env.unify(body_var, hasher_var);
+
+            WhenBranch {
+                patterns: vec![branch_pattern],
+                value: Loc::at_zero(body),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let body = if branches.is_empty() {
+        // An empty union has no values to hash.
+        Var(hasher_sym)
+    } else {
+        // when union is
+        //     A x1 x2 -> Hash.hash (Hash.hash (Hash.addU8 hasher 0) x1) x2
+        //     B x3 -> Hash.hash (Hash.addU8 hasher 1) x3
+        When {
+            loc_cond: Box::new(Loc::at_zero(Var(union_sym))),
+            cond_var: tag_union_var,
+            expr_var: hasher_var,
+            region: Region::zero(),
+            branches,
+            branches_cond_var: tag_union_var,
+            exhaustive: ExhaustiveMark::known_exhaustive(),
+        }
+    };
+
+    // \hasher, union -> when union is ...
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (hasher_var, hasher_sym),
+        (tag_union_var, Pattern::Identifier(union_sym)),
+        (hasher_var, body),
+    )
+}
+
+/// Builds a call to `Hash.hash hasher val`, returning the type of the resulting hasher.
+fn call_hash_hash(
+    env: &mut Env<'_>,
+    hasher_var: Variable,
+    hasher_expr: Expr,
+    val_var: Variable,
+    val_expr: Expr,
+) -> (Variable, Expr) {
+    // build `Hash.hash hasher val` type
+    // expected: hasher, a -[uls]-> hasher | a has Hash, hasher has Hasher
+    let hash_fn_var = env.import_builtin_symbol_var(Symbol::HASH_HASH);
+
+    // wanted: hasher_var, val_var -[clos]-> t1
+    let this_arguments_slice = VariableSubsSlice::insert_into_subs(env.subs, [hasher_var, val_var]);
+    let this_hash_clos_var = env.subs.fresh_unnamed_flex_var(); // clos
+    let this_out_hasher_var = env.subs.fresh_unnamed_flex_var(); // t1
+    let this_hash_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_hash_clos_var,
+            this_out_hasher_var,
+        )),
+    );
+
+    //   hasher,     a       -[uls]->  hasher | a has Hash, hasher has Hasher
+    // ~ hasher_var, val_var -[clos]-> t1
+    env.unify(hash_fn_var, this_hash_fn_var);
+
+    // Hash.hash : hasher_var, val_var -[clos]-> hasher_var | val_var has Hash, hasher_var has Hasher
+    let hash_var = Expr::AbilityMember(Symbol::HASH_HASH, None, this_hash_fn_var);
+    let hash_fn = Box::new((
+        this_hash_fn_var,
+        Loc::at_zero(hash_var),
+        this_hash_clos_var,
+        this_out_hasher_var,
+    ));
+
+    // Hash.hash hasher val
+    let hash_call = Expr::Call(
+        hash_fn,
+        vec![
+            (hasher_var, Loc::at_zero(hasher_expr)),
+            (val_var, Loc::at_zero(val_expr)),
+        ],
+        CalledVia::Space,
+    );
+
+    (this_out_hasher_var, hash_call)
+}
+
+/// Builds a call to e.g. `Hash.addU8 hasher 0`, returning the type of the resulting hasher.
+fn call_hash_add_discriminant(
+    env: &mut Env<'_>,
+    (hasher_var, hasher_expr): (Variable, Expr),
+    adder: Symbol,
+    (discriminant_var, width, discriminant): (Variable, IntLitWidth, usize),
+) -> (Variable, Expr) {
+    // build `Hash.addU8 hasher 0` type
+    // expected: a, U8 -[uls]-> a | a has Hasher
+    let add_fn_var = env.import_builtin_symbol_var(adder);
+
+    // wanted: hasher_var, U8 -[clos]-> t1
+    let this_arguments_slice =
+        VariableSubsSlice::insert_into_subs(env.subs, [hasher_var, discriminant_var]);
+    let this_add_clos_var = env.subs.fresh_unnamed_flex_var(); // clos
+    let this_out_hasher_var = env.subs.fresh_unnamed_flex_var(); // t1
+    let this_add_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_add_clos_var,
+            this_out_hasher_var,
+        )),
+    );
+
+    //   a,          U8 -[uls]->  a | a has Hasher
+    // ~ hasher_var, U8 -[clos]-> t1
+    env.unify(add_fn_var, this_add_fn_var);
+
+    // Hash.addU8 : hasher_var, U8 -[clos]-> hasher_var | hasher_var has Hasher
+    let add_var = Expr::AbilityMember(adder, None, this_add_fn_var);
+    let add_fn = Box::new((
+        this_add_fn_var,
+        Loc::at_zero(add_var),
+        this_add_clos_var,
+        this_out_hasher_var,
+    ));
+
+    // 0u8
+    let discriminant_precision_var = env.subs.fresh_unnamed_flex_var();
+    let discriminant_expr = Expr::Int(
+        discriminant_var,
+        discriminant_precision_var,
+        discriminant.to_string().into_boxed_str(),
+        IntValue::I128((discriminant as i128).to_ne_bytes()),
+        IntBound::Exact(width),
+    );
+
+    // Hash.addU8 hasher 0
+    let add_call = Expr::Call(
+        add_fn,
+        vec![
+            (hasher_var, Loc::at_zero(hasher_expr)),
+            (discriminant_var, Loc::at_zero(discriminant_expr)),
+        ],
+        CalledVia::Space,
+    );
+
+    (this_out_hasher_var, add_call)
+}
+
+/// Builds the outer closure `\hasher, val -> body` of a derived hash implementation.
+fn build_outer_derived_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    (hasher_var, hasher_sym): (Variable, Symbol),
+    (val_var, val_pattern): (Variable, Pattern),
+    (body_var, body): (Variable, Expr),
+) -> (Expr, Variable) {
+    let (fn_var, fn_clos_var) = {
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[fn_name]->
+        let fn_captures = vec![];
+        let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, fn_captures)));
+        let fn_clos_var = synth_var(
+            env.subs,
+            Content::LambdaSet(LambdaSet {
+                solved: fn_name_labels,
+                recursion_var: OptVariable::NONE,
+                unspecialized: SubsSlice::default(),
+                ambient_function: fn_var,
+            }),
+        );
+
+        // hasher, val -[fn_name]-> (hasher)
+        let args_slice = SubsSlice::insert_into_subs(env.subs, vec![hasher_var, val_var]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(args_slice, fn_clos_var, body_var)),
+        );
+
+        (fn_var, fn_clos_var)
+    };
+
+    let clos_expr = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: body_var,
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![
+            (
+                hasher_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(Pattern::Identifier(hasher_sym)),
+            ),
+            (
+                val_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(val_pattern),
+            ),
+        ],
+        loc_body: Box::new(Loc::at_zero(body)),
+    });
+
+    (clos_expr, fn_var)
+}
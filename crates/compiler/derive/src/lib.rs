@@ -16,8 +16,11 @@ use roc_types::subs::{
 };
 use util::Env;
 
+mod arbitrary;
 mod decoding;
 mod encoding;
+mod eq;
+mod hash;
 
 mod util;
 
@@ -77,6 +80,11 @@ fn build_derived_body(
         DeriveKey::Decoder(decoder_key) => {
             decoding::derive_decoder(&mut env, decoder_key, derived_symbol)
         }
+        DeriveKey::Hash(hash_key) => hash::derive_hash(&mut env, hash_key, derived_symbol),
+        DeriveKey::IsEq(eq_key) => eq::derive_is_eq(&mut env, eq_key, derived_symbol),
+        DeriveKey::Arbitrary(arbitrary_key) => {
+            arbitrary::derive_arbitrary(&mut env, arbitrary_key, derived_symbol)
+        }
     };
 
     let def = Def {
@@ -0,0 +1,155 @@
+use roc_error_macros::internal_error;
+use roc_module::{
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_types::subs::{Content, FlatType, Subs, Variable};
+
+use crate::{
+    util::{check_derivable_ext_var, debug_name_record, debug_name_tag},
+    DeriveError,
+};
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+pub enum FlatArbitraryKey {
+    // `Gen.u8`, `Gen.str`, etc. These are plain generators rather than ability members, so
+    // they are wrapped in a derived implementation of their own, like every other key.
+    Builtin(Symbol),
+    List(/* takes one variable */),
+
+    // Unfortunate that we must allocate here, c'est la vie
+    Record(Vec<Lowercase>),
+    TagUnion(Vec<(TagName, u16)>),
+}
+
+impl FlatArbitraryKey {
+    pub(crate) fn debug_name(&self) -> String {
+        match self {
+            FlatArbitraryKey::Builtin(symbol) => builtin_debug_name(*symbol).to_string(),
+            FlatArbitraryKey::List() => "list".to_string(),
+            FlatArbitraryKey::Record(fields) => debug_name_record(fields),
+            FlatArbitraryKey::TagUnion(tags) => debug_name_tag(tags),
+        }
+    }
+
+    pub(crate) fn from_var(subs: &Subs, var: Variable) -> Result<FlatArbitraryKey, DeriveError> {
+        use DeriveError::*;
+        use FlatArbitraryKey::*;
+        match *subs.get_content_without_compacting(var) {
+            Content::Structure(flat_type) => match flat_type {
+                FlatType::Apply(sym, _) => match sym {
+                    Symbol::LIST_LIST => Ok(List()),
+                    Symbol::STR_STR => Ok(Builtin(Symbol::GEN_STR)),
+                    _ => Err(Underivable),
+                },
+                FlatType::Record(fields, ext) => {
+                    let (fields_iter, ext) = fields.unsorted_iterator_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyRecord))
+                    })?;
+
+                    let mut field_names = Vec::with_capacity(fields.len());
+                    for (field_name, record_field) in fields_iter {
+                        if record_field.is_optional() {
+                            // Like decoding, we can't generate a value for an optional field,
+                            // since whether it is there is only known at compile time
+                            return Err(Underivable);
+                        }
+                        field_names.push(field_name.clone());
+                    }
+
+                    field_names.sort();
+
+                    Ok(Record(field_names))
+                }
+                FlatType::TagUnion(tags, ext) | FlatType::RecursiveTagUnion(_, tags, ext) => {
+                    // As with encoding, only the surface of the tag union matters; the payloads
+                    // are generated with their own `Arbitrary` implementations.
+                    let (tags_iter, ext) = tags.unsorted_tags_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyTagUnion))
+                    })?;
+
+                    let mut tag_names_and_payload_sizes: Vec<_> = tags_iter
+                        .tags
+                        .into_iter()
+                        .map(|(name, payload_slice)| {
+                            let payload_size = payload_slice.len();
+                            (name.clone(), payload_size as _)
+                        })
+                        .collect();
+
+                    tag_names_and_payload_sizes.sort_by(|(t1, _), (t2, _)| t1.cmp(t2));
+
+                    Ok(TagUnion(tag_names_and_payload_sizes))
+                }
+                FlatType::FunctionOrTagUnion(name_index, _, _) => {
+                    Ok(TagUnion(vec![(subs[name_index].clone(), 0)]))
+                }
+                FlatType::EmptyRecord => Ok(Record(vec![])),
+                FlatType::EmptyTagUnion => Ok(TagUnion(vec![])),
+                //
+                FlatType::Tuple(..) | FlatType::EmptyTuple => Err(Underivable),
+                FlatType::Erroneous(_) => Err(Underivable),
+                FlatType::Func(..) => Err(Underivable),
+            },
+            Content::Alias(sym, _, real_var, _) => {
+                let builtin = match sym {
+                    Symbol::NUM_U8 | Symbol::NUM_UNSIGNED8 => Symbol::GEN_U8,
+                    Symbol::NUM_U16 | Symbol::NUM_UNSIGNED16 => Symbol::GEN_U16,
+                    Symbol::NUM_U32 | Symbol::NUM_UNSIGNED32 => Symbol::GEN_U32,
+                    Symbol::NUM_U64 | Symbol::NUM_UNSIGNED64 => Symbol::GEN_U64,
+                    Symbol::NUM_U128 | Symbol::NUM_UNSIGNED128 => Symbol::GEN_U128,
+                    Symbol::NUM_I8 | Symbol::NUM_SIGNED8 => Symbol::GEN_I8,
+                    Symbol::NUM_I16 | Symbol::NUM_SIGNED16 => Symbol::GEN_I16,
+                    Symbol::NUM_I32 | Symbol::NUM_SIGNED32 => Symbol::GEN_I32,
+                    Symbol::NUM_I64 | Symbol::NUM_SIGNED64 => Symbol::GEN_I64,
+                    Symbol::NUM_I128 | Symbol::NUM_SIGNED128 => Symbol::GEN_I128,
+                    Symbol::NUM_NAT | Symbol::NUM_NATURAL => Symbol::GEN_NAT,
+                    Symbol::NUM_DEC | Symbol::NUM_DECIMAL => Symbol::GEN_DEC,
+                    Symbol::NUM_F32 | Symbol::NUM_BINARY32 => Symbol::GEN_F32,
+                    Symbol::NUM_F64 | Symbol::NUM_BINARY64 => Symbol::GEN_F64,
+                    Symbol::BOOL_BOOL => Symbol::GEN_BOOL,
+                    // NB: I believe it is okay to unwrap opaques here because derivers are only used
+                    // by the backend, and the backend treats opaques like structural aliases.
+                    _ => return Self::from_var(subs, real_var),
+                };
+
+                Ok(Builtin(builtin))
+            }
+            Content::RangedNumber(_) => Err(Underivable),
+            //
+            Content::RecursionVar { .. } => Err(Underivable),
+            Content::Error => Err(Underivable),
+            Content::FlexVar(_)
+            | Content::RigidVar(_)
+            | Content::FlexAbleVar(_, _)
+            | Content::RigidAbleVar(_, _) => Err(UnboundVar),
+            Content::LambdaSet(_) => Err(Underivable),
+        }
+    }
+}
+
+fn builtin_debug_name(symbol: Symbol) -> &'static str {
+    match symbol {
+        Symbol::GEN_U8 => "u8",
+        Symbol::GEN_U16 => "u16",
+        Symbol::GEN_U32 => "u32",
+        Symbol::GEN_U64 => "u64",
+        Symbol::GEN_U128 => "u128",
+        Symbol::GEN_I8 => "i8",
+        Symbol::GEN_I16 => "i16",
+        Symbol::GEN_I32 => "i32",
+        Symbol::GEN_I64 => "i64",
+        Symbol::GEN_I128 => "i128",
+        Symbol::GEN_NAT => "nat",
+        Symbol::GEN_F32 => "f32",
+        Symbol::GEN_F64 => "f64",
+        Symbol::GEN_DEC => "dec",
+        Symbol::GEN_BOOL => "bool",
+        Symbol::GEN_STR => "str",
+        _ => internal_error!("{:?} is not a builtin generator", symbol),
+    }
+}
@@ -0,0 +1,138 @@
+use roc_module::{
+    ident::{Lowercase, TagName},
+    symbol::{ModuleId, Symbol},
+};
+use roc_types::subs::{Content, FlatType, GetSubsSlice, Subs, Variable};
+
+use crate::{
+    util::{check_derivable_ext_var, debug_name_record, debug_name_tag},
+    DeriveError,
+};
+
+#[derive(Hash)]
+pub enum FlatEq {
+    // `Bool.structuralEq`, `List.isEq`
+    SingleLambdaSetImmediate(Symbol),
+    Key(FlatEqKey),
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+pub enum FlatEqKey {
+    // Unfortunate that we must allocate here, c'est la vie
+    Record(Vec<Lowercase>),
+    TagUnion(Vec<(TagName, u16)>),
+}
+
+impl FlatEqKey {
+    pub(crate) fn debug_name(&self) -> String {
+        match self {
+            FlatEqKey::Record(fields) => debug_name_record(fields),
+            FlatEqKey::TagUnion(tags) => debug_name_tag(tags),
+        }
+    }
+}
+
+impl FlatEq {
+    pub(crate) fn from_var(subs: &Subs, var: Variable) -> Result<FlatEq, DeriveError> {
+        use DeriveError::*;
+        use FlatEq::*;
+        match *subs.get_content_without_compacting(var) {
+            Content::Structure(flat_type) => match flat_type {
+                FlatType::Apply(sym, vars) => match sym {
+                    Symbol::LIST_LIST => {
+                        let elem_var = subs.get_subs_slice(vars)[0];
+                        if is_structural_eq(subs, elem_var) {
+                            Ok(SingleLambdaSetImmediate(Symbol::BOOL_STRUCTURAL_EQ))
+                        } else {
+                            Ok(SingleLambdaSetImmediate(Symbol::LIST_IS_EQ))
+                        }
+                    }
+                    Symbol::STR_STR => Ok(SingleLambdaSetImmediate(Symbol::BOOL_STRUCTURAL_EQ)),
+                    _ => Err(Underivable),
+                },
+                FlatType::Record(fields, ext) => {
+                    let (fields_iter, ext) = fields.unsorted_iterator_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyRecord))
+                    })?;
+
+                    let mut field_names = Vec::with_capacity(fields.len());
+                    for (field_name, _) in fields_iter {
+                        field_names.push(field_name.clone());
+                    }
+
+                    field_names.sort();
+
+                    Ok(Key(FlatEqKey::Record(field_names)))
+                }
+                FlatType::TagUnion(tags, ext) | FlatType::RecursiveTagUnion(_, tags, ext) => {
+                    // Like `Hash`, the derived implementation only looks at the surface of the tag
+                    // union; the payloads are compared with their own `isEq`, which the
+                    // monomorphizer resolves once it knows their types.
+                    let (tags_iter, ext) = tags.unsorted_tags_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyTagUnion))
+                    })?;
+
+                    let mut tag_names_and_payload_sizes: Vec<_> = tags_iter
+                        .tags
+                        .into_iter()
+                        .map(|(name, payload_slice)| {
+                            let payload_size = payload_slice.len();
+                            (name.clone(), payload_size as _)
+                        })
+                        .collect();
+
+                    tag_names_and_payload_sizes.sort_by(|(t1, _), (t2, _)| t1.cmp(t2));
+
+                    Ok(Key(FlatEqKey::TagUnion(tag_names_and_payload_sizes)))
+                }
+                FlatType::FunctionOrTagUnion(name_index, _, _) => Ok(Key(FlatEqKey::TagUnion(
+                    vec![(subs[name_index].clone(), 0)],
+                ))),
+                FlatType::EmptyRecord => Ok(Key(FlatEqKey::Record(vec![]))),
+                FlatType::EmptyTagUnion => Ok(Key(FlatEqKey::TagUnion(vec![]))),
+                // Tuples are still compared structurally, ignoring custom `isEq` implementations
+                // of their elements.
+                FlatType::Tuple(..) | FlatType::EmptyTuple => {
+                    Ok(SingleLambdaSetImmediate(Symbol::BOOL_STRUCTURAL_EQ))
+                }
+                FlatType::Erroneous(_) => Err(Underivable),
+                FlatType::Func(..) => Err(Underivable),
+            },
+            Content::Alias(sym, _, real_var, _) => {
+                if sym.module_id() == ModuleId::NUM {
+                    Ok(SingleLambdaSetImmediate(Symbol::BOOL_STRUCTURAL_EQ))
+                } else {
+                    // NB: I believe it is okay to unwrap opaques here because derivers are only
+                    // used by the backend, and the backend treats opaques like structural aliases.
+                    Self::from_var(subs, real_var)
+                }
+            }
+            Content::RangedNumber(_) => Ok(SingleLambdaSetImmediate(Symbol::BOOL_STRUCTURAL_EQ)),
+            //
+            Content::RecursionVar { .. } => Err(Underivable),
+            Content::Error => Err(Underivable),
+            Content::FlexVar(_)
+            | Content::RigidVar(_)
+            | Content::FlexAbleVar(_, _)
+            | Content::RigidAbleVar(_, _) => Err(UnboundVar),
+            Content::LambdaSet(_) => Err(Underivable),
+        }
+    }
+}
+
+/// Whether values of this type are always compared structurally, so that a list of them can be
+/// compared with `Bool.structuralEq` rather than element by element.
+fn is_structural_eq(subs: &Subs, var: Variable) -> bool {
+    match *subs.get_content_without_compacting(var) {
+        Content::Alias(sym, _, _, _) => {
+            sym.module_id() == ModuleId::NUM || sym == Symbol::BOOL_BOOL
+        }
+        Content::Structure(FlatType::Apply(Symbol::STR_STR, _)) => true,
+        Content::RangedNumber(_) => true,
+        _ => false,
+    }
+}
@@ -185,6 +185,15 @@ impl<'a> Formattable for TypeAnnotation<'a> {
                 fields.items.iter().any(|field| field.value.is_multiline())
             }
 
+            Tuple { elems, ext } => {
+                match ext {
+                    Some(ann) if ann.value.is_multiline() => return true,
+                    _ => {}
+                }
+
+                elems.iter().any(|elem| elem.value.is_multiline())
+            }
+
             TagUnion { tags, ext } => {
                 match ext {
                     Some(ann) if ann.value.is_multiline() => return true,
@@ -307,6 +316,14 @@ impl<'a> Formattable for TypeAnnotation<'a> {
                 }
             }
 
+            Tuple { elems, ext } => {
+                fmt_collection(buf, indent, Braces::Round, *elems, newlines);
+
+                if let Some(loc_ext_ann) = *ext {
+                    loc_ext_ann.value.format(buf, indent);
+                }
+            }
+
             As(lhs, _spaces, TypeHeader { name, vars }) => {
                 // TODO use _spaces?
                 lhs.value
@@ -546,7 +563,7 @@ impl<'a> Formattable for Tag<'a> {
 
 impl<'a> Formattable for HasClause<'a> {
     fn is_multiline(&self) -> bool {
-        self.ability.is_multiline()
+        self.abilities.iter().any(|ability| ability.is_multiline())
     }
 
     fn format_with_options<'buf>(
@@ -559,9 +576,15 @@ impl<'a> Formattable for HasClause<'a> {
         buf.push_str(self.var.value.extract_spaces().item);
         buf.spaces(1);
         buf.push_str("has");
-        buf.spaces(1);
-        self.ability
-            .format_with_options(buf, parens, newlines, indent);
+
+        for (i, ability) in self.abilities.iter().enumerate() {
+            buf.spaces(1);
+            if i > 0 {
+                buf.push_str("&");
+                buf.spaces(1);
+            }
+            ability.format_with_options(buf, parens, newlines, indent);
+        }
     }
 }
 
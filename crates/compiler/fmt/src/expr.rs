@@ -9,7 +9,8 @@ use crate::spaces::{
 use crate::Buf;
 use roc_module::called_via::{self, BinOp};
 use roc_parse::ast::{
-    AssignedField, Base, Collection, CommentOrNewline, Expr, ExtractSpaces, Pattern, WhenBranch,
+    AssignedField, Base, Collection, CommentOrNewline, Expr, ExtractSpaces, Pattern,
+    RecordBuilderField, WhenBranch,
 };
 use roc_parse::ast::{StrLiteral, StrSegment};
 use roc_region::all::Loc;
@@ -35,18 +36,22 @@ impl<'a> Formattable for Expr<'a> {
             | NonBase10Int { .. }
             | SingleQuote(_)
             | Access(_, _)
+            | TupleAccess(_, _)
             | AccessorFunction(_)
             | Var { .. }
             | Underscore { .. }
+            | Crash
             | MalformedIdent(_, _)
             | MalformedClosure
+            | MultipleRecordBuilders(_)
+            | UnappliedRecordBuilder(_)
             | Tag(_)
             | OpaqueRef(_) => false,
 
             // These expressions always have newlines
             Defs(_, _) | When(_, _) => true,
 
-            List(items) => items.iter().any(|loc_expr| loc_expr.is_multiline()),
+            List(items) | Tuple(items) => items.iter().any(|loc_expr| loc_expr.is_multiline()),
 
             Str(literal) => {
                 use roc_parse::ast::StrLiteral::*;
@@ -69,6 +74,7 @@ impl<'a> Formattable for Expr<'a> {
             Expect(condition, continuation) => {
                 condition.is_multiline() || continuation.is_multiline()
             }
+            Dbg(value) => value.is_multiline(),
 
             If(branches, final_else) => {
                 final_else.is_multiline()
@@ -107,6 +113,7 @@ impl<'a> Formattable for Expr<'a> {
 
             Record(fields) => fields.iter().any(|loc_field| loc_field.is_multiline()),
             RecordUpdate { fields, .. } => fields.iter().any(|loc_field| loc_field.is_multiline()),
+            RecordBuilder(fields) => fields.iter().any(|loc_field| loc_field.is_multiline()),
         }
     }
 
@@ -187,6 +194,10 @@ impl<'a> Formattable for Expr<'a> {
                 buf.push('_');
                 buf.push_str(name);
             }
+            Crash => {
+                buf.indent(indent);
+                buf.push_str("crash");
+            }
             Apply(loc_expr, loc_args, _) => {
                 buf.indent(indent);
                 if apply_needs_parens && !loc_args.is_empty() {
@@ -204,7 +215,7 @@ impl<'a> Formattable for Expr<'a> {
                     if iter.peek().is_none() {
                         found_multiline_expr = match loc_arg.value {
                             SpaceBefore(sub_expr, spaces) => match sub_expr {
-                                Record { .. } | List { .. } => {
+                                Record { .. } | RecordBuilder { .. } | List { .. } => {
                                     let is_only_newlines = spaces.iter().all(|s| s.is_newline());
                                     is_only_newlines
                                         && !found_multiline_expr
@@ -212,7 +223,7 @@ impl<'a> Formattable for Expr<'a> {
                                 }
                                 _ => false,
                             },
-                            Record { .. } | List { .. } | Closure { .. } => {
+                            Record { .. } | RecordBuilder { .. } | List { .. } | Closure { .. } => {
                                 !found_multiline_expr && loc_arg.is_multiline()
                             }
                             _ => false,
@@ -321,10 +332,34 @@ impl<'a> Formattable for Expr<'a> {
                 buf.push_str(string);
             }
             Record(fields) => {
-                fmt_record(buf, None, *fields, indent);
+                fmt_record_like(
+                    buf,
+                    None,
+                    *fields,
+                    indent,
+                    format_assigned_field_multiline,
+                    assigned_field_to_space_before,
+                );
             }
             RecordUpdate { update, fields } => {
-                fmt_record(buf, Some(*update), *fields, indent);
+                fmt_record_like(
+                    buf,
+                    Some(*update),
+                    *fields,
+                    indent,
+                    format_assigned_field_multiline,
+                    assigned_field_to_space_before,
+                );
+            }
+            RecordBuilder(fields) => {
+                fmt_record_like(
+                    buf,
+                    None,
+                    *fields,
+                    indent,
+                    format_record_builder_field_multiline,
+                    record_builder_field_to_space_before,
+                );
             }
             Closure(loc_patterns, loc_ret) => {
                 fmt_closure(buf, loc_patterns, loc_ret, indent);
@@ -376,11 +411,15 @@ impl<'a> Formattable for Expr<'a> {
             Expect(condition, continuation) => {
                 fmt_expect(buf, condition, continuation, self.is_multiline(), indent);
             }
+            Dbg(value) => {
+                fmt_dbg(buf, value, self.is_multiline(), indent);
+            }
             If(branches, final_else) => {
                 fmt_if(buf, branches, final_else, self.is_multiline(), indent);
             }
             When(loc_condition, branches) => fmt_when(buf, loc_condition, branches, indent),
             List(items) => fmt_collection(buf, indent, Braces::Square, *items, Newlines::No),
+            Tuple(items) => fmt_collection(buf, indent, Braces::Round, *items, Newlines::No),
             BinOps(lefts, right) => fmt_binops(buf, lefts, right, false, parens, indent),
             UnaryOp(sub_expr, unary_op) => {
                 buf.indent(indent);
@@ -400,7 +439,7 @@ impl<'a> Formattable for Expr<'a> {
                 buf.push('.');
                 buf.push_str(key);
             }
-            Access(expr, key) => {
+            Access(expr, key) | TupleAccess(expr, key) => {
                 expr.format_with_options(buf, Parens::InApply, Newlines::Yes, indent);
                 buf.push('.');
                 buf.push_str(key);
@@ -408,6 +447,8 @@ impl<'a> Formattable for Expr<'a> {
             MalformedIdent(_, _) => {}
             MalformedClosure => {}
             PrecedenceConflict { .. } => {}
+            MultipleRecordBuilders { .. } => {}
+            UnappliedRecordBuilder { .. } => {}
         }
     }
 }
@@ -848,6 +889,26 @@ fn fmt_expect<'a, 'buf>(
     continuation.format(buf, indent);
 }
 
+fn fmt_dbg<'a, 'buf>(
+    buf: &mut Buf<'buf>,
+    value: &'a Loc<Expr<'a>>,
+    is_multiline: bool,
+    indent: u16,
+) {
+    buf.indent(indent);
+    buf.push_str("dbg");
+
+    let value_indent = if is_multiline {
+        buf.newline();
+        indent + INDENT
+    } else {
+        buf.spaces(1);
+        indent
+    };
+
+    value.format(buf, value_indent);
+}
+
 fn fmt_if<'a, 'buf>(
     buf: &mut Buf<'buf>,
     branches: &'a [(Loc<Expr<'a>>, Loc<Expr<'a>>)],
@@ -1193,12 +1254,18 @@ fn pattern_needs_parens_when_backpassing(pat: &Pattern) -> bool {
     }
 }
 
-fn fmt_record<'a, 'buf>(
+fn fmt_record_like<'a, 'buf, Field, Format, ToSpaceBefore>(
     buf: &mut Buf<'buf>,
     update: Option<&'a Loc<Expr<'a>>>,
-    fields: Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>,
+    fields: Collection<'a, Loc<Field>>,
     indent: u16,
-) {
+    format_field_multiline: Format,
+    to_space_before: ToSpaceBefore,
+) where
+    Field: Formattable,
+    Format: Fn(&mut Buf<'buf>, &Field, u16, &str),
+    ToSpaceBefore: Fn(&'a Field) -> Option<(&'a Field, &'a [CommentOrNewline<'a>])>,
+{
     let loc_fields = fields.items;
     let final_comments = fields.final_comments();
     buf.indent(indent);
@@ -1234,7 +1301,7 @@ fn fmt_record<'a, 'buf>(
                 // In this case, we have to move the comma before the comment.
 
                 let is_first_item = index == 0;
-                if let AssignedField::SpaceBefore(_sub_field, spaces) = &field.value {
+                if let Some((_sub_field, spaces)) = to_space_before(&field.value) {
                     let is_only_newlines = spaces.iter().all(|s| s.is_newline());
                     if !is_first_item
                         && !is_only_newlines
@@ -1285,7 +1352,7 @@ fn fmt_record<'a, 'buf>(
     }
 }
 
-fn format_field_multiline<'a, 'buf, T>(
+fn format_assigned_field_multiline<'a, 'buf, T>(
     buf: &mut Buf<'buf>,
     field: &AssignedField<'a, T>,
     indent: u16,
@@ -1339,7 +1406,7 @@ fn format_field_multiline<'a, 'buf, T>(
             // ```
             // we'd like to preserve this
 
-            format_field_multiline(buf, sub_field, indent, separator_prefix);
+            format_assigned_field_multiline(buf, sub_field, indent, separator_prefix);
         }
         AssignedField::SpaceAfter(sub_field, spaces) => {
             // We have something like that:
@@ -1353,7 +1420,7 @@ fn format_field_multiline<'a, 'buf, T>(
             // # comment
             // otherfield
             // ```
-            format_field_multiline(buf, sub_field, indent, separator_prefix);
+            format_assigned_field_multiline(buf, sub_field, indent, separator_prefix);
             fmt_comments_only(buf, spaces.iter(), NewlineAt::Top, indent);
         }
         Malformed(raw) => {
@@ -1362,6 +1429,161 @@ fn format_field_multiline<'a, 'buf, T>(
     }
 }
 
+fn assigned_field_to_space_before<'a, T>(
+    field: &'a AssignedField<'a, T>,
+) -> Option<(&'a AssignedField<'a, T>, &'a [CommentOrNewline<'a>])> {
+    match field {
+        AssignedField::SpaceBefore(sub_field, spaces) => Some((sub_field, spaces)),
+        _ => None,
+    }
+}
+
+impl<'a> Formattable for RecordBuilderField<'a> {
+    fn is_multiline(&self) -> bool {
+        use self::RecordBuilderField::*;
+
+        match self {
+            Value(_, spaces, value) => !spaces.is_empty() || value.is_multiline(),
+            ApplyValue(_, colon_spaces, arrow_spaces, value) => {
+                !colon_spaces.is_empty() || !arrow_spaces.is_empty() || value.is_multiline()
+            }
+            LabelOnly(_) => false,
+            SpaceBefore(_, _) | SpaceAfter(_, _) => true,
+            Malformed(text) => text.chars().any(|c| c == '\n'),
+        }
+    }
+
+    fn format_with_options<'buf>(
+        &self,
+        buf: &mut Buf<'buf>,
+        _parens: Parens,
+        _newlines: Newlines,
+        indent: u16,
+    ) {
+        use self::RecordBuilderField::*;
+
+        match self {
+            Value(name, spaces, value) => {
+                buf.push_str(name.value);
+
+                if !spaces.is_empty() {
+                    fmt_spaces(buf, spaces.iter(), indent);
+                }
+
+                buf.push(':');
+                buf.spaces(1);
+                value.format(buf, indent);
+            }
+            ApplyValue(name, colon_spaces, arrow_spaces, value) => {
+                buf.push_str(name.value);
+
+                if !colon_spaces.is_empty() {
+                    fmt_spaces(buf, colon_spaces.iter(), indent);
+                }
+
+                buf.push(':');
+                buf.spaces(1);
+
+                if !arrow_spaces.is_empty() {
+                    fmt_spaces(buf, arrow_spaces.iter(), indent);
+                }
+
+                buf.push_str("<-");
+                buf.spaces(1);
+                value.format(buf, indent);
+            }
+            LabelOnly(name) => {
+                buf.push_str(name.value);
+            }
+            SpaceBefore(sub_field, spaces) => {
+                fmt_comments_only(buf, spaces.iter(), NewlineAt::Bottom, indent);
+                sub_field.format(buf, indent);
+            }
+            SpaceAfter(sub_field, spaces) => {
+                sub_field.format(buf, indent);
+                fmt_comments_only(buf, spaces.iter(), NewlineAt::Bottom, indent);
+            }
+            Malformed(raw) => {
+                buf.push_str(raw);
+            }
+        }
+    }
+}
+
+fn format_record_builder_field_multiline(
+    buf: &mut Buf,
+    field: &RecordBuilderField,
+    indent: u16,
+    separator_prefix: &str,
+) {
+    use self::RecordBuilderField::*;
+    match field {
+        Value(name, spaces, value) => {
+            buf.newline();
+            buf.indent(indent);
+            buf.push_str(name.value);
+
+            if !spaces.is_empty() {
+                fmt_spaces(buf, spaces.iter(), indent);
+            }
+
+            buf.push_str(separator_prefix);
+            buf.push_str(":");
+            buf.spaces(1);
+            value.format(buf, indent);
+            buf.push(',');
+        }
+        ApplyValue(name, colon_spaces, arrow_spaces, value) => {
+            buf.newline();
+            buf.indent(indent);
+            buf.push_str(name.value);
+
+            if !colon_spaces.is_empty() {
+                fmt_spaces(buf, colon_spaces.iter(), indent);
+            }
+
+            buf.push_str(separator_prefix);
+            buf.push_str(":");
+            buf.spaces(1);
+
+            if !arrow_spaces.is_empty() {
+                fmt_spaces(buf, arrow_spaces.iter(), indent);
+            }
+
+            buf.push_str("<-");
+            buf.spaces(1);
+            value.format(buf, indent);
+            buf.push(',');
+        }
+        LabelOnly(name) => {
+            buf.newline();
+            buf.indent(indent);
+            buf.push_str(name.value);
+            buf.push(',');
+        }
+        SpaceBefore(sub_field, _spaces) => {
+            // the comments before the field are written by `fmt_record_like`
+            format_record_builder_field_multiline(buf, sub_field, indent, separator_prefix);
+        }
+        SpaceAfter(sub_field, spaces) => {
+            format_record_builder_field_multiline(buf, sub_field, indent, separator_prefix);
+            fmt_comments_only(buf, spaces.iter(), NewlineAt::Top, indent);
+        }
+        Malformed(raw) => {
+            buf.push_str(raw);
+        }
+    }
+}
+
+fn record_builder_field_to_space_before<'a>(
+    field: &'a RecordBuilderField<'a>,
+) -> Option<(&'a RecordBuilderField<'a>, &'a [CommentOrNewline<'a>])> {
+    match field {
+        RecordBuilderField::SpaceBefore(sub_field, spaces) => Some((sub_field, spaces)),
+        _ => None,
+    }
+}
+
 fn sub_expr_requests_parens(expr: &Expr<'_>) -> bool {
     match expr {
         Expr::BinOps(left_side, _) => {
@@ -24,6 +24,7 @@ impl<'a> Formattable for Pattern<'a> {
             }
 
             Pattern::RecordDestructure(fields) => fields.iter().any(|f| f.is_multiline()),
+            Pattern::Tuple(elems) => elems.iter().any(|e| e.is_multiline()),
             Pattern::RequiredField(_, subpattern) => subpattern.is_multiline(),
 
             Pattern::OptionalField(_, expr) => expr.is_multiline(),
@@ -104,6 +105,23 @@ impl<'a> Formattable for Pattern<'a> {
                 buf.push_str("}");
             }
 
+            Tuple(loc_patterns) => {
+                buf.indent(indent);
+                buf.push('(');
+
+                let mut it = loc_patterns.iter().peekable();
+                while let Some(loc_pattern) = it.next() {
+                    loc_pattern.format(buf, indent);
+
+                    if it.peek().is_some() {
+                        buf.push(',');
+                        buf.spaces(1);
+                    }
+                }
+
+                buf.push(')');
+            }
+
             RequiredField(name, loc_pattern) => {
                 buf.indent(indent);
                 buf.push_str(name);
@@ -2864,6 +2864,28 @@ mod test_fmt {
 
     // RECORD LITERALS
 
+    #[test]
+    fn tuples() {
+        expr_formats_same(indoc!(
+            r#"
+            swap : (a, b) -> (b, a)
+            swap = \(x, y) -> (y, x)
+
+            swap (1, "two")
+            "#
+        ));
+        expr_formats_to("( 1 , pair.0 , (f x).1 )", "(1, pair.0, (f x).1)");
+        expr_formats_same(indoc!(
+            r#"
+            (
+                1,
+                # the second one
+                2,
+            )
+            "#
+        ));
+    }
+
     #[test]
     fn empty_record() {
         expr_formats_same("{}");
@@ -5315,6 +5337,39 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn has_clause_with_multiple_abilities() {
+        expr_formats_same(indoc!(
+            r#"
+            f : a -> U64 | a has Hash & Eq
+
+            f
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                f : a, b -> U64 | a has Hash&Eq,   b has Eq   &   Hash
+
+                A := a | a has Hash & Eq has [ Eq, Hash ]
+
+                f
+                "#
+            ),
+            indoc!(
+                r#"
+                f : a, b -> U64 | a has Hash & Eq, b has Eq & Hash
+
+                A := a | a has Hash & Eq
+                     has [Eq, Hash]
+
+                f
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn opaque_has_with_impls() {
         expr_formats_same(indoc!(
@@ -5524,6 +5579,109 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn dbg_single_line() {
+        expr_formats_same(indoc!(
+            r#"
+            x = dbg 5 + y
+
+            x
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                x = dbg   5
+
+                x
+                "#
+            ),
+            indoc!(
+                r#"
+                x = dbg 5
+
+                x
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn dbg_multiline() {
+        expr_formats_same(indoc!(
+            r#"
+            x =
+                dbg
+                    foo bar
+                    |> baz
+
+            x
+            "#
+        ));
+    }
+
+    #[test]
+    fn crash_with_message() {
+        expr_formats_same(indoc!(
+            r#"
+            when x is
+                Ok v -> v
+                Err _ -> crash "this should never happen"
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                crash   "oops"
+                "#
+            ),
+            indoc!(
+                r#"
+                crash "oops"
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn record_builder() {
+        expr_formats_same(indoc!(
+            r#"
+            succeed { a: <- get "a", b: <- get "b" }
+            "#
+        ));
+
+        expr_formats_same(indoc!(
+            r#"
+            Cli.succeed {
+                name: <- Cli.string "name",
+                verbose: <- Cli.flag "verbose",
+                retries,
+                kind: Person,
+            }
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                succeed { a:<-get "a",
+                  b :  <-   get "b" }
+                "#
+            ),
+            indoc!(
+                r#"
+                succeed {
+                    a: <- get "a",
+                    b: <- get "b",
+                }
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn single_line_string_literal_in_pattern() {
         expr_formats_same(indoc!(
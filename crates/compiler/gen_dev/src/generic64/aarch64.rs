@@ -711,8 +711,8 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         }
     }
     #[inline(always)]
-    fn neg_reg64_reg64(_buf: &mut Vec<'_, u8>, _dst: AArch64GeneralReg, _src: AArch64GeneralReg) {
-        todo!("neg for AArch64");
+    fn neg_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        neg_reg64_reg64(buf, dst, src);
     }
 
     #[inline(always)]
@@ -732,12 +732,12 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
     }
     #[inline(always)]
     fn sub_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("registers subtractions for AArch64");
+        sub_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     #[inline(always)]
@@ -836,21 +836,21 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
     }
 
     fn and_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("bitwise and for AArch64")
+        and_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     fn or_reg64_reg64_reg64(
-        _buf: &mut Vec<'_, u8>,
-        _dst: AArch64GeneralReg,
-        _src1: AArch64GeneralReg,
-        _src2: AArch64GeneralReg,
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
     ) {
-        todo!("bitwise or for AArch64")
+        orr_reg64_reg64_reg64(buf, dst, src1, src2);
     }
 
     fn xor_reg64_reg64_reg64(
@@ -861,6 +861,108 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
     ) {
         todo!("bitwise xor for AArch64")
     }
+
+    fn clz_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        clz_reg64_reg64(buf, dst, src);
+    }
+
+    fn ctz_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        // There is no CTZ before ARMv8.9, so count the leading zeros of the reversed bits.
+        rbit_reg64_reg64(buf, dst, src);
+        clz_reg64_reg64(buf, dst, dst);
+    }
+
+    fn popcnt_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<'a, AArch64GeneralReg, AArch64FloatReg, ASM, CC>,
+        dst: AArch64GeneralReg,
+        src: AArch64GeneralReg,
+    ) where
+        ASM: Assembler<AArch64GeneralReg, AArch64FloatReg>,
+        CC: CallConv<AArch64GeneralReg, AArch64FloatReg, ASM>,
+    {
+        // Only the SIMD registers have a popcount (CNT) before ARMv8.9, so count the bits in
+        // parallel instead: first within each pair of bits, then each nibble, then each byte,
+        // and finally add all the bytes up into the lowest one.
+        storage_manager.with_tmp_general_reg(buf, |storage_manager, buf, mask| {
+            storage_manager.with_tmp_general_reg(buf, |_storage_manager, buf, tmp| {
+                Self::mov_reg64_imm64(buf, mask, 0x5555_5555_5555_5555);
+                lsr_reg64_reg64_imm6(buf, tmp, src, 1);
+                and_reg64_reg64_reg64(buf, tmp, tmp, mask);
+                sub_reg64_reg64_reg64(buf, dst, src, tmp);
+
+                Self::mov_reg64_imm64(buf, mask, 0x3333_3333_3333_3333);
+                lsr_reg64_reg64_imm6(buf, tmp, dst, 2);
+                and_reg64_reg64_reg64(buf, tmp, tmp, mask);
+                and_reg64_reg64_reg64(buf, dst, dst, mask);
+                add_reg64_reg64_reg64(buf, dst, dst, tmp);
+
+                lsr_reg64_reg64_imm6(buf, tmp, dst, 4);
+                add_reg64_reg64_reg64(buf, dst, dst, tmp);
+                Self::mov_reg64_imm64(buf, mask, 0x0f0f_0f0f_0f0f_0f0f);
+                and_reg64_reg64_reg64(buf, dst, dst, mask);
+
+                for shift in [8, 16, 32] {
+                    lsr_reg64_reg64_imm6(buf, tmp, dst, shift);
+                    add_reg64_reg64_reg64(buf, dst, dst, tmp);
+                }
+                Self::mov_reg64_imm64(buf, mask, 0x7f);
+                and_reg64_reg64_reg64(buf, dst, dst, mask);
+            });
+        });
+    }
+
+    fn bswap_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+        rev_reg64_reg64(buf, dst, src);
+    }
+
+    fn shl_reg64_reg64_imm8(
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src: AArch64GeneralReg,
+        imm8: u8,
+    ) {
+        lsl_reg64_reg64_imm6(buf, dst, src, imm8);
+    }
+
+    fn shr_reg64_reg64_imm8(
+        buf: &mut Vec<'_, u8>,
+        dst: AArch64GeneralReg,
+        src: AArch64GeneralReg,
+        imm8: u8,
+    ) {
+        lsr_reg64_reg64_imm6(buf, dst, src, imm8);
+    }
+
+    fn rotl_reg64_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<'a, AArch64GeneralReg, AArch64FloatReg, ASM, CC>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
+    ) where
+        ASM: Assembler<AArch64GeneralReg, AArch64FloatReg>,
+        CC: CallConv<AArch64GeneralReg, AArch64FloatReg, ASM>,
+    {
+        // There is no rotate left, but rotating right by the negated amount is the same thing.
+        storage_manager.with_tmp_general_reg(buf, |_storage_manager, buf, tmp_reg| {
+            neg_reg64_reg64(buf, tmp_reg, src2);
+            ror_reg64_reg64_reg64(buf, dst, src1, tmp_reg);
+        });
+    }
+
+    fn rotr_reg64_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        _storage_manager: &mut StorageManager<'a, AArch64GeneralReg, AArch64FloatReg, ASM, CC>,
+        dst: AArch64GeneralReg,
+        src1: AArch64GeneralReg,
+        src2: AArch64GeneralReg,
+    ) where
+        ASM: Assembler<AArch64GeneralReg, AArch64FloatReg>,
+        CC: CallConv<AArch64GeneralReg, AArch64FloatReg, ASM>,
+    {
+        ror_reg64_reg64_reg64(buf, dst, src1, src2);
+    }
 }
 
 impl AArch64Assembler {}
@@ -1021,6 +1123,119 @@ impl ArithmeticShifted {
     }
 }
 
+#[derive(PackedStruct)]
+#[packed_struct(endian = "msb")]
+pub struct DataProcessingOneSource {
+    sf: bool,
+    fixed: bool, // = 0b1,
+    s: bool,
+    fixed2: Integer<u8, packed_bits::Bits<8>>, // = 0b11010110,
+    opcode2: Integer<u8, packed_bits::Bits<5>>,
+    opcode: Integer<u8, packed_bits::Bits<6>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
+
+impl Aarch64Bytes for DataProcessingOneSource {}
+
+impl DataProcessingOneSource {
+    #[inline(always)]
+    fn new(opcode: u8, rn: AArch64GeneralReg, rd: AArch64GeneralReg) -> Self {
+        debug_assert!(opcode <= 0b111111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            opcode: opcode.into(),
+            opcode2: 0b00000.into(),
+            fixed2: 0b11010110.into(),
+            s: false,
+            fixed: true,
+            // true for 64 bit operands
+            // false for 32 bit operands
+            sf: true,
+        }
+    }
+}
+
+#[derive(PackedStruct)]
+#[packed_struct(endian = "msb")]
+pub struct DataProcessingTwoSource {
+    sf: bool,
+    fixed: bool, // = 0b0,
+    s: bool,
+    fixed2: Integer<u8, packed_bits::Bits<8>>, // = 0b11010110,
+    reg_m: Integer<u8, packed_bits::Bits<5>>,
+    opcode: Integer<u8, packed_bits::Bits<6>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
+
+impl Aarch64Bytes for DataProcessingTwoSource {}
+
+impl DataProcessingTwoSource {
+    #[inline(always)]
+    fn new(
+        opcode: u8,
+        rm: AArch64GeneralReg,
+        rn: AArch64GeneralReg,
+        rd: AArch64GeneralReg,
+    ) -> Self {
+        debug_assert!(opcode <= 0b111111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            opcode: opcode.into(),
+            reg_m: rm.id().into(),
+            fixed2: 0b11010110.into(),
+            s: false,
+            fixed: false,
+            // true for 64 bit operands
+            // false for 32 bit operands
+            sf: true,
+        }
+    }
+}
+
+#[derive(PackedStruct)]
+#[packed_struct(endian = "msb")]
+pub struct BitfieldImmediate {
+    sf: bool,
+    opc: Integer<u8, packed_bits::Bits<2>>,
+    fixed: Integer<u8, packed_bits::Bits<6>>, // = 0b100110,
+    n: bool,
+    immr: Integer<u8, packed_bits::Bits<6>>,
+    imms: Integer<u8, packed_bits::Bits<6>>,
+    reg_n: Integer<u8, packed_bits::Bits<5>>,
+    reg_d: Integer<u8, packed_bits::Bits<5>>,
+}
+
+impl Aarch64Bytes for BitfieldImmediate {}
+
+impl BitfieldImmediate {
+    #[inline(always)]
+    fn new(opc: u8, immr: u8, imms: u8, rn: AArch64GeneralReg, rd: AArch64GeneralReg) -> Self {
+        debug_assert!(opc <= 0b11);
+        debug_assert!(immr <= 0b111111);
+        debug_assert!(imms <= 0b111111);
+
+        Self {
+            reg_d: rd.id().into(),
+            reg_n: rn.id().into(),
+            imms: imms.into(),
+            immr: immr.into(),
+            // must match sf
+            n: true,
+            fixed: 0b100110.into(),
+            opc: opc.into(),
+            // true for 64 bit operands
+            // false for 32 bit operands
+            sf: true,
+        }
+    }
+}
+
 #[derive(Debug)]
 #[allow(dead_code)]
 enum LogicalOp {
@@ -1204,6 +1419,27 @@ fn add_reg64_reg64_reg64(
     buf.extend(inst.bytes());
 }
 
+/// `AND Xd, Xn, Xm` -> Bitwise and Xn and Xm and place the result into Xd.
+#[inline(always)]
+fn and_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = LogicalShiftedRegister::new(LogicalOp::AND, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `CLZ Xd, Xn` -> Count the leading zero bits of Xn and place the result into Xd.
+#[inline(always)]
+fn clz_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+    let inst = DataProcessingOneSource::new(0b000100, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
 /// `LDR Xt, [Xn, #offset]` -> Load Xn + Offset Xt. ZRSP is SP.
 /// Note: imm12 is the offest divided by 8.
 #[inline(always)]
@@ -1218,6 +1454,36 @@ fn ldr_reg64_reg64_imm12(
     buf.extend(inst.bytes());
 }
 
+/// `LSL Xd, Xn, imm6` -> Logical shift Xn left by imm6 bits and place the result into Xd.
+#[inline(always)]
+fn lsl_reg64_reg64_imm6(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm6: u8,
+) {
+    debug_assert!(imm6 <= 0b111111);
+
+    // LSL is equivalent to `UBFM Xd, Xn, #(-imm6 MOD 64), #(63-imm6)` in AARCH64.
+    let inst = BitfieldImmediate::new(0b10, (64 - imm6) % 64, 63 - imm6, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `LSR Xd, Xn, imm6` -> Logical shift Xn right by imm6 bits and place the result into Xd.
+#[inline(always)]
+fn lsr_reg64_reg64_imm6(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm6: u8,
+) {
+    // LSR is equivalent to `UBFM Xd, Xn, #imm6, #63` in AARCH64.
+    let inst = BitfieldImmediate::new(0b10, imm6, 0b111111, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
 /// `MOV Xd, Xm` -> Move Xm to Xd.
 #[inline(always)]
 fn mov_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
@@ -1250,6 +1516,66 @@ fn movz_reg64_imm16(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, imm16: u16, h
     buf.extend(inst.bytes());
 }
 
+/// `NEG Xd, Xm` -> Negate Xm and place the result into Xd.
+#[inline(always)]
+fn neg_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+    // NEG is equivalent to `SUB Xd, XZR, Xm` in AARCH64.
+    let inst = ArithmeticShifted::new(
+        true,
+        false,
+        ShiftType::LSL,
+        0,
+        src,
+        AArch64GeneralReg::ZRSP,
+        dst,
+    );
+
+    buf.extend(inst.bytes());
+}
+
+/// `ORR Xd, Xn, Xm` -> Bitwise or Xn and Xm and place the result into Xd.
+#[inline(always)]
+fn orr_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = LogicalShiftedRegister::new(LogicalOp::ORR, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `RBIT Xd, Xn` -> Reverse the bit order of Xn and place the result into Xd.
+#[inline(always)]
+fn rbit_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+    let inst = DataProcessingOneSource::new(0b000000, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `REV Xd, Xn` -> Reverse the byte order of Xn and place the result into Xd.
+#[inline(always)]
+fn rev_reg64_reg64(buf: &mut Vec<'_, u8>, dst: AArch64GeneralReg, src: AArch64GeneralReg) {
+    let inst = DataProcessingOneSource::new(0b000011, src, dst);
+
+    buf.extend(inst.bytes());
+}
+
+/// `ROR Xd, Xn, Xm` -> Rotate Xn right by Xm bits and place the result into Xd.
+#[inline(always)]
+fn ror_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    // ROR is equivalent to `RORV Xd, Xn, Xm` in AARCH64.
+    let inst = DataProcessingTwoSource::new(0b001011, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
 /// `STR Xt, [Xn, #offset]` -> Store Xt to Xn + Offset. ZRSP is SP.
 /// Note: imm12 is the offest divided by 8.
 #[inline(always)]
@@ -1277,6 +1603,19 @@ fn sub_reg64_reg64_imm12(
     buf.extend(inst.bytes());
 }
 
+/// `SUB Xd, Xn, Xm` -> Subtract Xm from Xn and place the result into Xd.
+#[inline(always)]
+fn sub_reg64_reg64_reg64(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src1: AArch64GeneralReg,
+    src2: AArch64GeneralReg,
+) {
+    let inst = ArithmeticShifted::new(true, false, ShiftType::LSL, 0, src2, src1, dst);
+
+    buf.extend(inst.bytes());
+}
+
 /// `RET Xn` -> Return to the address stored in Xn.
 #[inline(always)]
 fn ret_reg64(buf: &mut Vec<'_, u8>, xn: AArch64GeneralReg) {
@@ -1399,6 +1738,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_and_reg64_reg64_reg64() {
+        disassembler_test!(
+            and_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "and {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_clz_reg64_reg64() {
+        disassembler_test!(
+            clz_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg| format!(
+                "clz {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
     #[test]
     fn test_ldr_reg64_reg64_imm12() {
         disassembler_test!(
@@ -1415,6 +1784,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lsl_reg64_reg64_imm6() {
+        disassembler_test!(
+            lsl_reg64_reg64_imm6,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "lsl {}, {}, #{}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [1, 2, 4, 8]
+        );
+    }
+
+    #[test]
+    fn test_lsr_reg64_reg64_imm6() {
+        disassembler_test!(
+            lsr_reg64_reg64_imm6,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "lsr {}, {}, #{}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [1, 2, 4, 8]
+        );
+    }
+
     #[test]
     fn test_mov_reg64_reg64() {
         disassembler_test!(
@@ -1465,6 +1866,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_neg_reg64_reg64() {
+        disassembler_test!(
+            neg_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg| format!(
+                "neg {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_orr_reg64_reg64_reg64() {
+        disassembler_test!(
+            orr_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| {
+                if reg2 == AArch64GeneralReg::ZRSP {
+                    // ORR with the zero register is how MOV is encoded
+                    format!(
+                        "mov {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                } else {
+                    format!(
+                        "orr {}, {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg2.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                }
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_rbit_reg64_reg64() {
+        disassembler_test!(
+            rbit_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg| format!(
+                "rbit {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_rev_reg64_reg64() {
+        disassembler_test!(
+            rev_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg| format!(
+                "rev {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_ror_reg64_reg64_reg64() {
+        disassembler_test!(
+            ror_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| format!(
+                "ror {}, {}, {}",
+                reg1.capstone_string(UsesZR),
+                reg2.capstone_string(UsesZR),
+                reg3.capstone_string(UsesZR)
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
     #[test]
     fn test_str_reg64_reg64_imm12() {
         disassembler_test!(
@@ -1497,6 +1983,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sub_reg64_reg64_reg64() {
+        disassembler_test!(
+            sub_reg64_reg64_reg64,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, reg3: AArch64GeneralReg| {
+                if reg2 == AArch64GeneralReg::ZRSP {
+                    // SUB from the zero register is how NEG is encoded
+                    format!(
+                        "neg {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                } else {
+                    format!(
+                        "sub {}, {}, {}",
+                        reg1.capstone_string(UsesZR),
+                        reg2.capstone_string(UsesZR),
+                        reg3.capstone_string(UsesZR)
+                    )
+                }
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
     #[test]
     fn test_ret_reg64() {
         disassembler_test!(
@@ -164,6 +164,39 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         src2: GeneralReg,
     );
 
+    fn clz_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+    fn ctz_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+    fn popcnt_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<'a, GeneralReg, FloatReg, ASM, CC>,
+        dst: GeneralReg,
+        src: GeneralReg,
+    ) where
+        ASM: Assembler<GeneralReg, FloatReg>,
+        CC: CallConv<GeneralReg, FloatReg, ASM>;
+    fn bswap_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+    fn shl_reg64_reg64_imm8(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg, imm8: u8);
+    fn shr_reg64_reg64_imm8(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg, imm8: u8);
+
+    fn rotl_reg64_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<'a, GeneralReg, FloatReg, ASM, CC>,
+        dst: GeneralReg,
+        src1: GeneralReg,
+        src2: GeneralReg,
+    ) where
+        ASM: Assembler<GeneralReg, FloatReg>,
+        CC: CallConv<GeneralReg, FloatReg, ASM>;
+    fn rotr_reg64_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<'a, GeneralReg, FloatReg, ASM, CC>,
+        dst: GeneralReg,
+        src1: GeneralReg,
+        src2: GeneralReg,
+    ) where
+        ASM: Assembler<GeneralReg, FloatReg>,
+        CC: CallConv<GeneralReg, FloatReg, ASM>;
+
     fn call(buf: &mut Vec<'_, u8>, relocs: &mut Vec<'_, Relocation>, fn_name: String);
 
     /// Jumps by an offset of offset bytes unconditionally.
@@ -1622,6 +1655,28 @@ impl<
         });
     }
 
+    fn build_crash(&mut self, message: &Symbol) {
+        // The Zig builtin makes a zero-terminated copy of the message for roc_panic
+        self.build_fn_call(
+            &Symbol::DEV_TMP,
+            bitcode::STR_CRASH.to_string(),
+            &[*message],
+            &[Layout::Builtin(Builtin::Str)],
+            &Layout::UNIT,
+        );
+        self.free_symbol(&Symbol::DEV_TMP);
+
+        // roc_panic must not return, but jump to the end in case the host's does,
+        // rather than falling through into whatever code comes next
+        let inst_loc = self.buf.len() as u64;
+        let offset = ASM::jmp_imm32(&mut self.buf, 0x1234_5678) as u64;
+        self.relocs.push(Relocation::JmpToReturn {
+            inst_loc,
+            inst_size: self.buf.len() as u64 - inst_loc,
+            offset,
+        });
+    }
+
     fn build_int_bitwise_and(
         &mut self,
         dst: &Symbol,
@@ -1681,6 +1736,139 @@ impl<
             }
         }
     }
+
+    fn build_int_rotate_left(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        int_width: IntWidth,
+    ) {
+        self.build_int_rotate(dst, src1, src2, int_width, true)
+    }
+
+    fn build_int_rotate_right(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        int_width: IntWidth,
+    ) {
+        self.build_int_rotate(dst, src1, src2, int_width, false)
+    }
+
+    fn build_int_count_leading_zero_bits(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        int_width: IntWidth,
+    ) {
+        match int_width {
+            IntWidth::U128 | IntWidth::I128 => self.build_int_count_fn_call(
+                dst,
+                bitcode::NUM_COUNT_LEADING_ZERO_BITS[int_width].to_string(),
+                src,
+                int_width,
+            ),
+            _ => {
+                let buf = &mut self.buf;
+                let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(buf, src);
+                let bit_width = 8 * int_width.stack_size() as u8;
+                if bit_width < 64 {
+                    // Zero-extended, the number has 64 - bit_width more leading zeros
+                    zero_extend::<GeneralReg, FloatReg, ASM>(buf, dst_reg, src_reg, bit_width);
+                    ASM::clz_reg64_reg64(buf, dst_reg, dst_reg);
+                    ASM::sub_reg64_reg64_imm32(buf, dst_reg, dst_reg, 64 - bit_width as i32);
+                } else {
+                    ASM::clz_reg64_reg64(buf, dst_reg, src_reg);
+                }
+            }
+        }
+    }
+
+    fn build_int_count_trailing_zero_bits(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        int_width: IntWidth,
+    ) {
+        match int_width {
+            IntWidth::U128 | IntWidth::I128 => self.build_int_count_fn_call(
+                dst,
+                bitcode::NUM_COUNT_TRAILING_ZERO_BITS[int_width].to_string(),
+                src,
+                int_width,
+            ),
+            _ => {
+                let buf = &mut self.buf;
+                let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(buf, src);
+                let bit_width = 8 * int_width.stack_size() as u8;
+                if bit_width < 64 {
+                    // Set the bit just past the top, so that zero counts bit_width zeros
+                    // and nothing above the number is ever counted.
+                    self.storage_manager
+                        .with_tmp_general_reg(buf, |_storage_manager, buf, reg| {
+                            ASM::mov_reg64_imm64(buf, reg, 1 << bit_width);
+                            ASM::or_reg64_reg64_reg64(buf, dst_reg, src_reg, reg);
+                        });
+                    ASM::ctz_reg64_reg64(buf, dst_reg, dst_reg);
+                } else {
+                    ASM::ctz_reg64_reg64(buf, dst_reg, src_reg);
+                }
+            }
+        }
+    }
+
+    fn build_int_count_one_bits(&mut self, dst: &Symbol, src: &Symbol, int_width: IntWidth) {
+        match int_width {
+            IntWidth::U128 | IntWidth::I128 => self.build_int_count_fn_call(
+                dst,
+                bitcode::NUM_COUNT_ONE_BITS[int_width].to_string(),
+                src,
+                int_width,
+            ),
+            _ => {
+                let buf = &mut self.buf;
+                let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(buf, src);
+                let bit_width = 8 * int_width.stack_size() as u8;
+                if bit_width < 64 {
+                    zero_extend::<GeneralReg, FloatReg, ASM>(buf, dst_reg, src_reg, bit_width);
+                    ASM::popcnt_reg64_reg64(buf, &mut self.storage_manager, dst_reg, dst_reg);
+                } else {
+                    ASM::popcnt_reg64_reg64(buf, &mut self.storage_manager, dst_reg, src_reg);
+                }
+            }
+        }
+    }
+
+    fn build_int_swap_bytes(&mut self, dst: &Symbol, src: &Symbol, int_width: IntWidth) {
+        match int_width {
+            IntWidth::U128 | IntWidth::I128 => {
+                let layout = Layout::Builtin(Builtin::Int(int_width));
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_SWAP_BYTES[int_width].to_string(),
+                    &[*src],
+                    &[layout],
+                    &layout,
+                )
+            }
+            _ => {
+                let buf = &mut self.buf;
+                let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(buf, src);
+                let bit_width = 8 * int_width.stack_size() as u8;
+                ASM::bswap_reg64_reg64(buf, dst_reg, src_reg);
+                if bit_width < 64 {
+                    // The swapped bytes end up at the top of the register
+                    ASM::shr_reg64_reg64_imm8(buf, dst_reg, dst_reg, 64 - bit_width);
+                }
+            }
+        }
+    }
 }
 
 /// This impl block is for ir related instructions that need backend specific information.
@@ -1708,6 +1896,95 @@ impl<
             self.buf[jmp_location as usize + i] = *byte;
         }
     }
+
+    /// Rotates `src1` by `src2` bits. Numbers narrower than a register are first repeated
+    /// across all 64 bits, so that a 64-bit rotation moves the right bits into the bottom.
+    fn build_int_rotate(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        int_width: IntWidth,
+        is_left: bool,
+    ) {
+        if let IntWidth::U128 | IntWidth::I128 = int_width {
+            let layout = Layout::Builtin(Builtin::Int(int_width));
+            let fn_name = if is_left {
+                &bitcode::NUM_ROTATE_LEFT_BY[int_width]
+            } else {
+                &bitcode::NUM_ROTATE_RIGHT_BY[int_width]
+            };
+            self.build_fn_call(
+                dst,
+                fn_name.to_string(),
+                &[*src1, *src2],
+                &[layout, layout],
+                &layout,
+            );
+            return;
+        }
+
+        let buf = &mut self.buf;
+        let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
+        let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
+        let src2_reg = self.storage_manager.load_to_general_reg(buf, src2);
+        let bit_width = 8 * int_width.stack_size() as u8;
+
+        let num_reg = if bit_width < 64 {
+            zero_extend::<GeneralReg, FloatReg, ASM>(buf, dst_reg, src1_reg, bit_width);
+            self.storage_manager
+                .with_tmp_general_reg(buf, |_storage_manager, buf, reg| {
+                    let mut copied_bits = bit_width;
+                    while copied_bits < 64 {
+                        ASM::shl_reg64_reg64_imm8(buf, reg, dst_reg, copied_bits);
+                        ASM::or_reg64_reg64_reg64(buf, dst_reg, dst_reg, reg);
+                        copied_bits *= 2;
+                    }
+                });
+            dst_reg
+        } else {
+            src1_reg
+        };
+
+        if is_left {
+            ASM::rotl_reg64_reg64_reg64(buf, &mut self.storage_manager, dst_reg, num_reg, src2_reg);
+        } else {
+            ASM::rotr_reg64_reg64_reg64(buf, &mut self.storage_manager, dst_reg, num_reg, src2_reg);
+        }
+
+        if bit_width < 64 {
+            zero_extend::<GeneralReg, FloatReg, ASM>(buf, dst_reg, dst_reg, bit_width);
+        }
+    }
+
+    /// Counts bits in a 128-bit integer with a zig builtin. The count is always a U8.
+    fn build_int_count_fn_call(
+        &mut self,
+        dst: &Symbol,
+        fn_name: String,
+        src: &Symbol,
+        int_width: IntWidth,
+    ) {
+        self.build_fn_call(
+            dst,
+            fn_name,
+            &[*src],
+            &[Layout::Builtin(Builtin::Int(int_width))],
+            &Layout::Builtin(Builtin::Int(IntWidth::U8)),
+        )
+    }
+}
+
+/// Puts the low `bit_width` bits of `src` into `dst` and clears the rest,
+/// for operations that only work on whole 64-bit registers.
+fn zero_extend<GeneralReg: RegTrait, FloatReg: RegTrait, ASM: Assembler<GeneralReg, FloatReg>>(
+    buf: &mut Vec<'_, u8>,
+    dst: GeneralReg,
+    src: GeneralReg,
+    bit_width: u8,
+) {
+    ASM::shl_reg64_reg64_imm8(buf, dst, src, 64 - bit_width);
+    ASM::shr_reg64_reg64_imm8(buf, dst, dst, 64 - bit_width);
 }
 
 #[macro_export]
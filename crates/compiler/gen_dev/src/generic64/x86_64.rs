@@ -252,7 +252,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
 
     #[inline(always)]
     fn load_args<'a>(
-        _buf: &mut Vec<'a, u8>,
+        buf: &mut Vec<'a, u8>,
         storage_manager: &mut StorageManager<
             'a,
             X86_64GeneralReg,
@@ -299,8 +299,24 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
                     storage_manager.complex_stack_arg(sym, arg_offset, stack_size);
                     arg_offset += stack_size as i32;
                 }
-                x => {
-                    todo!("Loading args with layout {:?}", x);
+                _ => {
+                    // Small structs are passed in general registers, eight bytes per register,
+                    // unless there aren't enough of them left.
+                    let reg_count = (stack_size as usize + 7) / 8;
+                    if general_i + reg_count <= Self::GENERAL_PARAM_REGS.len() {
+                        let base_offset = storage_manager.claim_stack_area(sym, stack_size);
+                        for i in 0..reg_count {
+                            X86_64Assembler::mov_base32_reg64(
+                                buf,
+                                base_offset + 8 * i as i32,
+                                Self::GENERAL_PARAM_REGS[general_i + i],
+                            );
+                        }
+                        general_i += reg_count;
+                    } else {
+                        storage_manager.complex_stack_arg(sym, arg_offset, stack_size);
+                        arg_offset += 8 * reg_count as i32;
+                    }
                 }
             }
         }
@@ -409,8 +425,37 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
                     }
                     tmp_stack_offset += size as i32;
                 }
-                x => {
-                    todo!("calling with arg type, {:?}", x);
+                _ => {
+                    // Small structs are passed in general registers, eight bytes per register,
+                    // unless there aren't enough of them left.
+                    let (base_offset, size) = storage_manager.stack_offset_and_size(sym);
+                    debug_assert_eq!(base_offset % 8, 0);
+                    let reg_count = (size as usize + 7) / 8;
+                    if general_i + reg_count <= Self::GENERAL_PARAM_REGS.len() {
+                        for i in 0..reg_count {
+                            X86_64Assembler::mov_reg64_base32(
+                                buf,
+                                Self::GENERAL_PARAM_REGS[general_i + i],
+                                base_offset + 8 * i as i32,
+                            );
+                        }
+                        general_i += reg_count;
+                    } else {
+                        // Copy onto the stack, using the return reg as buffer.
+                        for i in 0..reg_count as i32 {
+                            X86_64Assembler::mov_reg64_base32(
+                                buf,
+                                Self::GENERAL_RETURN_REGS[0],
+                                base_offset + 8 * i,
+                            );
+                            X86_64Assembler::mov_stack32_reg64(
+                                buf,
+                                tmp_stack_offset + 8 * i,
+                                Self::GENERAL_RETURN_REGS[0],
+                            );
+                        }
+                        tmp_stack_offset += 8 * reg_count as i32;
+                    }
                 }
             }
         }
@@ -1415,6 +1460,81 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
     fn xor_reg64_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src1: Reg64, src2: Reg64) {
         binop_move_src_to_dst_reg64(buf, xor_reg64_reg64, dst, src1, src2)
     }
+
+    fn clz_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src: Reg64) {
+        lzcnt_reg64_reg64(buf, dst, src)
+    }
+
+    fn ctz_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src: Reg64) {
+        tzcnt_reg64_reg64(buf, dst, src)
+    }
+
+    fn popcnt_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        _storage_manager: &mut StorageManager<'a, X86_64GeneralReg, X86_64FloatReg, ASM, CC>,
+        dst: X86_64GeneralReg,
+        src: X86_64GeneralReg,
+    ) where
+        ASM: Assembler<X86_64GeneralReg, X86_64FloatReg>,
+        CC: CallConv<X86_64GeneralReg, X86_64FloatReg, ASM>,
+    {
+        popcnt_reg64_reg64(buf, dst, src)
+    }
+
+    fn bswap_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src: Reg64) {
+        mov_reg64_reg64(buf, dst, src);
+        bswap_reg64(buf, dst);
+    }
+
+    fn shl_reg64_reg64_imm8(buf: &mut Vec<'_, u8>, dst: Reg64, src: Reg64, imm8: u8) {
+        mov_reg64_reg64(buf, dst, src);
+        shl_reg64_imm8(buf, dst, imm8);
+    }
+
+    fn shr_reg64_reg64_imm8(buf: &mut Vec<'_, u8>, dst: Reg64, src: Reg64, imm8: u8) {
+        mov_reg64_reg64(buf, dst, src);
+        shr_reg64_imm8(buf, dst, imm8);
+    }
+
+    fn rotl_reg64_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<'a, X86_64GeneralReg, X86_64FloatReg, ASM, CC>,
+        dst: X86_64GeneralReg,
+        src1: X86_64GeneralReg,
+        src2: X86_64GeneralReg,
+    ) where
+        ASM: Assembler<X86_64GeneralReg, X86_64FloatReg>,
+        CC: CallConv<X86_64GeneralReg, X86_64FloatReg, ASM>,
+    {
+        use crate::generic64::RegStorage;
+
+        // The rotation amount must be in CL
+        storage_manager.ensure_reg_free(buf, RegStorage::General(X86_64GeneralReg::RCX));
+
+        mov_reg64_reg64(buf, dst, src1);
+        mov_reg64_reg64(buf, X86_64GeneralReg::RCX, src2);
+        rol_reg64_cl(buf, dst);
+    }
+
+    fn rotr_reg64_reg64_reg64<'a, ASM, CC>(
+        buf: &mut Vec<'a, u8>,
+        storage_manager: &mut StorageManager<'a, X86_64GeneralReg, X86_64FloatReg, ASM, CC>,
+        dst: X86_64GeneralReg,
+        src1: X86_64GeneralReg,
+        src2: X86_64GeneralReg,
+    ) where
+        ASM: Assembler<X86_64GeneralReg, X86_64FloatReg>,
+        CC: CallConv<X86_64GeneralReg, X86_64FloatReg, ASM>,
+    {
+        use crate::generic64::RegStorage;
+
+        // The rotation amount must be in CL
+        storage_manager.ensure_reg_free(buf, RegStorage::General(X86_64GeneralReg::RCX));
+
+        mov_reg64_reg64(buf, dst, src1);
+        mov_reg64_reg64(buf, X86_64GeneralReg::RCX, src2);
+        ror_reg64_cl(buf, dst);
+    }
 }
 
 impl X86_64Assembler {
@@ -2050,6 +2170,73 @@ fn neg_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
     buf.extend(&[rex, 0xF7, 0xD8 | reg_mod]);
 }
 
+/// `BSWAP r64` -> Reverses the byte order of r64.
+#[inline(always)]
+fn bswap_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
+    let rex = add_opcode_extension(reg, REX_W);
+    let reg_mod = reg as u8 % 8;
+    buf.extend(&[rex, 0x0F, 0xC8 | reg_mod]);
+}
+
+/// `LZCNT r64,r/m64` -> Count the number of leading zero bits of r/m64, return result in r64.
+#[inline(always)]
+fn lzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+    buf.reserve(5);
+    buf.push(0xF3);
+    // NOTE: src and dst are flipped by design
+    extended_binop_reg64_reg64(0x0F, 0xBD, buf, src, dst);
+}
+
+/// `POPCNT r64,r/m64` -> Count the number of bits set to 1 in r/m64, return result in r64.
+#[inline(always)]
+fn popcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+    buf.reserve(5);
+    buf.push(0xF3);
+    // NOTE: src and dst are flipped by design
+    extended_binop_reg64_reg64(0x0F, 0xB8, buf, src, dst);
+}
+
+/// `ROL r/m64,CL` -> Rotate 64 bits r/m64 left CL times.
+#[inline(always)]
+fn rol_reg64_cl(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
+    let rex = add_rm_extension(reg, REX_W);
+    let reg_mod = reg as u8 % 8;
+    buf.extend(&[rex, 0xD3, 0xC0 | reg_mod]);
+}
+
+/// `ROR r/m64,CL` -> Rotate 64 bits r/m64 right CL times.
+#[inline(always)]
+fn ror_reg64_cl(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
+    let rex = add_rm_extension(reg, REX_W);
+    let reg_mod = reg as u8 % 8;
+    buf.extend(&[rex, 0xD3, 0xC8 | reg_mod]);
+}
+
+/// `SHL r/m64,imm8` -> Shift r/m64 left imm8 times.
+#[inline(always)]
+fn shl_reg64_imm8(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg, imm8: u8) {
+    let rex = add_rm_extension(reg, REX_W);
+    let reg_mod = reg as u8 % 8;
+    buf.extend(&[rex, 0xC1, 0xE0 | reg_mod, imm8]);
+}
+
+/// `SHR r/m64,imm8` -> Unsigned shift r/m64 right imm8 times.
+#[inline(always)]
+fn shr_reg64_imm8(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg, imm8: u8) {
+    let rex = add_rm_extension(reg, REX_W);
+    let reg_mod = reg as u8 % 8;
+    buf.extend(&[rex, 0xC1, 0xE8 | reg_mod, imm8]);
+}
+
+/// `TZCNT r64,r/m64` -> Count the number of trailing zero bits of r/m64, return result in r64.
+#[inline(always)]
+fn tzcnt_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+    buf.reserve(5);
+    buf.push(0xF3);
+    // NOTE: src and dst are flipped by design
+    extended_binop_reg64_reg64(0x0F, 0xBC, buf, src, dst);
+}
+
 // helper function for `set*` instructions
 #[inline(always)]
 fn set_reg64_help(op_code: u8, buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
@@ -2645,6 +2832,83 @@ mod tests {
         disassembler_test!(neg_reg64, |reg| format!("neg {}", reg), ALL_GENERAL_REGS);
     }
 
+    #[test]
+    fn test_bswap_reg64() {
+        disassembler_test!(
+            bswap_reg64,
+            |reg| format!("bswap {}", reg),
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_lzcnt_reg64_reg64() {
+        disassembler_test!(
+            lzcnt_reg64_reg64,
+            |reg1, reg2| format!("lzcnt {}, {}", reg1, reg2),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_popcnt_reg64_reg64() {
+        disassembler_test!(
+            popcnt_reg64_reg64,
+            |reg1, reg2| format!("popcnt {}, {}", reg1, reg2),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_rol_reg64_cl() {
+        disassembler_test!(
+            rol_reg64_cl,
+            |reg| format!("rol {}, cl", reg),
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_ror_reg64_cl() {
+        disassembler_test!(
+            ror_reg64_cl,
+            |reg| format!("ror {}, cl", reg),
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_shl_reg64_imm8() {
+        disassembler_test!(
+            shl_reg64_imm8,
+            |reg, imm| format!("shl {}, {}", reg, imm),
+            ALL_GENERAL_REGS,
+            [1, 2, 4, 8]
+        );
+    }
+
+    #[test]
+    fn test_shr_reg64_imm8() {
+        disassembler_test!(
+            shr_reg64_imm8,
+            |reg, imm| format!("shr {}, {}", reg, imm),
+            ALL_GENERAL_REGS,
+            [1, 2, 4, 8]
+        );
+    }
+
+    #[test]
+    fn test_tzcnt_reg64_reg64() {
+        disassembler_test!(
+            tzcnt_reg64_reg64,
+            |reg1, reg2| format!("tzcnt {}, {}", reg1, reg2),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS
+        );
+    }
+
     #[test]
     fn test_cvtsi2_help() {
         const CVTSI2SS_CODE: u8 = 0x2A;
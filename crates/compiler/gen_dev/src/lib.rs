@@ -209,6 +209,15 @@ trait Backend<'a> {
                 self.build_jump(id, args, arg_layouts.into_bump_slice(), ret_layout);
                 self.free_symbols(stmt);
             }
+            Stmt::Dbg { remainder, .. } => {
+                // `dbg` needs a host that can render values, so it is skipped on this backend
+                self.build_stmt(remainder, ret_layout)
+            }
+            Stmt::Crash { message, .. } => {
+                self.load_literal_symbols(&[*message]);
+                self.build_crash(message);
+                self.free_symbols(stmt);
+            }
             x => todo!("the statement, {:?}", x),
         }
     }
@@ -432,6 +441,97 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::NumAtan2 => {
+                let fn_name = match arg_layouts[0] {
+                    Layout::Builtin(Builtin::Decimal) => bitcode::DEC_ATAN2.to_string(),
+                    _ => bitcode::NUM_ATAN2[FloatWidth::F64].to_string(),
+                };
+                self.build_fn_call(sym, fn_name, args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumSinh => self.build_fn_call(
+                sym,
+                bitcode::NUM_SINH[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumCosh => self.build_fn_call(
+                sym,
+                bitcode::NUM_COSH[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumTanh => self.build_fn_call(
+                sym,
+                bitcode::NUM_TANH[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumExp => self.build_fn_call(
+                sym,
+                bitcode::NUM_EXP[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumExp2 => self.build_fn_call(
+                sym,
+                bitcode::NUM_EXP2[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumLog2 => self.build_fn_call(
+                sym,
+                bitcode::NUM_LOG2[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumLog10 => self.build_fn_call(
+                sym,
+                bitcode::NUM_LOG10[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumHypot => {
+                let fn_name = match arg_layouts[0] {
+                    Layout::Builtin(Builtin::Decimal) => bitcode::DEC_HYPOT.to_string(),
+                    _ => bitcode::NUM_HYPOT[FloatWidth::F64].to_string(),
+                };
+                self.build_fn_call(sym, fn_name, args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumFma => self.build_fn_call(
+                sym,
+                bitcode::NUM_FMA[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumCopySign => {
+                let fn_name = match arg_layouts[0] {
+                    Layout::Builtin(Builtin::Decimal) => bitcode::DEC_COPY_SIGN.to_string(),
+                    _ => bitcode::NUM_COPY_SIGN[FloatWidth::F64].to_string(),
+                };
+                self.build_fn_call(sym, fn_name, args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumIsNan => self.build_fn_call(
+                sym,
+                bitcode::NUM_IS_NAN[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumIsInfinite => self.build_fn_call(
+                sym,
+                bitcode::NUM_IS_INFINITE[FloatWidth::F64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::NumMul => {
                 debug_assert_eq!(
                     2,
@@ -520,6 +620,48 @@ trait Backend<'a> {
                     internal_error!("bitwise xor on a non-integer")
                 }
             }
+            LowLevel::NumRotateLeftBy => {
+                if let Layout::Builtin(Builtin::Int(int_width)) = ret_layout {
+                    self.build_int_rotate_left(sym, &args[0], &args[1], *int_width)
+                } else {
+                    internal_error!("rotate left on a non-integer")
+                }
+            }
+            LowLevel::NumRotateRightBy => {
+                if let Layout::Builtin(Builtin::Int(int_width)) = ret_layout {
+                    self.build_int_rotate_right(sym, &args[0], &args[1], *int_width)
+                } else {
+                    internal_error!("rotate right on a non-integer")
+                }
+            }
+            LowLevel::NumCountLeadingZeroBits => {
+                if let Layout::Builtin(Builtin::Int(int_width)) = arg_layouts[0] {
+                    self.build_int_count_leading_zero_bits(sym, &args[0], int_width)
+                } else {
+                    internal_error!("count leading zero bits on a non-integer")
+                }
+            }
+            LowLevel::NumCountTrailingZeroBits => {
+                if let Layout::Builtin(Builtin::Int(int_width)) = arg_layouts[0] {
+                    self.build_int_count_trailing_zero_bits(sym, &args[0], int_width)
+                } else {
+                    internal_error!("count trailing zero bits on a non-integer")
+                }
+            }
+            LowLevel::NumCountOneBits => {
+                if let Layout::Builtin(Builtin::Int(int_width)) = arg_layouts[0] {
+                    self.build_int_count_one_bits(sym, &args[0], int_width)
+                } else {
+                    internal_error!("count one bits on a non-integer")
+                }
+            }
+            LowLevel::NumSwapBytes => {
+                if let Layout::Builtin(Builtin::Int(int_width)) = ret_layout {
+                    self.build_int_swap_bytes(sym, &args[0], *int_width)
+                } else {
+                    internal_error!("swap bytes on a non-integer")
+                }
+            }
             LowLevel::Eq => {
                 debug_assert_eq!(2, args.len(), "Eq: expected to have exactly two argument");
                 debug_assert_eq!(
@@ -624,6 +766,13 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::NumTrunc => self.build_fn_call(
+                sym,
+                bitcode::NUM_TRUNC_F64[IntWidth::I64].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::ListLen => {
                 debug_assert_eq!(
                     1,
@@ -655,6 +804,48 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::StrGraphemes => self.build_fn_call(
+                sym,
+                bitcode::STR_GRAPHEMES.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrGraphemeByteOffset => self.build_fn_call(
+                sym,
+                bitcode::STR_GRAPHEME_BYTE_OFFSET.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToUpper => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_UPPER.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToLower => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_LOWER.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToNfc => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_NFC.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToNfd => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_NFD.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::PtrCast => {
                 debug_assert_eq!(
                     1,
@@ -677,6 +868,54 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::Hash => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "Hash: expected to have exactly two arguments"
+                );
+                debug_assert_eq!(
+                    Layout::u64(),
+                    *ret_layout,
+                    "Hash: expected to have return layout of type U64"
+                );
+                let zig_fn_name = match arg_layouts[0] {
+                    Layout::Builtin(Builtin::Int(int_width)) => {
+                        Some(bitcode::DICT_HASH_INT[int_width].to_string())
+                    }
+                    Layout::Builtin(Builtin::Float(float_width)) => {
+                        Some(bitcode::DICT_HASH_FLOAT[float_width].to_string())
+                    }
+                    Layout::Builtin(Builtin::Decimal) => {
+                        Some(bitcode::DICT_HASH_INT[IntWidth::I128].to_string())
+                    }
+                    Layout::Builtin(Builtin::Bool) => {
+                        Some(bitcode::DICT_HASH_INT[IntWidth::U8].to_string())
+                    }
+                    Layout::Builtin(Builtin::Str) => Some(bitcode::DICT_HASH_STR.to_string()),
+                    _ => None,
+                };
+                match zig_fn_name {
+                    Some(fn_name) => {
+                        self.build_fn_call(sym, fn_name, args, arg_layouts, ret_layout)
+                    }
+                    None => {
+                        let (hash_expr, new_specializations) = {
+                            let (env, interns, helper_proc_gen) = self.env_interns_helpers_mut();
+                            let module_id = env.module_id;
+                            let ident_ids = interns.all_ident_ids.get_mut(&module_id).unwrap();
+
+                            helper_proc_gen.call_specialized_hash(ident_ids, &arg_layouts[0], args)
+                        };
+
+                        for spec in new_specializations.into_iter() {
+                            self.helper_proc_symbols_mut().push(spec);
+                        }
+
+                        self.build_expr(sym, &hash_expr, ret_layout)
+                    }
+                }
+            }
             x => todo!("low level, {:?}", x),
         }
     }
@@ -808,6 +1047,46 @@ trait Backend<'a> {
         int_width: IntWidth,
     );
 
+    /// stores `src1` rotated left by `src2` bits into dst.
+    fn build_int_rotate_left(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        int_width: IntWidth,
+    );
+
+    /// stores `src1` rotated right by `src2` bits into dst.
+    fn build_int_rotate_right(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        int_width: IntWidth,
+    );
+
+    /// stores the number of leading zero bits in src into dst.
+    fn build_int_count_leading_zero_bits(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        int_width: IntWidth,
+    );
+
+    /// stores the number of trailing zero bits in src into dst.
+    fn build_int_count_trailing_zero_bits(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        int_width: IntWidth,
+    );
+
+    /// stores the number of one bits in src into dst.
+    fn build_int_count_one_bits(&mut self, dst: &Symbol, src: &Symbol, int_width: IntWidth);
+
+    /// stores src with its byte order reversed into dst.
+    fn build_int_swap_bytes(&mut self, dst: &Symbol, src: &Symbol, int_width: IntWidth);
+
     /// build_eq stores the result of `src1 == src2` into dst.
     fn build_eq(&mut self, dst: &Symbol, src1: &Symbol, src2: &Symbol, arg_layout: &Layout<'a>);
 
@@ -936,6 +1215,9 @@ trait Backend<'a> {
     /// return_symbol moves a symbol to the correct return location for the backend and adds a jump to the end of the function.
     fn return_symbol(&mut self, sym: &Symbol, layout: &Layout<'a>);
 
+    /// build_crash passes the message of a `crash` to roc_panic with the UserCrash tag and adds a jump to the end of the function.
+    fn build_crash(&mut self, message: &Symbol);
+
     /// free_symbols will free all symbols for the given statement.
     fn free_symbols(&mut self, stmt: &Stmt<'a>) {
         if let Some(syms) = self.free_map().remove(&(stmt as *const Stmt<'a>)) {
@@ -1107,6 +1389,12 @@ trait Backend<'a> {
             Stmt::Expect { .. } => todo!("expect is not implemented in the dev backend"),
             Stmt::ExpectFx { .. } => todo!("expect-fx is not implemented in the dev backend"),
 
+            Stmt::Dbg { remainder, .. } => self.scan_ast(remainder),
+
+            Stmt::Crash { message, .. } => {
+                self.set_last_seen(*message, stmt);
+            }
+
             Stmt::RuntimeError(_) => {}
         }
     }
@@ -2,17 +2,18 @@ use crate::llvm::bitcode::{
     call_bitcode_fn, call_bitcode_fn_fixing_for_convention, call_list_bitcode_fn,
     call_str_bitcode_fn, call_void_bitcode_fn, pass_list_or_string_to_zig_32bit, BitcodeReturns,
 };
+use crate::llvm::build_hash::generic_hash;
 use crate::llvm::build_list::{
-    self, allocate_list, empty_polymorphic_list, list_append_unsafe, list_capacity, list_concat,
-    list_drop_at, list_get_unsafe, list_len, list_map, list_map2, list_map3, list_map4,
-    list_prepend, list_replace_unsafe, list_reserve, list_sort_with, list_sublist, list_swap,
+    self, allocate_list, empty_polymorphic_list, list_append_unsafe, list_concat, list_drop_at,
+    list_get_unsafe, list_len, list_map, list_map2, list_map3, list_map4, list_prepend,
+    list_replace_unsafe, list_reserve, list_sort_with, list_sublist, list_swap,
     list_symbol_to_c_abi, list_with_capacity, pass_update_mode,
 };
 use crate::llvm::compare::{generic_eq, generic_neq};
 use crate::llvm::convert::{
     self, argument_type_from_layout, basic_type_from_builtin, basic_type_from_layout, zig_str_type,
 };
-use crate::llvm::expect::clone_to_shared_memory;
+use crate::llvm::expect::{clone_to_shared_memory, send_dbg_to_host, write_crash_location};
 use crate::llvm::refcounting::{
     build_reset, decrement_refcount_layout, increment_refcount_layout, PointerToRefcount,
 };
@@ -163,6 +164,8 @@ impl<'a, 'ctx> Scope<'a, 'ctx> {
 pub enum LlvmBackendMode {
     /// Assumes primitives (roc_alloc, roc_panic, etc) are provided by the host
     Binary,
+    /// Like `Binary`, but keeps `dbg`, which reports values to the host through `roc_dbg`
+    BinaryDev,
     /// Creates a test wrapper around the main roc function to catch and report panics.
     /// Provides a testing implementation of primitives (roc_alloc, roc_panic, etc)
     GenTest,
@@ -174,6 +177,7 @@ impl LlvmBackendMode {
     pub(crate) fn has_host(self) -> bool {
         match self {
             LlvmBackendMode::Binary => true,
+            LlvmBackendMode::BinaryDev => true,
             LlvmBackendMode::GenTest => false,
             LlvmBackendMode::WasmGenTest => true,
             LlvmBackendMode::CliTest => false,
@@ -184,6 +188,7 @@ impl LlvmBackendMode {
     fn returns_roc_result(self) -> bool {
         match self {
             LlvmBackendMode::Binary => false,
+            LlvmBackendMode::BinaryDev => false,
             LlvmBackendMode::GenTest => true,
             LlvmBackendMode::WasmGenTest => true,
             LlvmBackendMode::CliTest => true,
@@ -193,11 +198,23 @@ impl LlvmBackendMode {
     fn runs_expects(self) -> bool {
         match self {
             LlvmBackendMode::Binary => false,
+            LlvmBackendMode::BinaryDev => false,
             LlvmBackendMode::GenTest => false,
             LlvmBackendMode::WasmGenTest => false,
             LlvmBackendMode::CliTest => true,
         }
     }
+
+    fn runs_dbgs(self) -> bool {
+        match self {
+            LlvmBackendMode::Binary => false,
+            LlvmBackendMode::BinaryDev => true,
+            LlvmBackendMode::GenTest => false,
+            LlvmBackendMode::WasmGenTest => false,
+            // expect failures are rendered from the shared buffer; `dbg` frames would get in the way
+            LlvmBackendMode::CliTest => false,
+        }
+    }
 }
 
 pub struct Env<'a, 'ctx, 'env> {
@@ -215,8 +232,12 @@ pub struct Env<'a, 'ctx, 'env> {
 }
 
 #[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PanicTagId {
+    /// An error inserted by the compiler, e.g. for code that failed to compile
     NullTerminatedString = 0,
+    /// A `crash` in user code; the message is also a null-terminated string
+    UserCrash = 1,
 }
 
 impl std::convert::TryFrom<u32> for PanicTagId {
@@ -225,6 +246,7 @@ impl std::convert::TryFrom<u32> for PanicTagId {
     fn try_from(value: u32) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(PanicTagId::NullTerminatedString),
+            1 => Ok(PanicTagId::UserCrash),
             _ => Err(()),
         }
     }
@@ -612,6 +634,16 @@ fn add_intrinsics<'ctx>(ctx: &'ctx Context, module: &Module<'ctx>) {
         t.fn_type(&[t.into()], false)
     });
     add_float_intrinsic(ctx, module, &LLVM_FLOOR, |t| t.fn_type(&[t.into()], false));
+    add_float_intrinsic(ctx, module, &LLVM_EXP, |t| t.fn_type(&[t.into()], false));
+    add_float_intrinsic(ctx, module, &LLVM_EXP2, |t| t.fn_type(&[t.into()], false));
+    add_float_intrinsic(ctx, module, &LLVM_LOG2, |t| t.fn_type(&[t.into()], false));
+    add_float_intrinsic(ctx, module, &LLVM_LOG10, |t| t.fn_type(&[t.into()], false));
+    add_float_intrinsic(ctx, module, &LLVM_COPYSIGN, |t| {
+        t.fn_type(&[t.into(), t.into()], false)
+    });
+    add_float_intrinsic(ctx, module, &LLVM_FMA, |t| {
+        t.fn_type(&[t.into(), t.into(), t.into()], false)
+    });
 
     add_int_intrinsic(ctx, module, &LLVM_ADD_WITH_OVERFLOW, |t| {
         let fields = [t.into(), i1_type.into()];
@@ -638,12 +670,53 @@ fn add_intrinsics<'ctx>(ctx: &'ctx Context, module: &Module<'ctx>) {
     add_int_intrinsic(ctx, module, &LLVM_SUB_SATURATED, |t| {
         t.fn_type(&[t.into(), t.into()], false)
     });
+
+    add_int_intrinsic(ctx, module, &LLVM_COUNT_LEADING_ZEROS, |t| {
+        t.fn_type(&[t.into(), i1_type.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_COUNT_TRAILING_ZEROS, |t| {
+        t.fn_type(&[t.into(), i1_type.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_COUNT_ONES, |t| {
+        t.fn_type(&[t.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_FUNNEL_SHIFT_LEFT, |t| {
+        t.fn_type(&[t.into(), t.into(), t.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_FUNNEL_SHIFT_RIGHT, |t| {
+        t.fn_type(&[t.into(), t.into(), t.into()], false)
+    });
+
+    // llvm.bswap needs an even number of bytes, so there is no i8 version
+    for (int_width, int_type) in [
+        (IntWidth::U16, ctx.i16_type()),
+        (IntWidth::U32, ctx.i32_type()),
+        (IntWidth::U64, ctx.i64_type()),
+        (IntWidth::U128, ctx.i128_type()),
+    ] {
+        add_intrinsic(
+            ctx,
+            module,
+            &LLVM_SWAP_BYTES[int_width],
+            int_type.fn_type(&[int_type.into()], false),
+        );
+    }
 }
 
 const LLVM_POW: IntrinsicName = float_intrinsic!("llvm.pow");
 const LLVM_FABS: IntrinsicName = float_intrinsic!("llvm.fabs");
 static LLVM_SQRT: IntrinsicName = float_intrinsic!("llvm.sqrt");
 static LLVM_LOG: IntrinsicName = float_intrinsic!("llvm.log");
+static LLVM_LOG2: IntrinsicName = float_intrinsic!("llvm.log2");
+static LLVM_LOG10: IntrinsicName = float_intrinsic!("llvm.log10");
+static LLVM_EXP: IntrinsicName = float_intrinsic!("llvm.exp");
+static LLVM_EXP2: IntrinsicName = float_intrinsic!("llvm.exp2");
+static LLVM_COPYSIGN: IntrinsicName = float_intrinsic!("llvm.copysign");
+static LLVM_FMA: IntrinsicName = float_intrinsic!("llvm.fma");
 
 static LLVM_SIN: IntrinsicName = float_intrinsic!("llvm.sin");
 static LLVM_COS: IntrinsicName = float_intrinsic!("llvm.cos");
@@ -670,6 +743,13 @@ const LLVM_MUL_WITH_OVERFLOW: IntrinsicName =
 const LLVM_ADD_SATURATED: IntrinsicName = llvm_int_intrinsic!("llvm.sadd.sat", "llvm.uadd.sat");
 const LLVM_SUB_SATURATED: IntrinsicName = llvm_int_intrinsic!("llvm.ssub.sat", "llvm.usub.sat");
 
+const LLVM_COUNT_LEADING_ZEROS: IntrinsicName = llvm_int_intrinsic!("llvm.ctlz", "llvm.ctlz");
+const LLVM_COUNT_TRAILING_ZEROS: IntrinsicName = llvm_int_intrinsic!("llvm.cttz", "llvm.cttz");
+const LLVM_COUNT_ONES: IntrinsicName = llvm_int_intrinsic!("llvm.ctpop", "llvm.ctpop");
+const LLVM_FUNNEL_SHIFT_LEFT: IntrinsicName = llvm_int_intrinsic!("llvm.fshl", "llvm.fshl");
+const LLVM_FUNNEL_SHIFT_RIGHT: IntrinsicName = llvm_int_intrinsic!("llvm.fshr", "llvm.fshr");
+const LLVM_SWAP_BYTES: IntrinsicName = llvm_int_intrinsic!("llvm.bswap", "llvm.bswap");
+
 fn add_intrinsic<'ctx>(
     context: &Context,
     module: &Module<'ctx>,
@@ -2917,6 +2997,62 @@ pub fn build_exp_stmt<'a, 'ctx, 'env>(
             )
         }
 
+        Dbg {
+            symbol,
+            region,
+            remainder,
+        } => {
+            if env.mode.runs_dbgs() {
+                match env.target_info.ptr_width() {
+                    roc_target::PtrWidth::Bytes8 => {
+                        clone_to_shared_memory(
+                            env,
+                            scope,
+                            layout_ids,
+                            *symbol,
+                            *region,
+                            &[*symbol],
+                        );
+
+                        send_dbg_to_host(env, *region);
+                    }
+                    roc_target::PtrWidth::Bytes4 => {
+                        // values cannot be reported from WASM yet
+                    }
+                }
+            }
+
+            build_exp_stmt(
+                env,
+                layout_ids,
+                func_spec_solutions,
+                scope,
+                parent,
+                remainder,
+            )
+        }
+
+        Crash { message, region } => {
+            match env.target_info.ptr_width() {
+                roc_target::PtrWidth::Bytes8 if env.mode.runs_dbgs() => {
+                    // `roc dev` renders the crash like a `dbg` before the host sees the panic
+                    clone_to_shared_memory(env, scope, layout_ids, *message, *region, &[*message]);
+
+                    send_dbg_to_host(env, *region);
+                }
+                roc_target::PtrWidth::Bytes8 if env.mode.runs_expects() => {
+                    write_crash_location(env, *message, *region);
+                }
+                _ => {}
+            }
+
+            throw_crash(env, load_symbol(scope, message));
+
+            // unused value (must return a BasicValue)
+            let zero = env.context.i64_type().const_zero();
+            zero.into()
+        }
+
         RuntimeError(error_msg) => {
             throw_exception(env, error_msg);
 
@@ -3935,7 +4071,7 @@ fn expose_function_to_host_help_c_abi<'a, 'ctx, 'env>(
             )
         }
 
-        LlvmBackendMode::Binary => {}
+        LlvmBackendMode::Binary | LlvmBackendMode::BinaryDev => {}
     }
 
     // a generic version that writes the result into a passed *u8 pointer
@@ -3986,7 +4122,9 @@ fn expose_function_to_host_help_c_abi<'a, 'ctx, 'env>(
             roc_result_type(env, roc_function.get_type().get_return_type().unwrap()).into()
         }
 
-        LlvmBackendMode::Binary => basic_type_from_layout(env, &return_layout),
+        LlvmBackendMode::Binary | LlvmBackendMode::BinaryDev => {
+            basic_type_from_layout(env, &return_layout)
+        }
     };
 
     let size: BasicValueEnum = return_type.size_of().unwrap().into();
@@ -4088,6 +4226,20 @@ pub fn build_setjmp_call<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>) -> BasicValu
     }
 }
 
+/// Pointer to the tag of the last panic, a `PanicTagId`.
+pub fn get_panic_tag_ptr<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>) -> PointerValue<'ctx> {
+    let i32_type = env.context.i32_type();
+
+    let global_name = "roc_panic_tag";
+    let global = env.module.get_global(global_name).unwrap_or_else(|| {
+        let global = env.module.add_global(i32_type, None, global_name);
+        global.set_initializer(&i32_type.const_zero());
+        global
+    });
+
+    global.as_pointer_value()
+}
+
 /// Pointer to pointer of the panic message.
 pub fn get_panic_msg_ptr<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>) -> PointerValue<'ctx> {
     let ptr_to_u8_ptr = env.context.i8_type().ptr_type(AddressSpace::Generic);
@@ -4159,8 +4311,15 @@ fn set_jump_and_catch_long_jump<'a, 'ctx, 'env>(
         let return_value = {
             let v1 = call_result_type.const_zero();
 
-            // flag is non-zero, indicating failure
-            let flag = context.i64_type().const_int(1, false);
+            // flag is the panic tag plus one; non-zero, indicating failure
+            let flag = {
+                let tag = builder
+                    .build_load(get_panic_tag_ptr(env), "load_panic_tag")
+                    .into_int_value();
+                let tag = builder.build_int_z_extend(tag, context.i64_type(), "tag_to_u64");
+
+                builder.build_int_add(tag, context.i64_type().const_int(1, false), "flag")
+            };
 
             let v2 = builder
                 .build_insert_value(v1, flag, 0, "set_error")
@@ -4958,7 +5117,7 @@ pub fn build_proc<'a, 'ctx, 'env>(
                 GenTest | WasmGenTest | CliTest => {
                     /* no host, or exposing types is not supported */
                 }
-                Binary => {
+                Binary | BinaryDev => {
                     for (alias_name, (generated_function, top_level, layout)) in aliases.iter() {
                         expose_alias_to_host(
                             env,
@@ -6035,6 +6194,48 @@ fn run_low_level<'a, 'ctx, 'env>(
                 bitcode::STR_TRIM_RIGHT,
             )
         }
+        StrGraphemes => {
+            // Str.graphemes : Str -> List Str
+            debug_assert_eq!(args.len(), 1);
+
+            let string = load_symbol(scope, &args[0]);
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::List,
+                bitcode::STR_GRAPHEMES,
+            )
+        }
+        StrGraphemeByteOffset => {
+            // Str.graphemeByteOffsetLowlevel : Str, Nat -> Nat
+            debug_assert_eq!(args.len(), 2);
+
+            let string = load_symbol(scope, &args[0]);
+            let grapheme_index = load_symbol(scope, &args[1]);
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[grapheme_index],
+                BitcodeReturns::Basic,
+                bitcode::STR_GRAPHEME_BYTE_OFFSET,
+            )
+        }
+        StrToUpper | StrToLower | StrToNfc | StrToNfd => {
+            // Str.toUpper : Str -> Str
+            debug_assert_eq!(args.len(), 1);
+
+            let string = load_symbol(scope, &args[0]);
+            let intrinsic = match op {
+                StrToUpper => bitcode::STR_TO_UPPER,
+                StrToLower => bitcode::STR_TO_LOWER,
+                StrToNfc => bitcode::STR_TO_NFC,
+                StrToNfd => bitcode::STR_TO_NFD,
+                _ => unreachable!(),
+            };
+
+            call_str_bitcode_fn(env, &[string], &[], BitcodeReturns::Str, intrinsic)
+        }
         ListLen => {
             // List.len : List * -> Nat
             debug_assert_eq!(args.len(), 1);
@@ -6047,9 +6248,15 @@ fn run_low_level<'a, 'ctx, 'env>(
             // List.capacity : List * -> Nat
             debug_assert_eq!(args.len(), 1);
 
-            let arg = load_symbol(scope, &args[0]);
+            let list = load_symbol(scope, &args[0]).into_struct_value();
 
-            list_capacity(env.builder, arg.into_struct_value()).into()
+            call_list_bitcode_fn(
+                env,
+                &[list],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::LIST_CAPACITY,
+            )
         }
         ListWithCapacity => {
             // List.withCapacity : Nat -> List a
@@ -6219,6 +6426,40 @@ fn run_low_level<'a, 'ctx, 'env>(
                 bitcode::LIST_IS_UNIQUE,
             )
         }
+        NumDecToStrFixed => {
+            // Num.toStrFixed : Dec, U8 -> Str
+            debug_assert_eq!(args.len(), 2);
+
+            let dec = load_symbol(scope, &args[0]);
+            let places = load_symbol(scope, &args[1]);
+
+            dec_to_str_fixed(env, dec, places)
+        }
+        NumDecRoundTo => {
+            // Num.roundDecToLowlevel : Dec, U8, U8 -> { b : Bool, a : Dec }
+            debug_assert_eq!(args.len(), 3);
+
+            let dec = load_symbol(scope, &args[0]);
+            let places = load_symbol(scope, &args[1]);
+            let mode = load_symbol(scope, &args[2]);
+
+            dec_unary_op_with_overflow(env, bitcode::DEC_ROUND_TO, dec, &[places, mode], layout)
+        }
+        NumDecToScaledI128 | NumDecFromScaledI128 => {
+            // Num.toScaledI128Lowlevel : Dec, U8 -> { b : Bool, a : I128 }
+            // Num.fromScaledI128Lowlevel : I128, U8 -> { b : Bool, a : Dec }
+            debug_assert_eq!(args.len(), 2);
+
+            let num = load_symbol(scope, &args[0]);
+            let places = load_symbol(scope, &args[1]);
+
+            let fn_name = match op {
+                NumDecToScaledI128 => bitcode::DEC_TO_SCALED_I128,
+                _ => bitcode::DEC_FROM_SCALED_I128,
+            };
+
+            dec_unary_op_with_overflow(env, fn_name, num, &[places], layout)
+        }
         NumToStr => {
             // Num.toStr : Num a -> Str
             debug_assert_eq!(args.len(), 1);
@@ -6257,9 +6498,35 @@ fn run_low_level<'a, 'ctx, 'env>(
                 _ => unreachable!(),
             }
         }
-        NumAbs | NumNeg | NumRound | NumSqrtUnchecked | NumLogUnchecked | NumSin | NumCos
-        | NumCeiling | NumFloor | NumToFrac | NumIsFinite | NumAtan | NumAcos | NumAsin
-        | NumToIntChecked => {
+        NumAbs
+        | NumNeg
+        | NumRound
+        | NumSqrtUnchecked
+        | NumLogUnchecked
+        | NumSin
+        | NumCos
+        | NumCeiling
+        | NumFloor
+        | NumToFrac
+        | NumIsFinite
+        | NumAtan
+        | NumAcos
+        | NumAsin
+        | NumSinh
+        | NumCosh
+        | NumTanh
+        | NumExp
+        | NumExp2
+        | NumLog2
+        | NumLog10
+        | NumTrunc
+        | NumIsNan
+        | NumIsInfinite
+        | NumToIntChecked
+        | NumCountLeadingZeroBits
+        | NumCountTrailingZeroBits
+        | NumCountOneBits
+        | NumSwapBytes => {
             debug_assert_eq!(args.len(), 1);
 
             let (arg, arg_layout) = load_symbol_and_layout(scope, &args[0]);
@@ -6288,6 +6555,10 @@ fn run_low_level<'a, 'ctx, 'env>(
                             op,
                             *float_width,
                         ),
+                        // Dec is fixed-point, so it is never NaN or infinite
+                        Decimal if matches!(op, NumIsNan | NumIsInfinite) => {
+                            env.context.bool_type().const_zero().into()
+                        }
                         _ => {
                             unreachable!("Compiler bug: tried to run numeric operation {:?} on invalid builtin layout: ({:?})", op, arg_layout);
                         }
@@ -6416,7 +6687,8 @@ fn run_low_level<'a, 'ctx, 'env>(
         NumAdd | NumSub | NumMul | NumLt | NumLte | NumGt | NumGte | NumRemUnchecked
         | NumIsMultipleOf | NumAddWrap | NumAddChecked | NumAddSaturated | NumDivFrac
         | NumDivTruncUnchecked | NumDivCeilUnchecked | NumPow | NumPowInt | NumSubWrap
-        | NumSubChecked | NumSubSaturated | NumMulWrap | NumMulSaturated | NumMulChecked => {
+        | NumSubChecked | NumSubSaturated | NumMulWrap | NumMulSaturated | NumMulChecked
+        | NumAtan2 | NumHypot | NumCopySign => {
             debug_assert_eq!(args.len(), 2);
 
             let (lhs_arg, lhs_layout) = load_symbol_and_layout(scope, &args[0]);
@@ -6424,6 +6696,25 @@ fn run_low_level<'a, 'ctx, 'env>(
 
             build_num_binop(env, parent, lhs_arg, lhs_layout, rhs_arg, rhs_layout, op)
         }
+        NumFma => {
+            debug_assert_eq!(args.len(), 3);
+
+            let (a, arg_layout) = load_symbol_and_layout(scope, &args[0]);
+            let b = load_symbol(scope, &args[1]);
+            let c = load_symbol(scope, &args[2]);
+
+            match arg_layout {
+                Layout::Builtin(Builtin::Float(float_width)) => {
+                    env.call_intrinsic(&LLVM_FMA[*float_width], &[a.into(), b.into(), c.into()])
+                }
+                _ => {
+                    unreachable!(
+                        "Compiler bug: tried to run numeric operation {:?} on invalid layout: {:?}",
+                        op, arg_layout
+                    );
+                }
+            }
+        }
         NumBitwiseAnd | NumBitwiseOr | NumBitwiseXor => {
             debug_assert_eq!(args.len(), 2);
 
@@ -6442,7 +6733,8 @@ fn run_low_level<'a, 'ctx, 'env>(
                 op,
             )
         }
-        NumShiftLeftBy | NumShiftRightBy | NumShiftRightZfBy => {
+        NumShiftLeftBy | NumShiftRightBy | NumShiftRightZfBy | NumRotateLeftBy
+        | NumRotateRightBy => {
             debug_assert_eq!(args.len(), 2);
 
             let (lhs_arg, lhs_layout) = load_symbol_and_layout(scope, &args[0]);
@@ -6568,7 +6860,18 @@ fn run_low_level<'a, 'ctx, 'env>(
             BasicValueEnum::IntValue(bool_val)
         }
         Hash => {
-            unimplemented!()
+            debug_assert_eq!(args.len(), 2);
+
+            let (value, layout) = load_symbol_and_layout(scope, &args[0]);
+            let seed = load_symbol(scope, &args[1]);
+
+            generic_hash(env, layout_ids, value, seed.into_int_value(), layout).into()
+        }
+        GenSeed => {
+            // Gen.seedLowLevel : {} -> U64
+            debug_assert_eq!(args.len(), 1);
+
+            call_bitcode_fn(env, &[], bitcode::UTILS_GEN_SEED)
         }
 
         ListMap | ListMap2 | ListMap3 | ListMap4 | ListSortWith => {
@@ -7263,6 +7566,17 @@ fn build_int_binop<'a, 'ctx, 'env>(
         NumShiftRightZfBy => bd
             .build_right_shift(lhs, rhs, false, "int_shift_right_zf")
             .into(),
+        NumRotateLeftBy => {
+            // a funnel shift of a number with itself is a rotation
+            env.call_intrinsic(
+                &LLVM_FUNNEL_SHIFT_LEFT[int_width],
+                &[lhs.into(), lhs.into(), rhs.into()],
+            )
+        }
+        NumRotateRightBy => env.call_intrinsic(
+            &LLVM_FUNNEL_SHIFT_RIGHT[int_width],
+            &[lhs.into(), lhs.into(), rhs.into()],
+        ),
 
         _ => {
             unreachable!("Unrecognized int binary operation: {:?}", op);
@@ -7424,6 +7738,17 @@ fn build_float_binop<'a, 'ctx, 'env>(
         NumLte => bd.build_float_compare(OLE, lhs, rhs, "float_lte").into(),
         NumDivFrac => bd.build_float_div(lhs, rhs, "div_float").into(),
         NumPow => env.call_intrinsic(&LLVM_POW[float_width], &[lhs.into(), rhs.into()]),
+        NumAtan2 => call_bitcode_fn(
+            env,
+            &[lhs.into(), rhs.into()],
+            &bitcode::NUM_ATAN2[float_width],
+        ),
+        NumHypot => call_bitcode_fn(
+            env,
+            &[lhs.into(), rhs.into()],
+            &bitcode::NUM_HYPOT[float_width],
+        ),
+        NumCopySign => env.call_intrinsic(&LLVM_COPYSIGN[float_width], &[lhs.into(), rhs.into()]),
         _ => {
             unreachable!("Unrecognized int binary operation: {:?}", op);
         }
@@ -7503,6 +7828,89 @@ fn dec_to_str<'a, 'ctx, 'env>(
     }
 }
 
+fn dec_to_str_fixed<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    dec: BasicValueEnum<'ctx>,
+    places: BasicValueEnum<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    use roc_target::OperatingSystem::*;
+
+    let dec = dec.into_int_value();
+
+    match env.target_info.operating_system {
+        Windows => call_str_bitcode_fn(
+            env,
+            &[],
+            &[dec_alloca(env, dec).into(), places],
+            BitcodeReturns::Str,
+            bitcode::DEC_TO_STR_FIXED,
+        ),
+        Unix => {
+            let (low, high) = dec_split_into_words(env, dec);
+
+            call_str_bitcode_fn(
+                env,
+                &[],
+                &[low.into(), high.into(), places],
+                BitcodeReturns::Str,
+                bitcode::DEC_TO_STR_FIXED,
+            )
+        }
+        Wasi => unimplemented!(),
+    }
+}
+
+/// Calls a Dec builtin whose first argument is a Dec (or an I128, which has the same
+/// representation) and which returns a `WithOverflow(RocDec)`, giving back the
+/// `{ b : Bool, a : _ }` record described by `return_layout`.
+fn dec_unary_op_with_overflow<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    fn_name: &str,
+    arg: BasicValueEnum<'ctx>,
+    other_args: &[BasicValueEnum<'ctx>],
+    return_layout: &Layout<'a>,
+) -> BasicValueEnum<'ctx> {
+    use roc_target::OperatingSystem::*;
+
+    let arg = arg.into_int_value();
+
+    let return_type = zig_with_overflow_roc_dec(env);
+    let return_alloca = env.builder.build_alloca(return_type, "return_alloca");
+
+    let mut args = Vec::with_capacity_in(3 + other_args.len(), env.arena);
+    args.push(return_alloca.into());
+
+    match env.target_info.operating_system {
+        Windows => {
+            args.push(dec_alloca(env, arg).into());
+        }
+        Unix => {
+            let (low, high) = dec_split_into_words(env, arg);
+
+            args.push(low.into());
+            args.push(high.into());
+        }
+        Wasi => unimplemented!(),
+    }
+
+    args.extend_from_slice(other_args);
+
+    call_void_bitcode_fn(env, &args, fn_name);
+
+    // The zig struct and the roc record have the same shape, but are different LLVM types
+    let roc_return_type = basic_type_from_layout(env, return_layout);
+    let roc_return_ptr = env
+        .builder
+        .build_bitcast(
+            return_alloca,
+            roc_return_type.ptr_type(AddressSpace::Generic),
+            "to_roc_record",
+        )
+        .into_pointer_value();
+
+    env.builder.build_load(roc_return_ptr, "load_dec")
+}
+
 fn dec_binop_with_overflow<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     fn_name: &str,
@@ -7632,6 +8040,9 @@ fn build_dec_binop<'a, 'ctx, 'env>(
             "decimal multiplication overflowed",
         ),
         NumDivFrac => dec_binop_with_unchecked(env, bitcode::DEC_DIV, lhs, rhs),
+        NumCopySign => dec_binop_with_unchecked(env, bitcode::DEC_COPY_SIGN, lhs, rhs),
+        NumAtan2 => dec_binop_with_unchecked(env, bitcode::DEC_ATAN2, lhs, rhs),
+        NumHypot => dec_binop_with_unchecked(env, bitcode::DEC_HYPOT, lhs, rhs),
         _ => {
             unreachable!("Unrecognized int binary operation: {:?}", op);
         }
@@ -7842,6 +8253,38 @@ fn build_int_unary_op<'a, 'ctx, 'env>(
                 complex_bitcast_check_size(env, result, return_type.into(), "cast_bitpacked")
             }
         }
+        NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits => {
+            let count = match op {
+                NumCountOneBits => env.call_intrinsic(&LLVM_COUNT_ONES[arg_width], &[arg.into()]),
+                _ => {
+                    let intrinsic = if op == NumCountLeadingZeroBits {
+                        &LLVM_COUNT_LEADING_ZEROS[arg_width]
+                    } else {
+                        &LLVM_COUNT_TRAILING_ZEROS[arg_width]
+                    };
+
+                    // `false` makes a zero argument count every bit, rather than being undefined
+                    let zero_is_poison = env.context.bool_type().const_zero();
+                    env.call_intrinsic(intrinsic, &[arg.into(), zero_is_poison.into()])
+                }
+            };
+
+            // the count has the argument's type, but the result is always a U8
+            bd.build_int_cast_sign_flag(
+                count.into_int_value(),
+                env.context.i8_type(),
+                false,
+                "count_to_u8",
+            )
+            .into()
+        }
+        NumSwapBytes => {
+            if arg_width.stack_size() == 1 {
+                arg.into()
+            } else {
+                env.call_intrinsic(&LLVM_SWAP_BYTES[arg_width], &[arg.into()])
+            }
+        }
         _ => {
             unreachable!("Unrecognized int unary operation: {:?}", op);
         }
@@ -8054,7 +8497,44 @@ fn build_float_unary_op<'a, 'ctx, 'env>(
                 "num_round",
             )
         }
+        NumTrunc => {
+            let (return_signed, return_type) = match layout {
+                Layout::Builtin(Builtin::Int(int_width)) => (
+                    int_width.is_signed(),
+                    convert::int_type_from_int_width(env, *int_width),
+                ),
+                _ => internal_error!("Trunc return layout is not int: {:?}", layout),
+            };
+            // float-to-int conversions already round towards zero
+            let opcode = if return_signed {
+                InstructionOpcode::FPToSI
+            } else {
+                InstructionOpcode::FPToUI
+            };
+            env.builder
+                .build_cast(opcode, arg, return_type, "num_trunc")
+        }
         NumIsFinite => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_IS_FINITE[float_width]),
+        NumIsNan => bd
+            .build_float_compare(inkwell::FloatPredicate::UNO, arg, arg, "is_nan")
+            .into(),
+        NumIsInfinite => {
+            let abs = env.call_intrinsic(&LLVM_FABS[float_width], &[arg.into()]);
+            let infinity = arg.get_type().const_float(f64::INFINITY);
+            bd.build_float_compare(
+                inkwell::FloatPredicate::OEQ,
+                abs.into_float_value(),
+                infinity,
+                "is_infinite",
+            )
+            .into()
+        }
+
+        // exponents and logarithms
+        NumExp => env.call_intrinsic(&LLVM_EXP[float_width], &[arg.into()]),
+        NumExp2 => env.call_intrinsic(&LLVM_EXP2[float_width], &[arg.into()]),
+        NumLog2 => env.call_intrinsic(&LLVM_LOG2[float_width], &[arg.into()]),
+        NumLog10 => env.call_intrinsic(&LLVM_LOG10[float_width], &[arg.into()]),
 
         // trigonometry
         NumSin => env.call_intrinsic(&LLVM_SIN[float_width], &[arg.into()]),
@@ -8064,6 +8544,10 @@ fn build_float_unary_op<'a, 'ctx, 'env>(
         NumAcos => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_ACOS[float_width]),
         NumAsin => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_ASIN[float_width]),
 
+        NumSinh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_SINH[float_width]),
+        NumCosh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_COSH[float_width]),
+        NumTanh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_TANH[float_width]),
+
         _ => {
             unreachable!("Unrecognized int unary operation: {:?}", op);
         }
@@ -8198,6 +8682,38 @@ fn throw_exception<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>, message: &str) {
     builder.build_unreachable();
 }
 
+/// Panic with the message of a `crash`. The host expects a null-terminated string,
+/// so the bytes of the `Str` are copied into a fresh allocation with a trailing zero.
+fn throw_crash<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>, message: BasicValueEnum<'ctx>) {
+    let builder = env.builder;
+
+    let utf8 = call_str_bitcode_fn(
+        env,
+        &[message],
+        &[],
+        BitcodeReturns::List,
+        bitcode::STR_TO_UTF8,
+    );
+
+    let (bytes_ptr, length, _capacity) = build_list::destructure(builder, utf8.into_struct_value());
+
+    let one = env.ptr_int().const_int(1, false);
+    let size = builder.build_int_add(length, one, "size_with_terminator");
+
+    let c_str = env.call_alloc(size, 1);
+
+    builder
+        .build_memcpy(c_str, 1, bytes_ptr, 1, length)
+        .unwrap();
+
+    let terminator = unsafe { builder.build_in_bounds_gep(c_str, &[length], "terminator") };
+    builder.build_store(terminator, env.context.i8_type().const_zero());
+
+    env.call_panic(c_str, PanicTagId::UserCrash);
+
+    builder.build_unreachable();
+}
+
 fn get_foreign_symbol<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     foreign_symbol: roc_module::ident::ForeignSymbol,
@@ -0,0 +1,679 @@
+use crate::debug_info_init;
+use crate::llvm::bitcode::{call_bitcode_fn, call_str_bitcode_fn, BitcodeReturns};
+use crate::llvm::build::{get_tag_id, tag_pointer_clear_tag_id, Env, FAST_CALL_CONV};
+use crate::llvm::build_list::{list_len, load_list_ptr};
+use crate::llvm::convert::basic_type_from_layout;
+use bumpalo::collections::Vec;
+use inkwell::types::BasicType;
+use inkwell::values::{BasicValueEnum, FunctionValue, IntValue, PointerValue, StructValue};
+use inkwell::{AddressSpace, IntPredicate};
+use roc_builtins::bitcode;
+use roc_builtins::bitcode::IntWidth;
+use roc_module::symbol::Symbol;
+use roc_mono::layout::{Builtin, Layout, LayoutIds, TagIdIntType, UnionLayout};
+use roc_target::PtrWidth;
+
+use super::build::{load_roc_value, use_roc_value};
+use super::convert::argument_type_from_union_layout;
+
+#[derive(Clone, Debug)]
+enum WhenRecursive<'a> {
+    Unreachable,
+    Loop(UnionLayout<'a>),
+}
+
+/// Hash a value of any (non-function) layout, starting from the given seed.
+/// Composite values are hashed by threading the seed through the hashes of their parts.
+pub fn generic_hash<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    val: BasicValueEnum<'ctx>,
+    seed: IntValue<'ctx>,
+    layout: &Layout<'a>,
+) -> IntValue<'ctx> {
+    build_hash_layout(
+        env,
+        layout_ids,
+        val,
+        seed,
+        layout,
+        WhenRecursive::Unreachable,
+    )
+}
+
+fn build_hash_layout<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    val: BasicValueEnum<'ctx>,
+    seed: IntValue<'ctx>,
+    layout: &Layout<'a>,
+    when_recursive: WhenRecursive<'a>,
+) -> IntValue<'ctx> {
+    match layout {
+        Layout::Builtin(builtin) => {
+            hash_builtin(env, layout_ids, val, seed, builtin, when_recursive)
+        }
+
+        Layout::Struct { field_layouts, .. } => build_hash_struct(
+            env,
+            layout_ids,
+            field_layouts,
+            when_recursive,
+            val.into_struct_value(),
+            seed,
+        ),
+
+        Layout::LambdaSet(_) => unreachable!("cannot hash closures"),
+
+        Layout::Union(union_layout) => {
+            build_hash_tag(env, layout_ids, union_layout, when_recursive, val, seed)
+        }
+
+        Layout::Boxed(inner_layout) => {
+            let inner = load_roc_value(env, **inner_layout, val.into_pointer_value(), "unbox");
+
+            build_hash_layout(env, layout_ids, inner, seed, inner_layout, when_recursive)
+        }
+
+        Layout::RecursivePointer => match when_recursive {
+            WhenRecursive::Unreachable => {
+                unreachable!("recursion pointers should never be hashed directly")
+            }
+            WhenRecursive::Loop(union_layout) => {
+                let layout = Layout::Union(union_layout);
+
+                let bt = basic_type_from_layout(env, &layout);
+
+                // cast the i64 pointer to a pointer to block of memory
+                let field_cast = env
+                    .builder
+                    .build_bitcast(val, bt, "i64_to_opaque")
+                    .into_pointer_value();
+
+                build_hash_tag(
+                    env,
+                    layout_ids,
+                    &union_layout,
+                    WhenRecursive::Loop(union_layout),
+                    field_cast.into(),
+                    seed,
+                )
+            }
+        },
+    }
+}
+
+fn hash_int<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    val: BasicValueEnum<'ctx>,
+    seed: IntValue<'ctx>,
+    int_width: IntWidth,
+) -> IntValue<'ctx> {
+    call_bitcode_fn(env, &[val, seed.into()], &bitcode::DICT_HASH_INT[int_width]).into_int_value()
+}
+
+fn hash_builtin<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    val: BasicValueEnum<'ctx>,
+    seed: IntValue<'ctx>,
+    builtin: &Builtin<'a>,
+    when_recursive: WhenRecursive<'a>,
+) -> IntValue<'ctx> {
+    match builtin {
+        Builtin::Int(int_width) => hash_int(env, val, seed, *int_width),
+
+        Builtin::Float(float_width) => call_bitcode_fn(
+            env,
+            &[val, seed.into()],
+            &bitcode::DICT_HASH_FLOAT[*float_width],
+        )
+        .into_int_value(),
+
+        Builtin::Bool => {
+            let byte = env.builder.build_int_z_extend(
+                val.into_int_value(),
+                env.context.i8_type(),
+                "bool_to_u8",
+            );
+
+            hash_int(env, byte.into(), seed, IntWidth::U8)
+        }
+
+        Builtin::Decimal => hash_int(env, val, seed, IntWidth::I128),
+
+        Builtin::Str => call_str_bitcode_fn(
+            env,
+            &[val],
+            &[seed.into()],
+            BitcodeReturns::Basic,
+            bitcode::DICT_HASH_STR,
+        )
+        .into_int_value(),
+
+        Builtin::List(element_layout) => build_hash_list(
+            env,
+            layout_ids,
+            &Layout::Builtin(*builtin),
+            element_layout,
+            when_recursive,
+            val.into_struct_value(),
+            seed,
+        ),
+    }
+}
+
+fn build_hash_struct<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    field_layouts: &'a [Layout<'a>],
+    when_recursive: WhenRecursive<'a>,
+    value: StructValue<'ctx>,
+    seed: IntValue<'ctx>,
+) -> IntValue<'ctx> {
+    let block = env.builder.get_insert_block().expect("to be in a function");
+    let di_location = env.builder.get_current_debug_location().unwrap();
+
+    let struct_layout = Layout::struct_no_name_order(field_layouts);
+
+    let symbol = Symbol::GENERIC_HASH;
+    let fn_name = layout_ids
+        .get(symbol, &struct_layout)
+        .to_symbol_string(symbol, &env.interns);
+
+    let function = match env.module.get_function(fn_name.as_str()) {
+        Some(function_value) => function_value,
+        None => {
+            let seed_type = env.context.i64_type();
+            let arg_type = basic_type_from_layout(env, &struct_layout);
+
+            let function_value = crate::llvm::refcounting::build_header_help(
+                env,
+                &fn_name,
+                seed_type.into(),
+                &[arg_type, seed_type.into()],
+            );
+
+            build_hash_struct_help(
+                env,
+                layout_ids,
+                function_value,
+                when_recursive,
+                field_layouts,
+            );
+
+            function_value
+        }
+    };
+
+    env.builder.position_at_end(block);
+    env.builder
+        .set_current_debug_location(env.context, di_location);
+    let call = env
+        .builder
+        .build_call(function, &[value.into(), seed.into()], "struct_hash");
+
+    call.set_call_convention(FAST_CALL_CONV);
+
+    call.try_as_basic_value().left().unwrap().into_int_value()
+}
+
+fn build_hash_struct_help<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    parent: FunctionValue<'ctx>,
+    when_recursive: WhenRecursive<'a>,
+    field_layouts: &[Layout<'a>],
+) {
+    let ctx = env.context;
+
+    debug_info_init!(env, parent);
+
+    // Add args to scope
+    let mut it = parent.get_param_iter();
+    let value = it.next().unwrap().into_struct_value();
+    let seed = it.next().unwrap().into_int_value();
+
+    value.set_name(Symbol::ARG_1.as_str(&env.interns));
+    seed.set_name(Symbol::ARG_2.as_str(&env.interns));
+
+    let entry = ctx.append_basic_block(parent, "entry");
+    env.builder.position_at_end(entry);
+
+    let result = hash_struct(env, layout_ids, value, seed, when_recursive, field_layouts);
+
+    env.builder.build_return(Some(&result));
+}
+
+fn hash_struct<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    value: StructValue<'ctx>,
+    seed: IntValue<'ctx>,
+    when_recursive: WhenRecursive<'a>,
+    field_layouts: &[Layout<'a>],
+) -> IntValue<'ctx> {
+    let mut current_seed = seed;
+
+    for (index, field_layout) in field_layouts.iter().enumerate() {
+        let field = env
+            .builder
+            .build_extract_value(value, index as u32, "hash_field")
+            .unwrap();
+
+        current_seed = if let Layout::RecursivePointer = field_layout {
+            match &when_recursive {
+                WhenRecursive::Unreachable => {
+                    unreachable!("The current layout should not be recursive, but is")
+                }
+                WhenRecursive::Loop(union_layout) => {
+                    let field_layout = Layout::Union(*union_layout);
+
+                    let bt = basic_type_from_layout(env, &field_layout);
+
+                    // cast the i64 pointer to a pointer to block of memory
+                    let field_cast = env
+                        .builder
+                        .build_bitcast(field, bt, "i64_to_opaque")
+                        .into_pointer_value();
+
+                    build_hash_tag(
+                        env,
+                        layout_ids,
+                        union_layout,
+                        WhenRecursive::Loop(*union_layout),
+                        field_cast.into(),
+                        current_seed,
+                    )
+                }
+            }
+        } else {
+            build_hash_layout(
+                env,
+                layout_ids,
+                use_roc_value(env, *field_layout, field, "field"),
+                current_seed,
+                field_layout,
+                when_recursive.clone(),
+            )
+        };
+    }
+
+    current_seed
+}
+
+fn build_hash_tag<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    union_layout: &UnionLayout<'a>,
+    when_recursive: WhenRecursive<'a>,
+    value: BasicValueEnum<'ctx>,
+    seed: IntValue<'ctx>,
+) -> IntValue<'ctx> {
+    let block = env.builder.get_insert_block().expect("to be in a function");
+    let di_location = env.builder.get_current_debug_location().unwrap();
+
+    let tag_layout = Layout::Union(*union_layout);
+    let symbol = Symbol::GENERIC_HASH;
+    let fn_name = layout_ids
+        .get(symbol, &tag_layout)
+        .to_symbol_string(symbol, &env.interns);
+
+    let function = match env.module.get_function(fn_name.as_str()) {
+        Some(function_value) => function_value,
+        None => {
+            let seed_type = env.context.i64_type();
+            let arg_type = argument_type_from_union_layout(env, union_layout);
+
+            let function_value = crate::llvm::refcounting::build_header_help(
+                env,
+                &fn_name,
+                seed_type.into(),
+                &[arg_type, seed_type.into()],
+            );
+
+            build_hash_tag_help(
+                env,
+                layout_ids,
+                function_value,
+                when_recursive,
+                union_layout,
+            );
+
+            function_value
+        }
+    };
+
+    env.builder.position_at_end(block);
+    env.builder
+        .set_current_debug_location(env.context, di_location);
+    let call = env
+        .builder
+        .build_call(function, &[value.into(), seed.into()], "tag_hash");
+
+    call.set_call_convention(FAST_CALL_CONV);
+
+    call.try_as_basic_value().left().unwrap().into_int_value()
+}
+
+fn build_hash_tag_help<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    parent: FunctionValue<'ctx>,
+    when_recursive: WhenRecursive<'a>,
+    union_layout: &UnionLayout<'a>,
+) {
+    let ctx = env.context;
+
+    debug_info_init!(env, parent);
+
+    // Add args to scope
+    let mut it = parent.get_param_iter();
+    let tag = it.next().unwrap();
+    let seed = it.next().unwrap().into_int_value();
+
+    tag.set_name(Symbol::ARG_1.as_str(&env.interns));
+    seed.set_name(Symbol::ARG_2.as_str(&env.interns));
+
+    let entry = ctx.append_basic_block(parent, "entry");
+    env.builder.position_at_end(entry);
+
+    use UnionLayout::*;
+
+    let (tags, nullable_id, when_recursive): (&'a [&'a [Layout<'a>]], _, _) = match union_layout {
+        NonRecursive(&[]) => {
+            // we're hashing empty tag unions; this code is effectively unreachable
+            env.builder.build_unreachable();
+            return;
+        }
+        NonRecursive(tags) => (*tags, None, when_recursive),
+        Recursive(tags) => (*tags, None, WhenRecursive::Loop(*union_layout)),
+        NonNullableUnwrapped(fields) => (
+            env.arena.alloc([*fields]),
+            None,
+            WhenRecursive::Loop(*union_layout),
+        ),
+        NullableWrapped {
+            other_tags,
+            nullable_id,
+        } => (
+            *other_tags,
+            Some(*nullable_id),
+            WhenRecursive::Loop(*union_layout),
+        ),
+        NullableUnwrapped {
+            other_fields,
+            nullable_id,
+        } => (
+            env.arena.alloc([*other_fields]),
+            Some(*nullable_id as TagIdIntType),
+            WhenRecursive::Loop(*union_layout),
+        ),
+    };
+
+    // first, hash the tag id
+    let tag_id = get_tag_id(env, parent, union_layout, tag);
+    let tag_id_width = match union_layout.tag_id_layout() {
+        Layout::Builtin(Builtin::Int(int_width)) => int_width,
+        other => unreachable!("tag ids are integers, not {:?}", other),
+    };
+    let tag_hash = hash_int(env, tag_id.into(), seed, tag_id_width);
+
+    // the null tag has no fields, so its hash is just the hash of its id
+    if nullable_id.is_some() {
+        let is_null = env
+            .builder
+            .build_is_null(tag.into_pointer_value(), "is_null");
+
+        let return_tag_hash = ctx.append_basic_block(parent, "return_tag_hash");
+        let hash_fields = ctx.append_basic_block(parent, "hash_fields");
+
+        env.builder
+            .build_conditional_branch(is_null, return_tag_hash, hash_fields);
+
+        env.builder.position_at_end(return_tag_hash);
+        env.builder.build_return(Some(&tag_hash));
+
+        env.builder.position_at_end(hash_fields);
+    }
+
+    // get a pointer to the actual data
+    let tag_ptr = match union_layout {
+        Recursive(_) | NullableWrapped { .. } => {
+            tag_pointer_clear_tag_id(env, tag.into_pointer_value())
+        }
+        _ => tag.into_pointer_value(),
+    };
+
+    let switch_block = env.builder.get_insert_block().unwrap();
+
+    // switch on all the tag ids
+    let mut cases = Vec::with_capacity_in(tags.len(), env.arena);
+
+    let mut tag_id_value: TagIdIntType = 0;
+    for field_layouts in tags.iter() {
+        if let Some(null_id) = nullable_id {
+            if tag_id_value == null_id {
+                tag_id_value += 1;
+            }
+        }
+
+        let block = env.context.append_basic_block(parent, "tag_id_hash");
+        env.builder.position_at_end(block);
+
+        let answer = hash_ptr_to_struct(
+            env,
+            layout_ids,
+            when_recursive.clone(),
+            field_layouts,
+            tag_ptr,
+            tag_hash,
+        );
+
+        env.builder.build_return(Some(&answer));
+
+        cases.push((
+            tag_id.get_type().const_int(tag_id_value as u64, false),
+            block,
+        ));
+
+        tag_id_value += 1;
+    }
+
+    env.builder.position_at_end(switch_block);
+
+    let default = cases.pop().unwrap().1;
+
+    env.builder.build_switch(tag_id, default, &cases);
+}
+
+fn hash_ptr_to_struct<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    when_recursive: WhenRecursive<'a>,
+    field_layouts: &'a [Layout<'a>],
+    tag: PointerValue<'ctx>,
+    seed: IntValue<'ctx>,
+) -> IntValue<'ctx> {
+    let struct_layout = Layout::struct_no_name_order(field_layouts);
+
+    let wrapper_type = basic_type_from_layout(env, &struct_layout);
+    debug_assert!(wrapper_type.is_struct_type());
+
+    // cast the opaque pointer to a pointer of the correct shape
+    let struct_ptr = env
+        .builder
+        .build_bitcast(
+            tag,
+            wrapper_type.ptr_type(AddressSpace::Generic),
+            "opaque_to_correct",
+        )
+        .into_pointer_value();
+
+    let struct_value = env
+        .builder
+        .build_load(struct_ptr, "load_struct")
+        .into_struct_value();
+
+    hash_struct(
+        env,
+        layout_ids,
+        struct_value,
+        seed,
+        when_recursive,
+        field_layouts,
+    )
+}
+
+fn build_hash_list<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    list_layout: &Layout<'a>,
+    element_layout: &Layout<'a>,
+    when_recursive: WhenRecursive<'a>,
+    list: StructValue<'ctx>,
+    seed: IntValue<'ctx>,
+) -> IntValue<'ctx> {
+    let block = env.builder.get_insert_block().expect("to be in a function");
+    let di_location = env.builder.get_current_debug_location().unwrap();
+
+    let symbol = Symbol::GENERIC_HASH;
+    let fn_name = layout_ids
+        .get(symbol, list_layout)
+        .to_symbol_string(symbol, &env.interns);
+
+    let function = match env.module.get_function(fn_name.as_str()) {
+        Some(function_value) => function_value,
+        None => {
+            let seed_type = env.context.i64_type();
+            let arg_type = basic_type_from_layout(env, list_layout);
+
+            let function_value = crate::llvm::refcounting::build_header_help(
+                env,
+                &fn_name,
+                seed_type.into(),
+                &[arg_type, seed_type.into()],
+            );
+
+            build_hash_list_help(
+                env,
+                layout_ids,
+                function_value,
+                when_recursive,
+                element_layout,
+            );
+
+            function_value
+        }
+    };
+
+    env.builder.position_at_end(block);
+    env.builder
+        .set_current_debug_location(env.context, di_location);
+    let call = env
+        .builder
+        .build_call(function, &[list.into(), seed.into()], "list_hash");
+
+    call.set_call_convention(FAST_CALL_CONV);
+
+    call.try_as_basic_value().left().unwrap().into_int_value()
+}
+
+fn build_hash_list_help<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_ids: &mut LayoutIds<'a>,
+    parent: FunctionValue<'ctx>,
+    when_recursive: WhenRecursive<'a>,
+    element_layout: &Layout<'a>,
+) {
+    let ctx = env.context;
+    let builder = env.builder;
+
+    debug_info_init!(env, parent);
+
+    // Add args to scope
+    let mut it = parent.get_param_iter();
+    let list = it.next().unwrap().into_struct_value();
+    let seed = it.next().unwrap().into_int_value();
+
+    list.set_name(Symbol::ARG_1.as_str(&env.interns));
+    seed.set_name(Symbol::ARG_2.as_str(&env.interns));
+
+    let entry = ctx.append_basic_block(parent, "entry");
+    env.builder.position_at_end(entry);
+
+    // hash the length first, so that e.g. `[[], [1]]` and `[[1], []]` hash differently
+    let len = list_len(builder, list);
+    let len_width = match env.target_info.ptr_width() {
+        PtrWidth::Bytes4 => IntWidth::U32,
+        PtrWidth::Bytes8 => IntWidth::U64,
+    };
+    let len_hash = hash_int(env, len.into(), seed, len_width);
+
+    let element_type = basic_type_from_layout(env, element_layout);
+    let ptr_type = element_type.ptr_type(AddressSpace::Generic);
+    let ptr = load_list_ptr(builder, list, ptr_type);
+
+    // allocate stack slots for the current index and the current hash
+    let index_alloca = builder.build_alloca(env.ptr_int(), "index");
+    builder.build_store(index_alloca, env.ptr_int().const_zero());
+
+    let hash_alloca = builder.build_alloca(env.context.i64_type(), "hash");
+    builder.build_store(hash_alloca, len_hash);
+
+    let loop_bb = ctx.append_basic_block(parent, "loop");
+    let body_bb = ctx.append_basic_block(parent, "body");
+    let return_bb = ctx.append_basic_block(parent, "return");
+
+    // the "top" of the loop
+    builder.build_unconditional_branch(loop_bb);
+    builder.position_at_end(loop_bb);
+
+    let curr_index = builder.build_load(index_alloca, "index").into_int_value();
+
+    // #index < len
+    let loop_end_cond =
+        builder.build_int_compare(IntPredicate::ULT, curr_index, len, "bounds_check");
+
+    builder.build_conditional_branch(loop_end_cond, body_bb, return_bb);
+
+    {
+        // loop body
+        builder.position_at_end(body_bb);
+
+        let elem = {
+            let elem_ptr = unsafe { builder.build_in_bounds_gep(ptr, &[curr_index], "load_index") };
+            load_roc_value(env, *element_layout, elem_ptr, "get_elem")
+        };
+
+        let curr_hash = builder.build_load(hash_alloca, "hash").into_int_value();
+
+        let next_hash = build_hash_layout(
+            env,
+            layout_ids,
+            elem,
+            curr_hash,
+            element_layout,
+            when_recursive,
+        );
+
+        builder.build_store(hash_alloca, next_hash);
+
+        // constant 1isize
+        let one = env.ptr_int().const_int(1, false);
+
+        let next_index = builder.build_int_add(curr_index, one, "nextindex");
+
+        builder.build_store(index_alloca, next_index);
+
+        // jump back to the top of the loop
+        builder.build_unconditional_branch(loop_bb);
+    }
+
+    {
+        builder.position_at_end(return_bb);
+
+        let result = builder.build_load(hash_alloca, "hash");
+        builder.build_return(Some(&result));
+    }
+}
@@ -17,6 +17,9 @@ use super::build::{
     add_func, load_roc_value, load_symbol_and_layout, use_roc_value, FunctionSpec, Scope,
 };
 
+/// Frames start after the frame count and the next free offset
+const START_OFFSET: u64 = 16;
+
 #[derive(Debug, Clone, Copy)]
 struct Cursors<'ctx> {
     offset: IntValue<'ctx>,
@@ -181,6 +184,83 @@ pub(crate) fn clone_to_shared_memory<'a, 'ctx, 'env>(
     write_state(env, original_ptr, new_count, offset)
 }
 
+/// Hand the frame that a `dbg` just wrote to the host's `roc_dbg`,
+/// then clear the buffer so the next `dbg` starts from scratch
+pub(crate) fn send_dbg_to_host<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>, region: Region) {
+    let func = env
+        .module
+        .get_function(bitcode::UTILS_EXPECT_FAILED_START)
+        .unwrap();
+
+    let call_result = env
+        .builder
+        .build_call(func, &[], "call_expect_start_failed");
+
+    let original_ptr = call_result
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_pointer_value();
+
+    let (_count, length) = read_state(env, original_ptr);
+
+    let region_start = env
+        .context
+        .i32_type()
+        .const_int(region.start().offset as _, false);
+
+    let region_end = env
+        .context
+        .i32_type()
+        .const_int(region.end().offset as _, false);
+
+    let roc_dbg = env.module.get_function("roc_dbg").unwrap();
+
+    env.builder.build_call(
+        roc_dbg,
+        &[
+            region_start.into(),
+            region_end.into(),
+            original_ptr.into(),
+            length.into(),
+        ],
+        "call_roc_dbg",
+    );
+
+    let zero = env.ptr_int().const_zero();
+    let start_offset = env.ptr_int().const_int(START_OFFSET, false);
+    write_state(env, original_ptr, zero, start_offset)
+}
+
+/// Replace the contents of the buffer with a single frame holding the location of a `crash`,
+/// so that the test runner can point at it after the panic unwinds the test
+pub(crate) fn write_crash_location<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    message: Symbol,
+    region: Region,
+) {
+    let func = env
+        .module
+        .get_function(bitcode::UTILS_EXPECT_FAILED_START)
+        .unwrap();
+
+    let call_result = env
+        .builder
+        .build_call(func, &[], "call_expect_start_failed");
+
+    let original_ptr = call_result
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_pointer_value();
+
+    let start_offset = env.ptr_int().const_int(START_OFFSET, false);
+    let offset = write_header(env, original_ptr, start_offset, message, region);
+
+    let one = env.ptr_int().const_int(1, false);
+    write_state(env, original_ptr, one, offset)
+}
+
 #[derive(Clone, Debug, Copy)]
 enum WhenRecursive<'a> {
     Unreachable,
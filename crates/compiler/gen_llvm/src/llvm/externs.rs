@@ -1,5 +1,5 @@
 use crate::llvm::bitcode::call_void_bitcode_fn;
-use crate::llvm::build::{add_func, get_panic_msg_ptr, C_CALL_CONV};
+use crate::llvm::build::{add_func, get_panic_msg_ptr, get_panic_tag_ptr, C_CALL_CONV};
 use crate::llvm::build::{CCReturn, Env, FunctionSpec};
 use inkwell::module::Linkage;
 use inkwell::types::BasicType;
@@ -21,14 +21,30 @@ pub fn add_default_roc_externs(env: &Env<'_, '_, '_>) {
 
     match env.mode {
         super::build::LlvmBackendMode::CliTest => {
-            // expose this function
-            if let Some(fn_val) = module.get_function("set_shared_buffer") {
-                fn_val.set_linkage(Linkage::External);
+            // expose these functions
+            for name in ["set_shared_buffer", "set_gen_seed"] {
+                if let Some(fn_val) = module.get_function(name) {
+                    fn_val.set_linkage(Linkage::External);
+                }
+            }
+        }
+        _ => {
+            // remove these functions from the module
+            for name in ["set_shared_buffer", "set_gen_seed"] {
+                if let Some(fn_val) = module.get_function(name) {
+                    unsafe { fn_val.delete() };
+                }
             }
         }
+    }
+
+    match env.mode {
+        super::build::LlvmBackendMode::BinaryDev => {
+            // keep the default implementation; a host can still provide its own `roc_dbg`
+        }
         _ => {
-            // remove this function from the module
-            if let Some(fn_val) = module.get_function("set_shared_buffer") {
+            // nothing reports a `dbg` to the host in these modes
+            if let Some(fn_val) = module.get_function("roc_dbg") {
                 unsafe { fn_val.delete() };
             }
         }
@@ -172,8 +188,8 @@ pub fn add_sjlj_roc_panic(env: &Env<'_, '_, '_>) {
         let mut params = fn_val.get_param_iter();
         let ptr_arg = params.next().unwrap();
 
-        // in debug mode, this is assumed to be NullTerminatedString
-        let _tag_id_arg = params.next().unwrap();
+        // both kinds of panic pass a null-terminated string; the tag tells them apart
+        let tag_id_arg = params.next().unwrap();
 
         debug_assert!(params.next().is_none());
 
@@ -187,8 +203,9 @@ pub fn add_sjlj_roc_panic(env: &Env<'_, '_, '_>) {
 
         builder.position_at_end(entry);
 
-        // write our error message pointer
+        // write our error message pointer and the kind of panic
         env.builder.build_store(get_panic_msg_ptr(env), ptr_arg);
+        env.builder.build_store(get_panic_tag_ptr(env), tag_id_arg);
 
         build_longjmp_call(env);
 
@@ -71,13 +71,81 @@ impl<'ctx> PointerToRefcount<'ctx> {
         }
     }
 
+    /// A seamless slice of a list points into the middle of its allocation. It marks itself
+    /// with the sign bit of its capacity, and stores the data pointer of the allocation,
+    /// shifted right by one, in the remaining bits of its capacity.
     fn from_list_wrapper(env: &Env<'_, 'ctx, '_>, list_wrapper: StructValue<'ctx>) -> Self {
-        let data_ptr = env
-            .builder
+        let builder = env.builder;
+
+        let capacity = builder
+            .build_extract_value(list_wrapper, Builtin::WRAPPER_CAPACITY, "read_list_cap")
+            .unwrap()
+            .into_int_value();
+
+        let elements = builder
             .build_extract_value(list_wrapper, Builtin::WRAPPER_PTR, "read_list_ptr")
             .unwrap()
             .into_pointer_value();
 
+        let is_seamless_slice = builder.build_int_compare(
+            IntPredicate::SLT,
+            capacity,
+            env.ptr_int().const_zero(),
+            "is_seamless_slice",
+        );
+
+        // shifting left also clears the sign bit
+        let slice_elements = builder.build_int_to_ptr(
+            builder.build_left_shift(capacity, env.ptr_int().const_int(1, false), "shl"),
+            elements.get_type(),
+            "slice_elements",
+        );
+
+        let data_ptr = builder
+            .build_select(is_seamless_slice, slice_elements, elements, "data_ptr")
+            .into_pointer_value();
+
+        Self::from_ptr_to_data(env, data_ptr)
+    }
+
+    /// A seamless slice of a big string points into the middle of its allocation. It marks
+    /// itself with the sign bit of its length, and stores the data pointer of the allocation,
+    /// shifted right by one, in its capacity.
+    fn from_str_wrapper(env: &Env<'_, 'ctx, '_>, str_wrapper: StructValue<'ctx>) -> Self {
+        let builder = env.builder;
+
+        let length = builder
+            .build_extract_value(str_wrapper, Builtin::WRAPPER_LEN, "read_str_len")
+            .unwrap()
+            .into_int_value();
+
+        let capacity = builder
+            .build_extract_value(str_wrapper, Builtin::WRAPPER_CAPACITY, "read_str_capacity")
+            .unwrap()
+            .into_int_value();
+
+        let elements = builder
+            .build_extract_value(str_wrapper, Builtin::WRAPPER_PTR, "read_str_ptr")
+            .unwrap()
+            .into_pointer_value();
+
+        let is_seamless_slice = builder.build_int_compare(
+            IntPredicate::SLT,
+            length,
+            env.ptr_int().const_zero(),
+            "is_seamless_slice",
+        );
+
+        let slice_elements = builder.build_int_to_ptr(
+            builder.build_left_shift(capacity, env.ptr_int().const_int(1, false), "shl"),
+            elements.get_type(),
+            "slice_elements",
+        );
+
+        let data_ptr = builder
+            .build_select(is_seamless_slice, slice_elements, elements, "data_ptr")
+            .into_pointer_value();
+
         Self::from_ptr_to_data(env, data_ptr)
     }
 
@@ -857,7 +925,7 @@ fn modify_refcount_str_help<'a, 'ctx, 'env>(
     builder.build_conditional_branch(is_big_and_non_empty, modification_block, cont_block);
     builder.position_at_end(modification_block);
 
-    let refcount_ptr = PointerToRefcount::from_list_wrapper(env, str_wrapper);
+    let refcount_ptr = PointerToRefcount::from_str_wrapper(env, str_wrapper);
     let call_mode = mode_to_call_mode(fn_val, mode);
     refcount_ptr.modify(call_mode, layout, env);
 
@@ -1,3 +1,4 @@
+use crate::llvm::build::PanicTagId;
 use std::ffi::CStr;
 use std::mem::MaybeUninit;
 use std::os::raw::c_char;
@@ -33,13 +34,28 @@ impl<T: Default> Default for RocCallResult<T> {
 }
 
 impl<T: Sized> From<RocCallResult<T>> for Result<T, String> {
+    fn from(call_result: RocCallResult<T>) -> Self {
+        let result: Result<T, (String, PanicTagId)> = call_result.into();
+
+        result.map_err(|(message, _)| message)
+    }
+}
+
+/// Like the conversion to `Result<T, String>`, but also says whether the panic
+/// came from a `crash` in user code or from the compiler
+impl<T: Sized> From<RocCallResult<T>> for Result<T, (String, PanicTagId)> {
     fn from(call_result: RocCallResult<T>) -> Self {
         match call_result.tag {
             0 => Ok(unsafe { call_result.value.assume_init() }),
-            _ => Err({
+            tag => Err({
                 let raw = unsafe { CStr::from_ptr(call_result.error_msg) };
+                let message = raw.to_str().unwrap().to_owned();
+
+                // the tag is one more than the tag given to roc_panic
+                let panic_tag = PanicTagId::try_from((tag - 1) as u32)
+                    .unwrap_or(PanicTagId::NullTerminatedString);
 
-                raw.to_str().unwrap().to_owned()
+                (message, panic_tag)
             }),
         }
     }
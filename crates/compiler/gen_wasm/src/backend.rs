@@ -2,7 +2,7 @@ use bitvec::vec::BitVec;
 use bumpalo::collections::{String, Vec};
 
 use code_builder::Align;
-use roc_builtins::bitcode::{FloatWidth, IntWidth};
+use roc_builtins::bitcode::{self, FloatWidth, IntWidth};
 use roc_collections::all::MutMap;
 use roc_error_macros::internal_error;
 use roc_module::low_level::{LowLevel, LowLevelWrapperType};
@@ -714,6 +714,11 @@ impl<'a> WasmBackend<'a> {
             Stmt::Expect { .. } => todo!("expect is not implemented in the wasm backend"),
             Stmt::ExpectFx { .. } => todo!("expect-fx is not implemented in the wasm backend"),
 
+            // `dbg` needs a host that can render values, so it is skipped on this backend
+            Stmt::Dbg { remainder, .. } => self.stmt(remainder),
+
+            Stmt::Crash { message, .. } => self.stmt_crash(*message),
+
             Stmt::RuntimeError(msg) => self.stmt_runtime_error(msg),
         }
     }
@@ -1002,6 +1007,17 @@ impl<'a> WasmBackend<'a> {
         self.code_builder.unreachable_();
     }
 
+    fn stmt_crash(&mut self, message: Symbol) {
+        // The Zig builtin makes a zero-terminated copy of the message
+        // and passes it to roc_panic with the UserCrash tag
+        let num_wasm_args = self.storage.get(&message).arg_types(CallConv::Zig).len();
+        self.storage
+            .load_symbol_zig(&mut self.code_builder, message);
+        self.call_host_fn_after_loading_args(bitcode::STR_CRASH, num_wasm_args, false);
+
+        self.code_builder.unreachable_();
+    }
+
     /**********************************************************
 
             EXPRESSIONS
@@ -1389,6 +1405,37 @@ impl<'a> WasmBackend<'a> {
         );
     }
 
+    /// Call a helper procedure that hashes a data structure (not numbers or Str)
+    /// If this is the first call for this Layout, it will generate the IR for the procedure.
+    pub fn call_hash_specialized(
+        &mut self,
+        arguments: &'a [Symbol],
+        arg_layout: &Layout<'a>,
+        ret_symbol: Symbol,
+        ret_storage: &StoredValue,
+    ) {
+        let ident_ids = self
+            .interns
+            .all_ident_ids
+            .get_mut(&self.env.module_id)
+            .unwrap();
+
+        let (specialized_call_expr, new_specializations) = self
+            .helper_proc_gen
+            .call_specialized_hash(ident_ids, arg_layout, arguments);
+
+        for (spec_sym, spec_layout) in new_specializations.into_iter() {
+            self.register_helper_proc(spec_sym, spec_layout, ProcSource::Helper);
+        }
+
+        self.expr(
+            ret_symbol,
+            self.env.arena.alloc(specialized_call_expr),
+            &Layout::u64(),
+            ret_storage,
+        );
+    }
+
     /*******************************************************************
      * Structs
      *******************************************************************/
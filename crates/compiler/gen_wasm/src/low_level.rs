@@ -296,6 +296,14 @@ impl<'a> LowLevelCall<'a> {
             StrRepeat => self.load_args_and_call_zig(backend, bitcode::STR_REPEAT),
             StrAppendScalar => self.load_args_and_call_zig(backend, bitcode::STR_APPEND_SCALAR),
             StrTrim => self.load_args_and_call_zig(backend, bitcode::STR_TRIM),
+            StrGraphemes => self.load_args_and_call_zig(backend, bitcode::STR_GRAPHEMES),
+            StrGraphemeByteOffset => {
+                self.load_args_and_call_zig(backend, bitcode::STR_GRAPHEME_BYTE_OFFSET)
+            }
+            StrToUpper => self.load_args_and_call_zig(backend, bitcode::STR_TO_UPPER),
+            StrToLower => self.load_args_and_call_zig(backend, bitcode::STR_TO_LOWER),
+            StrToNfc => self.load_args_and_call_zig(backend, bitcode::STR_TO_NFC),
+            StrToNfd => self.load_args_and_call_zig(backend, bitcode::STR_TO_NFD),
             StrGetScalarUnsafe => {
                 self.load_args_and_call_zig(backend, bitcode::STR_GET_SCALAR_UNSAFE)
             }
@@ -324,25 +332,7 @@ impl<'a> LowLevelCall<'a> {
                 _ => internal_error!("invalid storage for List"),
             },
 
-            ListGetCapacity => match backend.storage.get(&self.arguments[0]) {
-                StoredValue::StackMemory { location, .. } => {
-                    let (local_id, offset) =
-                        location.local_and_offset(backend.storage.stack_frame_pointer);
-                    backend.code_builder.get_local(local_id);
-                    // List is stored as (pointer, length, capacity),
-                    // with each of those fields being 4 bytes on wasm.
-                    // So the capacity is 8 bytes after the start of the struct.
-                    //
-                    // WRAPPER_CAPACITY represents the index of the capacity field
-                    // (which is 2 as of the writing of this comment). If the field order
-                    // ever changes, WRAPPER_CAPACITY should be updated and this logic should
-                    // continue to work even though this comment may become inaccurate.
-                    backend
-                        .code_builder
-                        .i32_load(Align::Bytes4, offset + (4 * Builtin::WRAPPER_CAPACITY));
-                }
-                _ => internal_error!("invalid storage for List"),
-            },
+            ListGetCapacity => self.load_args_and_call_zig(backend, bitcode::LIST_CAPACITY),
 
             ListIsUnique => self.load_args_and_call_zig(backend, bitcode::LIST_IS_UNIQUE),
 
@@ -1585,6 +1575,130 @@ impl<'a> LowLevelCall<'a> {
                 }
                 _ => panic_ret_type(),
             },
+            NumAtan2 | NumSinh | NumCosh | NumTanh | NumExp | NumExp2 | NumLog2 | NumLog10
+            | NumHypot | NumFma => {
+                let intrinsic = match self.lowlevel {
+                    NumAtan2 => &bitcode::NUM_ATAN2,
+                    NumSinh => &bitcode::NUM_SINH,
+                    NumCosh => &bitcode::NUM_COSH,
+                    NumTanh => &bitcode::NUM_TANH,
+                    NumExp => &bitcode::NUM_EXP,
+                    NumExp2 => &bitcode::NUM_EXP2,
+                    NumLog2 => &bitcode::NUM_LOG2,
+                    NumLog10 => &bitcode::NUM_LOG10,
+                    NumHypot => &bitcode::NUM_HYPOT,
+                    NumFma => &bitcode::NUM_FMA,
+                    _ => unreachable!(),
+                };
+                match (self.ret_layout, self.lowlevel) {
+                    (Layout::Builtin(Builtin::Float(width)), _) => {
+                        self.load_args_and_call_zig(backend, &intrinsic[width]);
+                    }
+                    (Layout::Builtin(Builtin::Decimal), NumAtan2) => {
+                        self.load_args_and_call_zig(backend, bitcode::DEC_ATAN2);
+                    }
+                    (Layout::Builtin(Builtin::Decimal), NumHypot) => {
+                        self.load_args_and_call_zig(backend, bitcode::DEC_HYPOT);
+                    }
+                    _ => panic_ret_type(),
+                }
+            }
+            NumCopySign => match CodeGenNumType::from(self.ret_layout) {
+                F32 => {
+                    self.load_args(backend);
+                    backend.code_builder.f32_copysign();
+                }
+                F64 => {
+                    self.load_args(backend);
+                    backend.code_builder.f64_copysign();
+                }
+                Decimal => self.load_args_and_call_zig(backend, bitcode::DEC_COPY_SIGN),
+                _ => panic_ret_type(),
+            },
+            NumTrunc => {
+                let arg_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
+                let int_width = match self.ret_layout {
+                    Layout::Builtin(Builtin::Int(width)) => width,
+                    _ => panic_ret_type(),
+                };
+
+                if let I128 = CodeGenNumType::from(self.ret_layout) {
+                    match arg_type {
+                        F32 => {
+                            self.load_args_and_call_zig(backend, &bitcode::NUM_TRUNC_F32[int_width])
+                        }
+                        F64 => {
+                            self.load_args_and_call_zig(backend, &bitcode::NUM_TRUNC_F64[int_width])
+                        }
+                        _ => internal_error!("Invalid argument type for trunc: {:?}", arg_type),
+                    }
+                    return;
+                }
+
+                self.load_args(backend);
+
+                // Wasm's float-to-int conversions already round towards zero
+                match (
+                    CodeGenNumType::from(self.ret_layout),
+                    int_width.is_signed(),
+                    arg_type,
+                ) {
+                    (I32, true, F32) => backend.code_builder.i32_trunc_s_f32(),
+                    (I32, true, F64) => backend.code_builder.i32_trunc_s_f64(),
+                    (I32, false, F32) => backend.code_builder.i32_trunc_u_f32(),
+                    (I32, false, F64) => backend.code_builder.i32_trunc_u_f64(),
+                    (I64, true, F32) => backend.code_builder.i64_trunc_s_f32(),
+                    (I64, true, F64) => backend.code_builder.i64_trunc_s_f64(),
+                    (I64, false, F32) => backend.code_builder.i64_trunc_u_f32(),
+                    (I64, false, F64) => backend.code_builder.i64_trunc_u_f64(),
+                    _ => internal_error!("Invalid argument type for trunc: {:?}", arg_type),
+                }
+            }
+            NumIsNan | NumIsInfinite => {
+                // Compare the bits without the sign against those of infinity.
+                // NaN has all exponent bits set and a nonzero mantissa, so it's bigger.
+                let arg_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
+                let is_nan = self.lowlevel == NumIsNan;
+                match arg_type {
+                    F32 => {
+                        self.load_args(backend);
+                        backend.code_builder.i32_reinterpret_f32();
+                        backend.code_builder.i32_const(0x7fff_ffff);
+                        backend.code_builder.i32_and();
+                        backend.code_builder.i32_const(0x7f80_0000);
+                        if is_nan {
+                            backend.code_builder.i32_gt_u();
+                        } else {
+                            backend.code_builder.i32_eq();
+                        }
+                    }
+                    F64 => {
+                        self.load_args(backend);
+                        backend.code_builder.i64_reinterpret_f64();
+                        backend.code_builder.i64_const(0x7fff_ffff_ffff_ffff);
+                        backend.code_builder.i64_and();
+                        backend.code_builder.i64_const(0x7ff0_0000_0000_0000);
+                        if is_nan {
+                            backend.code_builder.i64_gt_u();
+                        } else {
+                            backend.code_builder.i64_eq();
+                        }
+                    }
+                    // Fixed-point numbers are never NaN or infinite
+                    Decimal => backend.code_builder.i32_const(0),
+                    _ => internal_error!(
+                        "Invalid argument type for {:?}: {:?}",
+                        self.lowlevel,
+                        arg_type
+                    ),
+                }
+            }
+            NumDecRoundTo => self.load_args_and_call_zig(backend, bitcode::DEC_ROUND_TO),
+            NumDecToStrFixed => self.load_args_and_call_zig(backend, bitcode::DEC_TO_STR_FIXED),
+            NumDecToScaledI128 => self.load_args_and_call_zig(backend, bitcode::DEC_TO_SCALED_I128),
+            NumDecFromScaledI128 => {
+                self.load_args_and_call_zig(backend, bitcode::DEC_FROM_SCALED_I128)
+            }
             NumBytesToU16 => self.load_args_and_call_zig(backend, bitcode::NUM_BYTES_TO_U16),
             NumBytesToU32 => self.load_args_and_call_zig(backend, bitcode::NUM_BYTES_TO_U32),
             NumBitwiseAnd => {
@@ -1717,6 +1831,239 @@ impl<'a> LowLevelCall<'a> {
                     _ => panic_ret_type(),
                 }
             }
+            NumRotateLeftBy | NumRotateRightBy => {
+                let num = self.arguments[0];
+                let bits = self.arguments[1];
+                let is_left = self.lowlevel == NumRotateLeftBy;
+                match CodeGenNumType::from(self.ret_layout) {
+                    I32 => {
+                        let int_width = match self.ret_layout {
+                            Layout::Builtin(Builtin::Int(w)) => w,
+                            x => internal_error!(
+                                "Invalid return layout for {:?}: {:?}",
+                                self.lowlevel,
+                                x
+                            ),
+                        };
+                        let bit_width = 8 * int_width.stack_size() as i32;
+                        if bit_width < 32 {
+                            // Wasm only rotates whole i32's, so build the rotation from two shifts
+                            // of the zero-extended number, then wrap back to the narrow width.
+                            let mask = (1 << bit_width) - 1;
+                            let load_amount = |backend: &mut WasmBackend<'a>| {
+                                backend
+                                    .storage
+                                    .load_symbols(&mut backend.code_builder, &[bits]);
+                                backend.code_builder.i32_const(bit_width - 1);
+                                backend.code_builder.i32_and();
+                            };
+
+                            backend
+                                .storage
+                                .load_symbols(&mut backend.code_builder, &[num]);
+                            backend.code_builder.i32_const(mask);
+                            backend.code_builder.i32_and();
+                            load_amount(backend);
+                            if is_left {
+                                backend.code_builder.i32_shl();
+                            } else {
+                                backend.code_builder.i32_shr_u();
+                            }
+
+                            backend
+                                .storage
+                                .load_symbols(&mut backend.code_builder, &[num]);
+                            backend.code_builder.i32_const(mask);
+                            backend.code_builder.i32_and();
+                            backend.code_builder.i32_const(bit_width);
+                            load_amount(backend);
+                            backend.code_builder.i32_sub();
+                            if is_left {
+                                backend.code_builder.i32_shr_u();
+                            } else {
+                                backend.code_builder.i32_shl();
+                            }
+
+                            backend.code_builder.i32_or();
+                            self.wrap_small_int(backend, int_width);
+                        } else {
+                            backend
+                                .storage
+                                .load_symbols(&mut backend.code_builder, &[num, bits]);
+                            if is_left {
+                                backend.code_builder.i32_rotl();
+                            } else {
+                                backend.code_builder.i32_rotr();
+                            }
+                        }
+                    }
+                    I64 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
+                        if is_left {
+                            backend.code_builder.i64_rotl();
+                        } else {
+                            backend.code_builder.i64_rotr();
+                        }
+                    }
+                    I128 => {
+                        let int_width = match self.ret_layout {
+                            Layout::Builtin(Builtin::Int(w)) => w,
+                            x => internal_error!(
+                                "Invalid return layout for {:?}: {:?}",
+                                self.lowlevel,
+                                x
+                            ),
+                        };
+                        let name = if is_left {
+                            &bitcode::NUM_ROTATE_LEFT_BY[int_width]
+                        } else {
+                            &bitcode::NUM_ROTATE_RIGHT_BY[int_width]
+                        };
+                        self.load_args_and_call_zig(backend, name);
+                    }
+                    _ => panic_ret_type(),
+                }
+            }
+            NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits => {
+                // The result is a U8, so look at the argument's layout to find the bit width
+                let num = self.arguments[0];
+                let arg_layout = backend.storage.symbol_layouts[&num];
+                let bit_width =
+                    8 * arg_layout.stack_size(backend.env.layout_interner, TARGET_INFO) as i32;
+                match CodeGenNumType::from(arg_layout) {
+                    I32 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+                        // i8 and i16 may be sign-extended in their i32, so ignore the high bits.
+                        // For trailing zeros, set the bit just past the top so we never count them.
+                        if bit_width < 32 {
+                            if self.lowlevel == NumCountTrailingZeroBits {
+                                backend.code_builder.i32_const(1 << bit_width);
+                                backend.code_builder.i32_or();
+                            } else {
+                                backend.code_builder.i32_const((1 << bit_width) - 1);
+                                backend.code_builder.i32_and();
+                            }
+                        }
+                        match self.lowlevel {
+                            NumCountLeadingZeroBits => {
+                                backend.code_builder.i32_clz();
+                                if bit_width < 32 {
+                                    backend.code_builder.i32_const(32 - bit_width);
+                                    backend.code_builder.i32_sub();
+                                }
+                            }
+                            NumCountTrailingZeroBits => backend.code_builder.i32_ctz(),
+                            _ => backend.code_builder.i32_popcnt(),
+                        }
+                    }
+                    I64 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+                        match self.lowlevel {
+                            NumCountLeadingZeroBits => backend.code_builder.i64_clz(),
+                            NumCountTrailingZeroBits => backend.code_builder.i64_ctz(),
+                            _ => backend.code_builder.i64_popcnt(),
+                        }
+                        backend.code_builder.i32_wrap_i64();
+                    }
+                    I128 => {
+                        let int_width = match arg_layout {
+                            Layout::Builtin(Builtin::Int(w)) => w,
+                            x => internal_error!(
+                                "Invalid argument layout for {:?}: {:?}",
+                                self.lowlevel,
+                                x
+                            ),
+                        };
+                        let name = match self.lowlevel {
+                            NumCountLeadingZeroBits => &bitcode::NUM_COUNT_LEADING_ZERO_BITS,
+                            NumCountTrailingZeroBits => &bitcode::NUM_COUNT_TRAILING_ZERO_BITS,
+                            _ => &bitcode::NUM_COUNT_ONE_BITS,
+                        };
+                        self.load_args_and_call_zig(backend, &name[int_width]);
+                    }
+                    x => {
+                        internal_error!("Invalid argument layout for {:?}: {:?}", self.lowlevel, x)
+                    }
+                }
+            }
+            NumSwapBytes => {
+                let num = self.arguments[0];
+                let int_width = match self.ret_layout {
+                    Layout::Builtin(Builtin::Int(w)) => w,
+                    x => internal_error!("Invalid return layout for {:?}: {:?}", self.lowlevel, x),
+                };
+                match int_width.stack_size() {
+                    1 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+                    }
+                    2 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+                        backend.code_builder.i32_const(0xff);
+                        backend.code_builder.i32_and();
+                        backend.code_builder.i32_const(8);
+                        backend.code_builder.i32_shl();
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+                        backend.code_builder.i32_const(8);
+                        backend.code_builder.i32_shr_u();
+                        backend.code_builder.i32_const(0xff);
+                        backend.code_builder.i32_and();
+                        backend.code_builder.i32_or();
+                        self.wrap_small_int(backend, int_width);
+                    }
+                    4 => {
+                        // Each pair of bytes that swap places is a single rotation apart
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+                        backend.code_builder.i32_const(0x00ff_00ff);
+                        backend.code_builder.i32_and();
+                        backend.code_builder.i32_const(8);
+                        backend.code_builder.i32_rotr();
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num]);
+                        backend.code_builder.i32_const(0xff00_ff00_u32 as i32);
+                        backend.code_builder.i32_and();
+                        backend.code_builder.i32_const(8);
+                        backend.code_builder.i32_rotl();
+                        backend.code_builder.i32_or();
+                    }
+                    8 => {
+                        // Each pair of bytes that swap places is a single rotation apart
+                        let groups: [(u64, i64); 4] = [
+                            (0x0000_00ff_0000_00ff, 56),
+                            (0x0000_ff00_0000_ff00, 40),
+                            (0x00ff_0000_00ff_0000, 24),
+                            (0xff00_0000_ff00_0000, 8),
+                        ];
+                        for (i, (mask, rotl_bits)) in groups.into_iter().enumerate() {
+                            backend
+                                .storage
+                                .load_symbols(&mut backend.code_builder, &[num]);
+                            backend.code_builder.i64_const(mask as i64);
+                            backend.code_builder.i64_and();
+                            backend.code_builder.i64_const(rotl_bits);
+                            backend.code_builder.i64_rotl();
+                            if i > 0 {
+                                backend.code_builder.i64_or();
+                            }
+                        }
+                    }
+                    _ => self.load_args_and_call_zig(backend, &bitcode::NUM_SWAP_BYTES[int_width]),
+                }
+            }
             NumIntCast => {
                 self.load_args(backend);
                 let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
@@ -1852,7 +2199,10 @@ impl<'a> LowLevelCall<'a> {
                 backend.storage.load_symbols(code_builder, self.arguments);
             }
 
-            Hash => todo!("{:?}", self.lowlevel),
+            Hash => self.hash(backend),
+
+            // `roc test` doesn't run on wasm, so nothing sets the seed
+            GenSeed => backend.code_builder.i64_const(0),
 
             Eq | NotEq => self.eq_or_neq(backend),
 
@@ -1944,6 +2294,42 @@ impl<'a> LowLevelCall<'a> {
         }
     }
 
+    /// Hashing
+    /// Primitive values are hashed by Zig functions. Everything else gets a generated helper proc,
+    /// which hashes each part of the value in turn, threading the seed through.
+    fn hash(&self, backend: &mut WasmBackend<'a>) {
+        let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]]
+            .runtime_representation(backend.env.layout_interner);
+
+        let zig_fn_name: Option<&'a str> = match arg_layout {
+            Layout::Builtin(Builtin::Int(int_width)) => Some(&bitcode::DICT_HASH_INT[int_width]),
+            Layout::Builtin(Builtin::Float(float_width)) => {
+                Some(&bitcode::DICT_HASH_FLOAT[float_width])
+            }
+            Layout::Builtin(Builtin::Decimal) => Some(&bitcode::DICT_HASH_INT[IntWidth::I128]),
+            Layout::Builtin(Builtin::Bool) => Some(&bitcode::DICT_HASH_INT[IntWidth::U8]),
+            Layout::Builtin(Builtin::Str) => Some(bitcode::DICT_HASH_STR),
+            _ => None,
+        };
+
+        match zig_fn_name {
+            Some(name) => self.load_args_and_call_zig(backend, name),
+            None => {
+                // Don't want Zig calling convention here, we're calling internal Roc functions
+                backend
+                    .storage
+                    .load_symbols(&mut backend.code_builder, self.arguments);
+
+                backend.call_hash_specialized(
+                    self.arguments,
+                    &arg_layout,
+                    self.ret_symbol,
+                    &self.ret_storage,
+                );
+            }
+        }
+    }
+
     fn eq_or_neq_number(&self, backend: &mut WasmBackend<'a>) {
         use StoredValue::*;
 
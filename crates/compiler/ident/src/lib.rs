@@ -308,6 +308,24 @@ impl Clone for IdentStr {
     }
 }
 
+impl serde::Serialize for IdentStr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for IdentStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(IdentStr::from)
+    }
+}
+
 impl Drop for IdentStr {
     fn drop(&mut self) {
         if !self.is_empty() && !self.is_small_str() {
@@ -230,6 +230,9 @@ impl Phase for LatePhase<'_> {
                     Content::Structure(FlatType::Func(..))
                 ));
 
+                // The specialization may be polymorphic, like `Dict k v`.
+                instantiate_rigids(target_subs, our_ambient_function_var);
+
                 our_ambient_function_var
             }
         }
@@ -23,6 +23,8 @@ const MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::ENCODE, "Encode.roc"),
     (ModuleId::DECODE, "Decode.roc"),
     (ModuleId::JSON, "Json.roc"),
+    (ModuleId::HASH, "Hash.roc"),
+    (ModuleId::GEN, "Gen.roc"),
 ];
 
 fn main() {
@@ -14,10 +14,11 @@ const SKIP_SUBS_CACHE: bool = {
     }
 };
 
+pub use roc_load_internal::cache;
 pub use roc_load_internal::docs;
 pub use roc_load_internal::file::{
-    EntryPoint, ExecutionMode, Expectations, LoadConfig, LoadResult, LoadStart, LoadedModule,
-    LoadingProblem, MonomorphizedModule, Phase, Threading,
+    EntryPoint, ExecutionMode, ExpectSource, Expectations, LoadConfig, LoadResult, LoadStart,
+    LoadedModule, LoadingProblem, MonomorphizedModule, Phase, Threading,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -51,6 +52,7 @@ pub fn load_single_threaded<'a>(
         cached_subs,
         render,
         exec_mode,
+        None,
     )
 }
 
@@ -161,8 +163,6 @@ const BOOL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Bool.dat")) as &[_
 const RESULT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Result.dat")) as &[_];
 const LIST: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/List.dat")) as &[_];
 const STR: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Str.dat")) as &[_];
-const DICT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Dict.dat")) as &[_];
-const SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Set.dat")) as &[_];
 const BOX: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Box.dat")) as &[_];
 const NUM: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Num.dat")) as &[_];
 
@@ -184,10 +184,10 @@ fn read_cached_subs() -> MutMap<ModuleId, (Subs, Vec<(Symbol, Variable)>)> {
 
         output.insert(ModuleId::LIST, deserialize_help(LIST));
         output.insert(ModuleId::STR, deserialize_help(STR));
-        output.insert(ModuleId::DICT, deserialize_help(DICT));
-
-        output.insert(ModuleId::SET, deserialize_help(SET));
         output.insert(ModuleId::BOX, deserialize_help(BOX));
+
+        // Dict and Set are not cached, because they rely on ability implementations, which
+        // the cache does not store yet.
     }
 
     output
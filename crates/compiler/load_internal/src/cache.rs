@@ -0,0 +1,387 @@
+//! An on-disk cache of checked modules, so that loading a program again only needs to parse,
+//! canonicalize and solve the modules that changed (or whose dependencies changed) since the last
+//! time it was loaded.
+//!
+//! Each entry is keyed by a hash of the module's source, the keys of the modules it imports, and
+//! the compiler that wrote it. An entry holds everything the loader would otherwise compute for the
+//! module up to and including solving: its canonical declarations, aliases, abilities and
+//! expectations, and its solved `Subs`. It also holds the names of the module and of the modules it (transitively)
+//! imports, since `ModuleId`s are handed out in the order in which modules are discovered, so an
+//! entry is only used if they still refer to the same modules.
+//!
+//! The header of an entry records the length and a checksum of the rest of it, and an entry that
+//! doesn't match them (e.g. because it was cut short) is treated as a miss.
+
+use roc_can::abilities::{AbilitiesStore, PendingAbilitiesStore};
+use roc_can::expr::Declarations;
+use roc_collections::{default_hasher, MutMap, MutSet, VecMap, VecSet};
+use roc_module::symbol::{
+    IdentIds, ModuleId, PQModuleName, PackageModuleIds, PackageQualified, Symbol,
+};
+use roc_region::all::Region;
+use roc_types::subs::{Content, FlatType, Subs, Variable};
+use roc_types::types::Alias;
+use serde::{Deserialize, Serialize};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+/// Change this whenever the layout of an entry, or of `Subs`, changes.
+const FORMAT_VERSION: u64 = 6;
+const MAGIC: &[u8; 8] = b"ROCSUBS\0";
+/// The magic bytes, followed by the length and the checksum of the payload.
+const HEADER_LEN: usize = MAGIC.len() + 16;
+const COMPILER_VERSION: &str = include_str!("../../../../version.txt");
+
+/// The directory `roc check`, `roc test` and `roc dev` cache modules in: `$ROC_CACHE_DIR` if it is set (setting it to the
+/// empty string turns the cache off), and otherwise `roc/modules` in the user's cache directory.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    match env::var_os("ROC_CACHE_DIR") {
+        Some(dir) if dir.is_empty() => None,
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => {
+            let base = match env::var_os("XDG_CACHE_HOME") {
+                Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+                _ if cfg!(windows) => PathBuf::from(env::var_os("LOCALAPPDATA")?),
+                _ => PathBuf::from(env::var_os("HOME")?).join(".cache"),
+            };
+
+            Some(base.join("roc").join("modules"))
+        }
+    }
+}
+
+/// The results of canonicalizing a module, as far as the modules that import it, and solving it,
+/// need them.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CachedDefs {
+    pub ident_ids: IdentIds,
+    pub references: References,
+    pub aliases: MutMap<Symbol, (bool, Alias)>,
+    pub abilities_store: PendingAbilitiesStore,
+    pub declarations: Declarations,
+    pub loc_expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+    pub loc_dbgs: VecMap<Region, Variable>,
+    pub loc_crashes: VecSet<Region>,
+}
+
+/// What a module imports and uses, so that its unused imports are reported even when it comes from
+/// the cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct References {
+    pub exposed_imports: MutMap<Symbol, Region>,
+    pub referenced_values: VecSet<Symbol>,
+    pub referenced_types: VecSet<Symbol>,
+}
+
+/// The results of solving a module.
+#[derive(Debug)]
+pub(crate) struct CachedSolution {
+    pub subs: Subs,
+    pub exposed_vars_by_symbol: Vec<(Symbol, Variable)>,
+    pub abilities_store: AbilitiesStore,
+}
+
+#[derive(Debug)]
+struct Registered {
+    key: u64,
+    /// The module and the non-builtin modules it transitively imports.
+    modules: MutSet<ModuleId>,
+}
+
+#[derive(Debug)]
+pub(crate) struct DiskCache {
+    dir: PathBuf,
+    /// Identifies the compiler, since entries can only be read by the compiler that wrote them.
+    salt: u64,
+    registered: MutMap<ModuleId, Registered>,
+}
+
+impl DiskCache {
+    /// `with_doctests` tells whether the loaded modules include the examples in their docs (as
+    /// they do for `roc test`), since those end up in their declarations.
+    pub(crate) fn new(dir: PathBuf, with_doctests: bool) -> Self {
+        let mut hasher = default_hasher().build_hasher();
+
+        FORMAT_VERSION.hash(&mut hasher);
+        COMPILER_VERSION.hash(&mut hasher);
+        with_doctests.hash(&mut hasher);
+
+        // Every build from source has the same version, so also tell them apart by executable.
+        if let Ok(metadata) = env::current_exe().and_then(fs::metadata) {
+            metadata.len().hash(&mut hasher);
+            metadata.modified().ok().hash(&mut hasher);
+        }
+
+        DiskCache {
+            dir,
+            salt: hasher.finish(),
+            registered: MutMap::default(),
+        }
+    }
+
+    /// Compute the key of a module from its source and the keys of its dependencies. If one of
+    /// its (non-builtin) dependencies has no key, then neither does the module, and it is never
+    /// cached.
+    pub(crate) fn register(
+        &mut self,
+        module_id: ModuleId,
+        src: &[u8],
+        deps_by_name: &MutMap<PQModuleName, ModuleId>,
+    ) {
+        let mut deps: Vec<_> = deps_by_name
+            .iter()
+            .map(|(name, dep_id)| (render_name(name), *dep_id))
+            .collect();
+
+        deps.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut hasher = default_hasher().build_hasher();
+        let mut modules = MutSet::default();
+
+        self.salt.hash(&mut hasher);
+        src.hash(&mut hasher);
+        modules.insert(module_id);
+
+        for (name, dep_id) in deps {
+            name.hash(&mut hasher);
+
+            if !dep_id.is_builtin() {
+                match self.registered.get(&dep_id) {
+                    Some(dep) => {
+                        dep.key.hash(&mut hasher);
+                        modules.extend(dep.modules.iter().copied());
+                    }
+                    None => return,
+                }
+            }
+        }
+
+        let key = hasher.finish();
+
+        self.registered
+            .insert(module_id, Registered { key, modules });
+    }
+
+    pub(crate) fn read(
+        &self,
+        module_id: ModuleId,
+        module_ids: &PackageModuleIds,
+    ) -> Option<(CachedDefs, CachedSolution)> {
+        let registered = self.registered.get(&module_id)?;
+        let file = fs::read(self.entry_path(registered.key)).ok()?;
+
+        // Subs::deserialize reinterprets the bytes in place, so they need to be aligned.
+        let mut words = vec![0u64; (file.len() + 7) / 8];
+        let bytes =
+            unsafe { std::slice::from_raw_parts_mut(words.as_mut_ptr() as *mut u8, file.len()) };
+
+        bytes.copy_from_slice(&file);
+
+        if bytes.get(..MAGIC.len())? != MAGIC {
+            return None;
+        }
+
+        let mut offset = MAGIC.len();
+        let payload_len = read_u64(bytes, &mut offset)? as usize;
+        let checksum = read_u64(bytes, &mut offset)?;
+
+        if payload_len != bytes.len() - HEADER_LEN || checksum != payload_checksum(&bytes[offset..])
+        {
+            return None;
+        }
+
+        let module_count = read_u64(bytes, &mut offset)? as usize;
+        let available_modules: Vec<_> = module_ids.available_modules().collect();
+
+        if module_count != registered.modules.len() {
+            return None;
+        }
+
+        for _ in 0..module_count {
+            let index = read_u64(bytes, &mut offset)? as usize;
+            let name_len = read_u64(bytes, &mut offset)? as usize;
+            let name = bytes.get(offset..offset + name_len)?;
+
+            offset += round_to_word(name_len);
+
+            match available_modules.get(index) {
+                Some(available) if render_name(available).as_bytes() == name => {}
+                _ => return None,
+            }
+        }
+
+        let defs_len = read_u64(bytes, &mut offset)? as usize;
+        let (defs, abilities_store) =
+            bincode::deserialize(bytes.get(offset..offset + defs_len)?).ok()?;
+
+        offset += round_to_word(defs_len);
+
+        let (subs, exposed_vars_by_symbol) = Subs::deserialize(bytes.get(offset..)?);
+
+        let solution = CachedSolution {
+            subs,
+            exposed_vars_by_symbol: exposed_vars_by_symbol.to_vec(),
+            abilities_store,
+        };
+
+        Some((defs, solution))
+    }
+
+    /// Write a checked module to the cache, unless it can't be cached.
+    pub(crate) fn write(
+        &self,
+        module_id: ModuleId,
+        module_ids: &PackageModuleIds,
+        defs: &CachedDefs,
+        subs: &Subs,
+        exposed_vars_by_symbol: &[(Symbol, Variable)],
+        abilities_store: &AbilitiesStore,
+    ) -> io::Result<()> {
+        let registered = match self.registered.get(&module_id) {
+            Some(registered) => registered,
+            None => return Ok(()),
+        };
+
+        match referenced_modules(subs, exposed_vars_by_symbol) {
+            Some(referenced) if referenced.is_subset(&registered.modules) => {}
+            _ => return Ok(()),
+        }
+
+        let names: Vec<_> = module_ids
+            .available_modules()
+            .enumerate()
+            .filter(|(_, name)| match module_ids.get_id(name) {
+                Some(id) => registered.modules.contains(&id),
+                None => false,
+            })
+            .map(|(index, name)| (index, render_name(name)))
+            .collect();
+
+        if names.len() != registered.modules.len() {
+            return Ok(());
+        }
+
+        // Runtime errors and erroneous types can't be serialized, so modules that have them are
+        // never cached.
+        let defs_bytes = match bincode::serialize(&(defs, abilities_store)) {
+            Ok(bytes) => bytes,
+            Err(_) => return Ok(()),
+        };
+
+        let mut buf = Vec::with_capacity(4096);
+
+        // The length and the checksum of the payload are filled in once it has been written.
+        buf.extend_from_slice(MAGIC);
+        buf.resize(HEADER_LEN, 0);
+        buf.extend_from_slice(&(names.len() as u64).to_ne_bytes());
+
+        for (index, name) in names {
+            buf.extend_from_slice(&(index as u64).to_ne_bytes());
+            buf.extend_from_slice(&(name.len() as u64).to_ne_bytes());
+            buf.extend_from_slice(name.as_bytes());
+            buf.resize(buf.len() + round_to_word(name.len()) - name.len(), 0);
+        }
+
+        buf.extend_from_slice(&(defs_bytes.len() as u64).to_ne_bytes());
+        buf.extend_from_slice(&defs_bytes);
+        buf.resize(
+            buf.len() + round_to_word(defs_bytes.len()) - defs_bytes.len(),
+            0,
+        );
+
+        subs.serialize(exposed_vars_by_symbol, &mut buf)?;
+
+        let payload_len = (buf.len() - HEADER_LEN) as u64;
+        let checksum = payload_checksum(&buf[HEADER_LEN..]);
+
+        buf[MAGIC.len()..][..8].copy_from_slice(&payload_len.to_ne_bytes());
+        buf[MAGIC.len() + 8..][..8].copy_from_slice(&checksum.to_ne_bytes());
+
+        fs::create_dir_all(&self.dir)?;
+
+        // Write to a temporary file first, so that a concurrent `roc check` never reads half an
+        // entry.
+        let path = self.entry_path(registered.key);
+        let temp_path = path.with_extension(format!("tmp{}", std::process::id()));
+
+        fs::write(&temp_path, &buf)?;
+        fs::rename(&temp_path, &path)
+    }
+
+    fn entry_path(&self, key: u64) -> PathBuf {
+        self.dir.join(format!("{:016x}.subs", key))
+    }
+}
+
+/// The non-builtin modules whose symbols appear in the solved types, or `None` if the types can't
+/// be cached, e.g. because they mention derived implementations, whose symbols only mean
+/// something within a single run of the compiler.
+fn referenced_modules(
+    subs: &Subs,
+    exposed_vars_by_symbol: &[(Symbol, Variable)],
+) -> Option<MutSet<ModuleId>> {
+    let mut symbols: Vec<Symbol> = exposed_vars_by_symbol
+        .iter()
+        .map(|(symbol, _)| *symbol)
+        .collect();
+
+    // This also covers the abilities that type variables are bound to.
+    symbols.extend(subs.symbol_names.iter().copied());
+    symbols.extend(subs.unspecialized_lambda_sets.iter().map(|uls| uls.1));
+
+    for index in 0..subs.len() {
+        // Safety: every index below subs.len() is a variable in subs
+        let var = unsafe { Variable::from_index(index as u32) };
+
+        match subs.get_content_without_compacting(var) {
+            Content::Alias(symbol, ..)
+            | Content::Structure(FlatType::Apply(symbol, _))
+            | Content::Structure(FlatType::FunctionOrTagUnion(_, symbol, _)) => {
+                symbols.push(*symbol)
+            }
+            Content::Error | Content::Structure(FlatType::Erroneous(_)) => return None,
+            _ => {}
+        }
+    }
+
+    let mut modules = MutSet::default();
+
+    for symbol in symbols {
+        match symbol.module_id() {
+            ModuleId::DERIVED_SYNTH | ModuleId::DERIVED_GEN => return None,
+            module_id if module_id.is_builtin() => {}
+            module_id => {
+                modules.insert(module_id);
+            }
+        }
+    }
+
+    Some(modules)
+}
+
+fn payload_checksum(payload: &[u8]) -> u64 {
+    let mut hasher = default_hasher().build_hasher();
+
+    hasher.write(payload);
+    hasher.finish()
+}
+
+fn render_name(name: &PQModuleName) -> String {
+    match name {
+        PackageQualified::Unqualified(name) => name.as_str().to_string(),
+        PackageQualified::Qualified(package, name) => format!("{}.{}", package, name.as_str()),
+    }
+}
+
+fn read_u64(bytes: &[u8], offset: &mut usize) -> Option<u64> {
+    let word = bytes.get(*offset..*offset + 8)?;
+
+    *offset += 8;
+
+    Some(u64::from_ne_bytes(word.try_into().unwrap()))
+}
+
+fn round_to_word(len: usize) -> usize {
+    (len + 7) / 8 * 8
+}
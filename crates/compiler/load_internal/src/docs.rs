@@ -393,12 +393,12 @@ fn ability_member_type_to_docs(
             let ta = type_to_docs(false, ta.value);
             let has_clauses = has_clauses
                 .iter()
-                .map(|hc| {
-                    let ast::HasClause { var, ability } = hc.value;
-                    (
-                        var.value.extract_spaces().item.to_string(),
-                        type_to_docs(false, ability.value),
-                    )
+                .flat_map(|hc| {
+                    let ast::HasClause { var, abilities } = hc.value;
+                    let var_name = var.value.extract_spaces().item;
+                    abilities.iter().map(move |ability| {
+                        (var_name.to_string(), type_to_docs(false, ability.value))
+                    })
                 })
                 .collect();
 
@@ -11,7 +11,7 @@ use roc_can::expr::Declarations;
 use roc_can::expr::PendingDerives;
 use roc_can::module::{
     canonicalize_module_defs, ExposedByModule, ExposedForModule, ExposedModuleTypes, Module,
-    ResolvedImplementations,
+    ResolvedImplementations, RigidVariables,
 };
 use roc_collections::{default_hasher, BumpMap, MutMap, MutSet, VecMap, VecSet};
 use roc_constrain::module::constrain_module;
@@ -48,7 +48,9 @@ use roc_reporting::report::{Annotation, RenderTarget};
 use roc_solve::module::{extract_module_owned_implementations, Solved, SolvedModule};
 use roc_solve_problem::TypeError;
 use roc_target::TargetInfo;
-use roc_types::subs::{ExposedTypesStorageSubs, Subs, VarStore, Variable};
+use roc_types::subs::{
+    instantiate_rigids, ExposedTypesStorageSubs, LambdaSet, Subs, VarStore, Variable,
+};
 use roc_types::types::{Alias, AliasKind};
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
@@ -61,6 +63,7 @@ use std::str::from_utf8_unchecked;
 use std::sync::Arc;
 use std::{env, fs};
 
+use crate::cache::{CachedDefs, CachedSolution, DiskCache, References};
 use crate::work::Dependencies;
 pub use crate::work::Phase;
 
@@ -90,6 +93,8 @@ pub struct LoadConfig {
     pub render: RenderTarget,
     pub threading: Threading,
     pub exec_mode: ExecutionMode,
+    /// Where to cache the solved types of modules between runs.
+    pub cache_dir: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -120,6 +125,9 @@ struct ModuleCache<'a> {
     parsed: MutMap<ModuleId, ParsedModule<'a>>,
     aliases: MutMap<ModuleId, MutMap<Symbol, (bool, Alias)>>,
     pending_abilities: MutMap<ModuleId, PendingAbilitiesStore>,
+    cached_defs: MutMap<ModuleId, CachedDefs>,
+    cached_solutions: MutMap<ModuleId, CachedSolution>,
+    references: MutMap<ModuleId, References>,
     constrained: MutMap<ModuleId, ConstrainedModule>,
     typechecked: MutMap<ModuleId, TypeCheckedModule<'a>>,
     found_specializations: MutMap<ModuleId, FoundSpecializationsModule<'a>>,
@@ -178,6 +186,8 @@ impl Default for ModuleCache<'_> {
             ENCODE,
             DECODE,
             JSON,
+            HASH,
+            GEN,
         }
 
         Self {
@@ -186,6 +196,9 @@ impl Default for ModuleCache<'_> {
             parsed: Default::default(),
             aliases: Default::default(),
             pending_abilities: Default::default(),
+            cached_defs: Default::default(),
+            cached_solutions: Default::default(),
+            references: Default::default(),
             constrained: Default::default(),
             typechecked: Default::default(),
             found_specializations: Default::default(),
@@ -254,9 +267,43 @@ fn start_phase<'a>(
             }
             Phase::Parse => {
                 // parse the file
-                let header = state.module_cache.headers.remove(&module_id).unwrap();
+                let mut header = state.module_cache.headers.remove(&module_id).unwrap();
+
+                // Interface modules can only depend on other interface modules, so they only
+                // change when their source or their dependencies do. Builtins have a cache of
+                // their own, and the root module is the one being worked on, so we always check
+                // it.
+                let use_disk_cache = !module_id.is_builtin()
+                    && module_id != state.root_id
+                    && matches!(header.module_name, ModuleNameEnum::Interface(_));
+
+                if let Some(disk_cache) = state.disk_cache.as_mut().filter(|_| use_disk_cache) {
+                    let src = header.parse_state.original_bytes();
 
-                BuildTask::Parse { header }
+                    disk_cache.register(module_id, src, &header.deps_by_name);
+
+                    if let Some((defs, solution)) =
+                        disk_cache.read(module_id, &state.arc_modules.lock())
+                    {
+                        // Parsing, canonicalization and solving all use what was cached.
+                        header.module_timing.cache_hit = true;
+
+                        state.module_cache.cached_defs.insert(module_id, defs);
+                        state
+                            .module_cache
+                            .cached_solutions
+                            .insert(module_id, solution);
+                    }
+                }
+
+                // `roc test` also runs the examples in the docs of the modules it tests
+                let include_doctests =
+                    matches!(state.exec_mode, ExecutionMode::Test) && !module_id.is_builtin();
+
+                BuildTask::Parse {
+                    header,
+                    include_doctests,
+                }
             }
             Phase::CanonicalizeAndConstrain => {
                 // canonicalize the file
@@ -355,6 +402,8 @@ fn start_phase<'a>(
                     state.cached_subs.lock().contains_key(&module_id)
                 };
 
+                let cached_defs = state.module_cache.cached_defs.remove(&module_id);
+
                 BuildTask::CanonicalizeAndConstrain {
                     parsed,
                     dep_idents,
@@ -363,6 +412,7 @@ fn start_phase<'a>(
                     aliases,
                     abilities_store,
                     skip_constraint_gen,
+                    cached_defs,
                 }
             }
 
@@ -384,6 +434,7 @@ fn start_phase<'a>(
                 } = constrained;
 
                 let derived_module = SharedDerivedModule::clone(&state.derived_module);
+                let cached_solution = state.module_cache.cached_solutions.remove(&module_id);
 
                 BuildTask::solve_module(
                     module,
@@ -398,6 +449,7 @@ fn start_phase<'a>(
                     dep_idents,
                     declarations,
                     state.cached_subs.clone(),
+                    cached_solution,
                     derived_module,
                 )
             }
@@ -492,24 +544,8 @@ fn start_phase<'a>(
                             procs_base,
                             layout_cache,
                             module_timing,
-                            abilities_store,
                         } = found_specializations;
 
-                        let our_exposed_types = state
-                            .exposed_types
-                            .get(&module_id)
-                            .unwrap_or_else(|| {
-                                internal_error!("Exposed types for {:?} missing", module_id)
-                            })
-                            .clone();
-
-                        // Add our abilities to the world.
-                        state.world_abilities.insert(
-                            module_id,
-                            abilities_store,
-                            our_exposed_types.exposed_types_storage_subs,
-                        );
-
                         (ident_ids, subs, procs_base, layout_cache, module_timing)
                     } else {
                         let LateSpecializationsModule {
@@ -665,7 +701,6 @@ struct FoundSpecializationsModule<'a> {
     procs_base: ProcsBase<'a>,
     subs: Subs,
     module_timing: ModuleTiming,
-    abilities_store: AbilitiesStore,
 }
 
 #[derive(Debug)]
@@ -679,8 +714,18 @@ struct LateSpecializationsModule<'a> {
 
 #[derive(Debug, Default)]
 pub struct ToplevelExpects {
-    pub pure: VecMap<Symbol, Region>,
-    pub fx: VecMap<Symbol, Region>,
+    pub pure: VecMap<Symbol, ExpectSource>,
+    pub fx: VecMap<Symbol, ExpectSource>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExpectSource {
+    /// The expect, extended with the comment before it so that it is shown in failure messages
+    pub region: Region,
+    /// The comments (and blank lines) between the previous definition and the expect
+    pub preceding_comment: Region,
+    /// Whether the expect was generated from an example in a doc comment
+    pub is_doctest: bool,
 }
 
 #[derive(Debug)]
@@ -716,6 +761,10 @@ pub struct Expectations {
     pub subs: roc_types::subs::Subs,
     pub path: PathBuf,
     pub expectations: VecMap<Region, Vec<(Symbol, Variable)>>,
+    /// The type of the value passed to each `dbg`, by the region of that value
+    pub dbgs: VecMap<Region, Variable>,
+    /// The region of the message passed to each `crash`
+    pub crashes: VecSet<Region>,
     pub ident_ids: IdentIds,
 }
 
@@ -757,9 +806,13 @@ struct ParsedModule<'a> {
     module_name: ModuleNameEnum<'a>,
     symbols_from_requires: Vec<(Loc<Symbol>, Loc<TypeAnnotation<'a>>)>,
     header_for: HeaderFor<'a>,
+    /// The doc comment examples that were left out of `parsed_defs` because they don't parse
+    skipped_doctests: Vec<Region>,
 }
 
 type LocExpects = VecMap<Region, Vec<(Symbol, Variable)>>;
+type LocDbgs = VecMap<Region, Variable>;
+type LocCrashes = VecSet<Region>;
 
 /// A message sent out _from_ a worker thread,
 /// representing a result of work done, or a request for further work
@@ -779,6 +832,8 @@ enum Msg<'a> {
         module_timing: ModuleTiming,
         abilities_store: AbilitiesStore,
         loc_expects: LocExpects,
+        loc_dbgs: LocDbgs,
+        loc_crashes: LocCrashes,
     },
     FinishedAllTypeChecking {
         solved_subs: Solved<Subs>,
@@ -924,9 +979,11 @@ struct State<'a> {
 
     make_specializations_pass: MakeSpecializationsPass,
 
-    // cached subs (used for builtin modules, could include packages in the future too)
+    // cached subs (used for builtin modules, and for modules found in the disk cache)
     cached_subs: CachedSubs,
 
+    disk_cache: Option<DiskCache>,
+
     layout_interner: Arc<GlobalInterner<'a, Layout<'a>>>,
 }
 
@@ -948,10 +1005,20 @@ impl<'a> State<'a> {
         render: RenderTarget,
         number_of_workers: usize,
         exec_mode: ExecutionMode,
+        cache_dir: Option<PathBuf>,
     ) -> Self {
         let arc_shorthands = Arc::new(Mutex::new(MutMap::default()));
 
-        let dependencies = Dependencies::new(exec_mode.goal_phase());
+        // Modules are cached up to solving; a cached module is specialized from its cached
+        // declarations and solved `Subs`, just like one that was solved in this run.
+        let disk_cache = cache_dir.map(|dir| {
+            // see the `include_doctests` of `BuildTask::Parse`
+            let with_doctests = matches!(exec_mode, ExecutionMode::Test);
+
+            DiskCache::new(dir, with_doctests)
+        });
+
+        let dependencies = Dependencies::new(exec_mode.goal_phase(), disk_cache.is_some());
 
         Self {
             root_id,
@@ -976,6 +1043,7 @@ impl<'a> State<'a> {
             timings: MutMap::default(),
             layout_caches: std::vec::Vec::with_capacity(number_of_workers),
             cached_subs: Arc::new(Mutex::new(cached_subs)),
+            disk_cache,
             render,
             exec_mode,
             make_specializations_pass: MakeSpecializationsPass::Pass(1),
@@ -993,6 +1061,9 @@ pub struct ModuleTiming {
     pub canonicalize: Duration,
     pub constrain: Duration,
     pub solve: Duration,
+    /// Whether the module's defs and solved types were loaded from a cache, rather than
+    /// canonicalized and solved from scratch
+    pub cache_hit: bool,
     pub find_specializations: Duration,
     // indexed by make specializations pass
     pub make_specializations: Vec<Duration>,
@@ -1012,6 +1083,7 @@ impl ModuleTiming {
             canonicalize: Duration::default(),
             constrain: Duration::default(),
             solve: Duration::default(),
+            cache_hit: false,
             find_specializations: Duration::default(),
             make_specializations: Vec::with_capacity(2),
             start_time,
@@ -1032,6 +1104,7 @@ impl ModuleTiming {
             canonicalize,
             constrain,
             solve,
+            cache_hit: _,
             find_specializations,
             make_specializations,
             start_time,
@@ -1067,6 +1140,7 @@ enum BuildTask<'a> {
     },
     Parse {
         header: ModuleHeader<'a>,
+        include_doctests: bool,
     },
     CanonicalizeAndConstrain {
         parsed: ParsedModule<'a>,
@@ -1076,6 +1150,7 @@ enum BuildTask<'a> {
         aliases: MutMap<Symbol, Alias>,
         abilities_store: PendingAbilitiesStore,
         skip_constraint_gen: bool,
+        cached_defs: Option<CachedDefs>,
     },
     Solve {
         module: Module,
@@ -1089,6 +1164,7 @@ enum BuildTask<'a> {
         declarations: Declarations,
         dep_idents: IdentIdsByModule,
         cached_subs: CachedSubs,
+        cached_solution: Option<CachedSolution>,
         derived_module: SharedDerivedModule,
     },
     BuildPendingSpecializations {
@@ -1191,6 +1267,7 @@ pub fn load_and_typecheck_str<'a>(
         render,
         threading,
         exec_mode: ExecutionMode::Check,
+        cache_dir: None,
     };
 
     match load(arena, load_start, exposed_types, cached_subs, load_config)? {
@@ -1285,7 +1362,7 @@ impl<'a> LoadStart<'a> {
                     return Err(LoadingProblem::FormattedReport(buf));
                 }
                 Err(LoadingProblem::FileProblem { filename, error }) => {
-                    let buf = to_file_problem_report(&filename, error);
+                    let buf = to_file_problem_report(&filename, error, render);
                     return Err(LoadingProblem::FormattedReport(buf));
                 }
                 Err(e) => return Err(e),
@@ -1438,6 +1515,7 @@ pub fn load<'a>(
             cached_subs,
             load_config.render,
             load_config.exec_mode,
+            load_config.cache_dir,
         ),
         Threads::Many(threads) => load_multi_threaded(
             arena,
@@ -1448,6 +1526,7 @@ pub fn load<'a>(
             load_config.render,
             threads,
             load_config.exec_mode,
+            load_config.cache_dir,
         ),
     }
 }
@@ -1462,6 +1541,7 @@ pub fn load_single_threaded<'a>(
     cached_subs: MutMap<ModuleId, (Subs, Vec<(Symbol, Variable)>)>,
     render: RenderTarget,
     exec_mode: ExecutionMode,
+    cache_dir: Option<PathBuf>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
         arc_modules,
@@ -1489,6 +1569,7 @@ pub fn load_single_threaded<'a>(
         render,
         number_of_workers,
         exec_mode,
+        cache_dir,
     );
 
     // We'll add tasks to this, and then worker threads will take tasks from it.
@@ -1591,7 +1672,7 @@ fn state_thread_step<'a>(
                     Ok(ControlFlow::Break(LoadResult::Monomorphized(monomorphized)))
                 }
                 Msg::FailedToReadFile { filename, error } => {
-                    let buf = to_file_problem_report(&filename, error);
+                    let buf = to_file_problem_report(&filename, error, state.render);
                     Err(LoadingProblem::FormattedReport(buf))
                 }
 
@@ -1666,6 +1747,7 @@ fn load_multi_threaded<'a>(
     render: RenderTarget,
     available_threads: usize,
     exec_mode: ExecutionMode,
+    cache_dir: Option<PathBuf>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
         arc_modules,
@@ -1708,6 +1790,7 @@ fn load_multi_threaded<'a>(
         render,
         num_workers,
         exec_mode,
+        cache_dir,
     );
 
     // an arena for every worker, stored in an arena-allocated bumpalo vec to make the lifetimes work
@@ -2147,6 +2230,7 @@ fn update<'a>(
                 extend_header_with_builtin(&mut header, ModuleId::LIST);
                 extend_header_with_builtin(&mut header, ModuleId::ENCODE);
                 extend_header_with_builtin(&mut header, ModuleId::DECODE);
+                extend_header_with_builtin(&mut header, ModuleId::HASH);
             }
 
             state
@@ -2230,6 +2314,17 @@ fn update<'a>(
 
             report_unused_imported_modules(&mut state, module_id, &constrained_module);
 
+            if state.disk_cache.is_some() {
+                let module = &constrained_module.module;
+                let references = References {
+                    exposed_imports: module.exposed_imports.clone(),
+                    referenced_values: module.referenced_values.clone(),
+                    referenced_types: module.referenced_types.clone(),
+                };
+
+                state.module_cache.references.insert(module_id, references);
+            }
+
             state
                 .module_cache
                 .aliases
@@ -2263,27 +2358,74 @@ fn update<'a>(
             mut module_timing,
             abilities_store,
             loc_expects,
+            loc_dbgs,
+            loc_crashes,
         } => {
             log!("solved types for {:?}", module_id);
             module_timing.end_time = Instant::now();
 
+            if let Some(disk_cache) = &state.disk_cache {
+                // Unused imports are reported for cached modules too, so they are the only
+                // problems a cached module can have.
+                let cacheable = !module_timing.cache_hit
+                    && solved_module.problems.is_empty()
+                    && solved_subs.inner().problems.is_empty()
+                    && state.module_cache.can_problems[&module_id]
+                        .iter()
+                        .all(|problem| {
+                            matches!(
+                                problem,
+                                roc_problem::can::Problem::UnusedModuleImport(..)
+                                    | roc_problem::can::Problem::UnusedImport(..)
+                            )
+                        });
+
+                let references = state.module_cache.references.remove(&module_id);
+
+                if let Some(references) = references.filter(|_| cacheable) {
+                    let defs = CachedDefs {
+                        ident_ids: ident_ids.clone(),
+                        references,
+                        aliases: solved_module.aliases.clone(),
+                        abilities_store: state.module_cache.pending_abilities[&module_id].clone(),
+                        declarations: decls.clone(),
+                        loc_expects: loc_expects.clone(),
+                        loc_dbgs: loc_dbgs.clone(),
+                        loc_crashes: loc_crashes.clone(),
+                    };
+
+                    // The cache only saves time, so it's fine if we can't write to it.
+                    let _ = disk_cache.write(
+                        module_id,
+                        &state.arc_modules.lock(),
+                        &defs,
+                        solved_subs.inner(),
+                        &solved_module.exposed_vars_by_symbol,
+                        &abilities_store,
+                    );
+                }
+            }
+
             state
                 .module_cache
                 .type_problems
                 .insert(module_id, solved_module.problems);
 
-            let should_include_expects = !loc_expects.is_empty() && {
-                let modules = state.arc_modules.lock();
-                modules
-                    .package_eq(module_id, state.root_id)
-                    .expect("root or this module is not yet known - that's a bug!")
-            };
+            let should_include_expects =
+                (!loc_expects.is_empty() || !loc_dbgs.is_empty() || !loc_crashes.is_empty()) && {
+                    let modules = state.arc_modules.lock();
+                    modules
+                        .package_eq(module_id, state.root_id)
+                        .expect("root or this module is not yet known - that's a bug!")
+                };
 
             if should_include_expects {
                 let (path, _) = state.module_cache.sources.get(&module_id).unwrap();
 
                 let expectations = Expectations {
                     expectations: loc_expects,
+                    dbgs: loc_dbgs,
+                    crashes: loc_crashes,
                     subs: solved_subs.clone().into_inner(),
                     path: path.to_owned(),
                     ident_ids: ident_ids.clone(),
@@ -2415,6 +2557,9 @@ fn update<'a>(
                         .typechecked
                         .insert(module_id, typechecked);
                 } else {
+                    // Keep the declarations of every module we check, so tooling (e.g. the
+                    // language server) can find where the root module's imports are defined.
+                    state.declarations_by_id.insert(module_id, decls);
                     state.constrained_ident_ids.insert(module_id, ident_ids);
                     state.timings.insert(module_id, module_timing);
                 }
@@ -2467,13 +2612,27 @@ fn update<'a>(
                 .or_default()
                 .extend(procs_base.module_thunks.iter().copied());
 
+            let our_exposed_types = state
+                .exposed_types
+                .get(&module_id)
+                .unwrap_or_else(|| internal_error!("Exposed types for {:?} missing", module_id))
+                .clone();
+
+            // Add our abilities to the world. This happens before any module makes its
+            // specializations, since dependents make theirs first, and may need to resolve
+            // ability members of ours that are passed around as values (like `Decode.decoder`).
+            state.world_abilities.insert(
+                module_id,
+                abilities_store,
+                our_exposed_types.exposed_types_storage_subs,
+            );
+
             let found_specializations_module = FoundSpecializationsModule {
                 ident_ids,
                 layout_cache,
                 procs_base,
                 subs,
                 module_timing,
-                abilities_store,
             };
 
             state
@@ -2847,7 +3006,8 @@ fn finish_specialization<'a>(
                         }
                         Valid(To::NewPackage(p_or_p)) => p_or_p,
                         other => {
-                            let buf = to_missing_platform_report(state.root_id, other);
+                            let buf =
+                                to_missing_platform_report(state.root_id, other, state.render);
                             return Err(LoadingProblem::FormattedReport(buf));
                         }
                     };
@@ -3165,6 +3325,8 @@ fn load_module<'a>(
         "Encode", ModuleId::ENCODE
         "Decode", ModuleId::DECODE
         "Json", ModuleId::JSON
+        "Hash", ModuleId::HASH
+        "Gen", ModuleId::GEN
     }
 
     let (filename, opt_shorthand) = module_name_to_path(src_dir, module_name, arc_shorthands);
@@ -4006,6 +4168,7 @@ impl<'a> BuildTask<'a> {
         dep_idents: IdentIdsByModule,
         declarations: Declarations,
         cached_subs: CachedSubs,
+        cached_solution: Option<CachedSolution>,
         derived_module: SharedDerivedModule,
     ) -> Self {
         let exposed_by_module = exposed_types.retain_modules(imported_modules.keys());
@@ -4026,6 +4189,7 @@ impl<'a> BuildTask<'a> {
             dep_idents,
             module_timing,
             cached_subs,
+            cached_solution,
             derived_module,
         }
     }
@@ -4041,35 +4205,68 @@ fn synth_import(subs: &mut Subs, content: roc_types::subs::Content) -> Variable
     })
 }
 
-fn synth_list_len_type(subs: &mut Subs) -> Variable {
-    use roc_types::subs::{Content, FlatType, LambdaSet, OptVariable, SubsSlice, UnionLabels};
+/// Synthesizes the type of a `List` function, given its arguments and return
+/// type in terms of the element type `a` and `List a`.
+fn synth_list_fn_type<F>(subs: &mut Subs, symbol: Symbol, signature: F) -> Variable
+where
+    F: FnOnce(Variable, Variable) -> (Vec<Variable>, Variable),
+{
+    use roc_types::subs::{Content, FlatType, OptVariable, SubsSlice, UnionLabels};
 
-    // List.len : List a -> Nat
     let a = synth_import(subs, Content::FlexVar(None));
     let a_slice = SubsSlice::extend_new(&mut subs.variables, [a]);
     let list_a = synth_import(
         subs,
         Content::Structure(FlatType::Apply(Symbol::LIST_LIST, a_slice)),
     );
+    let (args, ret) = signature(a, list_a);
     let fn_var = synth_import(subs, Content::Error);
-    let solved_list_len = UnionLabels::insert_into_subs(subs, [(Symbol::LIST_LEN, [])]);
-    let clos_list_len = synth_import(
+    let solved = UnionLabels::insert_into_subs(subs, [(symbol, [])]);
+    let clos = synth_import(
         subs,
         Content::LambdaSet(LambdaSet {
-            solved: solved_list_len,
+            solved,
             recursion_var: OptVariable::NONE,
             unspecialized: SubsSlice::default(),
             ambient_function: fn_var,
         }),
     );
-    let fn_args_slice = SubsSlice::extend_new(&mut subs.variables, [list_a]);
+    let fn_args_slice = SubsSlice::extend_new(&mut subs.variables, args);
     subs.set_content(
         fn_var,
-        Content::Structure(FlatType::Func(fn_args_slice, clos_list_len, Variable::NAT)),
+        Content::Structure(FlatType::Func(fn_args_slice, clos, ret)),
     );
     fn_var
 }
 
+fn synth_list_len_type(subs: &mut Subs) -> Variable {
+    // List.len : List a -> Nat
+    synth_list_fn_type(subs, Symbol::LIST_LEN, |_, list_a| {
+        (vec![list_a], Variable::NAT)
+    })
+}
+
+fn synth_list_with_capacity_type(subs: &mut Subs) -> Variable {
+    // List.withCapacity : Nat -> List a
+    synth_list_fn_type(subs, Symbol::LIST_WITH_CAPACITY, |_, list_a| {
+        (vec![Variable::NAT], list_a)
+    })
+}
+
+fn synth_list_append_unsafe_type(subs: &mut Subs) -> Variable {
+    // List.appendUnsafe : List a, a -> List a
+    synth_list_fn_type(subs, Symbol::LIST_APPEND_UNSAFE, |a, list_a| {
+        (vec![list_a, a], list_a)
+    })
+}
+
+fn synth_list_get_unsafe_type(subs: &mut Subs) -> Variable {
+    // List.getUnsafe : List a, Nat -> a
+    synth_list_fn_type(subs, Symbol::LIST_GET_UNSAFE, |a, list_a| {
+        (vec![list_a, Variable::NAT], a)
+    })
+}
+
 pub fn add_imports(
     my_module: ModuleId,
     subs: &mut Subs,
@@ -4136,13 +4333,23 @@ pub fn add_imports(
 
     // Patch used symbols from circular dependencies.
     if my_module == ModuleId::NUM {
-        // Num needs List.len, but List imports Num.
-        let list_len_type = synth_list_len_type(subs);
-        def_types.push((
-            Symbol::LIST_LEN,
-            Loc::at_zero(Type::Variable(list_len_type)),
-        ));
-        import_variables.push(list_len_type);
+        // Num needs some List functions, but List imports Num.
+        let list_fn_types = [
+            (Symbol::LIST_LEN, synth_list_len_type(subs)),
+            (
+                Symbol::LIST_WITH_CAPACITY,
+                synth_list_with_capacity_type(subs),
+            ),
+            (
+                Symbol::LIST_APPEND_UNSAFE,
+                synth_list_append_unsafe_type(subs),
+            ),
+            (Symbol::LIST_GET_UNSAFE, synth_list_get_unsafe_type(subs)),
+        ];
+        for (symbol, list_fn_type) in list_fn_types {
+            def_types.push((symbol, Loc::at_zero(Type::Variable(list_fn_type))));
+            import_variables.push(list_fn_type);
+        }
     }
 
     // TODO: see if we can reduce the amount of specializations we need to import.
@@ -4190,8 +4397,16 @@ pub fn add_imports(
                 let copied_import = exposed_types
                     .storage_subs
                     .export_variable_to(ctx.subs, *var);
+                let lset_var = copied_import.variable;
+
+                // Specializations can be polymorphic, like `Dict k v`; their type variables must
+                // be instantiated at each use rather than stay rigid.
+                let LambdaSet {
+                    ambient_function, ..
+                } = ctx.subs.get_lambda_set(lset_var);
+                instantiate_rigids(ctx.subs, ambient_function);
 
-                copied_import.variable
+                lset_var
             }
             None => internal_error!("Imported module {:?} is not available", module),
         },
@@ -4313,6 +4528,7 @@ fn run_solve<'a>(
     decls: Declarations,
     dep_idents: IdentIdsByModule,
     cached_subs: CachedSubs,
+    cached_solution: Option<CachedSolution>,
     derived_module: SharedDerivedModule,
 ) -> Msg<'a> {
     let solve_start = Instant::now();
@@ -4324,10 +4540,29 @@ fn run_solve<'a>(
 
     let mut module = module;
     let loc_expects = std::mem::take(&mut module.loc_expects);
+    let loc_dbgs = std::mem::take(&mut module.loc_dbgs);
+    let loc_crashes = std::mem::take(&mut module.loc_crashes);
     let module = module;
 
     let (solved_subs, solved_implementations, exposed_vars_by_symbol, problems, abilities_store) = {
-        if module_id.is_builtin() {
+        if let Some(cached) = cached_solution {
+            let CachedSolution {
+                subs,
+                exposed_vars_by_symbol,
+                abilities_store,
+            } = cached;
+
+            let solved_implementations =
+                extract_module_owned_implementations(module_id, &abilities_store);
+
+            (
+                Solved(subs),
+                solved_implementations,
+                exposed_vars_by_symbol,
+                vec![],
+                abilities_store,
+            )
+        } else if module_id.is_builtin() {
             match cached_subs.lock().remove(&module_id) {
                 None => run_solve_solve(
                     exposed_for_module,
@@ -4395,6 +4630,8 @@ fn run_solve<'a>(
         module_timing,
         abilities_store,
         loc_expects,
+        loc_dbgs,
+        loc_crashes,
     }
 }
 
@@ -4459,7 +4696,12 @@ fn canonicalize_and_constrain<'a>(
     imported_abilities_state: PendingAbilitiesStore,
     parsed: ParsedModule<'a>,
     skip_constraint_gen: bool,
+    cached_defs: Option<CachedDefs>,
 ) -> CanAndCon {
+    if let Some(cached_defs) = cached_defs {
+        return cached_can_and_con(parsed, dep_idents, exposed_symbols, cached_defs);
+    }
+
     let canonicalize_start = Instant::now();
 
     let ParsedModule {
@@ -4472,6 +4714,7 @@ fn canonicalize_and_constrain<'a>(
         imported_modules,
         mut module_timing,
         symbols_from_requires,
+        skipped_doctests,
         ..
     } = parsed;
 
@@ -4575,7 +4818,12 @@ fn canonicalize_and_constrain<'a>(
             Vacant(vacant) => {
                 let should_include_builtin = matches!(
                     name.module_id(),
-                    ModuleId::ENCODE | ModuleId::DECODE | ModuleId::DICT | ModuleId::SET
+                    ModuleId::ENCODE
+                        | ModuleId::DECODE
+                        | ModuleId::DICT
+                        | ModuleId::SET
+                        | ModuleId::HASH
+                        | ModuleId::GEN
                 );
 
                 if !name.is_builtin() || should_include_builtin {
@@ -4595,6 +4843,8 @@ fn canonicalize_and_constrain<'a>(
         rigid_variables: module_output.rigid_variables,
         abilities_store: module_output.scope.abilities_store,
         loc_expects: module_output.loc_expects,
+        loc_dbgs: module_output.loc_dbgs,
+        loc_crashes: module_output.loc_crashes,
     };
 
     let constrained_module = ConstrainedModule {
@@ -4610,27 +4860,107 @@ fn canonicalize_and_constrain<'a>(
         pending_derives: module_output.pending_derives,
     };
 
+    let mut canonicalization_problems = module_output.problems;
+
+    canonicalization_problems.extend(
+        skipped_doctests
+            .into_iter()
+            .map(roc_problem::can::Problem::UnparsableDoctest),
+    );
+
     CanAndCon {
         constrained_module,
-        canonicalization_problems: module_output.problems,
+        canonicalization_problems,
         module_docs,
     }
 }
 
-fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, LoadingProblem<'a>> {
+/// Stand in for canonicalizing and constraining a module from the disk cache. Its declarations
+/// only need to be solved, and their solution was cached too.
+fn cached_can_and_con(
+    parsed: ParsedModule<'_>,
+    dep_idents: IdentIdsByModule,
+    exposed_symbols: VecSet<Symbol>,
+    cached_defs: CachedDefs,
+) -> CanAndCon {
+    let CachedDefs {
+        ident_ids,
+        references,
+        aliases,
+        abilities_store,
+        declarations,
+        loc_expects,
+        loc_dbgs,
+        loc_crashes,
+    } = cached_defs;
+
+    let module = Module {
+        module_id: parsed.module_id,
+        exposed_imports: references.exposed_imports,
+        exposed_symbols,
+        referenced_values: references.referenced_values,
+        referenced_types: references.referenced_types,
+        aliases,
+        rigid_variables: RigidVariables::default(),
+        abilities_store,
+        loc_expects,
+        loc_dbgs,
+        loc_crashes,
+    };
+
+    let constrained_module = ConstrainedModule {
+        module,
+        declarations,
+        imported_modules: parsed.imported_modules,
+        var_store: VarStore::default(),
+        constraints: Constraints::new(),
+        constraint: roc_can::constraint::Constraint::True,
+        ident_ids,
+        dep_idents,
+        module_timing: parsed.module_timing,
+        pending_derives: PendingDerives::default(),
+    };
+
+    CanAndCon {
+        constrained_module,
+        canonicalization_problems: Vec::new(),
+        module_docs: None,
+    }
+}
+
+fn parse<'a>(
+    arena: &'a Bump,
+    header: ModuleHeader<'a>,
+    include_doctests: bool,
+) -> Result<Msg<'a>, LoadingProblem<'a>> {
     let mut module_timing = header.module_timing;
     let parse_start = Instant::now();
     let source = header.parse_state.original_bytes();
     let parse_state = header.parse_state;
-    let parsed_defs = match module_defs().parse(arena, parse_state) {
-        Ok((_, success, _state)) => success,
-        Err((_, fail, state)) => {
-            return Err(LoadingProblem::ParsingFailed(
-                fail.into_file_error(header.module_path, &state),
-            ));
+
+    // A module from the disk cache is never canonicalized, so there is no need to parse it.
+    let mut parsed_defs = if module_timing.cache_hit {
+        Defs::default()
+    } else {
+        match module_defs().parse(arena, parse_state.clone()) {
+            Ok((_, success, _state)) => success,
+            Err((_, fail, state)) => {
+                return Err(LoadingProblem::ParsingFailed(
+                    fail.into_file_error(header.module_path, &state),
+                ));
+            }
         }
     };
 
+    let mut skipped_doctests = Vec::new();
+
+    if include_doctests && !module_timing.cache_hit {
+        // SAFETY: the module parsed, so its bytes are valid UTF-8
+        let src = unsafe { from_utf8_unchecked(source) };
+
+        skipped_doctests = roc_parse::doctest::push_doctests(arena, src, &mut parsed_defs);
+    }
+
     // Record the parse end time once, to avoid checking the time a second time
     // immediately afterward (for the beginning of canonicalization).
     let parse_end = Instant::now();
@@ -4669,6 +4999,7 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
         parsed_defs,
         symbols_from_requires,
         header_for,
+        skipped_doctests,
     };
 
     Ok(Msg::Parsed(parsed))
@@ -5121,7 +5452,13 @@ fn build_pending_specializations<'a>(
                 let expr_region = declarations.expressions[index].region;
                 let region = Region::span_across(&name_region, &expr_region);
 
-                toplevel_expects.pure.insert(symbol, region);
+                let expect = ExpectSource {
+                    region,
+                    preceding_comment: name_region,
+                    is_doctest: declarations.doctests.contains(&index),
+                };
+
+                toplevel_expects.pure.insert(symbol, expect);
                 procs_base.partial_procs.insert(symbol, proc);
             }
             ExpectationFx => {
@@ -5194,7 +5531,13 @@ fn build_pending_specializations<'a>(
                 let expr_region = declarations.expressions[index].region;
                 let region = Region::span_across(&name_region, &expr_region);
 
-                toplevel_expects.fx.insert(symbol, region);
+                let expect = ExpectSource {
+                    region,
+                    preceding_comment: name_region,
+                    is_doctest: false,
+                };
+
+                toplevel_expects.fx.insert(symbol, expect);
                 procs_base.partial_procs.insert(symbol, proc);
             }
         }
@@ -5350,7 +5693,10 @@ fn run_task<'a>(
             ident_ids_by_module,
         )
         .map(|(_, msg)| msg),
-        Parse { header } => parse(arena, header),
+        Parse {
+            header,
+            include_doctests,
+        } => parse(arena, header, include_doctests),
         CanonicalizeAndConstrain {
             parsed,
             module_ids,
@@ -5359,6 +5705,7 @@ fn run_task<'a>(
             aliases,
             abilities_store,
             skip_constraint_gen,
+            cached_defs,
         } => {
             let can_and_con = canonicalize_and_constrain(
                 arena,
@@ -5369,6 +5716,7 @@ fn run_task<'a>(
                 abilities_store,
                 parsed,
                 skip_constraint_gen,
+                cached_defs,
             );
 
             Ok(Msg::CanonicalizedAndConstrained(can_and_con))
@@ -5385,6 +5733,7 @@ fn run_task<'a>(
             declarations,
             dep_idents,
             cached_subs,
+            cached_solution,
             derived_module,
         } => Ok(run_solve(
             module,
@@ -5398,6 +5747,7 @@ fn run_task<'a>(
             declarations,
             dep_idents,
             cached_subs,
+            cached_solution,
             derived_module,
         )),
         BuildPendingSpecializations {
@@ -5463,7 +5813,7 @@ fn run_task<'a>(
     Ok(())
 }
 
-fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
+fn to_file_problem_report(filename: &Path, error: io::ErrorKind, render: RenderTarget) -> String {
     use roc_reporting::report::{Report, RocDocAllocator, Severity, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
 
@@ -5492,7 +5842,7 @@ fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
             ]);
 
             Report {
-                filename: "UNKNOWN.roc".into(),
+                filename: filename.to_path_buf(),
                 doc,
                 title: "FILE NOT FOUND".to_string(),
                 severity: Severity::RuntimeError,
@@ -5509,7 +5859,7 @@ fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
             ]);
 
             Report {
-                filename: "UNKNOWN.roc".into(),
+                filename: filename.to_path_buf(),
                 doc,
                 title: "FILE PERMISSION DENIED".to_string(),
                 severity: Severity::RuntimeError,
@@ -5529,7 +5879,7 @@ fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
             ]);
 
             Report {
-                filename: "UNKNOWN.roc".into(),
+                filename: filename.to_path_buf(),
                 doc,
                 title: "FILE PROBLEM".to_string(),
                 severity: Severity::RuntimeError,
@@ -5539,7 +5889,8 @@ fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
 
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
-    report.render_color_terminal(&mut buf, &alloc, &palette);
+    // The file couldn't be read, so there is no code to point at.
+    report.render(render, None, &mut buf, &alloc, &palette);
 
     buf
 }
@@ -5572,6 +5923,12 @@ fn to_parse_problem_report<'a>(
 
     let lines = LineInfo::new(src);
 
+    let region = problem
+        .problem
+        .problem
+        .get_region()
+        .map(|region| lines.convert_region(region));
+
     let report = parse_problem(
         &alloc,
         &lines,
@@ -5583,12 +5940,16 @@ fn to_parse_problem_report<'a>(
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
 
-    report.render(render, &mut buf, &alloc, &palette);
+    report.render(render, region, &mut buf, &alloc, &palette);
 
     buf
 }
 
-fn to_missing_platform_report(module_id: ModuleId, other: PlatformPath) -> String {
+fn to_missing_platform_report(
+    module_id: ModuleId,
+    other: PlatformPath,
+    render: RenderTarget,
+) -> String {
     use roc_reporting::report::{Report, RocDocAllocator, Severity, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
     use PlatformPath::*;
@@ -5667,7 +6028,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: PlatformPath) -> Strin
 
     let palette = DEFAULT_PALETTE;
     let mut buf = String::new();
-    report.render_color_terminal(&mut buf, &alloc, &palette);
+    report.render(render, None, &mut buf, &alloc, &palette);
 
     buf
 }
@@ -101,15 +101,21 @@ pub struct Dependencies<'a> {
     status: MutMap<Job<'a>, Status>,
 
     make_specializations_dependents: MakeSpecializationsDependents,
+
+    /// Whether a module is only parsed once its (non-builtin) dependencies are parsed.
+    /// The disk cache needs this, since it looks up a module when parsing starts, using the keys
+    /// of its dependencies.
+    parse_after_dependencies: bool,
 }
 
 impl<'a> Dependencies<'a> {
-    pub fn new(goal_phase: Phase) -> Self {
+    pub fn new(goal_phase: Phase, parse_after_dependencies: bool) -> Self {
         let mut deps = Self {
             waiting_for: Default::default(),
             notifies: Default::default(),
             status: Default::default(),
             make_specializations_dependents: Default::default(),
+            parse_after_dependencies,
         };
 
         if goal_phase >= Phase::MakeSpecializations {
@@ -146,6 +152,10 @@ impl<'a> Dependencies<'a> {
             // otherwise, we don't know whether an imported symbol is actually exposed
             self.add_dependency_help(module_id, dep, Phase::Parse, Phase::LoadHeader);
 
+            if self.parse_after_dependencies && !dep.is_builtin() {
+                self.add_dependency(module_id, dep, Phase::Parse);
+            }
+
             // to canonicalize a module, all its dependencies must be canonicalized
             self.add_dependency(module_id, dep, Phase::CanonicalizeAndConstrain);
 
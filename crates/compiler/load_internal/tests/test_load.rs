@@ -18,7 +18,9 @@ use crate::helpers::fixtures_dir;
 use bumpalo::Bump;
 use roc_can::module::ExposedByModule;
 use roc_load_internal::file::{ExecutionMode, LoadConfig, Threading};
-use roc_load_internal::file::{LoadResult, LoadStart, LoadedModule, LoadingProblem};
+use roc_load_internal::file::{
+    LoadResult, LoadStart, LoadedModule, LoadingProblem, MonomorphizedModule,
+};
 use roc_module::ident::ModuleName;
 use roc_module::symbol::{Interns, ModuleId};
 use roc_problem::can::Problem;
@@ -46,6 +48,7 @@ fn load_and_typecheck(
         render: RenderTarget::Generic,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Check,
+        cache_dir: None,
     };
 
     match roc_load_internal::file::load(
@@ -481,12 +484,12 @@ fn load_astar() {
     expect_types(
         loaded_module,
         hashmap! {
-            "findPath" => "{ costFunction : position, position -> F64, end : position, moveFunction : position -> Set position, start : position } -> Result (List position) [KeyNotFound]*",
-            "initialModel" => "position -> Model position",
-            "reconstructPath" => "Dict position position, position -> List position",
-            "updateCost" => "position, position, Model position -> Model position",
-            "cheapestOpen" => "(position -> F64), Model position -> Result position [KeyNotFound]*",
-            "astar" => "(position, position -> F64), (position -> Set position), position, Model position -> [Err [KeyNotFound]*, Ok (List position)]*",
+            "findPath" => "{ costFunction : position, position -> F64, end : position, moveFunction : position -> Set position, start : position } -> Result (List position) [KeyNotFound]* | position has Hash & Eq",
+            "initialModel" => "position -> Model position | position has Hash & Eq",
+            "reconstructPath" => "Dict position position, position -> List position | position has Hash & Eq",
+            "updateCost" => "position, position, Model position -> Model position | position has Hash & Eq",
+            "cheapestOpen" => "(position -> F64), Model position -> Result position [KeyNotFound]* | position has Hash & Eq",
+            "astar" => "(position, position -> F64), (position -> Set position), position, Model position -> [Err [KeyNotFound]*, Ok (List position)]* | position has Hash & Eq",
         },
     );
 }
@@ -527,6 +530,314 @@ fn iface_dep_types() {
     );
 }
 
+fn load_with_cache<'a>(
+    arena: &'a Bump,
+    filename: PathBuf,
+    cache_dir: &std::path::Path,
+) -> Result<LoadedModule, LoadingProblem<'a>> {
+    let load_start = LoadStart::from_path(arena, filename, RenderTarget::Generic)?;
+    let load_config = LoadConfig {
+        target_info: TARGET_INFO,
+        render: RenderTarget::Generic,
+        threading: Threading::Single,
+        exec_mode: ExecutionMode::Check,
+        cache_dir: Some(cache_dir.to_path_buf()),
+    };
+
+    match roc_load_internal::file::load(
+        arena,
+        load_start,
+        Default::default(),
+        Default::default(),
+        load_config,
+    )? {
+        LoadResult::Monomorphized(_) => unreachable!(""),
+        LoadResult::TypeChecked(module) => Ok(module),
+    }
+}
+
+fn cache_hits(loaded_module: &LoadedModule) -> Vec<String> {
+    let mut modules: Vec<_> = loaded_module
+        .timings
+        .iter()
+        .filter(|(_, timing)| timing.cache_hit)
+        .map(|(module_id, _)| loaded_module.interns.module_name(*module_id).to_string())
+        .collect();
+
+    modules.sort();
+    modules
+}
+
+fn load_tests_with_cache<'a>(
+    arena: &'a Bump,
+    filename: PathBuf,
+    cache_dir: &std::path::Path,
+) -> MonomorphizedModule<'a> {
+    let load_start = LoadStart::from_path(arena, filename, RenderTarget::Generic).unwrap();
+    let load_config = LoadConfig {
+        target_info: TARGET_INFO,
+        render: RenderTarget::Generic,
+        threading: Threading::Single,
+        exec_mode: ExecutionMode::Test,
+        cache_dir: Some(cache_dir.to_path_buf()),
+    };
+
+    match roc_load_internal::file::load(
+        arena,
+        load_start,
+        Default::default(),
+        Default::default(),
+        load_config,
+    )
+    .unwrap()
+    {
+        LoadResult::Monomorphized(module) => module,
+        LoadResult::TypeChecked(_) => unreachable!(""),
+    }
+}
+
+fn monomorphized_cache_hits(module: &MonomorphizedModule) -> Vec<String> {
+    let mut modules: Vec<_> = module
+        .timings
+        .iter()
+        .filter(|(_, timing)| timing.cache_hit)
+        .map(|(module_id, _)| module.interns.module_name(*module_id).to_string())
+        .collect();
+
+    modules.sort();
+    modules
+}
+
+#[test]
+fn iface_dep_types_from_cache() {
+    let cache_dir = roc_test_utils::TmpDir::new("tmp/iface_dep_types_from_cache");
+    let filename = fixtures_dir()
+        .join("interface_with_deps")
+        .join("Primary.roc");
+
+    let arena = Bump::new();
+    let loaded_module = load_with_cache(&arena, filename.clone(), cache_dir.path()).unwrap();
+
+    assert_eq!(cache_hits(&loaded_module), Vec::<String>::new());
+
+    let arena = Bump::new();
+    let loaded_module = load_with_cache(&arena, filename, cache_dir.path()).unwrap();
+
+    assert_eq!(
+        cache_hits(&loaded_module),
+        vec!["Dep1", "Dep2", "Dep3.Blah", "Res"]
+    );
+
+    expect_types(
+        loaded_module,
+        hashmap! {
+            "blah2" => "Float *",
+            "blah3" => "Str",
+            "str" => "Str",
+            "alwaysThree" => "* -> Float *",
+            "identity" => "a -> a",
+            "z" => "Float *",
+            "w" => "Dep1.Identity {}",
+            "succeed" => "a -> Dep1.Identity a",
+            "yay" => "Res.Res {} err",
+            "withDefault" => "Res.Res a err, a -> a",
+        },
+    );
+}
+
+#[test]
+fn truncated_cache_entries_are_ignored() {
+    let cache_dir = roc_test_utils::TmpDir::new("tmp/truncated_cache_entries_are_ignored");
+    let filename = fixtures_dir()
+        .join("interface_with_deps")
+        .join("Primary.roc");
+
+    let arena = Bump::new();
+    load_with_cache(&arena, filename.clone(), cache_dir.path()).unwrap();
+
+    let entries: Vec<_> = std::fs::read_dir(cache_dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+
+    assert_eq!(entries.len(), 4);
+
+    for entry in entries {
+        let bytes = std::fs::read(&entry).unwrap();
+
+        std::fs::write(&entry, &bytes[..bytes.len() / 2]).unwrap();
+    }
+
+    let arena = Bump::new();
+    let loaded_module = load_with_cache(&arena, filename.clone(), cache_dir.path()).unwrap();
+
+    assert_eq!(cache_hits(&loaded_module), Vec::<String>::new());
+
+    expect_types(
+        loaded_module,
+        hashmap! {
+            "blah2" => "Float *",
+            "blah3" => "Str",
+            "str" => "Str",
+            "alwaysThree" => "* -> Float *",
+            "identity" => "a -> a",
+            "z" => "Float *",
+            "w" => "Dep1.Identity {}",
+            "succeed" => "a -> Dep1.Identity a",
+            "yay" => "Res.Res {} err",
+            "withDefault" => "Res.Res a err, a -> a",
+        },
+    );
+
+    // The truncated entries were replaced, so the next check uses them again.
+    let arena = Bump::new();
+    let loaded_module = load_with_cache(&arena, filename, cache_dir.path()).unwrap();
+
+    assert_eq!(cache_hits(&loaded_module).len(), 4);
+}
+
+#[test]
+fn ability_modules_from_cache() {
+    let cache_dir = roc_test_utils::TmpDir::new("tmp/ability_modules_from_cache");
+    let src_dir = roc_test_utils::TmpDir::new("tmp/ability_modules_from_cache_src");
+
+    let modules = [
+        (
+            "Greet.roc",
+            indoc!(
+                r#"
+                interface Greet exposes [Greet, greet, greetTwice] imports []
+
+                Greet has greet : a -> Str | a has Greet
+
+                greetTwice = \a -> Str.concat (greet a) (greet a)
+                "#
+            ),
+        ),
+        (
+            "Person.roc",
+            indoc!(
+                r#"
+                interface Person exposes [Person, new] imports [Greet.{ Greet }]
+
+                Person := Str has [Greet { greet: greetPerson }]
+
+                new = \name -> @Person name
+
+                greetPerson = \@Person name -> "Hi, \(name)!"
+                "#
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r#"
+                interface Main exposes [main] imports [Greet, Person]
+
+                main = Greet.greetTwice (Person.new "Ayla")
+                "#
+            ),
+        ),
+    ];
+
+    for (name, source) in modules {
+        std::fs::write(src_dir.path().join(name), source).unwrap();
+    }
+
+    let filename = src_dir.path().join("Main.roc");
+
+    let arena = Bump::new();
+    let loaded_module = load_with_cache(&arena, filename.clone(), cache_dir.path()).unwrap();
+    let person_defs = |loaded_module: &LoadedModule| {
+        let (person_id, _) = loaded_module
+            .declarations_by_id
+            .iter()
+            .find(|(module_id, _)| {
+                loaded_module.interns.module_name(**module_id).as_str() == "Person"
+            })
+            .unwrap();
+
+        let declarations = &loaded_module.declarations_by_id[person_id];
+        let mut names: Vec<_> = declarations
+            .symbols
+            .iter()
+            .map(|symbol| symbol.value.as_str(&loaded_module.interns).to_string())
+            .collect();
+
+        names.sort();
+        names
+    };
+
+    assert_eq!(cache_hits(&loaded_module), Vec::<String>::new());
+    assert_eq!(person_defs(&loaded_module), vec!["greetPerson", "new"]);
+
+    let arena = Bump::new();
+    let loaded_module = load_with_cache(&arena, filename, cache_dir.path()).unwrap();
+
+    assert_eq!(cache_hits(&loaded_module), vec!["Greet", "Person"]);
+    assert_eq!(person_defs(&loaded_module), vec!["greetPerson", "new"]);
+    assert_eq!(loaded_module.total_problems(), 0);
+
+    expect_types(loaded_module, hashmap! { "main" => "Str" });
+}
+
+#[test]
+fn test_mode_modules_from_cache() {
+    let cache_dir = roc_test_utils::TmpDir::new("tmp/test_mode_modules_from_cache");
+    let src_dir = roc_test_utils::TmpDir::new("tmp/test_mode_modules_from_cache_src");
+
+    let modules = [
+        (
+            "Helper.roc",
+            indoc!(
+                r#"
+                interface Helper exposes [double] imports []
+
+                double = \n -> n * 2
+
+                expect double 2 == 4
+                "#
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r#"
+                interface Main exposes [quadruple] imports [Helper]
+
+                quadruple = \n -> Helper.double (Helper.double n)
+
+                expect quadruple 1 == 4
+                "#
+            ),
+        ),
+    ];
+
+    for (name, source) in modules {
+        std::fs::write(src_dir.path().join(name), source).unwrap();
+    }
+
+    let filename = src_dir.path().join("Main.roc");
+
+    let arena = Bump::new();
+    let module = load_tests_with_cache(&arena, filename.clone(), cache_dir.path());
+
+    assert_eq!(monomorphized_cache_hits(&module), Vec::<String>::new());
+    assert_eq!(module.toplevel_expects.pure.len(), 2);
+    assert_eq!(module.expectations.len(), 2);
+
+    let procedures = module.procedures.len();
+
+    // The expects of a cached module still run, and it is specialized just like before.
+    let arena = Bump::new();
+    let module = load_tests_with_cache(&arena, filename, cache_dir.path());
+
+    assert_eq!(monomorphized_cache_hits(&module), vec!["Helper"]);
+    assert_eq!(module.toplevel_expects.pure.len(), 2);
+    assert_eq!(module.expectations.len(), 2);
+    assert_eq!(module.procedures.len(), procedures);
+}
+
 #[test]
 fn app_dep_types() {
     let subs_by_module = Default::default();
@@ -909,3 +1220,99 @@ fn import_builtin_in_platform_and_check_app() {
     let result = multiple_modules("import_builtin_in_platform_and_check_app", modules);
     assert!(result.is_ok(), "should check");
 }
+
+/// Load a module the way `roc test` does, and count the top-level expects it has
+fn count_test_mode_expects(subdir: &str, source: &str) -> Result<usize, String> {
+    let tmp = format!("tmp/{}", subdir);
+    let dir = roc_test_utils::TmpDir::new(&tmp);
+    let filename = dir.path().join("Main.roc");
+
+    std::fs::write(&filename, source).unwrap();
+
+    let arena = Bump::new();
+    let load_start = LoadStart::from_path(&arena, filename, RenderTarget::Generic)
+        .map_err(|problem| format!("{:?}", problem))?;
+    let load_config = LoadConfig {
+        target_info: TARGET_INFO,
+        render: RenderTarget::Generic,
+        threading: Threading::Single,
+        exec_mode: ExecutionMode::Test,
+        cache_dir: None,
+    };
+
+    let result = match roc_load_internal::file::load(
+        &arena,
+        load_start,
+        Default::default(),
+        Default::default(),
+        load_config,
+    ) {
+        Ok(LoadResult::Monomorphized(module)) => {
+            Ok(module.toplevel_expects.pure.len() + module.toplevel_expects.fx.len())
+        }
+        Ok(LoadResult::TypeChecked(_)) => unreachable!("tests are monomorphized"),
+        Err(LoadingProblem::FormattedReport(report)) => Err(report),
+        Err(problem) => Err(format!("{:?}", problem)),
+    };
+
+    result
+}
+
+#[test]
+fn doctests_become_expects() {
+    let source = indoc!(
+        r#"
+        interface Main exposes [addOne] imports []
+
+        ## Add one.
+        ##
+        ## >>> addOne 1
+        ## == 2
+        ##
+        ## These examples are not checked:
+        ##
+        ## >>> addOne
+        ##
+        ## >>> addOne 2
+        ## Returns three
+        ##
+        ## ```roc test
+        ## two = addOne 1
+        ##
+        ## addOne two == 3
+        ## ```
+        addOne : I64 -> I64
+        addOne = \n -> n + 1
+
+        expect addOne 0 == 1
+        "#
+    );
+
+    assert_eq!(
+        count_test_mode_expects("doctests_become_expects", source),
+        Ok(3)
+    );
+}
+
+#[test]
+fn doctest_parse_problem() {
+    let source = indoc!(
+        r#"
+        interface Main exposes [addOne] imports []
+
+        ## >>> addOne (1
+        ## == 2
+        addOne : I64 -> I64
+        addOne = \n -> n + 1
+        "#
+    );
+
+    match count_test_mode_expects("doctest_parse_problem", source) {
+        Err(report) => assert!(
+            report.contains("3│  ## >>> addOne (1"),
+            "the problem should be reported at the doc comment:\n{}",
+            report
+        ),
+        Ok(_) => unreachable!("we expect failure here"),
+    }
+}
@@ -1,8 +1,9 @@
 use self::BinOp::*;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CalledVia {
     /// Calling with space, e.g. (foo bar)
     Space,
@@ -16,9 +17,14 @@ pub enum CalledVia {
     /// This call is the result of desugaring string interpolation,
     /// e.g. "\(first) \(last)" is transformed into Str.concat (Str.concat first " ") last.
     StringInterpolation,
+
+    /// This call is the result of desugaring a record builder,
+    /// e.g. `succeed { a: <- x, b: <- y }` is transformed into
+    /// `apply (apply (succeed (\a -> \b -> { a, b })) x) y`.
+    RecordBuilder,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UnaryOp {
     /// (-), e.g. (-x)
     Negate,
@@ -26,7 +32,7 @@ pub enum UnaryOp {
     Not,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BinOp {
     // highest precedence
     Caret,
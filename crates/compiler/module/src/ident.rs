@@ -1,4 +1,5 @@
 pub use roc_ident::IdentStr;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// This could be uppercase or lowercase, qualified or unqualified.
@@ -33,15 +34,15 @@ impl std::ops::Deref for ModuleName {
 }
 
 /// An uncapitalized identifier, such as a field name or local variable
-#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Lowercase(IdentStr);
 
 /// A capitalized identifier, such as a tag name or module name
-#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Uppercase(IdentStr);
 
 /// A string representing a foreign (linked-in) symbol
-#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 pub struct ForeignSymbol(IdentStr);
 
 pub type TagIdIntType = u16;
@@ -53,7 +54,7 @@ pub type TagIdIntType = u16;
 /// If tags had a Symbol representation, then each module would have to
 /// deal with contention on a global mutex around translating tag strings
 /// into integers. (Record field labels work the same way, for the same reason.)
-#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct TagName(pub Uppercase);
 
 roc_error_macros::assert_sizeof_non_wasm!(TagName, 16);
@@ -86,6 +87,8 @@ impl ModuleName {
     pub const ENCODE: &'static str = "Encode";
     pub const DECODE: &'static str = "Decode";
     pub const JSON: &'static str = "Json";
+    pub const HASH: &'static str = "Hash";
+    pub const GEN: &'static str = "Gen";
 
     pub fn as_str(&self) -> &str {
         self.0.as_str()
@@ -1,9 +1,10 @@
 use crate::symbol::Symbol;
+use serde::{Deserialize, Serialize};
 
 /// Low-level operations that get translated directly into e.g. LLVM instructions.
 /// These are always wrapped when exposed to end users, and can only make it
 /// into an Expr when added directly by can::builtins
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LowLevel {
     StrConcat,
     StrJoinWith,
@@ -30,6 +31,12 @@ pub enum LowLevel {
     StrAppendScalar,
     StrGetScalarUnsafe,
     StrGetCapacity,
+    StrGraphemes,
+    StrGraphemeByteOffset,
+    StrToUpper,
+    StrToLower,
+    StrToNfc,
+    StrToNfd,
     ListLen,
     ListWithCapacity,
     ListReserve,
@@ -86,6 +93,24 @@ pub enum LowLevel {
     NumAtan,
     NumAcos,
     NumAsin,
+    NumExp,
+    NumExp2,
+    NumLog2,
+    NumLog10,
+    NumSinh,
+    NumCosh,
+    NumTanh,
+    NumAtan2,
+    NumHypot,
+    NumTrunc,
+    NumFma,
+    NumIsNan,
+    NumIsInfinite,
+    NumCopySign,
+    NumDecRoundTo,
+    NumDecToStrFixed,
+    NumDecToScaledI128,
+    NumDecFromScaledI128,
     NumBytesToU16,
     NumBytesToU32,
     NumBitwiseAnd,
@@ -94,6 +119,12 @@ pub enum LowLevel {
     NumShiftLeftBy,
     NumShiftRightBy,
     NumShiftRightZfBy,
+    NumRotateLeftBy,
+    NumRotateRightBy,
+    NumCountLeadingZeroBits,
+    NumCountTrailingZeroBits,
+    NumCountOneBits,
+    NumSwapBytes,
     NumIntCast,
     NumToFloatCast,
     NumToIntChecked,
@@ -105,6 +136,7 @@ pub enum LowLevel {
     Or,
     Not,
     Hash,
+    GenSeed,
     PtrCast,
     RefCountInc,
     RefCountDec,
@@ -207,10 +239,10 @@ macro_rules! map_symbol_to_lowlevel {
                 LowLevel::NumToFloatChecked => unreachable!(),
 
                 // these are used internally and not tied to a symbol
-                LowLevel::Hash => unimplemented!(),
                 LowLevel::PtrCast => unimplemented!(),
                 LowLevel::RefCountInc => unimplemented!(),
                 LowLevel::RefCountDec => unimplemented!(),
+                LowLevel::NotEq => unimplemented!(),
 
                 // these are not implemented, not sure why
                 LowLevel::StrFromInt => unimplemented!(),
@@ -249,6 +281,12 @@ map_symbol_to_lowlevel! {
     StrGetScalarUnsafe <= STR_GET_SCALAR_UNSAFE,
     StrToNum <= STR_TO_NUM,
     StrGetCapacity <= STR_CAPACITY,
+    StrGraphemes <= STR_GRAPHEMES,
+    StrGraphemeByteOffset <= STR_GRAPHEME_BYTE_OFFSET_LOWLEVEL,
+    StrToUpper <= STR_TO_UPPER,
+    StrToLower <= STR_TO_LOWER,
+    StrToNfc <= STR_TO_NFC,
+    StrToNfd <= STR_TO_NFD,
     ListLen <= LIST_LEN,
     ListGetCapacity <= LIST_CAPACITY,
     ListWithCapacity <= LIST_WITH_CAPACITY,
@@ -299,6 +337,24 @@ map_symbol_to_lowlevel! {
     NumAtan <= NUM_ATAN,
     NumAcos <= NUM_ACOS,
     NumAsin <= NUM_ASIN,
+    NumExp <= NUM_EXP,
+    NumExp2 <= NUM_EXP2,
+    NumLog2 <= NUM_LOG2,
+    NumLog10 <= NUM_LOG10,
+    NumSinh <= NUM_SINH,
+    NumCosh <= NUM_COSH,
+    NumTanh <= NUM_TANH,
+    NumAtan2 <= NUM_ATAN2,
+    NumHypot <= NUM_HYPOT,
+    NumTrunc <= NUM_TRUNC,
+    NumFma <= NUM_FMA,
+    NumIsNan <= NUM_IS_NAN,
+    NumIsInfinite <= NUM_IS_INFINITE,
+    NumCopySign <= NUM_COPY_SIGN,
+    NumDecRoundTo <= NUM_ROUND_DEC_TO_LOWLEVEL,
+    NumDecToStrFixed <= NUM_TO_STR_FIXED,
+    NumDecToScaledI128 <= NUM_TO_SCALED_I128_LOWLEVEL,
+    NumDecFromScaledI128 <= NUM_FROM_SCALED_I128_LOWLEVEL,
     NumBytesToU16 <= NUM_BYTES_TO_U16_LOWLEVEL,
     NumBytesToU32 <= NUM_BYTES_TO_U32_LOWLEVEL,
     NumBitwiseAnd <= NUM_BITWISE_AND,
@@ -307,11 +363,18 @@ map_symbol_to_lowlevel! {
     NumShiftLeftBy <= NUM_SHIFT_LEFT,
     NumShiftRightBy <= NUM_SHIFT_RIGHT,
     NumShiftRightZfBy <= NUM_SHIFT_RIGHT_ZERO_FILL,
+    NumRotateLeftBy <= NUM_ROTATE_LEFT,
+    NumRotateRightBy <= NUM_ROTATE_RIGHT,
+    NumCountLeadingZeroBits <= NUM_COUNT_LEADING_ZERO_BITS,
+    NumCountTrailingZeroBits <= NUM_COUNT_TRAILING_ZERO_BITS,
+    NumCountOneBits <= NUM_COUNT_ONE_BITS,
+    NumSwapBytes <= NUM_SWAP_BYTES,
     NumToStr <= NUM_TO_STR,
-    Eq <= BOOL_EQ,
-    NotEq <= BOOL_NEQ,
+    Eq <= BOOL_STRUCTURAL_EQ,
     And <= BOOL_AND,
     Or <= BOOL_OR,
     Not <= BOOL_NOT,
     Unreachable <= LIST_UNREACHABLE,
+    Hash <= DICT_HASH_LOW_LEVEL,
+    GenSeed <= GEN_SEED_LOW_LEVEL,
 }
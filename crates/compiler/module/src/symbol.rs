@@ -3,6 +3,7 @@ use crate::module_err::{IdentIdNotFoundSnafu, ModuleIdNotFoundSnafu, ModuleResul
 use roc_collections::{SmallStringInterner, VecMap};
 use roc_ident::IdentStr;
 use roc_region::all::Region;
+use serde::{Deserialize, Serialize};
 use snafu::OptionExt;
 use std::num::NonZeroU32;
 use std::{fmt, u32};
@@ -26,7 +27,7 @@ use std::{fmt, u32};
 //
 // #[repr(packed)] gives you #[repr(packed(1))], and then all your reads are unaligned
 // so we set the alignment to (the natural) 4
-#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[repr(packed(4))]
 pub struct Symbol {
     ident_id: u32,
@@ -50,6 +51,9 @@ const PRETTY_PRINT_DEBUG_SYMBOLS: bool = true;
 pub const DERIVABLE_ABILITIES: &[(Symbol, &[Symbol])] = &[
     (Symbol::ENCODE_ENCODING, &[Symbol::ENCODE_TO_ENCODER]),
     (Symbol::DECODE_DECODING, &[Symbol::DECODE_DECODER]),
+    (Symbol::HASH_HASH_ABILITY, &[Symbol::HASH_HASH]),
+    (Symbol::BOOL_EQ, &[Symbol::BOOL_IS_EQ]),
+    (Symbol::GEN_ARBITRARY_ABILITY, &[Symbol::GEN_ARBITRARY]),
 ];
 
 /// In Debug builds only, Symbol has a name() method that lets
@@ -339,7 +343,7 @@ lazy_static! {
 }
 
 /// A globally unique ID that gets assigned to each module as it is loaded.
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ModuleId(NonZeroU32);
 
 impl ModuleId {
@@ -592,7 +596,7 @@ impl ModuleIds {
 ///
 /// This ID is unique within a given module, not globally - so to turn this back into
 /// a string, you would need a ModuleId, an IdentId, and a Map<ModuleId, Map<IdentId, String>>.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct IdentId(u32);
 
 impl IdentId {
@@ -602,7 +606,7 @@ impl IdentId {
 }
 
 /// Stores a mapping between Ident and IdentId.
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IdentIds {
     pub interner: SmallStringInterner,
 }
@@ -1227,6 +1231,42 @@ define_builtins! {
         143 NUM_MUL_CHECKED_LOWLEVEL: "mulCheckedLowlevel"
         144 NUM_BYTES_TO_U16_LOWLEVEL: "bytesToU16Lowlevel"
         145 NUM_BYTES_TO_U32_LOWLEVEL: "bytesToU32Lowlevel"
+        146 NUM_COUNT_LEADING_ZERO_BITS: "countLeadingZeroBits"
+        147 NUM_COUNT_TRAILING_ZERO_BITS: "countTrailingZeroBits"
+        148 NUM_COUNT_ONE_BITS: "countOneBits"
+        149 NUM_ROTATE_LEFT: "rotateLeftBy"
+        150 NUM_ROTATE_RIGHT: "rotateRightBy"
+        151 NUM_SWAP_BYTES: "swapBytes"
+        152 NUM_TO_BYTES_LE: "toBytesLe"
+        153 NUM_TO_BYTES_BE: "toBytesBe"
+        154 NUM_FROM_BYTES_LE: "fromBytesLe"
+        155 NUM_FROM_BYTES_BE: "fromBytesBe"
+        156 NUM_EXP: "exp"
+        157 NUM_EXP2: "exp2"
+        158 NUM_LOG2: "log2"
+        159 NUM_LOG10: "log10"
+        160 NUM_SINH: "sinh"
+        161 NUM_COSH: "cosh"
+        162 NUM_TANH: "tanh"
+        163 NUM_ATAN2: "atan2"
+        164 NUM_HYPOT: "hypot"
+        165 NUM_TRUNC: "trunc"
+        166 NUM_FMA: "fma"
+        167 NUM_IS_NAN: "isNaN"
+        168 NUM_IS_INFINITE: "isInfinite"
+        169 NUM_COPY_SIGN: "copySign"
+        170 NUM_E: "e"
+        171 NUM_PI: "pi"
+        172 NUM_ROUND_DEC_TO: "roundDecTo"
+        173 NUM_ROUND_DEC_TO_CHECKED: "roundDecToChecked"
+        174 NUM_ROUND_DEC_TO_LOWLEVEL: "roundDecToLowlevel"
+        175 NUM_TO_STR_FIXED: "toStrFixed"
+        176 NUM_TO_SCALED_I128: "toScaledI128"
+        177 NUM_TO_SCALED_I128_CHECKED: "toScaledI128Checked"
+        178 NUM_TO_SCALED_I128_LOWLEVEL: "toScaledI128Lowlevel"
+        179 NUM_FROM_SCALED_I128: "fromScaledI128"
+        180 NUM_FROM_SCALED_I128_CHECKED: "fromScaledI128Checked"
+        181 NUM_FROM_SCALED_I128_LOWLEVEL: "fromScaledI128Lowlevel"
     }
     4 BOOL: "Bool" => {
         0 BOOL_BOOL: "Bool" exposed_type=true // the Bool.Bool type alias
@@ -1236,8 +1276,11 @@ define_builtins! {
         4 BOOL_OR: "or"
         5 BOOL_NOT: "not"
         6 BOOL_XOR: "xor"
-        7 BOOL_EQ: "isEq"
-        8 BOOL_NEQ: "isNotEq"
+        7 BOOL_IS_EQ: "isEq"
+        8 BOOL_IS_NOT_EQ: "isNotEq"
+        9 BOOL_EQ: "Eq" exposed_type=true // the Bool.Eq ability
+        10 BOOL_IS_EQ_IMPL: "boolIsEq"
+        11 BOOL_STRUCTURAL_EQ: "structuralEq"
     }
     5 STR: "Str" => {
         0 STR_STR: "Str" exposed_apply_type=true // the Str.Str type alias
@@ -1293,6 +1336,17 @@ define_builtins! {
         50 STR_REPLACE_EACH: "replaceEach"
         51 STR_REPLACE_FIRST: "replaceFirst"
         52 STR_REPLACE_LAST: "replaceLast"
+        53 STR_GRAPHEMES: "graphemes"
+        54 STR_TO_UPPER: "toUpper"
+        55 STR_TO_LOWER: "toLower"
+        56 STR_TO_NFC: "toNfc"
+        57 STR_TO_NFD: "toNfd"
+        58 STR_SPLIT_AT: "splitAt"
+        59 STR_GRAPHEME_BYTE_OFFSET_LOWLEVEL: "graphemeByteOffsetLowlevel"
+        60 STR_CONTAINS: "contains"
+        61 STR_INDEX_OF: "indexOf"
+        62 STR_SLICE_UTF8: "sliceUtf8"
+        63 STR_WALK_UTF8: "walkUtf8"
     }
     6 LIST: "List" => {
         0 LIST_LIST: "List" exposed_apply_type=true // the List.List type alias
@@ -1370,6 +1424,7 @@ define_builtins! {
         72 LIST_SUBLIST_LOWLEVEL: "sublistLowlevel"
         73 LIST_CAPACITY: "capacity"
         74 LIST_MAP_TRY: "mapTry"
+        75 LIST_IS_EQ: "isEq"
     }
     7 RESULT: "Result" => {
         0 RESULT_RESULT: "Result" exposed_type=true // the Result.Result type alias
@@ -1404,6 +1459,7 @@ define_builtins! {
 
         15 DICT_WITH_CAPACITY: "withCapacity"
         16 DICT_CAPACITY: "capacity"
+        17 DICT_HASH_LOW_LEVEL: "hashLowLevel"
     }
     9 SET: "Set" => {
         0 SET_SET: "Set" exposed_type=true // the Set.Set type alias
@@ -1484,10 +1540,66 @@ define_builtins! {
         24 DECODE_DECODE_WITH: "decodeWith"
         25 DECODE_FROM_BYTES_PARTIAL: "fromBytesPartial"
         26 DECODE_FROM_BYTES: "fromBytes"
+        27 DECODE_TAG: "tag"
     }
     13 JSON: "Json" => {
         0 JSON_JSON: "Json"
     }
-
-    num_modules: 14 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
+    14 HASH: "Hash" => {
+        0 HASH_HASH_ABILITY: "Hash" exposed_type=true
+        1 HASH_HASH: "hash"
+        2 HASH_HASHER: "Hasher" exposed_type=true
+        3 HASH_ADD_BYTES: "addBytes"
+        4 HASH_ADD_U8: "addU8"
+        5 HASH_ADD_U16: "addU16"
+        6 HASH_ADD_U32: "addU32"
+        7 HASH_ADD_U64: "addU64"
+        8 HASH_ADD_U128: "addU128"
+        9 HASH_COMPLETE: "complete"
+        10 HASH_HASH_BOOL: "hashBool"
+        11 HASH_HASH_I8: "hashI8"
+        12 HASH_HASH_I16: "hashI16"
+        13 HASH_HASH_I32: "hashI32"
+        14 HASH_HASH_I64: "hashI64"
+        15 HASH_HASH_I128: "hashI128"
+        16 HASH_HASH_NAT: "hashNat"
+        17 HASH_HASH_STR: "hashStr"
+        18 HASH_HASH_LIST: "hashList"
+    }
+    15 GEN: "Gen" => {
+        0 GEN_GEN: "Gen" exposed_type=true
+        1 GEN_CHOICES: "Choices" exposed_type=true
+        2 GEN_ARBITRARY_ABILITY: "Arbitrary" exposed_type=true
+        3 GEN_ARBITRARY: "arbitrary"
+        4 GEN_CUSTOM: "custom"
+        5 GEN_GENERATE_WITH: "generateWith"
+        6 GEN_CONSTANT: "constant"
+        7 GEN_MAP: "map"
+        8 GEN_MAP2: "map2"
+        9 GEN_AND_THEN: "andThen"
+        10 GEN_ONE_OF: "oneOf"
+        11 GEN_NAT_BELOW: "natBelow"
+        12 GEN_U8: "u8"
+        13 GEN_U16: "u16"
+        14 GEN_U32: "u32"
+        15 GEN_U64: "u64"
+        16 GEN_U128: "u128"
+        17 GEN_I8: "i8"
+        18 GEN_I16: "i16"
+        19 GEN_I32: "i32"
+        20 GEN_I64: "i64"
+        21 GEN_I128: "i128"
+        22 GEN_NAT: "nat"
+        23 GEN_F32: "f32"
+        24 GEN_F64: "f64"
+        25 GEN_DEC: "dec"
+        26 GEN_BOOL: "bool"
+        27 GEN_STR: "str"
+        28 GEN_LIST: "list"
+        29 GEN_CHECK: "check"
+        30 GEN_CHECK_WITH: "checkWith"
+        31 GEN_SEED_LOW_LEVEL: "seedLowLevel"
+    }
+
+    num_modules: 16 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
 }
@@ -310,6 +310,7 @@ impl<'a> ParamMap<'a> {
 
                 Expect { remainder, .. } => stack.push(remainder),
                 ExpectFx { remainder, .. } => stack.push(remainder),
+                Dbg { remainder, .. } => stack.push(remainder),
 
                 Switch {
                     branches,
@@ -321,7 +322,7 @@ impl<'a> ParamMap<'a> {
                 }
                 Refcounting(_, _) => unreachable!("these have not been introduced yet"),
 
-                Ret(_) | Jump(_, _) | RuntimeError(_) => {
+                Ret(_) | Jump(_, _) | Crash { .. } | RuntimeError(_) => {
                     // these are terminal, do nothing
                 }
             }
@@ -825,9 +826,13 @@ impl<'a> BorrowInfState<'a> {
                 self.collect_stmt(param_map, remainder);
             }
 
+            Dbg { remainder, .. } => {
+                self.collect_stmt(param_map, remainder);
+            }
+
             Refcounting(_, _) => unreachable!("these have not been introduced yet"),
 
-            Ret(_) | RuntimeError(_) => {
+            Ret(_) | Crash { .. } | RuntimeError(_) => {
                 // these are terminal, do nothing
             }
         }
@@ -886,7 +891,7 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         StrGetUnsafe | ListGetUnsafe => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         ListConcat => arena.alloc_slice_copy(&[owned, owned]),
         StrConcat => arena.alloc_slice_copy(&[owned, borrowed]),
-        StrSubstringUnsafe => arena.alloc_slice_copy(&[borrowed, irrelevant, irrelevant]),
+        StrSubstringUnsafe => arena.alloc_slice_copy(&[owned, irrelevant, irrelevant]),
         StrReserve => arena.alloc_slice_copy(&[owned, irrelevant]),
         StrAppendScalar => arena.alloc_slice_copy(&[owned, irrelevant]),
         StrGetScalarUnsafe => arena.alloc_slice_copy(&[borrowed, irrelevant]),
@@ -897,6 +902,10 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         StrToNum => arena.alloc_slice_copy(&[borrowed]),
         ListPrepend => arena.alloc_slice_copy(&[owned, owned]),
         StrJoinWith => arena.alloc_slice_copy(&[borrowed, borrowed]),
+        StrGraphemes | StrToUpper | StrToLower | StrToNfc | StrToNfd => {
+            arena.alloc_slice_copy(&[borrowed])
+        }
+        StrGraphemeByteOffset => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         ListMap => arena.alloc_slice_copy(&[owned, function, closure_data]),
         ListMap2 => arena.alloc_slice_copy(&[owned, owned, function, closure_data]),
         ListMap3 => arena.alloc_slice_copy(&[owned, owned, owned, function, closure_data]),
@@ -916,13 +925,24 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         | NumMulChecked | NumGt | NumGte | NumLt | NumLte | NumCompare | NumDivFrac
         | NumDivTruncUnchecked | NumDivCeilUnchecked | NumRemUnchecked | NumIsMultipleOf
         | NumPow | NumPowInt | NumBitwiseAnd | NumBitwiseXor | NumBitwiseOr | NumShiftLeftBy
-        | NumShiftRightBy | NumShiftRightZfBy => arena.alloc_slice_copy(&[irrelevant, irrelevant]),
+        | NumShiftRightBy | NumShiftRightZfBy | NumRotateLeftBy | NumRotateRightBy | NumAtan2
+        | NumHypot | NumCopySign => arena.alloc_slice_copy(&[irrelevant, irrelevant]),
+
+        NumFma | NumDecRoundTo => arena.alloc_slice_copy(&[irrelevant, irrelevant, irrelevant]),
+        NumDecToStrFixed | NumDecToScaledI128 | NumDecFromScaledI128 => {
+            arena.alloc_slice_copy(&[irrelevant, irrelevant])
+        }
 
         NumToStr | NumAbs | NumNeg | NumSin | NumCos | NumSqrtUnchecked | NumLogUnchecked
         | NumRound | NumCeiling | NumFloor | NumToFrac | Not | NumIsFinite | NumAtan | NumAcos
         | NumAsin | NumIntCast | NumToIntChecked | NumToFloatCast | NumToFloatChecked => {
             arena.alloc_slice_copy(&[irrelevant])
         }
+        NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits | NumSwapBytes => {
+            arena.alloc_slice_copy(&[irrelevant])
+        }
+        NumExp | NumExp2 | NumLog2 | NumLog10 | NumSinh | NumCosh | NumTanh | NumTrunc
+        | NumIsNan | NumIsInfinite => arena.alloc_slice_copy(&[irrelevant]),
         NumBytesToU16 => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         NumBytesToU32 => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         StrStartsWith | StrEndsWith => arena.alloc_slice_copy(&[borrowed, borrowed]),
@@ -932,6 +952,7 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         StrRepeat => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         StrFromInt | StrFromFloat => arena.alloc_slice_copy(&[irrelevant]),
         Hash => arena.alloc_slice_copy(&[borrowed, irrelevant]),
+        GenSeed => arena.alloc_slice_copy(&[irrelevant]),
 
         ListIsUnique => arena.alloc_slice_copy(&[borrowed]),
 
@@ -994,10 +1015,11 @@ fn call_info_stmt<'a>(arena: &'a Bump, stmt: &Stmt<'a>, info: &mut CallInfo<'a>)
 
             Expect { remainder, .. } => stack.push(remainder),
             ExpectFx { remainder, .. } => stack.push(remainder),
+            Dbg { remainder, .. } => stack.push(remainder),
 
             Refcounting(_, _) => unreachable!("these have not been introduced yet"),
 
-            Ret(_) | Jump(_, _) | RuntimeError(_) => {
+            Ret(_) | Jump(_, _) | Crash { .. } | RuntimeError(_) => {
                 // these are terminal, do nothing
             }
         }
@@ -0,0 +1,483 @@
+use bumpalo::collections::vec::Vec;
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, Symbol};
+
+use crate::ir::{BranchInfo, Expr, JoinPointId, Literal, Param, Stmt};
+use crate::layout::{Builtin, Layout, TagIdIntType, UnionLayout};
+
+use super::{let_lowlevel, CodeGenHelp, Context, LAYOUT_BOOL, LAYOUT_U64};
+
+const ARG_1: Symbol = Symbol::ARG_1;
+const ARG_2: Symbol = Symbol::ARG_2;
+
+/// Generate the body of a hash helper proc.
+/// The proc takes the value to hash and a seed, and returns the new hash.
+/// Composite values are hashed by threading the seed through the hashes of their parts,
+/// so that the result only depends on the structure of the value, and never on its address.
+pub fn hash_generic<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout: Layout<'a>,
+) -> Stmt<'a> {
+    match layout {
+        Layout::Builtin(
+            Builtin::Int(_) | Builtin::Float(_) | Builtin::Bool | Builtin::Decimal | Builtin::Str,
+        ) => {
+            unreachable!(
+                "No generated proc for hashing {:?}. Use a Zig function.",
+                layout
+            )
+        }
+        Layout::Builtin(Builtin::List(elem_layout)) => hash_list(root, ident_ids, ctx, elem_layout),
+        Layout::Struct { field_layouts, .. } => hash_struct(root, ident_ids, ctx, field_layouts),
+        Layout::Union(union_layout) => hash_tag_union(root, ident_ids, ctx, union_layout),
+        Layout::Boxed(inner_layout) => hash_boxed(root, ident_ids, ctx, inner_layout),
+        Layout::LambdaSet(_) => unreachable!("Functions cannot be hashed"),
+        Layout::RecursivePointer => {
+            unreachable!("Can't hash a RecursivePointer. Should have been replaced by a tag union.")
+        }
+    }
+}
+
+/// Hash each of the given values in turn, passing the result of each hash as the seed for the next.
+/// The values are bound by `load_value`, and the final hash is passed to `following`.
+fn hash_fields<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    seed: Symbol,
+    field_layouts: &'a [Layout<'a>],
+    load_value: impl Fn(usize) -> Expr<'a>,
+    following: impl FnOnce(Symbol) -> Stmt<'a>,
+) -> Stmt<'a> {
+    let mut hashes = Vec::with_capacity_in(field_layouts.len(), root.arena);
+    let mut fields = Vec::with_capacity_in(field_layouts.len(), root.arena);
+    let mut calls = Vec::with_capacity_in(field_layouts.len(), root.arena);
+
+    let mut current_seed = seed;
+    for (i, layout) in field_layouts.iter().enumerate() {
+        let field = root.create_symbol(ident_ids, &format!("field_{}", i));
+        let hash = root.create_symbol(ident_ids, &format!("hash_{}", i));
+
+        let call = root
+            .call_specialized_op(
+                ident_ids,
+                ctx,
+                *layout,
+                root.arena.alloc([field, current_seed]),
+            )
+            .unwrap();
+
+        fields.push(field);
+        hashes.push(hash);
+        calls.push(call);
+        current_seed = hash;
+    }
+
+    let mut stmt = following(current_seed);
+    for (i, call) in calls.into_iter().enumerate().rev() {
+        stmt = Stmt::Let(
+            fields[i],
+            load_value(i),
+            field_layouts[i],
+            root.arena.alloc(
+                //
+                Stmt::Let(hashes[i], call, LAYOUT_U64, root.arena.alloc(stmt)),
+            ),
+        );
+    }
+
+    stmt
+}
+
+fn hash_struct<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    field_layouts: &'a [Layout<'a>],
+) -> Stmt<'a> {
+    hash_fields(
+        root,
+        ident_ids,
+        ctx,
+        ARG_2,
+        field_layouts,
+        |i| Expr::StructAtIndex {
+            index: i as u64,
+            field_layouts,
+            structure: ARG_1,
+        },
+        Stmt::Ret,
+    )
+}
+
+fn hash_tag_union<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    union_layout: UnionLayout<'a>,
+) -> Stmt<'a> {
+    use UnionLayout::*;
+
+    let parent_rec_ptr_layout = ctx.recursive_union;
+    if !matches!(union_layout, NonRecursive(_)) {
+        ctx.recursive_union = Some(union_layout);
+    }
+
+    let body = match union_layout {
+        NonRecursive(tags) => hash_tag_union_help(root, ident_ids, ctx, union_layout, tags, None),
+
+        Recursive(tags) => hash_tag_union_help(root, ident_ids, ctx, union_layout, tags, None),
+
+        NonNullableUnwrapped(field_layouts) => {
+            let tags = root.arena.alloc([field_layouts]);
+            hash_tag_union_help(root, ident_ids, ctx, union_layout, tags, None)
+        }
+
+        NullableWrapped {
+            other_tags,
+            nullable_id,
+        } => hash_tag_union_help(
+            root,
+            ident_ids,
+            ctx,
+            union_layout,
+            other_tags,
+            Some(nullable_id),
+        ),
+
+        NullableUnwrapped {
+            other_fields,
+            nullable_id,
+        } => hash_tag_union_help(
+            root,
+            ident_ids,
+            ctx,
+            union_layout,
+            root.arena.alloc([other_fields]),
+            Some(nullable_id as TagIdIntType),
+        ),
+    };
+
+    ctx.recursive_union = parent_rec_ptr_layout;
+
+    body
+}
+
+/// Hash the tag ID, then the fields of the active tag.
+/// Recursive fields are hashed by (non-tail) recursive calls to the same helper proc.
+fn hash_tag_union_help<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    union_layout: UnionLayout<'a>,
+    tag_layouts: &'a [&'a [Layout<'a>]],
+    nullable_id: Option<TagIdIntType>,
+) -> Stmt<'a> {
+    let arena = root.arena;
+    let tag_id_layout = union_layout.tag_id_layout();
+
+    let tag_id = root.create_symbol(ident_ids, "tag_id");
+    let tag_id_stmt = |next| {
+        Stmt::Let(
+            tag_id,
+            Expr::GetTagId {
+                structure: ARG_1,
+                union_layout,
+            },
+            tag_id_layout,
+            next,
+        )
+    };
+
+    let tag_hash = root.create_symbol(ident_ids, "tag_hash");
+    let tag_hash_stmt = |next| {
+        let_lowlevel(
+            arena,
+            LAYOUT_U64,
+            tag_hash,
+            LowLevel::Hash,
+            &[tag_id, ARG_2],
+            next,
+        )
+    };
+
+    //
+    // Switch statement by tag ID
+    //
+
+    let mut tag_branches = Vec::with_capacity_in(tag_layouts.len(), root.arena);
+
+    // The null tag has no fields, so its hash is just the hash of its ID
+    if let Some(id) = nullable_id {
+        tag_branches.push((id as u64, BranchInfo::None, Stmt::Ret(tag_hash)))
+    }
+
+    let mut current_tag_id: TagIdIntType = 0;
+    let mut tag_stmts = Vec::with_capacity_in(tag_layouts.len(), root.arena);
+    for field_layouts in tag_layouts.iter() {
+        if let Some(null_id) = nullable_id {
+            if current_tag_id == null_id as TagIdIntType {
+                current_tag_id += 1;
+            }
+        }
+
+        let id = current_tag_id;
+        let tag_stmt = hash_fields(
+            root,
+            ident_ids,
+            ctx,
+            tag_hash,
+            field_layouts,
+            |i| Expr::UnionAtIndex {
+                structure: ARG_1,
+                union_layout,
+                tag_id: id,
+                index: i as u64,
+            },
+            Stmt::Ret,
+        );
+        tag_stmts.push((id, tag_stmt));
+
+        current_tag_id += 1;
+    }
+
+    let (_, default_stmt) = tag_stmts.pop().unwrap();
+    for (id, stmt) in tag_stmts {
+        tag_branches.push((id as u64, BranchInfo::None, stmt));
+    }
+
+    let tag_switch_stmt = Stmt::Switch {
+        cond_symbol: tag_id,
+        cond_layout: tag_id_layout,
+        branches: tag_branches.into_bump_slice(),
+        default_branch: (BranchInfo::None, root.arena.alloc(default_stmt)),
+        ret_layout: LAYOUT_U64,
+    };
+
+    tag_id_stmt(root.arena.alloc(
+        //
+        tag_hash_stmt(root.arena.alloc(
+            //
+            tag_switch_stmt,
+        )),
+    ))
+}
+
+fn hash_boxed<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    inner_layout: &'a Layout<'a>,
+) -> Stmt<'a> {
+    let inner = root.create_symbol(ident_ids, "inner");
+    let result = root.create_symbol(ident_ids, "result");
+
+    let hash_call_expr = root
+        .call_specialized_op(
+            ident_ids,
+            ctx,
+            *inner_layout,
+            root.arena.alloc([inner, ARG_2]),
+        )
+        .unwrap();
+
+    Stmt::Let(
+        inner,
+        Expr::ExprUnbox { symbol: ARG_1 },
+        *inner_layout,
+        root.arena.alloc(
+            //
+            Stmt::Let(
+                result,
+                hash_call_expr,
+                LAYOUT_U64,
+                root.arena.alloc(Stmt::Ret(result)),
+            ),
+        ),
+    )
+}
+
+/// List hashing
+/// Like list equality, this walks a pointer over the elements rather than using `ListGetUnsafe`,
+/// so that reading an element doesn't touch its refcount.
+/// The length is hashed first, so that e.g. `[[], [1]]` and `[[1], []]` hash differently.
+fn hash_list<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    elem_layout: &Layout<'a>,
+) -> Stmt<'a> {
+    use LowLevel::*;
+    let layout_isize = root.layout_isize;
+    let arena = root.arena;
+
+    // A "Box" layout (heap pointer to a single list element)
+    let box_union_layout = UnionLayout::NonNullableUnwrapped(root.arena.alloc([*elem_layout]));
+    let box_layout = Layout::Union(box_union_layout);
+
+    // Hash the length
+
+    let len = root.create_symbol(ident_ids, "len");
+    let len_stmt = |next| let_lowlevel(arena, layout_isize, len, ListLen, &[ARG_1], next);
+
+    let len_hash = root.create_symbol(ident_ids, "len_hash");
+    let len_hash_stmt = |next| let_lowlevel(arena, LAYOUT_U64, len_hash, Hash, &[len, ARG_2], next);
+
+    // get the element pointer, cast to an integer
+    let elements = root.create_symbol(ident_ids, "elements");
+    let elements_expr = Expr::StructAtIndex {
+        index: 0,
+        field_layouts: root.arena.alloc([box_layout, layout_isize]),
+        structure: ARG_1,
+    };
+    let elements_stmt = |next| Stmt::Let(elements, elements_expr, box_layout, next);
+
+    let start = root.create_symbol(ident_ids, "start");
+    let start_stmt = |next| let_lowlevel(arena, layout_isize, start, PtrCast, &[elements], next);
+
+    //
+    // Loop initialisation
+    //
+
+    // let size = literal int
+    let size = root.create_symbol(ident_ids, "size");
+    let size_expr = Expr::Literal(Literal::Int(
+        (elem_layout.stack_size(root.layout_interner, root.target_info) as i128).to_ne_bytes(),
+    ));
+    let size_stmt = |next| Stmt::Let(size, size_expr, layout_isize, next);
+
+    // let list_size = len * size
+    let list_size = root.create_symbol(ident_ids, "list_size");
+    let list_size_stmt =
+        |next| let_lowlevel(arena, layout_isize, list_size, NumMul, &[len, size], next);
+
+    // let end = start + list_size
+    let end = root.create_symbol(ident_ids, "end");
+    let end_stmt = |next| let_lowlevel(arena, layout_isize, end, NumAdd, &[start, list_size], next);
+
+    //
+    // Loop name & parameters
+    //
+
+    let elems_loop = JoinPointId(root.create_symbol(ident_ids, "elems_loop"));
+    let addr = root.create_symbol(ident_ids, "addr");
+    let seed = root.create_symbol(ident_ids, "seed");
+
+    let param_addr = Param {
+        symbol: addr,
+        borrow: false,
+        layout: layout_isize,
+    };
+
+    let param_seed = Param {
+        symbol: seed,
+        borrow: false,
+        layout: LAYOUT_U64,
+    };
+
+    //
+    // if we haven't reached the end yet...
+    //
+
+    // Cast the integer to a box pointer
+    let box_sym = root.create_symbol(ident_ids, "box");
+    let box_stmt = |next| let_lowlevel(arena, box_layout, box_sym, PtrCast, &[addr], next);
+
+    // Dereference the box pointer to get the current element
+    let elem = root.create_symbol(ident_ids, "elem");
+    let elem_expr = Expr::UnionAtIndex {
+        structure: box_sym,
+        union_layout: box_union_layout,
+        tag_id: 0,
+        index: 0,
+    };
+    let elem_stmt = |next| Stmt::Let(elem, elem_expr, *elem_layout, next);
+
+    // Hash the current element
+    let elem_hash = root.create_symbol(ident_ids, "elem_hash");
+    let elem_hash_args = root.arena.alloc([elem, seed]);
+    let elem_hash_expr = root
+        .call_specialized_op(ident_ids, ctx, *elem_layout, elem_hash_args)
+        .unwrap();
+    let elem_hash_stmt = |next| Stmt::Let(elem_hash, elem_hash_expr, LAYOUT_U64, next);
+
+    // Loop back again with the next element
+    let next_addr = root.create_symbol(ident_ids, "next_addr");
+    let next_addr_stmt =
+        |next| let_lowlevel(arena, layout_isize, next_addr, NumAdd, &[addr, size], next);
+
+    let jump_back = Stmt::Jump(elems_loop, root.arena.alloc([next_addr, elem_hash]));
+
+    //
+    // Control flow
+    //
+
+    let is_end = root.create_symbol(ident_ids, "is_end");
+    let is_end_stmt = |next| let_lowlevel(arena, LAYOUT_BOOL, is_end, NumGte, &[addr, end], next);
+
+    let if_end_of_list = Stmt::Switch {
+        cond_symbol: is_end,
+        cond_layout: LAYOUT_BOOL,
+        ret_layout: LAYOUT_U64,
+        branches: root.arena.alloc([(1, BranchInfo::None, Stmt::Ret(seed))]),
+        default_branch: (
+            BranchInfo::None,
+            root.arena.alloc(
+                //
+                box_stmt(root.arena.alloc(
+                    //
+                    elem_stmt(root.arena.alloc(
+                        //
+                        elem_hash_stmt(root.arena.alloc(
+                            //
+                            next_addr_stmt(root.arena.alloc(
+                                //
+                                jump_back,
+                            )),
+                        )),
+                    )),
+                )),
+            ),
+        ),
+    };
+
+    let joinpoint_loop = Stmt::Join {
+        id: elems_loop,
+        parameters: root.arena.alloc([param_addr, param_seed]),
+        body: root.arena.alloc(
+            //
+            is_end_stmt(
+                //
+                root.arena.alloc(if_end_of_list),
+            ),
+        ),
+        remainder: root
+            .arena
+            .alloc(Stmt::Jump(elems_loop, root.arena.alloc([start, len_hash]))),
+    };
+
+    len_stmt(root.arena.alloc(
+        //
+        len_hash_stmt(root.arena.alloc(
+            //
+            elements_stmt(root.arena.alloc(
+                //
+                start_stmt(root.arena.alloc(
+                    //
+                    size_stmt(root.arena.alloc(
+                        //
+                        list_size_stmt(root.arena.alloc(
+                            //
+                            end_stmt(root.arena.alloc(
+                                //
+                                joinpoint_loop,
+                            )),
+                        )),
+                    )),
+                )),
+            )),
+        )),
+    ))
+}
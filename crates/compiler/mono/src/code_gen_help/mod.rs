@@ -1,5 +1,6 @@
 use bumpalo::collections::vec::Vec;
 use bumpalo::Bump;
+use roc_builtins::bitcode::IntWidth;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
 use roc_target::TargetInfo;
@@ -11,10 +12,12 @@ use crate::ir::{
 use crate::layout::{Builtin, CapturesNiche, LambdaName, Layout, STLayoutInterner, UnionLayout};
 
 mod equality;
+mod hash;
 mod refcount;
 
 const LAYOUT_BOOL: Layout = Layout::Builtin(Builtin::Bool);
 const LAYOUT_UNIT: Layout = Layout::UNIT;
+const LAYOUT_U64: Layout = Layout::Builtin(Builtin::Int(IntWidth::U64));
 
 const ARG_1: Symbol = Symbol::ARG_1;
 const ARG_2: Symbol = Symbol::ARG_2;
@@ -30,6 +33,7 @@ pub enum HelperOp {
     DecRef(JoinPointId),
     Reset,
     Eq,
+    Hash,
 }
 
 impl HelperOp {
@@ -229,6 +233,28 @@ impl<'a> CodeGenHelp<'a> {
         (expr, ctx.new_linker_data)
     }
 
+    /// Replace a generic `Lowlevel::Hash` call with a specialized helper proc.
+    /// The arguments are the value to hash and a `U64` seed.
+    /// The helper procs themselves are to be generated later with `generate_procs`
+    pub fn call_specialized_hash(
+        &mut self,
+        ident_ids: &mut IdentIds,
+        layout: &Layout<'a>,
+        arguments: &'a [Symbol],
+    ) -> (Expr<'a>, Vec<'a, (Symbol, ProcLayout<'a>)>) {
+        let mut ctx = Context {
+            new_linker_data: Vec::new_in(self.arena),
+            recursive_union: None,
+            op: HelperOp::Hash,
+        };
+
+        let expr = self
+            .call_specialized_op(ident_ids, &mut ctx, *layout, arguments)
+            .unwrap();
+
+        (expr, ctx.new_linker_data)
+    }
+
     // ============================================================================
     //
     //              CALL SPECIALIZED OP
@@ -264,6 +290,7 @@ impl<'a> CodeGenHelp<'a> {
                     Reset => (self.arena.alloc(layout), self.arena.alloc([layout])),
                     Inc => (&LAYOUT_UNIT, self.arena.alloc([arg, self.layout_isize])),
                     Eq => (&LAYOUT_BOOL, self.arena.alloc([arg, arg])),
+                    Hash => (&LAYOUT_U64, self.arena.alloc([arg, LAYOUT_U64])),
                 }
             };
 
@@ -276,10 +303,16 @@ impl<'a> CodeGenHelp<'a> {
                 },
                 arguments,
             }))
-        } else if ctx.op == HelperOp::Eq {
+        } else if matches!(ctx.op, HelperOp::Eq | HelperOp::Hash) {
+            let op = if ctx.op == HelperOp::Eq {
+                LowLevel::Eq
+            } else {
+                LowLevel::Hash
+            };
+
             Some(Expr::Call(Call {
                 call_type: CallType::LowLevel {
-                    op: LowLevel::Eq,
+                    op,
                     update_mode: UpdateModeId::BACKEND_DUMMY,
                 },
                 arguments,
@@ -335,6 +368,7 @@ impl<'a> CodeGenHelp<'a> {
                 LAYOUT_BOOL,
                 equality::eq_generic(self, ident_ids, ctx, layout),
             ),
+            Hash => (LAYOUT_U64, hash::hash_generic(self, ident_ids, ctx, layout)),
         };
 
         let args: &'a [(Layout<'a>, Symbol)] = {
@@ -346,6 +380,7 @@ impl<'a> CodeGenHelp<'a> {
                 }
                 Dec | DecRef(_) | Reset => self.arena.alloc([roc_value]),
                 Eq => self.arena.alloc([roc_value, (layout, ARG_2)]),
+                Hash => self.arena.alloc([roc_value, (LAYOUT_U64, ARG_2)]),
             }
         };
 
@@ -400,6 +435,11 @@ impl<'a> CodeGenHelp<'a> {
                 result: LAYOUT_BOOL,
                 captures_niche: CapturesNiche::no_niche(),
             },
+            HelperOp::Hash => ProcLayout {
+                arguments: self.arena.alloc([*layout, LAYOUT_U64]),
+                result: LAYOUT_U64,
+                captures_niche: CapturesNiche::no_niche(),
+            },
         };
 
         (proc_symbol, proc_layout)
@@ -537,7 +577,7 @@ fn layout_needs_helper_proc(layout: &Layout, op: HelperOp) -> bool {
         }
         Layout::Builtin(Builtin::Str) => {
             // Str type can use either Zig functions or generated IR, since it's not generic.
-            // Eq uses a Zig function, refcount uses generated IR.
+            // Eq and Hash use Zig functions, refcount uses generated IR.
             // Both are fine, they were just developed at different times.
             matches!(op, HelperOp::Inc | HelperOp::Dec | HelperOp::DecRef(_))
         }
@@ -627,6 +627,27 @@ fn refcount_str<'a>(
     });
     let is_big_str_stmt = |next| Stmt::Let(is_big_str, is_big_str_expr, LAYOUT_BOOL, next);
 
+    // Get the length, whose sign bit marks a seamless slice
+    let length = root.create_symbol(ident_ids, "length");
+    let length_expr = Expr::StructAtIndex {
+        index: 1,
+        field_layouts,
+        structure: string,
+    };
+    let length_stmt = |next| Stmt::Let(length, length_expr, layout_isize, next);
+
+    // is_seamless_slice = (length < 0);
+    let is_seamless_slice = root.create_symbol(ident_ids, "is_seamless_slice");
+    let is_seamless_slice_expr = Expr::Call(Call {
+        call_type: CallType::LowLevel {
+            op: LowLevel::NumLt,
+            update_mode: UpdateModeId::BACKEND_DUMMY,
+        },
+        arguments: root.arena.alloc([length, zero]),
+    });
+    let is_seamless_slice_stmt =
+        |next| Stmt::Let(is_seamless_slice, is_seamless_slice_expr, LAYOUT_BOOL, next);
+
     // Get the pointer to the string elements
     let elements = root.create_symbol(ident_ids, "elements");
     let elements_expr = Expr::StructAtIndex {
@@ -636,6 +657,19 @@ fn refcount_str<'a>(
     };
     let elements_stmt = |next| Stmt::Let(elements, elements_expr, layout_isize, next);
 
+    // A seamless slice stores the elements pointer of its allocation, shifted right by one,
+    // in its last word. Shift it back by adding it to itself.
+    let slice_elements = root.create_symbol(ident_ids, "slice_elements");
+    let slice_elements_expr = Expr::Call(Call {
+        call_type: CallType::LowLevel {
+            op: LowLevel::NumAdd,
+            update_mode: UpdateModeId::BACKEND_DUMMY,
+        },
+        arguments: root.arena.alloc([last_word, last_word]),
+    });
+    let slice_elements_stmt =
+        |next| Stmt::Let(slice_elements, slice_elements_expr, layout_isize, next);
+
     // A pointer to the refcount value itself
     let rc_ptr = root.create_symbol(ident_ids, "rc_ptr");
     let alignment = root.target_info.ptr_width() as u32;
@@ -650,20 +684,55 @@ fn refcount_str<'a>(
         root.arena.alloc(ret_unit_stmt),
     );
 
+    // Both kinds of big string modify the refcount of the allocation their data pointer is in
+    let jp_modify_rc = JoinPointId(root.create_symbol(ident_ids, "jp_modify_rc"));
+    let data_ptr = root.create_symbol(ident_ids, "data_ptr");
+    let jp_param = Param {
+        symbol: data_ptr,
+        borrow: true,
+        layout: layout_isize,
+    };
+    let jp_body = rc_ptr_from_data_ptr(
+        root,
+        ident_ids,
+        data_ptr,
+        rc_ptr,
+        false,
+        root.arena.alloc(
+            //
+            mod_rc_stmt,
+        ),
+    );
+
+    let slice_branch = slice_elements_stmt(root.arena.alloc(
+        //
+        Stmt::Jump(jp_modify_rc, root.arena.alloc([slice_elements])),
+    ));
+    let big_str_branch = elements_stmt(root.arena.alloc(
+        //
+        Stmt::Jump(jp_modify_rc, root.arena.alloc([elements])),
+    ));
+
+    let slice_switch = Stmt::Switch {
+        cond_symbol: is_seamless_slice,
+        cond_layout: LAYOUT_BOOL,
+        branches: root.arena.alloc([(1, BranchInfo::None, slice_branch)]),
+        default_branch: (BranchInfo::None, root.arena.alloc(big_str_branch)),
+        ret_layout: LAYOUT_UNIT,
+    };
+
     // Generate an `if` to skip small strings but modify big strings
-    let then_branch = elements_stmt(root.arena.alloc(
+    let then_branch = length_stmt(root.arena.alloc(
         //
-        rc_ptr_from_data_ptr(
-            root,
-            ident_ids,
-            elements,
-            rc_ptr,
-            false,
-            root.arena.alloc(
-                //
-                mod_rc_stmt,
-            ),
-        ),
+        is_seamless_slice_stmt(root.arena.alloc(
+            //
+            Stmt::Join {
+                id: jp_modify_rc,
+                parameters: root.arena.alloc([jp_param]),
+                body: root.arena.alloc(jp_body),
+                remainder: root.arena.alloc(slice_switch),
+            },
+        )),
     ));
 
     let if_stmt = Stmt::Switch {
@@ -729,14 +798,89 @@ fn refcount_list<'a>(
     let is_empty_stmt = |next| Stmt::Let(is_empty, is_empty_expr, LAYOUT_BOOL, next);
 
     // get elements pointer
+    let field_layouts = arena.alloc([box_layout, layout_isize, layout_isize]);
     let elements = root.create_symbol(ident_ids, "elements");
     let elements_expr = Expr::StructAtIndex {
         index: 0,
-        field_layouts: arena.alloc([box_layout, layout_isize, layout_isize]),
+        field_layouts,
         structure,
     };
     let elements_stmt = |next| Stmt::Let(elements, elements_expr, box_layout, next);
 
+    //
+    // Find the allocation the elements live in
+    //
+
+    // Get the capacity, whose sign bit marks a seamless slice
+    let capacity = root.create_symbol(ident_ids, "capacity");
+    let capacity_expr = Expr::StructAtIndex {
+        index: 2,
+        field_layouts,
+        structure,
+    };
+    let capacity_stmt = |next| Stmt::Let(capacity, capacity_expr, layout_isize, next);
+
+    // is_seamless_slice = (capacity < 0);
+    let is_seamless_slice = root.create_symbol(ident_ids, "is_seamless_slice");
+    let is_seamless_slice_stmt = |next| {
+        let_lowlevel(
+            arena,
+            LAYOUT_BOOL,
+            is_seamless_slice,
+            NumLt,
+            &[capacity, zero],
+            next,
+        )
+    };
+
+    // A seamless slice stores the elements pointer of its allocation, shifted right by one,
+    // in the rest of its capacity. Clear the sign bit, then shift it back by adding it to itself.
+    let max_isize = root.create_symbol(ident_ids, "max_isize");
+    let max_isize_expr = Expr::Literal(Literal::Int(
+        match root.target_info.ptr_width() {
+            PtrWidth::Bytes4 => i32::MAX as i128,
+            PtrWidth::Bytes8 => i64::MAX as i128,
+        }
+        .to_ne_bytes(),
+    ));
+    let max_isize_stmt = |next| Stmt::Let(max_isize, max_isize_expr, layout_isize, next);
+
+    let shifted_elements = root.create_symbol(ident_ids, "shifted_elements");
+    let shifted_elements_stmt = |next| {
+        let_lowlevel(
+            arena,
+            layout_isize,
+            shifted_elements,
+            NumBitwiseAnd,
+            &[capacity, max_isize],
+            next,
+        )
+    };
+
+    let slice_elements = root.create_symbol(ident_ids, "slice_elements");
+    let slice_elements_stmt = |next| {
+        let_lowlevel(
+            arena,
+            layout_isize,
+            slice_elements,
+            NumAdd,
+            &[shifted_elements, shifted_elements],
+            next,
+        )
+    };
+
+    let elements_addr = root.create_symbol(ident_ids, "elements_addr");
+    let elements_addr_stmt = |next| {
+        let_lowlevel(
+            arena,
+            layout_isize,
+            elements_addr,
+            PtrCast,
+            &[elements],
+            next,
+        )
+    };
+
     //
     // modify refcount of the list and its elements
     // (elements first, to avoid use-after-free for Dec)
@@ -755,15 +899,59 @@ fn refcount_list<'a>(
         arena.alloc(ret_stmt),
     );
 
-    let get_rc_and_modify_list = rc_ptr_from_data_ptr(
+    // Both kinds of list modify the refcount of the allocation their data pointer is in
+    let jp_modify_rc = JoinPointId(root.create_symbol(ident_ids, "jp_modify_rc"));
+    let data_ptr = root.create_symbol(ident_ids, "data_ptr");
+    let jp_param = Param {
+        symbol: data_ptr,
+        borrow: true,
+        layout: layout_isize,
+    };
+    let jp_body = rc_ptr_from_data_ptr(
         root,
         ident_ids,
-        elements,
+        data_ptr,
         rc_ptr,
         false,
         arena.alloc(modify_list),
     );
 
+    let slice_branch = max_isize_stmt(arena.alloc(
+        //
+        shifted_elements_stmt(arena.alloc(
+            //
+            slice_elements_stmt(arena.alloc(
+                //
+                Stmt::Jump(jp_modify_rc, arena.alloc([slice_elements])),
+            )),
+        )),
+    ));
+    let list_branch = elements_addr_stmt(arena.alloc(
+        //
+        Stmt::Jump(jp_modify_rc, arena.alloc([elements_addr])),
+    ));
+
+    let slice_switch = Stmt::Switch {
+        cond_symbol: is_seamless_slice,
+        cond_layout: LAYOUT_BOOL,
+        branches: arena.alloc([(1, BranchInfo::None, slice_branch)]),
+        default_branch: (BranchInfo::None, arena.alloc(list_branch)),
+        ret_layout: LAYOUT_UNIT,
+    };
+
+    let get_rc_and_modify_list = capacity_stmt(arena.alloc(
+        //
+        is_seamless_slice_stmt(arena.alloc(
+            //
+            Stmt::Join {
+                id: jp_modify_rc,
+                parameters: arena.alloc([jp_param]),
+                body: arena.alloc(jp_body),
+                remainder: arena.alloc(slice_switch),
+            },
+        )),
+    ));
+
     let modify_elems_and_list = if elem_layout.is_refcounted() && !ctx.op.is_decref() {
         refcount_list_elems(
             root,
@@ -616,7 +616,7 @@ fn expand_and_cancel<'a>(env: &mut Env<'a, '_>, stmt: &'a Stmt<'a>) -> &'a Stmt<
                 env.arena.alloc(stmt)
             }
 
-            Ret(_) | Jump(_, _) | RuntimeError(_) => stmt,
+            Ret(_) | Jump(_, _) | Crash { .. } | RuntimeError(_) => stmt,
         }
     };
 
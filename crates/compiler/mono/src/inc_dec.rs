@@ -98,7 +98,10 @@ pub fn occurring_variables(stmt: &Stmt<'_>) -> (MutSet<Symbol>, MutSet<Symbol>)
                 stack.push(cont);
             }
 
-            Ret(symbol) => {
+            Ret(symbol)
+            | Crash {
+                message: symbol, ..
+            } => {
                 result.insert(*symbol);
             }
 
@@ -130,6 +133,13 @@ pub fn occurring_variables(stmt: &Stmt<'_>) -> (MutSet<Symbol>, MutSet<Symbol>)
                 stack.push(remainder);
             }
 
+            Dbg {
+                symbol, remainder, ..
+            } => {
+                result.insert(*symbol);
+                stack.push(remainder);
+            }
+
             Jump(_, arguments) => {
                 result.extend(arguments.iter().copied());
             }
@@ -1240,6 +1250,34 @@ impl<'a, 'i> Context<'a, 'i> {
                 (expect, b_live_vars)
             }
 
+            Dbg {
+                symbol,
+                region,
+                remainder,
+            } => {
+                let (b, mut b_live_vars) = self.visit_stmt(codegen, remainder);
+
+                let dbg = self.arena.alloc(Stmt::Dbg {
+                    symbol: *symbol,
+                    region: *region,
+                    remainder: b,
+                });
+
+                let dbg = self.add_inc_before_consume_all(&[*symbol], dbg, &b_live_vars);
+
+                b_live_vars.insert(*symbol);
+
+                (dbg, b_live_vars)
+            }
+
+            Crash { message, .. } => {
+                // the program stops here, so the message is never decremented
+                let mut live_vars = MutSet::default();
+                live_vars.insert(*message);
+
+                (stmt, live_vars)
+            }
+
             RuntimeError(_) | Refcounting(_, _) => (stmt, MutSet::default()),
         }
     }
@@ -1332,7 +1370,10 @@ pub fn collect_stmt(
             vars
         }
 
-        Ret(symbol) => {
+        Ret(symbol)
+        | Crash {
+            message: symbol, ..
+        } => {
             vars.insert(*symbol);
             vars
         }
@@ -1365,6 +1406,13 @@ pub fn collect_stmt(
             collect_stmt(remainder, jp_live_vars, vars)
         }
 
+        Dbg {
+            symbol, remainder, ..
+        } => {
+            vars.insert(*symbol);
+            collect_stmt(remainder, jp_live_vars, vars)
+        }
+
         Join {
             id: j,
             parameters,
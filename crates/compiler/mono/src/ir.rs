@@ -1571,6 +1571,13 @@ pub enum Stmt<'a> {
         /// what happens after the expect
         remainder: &'a Stmt<'a>,
     },
+    Dbg {
+        /// the value that is reported
+        symbol: Symbol,
+        region: Region,
+        /// what happens after the dbg
+        remainder: &'a Stmt<'a>,
+    },
     /// a join point `join f <params> = <continuation> in remainder`
     Join {
         id: JoinPointId,
@@ -1582,6 +1589,12 @@ pub enum Stmt<'a> {
         remainder: &'a Stmt<'a>,
     },
     Jump(JoinPointId, &'a [Symbol]),
+    /// a `crash` in user code; unlike a `RuntimeError`, the message is a runtime `Str`
+    Crash {
+        message: Symbol,
+        /// where the message is in the source, for reporting
+        region: Region,
+    },
     RuntimeError(&'a str),
 }
 
@@ -2170,11 +2183,25 @@ impl<'a> Stmt<'a> {
                 .append(alloc.hardline())
                 .append(remainder.to_doc(alloc, interner)),
 
+            Dbg {
+                symbol, remainder, ..
+            } => alloc
+                .text("dbg ")
+                .append(symbol_to_doc(alloc, *symbol))
+                .append(";")
+                .append(alloc.hardline())
+                .append(remainder.to_doc(alloc, interner)),
+
             Ret(symbol) => alloc
                 .text("ret ")
                 .append(symbol_to_doc(alloc, *symbol))
                 .append(";"),
 
+            Crash { message, .. } => alloc
+                .text("crash ")
+                .append(symbol_to_doc(alloc, *message))
+                .append(";"),
+
             Switch {
                 cond_symbol,
                 branches,
@@ -2737,7 +2764,10 @@ fn pattern_to_when<'a>(
             (env.unique_symbol(), Loc::at_zero(RuntimeError(error)))
         }
 
-        AppliedTag { .. } | RecordDestructure { .. } | UnwrappedOpaque { .. } => {
+        AppliedTag { .. }
+        | RecordDestructure { .. }
+        | TupleDestructure { .. }
+        | UnwrappedOpaque { .. } => {
             let symbol = env.unique_symbol();
 
             let wrapped_body = When {
@@ -4211,100 +4241,71 @@ pub fn with_hole<'a>(
                 Err(_) => return Stmt::RuntimeError("Can't create record with improper layout"),
             };
 
-            let mut field_symbols = Vec::with_capacity_in(fields.len(), env.arena);
-            let mut can_fields = Vec::with_capacity_in(fields.len(), env.arena);
-
-            #[allow(clippy::enum_variant_names)]
-            enum Field {
-                // TODO: rename this since it can handle unspecialized expressions now too
-                FunctionOrUnspecialized(Symbol, Variable),
-                ValueSymbol,
-                Field(roc_can::expr::Field),
-            }
-
-            for (label, variable, _) in sorted_fields.into_iter() {
-                // TODO how should function pointers be handled here?
-                use ReuseSymbol::*;
-                match fields.remove(&label) {
-                    Some(field) => {
-                        match can_reuse_symbol(env, procs, &field.loc_expr.value, field.var) {
-                            Imported(symbol)
-                            | LocalFunction(symbol)
-                            | UnspecializedExpr(symbol) => {
-                                field_symbols.push(symbol);
-                                can_fields.push(Field::FunctionOrUnspecialized(symbol, variable));
-                            }
-                            Value(symbol) => {
-                                let reusable = procs.symbol_specializations.get_or_insert(
-                                    env,
-                                    layout_cache,
-                                    symbol,
-                                    field.var,
-                                );
-                                field_symbols.push(reusable);
-                                can_fields.push(Field::ValueSymbol);
-                            }
-                            NotASymbol => {
-                                field_symbols.push(env.unique_symbol());
-                                can_fields.push(Field::Field(field));
-                            }
-                        }
-                    }
-                    None => {
-                        // this field was optional, but not given
-                        continue;
-                    }
-                }
-            }
-
             // creating a record from the var will unpack it if it's just a single field.
             let layout = match layout_cache.from_var(env.arena, record_var, env.subs) {
                 Ok(layout) => layout,
                 Err(_) => return Stmt::RuntimeError("Can't create record with improper layout"),
             };
 
-            let field_symbols = field_symbols.into_bump_slice();
+            let sorted_fields = sorted_fields
+                .into_iter()
+                .map(|(label, variable, _)| (label, variable));
 
-            let mut stmt = if let [only_field] = field_symbols {
-                let mut hole = hole.clone();
-                substitute_in_exprs(env.arena, &mut hole, assigned, *only_field);
-                hole
-            } else {
-                Stmt::Let(assigned, Expr::Struct(field_symbols), layout, hole)
+            compile_struct_like(
+                env,
+                procs,
+                layout_cache,
+                sorted_fields,
+                // a missing field was optional, but not given
+                |label| {
+                    fields
+                        .remove(label)
+                        .map(|field| (field.var, field.loc_expr))
+                },
+                layout,
+                assigned,
+                hole,
+            )
+        }
+
+        Tuple {
+            tuple_var, elems, ..
+        } => {
+            let sorted_elems_result = {
+                let mut layout_env = layout::Env::from_components(
+                    layout_cache,
+                    env.subs,
+                    env.arena,
+                    env.target_info,
+                );
+                layout::sort_tuple_elems(&mut layout_env, tuple_var)
+            };
+            let sorted_elems = match sorted_elems_result {
+                Ok(elems) => elems,
+                Err(_) => return Stmt::RuntimeError("Can't create tuple with improper layout"),
             };
 
-            for (opt_field, symbol) in can_fields.into_iter().rev().zip(field_symbols.iter().rev())
-            {
-                match opt_field {
-                    Field::ValueSymbol => {
-                        // this symbol is already defined; nothing to do
-                    }
-                    Field::FunctionOrUnspecialized(symbol, variable) => {
-                        stmt = specialize_symbol(
-                            env,
-                            procs,
-                            layout_cache,
-                            Some(variable),
-                            symbol,
-                            stmt,
-                            symbol,
-                        );
-                    }
-                    Field::Field(field) => {
-                        stmt = with_hole(
-                            env,
-                            field.loc_expr.value,
-                            field.var,
-                            procs,
-                            layout_cache,
-                            *symbol,
-                            env.arena.alloc(stmt),
-                        );
-                    }
-                }
-            }
+            let layout = match layout_cache.from_var(env.arena, tuple_var, env.subs) {
+                Ok(layout) => layout,
+                Err(_) => return Stmt::RuntimeError("Can't create tuple with improper layout"),
+            };
 
-            stmt
+            let mut elems: std::vec::Vec<_> = elems.into_iter().map(Some).collect();
+
+            let sorted_elems = sorted_elems
+                .into_iter()
+                .map(|(index, variable, _)| (index, variable));
+
+            compile_struct_like(
+                env,
+                procs,
+                layout_cache,
+                sorted_elems,
+                |index| elems[*index].take(),
+                layout,
+                assigned,
+                hole,
+            )
         }
 
         EmptyRecord => let_empty_struct(assigned, hole),
@@ -4312,6 +4313,47 @@ pub fn with_hole<'a>(
         Expect { .. } => unreachable!("I think this is unreachable"),
         ExpectFx { .. } => unreachable!("I think this is unreachable"),
 
+        Dbg {
+            loc_value,
+            variable: _,
+        } => {
+            let dbg = Stmt::Dbg {
+                symbol: assigned,
+                region: loc_value.region,
+                remainder: hole,
+            };
+
+            with_hole(
+                env,
+                loc_value.value,
+                variable,
+                procs,
+                layout_cache,
+                assigned,
+                env.arena.alloc(dbg),
+            )
+        }
+
+        Crash { msg, ret_var: _ } => {
+            // the crash does not continue, so the hole is never filled
+            let message = env.unique_symbol();
+
+            let crash = Stmt::Crash {
+                message,
+                region: msg.region,
+            };
+
+            with_hole(
+                env,
+                msg.value,
+                Variable::STR,
+                procs,
+                layout_cache,
+                message,
+                env.arena.alloc(crash),
+            )
+        }
+
         If {
             cond_var,
             branch_var,
@@ -4675,6 +4717,83 @@ pub fn with_hole<'a>(
             stmt
         }
 
+        TupleAccess {
+            tuple_var,
+            elem_var,
+            index: accessed_index,
+            loc_expr,
+            ..
+        } => {
+            let sorted_elems_result = {
+                let mut layout_env = layout::Env::from_components(
+                    layout_cache,
+                    env.subs,
+                    env.arena,
+                    env.target_info,
+                );
+                layout::sort_tuple_elems(&mut layout_env, tuple_var)
+            };
+            let sorted_elems = match sorted_elems_result {
+                Ok(elems) => elems,
+                Err(_) => return Stmt::RuntimeError("Can't access tuple with improper layout"),
+            };
+
+            let mut final_index = None;
+            let mut elem_layouts = Vec::with_capacity_in(sorted_elems.len(), env.arena);
+
+            for (current, (index, _, elem_layout)) in sorted_elems.into_iter().enumerate() {
+                elem_layouts.push(elem_layout);
+
+                if index == accessed_index {
+                    final_index = Some(current);
+                }
+            }
+
+            let tuple_symbol = possible_reuse_symbol_or_specialize(
+                env,
+                procs,
+                layout_cache,
+                &loc_expr.value,
+                tuple_var,
+            );
+
+            let mut stmt = match elem_layouts.as_slice() {
+                [_] => {
+                    let mut hole = hole.clone();
+                    substitute_in_exprs(env.arena, &mut hole, assigned, tuple_symbol);
+
+                    hole
+                }
+                _ => {
+                    let expr = Expr::StructAtIndex {
+                        index: final_index.expect("elem not in its own type") as u64,
+                        field_layouts: elem_layouts.into_bump_slice(),
+                        structure: tuple_symbol,
+                    };
+
+                    let layout = layout_cache
+                        .from_var(env.arena, elem_var, env.subs)
+                        .unwrap_or_else(|err| {
+                            panic!("TODO turn fn_var into a RuntimeError {:?}", err)
+                        });
+
+                    Stmt::Let(assigned, expr, layout, hole)
+                }
+            };
+
+            stmt = assign_to_symbol(
+                env,
+                procs,
+                layout_cache,
+                tuple_var,
+                *loc_expr,
+                tuple_symbol,
+                stmt,
+            );
+
+            stmt
+        }
+
         Accessor(accessor_data) => {
             let field_var = accessor_data.field_var;
             let fresh_record_symbol = env.unique_symbol();
@@ -5528,6 +5647,7 @@ fn late_resolve_ability_specialization<'a>(
                         // The immediate is an ability member itself, so it must be resolved!
                         late_resolve_ability_specialization(env, imm, None, specialization_var)
                     }
+                    roc_derive_key::Derived::SingleLambdaSetImmediate(imm) => imm,
                     roc_derive_key::Derived::Key(derive_key) => {
                         let mut derived_module = env
                             .derived_module
@@ -6820,6 +6940,31 @@ fn substitute_in_stmt_help<'a>(
             Some(arena.alloc(expect))
         }
 
+        Dbg {
+            symbol,
+            region,
+            remainder,
+        } => {
+            let new_remainder =
+                substitute_in_stmt_help(arena, remainder, subs).unwrap_or(remainder);
+
+            let dbg = Dbg {
+                symbol: substitute(subs, *symbol).unwrap_or(*symbol),
+                region: *region,
+                remainder: new_remainder,
+            };
+
+            Some(arena.alloc(dbg))
+        }
+
+        Crash { message, region } => match substitute(subs, *message) {
+            None => None,
+            Some(message) => Some(arena.alloc(Crash {
+                message,
+                region: *region,
+            })),
+        },
+
         Jump(id, args) => {
             let mut did_change = false;
             let new_args = Vec::from_iter_in(
@@ -7185,6 +7330,43 @@ fn store_pattern_help<'a>(
                 }
             }
         }
+        TupleDestructure(destructs, [_single_elem]) => {
+            return store_pattern_help(
+                env,
+                procs,
+                layout_cache,
+                &destructs[0].pat,
+                outer_symbol,
+                stmt,
+            );
+        }
+        TupleDestructure(destructs, sorted_elems) => {
+            let mut is_productive = false;
+            for (index, destruct) in destructs.iter().enumerate().rev() {
+                match store_tuple_destruct(
+                    env,
+                    procs,
+                    layout_cache,
+                    destruct,
+                    index as u64,
+                    outer_symbol,
+                    sorted_elems,
+                    stmt,
+                ) {
+                    StorePattern::Productive(new) => {
+                        is_productive = true;
+                        stmt = new;
+                    }
+                    StorePattern::NotProductive(new) => {
+                        stmt = new;
+                    }
+                }
+            }
+
+            if !is_productive {
+                return StorePattern::NotProductive(stmt);
+            }
+        }
         RecordDestructure(destructs, sorted_fields) => {
             let mut is_productive = false;
             for (index, destruct) in destructs.iter().enumerate().rev() {
@@ -7477,6 +7659,71 @@ fn store_record_destruct<'a>(
     StorePattern::Productive(stmt)
 }
 
+#[allow(clippy::too_many_arguments)]
+fn store_tuple_destruct<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    destruct: &TupleDestruct<'a>,
+    index: u64,
+    outer_symbol: Symbol,
+    sorted_elems: &'a [Layout<'a>],
+    mut stmt: Stmt<'a>,
+) -> StorePattern<'a> {
+    use Pattern::*;
+
+    let load = Expr::StructAtIndex {
+        index,
+        field_layouts: sorted_elems,
+        structure: outer_symbol,
+    };
+
+    match &destruct.pat {
+        Identifier(symbol) => {
+            let specialization_symbol = procs
+                .symbol_specializations
+                .remove_single(*symbol)
+                // Can happen when the symbol was never used under this body, and hence has no
+                // requested specialization.
+                .unwrap_or(*symbol);
+
+            stmt = Stmt::Let(
+                specialization_symbol,
+                load,
+                destruct.layout,
+                env.arena.alloc(stmt),
+            );
+        }
+        Underscore => {
+            // elements that are not bound in the source are guarded with the underscore pattern;
+            // like for records, make sure they are not stored/loaded.
+            return StorePattern::NotProductive(stmt);
+        }
+        IntLiteral(_, _)
+        | FloatLiteral(_, _)
+        | DecimalLiteral(_)
+        | EnumLiteral { .. }
+        | BitLiteral { .. }
+        | StrLiteral(_) => {
+            return StorePattern::NotProductive(stmt);
+        }
+
+        _ => {
+            let symbol = env.unique_symbol();
+
+            match store_pattern_help(env, procs, layout_cache, &destruct.pat, symbol, stmt) {
+                StorePattern::Productive(new) => {
+                    stmt = new;
+                    stmt = Stmt::Let(symbol, load, destruct.layout, env.arena.alloc(stmt));
+                }
+                StorePattern::NotProductive(stmt) => return StorePattern::NotProductive(stmt),
+            }
+        }
+    }
+
+    StorePattern::Productive(stmt)
+}
+
 /// We want to re-use symbols that are not function symbols
 /// for any other expression, we create a new symbol, and will
 /// later make sure it gets assigned the correct value.
@@ -7698,6 +7945,101 @@ fn force_thunk<'a>(
     build_call(env, call, assigned, layout, env.arena.alloc(hole))
 }
 
+/// Compile a record or tuple, whose fields (or elements) are given in layout order.
+#[allow(clippy::too_many_arguments)]
+fn compile_struct_like<'a, L>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    sorted_elems: impl ExactSizeIterator<Item = (L, Variable)>,
+    mut take_elem_expr: impl FnMut(&L) -> Option<(Variable, Box<Loc<roc_can::expr::Expr>>)>,
+    layout: Layout<'a>,
+    assigned: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    let mut elem_symbols = Vec::with_capacity_in(sorted_elems.len(), env.arena);
+    let mut can_elems = Vec::with_capacity_in(sorted_elems.len(), env.arena);
+
+    #[allow(clippy::enum_variant_names)]
+    enum Field {
+        // TODO: rename this since it can handle unspecialized expressions now too
+        FunctionOrUnspecialized(Symbol, Variable),
+        ValueSymbol,
+        Field(Variable, Loc<roc_can::expr::Expr>),
+    }
+
+    for (label, variable) in sorted_elems {
+        // TODO how should function pointers be handled here?
+        use ReuseSymbol::*;
+        match take_elem_expr(&label) {
+            Some((var, loc_expr)) => match can_reuse_symbol(env, procs, &loc_expr.value, var) {
+                Imported(symbol) | LocalFunction(symbol) | UnspecializedExpr(symbol) => {
+                    elem_symbols.push(symbol);
+                    can_elems.push(Field::FunctionOrUnspecialized(symbol, variable));
+                }
+                Value(symbol) => {
+                    let reusable =
+                        procs
+                            .symbol_specializations
+                            .get_or_insert(env, layout_cache, symbol, var);
+                    elem_symbols.push(reusable);
+                    can_elems.push(Field::ValueSymbol);
+                }
+                NotASymbol => {
+                    elem_symbols.push(env.unique_symbol());
+                    can_elems.push(Field::Field(var, *loc_expr));
+                }
+            },
+            None => {
+                // this field was optional, but not given
+                continue;
+            }
+        }
+    }
+
+    let elem_symbols = elem_symbols.into_bump_slice();
+
+    let mut stmt = if let [only_field] = elem_symbols {
+        let mut hole = hole.clone();
+        substitute_in_exprs(env.arena, &mut hole, assigned, *only_field);
+        hole
+    } else {
+        Stmt::Let(assigned, Expr::Struct(elem_symbols), layout, hole)
+    };
+
+    for (opt_field, symbol) in can_elems.into_iter().rev().zip(elem_symbols.iter().rev()) {
+        match opt_field {
+            Field::ValueSymbol => {
+                // this symbol is already defined; nothing to do
+            }
+            Field::FunctionOrUnspecialized(symbol, variable) => {
+                stmt = specialize_symbol(
+                    env,
+                    procs,
+                    layout_cache,
+                    Some(variable),
+                    symbol,
+                    stmt,
+                    symbol,
+                );
+            }
+            Field::Field(var, loc_expr) => {
+                stmt = with_hole(
+                    env,
+                    loc_expr.value,
+                    var,
+                    procs,
+                    layout_cache,
+                    *symbol,
+                    env.arena.alloc(stmt),
+                );
+            }
+        }
+    }
+
+    stmt
+}
+
 fn let_empty_struct<'a>(assigned: Symbol, hole: &'a Stmt<'a>) -> Stmt<'a> {
     Stmt::Let(assigned, Expr::Struct(&[]), Layout::UNIT, hole)
 }
@@ -8753,6 +9095,7 @@ pub enum Pattern<'a> {
     StrLiteral(Box<str>),
 
     RecordDestructure(Vec<'a, RecordDestruct<'a>>, &'a [Layout<'a>]),
+    TupleDestructure(Vec<'a, TupleDestruct<'a>>, &'a [Layout<'a>]),
     NewtypeDestructure {
         tag_name: TagName,
         arguments: Vec<'a, (Pattern<'a>, Layout<'a>)>,
@@ -8799,6 +9142,9 @@ impl<'a> Pattern<'a> {
                         }
                     }
                 }
+                Pattern::TupleDestructure(destructs, _) => {
+                    stack.extend(destructs.iter().map(|destruct| &destruct.pat))
+                }
                 Pattern::NewtypeDestructure { arguments, .. } => {
                     stack.extend(arguments.iter().map(|(t, _)| t))
                 }
@@ -8828,6 +9174,14 @@ pub enum DestructType<'a> {
     Guard(Pattern<'a>),
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct TupleDestruct<'a> {
+    pub index: usize,
+    pub variable: Variable,
+    pub layout: Layout<'a>,
+    pub pat: Pattern<'a>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct WhenBranch<'a> {
     pub patterns: Vec<'a, Pattern<'a>>,
@@ -9555,6 +9909,75 @@ fn from_can_pattern_help<'a>(
                 field_layouts.into_bump_slice(),
             ))
         }
+
+        TupleDestructure {
+            whole_var,
+            destructs,
+            ..
+        } => {
+            // sorted elements based on the type
+            let sorted_elems = {
+                let mut layout_env = layout::Env::from_components(
+                    layout_cache,
+                    env.subs,
+                    env.arena,
+                    env.target_info,
+                );
+                crate::layout::sort_tuple_elems(&mut layout_env, *whole_var)
+                    .map_err(RuntimeError::from)?
+            };
+
+            // sorted elements based on the destruct
+            let mut mono_destructs = Vec::with_capacity_in(destructs.len(), env.arena);
+            let mut destructs_by_index = BumpMap::with_capacity_in(destructs.len(), env.arena);
+            destructs_by_index.extend(destructs.iter().map(|x| (x.value.destruct_index, x)));
+
+            let mut elem_layouts = Vec::with_capacity_in(sorted_elems.len(), env.arena);
+
+            // as with records, every element of the tuple is destructured in the mono pattern,
+            // using an Underscore for elements that the source pattern does not mention
+            for (index, variable, elem_layout) in sorted_elems.into_iter() {
+                match destructs_by_index.remove(&index) {
+                    Some(destruct) => {
+                        let (_, loc_pattern) = &destruct.value.typ;
+                        let mono_pattern = from_can_pattern_help(
+                            env,
+                            procs,
+                            layout_cache,
+                            &loc_pattern.value,
+                            assignments,
+                        )?;
+
+                        mono_destructs.push(TupleDestruct {
+                            index,
+                            variable: destruct.value.var,
+                            layout: elem_layout,
+                            pat: mono_pattern,
+                        });
+                    }
+                    None => {
+                        mono_destructs.push(TupleDestruct {
+                            index,
+                            variable,
+                            layout: elem_layout,
+                            pat: Pattern::Underscore,
+                        });
+                    }
+                }
+
+                elem_layouts.push(elem_layout);
+            }
+
+            debug_assert!(
+                destructs_by_index.is_empty(),
+                "tuple pattern destructs an element that is not in the type"
+            );
+
+            Ok(Pattern::TupleDestructure(
+                mono_destructs,
+                elem_layouts.into_bump_slice(),
+            ))
+        }
     }
 }
 
@@ -292,7 +292,7 @@ impl LambdaSet {
 
         let variables = solved.variables();
         if variables.len() == 1 {
-            let symbol = subs.closure_names[lambda_names.start as usize];
+            let symbol = subs.symbol_names[lambda_names.start as usize];
             let symbol_index = Index::new(layouts.symbols.len() as u32);
             layouts.symbols.push(symbol);
             let variable_slice = subs.variable_slices[variables.start as usize];
@@ -339,7 +339,7 @@ impl LambdaSet {
     ) -> Slice<Symbol> {
         let slice = Slice::new(layouts.symbols.len() as u32, subs_slice.len() as u16);
 
-        let symbols = &subs.closure_names[subs_slice.indices()];
+        let symbols = &subs.symbol_names[subs_slice.indices()];
 
         for symbol in symbols {
             layouts.symbols.push(*symbol);
@@ -825,6 +825,20 @@ impl Layout {
 
                 Ok(Layout::Struct(slice))
             }
+            FlatType::Tuple(elems, ext) => {
+                let slice = Slice::reserve(layouts, elems.len());
+
+                let it = slice.indices().zip(elems.sorted_iterator(subs, *ext));
+                for (target_index, (_, var)) in it {
+                    let layout = Layout::from_var_help(layouts, subs, var)?;
+
+                    layouts.layouts[target_index] = layout;
+                }
+
+                layouts.sort_slice_by_alignment(slice);
+
+                Ok(Layout::Struct(slice))
+            }
             FlatType::TagUnion(union_tags, ext) => {
                 debug_assert!(ext_var_is_empty_tag_union(subs, *ext));
 
@@ -866,7 +880,7 @@ impl Layout {
                 Ok(Layout::UnionRecursive(slices))
             }
             FlatType::Erroneous(_) => Err(TypeError(())),
-            FlatType::EmptyRecord => Ok(Layout::UNIT),
+            FlatType::EmptyRecord | FlatType::EmptyTuple => Ok(Layout::UNIT),
             FlatType::EmptyTagUnion => Ok(Layout::VOID),
         }
     }
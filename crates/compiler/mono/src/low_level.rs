@@ -71,6 +71,12 @@ enum FirstOrder {
     StrToUtf8,
     StrRepeat,
     StrFromFloat,
+    StrGraphemes,
+    StrGraphemeByteOffset,
+    StrToUpper,
+    StrToLower,
+    StrToNfc,
+    StrToNfd,
     ListLen,
     ListGetUnsafe,
     ListSublist,
@@ -113,6 +119,24 @@ enum FirstOrder {
     NumAtan,
     NumAcos,
     NumAsin,
+    NumExp,
+    NumExp2,
+    NumLog2,
+    NumLog10,
+    NumSinh,
+    NumCosh,
+    NumTanh,
+    NumAtan2,
+    NumHypot,
+    NumTrunc,
+    NumFma,
+    NumIsNan,
+    NumIsInfinite,
+    NumCopySign,
+    NumDecRoundTo,
+    NumDecToStrFixed,
+    NumDecToScaledI128,
+    NumDecFromScaledI128,
     NumBitwiseAnd,
     NumBitwiseXor,
     NumBitwiseOr,
@@ -121,6 +145,12 @@ enum FirstOrder {
     NumBytesToU16,
     NumBytesToU32,
     NumShiftRightZfBy,
+    NumRotateLeftBy,
+    NumRotateRightBy,
+    NumCountLeadingZeroBits,
+    NumCountTrailingZeroBits,
+    NumCountOneBits,
+    NumSwapBytes,
     NumIntCast,
     NumFloatCast,
     Eq,
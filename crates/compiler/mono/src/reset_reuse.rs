@@ -241,7 +241,28 @@ fn function_s<'a, 'i>(
             }
         }
 
-        Ret(_) | Jump(_, _) | RuntimeError(_) => stmt,
+        Dbg {
+            symbol,
+            region,
+            remainder,
+        } => {
+            let continuation: &Stmt = *remainder;
+            let new_continuation = function_s(env, w, c, continuation);
+
+            if std::ptr::eq(continuation, new_continuation) || continuation == new_continuation {
+                stmt
+            } else {
+                let new_dbg = Dbg {
+                    symbol: *symbol,
+                    region: *region,
+                    remainder: new_continuation,
+                };
+
+                arena.alloc(new_dbg)
+            }
+        }
+
+        Ret(_) | Jump(_, _) | Crash { .. } | RuntimeError(_) => stmt,
     }
 }
 
@@ -504,6 +525,27 @@ fn function_d_main<'a, 'i>(
                 (arena.alloc(refcounting), found)
             }
         }
+        Dbg {
+            symbol,
+            region,
+            remainder,
+        } => {
+            let (b, found) = function_d_main(env, x, c, remainder);
+
+            let b = if found || *symbol != x {
+                b
+            } else {
+                try_function_s(env, x, c, b)
+            };
+
+            let dbg = Dbg {
+                symbol: *symbol,
+                region: *region,
+                remainder: b,
+            };
+
+            (arena.alloc(dbg), found)
+        }
         Join {
             id,
             parameters,
@@ -535,7 +577,9 @@ fn function_d_main<'a, 'i>(
 
             (arena.alloc(new_join), found)
         }
-        Ret(_) | Jump(_, _) | RuntimeError(_) => (stmt, has_live_var(&env.jp_live_vars, stmt, x)),
+        Ret(_) | Jump(_, _) | Crash { .. } | RuntimeError(_) => {
+            (stmt, has_live_var(&env.jp_live_vars, stmt, x))
+        }
     }
 }
 
@@ -696,7 +740,23 @@ fn function_r<'a, 'i>(env: &mut Env<'a, 'i>, stmt: &'a Stmt<'a>) -> &'a Stmt<'a>
             arena.alloc(expect)
         }
 
-        Ret(_) | Jump(_, _) | RuntimeError(_) => {
+        Dbg {
+            symbol,
+            region,
+            remainder,
+        } => {
+            let b = function_r(env, remainder);
+
+            let dbg = Dbg {
+                symbol: *symbol,
+                region: *region,
+                remainder: b,
+            };
+
+            arena.alloc(dbg)
+        }
+
+        Ret(_) | Jump(_, _) | Crash { .. } | RuntimeError(_) => {
             // terminals
             stmt
         }
@@ -723,6 +783,7 @@ fn has_live_var<'a>(jp_live_vars: &JPLiveVarMap, stmt: &'a Stmt<'a>, needle: Sym
                     .any(|(_, _, body)| has_live_var(jp_live_vars, body, needle))
         }
         Ret(s) => *s == needle,
+        Crash { message, .. } => *message == needle,
         Refcounting(modify_rc, cont) => {
             modify_rc.get_symbol() == needle || has_live_var(jp_live_vars, cont, needle)
         }
@@ -736,6 +797,9 @@ fn has_live_var<'a>(jp_live_vars: &JPLiveVarMap, stmt: &'a Stmt<'a>, needle: Sym
             remainder,
             ..
         } => *condition == needle || has_live_var(jp_live_vars, remainder, needle),
+        Dbg {
+            symbol, remainder, ..
+        } => *symbol == needle || has_live_var(jp_live_vars, remainder, needle),
         Join {
             id,
             parameters,
@@ -167,6 +167,8 @@ pub enum Expr<'a> {
     Access(&'a Expr<'a>, &'a str),
     /// e.g. `.foo`
     AccessorFunction(&'a str),
+    /// Look up exactly one element of a tuple, e.g. (expr).1
+    TupleAccess(&'a Expr<'a>, &'a str),
     /// eg 'b'
     SingleQuote(&'a str),
 
@@ -180,6 +182,13 @@ pub enum Expr<'a> {
 
     Record(Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>),
 
+    /// A record with at least one `<-` field, e.g. `{ name: <- field "name", kind: Person }`.
+    /// It must be the argument of a function, and is desugared into calls to `apply`.
+    RecordBuilder(Collection<'a, Loc<RecordBuilderField<'a>>>),
+
+    /// e.g. `(a, b)`. A tuple always has at least two elements.
+    Tuple(Collection<'a, &'a Loc<Expr<'a>>>),
+
     // Lookups
     Var {
         module_name: &'a str, // module_name will only be filled if the original Roc code stated something like `5 + SomeModule.myVar`, module_name will be blank if it was `5 + myVar`
@@ -188,6 +197,9 @@ pub enum Expr<'a> {
 
     Underscore(&'a str),
 
+    /// The `crash` keyword; it must be applied to a message, as in `crash "oops"`
+    Crash,
+
     // Tags
     Tag(&'a str),
 
@@ -200,6 +212,8 @@ pub enum Expr<'a> {
     Defs(&'a Defs<'a>, &'a Loc<Expr<'a>>),
     Backpassing(&'a [Loc<Pattern<'a>>], &'a Loc<Expr<'a>>, &'a Loc<Expr<'a>>),
     Expect(&'a Loc<Expr<'a>>, &'a Loc<Expr<'a>>),
+    /// `dbg value` evaluates to `value`, reporting it to the host along the way
+    Dbg(&'a Loc<Expr<'a>>),
 
     // Application
     /// To apply by name, do Apply(Var(...), ...)
@@ -233,6 +247,10 @@ pub enum Expr<'a> {
     // Both operators were non-associative, e.g. (True == False == False).
     // We should tell the author to disambiguate by grouping them with parens.
     PrecedenceConflict(&'a PrecedenceConflict<'a>),
+    // A function was given more than one record builder, e.g. `succeed { a: <- x } { b: <- y }`
+    MultipleRecordBuilders(&'a Loc<Expr<'a>>),
+    // A record builder that is not the argument of a function, e.g. `x = { a: <- y }`
+    UnappliedRecordBuilder(&'a Loc<Expr<'a>>),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -334,6 +352,8 @@ pub enum ValueDef<'a> {
     Expect {
         condition: &'a Loc<Expr<'a>>,
         preceding_comment: Region,
+        /// Whether this was generated from an example in a doc comment (see [`crate::doctest`])
+        is_doctest: bool,
     },
 
     ExpectFx {
@@ -439,7 +459,8 @@ pub type AbilityName<'a> = Loc<TypeAnnotation<'a>>;
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct HasClause<'a> {
     pub var: Loc<Spaced<'a, &'a str>>,
-    pub ability: AbilityName<'a>,
+    /// The abilities the variable has, e.g. `[Hash, Eq]` in `a has Hash & Eq`.
+    pub abilities: &'a [AbilityName<'a>],
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -519,6 +540,13 @@ pub enum TypeAnnotation<'a> {
         ext: Option<&'a Loc<TypeAnnotation<'a>>>,
     },
 
+    Tuple {
+        elems: Collection<'a, Loc<TypeAnnotation<'a>>>,
+        /// The row type variable in an open tuple, e.g. the `t` in `(Str, U8)t`.
+        /// This is None if it's a closed tuple annotation like `(Str, U8)`.
+        ext: Option<&'a Loc<TypeAnnotation<'a>>>,
+    },
+
     /// A tag union, e.g. `[
     TagUnion {
         /// The row type variable in an open tag union, e.g. the `a` in `[Foo, Bar]a`.
@@ -581,6 +609,30 @@ pub enum AssignedField<'a, Val> {
     Malformed(&'a str),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordBuilderField<'a> {
+    // A field with a value, e.g. `{ name: "blah" }`
+    Value(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Expr<'a>>),
+
+    // A field whose value is built by `apply`, e.g. `{ name: <- field "name" }`
+    ApplyValue(
+        Loc<&'a str>,
+        &'a [CommentOrNewline<'a>],
+        &'a [CommentOrNewline<'a>],
+        &'a Loc<Expr<'a>>,
+    ),
+
+    // A label with no value, e.g. `{ name }` (this is sugar for { name: name })
+    LabelOnly(Loc<&'a str>),
+
+    // We preserve this for the formatter; canonicalization ignores it.
+    SpaceBefore(&'a RecordBuilderField<'a>, &'a [CommentOrNewline<'a>]),
+    SpaceAfter(&'a RecordBuilderField<'a>, &'a [CommentOrNewline<'a>]),
+
+    /// A malformed field, which will code gen to a runtime error
+    Malformed(&'a str),
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CommentOrNewline<'a> {
     Newline,
@@ -633,6 +685,9 @@ pub enum Pattern<'a> {
     /// In practice, these patterns will always be Identifier
     RecordDestructure(Collection<'a, Loc<Pattern<'a>>>),
 
+    /// e.g. `(a, _)`, which matches a tuple of two elements
+    Tuple(Collection<'a, Loc<Pattern<'a>>>),
+
     /// A required field pattern, e.g. { x: Just 0 } -> ...
     /// Can only occur inside of a RecordDestructure
     RequiredField(&'a str, &'a Loc<Pattern<'a>>),
@@ -739,6 +794,13 @@ impl<'a> Pattern<'a> {
                 .iter()
                 .zip(fields_y.iter())
                 .all(|(p, q)| p.value.equivalent(&q.value)),
+            (Tuple(elems_x), Tuple(elems_y)) => {
+                elems_x.len() == elems_y.len()
+                    && elems_x
+                        .iter()
+                        .zip(elems_y.iter())
+                        .all(|(p, q)| p.value.equivalent(&q.value))
+            }
             (RequiredField(x, inner_x), RequiredField(y, inner_y)) => {
                 x == y && inner_x.value.equivalent(&inner_y.value)
             }
@@ -987,6 +1049,15 @@ impl<'a, Val> Spaceable<'a> for AssignedField<'a, Val> {
     }
 }
 
+impl<'a> Spaceable<'a> for RecordBuilderField<'a> {
+    fn before(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
+        RecordBuilderField::SpaceBefore(self, spaces)
+    }
+    fn after(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
+        RecordBuilderField::SpaceAfter(self, spaces)
+    }
+}
+
 impl<'a> Spaceable<'a> for Tag<'a> {
     fn before(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
         Tag::SpaceBefore(self, spaces)
@@ -0,0 +1,434 @@
+//! Examples in doc comments that `roc test` runs as `expect`s.
+//!
+//! An example is one or more `>>>` lines, followed by an `==` line with the value it should
+//! evaluate to:
+//!
+//! ```text
+//! ## >>> [0, 1, 2]
+//! ## >>>     |> List.append 3
+//! ## == [0, 1, 2, 3]
+//! ```
+//!
+//! which is checked like `expect ([0, 1, 2] |> List.append 3) == ([0, 1, 2, 3])`. An example
+//! that isn't directly followed by an `==` line is only there to illustrate, and is not checked.
+//! Prose after an example is never taken as its value, even when it happens to parse.
+//!
+//! A fenced code block marked `roc test` is checked like an `expect` with the block as its body:
+//!
+//! ```text
+//! ## ```roc test
+//! ## list = List.append [0, 1, 2] 3
+//! ##
+//! ## List.len list == 4
+//! ## ```
+//! ```
+//!
+//! The code is parsed where it appears in the source file, so problems with it (and failures)
+//! are reported at the doc comment. An example or block whose code doesn't parse is skipped, so
+//! that one typo in a doc comment doesn't stop the rest of the module from being tested.
+use crate::ast::{Defs, Expr, ValueDef};
+use crate::parser::SyntaxError;
+use crate::state::State;
+use bumpalo::Bump;
+use roc_module::called_via::BinOp;
+use roc_region::all::{Loc, Position, Region};
+
+const EXAMPLE: &str = ">>>";
+const RESULT: &str = "==";
+const FENCE: &str = "```";
+const TEST_FENCE_INFO: &str = "roc test";
+
+/// A line of Roc code in a doc comment, as byte offsets into the source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CodeLine {
+    /// Where the line (and its `##` marker) starts
+    start: usize,
+    /// Where the code starts, after the `##`, `## >>>` or `## ==` marker
+    code_start: usize,
+    end: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Doctest {
+    Example {
+        example: Vec<CodeLine>,
+        expected: CodeLine,
+    },
+    Block {
+        body: Vec<CodeLine>,
+    },
+}
+
+/// Add an `expect` to `defs` for every example in the module's doc comments. Returns the regions
+/// of the doc comment lines of the examples that were skipped because their code doesn't parse.
+pub fn push_doctests<'a>(arena: &'a Bump, src: &'a str, defs: &mut Defs<'a>) -> Vec<Region> {
+    let mut skipped = Vec::new();
+
+    for doctest in find_doctests(src) {
+        let code = match &doctest {
+            Doctest::Example { example, .. } => example,
+            Doctest::Block { body } => body,
+        };
+
+        let start = code[0].start;
+        let lines_region = Region::new(
+            Position::new(start as u32),
+            Position::new(code[code.len() - 1].end as u32),
+        );
+
+        let condition = match doctest {
+            Doctest::Example { example, expected } => {
+                let example = match parse_code(arena, src, &example) {
+                    Ok(example) => example,
+                    Err(_) => {
+                        skipped.push(lines_region);
+                        continue;
+                    }
+                };
+                let expected = match parse_code(arena, src, &[expected]) {
+                    Ok(expected) => expected,
+                    Err(_) => {
+                        skipped.push(Region::new(
+                            Position::new(expected.start as u32),
+                            Position::new(expected.end as u32),
+                        ));
+                        continue;
+                    }
+                };
+
+                let region = Region::span_across(&example.region, &expected.region);
+                let equals = Loc::at(
+                    Region::between(example.region.end(), expected.region.start()),
+                    BinOp::Equals,
+                );
+
+                let lefts = arena.alloc([(parenthesized(arena, example), equals)]);
+                let right = arena.alloc(parenthesized(arena, expected));
+
+                Loc::at(region, Expr::BinOps(lefts, right))
+            }
+            Doctest::Block { body } => match parse_code(arena, src, &body) {
+                Ok(body) => body,
+                Err(_) => {
+                    skipped.push(lines_region);
+                    continue;
+                }
+            },
+        };
+
+        // the doctest's "comment" starts with its first line, so that is where it is reported
+        let start = Position::new(start as u32);
+        let preceding_comment = Region::new(start, start);
+        let region = Region::new(start, condition.region.end());
+
+        let value_def = ValueDef::Expect {
+            condition: arena.alloc(condition),
+            preceding_comment,
+            is_doctest: true,
+        };
+
+        defs.push_value_def(value_def, region, &[], &[]);
+    }
+
+    skipped
+}
+
+fn parenthesized<'a>(arena: &'a Bump, loc_expr: Loc<Expr<'a>>) -> Loc<Expr<'a>> {
+    Loc::at(
+        loc_expr.region,
+        Expr::ParensAround(arena.alloc(loc_expr.value)),
+    )
+}
+
+/// Parse the code on these lines as an expression, at its place in the source.
+fn parse_code<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    lines: &[CodeLine],
+) -> Result<Loc<Expr<'a>>, SyntaxError<'a>> {
+    let first = lines[0];
+    let last = lines[lines.len() - 1];
+
+    // A copy of the source up to the end of the code, with the comment markers on its lines
+    // blanked out. The parser only looks forward, and stops at the end of the code.
+    let bytes = arena.alloc_slice_copy(&src.as_bytes()[..last.end]);
+
+    for line in lines {
+        bytes[line.start..line.code_start].fill(b' ');
+    }
+
+    let state = State::at_offset(bytes, first.code_start);
+
+    crate::expr::parse_expr_to_end(0, arena, state)
+        .map_err(|fail| SyntaxError::Expr(fail, Position::new(first.code_start as u32)))
+}
+
+/// If this line is a `##` doc comment, the offset where its content starts
+fn doc_line_content(start: usize, line: &str) -> Option<usize> {
+    let rest = line.trim_end_matches('\r').strip_prefix("##")?;
+
+    if rest.is_empty() {
+        Some(start + 2)
+    } else if rest.starts_with(' ') {
+        Some(start + 3)
+    } else {
+        // e.g. `###`, which is a regular comment
+        None
+    }
+}
+
+fn find_doctests(src: &str) -> Vec<Doctest> {
+    // the doc comment lines as (line start, content start, line end), split into
+    // comments wherever there is a line in between
+    let mut comments: Vec<Vec<(usize, usize, usize)>> = Vec::new();
+    let mut previous_end = None;
+    let mut start = 0;
+
+    for line in src.split('\n') {
+        let end = start + line.len();
+        let trimmed_end = start + line.trim_end_matches('\r').len();
+
+        if let Some(content_start) = doc_line_content(start, line) {
+            let doc_line = (start, content_start, trimmed_end);
+
+            match comments.last_mut() {
+                Some(comment) if previous_end == Some(start - 1) => comment.push(doc_line),
+                _ => comments.push(vec![doc_line]),
+            }
+
+            previous_end = Some(end);
+        }
+
+        start = end + 1;
+    }
+
+    let mut doctests = Vec::new();
+
+    for comment in comments {
+        let content = |index: usize| {
+            let (_, content_start, end) = comment[index];
+            &src[content_start..end]
+        };
+
+        let mut index = 0;
+
+        while index < comment.len() {
+            let line = content(index);
+
+            if line.starts_with(EXAMPLE) {
+                let mut example = Vec::new();
+
+                while index < comment.len() && content(index).starts_with(EXAMPLE) {
+                    let (start, content_start, end) = comment[index];
+                    let code_start = content_start + EXAMPLE.len();
+
+                    example.push(CodeLine {
+                        start,
+                        code_start,
+                        end,
+                    });
+
+                    index += 1;
+                }
+
+                // Only an `==` line right after the example is its expected value
+                if index < comment.len() && content(index).starts_with(RESULT) {
+                    let (start, content_start, end) = comment[index];
+
+                    let expected = CodeLine {
+                        start,
+                        code_start: content_start + RESULT.len(),
+                        end,
+                    };
+
+                    doctests.push(Doctest::Example { example, expected });
+
+                    index += 1;
+                }
+            } else if let Some(info) = line.strip_prefix(FENCE) {
+                let is_test = info.trim() == TEST_FENCE_INFO;
+                let mut body = Vec::new();
+
+                index += 1;
+
+                while index < comment.len() && content(index).trim_end() != FENCE {
+                    let (start, _, end) = comment[index];
+
+                    body.push(CodeLine {
+                        start,
+                        code_start: start + 2,
+                        end,
+                    });
+
+                    index += 1;
+                }
+
+                // skip the closing fence
+                index += 1;
+
+                let has_code = body
+                    .iter()
+                    .any(|line| !src[line.code_start..line.end].trim().is_empty());
+
+                if is_test && has_code {
+                    doctests.push(Doctest::Block { body });
+                }
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    doctests
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn code(src: &str, lines: &[CodeLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| src[line.code_start..line.end].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn finds_examples_with_expected_values() {
+        let src = "## Add one.\n##\n## >>> [0, 1]\n## >>>     |> List.append 2\n## == [0, 1, 2]\n##\n## >>> 1 + 1\nappend = 1\n";
+
+        let doctests = find_doctests(src);
+
+        match doctests.as_slice() {
+            [Doctest::Example { example, expected }] => {
+                assert_eq!(code(src, example), vec![" [0, 1]", "     |> List.append 2"]);
+                assert_eq!(code(src, &[*expected]), vec![" [0, 1, 2]"]);
+            }
+            other => panic!("unexpected doctests: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn expected_value_is_one_line() {
+        let src = "## >>> 1 + 1\n## == 2\n## which is two.\nx = 1\n";
+
+        let doctests = find_doctests(src);
+
+        match doctests.as_slice() {
+            [Doctest::Example { expected, .. }] => {
+                assert_eq!(code(src, &[*expected]), vec![" 2"]);
+            }
+            other => panic!("unexpected doctests: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn finds_test_blocks_only() {
+        let src = "## ```roc test\n## x = 1\n##\n## x == 1\n## ```\n##\n## ```roc\n## >>> 1\n## 2\n## ```\nx = 1\n";
+
+        let doctests = find_doctests(src);
+
+        match doctests.as_slice() {
+            [Doctest::Block { body }] => {
+                assert_eq!(code(src, body), vec![" x = 1", "", " x == 1"]);
+            }
+            other => panic!("unexpected doctests: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn separate_comments_are_separate() {
+        // the expected value must be in the same comment as the example
+        let src = "## >>> 1 + 1\nx = 1\n## == 2\ny = 2\n";
+
+        assert_eq!(find_doctests(src), vec![]);
+    }
+
+    #[test]
+    fn parses_example_in_place() {
+        let arena = Bump::new();
+        let src = "## >>> 1 + 1\n## == 2\nx = 1\n";
+
+        let mut defs = Defs::default();
+        assert_eq!(push_doctests(&arena, src, &mut defs), vec![]);
+
+        assert_eq!(defs.value_defs.len(), 1);
+
+        match &defs.value_defs[0] {
+            ValueDef::Expect {
+                condition,
+                preceding_comment,
+                is_doctest,
+            } => {
+                assert!(is_doctest);
+                assert_eq!(preceding_comment.start().offset, 0);
+
+                let region = condition.region;
+                let text = &src[region.start().offset as usize..region.end().offset as usize];
+                assert_eq!(text, "1 + 1\n## == 2");
+            }
+            other => panic!("unexpected def: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn skips_examples_followed_by_prose() {
+        let arena = Bump::new();
+        let src = "## >>> List.len [1, 2]\n## returns the number of elements, i.e. 2.\nx = 1\n";
+
+        let mut defs = Defs::default();
+        assert_eq!(push_doctests(&arena, src, &mut defs), vec![]);
+
+        assert_eq!(defs.value_defs.len(), 0);
+    }
+
+    #[test]
+    fn prose_after_example_is_not_an_expect() {
+        let arena = Bump::new();
+        // `Returns the length` parses, as a tag applied to two arguments
+        let src = "## >>> List.len [1, 2]\n## Returns the length\nx = 1\n";
+
+        assert_eq!(find_doctests(src), vec![]);
+
+        let mut defs = Defs::default();
+        assert_eq!(push_doctests(&arena, src, &mut defs), vec![]);
+
+        assert_eq!(defs.value_defs.len(), 0);
+    }
+
+    #[test]
+    fn reports_expected_values_that_do_not_parse() {
+        let arena = Bump::new();
+        let src = "## >>> List.len [1, 2]\n## == two, probably\nx = 1\n";
+
+        let mut defs = Defs::default();
+        let skipped = push_doctests(&arena, src, &mut defs);
+
+        let lines: Vec<_> = skipped
+            .iter()
+            .map(|region| &src[region.start().offset as usize..region.end().offset as usize])
+            .collect();
+
+        assert_eq!(lines, vec!["## == two, probably"]);
+        assert_eq!(defs.value_defs.len(), 0);
+    }
+
+    #[test]
+    fn skips_examples_that_do_not_parse() {
+        let arena = Bump::new();
+        let src =
+            "x = 1\n\n## >>> List.map [1, 2\n## == [2, 3]\n##\n## >>> 1 + 1\n## == 2\ny = 2\n";
+
+        let mut defs = Defs::default();
+        let skipped = push_doctests(&arena, src, &mut defs);
+
+        // the skipped example is reported at its doc comment line, and the next one still runs
+        let lines: Vec<_> = skipped
+            .iter()
+            .map(|region| &src[region.start().offset as usize..region.end().offset as usize])
+            .collect();
+
+        assert_eq!(lines, vec!["## >>> List.map [1, 2"]);
+        assert_eq!(defs.value_defs.len(), 1);
+    }
+}
@@ -1,15 +1,15 @@
 use crate::ast::{
     AssignedField, Collection, CommentOrNewline, Defs, Expr, ExtractSpaces, Has, HasAbilities,
-    Pattern, Spaceable, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
+    Pattern, RecordBuilderField, Spaceable, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
 };
 use crate::blankspace::{
     space0_after_e, space0_around_ee, space0_before_e, space0_before_optional_after, space0_e,
 };
-use crate::ident::{lowercase_ident, parse_ident, Ident};
+use crate::ident::{lowercase_ident, parse_ident, tuple_index, Ident};
 use crate::keyword;
 use crate::parser::{
     self, backtrackable, optional, sep_by1, sep_by1_e, specialize, specialize_ref, then,
-    trailing_sep_by0, word1, word2, EExpect, EExpr, EIf, EInParens, ELambda, EList, ENumber,
+    trailing_sep_by0, word1, word2, EDbg, EExpect, EExpr, EIf, EInParens, ELambda, EList, ENumber,
     EPattern, ERecord, EString, EType, EWhen, Either, ParseResult, Parser,
 };
 use crate::pattern::{loc_closure_param, loc_has_parser};
@@ -37,6 +37,15 @@ pub fn test_parse_expr<'a>(
     min_indent: u32,
     arena: &'a bumpalo::Bump,
     state: State<'a>,
+) -> Result<Loc<Expr<'a>>, EExpr<'a>> {
+    parse_expr_to_end(min_indent, arena, state)
+}
+
+/// Parse a single expression (and any whitespace around it) that takes up the rest of the input.
+pub fn parse_expr_to_end<'a>(
+    min_indent: u32,
+    arena: &'a bumpalo::Bump,
+    state: State<'a>,
 ) -> Result<Loc<Expr<'a>>, EExpr<'a>> {
     let parser = skip_second!(
         space0_before_e(
@@ -83,36 +92,106 @@ pub fn expr_help<'a>(min_indent: u32) -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
     }
 }
 
+/// Either an expression in parentheses, like `(x + 1)`, or a tuple, like `(x, 1)`
 fn loc_expr_in_parens_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Expr<'a>>, EInParens<'a>> {
-    move |arena, state| {
-        let (_, loc_expr, state) = loc_expr_in_parens_help_help(min_indent).parse(arena, state)?;
+    move |arena, state: State<'a>| {
+        let start = state.pos();
 
-        Ok((
-            MadeProgress,
-            Loc {
-                region: loc_expr.region,
-                value: Expr::ParensAround(arena.alloc(loc_expr.value)),
-            },
-            state,
-        ))
+        let (_, elements, state) = collection_trailing_sep_e!(
+            word1(b'(', EInParens::Open),
+            specialize_ref(EInParens::Expr, move |arena, state| {
+                parse_loc_expr_no_multi_backpassing(min_indent, arena, state)
+            }),
+            word1(b',', EInParens::End),
+            word1(b')', EInParens::End),
+            min_indent,
+            EInParens::Open,
+            EInParens::IndentEnd,
+            Expr::SpaceBefore
+        )
+        .parse(arena, state)?;
+
+        match elements.items {
+            [] => Err((MadeProgress, EInParens::Empty(start), state)),
+            [loc_expr] => {
+                let value = match elements.final_comments() {
+                    [] => loc_expr.value,
+                    comments => Expr::SpaceAfter(arena.alloc(loc_expr.value), comments),
+                };
+
+                Ok((
+                    MadeProgress,
+                    Loc {
+                        region: loc_expr.region,
+                        value: Expr::ParensAround(arena.alloc(value)),
+                    },
+                    state,
+                ))
+            }
+            items => {
+                let loc_expr = match tuple_to_backpassing(arena, items) {
+                    Some(loc_expr) => {
+                        loc_expr.map_owned(|value| Expr::ParensAround(arena.alloc(value)))
+                    }
+                    None => Loc::at(
+                        Region::new(start, state.pos()),
+                        Expr::Tuple(elements.ptrify_items(arena)),
+                    ),
+                };
+
+                Ok((MadeProgress, loc_expr, state))
+            }
+        }
     }
 }
 
-fn loc_expr_in_parens_help_help<'a>(
-    min_indent: u32,
-) -> impl Parser<'a, Loc<Expr<'a>>, EInParens<'a>> {
-    between!(
-        word1(b'(', EInParens::Open),
-        space0_around_ee(
-            specialize_ref(EInParens::Expr, move |arena, state| parse_loc_expr(
-                min_indent, arena, state
-            )),
-            min_indent,
-            EInParens::IndentOpen,
-            EInParens::IndentEnd,
-        ),
-        word1(b')', EInParens::End)
-    )
+/// `(a, b <- task ...)` parses like a tuple whose last element is a backpassing expression,
+/// but it is really a single backpassing expression with several patterns.
+fn tuple_to_backpassing<'a>(arena: &'a Bump, items: &[Loc<Expr<'a>>]) -> Option<Loc<Expr<'a>>> {
+    let (last, init) = items.split_last()?;
+    let last_spaces = last.value.extract_spaces();
+
+    let (last_patterns, loc_body, loc_cont) = match last_spaces.item {
+        Expr::Backpassing(patterns, body, cont) => (patterns, body, cont),
+        _ => return None,
+    };
+
+    // The spaces around the elements belong to the whole backpassing expression.
+    let first_spaces = items[0].value.extract_spaces();
+    let mut patterns = Vec::with_capacity_in(init.len() + last_patterns.len(), arena);
+
+    for (i, loc_elem) in init.iter().enumerate() {
+        let pattern = if i == 0 {
+            expr_to_pattern_help(arena, &first_spaces.item).ok()?
+        } else {
+            expr_to_pattern_help(arena, &loc_elem.value).ok()?
+        };
+
+        patterns.push(Loc::at(loc_elem.region, pattern));
+    }
+
+    for (i, loc_pattern) in last_patterns.iter().enumerate() {
+        if i == 0 && !last_spaces.before.is_empty() {
+            let value = Pattern::SpaceBefore(arena.alloc(loc_pattern.value), last_spaces.before);
+            patterns.push(Loc::at(loc_pattern.region, value));
+        } else {
+            patterns.push(*loc_pattern);
+        }
+    }
+
+    let mut value = Expr::Backpassing(patterns.into_bump_slice(), loc_body, loc_cont);
+
+    if !last_spaces.after.is_empty() {
+        value = Expr::SpaceAfter(arena.alloc(value), last_spaces.after);
+    }
+
+    if !first_spaces.before.is_empty() {
+        value = Expr::SpaceBefore(arena.alloc(value), first_spaces.before);
+    }
+
+    let region = Region::span_across(&items[0].region, &last.region);
+
+    Some(Loc::at(region, value))
 }
 
 fn loc_expr_in_parens_etc_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>> {
@@ -146,7 +225,7 @@ fn loc_expr_in_parens_etc_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Expr<
                 // Wrap the previous answer in the new one, so we end up
                 // with a nested Expr. That way, `foo.bar.baz` gets represented
                 // in the AST as if it had been written (foo.bar).baz all along.
-                value = Expr::Access(arena.alloc(value), field);
+                value = access(arena, value, field);
             }
         }
 
@@ -183,10 +262,22 @@ fn record_field_access_chain<'a>() -> impl Parser<'a, Vec<'a, &'a str>, EExpr<'a
 fn record_field_access<'a>() -> impl Parser<'a, &'a str, EExpr<'a>> {
     skip_first!(
         word1(b'.', EExpr::Access),
-        specialize(|_, pos| EExpr::Access(pos), lowercase_ident())
+        specialize(
+            |_, pos| EExpr::Access(pos),
+            one_of!(lowercase_ident(), tuple_index())
+        )
     )
 }
 
+/// `expr.foo` looks up a record field, and `expr.0` a tuple element.
+fn access<'a>(arena: &'a Bump, expr: Expr<'a>, field: &'a str) -> Expr<'a> {
+    if field.starts_with(|c: char| c.is_ascii_digit()) {
+        Expr::TupleAccess(arena.alloc(expr), field)
+    } else {
+        Expr::Access(arena.alloc(expr), field)
+    }
+}
+
 /// In some contexts we want to parse the `_` as an expression, so it can then be turned into a
 /// pattern later
 fn parse_loc_term_or_underscore_or_conditional<'a>(
@@ -207,6 +298,7 @@ fn parse_loc_term_or_underscore_or_conditional<'a>(
         loc!(specialize(EExpr::Number, positive_number_literal_help())),
         loc!(specialize(EExpr::Lambda, closure_help(min_indent, options))),
         loc!(underscore_expression()),
+        loc!(crash_kw()),
         loc!(record_literal_help(min_indent)),
         loc!(specialize(EExpr::List, list_literal_help(min_indent))),
         loc!(map_with_arena!(
@@ -232,6 +324,7 @@ fn parse_loc_term_or_underscore<'a>(
         loc!(specialize(EExpr::Number, positive_number_literal_help())),
         loc!(specialize(EExpr::Lambda, closure_help(min_indent, options))),
         loc!(underscore_expression()),
+        loc!(crash_kw()),
         loc!(record_literal_help(min_indent)),
         loc!(specialize(EExpr::List, list_literal_help(min_indent))),
         loc!(map_with_arena!(
@@ -254,6 +347,7 @@ fn parse_loc_term<'a>(
         loc!(specialize(EExpr::SingleQuote, single_quote_literal_help())),
         loc!(specialize(EExpr::Number, positive_number_literal_help())),
         loc!(specialize(EExpr::Lambda, closure_help(min_indent, options))),
+        loc!(crash_kw()),
         loc!(record_literal_help(min_indent)),
         loc!(specialize(EExpr::List, list_literal_help(min_indent))),
         loc!(map_with_arena!(
@@ -281,6 +375,15 @@ fn underscore_expression<'a>() -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
     }
 }
 
+fn crash_kw<'a>() -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
+    move |arena: &'a Bump, state: State<'a>| {
+        let (_, _, next_state) =
+            parser::keyword_e(keyword::CRASH, EExpr::Crash).parse(arena, state)?;
+
+        Ok((MadeProgress, Expr::Crash, next_state))
+    }
+}
+
 fn loc_possibly_negative_or_negated_term<'a>(
     min_indent: u32,
     options: ExprParseOptions,
@@ -355,6 +458,7 @@ fn parse_expr_start<'a>(
             when::expr_help(min_indent, options)
         )),
         loc!(specialize(EExpr::Expect, expect_help(min_indent, options))),
+        loc!(specialize(EExpr::Dbg, dbg_help(options))),
         loc!(specialize(EExpr::Lambda, closure_help(min_indent, options))),
         loc!(move |a, s| parse_expr_operator_chain(min_indent, options, start_column, a, s)),
         fail_expr_start_e()
@@ -685,6 +789,7 @@ fn parse_defs_end<'a>(
                             Either::Second(_) => ValueDef::Expect {
                                 condition: arena.alloc(loc_def_expr),
                                 preceding_comment,
+                                is_doctest: false,
                             },
                             Either::First(_) => ValueDef::ExpectFx {
                                 condition: arena.alloc(loc_def_expr),
@@ -1842,6 +1947,16 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
             Ok(Pattern::RecordDestructure(patterns))
         }
 
+        Expr::Tuple(elems) => {
+            let patterns = elems.map_items_result(arena, |loc_elem| {
+                let region = loc_elem.region;
+                let value = expr_to_pattern_help(arena, &loc_elem.value)?;
+                Ok(Loc { region, value })
+            })?;
+
+            Ok(Pattern::Tuple(patterns))
+        }
+
         &Expr::Float(string) => Ok(Pattern::FloatLiteral(string)),
         &Expr::Num(string) => Ok(Pattern::NumLiteral(string)),
         Expr::NonBase10Int {
@@ -1856,6 +1971,7 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
         // These would not have parsed as patterns
         Expr::AccessorFunction(_)
         | Expr::Access(_, _)
+        | Expr::TupleAccess(_, _)
         | Expr::List { .. }
         | Expr::Closure(_, _)
         | Expr::Backpassing(_, _, _)
@@ -1864,9 +1980,14 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
         | Expr::If(_, _)
         | Expr::When(_, _)
         | Expr::Expect(_, _)
+        | Expr::Dbg(_)
+        | Expr::Crash
         | Expr::MalformedClosure
         | Expr::PrecedenceConflict { .. }
         | Expr::RecordUpdate { .. }
+        | Expr::RecordBuilder(_)
+        | Expr::MultipleRecordBuilders(_)
+        | Expr::UnappliedRecordBuilder(_)
         | Expr::UnaryOp(_, _) => Err(()),
 
         Expr::Str(string) => Ok(Pattern::StrLiteral(*string)),
@@ -2361,6 +2482,26 @@ fn expect_help<'a>(
     }
 }
 
+fn dbg_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EDbg<'a>> {
+    move |arena: &'a Bump, state: State<'a>| {
+        let start_column = state.column();
+
+        let (_, _, state) = parser::keyword_e(keyword::DBG, EDbg::Dbg).parse(arena, state)?;
+
+        let (_, value, state) = space0_before_e(
+            specialize_ref(EDbg::Value, move |arena, state| {
+                parse_loc_expr_with_options(start_column + 1, options, arena, state)
+            }),
+            start_column + 1,
+            EDbg::IndentValue,
+        )
+        .parse(arena, state)
+        .map_err(|(_, f, s)| (MadeProgress, f, s))?;
+
+        Ok((MadeProgress, Expr::Dbg(arena.alloc(value)), state))
+    }
+}
+
 fn if_expr_help<'a>(
     min_indent: u32,
     options: ExprParseOptions,
@@ -2472,7 +2613,7 @@ fn ident_to_expr<'a>(arena: &'a Bump, src: Ident<'a>) -> Expr<'a> {
                 // Wrap the previous answer in the new one, so we end up
                 // with a nested Expr. That way, `foo.bar.baz` gets represented
                 // in the AST as if it had been written (foo.bar).baz all along.
-                answer = Expr::Access(arena.alloc(answer), field);
+                answer = access(arena, answer, field);
             }
 
             answer
@@ -2568,16 +2709,198 @@ fn record_updateable_identifier<'a>() -> impl Parser<'a, Expr<'a>, ERecord<'a>>
     )
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RecordField<'a> {
+    RequiredValue(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Expr<'a>>),
+    OptionalValue(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Expr<'a>>),
+    LabelOnly(Loc<&'a str>),
+    SpaceBefore(&'a RecordField<'a>, &'a [CommentOrNewline<'a>]),
+    SpaceAfter(&'a RecordField<'a>, &'a [CommentOrNewline<'a>]),
+    ApplyValue(
+        Loc<&'a str>,
+        &'a [CommentOrNewline<'a>],
+        &'a [CommentOrNewline<'a>],
+        &'a Loc<Expr<'a>>,
+    ),
+}
+
+#[derive(Debug)]
+struct FoundApplyValue;
+
+#[derive(Debug)]
+struct FoundOptionalValue;
+
+impl<'a> RecordField<'a> {
+    fn is_apply_value(&self) -> bool {
+        let mut current = self;
+
+        loop {
+            match current {
+                RecordField::ApplyValue(_, _, _, _) => break true,
+                RecordField::SpaceBefore(field, _) | RecordField::SpaceAfter(field, _) => {
+                    current = *field;
+                }
+                _ => break false,
+            }
+        }
+    }
+
+    fn to_assigned_field(
+        self,
+        arena: &'a Bump,
+    ) -> Result<AssignedField<'a, Expr<'a>>, FoundApplyValue> {
+        use AssignedField::*;
+
+        match self {
+            RecordField::RequiredValue(loc_label, spaces, loc_expr) => {
+                Ok(RequiredValue(loc_label, spaces, loc_expr))
+            }
+
+            RecordField::OptionalValue(loc_label, spaces, loc_expr) => {
+                Ok(OptionalValue(loc_label, spaces, loc_expr))
+            }
+
+            RecordField::LabelOnly(loc_label) => Ok(LabelOnly(loc_label)),
+
+            RecordField::ApplyValue(_, _, _, _) => Err(FoundApplyValue),
+
+            RecordField::SpaceBefore(field, spaces) => {
+                let assigned_field = field.to_assigned_field(arena)?;
+
+                Ok(SpaceBefore(arena.alloc(assigned_field), spaces))
+            }
+
+            RecordField::SpaceAfter(field, spaces) => {
+                let assigned_field = field.to_assigned_field(arena)?;
+
+                Ok(SpaceAfter(arena.alloc(assigned_field), spaces))
+            }
+        }
+    }
+
+    fn to_builder_field(
+        self,
+        arena: &'a Bump,
+    ) -> Result<RecordBuilderField<'a>, FoundOptionalValue> {
+        use RecordBuilderField::*;
+
+        match self {
+            RecordField::RequiredValue(loc_label, spaces, loc_expr) => {
+                Ok(Value(loc_label, spaces, loc_expr))
+            }
+
+            RecordField::OptionalValue(_, _, _) => Err(FoundOptionalValue),
+
+            RecordField::LabelOnly(loc_label) => Ok(LabelOnly(loc_label)),
+
+            RecordField::ApplyValue(loc_label, colon_spaces, arrow_spaces, loc_expr) => {
+                Ok(ApplyValue(loc_label, colon_spaces, arrow_spaces, loc_expr))
+            }
+
+            RecordField::SpaceBefore(field, spaces) => {
+                let builder_field = field.to_builder_field(arena)?;
+
+                Ok(SpaceBefore(arena.alloc(builder_field), spaces))
+            }
+
+            RecordField::SpaceAfter(field, spaces) => {
+                let builder_field = field.to_builder_field(arena)?;
+
+                Ok(SpaceAfter(arena.alloc(builder_field), spaces))
+            }
+        }
+    }
+}
+
+impl<'a> Spaceable<'a> for RecordField<'a> {
+    fn before(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
+        RecordField::SpaceBefore(self, spaces)
+    }
+    fn after(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
+        RecordField::SpaceAfter(self, spaces)
+    }
+}
+
+/// Like `record_value_field`, but also accepts the fields of a record builder,
+/// e.g. `name: <- field "name"`
+fn record_field<'a>(min_indent: u32) -> impl Parser<'a, RecordField<'a>, ERecord<'a>> {
+    use RecordField::*;
+
+    move |arena, state: State<'a>| {
+        // You must have a field name, e.g. "email"
+        let (progress, loc_label, state) =
+            specialize(|_, pos| ERecord::Field(pos), loc!(lowercase_ident()))
+                .parse(arena, state)?;
+        debug_assert_eq!(progress, MadeProgress);
+
+        let (_, spaces, state) = space0_e(min_indent, ERecord::IndentColon).parse(arena, state)?;
+
+        // Having a value is optional; both `{ email }` and `{ email: blah }` work.
+        let (_, opt_loc_val, state) = optional(either!(
+            and!(
+                word1(b':', ERecord::Colon),
+                and!(
+                    optional(and!(
+                        space0_e(min_indent, ERecord::IndentColon),
+                        word2(b'<', b'-', ERecord::Arrow)
+                    )),
+                    space0_before_e(
+                        specialize_ref(ERecord::Expr, move |a, s| {
+                            parse_loc_expr_no_multi_backpassing(min_indent, a, s)
+                        }),
+                        min_indent,
+                        ERecord::IndentEnd,
+                    )
+                )
+            ),
+            and!(
+                word1(b'?', ERecord::QuestionMark),
+                space0_before_e(
+                    specialize_ref(ERecord::Expr, move |a, s| {
+                        parse_loc_expr_no_multi_backpassing(min_indent, a, s)
+                    }),
+                    min_indent,
+                    ERecord::IndentEnd,
+                )
+            )
+        ))
+        .parse(arena, state)?;
+
+        let answer = match opt_loc_val {
+            Some(Either::First((_, (None, loc_val)))) => {
+                RequiredValue(loc_label, spaces, arena.alloc(loc_val))
+            }
+
+            Some(Either::First((_, (Some((arrow_spaces, _)), loc_val)))) => {
+                ApplyValue(loc_label, spaces, arrow_spaces, arena.alloc(loc_val))
+            }
+
+            Some(Either::Second((_, loc_val))) => {
+                OptionalValue(loc_label, spaces, arena.alloc(loc_val))
+            }
+
+            // If no value was provided, record it as a Var.
+            // Canonicalize will know what to do with a Var later.
+            None => {
+                if !spaces.is_empty() {
+                    SpaceAfter(arena.alloc(LabelOnly(loc_label)), spaces)
+                } else {
+                    LabelOnly(loc_label)
+                }
+            }
+        };
+
+        Ok((MadeProgress, answer, state))
+    }
+}
+
 fn record_help<'a>(
     min_indent: u32,
 ) -> impl Parser<
     'a,
     (
         Option<Loc<Expr<'a>>>,
-        Loc<(
-            Vec<'a, Loc<AssignedField<'a, Expr<'a>>>>,
-            &'a [CommentOrNewline<'a>],
-        )>,
+        Loc<(Vec<'a, Loc<RecordField<'a>>>, &'a [CommentOrNewline<'a>])>,
     ),
     ERecord<'a>,
 > {
@@ -2609,7 +2932,7 @@ fn record_help<'a>(
                         trailing_sep_by0(
                             word1(b',', ERecord::End),
                             space0_before_optional_after(
-                                loc!(record_value_field(min_indent)),
+                                loc!(record_field(min_indent)),
                                 min_indent,
                                 ERecord::IndentEnd,
                                 ERecord::IndentEnd
@@ -2629,23 +2952,74 @@ fn record_literal_help<'a>(min_indent: u32) -> impl Parser<'a, Expr<'a>, EExpr<'
     then(
         loc!(specialize(EExpr::Record, record_help(min_indent))),
         move |arena, state, _, loc_record| {
-            let (opt_update, loc_assigned_fields_with_comments) = loc_record.value;
+            let (opt_update, loc_fields_with_comments) = loc_record.value;
+            let (loc_fields, final_comments) = loc_fields_with_comments.value;
 
             // This is a record literal, not a destructure.
             let mut value = match opt_update {
-                Some(update) => Expr::RecordUpdate {
-                    update: &*arena.alloc(update),
-                    fields: Collection::with_items_and_comments(
+                Some(update) => {
+                    let mut assigned_fields = Vec::with_capacity_in(loc_fields.len(), arena);
+
+                    for loc_field in loc_fields {
+                        match loc_field.value.to_assigned_field(arena) {
+                            Ok(field) => assigned_fields.push(Loc::at(loc_field.region, field)),
+                            Err(FoundApplyValue) => {
+                                return Err((
+                                    MadeProgress,
+                                    EExpr::RecordUpdateBuilder(loc_field.region),
+                                    state,
+                                ))
+                            }
+                        }
+                    }
+
+                    Expr::RecordUpdate {
+                        update: &*arena.alloc(update),
+                        fields: Collection::with_items_and_comments(
+                            arena,
+                            assigned_fields.into_bump_slice(),
+                            arena.alloc(final_comments),
+                        ),
+                    }
+                }
+                None if loc_fields.iter().any(|field| field.value.is_apply_value()) => {
+                    let mut builder_fields = Vec::with_capacity_in(loc_fields.len(), arena);
+
+                    for loc_field in loc_fields {
+                        match loc_field.value.to_builder_field(arena) {
+                            Ok(field) => builder_fields.push(Loc::at(loc_field.region, field)),
+                            Err(FoundOptionalValue) => {
+                                return Err((
+                                    MadeProgress,
+                                    EExpr::OptionalValueInRecordBuilder(loc_field.region),
+                                    state,
+                                ))
+                            }
+                        }
+                    }
+
+                    Expr::RecordBuilder(Collection::with_items_and_comments(
                         arena,
-                        loc_assigned_fields_with_comments.value.0.into_bump_slice(),
-                        arena.alloc(loc_assigned_fields_with_comments.value.1),
-                    ),
-                },
-                None => Expr::Record(Collection::with_items_and_comments(
-                    arena,
-                    loc_assigned_fields_with_comments.value.0.into_bump_slice(),
-                    loc_assigned_fields_with_comments.value.1,
-                )),
+                        builder_fields.into_bump_slice(),
+                        final_comments,
+                    ))
+                }
+                None => {
+                    let mut assigned_fields = Vec::with_capacity_in(loc_fields.len(), arena);
+
+                    for loc_field in loc_fields {
+                        // there are no `<-` fields, so this can't fail
+                        if let Ok(field) = loc_field.value.to_assigned_field(arena) {
+                            assigned_fields.push(Loc::at(loc_field.region, field));
+                        }
+                    }
+
+                    Expr::Record(Collection::with_items_and_comments(
+                        arena,
+                        assigned_fields.into_bump_slice(),
+                        final_comments,
+                    ))
+                }
             };
 
             // there can be field access, e.g. `{ x : 4 }.x`
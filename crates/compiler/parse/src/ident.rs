@@ -38,7 +38,7 @@ pub enum Ident<'a> {
     Tag(&'a str),
     /// @Foo or @Bar
     OpaqueRef(&'a str),
-    /// foo or foo.bar or Foo.Bar.baz.qux
+    /// foo or foo.bar or Foo.Bar.baz.qux, where a part can also be a tuple index, as in foo.0
     Access {
         module_name: &'a str,
         parts: &'a [&'a str],
@@ -97,6 +97,17 @@ pub fn lowercase_ident<'a>() -> impl Parser<'a, &'a str, ()> {
     }
 }
 
+/// The index of a tuple element, e.g. the `1` in `pair.1`
+pub fn tuple_index<'a>() -> impl Parser<'a, &'a str, ()> {
+    move |_, state: State<'a>| match chomp_tuple_index(state.bytes()) {
+        Err(progress) => Err((progress, (), state)),
+        Ok(index) => {
+            let width = index.len();
+            Ok((MadeProgress, index, state.advance(width)))
+        }
+    }
+}
+
 pub fn tag_name<'a>() -> impl Parser<'a, &'a str, ()> {
     move |arena, state: State<'a>| uppercase_ident().parse(arena, state)
 }
@@ -233,6 +244,16 @@ fn chomp_uppercase_part(buffer: &[u8]) -> Result<&str, Progress> {
     chomp_part(|c: char| c.is_uppercase(), buffer)
 }
 
+fn chomp_tuple_index(buffer: &[u8]) -> Result<&str, Progress> {
+    let index = chomp_part(|c: char| c.is_ascii_digit(), buffer)?;
+
+    if index.bytes().all(|b| b.is_ascii_digit()) {
+        Ok(index)
+    } else {
+        Err(MadeProgress)
+    }
+}
+
 #[inline(always)]
 fn chomp_part<F>(leading_is_good: F, buffer: &[u8]) -> Result<&str, Progress>
 where
@@ -504,19 +525,21 @@ fn chomp_access_chain<'a>(buffer: &'a [u8], parts: &mut Vec<'a, &'a str>) -> Res
 
     while let Some(b'.') = buffer.get(chomped) {
         match &buffer.get(chomped + 1..) {
-            Some(slice) => match chomp_lowercase_part(slice) {
-                Ok(name) => {
-                    let value = unsafe {
-                        std::str::from_utf8_unchecked(
-                            &buffer[chomped + 1..chomped + 1 + name.len()],
-                        )
-                    };
-                    parts.push(value);
-
-                    chomped += name.len() + 1;
+            Some(slice) => {
+                match chomp_lowercase_part(slice).or_else(|_| chomp_tuple_index(slice)) {
+                    Ok(name) => {
+                        let value = unsafe {
+                            std::str::from_utf8_unchecked(
+                                &buffer[chomped + 1..chomped + 1 + name.len()],
+                            )
+                        };
+                        parts.push(value);
+
+                        chomped += name.len() + 1;
+                    }
+                    Err(_) => return Err(chomped as u32 + 1),
                 }
-                Err(_) => return Err(chomped as u32 + 1),
-            },
+            }
             None => return Err(chomped as u32 + 1),
         }
     }
@@ -80,6 +80,7 @@ macro_rules! impl_space_problem {
 }
 
 impl_space_problem! {
+    EDbg<'a>,
     EExpect<'a>,
     EExposes,
     EExpr<'a>,
@@ -128,6 +129,27 @@ pub enum EHeader<'a> {
     IndentStart(Position),
 }
 
+impl<'a> EHeader<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EHeader::Provides(e, _) => e.get_region(),
+            EHeader::Exposes(e, _) => e.get_region(),
+            EHeader::Imports(e, _) => e.get_region(),
+            EHeader::Requires(e, _) => e.get_region(),
+            EHeader::Packages(e, _) => e.get_region(),
+            EHeader::Generates(e, _) => e.get_region(),
+            EHeader::GeneratesWith(e, _) => e.get_region(),
+            EHeader::AppName(e, _) => e.get_region(),
+            EHeader::PlatformName(e, _) => e.get_region(),
+            EHeader::Space(_, pos)
+            | EHeader::Start(pos)
+            | EHeader::ModuleName(pos)
+            | EHeader::IndentStart(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EProvides<'a> {
     Provides(Position),
@@ -145,6 +167,27 @@ pub enum EProvides<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EProvides<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EProvides::Package(e, _) => e.get_region(),
+            EProvides::Provides(pos)
+            | EProvides::Open(pos)
+            | EProvides::To(pos)
+            | EProvides::IndentProvides(pos)
+            | EProvides::IndentTo(pos)
+            | EProvides::IndentListStart(pos)
+            | EProvides::IndentListEnd(pos)
+            | EProvides::IndentPackage(pos)
+            | EProvides::ListStart(pos)
+            | EProvides::ListEnd(pos)
+            | EProvides::Identifier(pos)
+            | EProvides::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EExposes {
     Exposes(Position),
@@ -158,6 +201,23 @@ pub enum EExposes {
     Space(BadInputError, Position),
 }
 
+impl EExposes {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExposes::Exposes(pos)
+            | EExposes::Open(pos)
+            | EExposes::IndentExposes(pos)
+            | EExposes::IndentListStart(pos)
+            | EExposes::IndentListEnd(pos)
+            | EExposes::ListStart(pos)
+            | EExposes::ListEnd(pos)
+            | EExposes::Identifier(pos)
+            | EExposes::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ERequires<'a> {
     Requires(Position),
@@ -172,6 +232,24 @@ pub enum ERequires<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> ERequires<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ERequires::TypedIdent(e, _) => e.get_region(),
+            ERequires::Requires(pos)
+            | ERequires::Open(pos)
+            | ERequires::IndentRequires(pos)
+            | ERequires::IndentListStart(pos)
+            | ERequires::IndentListEnd(pos)
+            | ERequires::ListStart(pos)
+            | ERequires::ListEnd(pos)
+            | ERequires::Rigid(pos)
+            | ERequires::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypedIdent<'a> {
     Space(BadInputError, Position),
@@ -183,6 +261,21 @@ pub enum ETypedIdent<'a> {
     Identifier(Position),
 }
 
+impl<'a> ETypedIdent<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypedIdent::Type(e, _) => e.get_region(),
+            ETypedIdent::Space(_, pos)
+            | ETypedIdent::HasType(pos)
+            | ETypedIdent::IndentHasType(pos)
+            | ETypedIdent::Name(pos)
+            | ETypedIdent::IndentType(pos)
+            | ETypedIdent::Identifier(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackages<'a> {
     Open(Position),
@@ -196,6 +289,23 @@ pub enum EPackages<'a> {
     PackageEntry(EPackageEntry<'a>, Position),
 }
 
+impl<'a> EPackages<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackages::PackageEntry(e, _) => e.get_region(),
+            EPackages::Open(pos)
+            | EPackages::Space(_, pos)
+            | EPackages::Packages(pos)
+            | EPackages::IndentPackages(pos)
+            | EPackages::ListStart(pos)
+            | EPackages::ListEnd(pos)
+            | EPackages::IndentListStart(pos)
+            | EPackages::IndentListEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackageName<'a> {
     BadPath(EString<'a>, Position),
@@ -203,6 +313,16 @@ pub enum EPackageName<'a> {
     Multiline(Position),
 }
 
+impl<'a> EPackageName<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackageName::BadPath(e, _) => e.get_region(),
+            EPackageName::Escapes(pos) | EPackageName::Multiline(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackageEntry<'a> {
     BadPackage(EPackageName<'a>, Position),
@@ -212,6 +332,19 @@ pub enum EPackageEntry<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EPackageEntry<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackageEntry::BadPackage(e, _) => e.get_region(),
+            EPackageEntry::Shorthand(pos)
+            | EPackageEntry::Colon(pos)
+            | EPackageEntry::IndentPackage(pos)
+            | EPackageEntry::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EImports {
     Open(Position),
@@ -233,6 +366,31 @@ pub enum EImports {
     SetEnd(Position),
 }
 
+impl EImports {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EImports::Open(pos)
+            | EImports::Imports(pos)
+            | EImports::IndentImports(pos)
+            | EImports::IndentListStart(pos)
+            | EImports::IndentListEnd(pos)
+            | EImports::ListStart(pos)
+            | EImports::ListEnd(pos)
+            | EImports::Identifier(pos)
+            | EImports::ExposingDot(pos)
+            | EImports::ShorthandDot(pos)
+            | EImports::Shorthand(pos)
+            | EImports::ModuleName(pos)
+            | EImports::Space(_, pos)
+            | EImports::IndentSetStart(pos)
+            | EImports::IndentSetEnd(pos)
+            | EImports::SetStart(pos)
+            | EImports::SetEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EGenerates {
     Open(Position),
@@ -244,6 +402,21 @@ pub enum EGenerates {
     IndentTypeEnd(Position),
 }
 
+impl EGenerates {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EGenerates::Open(pos)
+            | EGenerates::Generates(pos)
+            | EGenerates::IndentGenerates(pos)
+            | EGenerates::Identifier(pos)
+            | EGenerates::Space(_, pos)
+            | EGenerates::IndentTypeStart(pos)
+            | EGenerates::IndentTypeEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EGeneratesWith {
     Open(Position),
@@ -257,6 +430,23 @@ pub enum EGeneratesWith {
     Space(BadInputError, Position),
 }
 
+impl EGeneratesWith {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EGeneratesWith::Open(pos)
+            | EGeneratesWith::With(pos)
+            | EGeneratesWith::IndentWith(pos)
+            | EGeneratesWith::IndentListStart(pos)
+            | EGeneratesWith::IndentListEnd(pos)
+            | EGeneratesWith::ListStart(pos)
+            | EGeneratesWith::ListEnd(pos)
+            | EGeneratesWith::Identifier(pos)
+            | EGeneratesWith::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BadInputError {
     HasTab,
@@ -314,6 +504,28 @@ impl<'a> SyntaxError<'a> {
     ) -> FileError<'a, SyntaxError<'a>> {
         self.into_source_error(state).into_file_error(filename)
     }
+
+    /// Where in the source this error happened, if it is about a particular place
+    pub fn get_region(&self) -> Option<Region> {
+        match self {
+            SyntaxError::Unexpected(region)
+            | SyntaxError::Eof(region)
+            | SyntaxError::ReservedKeyword(region)
+            | SyntaxError::ArgumentsBeforeEquals(region) => Some(*region),
+            SyntaxError::Type(e) => Some(e.get_region()),
+            SyntaxError::Pattern(e) => Some(e.get_region()),
+            SyntaxError::Expr(e, _) => Some(e.get_region()),
+            SyntaxError::Header(e) => Some(e.get_region()),
+            SyntaxError::NotEndOfFile(pos) => Some(Region::from_pos(*pos)),
+            SyntaxError::OutdentedTooFar
+            | SyntaxError::TooManyLines
+            | SyntaxError::InvalidPattern
+            | SyntaxError::BadUtf8
+            | SyntaxError::NotYetImplemented(_)
+            | SyntaxError::Todo
+            | SyntaxError::Space(_) => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -346,14 +558,20 @@ pub enum EExpr<'a> {
     QualifiedTag(Position),
     BackpassComma(Position),
     BackpassArrow(Position),
+    /// A record update can't build fields with `<-`, e.g. `{ user & name: <- x }`
+    RecordUpdateBuilder(Region),
+    /// A record builder can't have optional fields, e.g. `{ a: <- x, b ? 1 }`
+    OptionalValueInRecordBuilder(Region),
 
     When(EWhen<'a>, Position),
     If(EIf<'a>, Position),
 
     Expect(EExpect<'a>, Position),
+    Dbg(EDbg<'a>, Position),
 
     Lambda(ELambda<'a>, Position),
     Underscore(Position),
+    Crash(Position),
 
     InParens(EInParens<'a>, Position),
     Record(ERecord<'a>, Position),
@@ -366,6 +584,58 @@ pub enum EExpr<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> EExpr<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExpr::DefMissingFinalExpr2(e, _) => e.get_region(),
+            EExpr::Type(e, _) => e.get_region(),
+            EExpr::Pattern(e, _) => e.get_region(),
+            EExpr::Ability(e, _) => e.get_region(),
+            EExpr::When(e, _) => e.get_region(),
+            EExpr::If(e, _) => e.get_region(),
+            EExpr::Expect(e, _) => e.get_region(),
+            EExpr::Dbg(e, _) => e.get_region(),
+            EExpr::Lambda(e, _) => e.get_region(),
+            EExpr::InParens(e, _) => e.get_region(),
+            EExpr::Record(e, _) => e.get_region(),
+            EExpr::Str(e, _) => e.get_region(),
+            EExpr::SingleQuote(e, _) => e.get_region(),
+            EExpr::List(e, _) => e.get_region(),
+            EExpr::RecordUpdateBuilder(region) | EExpr::OptionalValueInRecordBuilder(region) => {
+                *region
+            }
+            EExpr::Start(pos)
+            | EExpr::End(pos)
+            | EExpr::BadExprEnd(pos)
+            | EExpr::Space(_, pos)
+            | EExpr::Dot(pos)
+            | EExpr::Access(pos)
+            | EExpr::UnaryNot(pos)
+            | EExpr::UnaryNegate(pos)
+            | EExpr::BadOperator(_, pos)
+            | EExpr::DefMissingFinalExpr(pos)
+            | EExpr::IndentDefBody(pos)
+            | EExpr::IndentEquals(pos)
+            | EExpr::IndentAnnotation(pos)
+            | EExpr::Equals(pos)
+            | EExpr::Colon(pos)
+            | EExpr::DoubleColon(pos)
+            | EExpr::Ident(pos)
+            | EExpr::ElmStyleFunction(_, pos)
+            | EExpr::MalformedPattern(pos)
+            | EExpr::QualifiedTag(pos)
+            | EExpr::BackpassComma(pos)
+            | EExpr::BackpassArrow(pos)
+            | EExpr::Underscore(pos)
+            | EExpr::Crash(pos)
+            | EExpr::Number(_, pos)
+            | EExpr::IndentStart(pos)
+            | EExpr::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ENumber {
     End,
@@ -387,6 +657,24 @@ pub enum EString<'a> {
     MultilineInsufficientIndent(Position),
 }
 
+impl<'a> EString<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EString::Format(e, _) => e.get_region(),
+            EString::Open(pos)
+            | EString::CodePtOpen(pos)
+            | EString::CodePtEnd(pos)
+            | EString::Space(_, pos)
+            | EString::EndlessSingle(pos)
+            | EString::EndlessMulti(pos)
+            | EString::UnknownEscape(pos)
+            | EString::FormatEnd(pos)
+            | EString::MultilineInsufficientIndent(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ERecord<'a> {
     End(Position),
@@ -398,6 +686,7 @@ pub enum ERecord<'a> {
     QuestionMark(Position),
     Bar(Position),
     Ampersand(Position),
+    Arrow(Position),
 
     // TODO remove
     Expr(&'a EExpr<'a>, Position),
@@ -411,10 +700,36 @@ pub enum ERecord<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> ERecord<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ERecord::Expr(e, _) => e.get_region(),
+            ERecord::End(pos)
+            | ERecord::Open(pos)
+            | ERecord::Updateable(pos)
+            | ERecord::Field(pos)
+            | ERecord::Colon(pos)
+            | ERecord::QuestionMark(pos)
+            | ERecord::Bar(pos)
+            | ERecord::Ampersand(pos)
+            | ERecord::Arrow(pos)
+            | ERecord::Space(_, pos)
+            | ERecord::IndentOpen(pos)
+            | ERecord::IndentColon(pos)
+            | ERecord::IndentBar(pos)
+            | ERecord::IndentAmpersand(pos)
+            | ERecord::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EInParens<'a> {
     End(Position),
     Open(Position),
+    /// `()`, which is neither an expression in parentheses nor a tuple
+    Empty(Position),
     ///
     Expr(&'a EExpr<'a>, Position),
 
@@ -425,6 +740,21 @@ pub enum EInParens<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> EInParens<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EInParens::Expr(e, _) => e.get_region(),
+            EInParens::End(pos)
+            | EInParens::Open(pos)
+            | EInParens::Empty(pos)
+            | EInParens::Space(_, pos)
+            | EInParens::IndentOpen(pos)
+            | EInParens::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ELambda<'a> {
     Space(BadInputError, Position),
@@ -440,6 +770,24 @@ pub enum ELambda<'a> {
     IndentArg(Position),
 }
 
+impl<'a> ELambda<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ELambda::Pattern(e, _) => e.get_region(),
+            ELambda::Body(e, _) => e.get_region(),
+            ELambda::Space(_, pos)
+            | ELambda::Start(pos)
+            | ELambda::Arrow(pos)
+            | ELambda::Comma(pos)
+            | ELambda::Arg(pos)
+            | ELambda::IndentArrow(pos)
+            | ELambda::IndentBody(pos)
+            | ELambda::IndentArg(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EList<'a> {
     Open(Position),
@@ -452,6 +800,20 @@ pub enum EList<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> EList<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EList::Expr(e, _) => e.get_region(),
+            EList::Open(pos)
+            | EList::End(pos)
+            | EList::Space(_, pos)
+            | EList::IndentOpen(pos)
+            | EList::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EWhen<'a> {
     Space(BadInputError, Position),
@@ -476,6 +838,31 @@ pub enum EWhen<'a> {
     PatternAlignment(u32, Position),
 }
 
+impl<'a> EWhen<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EWhen::Pattern(e, _) => e.get_region(),
+            EWhen::IfGuard(e, _) => e.get_region(),
+            EWhen::Condition(e, _) => e.get_region(),
+            EWhen::Branch(e, _) => e.get_region(),
+            EWhen::Space(_, pos)
+            | EWhen::When(pos)
+            | EWhen::Is(pos)
+            | EWhen::Arrow(pos)
+            | EWhen::Bar(pos)
+            | EWhen::IfToken(pos)
+            | EWhen::IndentIs(pos)
+            | EWhen::IndentCondition(pos)
+            | EWhen::IndentPattern(pos)
+            | EWhen::IndentArrow(pos)
+            | EWhen::IndentBranch(pos)
+            | EWhen::IndentIfGuard(pos)
+            | EWhen::PatternAlignment(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EAbility<'a> {
     Space(BadInputError, Position),
@@ -486,6 +873,19 @@ pub enum EAbility<'a> {
     DemandColon(Position),
 }
 
+impl<'a> EAbility<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EAbility::Type(e, _) => e.get_region(),
+            EAbility::Space(_, pos)
+            | EAbility::DemandAlignment(_, pos)
+            | EAbility::DemandName(pos)
+            | EAbility::DemandColon(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EIf<'a> {
     Space(BadInputError, Position),
@@ -505,6 +905,27 @@ pub enum EIf<'a> {
     IndentElseBranch(Position),
 }
 
+impl<'a> EIf<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EIf::Condition(e, _) => e.get_region(),
+            EIf::ThenBranch(e, _) => e.get_region(),
+            EIf::ElseBranch(e, _) => e.get_region(),
+            EIf::Space(_, pos)
+            | EIf::If(pos)
+            | EIf::Then(pos)
+            | EIf::Else(pos)
+            | EIf::IndentCondition(pos)
+            | EIf::IndentIf(pos)
+            | EIf::IndentThenToken(pos)
+            | EIf::IndentElseToken(pos)
+            | EIf::IndentThenBranch(pos)
+            | EIf::IndentElseBranch(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EExpect<'a> {
     Space(BadInputError, Position),
@@ -514,6 +935,37 @@ pub enum EExpect<'a> {
     IndentCondition(Position),
 }
 
+impl<'a> EExpect<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExpect::Condition(e, _) => e.get_region(),
+            EExpect::Continuation(e, _) => e.get_region(),
+            EExpect::Space(_, pos) | EExpect::Expect(pos) | EExpect::IndentCondition(pos) => {
+                Region::from_pos(*pos)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EDbg<'a> {
+    Space(BadInputError, Position),
+    Dbg(Position),
+    Value(&'a EExpr<'a>, Position),
+    IndentValue(Position),
+}
+
+impl<'a> EDbg<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EDbg::Value(e, _) => e.get_region(),
+            EDbg::Space(_, pos) | EDbg::Dbg(pos) | EDbg::IndentValue(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPattern<'a> {
     Record(PRecord<'a>, Position),
@@ -531,6 +983,24 @@ pub enum EPattern<'a> {
     AsIndentStart(Position),
 }
 
+impl<'a> EPattern<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPattern::Record(e, _) => e.get_region(),
+            EPattern::PInParens(e, _) => e.get_region(),
+            EPattern::Underscore(pos)
+            | EPattern::Start(pos)
+            | EPattern::End(pos)
+            | EPattern::Space(_, pos)
+            | EPattern::NumLiteral(_, pos)
+            | EPattern::IndentStart(pos)
+            | EPattern::IndentEnd(pos)
+            | EPattern::AsIndentStart(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PRecord<'a> {
     End(Position),
@@ -551,10 +1021,32 @@ pub enum PRecord<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> PRecord<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            PRecord::Pattern(e, _) => e.get_region(),
+            PRecord::Expr(e, _) => e.get_region(),
+            PRecord::End(pos)
+            | PRecord::Open(pos)
+            | PRecord::Field(pos)
+            | PRecord::Colon(pos)
+            | PRecord::Optional(pos)
+            | PRecord::Space(_, pos)
+            | PRecord::IndentOpen(pos)
+            | PRecord::IndentColon(pos)
+            | PRecord::IndentOptional(pos)
+            | PRecord::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PInParens<'a> {
     End(Position),
     Open(Position),
+    /// `()`, which is neither a pattern in parentheses nor a tuple pattern
+    Empty(Position),
     Pattern(&'a EPattern<'a>, Position),
 
     Space(BadInputError, Position),
@@ -562,6 +1054,21 @@ pub enum PInParens<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> PInParens<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            PInParens::Pattern(e, _) => e.get_region(),
+            PInParens::End(pos)
+            | PInParens::Open(pos)
+            | PInParens::Empty(pos)
+            | PInParens::Space(_, pos)
+            | PInParens::IndentOpen(pos)
+            | PInParens::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EType<'a> {
     Space(BadInputError, Position),
@@ -587,6 +1094,32 @@ pub enum EType<'a> {
     TAsIndentStart(Position),
 }
 
+impl<'a> EType<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            EType::TRecord(e, _) => e.get_region(),
+            EType::TTagUnion(e, _) => e.get_region(),
+            EType::TInParens(e, _) => e.get_region(),
+            EType::TApply(e, _) => e.get_region(),
+            EType::TInlineAlias(e, _) => e.get_region(),
+            EType::TAbilityImpl(e, _) => e.get_region(),
+            EType::Space(_, pos)
+            | EType::TBadTypeVariable(pos)
+            | EType::TWildcard(pos)
+            | EType::TInferred(pos)
+            | EType::TStart(pos)
+            | EType::TEnd(pos)
+            | EType::TFunctionArgument(pos)
+            | EType::TWhereBar(pos)
+            | EType::THasClause(pos)
+            | EType::TIndentStart(pos)
+            | EType::TIndentEnd(pos)
+            | EType::TAsIndentStart(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeRecord<'a> {
     End(Position),
@@ -605,6 +1138,25 @@ pub enum ETypeRecord<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> ETypeRecord<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeRecord::Type(e, _) => e.get_region(),
+            ETypeRecord::End(pos)
+            | ETypeRecord::Open(pos)
+            | ETypeRecord::Field(pos)
+            | ETypeRecord::Colon(pos)
+            | ETypeRecord::Optional(pos)
+            | ETypeRecord::Space(_, pos)
+            | ETypeRecord::IndentOpen(pos)
+            | ETypeRecord::IndentColon(pos)
+            | ETypeRecord::IndentOptional(pos)
+            | ETypeRecord::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeTagUnion<'a> {
     End(Position),
@@ -618,10 +1170,26 @@ pub enum ETypeTagUnion<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> ETypeTagUnion<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeTagUnion::Type(e, _) => e.get_region(),
+            ETypeTagUnion::End(pos)
+            | ETypeTagUnion::Open(pos)
+            | ETypeTagUnion::Space(_, pos)
+            | ETypeTagUnion::IndentOpen(pos)
+            | ETypeTagUnion::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeInParens<'a> {
     End(Position),
     Open(Position),
+    /// `()`, which is neither a type in parentheses nor a tuple type
+    Empty(Position),
     ///
     Type(&'a EType<'a>, Position),
 
@@ -632,6 +1200,21 @@ pub enum ETypeInParens<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> ETypeInParens<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeInParens::Type(e, _) => e.get_region(),
+            ETypeInParens::End(pos)
+            | ETypeInParens::Open(pos)
+            | ETypeInParens::Empty(pos)
+            | ETypeInParens::Space(_, pos)
+            | ETypeInParens::IndentOpen(pos)
+            | ETypeInParens::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeApply {
     ///
@@ -644,6 +1227,20 @@ pub enum ETypeApply {
     StartIsNumber(Position),
 }
 
+impl ETypeApply {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeApply::StartNotUppercase(pos)
+            | ETypeApply::End(pos)
+            | ETypeApply::Space(_, pos)
+            | ETypeApply::DoubleDot(pos)
+            | ETypeApply::TrailingDot(pos)
+            | ETypeApply::StartIsNumber(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeInlineAlias {
     NotAnAlias(Position),
@@ -651,6 +1248,17 @@ pub enum ETypeInlineAlias {
     ArgumentNotLowercase(Position),
 }
 
+impl ETypeInlineAlias {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeInlineAlias::NotAnAlias(pos)
+            | ETypeInlineAlias::Qualified(pos)
+            | ETypeInlineAlias::ArgumentNotLowercase(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeAbilityImpl<'a> {
     End(Position),
@@ -671,11 +1279,39 @@ pub enum ETypeAbilityImpl<'a> {
     QuestionMark(Position),
     Bar(Position),
     Ampersand(Position),
+    Arrow(Position),
     Expr(&'a EExpr<'a>, Position),
     IndentBar(Position),
     IndentAmpersand(Position),
 }
 
+impl<'a> ETypeAbilityImpl<'a> {
+    /// Where in the source this error happened
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeAbilityImpl::Type(e, _) => e.get_region(),
+            ETypeAbilityImpl::Expr(e, _) => e.get_region(),
+            ETypeAbilityImpl::End(pos)
+            | ETypeAbilityImpl::Open(pos)
+            | ETypeAbilityImpl::Field(pos)
+            | ETypeAbilityImpl::Colon(pos)
+            | ETypeAbilityImpl::Optional(pos)
+            | ETypeAbilityImpl::Space(_, pos)
+            | ETypeAbilityImpl::IndentOpen(pos)
+            | ETypeAbilityImpl::IndentColon(pos)
+            | ETypeAbilityImpl::IndentOptional(pos)
+            | ETypeAbilityImpl::IndentEnd(pos)
+            | ETypeAbilityImpl::Updateable(pos)
+            | ETypeAbilityImpl::QuestionMark(pos)
+            | ETypeAbilityImpl::Bar(pos)
+            | ETypeAbilityImpl::Ampersand(pos)
+            | ETypeAbilityImpl::Arrow(pos)
+            | ETypeAbilityImpl::IndentBar(pos)
+            | ETypeAbilityImpl::IndentAmpersand(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 impl<'a> From<ERecord<'a>> for ETypeAbilityImpl<'a> {
     fn from(e: ERecord<'a>) -> Self {
         match e {
@@ -691,6 +1327,7 @@ impl<'a> From<ERecord<'a>> for ETypeAbilityImpl<'a> {
             ERecord::QuestionMark(p) => ETypeAbilityImpl::QuestionMark(p),
             ERecord::Bar(p) => ETypeAbilityImpl::Bar(p),
             ERecord::Ampersand(p) => ETypeAbilityImpl::Ampersand(p),
+            ERecord::Arrow(p) => ETypeAbilityImpl::Arrow(p),
             ERecord::Expr(e, p) => ETypeAbilityImpl::Expr(e, p),
             ERecord::IndentBar(p) => ETypeAbilityImpl::IndentBar(p),
             ERecord::IndentAmpersand(p) => ETypeAbilityImpl::IndentAmpersand(p),
@@ -1,5 +1,5 @@
 use crate::ast::{Has, Pattern};
-use crate::blankspace::{space0_around_ee, space0_before_e, space0_e};
+use crate::blankspace::{space0_before_e, space0_e};
 use crate::ident::{lowercase_ident, parse_ident, Ident};
 use crate::parser::Progress::{self, *};
 use crate::parser::{
@@ -146,20 +146,43 @@ fn loc_parse_tag_pattern_arg<'a>(
     .parse(arena, state)
 }
 
+/// Either a pattern in parentheses, like `(Ok x)`, or a tuple pattern, like `(x, _)`
 fn loc_pattern_in_parens_help<'a>(
     min_indent: u32,
 ) -> impl Parser<'a, Loc<Pattern<'a>>, PInParens<'a>> {
-    between!(
-        word1(b'(', PInParens::Open),
-        space0_around_ee(
+    move |arena, state: State<'a>| {
+        let start = state.pos();
+
+        let (_, elements, state) = collection_trailing_sep_e!(
+            word1(b'(', PInParens::Open),
             move |arena, state| specialize_ref(PInParens::Pattern, loc_pattern_help(min_indent))
                 .parse(arena, state),
+            word1(b',', PInParens::End),
+            word1(b')', PInParens::End),
             min_indent,
-            PInParens::IndentOpen,
+            PInParens::Open,
             PInParens::IndentEnd,
-        ),
-        word1(b')', PInParens::End)
-    )
+            Pattern::SpaceBefore
+        )
+        .parse(arena, state)?;
+
+        match elements.items {
+            [] => Err((MadeProgress, PInParens::Empty(start), state)),
+            [loc_pattern] => {
+                let value = match elements.final_comments() {
+                    [] => loc_pattern.value,
+                    comments => Pattern::SpaceAfter(arena.alloc(loc_pattern.value), comments),
+                };
+
+                Ok((MadeProgress, Loc::at(loc_pattern.region, value), state))
+            }
+            _ => Ok((
+                MadeProgress,
+                Loc::at(Region::new(start, state.pos()), Pattern::Tuple(elements)),
+                state,
+            )),
+        }
+    }
 }
 
 fn number_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, EPattern<'a>> {
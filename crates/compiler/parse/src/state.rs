@@ -25,6 +25,21 @@ impl<'a> State<'a> {
         }
     }
 
+    /// Start parsing at `offset` rather than at the start of the file, e.g. to parse code
+    /// that is embedded in a comment
+    pub(crate) fn at_offset(bytes: &'a [u8], offset: usize) -> State<'a> {
+        let line_start = match bytes[..offset].iter().rposition(|byte| *byte == b'\n') {
+            Some(newline) => newline + 1,
+            None => 0,
+        };
+
+        State {
+            original_bytes: bytes,
+            offset,
+            line_start: Position::new(line_start as u32),
+        }
+    }
+
     pub fn original_bytes(&self) -> &'a [u8] {
         self.original_bytes
     }
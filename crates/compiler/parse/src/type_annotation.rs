@@ -1,6 +1,6 @@
 use crate::ast::{
-    AssignedField, CommentOrNewline, HasAbilities, HasAbility, HasClause, HasImpls, Pattern,
-    Spaced, Tag, TypeAnnotation, TypeHeader,
+    AbilityName, AssignedField, CommentOrNewline, HasAbilities, HasAbility, HasClause, HasImpls,
+    Pattern, Spaced, Tag, TypeAnnotation, TypeHeader,
 };
 use crate::blankspace::{space0_around_ee, space0_before_e, space0_e};
 use crate::expr::record_value_field;
@@ -220,23 +220,46 @@ fn loc_applied_arg<'a>(
     )
 }
 
+/// Either a type in parentheses, like `(List a)`, or a tuple type, like `(Str, a)ext`
 fn loc_type_in_parens<'a>(
     min_indent: u32,
 ) -> impl Parser<'a, Loc<TypeAnnotation<'a>>, ETypeInParens<'a>> {
-    between!(
-        word1(b'(', ETypeInParens::Open),
-        space0_around_ee(
-            move |arena, state| specialize_ref(
-                ETypeInParens::Type,
-                expression(min_indent, true, false)
-            )
-            .parse(arena, state),
+    (move |arena, state: State<'a>| {
+        let start = state.pos();
+
+        let (_, elems, state) = collection_trailing_sep_e!(
+            word1(b'(', ETypeInParens::Open),
+            specialize_ref(ETypeInParens::Type, expression(min_indent, true, false)),
+            word1(b',', ETypeInParens::End),
+            word1(b')', ETypeInParens::End),
             min_indent,
-            ETypeInParens::IndentOpen,
+            ETypeInParens::Open,
             ETypeInParens::IndentEnd,
-        ),
-        word1(b')', ETypeInParens::IndentEnd)
-    )
+            TypeAnnotation::SpaceBefore
+        )
+        .parse(arena, state)?;
+
+        match elems.items {
+            [] => Err((MadeProgress, ETypeInParens::Empty(start), state)),
+            [loc_type] if elems.final_comments().is_empty() => Ok((MadeProgress, *loc_type, state)),
+            [loc_type] => {
+                let value =
+                    TypeAnnotation::SpaceAfter(arena.alloc(loc_type.value), elems.final_comments());
+
+                Ok((MadeProgress, Loc::at(loc_type.region, value), state))
+            }
+            _ => {
+                let elem_term = specialize_ref(ETypeInParens::Type, term(min_indent, false));
+                let (_, ext, state) = optional(allocated(elem_term)).parse(arena, state)?;
+
+                let region = Region::new(start, state.pos());
+                let value = TypeAnnotation::Tuple { elems, ext };
+
+                Ok((MadeProgress, Loc::at(region, value), state))
+            }
+        }
+    })
+    .trace("type_annotation:type_in_parens")
 }
 
 #[inline(always)]
@@ -412,7 +435,7 @@ fn loc_applied_args_e<'a>(
 
 fn has_clause<'a>(min_indent: u32) -> impl Parser<'a, Loc<HasClause<'a>>, EType<'a>> {
     map!(
-        // Suppose we are trying to parse "a has Hash"
+        // Suppose we are trying to parse "a has Hash & Eq"
         and!(
             space0_around_ee(
                 // Parse "a", with appropriate spaces
@@ -427,25 +450,51 @@ fn has_clause<'a>(min_indent: u32) -> impl Parser<'a, Loc<HasClause<'a>>, EType<
             then(
                 // Parse "has"; we don't care about this keyword
                 word3(b'h', b'a', b's', EType::THasClause),
-                // Parse "Hash"; this may be qualified from another module like "Hash.Hash"
+                // Parse "Hash & Eq"; each may be qualified from another module like "Hash.Hash"
                 |arena, state, _progress, _output| {
-                    space0_before_e(
-                        specialize(EType::TApply, loc!(parse_concrete_type)),
-                        state.column() + 1,
-                        EType::TIndentStart,
-                    )
-                    .parse(arena, state)
+                    has_clause_abilities(state.column() + 1).parse(arena, state)
                 }
             )
         ),
-        |(var, ability): (Loc<Spaced<'a, &'a str>>, Loc<TypeAnnotation<'a>>)| {
-            let region = Region::span_across(&var.region, &ability.region);
-            let has_clause = HasClause { var, ability };
+        |(var, abilities): (Loc<Spaced<'a, &'a str>>, &'a [AbilityName<'a>])| {
+            let last_ability = abilities.last().expect("there is at least one ability");
+            let region = Region::span_across(&var.region, &last_ability.region);
+            let has_clause = HasClause { var, abilities };
             Loc::at(region, has_clause)
         }
     )
 }
 
+/// Parse the abilities of a `has` clause, e.g. "Hash & Eq" in "a has Hash & Eq".
+fn has_clause_abilities<'a>(min_indent: u32) -> impl Parser<'a, &'a [AbilityName<'a>], EType<'a>> {
+    move |arena, state: State<'a>| {
+        let (_, first_ability, state) = space0_before_e(
+            specialize(EType::TApply, loc!(parse_concrete_type)),
+            min_indent,
+            EType::TIndentStart,
+        )
+        .parse(arena, state)?;
+
+        let (_, mut abilities, state) = zero_or_more!(skip_first!(
+            skip_second!(
+                backtrackable(space0_e(min_indent, EType::TIndentStart)),
+                word1(b'&', EType::THasClause)
+            ),
+            space0_before_e(
+                specialize(EType::TApply, loc!(parse_concrete_type)),
+                min_indent,
+                EType::TIndentStart,
+            )
+        ))
+        .parse(arena, state)?;
+
+        // Usually the number of abilities shouldn't be too large, so this is okay
+        abilities.insert(0, first_ability);
+
+        Ok((MadeProgress, abilities.into_bump_slice(), state))
+    }
+}
+
 /// Parse a chain of `has` clauses, e.g. " | a has Hash, b has Eq".
 /// Returns the clauses and spaces before the starting "|", if there were any.
 fn has_clause_chain<'a>(
@@ -0,0 +1 @@
+crash "oops"
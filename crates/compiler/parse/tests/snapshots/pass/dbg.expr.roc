@@ -0,0 +1 @@
+dbg 1 == 1
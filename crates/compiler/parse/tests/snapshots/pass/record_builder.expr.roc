@@ -0,0 +1,6 @@
+succeed {
+    a: <- get "a",
+    b: 2,
+    c,
+    d: <- get "d",
+}
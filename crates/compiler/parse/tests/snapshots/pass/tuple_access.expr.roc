@@ -0,0 +1 @@
+pair.0 + (f x).1
@@ -0,0 +1,3 @@
+(x, (y, _)) = (1, ("two", 3.0))
+
+x
@@ -0,0 +1,3 @@
+f : a -> b | a has Hash & Eq, b has Eq & Hash & Sort
+
+f
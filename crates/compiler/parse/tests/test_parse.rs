@@ -120,6 +120,7 @@ mod test_parse {
 
     // see tests/snapshots to see test input(.roc) and expected output(.result-ast)
     snapshot_tests! {
+        fail/record_update_builder.expr,
         fail/type_argument_no_arrow.expr,
         fail/type_double_comma.expr,
         pass/ability_demand_signature_is_multiline.expr,
@@ -147,6 +148,8 @@ mod test_parse {
         pass/comment_before_op.expr,
         pass/comment_inside_empty_list.expr,
         pass/comment_with_non_ascii.expr,
+        pass/crash.expr,
+        pass/dbg.expr,
         pass/destructure_tag_assignment.expr,
         pass/empty_app_header.header,
         pass/empty_hosted_header.header,
@@ -239,6 +242,7 @@ mod test_parse {
         pass/qualified_field.expr,
         pass/qualified_tag.expr,
         pass/qualified_var.expr,
+        pass/record_builder.expr,
         pass/record_destructure_def.expr,
         pass/record_func_type_decl.expr,
         pass/record_type_with_function.expr,
@@ -258,6 +262,9 @@ mod test_parse {
         pass/tag_pattern.expr,
         pass/ten_times_eleven.expr,
         pass/three_arg_closure.expr,
+        pass/tuple_access.expr,
+        pass/tuple_destructure_def.expr,
+        pass/tuple_type.expr,
         pass/two_arg_closure.expr,
         pass/two_backpassing.expr,
         pass/two_branch_when.expr,
@@ -289,6 +296,7 @@ mod test_parse {
         pass/when_with_records.expr,
         pass/where_clause_function.expr,
         pass/where_clause_multiple_has_across_newlines.expr,
+        pass/where_clause_multiple_bound_abilities.expr,
         pass/where_clause_multiple_has.expr,
         pass/where_clause_non_function.expr,
         pass/where_clause_on_newline.expr,
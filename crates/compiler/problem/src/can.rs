@@ -178,12 +178,106 @@ pub enum Problem {
         original_opaque: Symbol,
         ability_member: Symbol,
     },
+    /// An example in a doc comment whose code doesn't parse, so `roc test` skips it
+    UnparsableDoctest(Region),
+}
+
+impl Problem {
+    /// The region the problem is reported at, if it has one.
+    pub fn region(&self) -> Option<Region> {
+        match self {
+            Problem::UnusedDef(_, region)
+            | Problem::UnusedImport(_, region)
+            | Problem::UnusedModuleImport(_, region)
+            | Problem::UnusedArgument(_, _, _, region)
+            | Problem::UnusedBranchDef(_, region)
+            | Problem::PrecedenceProblem(PrecedenceProblem::BothNonAssociative(region, _, _))
+            | Problem::UnsupportedPattern(_, region)
+            | Problem::CyclicAlias(_, region, _, _)
+            | Problem::PhantomTypeArgument {
+                variable_region: region,
+                ..
+            }
+            | Problem::UnboundTypeVariable {
+                one_occurrence: region,
+                ..
+            }
+            | Problem::DuplicateRecordFieldValue {
+                field_region: region,
+                ..
+            }
+            | Problem::DuplicateRecordFieldType {
+                field_region: region,
+                ..
+            }
+            | Problem::InvalidOptionalValue {
+                field_region: region,
+                ..
+            }
+            | Problem::DuplicateTag {
+                tag_region: region, ..
+            }
+            | Problem::SignatureDefMismatch {
+                def_pattern: region,
+                ..
+            }
+            | Problem::InvalidAliasRigid { region, .. }
+            | Problem::InvalidInterpolation(region)
+            | Problem::InvalidHexadecimal(region)
+            | Problem::InvalidUnicodeCodePt(region)
+            | Problem::NestedDatatype {
+                def_region: region, ..
+            }
+            | Problem::InvalidExtensionType { region, .. }
+            | Problem::AbilityHasTypeVariables {
+                variables_region: region,
+                ..
+            }
+            | Problem::HasClauseIsNotAbility { region }
+            | Problem::IllegalHasClause { region }
+            | Problem::AbilityMemberMissingHasClause { region, .. }
+            | Problem::AbilityMemberMultipleBoundVars {
+                span_has_clauses: region,
+                ..
+            }
+            | Problem::AbilityNotOnToplevel { region }
+            | Problem::AbilityUsedAsType(_, _, region)
+            | Problem::NestedSpecialization(_, region)
+            | Problem::IllegalDerivedAbility(region)
+            | Problem::ImplementationNotFound { region, .. }
+            | Problem::NotAnAbilityMember { region, .. }
+            | Problem::OptionalAbilityImpl { region, .. }
+            | Problem::QualifiedAbilityImpl { region }
+            | Problem::AbilityImplNotIdent { region }
+            | Problem::DuplicateImpl {
+                duplicate: region, ..
+            }
+            | Problem::NotAnAbility(region)
+            | Problem::ImplementsNonRequired { region, .. }
+            | Problem::DoesNotImplementAbility { region, .. }
+            | Problem::NotBoundInAllPatterns { region, .. }
+            | Problem::NoIdentifiersIntroduced(region)
+            | Problem::UnparsableDoctest(region)
+            | Problem::OverloadedSpecialization {
+                overload: region, ..
+            } => Some(*region),
+            Problem::UnknownGeneratesWith(Loc { region, .. })
+            | Problem::Shadowing {
+                shadow: Loc { region, .. },
+                ..
+            } => Some(*region),
+            Problem::BadRecursion(cycle) => cycle.first().map(|entry| entry.symbol_region),
+            Problem::RuntimeError(runtime_error) => runtime_error.region(),
+            Problem::ExposedButNotDefined(_) => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ExtensionTypeKind {
     Record,
     TagUnion,
+    Tuple,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -268,6 +362,14 @@ pub enum RuntimeError {
     },
     OpaqueNotApplied(Loc<Ident>),
     OpaqueAppliedToMultipleArgs(Region),
+    /// `crash` was used without a message
+    CrashNotApplied(Region),
+    /// `crash` was given more than just a message
+    CrashAppliedToMultipleArgs(Region),
+    /// A function was given more than one record builder
+    MultipleRecordBuilders(Region),
+    /// A record builder was used without being given to a function
+    UnappliedRecordBuilder(Region),
     ValueNotExposed {
         module_name: ModuleName,
         ident: Ident,
@@ -343,6 +445,60 @@ pub enum RuntimeError {
 }
 
 impl RuntimeError {
+    /// The region the error is reported at, if it has one.
+    pub fn region(&self) -> Option<Region> {
+        match self {
+            RuntimeError::Shadowing {
+                shadow: Loc { region, .. },
+                ..
+            }
+            | RuntimeError::InvalidOptionalValue {
+                field_region: region,
+                ..
+            }
+            | RuntimeError::UnsupportedPattern(region)
+            | RuntimeError::MalformedPattern(_, region)
+            | RuntimeError::LookupNotInScope(Loc { region, .. }, _)
+            | RuntimeError::OpaqueNotDefined {
+                usage: Loc { region, .. },
+                ..
+            }
+            | RuntimeError::OpaqueOutsideScope {
+                referenced_region: region,
+                ..
+            }
+            | RuntimeError::OpaqueNotApplied(Loc { region, .. })
+            | RuntimeError::OpaqueAppliedToMultipleArgs(region)
+            | RuntimeError::CrashNotApplied(region)
+            | RuntimeError::CrashAppliedToMultipleArgs(region)
+            | RuntimeError::MultipleRecordBuilders(region)
+            | RuntimeError::UnappliedRecordBuilder(region)
+            | RuntimeError::ValueNotExposed { region, .. }
+            | RuntimeError::ModuleNotImported { region, .. }
+            | RuntimeError::InvalidPrecedence(_, region)
+            | RuntimeError::MalformedIdentifier(_, _, region)
+            | RuntimeError::MalformedTypeName(_, region)
+            | RuntimeError::MalformedClosure(region)
+            | RuntimeError::InvalidRecordUpdate { region }
+            | RuntimeError::InvalidFloat(_, region, _)
+            | RuntimeError::InvalidInt(_, _, region, _)
+            | RuntimeError::InvalidInterpolation(region)
+            | RuntimeError::InvalidHexadecimal(region)
+            | RuntimeError::InvalidUnicodeCodePt(region)
+            | RuntimeError::EmptySingleQuote(region)
+            | RuntimeError::MultipleCharsInSingleQuote(region)
+            | RuntimeError::DegenerateBranch(region) => Some(*region),
+            RuntimeError::CircularDef(cycle) => cycle.first().map(|entry| entry.symbol_region),
+            RuntimeError::UnresolvedTypeVar
+            | RuntimeError::ErroneousType
+            | RuntimeError::NonExhaustivePattern
+            | RuntimeError::NoImplementationNamed { .. }
+            | RuntimeError::NoImplementation
+            | RuntimeError::VoidValue
+            | RuntimeError::ExposedButNotDefined(_) => None,
+        }
+    }
+
     pub fn runtime_message(self) -> String {
         use RuntimeError::*;
 
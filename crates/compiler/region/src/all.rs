@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Debug};
 
-#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub struct Region {
     start: Position,
     end: Position,
@@ -88,7 +89,7 @@ impl fmt::Debug for Region {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub struct Position {
     pub offset: u32,
 }
@@ -272,7 +273,7 @@ impl fmt::Debug for LineColumnRegion {
     }
 }
 
-#[derive(Clone, Eq, Copy, PartialEq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Eq, Copy, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Loc<T> {
     pub region: Region,
     pub value: T,
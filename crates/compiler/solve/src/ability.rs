@@ -9,7 +9,8 @@ use roc_solve_problem::{
 };
 use roc_types::num::NumericRange;
 use roc_types::subs::{
-    instantiate_rigids, Content, FlatType, GetSubsSlice, Rank, RecordFields, Subs, Variable,
+    instantiate_rigids, Content, FlatType, GetSubsSlice, Rank, RecordFields, Subs, SubsSlice,
+    Variable,
 };
 use roc_types::types::{AliasKind, Category, MemberImpl, PatternCategory};
 use roc_unify::unify::{Env, MustImplementConstraints};
@@ -272,6 +273,19 @@ impl ObligationCache {
                 var,
             )),
 
+            Symbol::HASH_HASH_ABILITY => {
+                Some(DeriveHash::is_derivable(self, abilities_store, subs, var))
+            }
+
+            Symbol::BOOL_EQ => Some(DeriveEq::is_derivable(self, abilities_store, subs, var)),
+
+            Symbol::GEN_ARBITRARY_ABILITY => Some(DeriveArbitrary::is_derivable(
+                self,
+                abilities_store,
+                subs,
+                var,
+            )),
+
             _ => None,
         };
 
@@ -435,6 +449,16 @@ fn is_builtin_number_alias(symbol: Symbol) -> bool {
     )
 }
 
+#[inline(always)]
+#[rustfmt::skip]
+fn is_builtin_fractional_alias(symbol: Symbol) -> bool {
+    matches!(symbol,
+          Symbol::NUM_F32  | Symbol::NUM_BINARY32
+        | Symbol::NUM_F64  | Symbol::NUM_BINARY64
+        | Symbol::NUM_DEC  | Symbol::NUM_DECIMAL,
+    )
+}
+
 struct NotDerivable {
     var: Variable,
     context: NotDerivableContext,
@@ -450,21 +474,16 @@ trait DerivableVisitor {
         false
     }
 
+    /// Whether a `Num *`, `Int *`, or `Frac *` opaque should be inspected down to its ground
+    /// type, rather than being considered derivable for any instantiation.
     #[inline(always)]
-    fn visit_flex_able(var: Variable, ability: Symbol) -> Result<(), NotDerivable> {
-        if ability != Self::ABILITY {
-            Err(NotDerivable {
-                var,
-                context: NotDerivableContext::NoContext,
-            })
-        } else {
-            Ok(())
-        }
+    fn visit_number_opaque(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
     }
 
     #[inline(always)]
-    fn visit_rigid_able(var: Variable, ability: Symbol) -> Result<(), NotDerivable> {
-        if ability != Self::ABILITY {
+    fn visit_rigid_able(var: Variable, abilities: &[Symbol]) -> Result<(), NotDerivable> {
+        if !abilities.contains(&Self::ABILITY) {
             Err(NotDerivable {
                 var,
                 context: NotDerivableContext::NoContext,
@@ -594,7 +613,8 @@ trait DerivableVisitor {
             match *content {
                 FlexVar(opt_name) => {
                     // Promote the flex var to be bound to the ability.
-                    subs.set_content(var, Content::FlexAbleVar(opt_name, Self::ABILITY));
+                    let abilities = SubsSlice::extend_new(&mut subs.symbol_names, [Self::ABILITY]);
+                    subs.set_content(var, Content::FlexAbleVar(opt_name, abilities));
                 }
                 RigidVar(_) => {
                     return Err(NotDerivable {
@@ -602,8 +622,18 @@ trait DerivableVisitor {
                         context: NotDerivableContext::NoContext,
                     })
                 }
-                FlexAbleVar(_, ability) => Self::visit_flex_able(var, ability)?,
-                RigidAbleVar(_, ability) => Self::visit_rigid_able(var, ability)?,
+                FlexAbleVar(opt_name, abilities) => {
+                    // The var can still be anything that has its abilities, so it can be
+                    // bound to this ability as well.
+                    let mut abilities = subs.get_abilities(abilities);
+                    if abilities.insert(Self::ABILITY) {
+                        let abilities = subs.push_abilities(&abilities);
+                        subs.set_content(var, Content::FlexAbleVar(opt_name, abilities));
+                    }
+                }
+                RigidAbleVar(_, abilities) => {
+                    Self::visit_rigid_able(var, subs.get_subs_slice(abilities))?
+                }
                 RecursionVar {
                     structure,
                     opt_name: _,
@@ -671,7 +701,8 @@ trait DerivableVisitor {
                     EmptyRecord => Self::visit_empty_record(var)?,
                     EmptyTagUnion => Self::visit_empty_tag_union(var)?,
 
-                    Erroneous(_) => {
+                    // Abilities are not yet derived for tuples
+                    Tuple(..) | EmptyTuple | Erroneous(_) => {
                         return Err(NotDerivable {
                             var,
                             context: NotDerivableContext::NoContext,
@@ -684,8 +715,11 @@ trait DerivableVisitor {
                     real_var,
                     AliasKind::Opaque,
                 ) => {
-                    // Numbers: always decay until a ground is hit.
-                    stack.push(real_var);
+                    // Numbers: decay until a ground is hit, unless every number is derivable.
+                    let descend = Self::visit_number_opaque(var)?;
+                    if descend.0 {
+                        stack.push(real_var);
+                    }
                 }
                 Alias(opaque, _alias_variables, _real_var, AliasKind::Opaque) => {
                     if obligation_cache
@@ -893,6 +927,257 @@ impl DerivableVisitor for DeriveDecoding {
     }
 }
 
+struct DeriveHash;
+impl DerivableVisitor for DeriveHash {
+    const ABILITY: Symbol = Symbol::HASH_HASH_ABILITY;
+
+    #[inline(always)]
+    fn is_derivable_builtin_opaque(symbol: Symbol) -> bool {
+        (is_builtin_number_alias(symbol) && !is_builtin_fractional_alias(symbol))
+            || symbol == Symbol::BOOL_BOOL
+    }
+
+    #[inline(always)]
+    fn visit_recursion(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_apply(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if matches!(symbol, Symbol::LIST_LIST | Symbol::STR_STR) {
+            Ok(Descend(true))
+        } else {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        }
+    }
+
+    #[inline(always)]
+    fn visit_record(
+        _subs: &Subs,
+        _var: Variable,
+        _fields: RecordFields,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_recursive_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_function_or_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_empty_record(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tag_union(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_alias(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if is_builtin_fractional_alias(symbol) {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        } else if is_builtin_number_alias(symbol) {
+            Ok(Descend(false))
+        } else {
+            Ok(Descend(true))
+        }
+    }
+
+    #[inline(always)]
+    fn visit_ranged_number(_var: Variable, _range: NumericRange) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+}
+
+struct DeriveArbitrary;
+impl DerivableVisitor for DeriveArbitrary {
+    const ABILITY: Symbol = Symbol::GEN_ARBITRARY_ABILITY;
+
+    #[inline(always)]
+    fn is_derivable_builtin_opaque(symbol: Symbol) -> bool {
+        is_builtin_number_alias(symbol) || symbol == Symbol::BOOL_BOOL
+    }
+
+    #[inline(always)]
+    fn visit_recursion(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_apply(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if matches!(symbol, Symbol::LIST_LIST | Symbol::STR_STR) {
+            Ok(Descend(true))
+        } else {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        }
+    }
+
+    #[inline(always)]
+    fn visit_record(
+        subs: &Subs,
+        var: Variable,
+        fields: RecordFields,
+    ) -> Result<Descend, NotDerivable> {
+        // whether an optional field is there is only known at compile time
+        if fields
+            .iter_all()
+            .any(|(_, _, field)| subs[field].is_optional())
+        {
+            return Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            });
+        }
+
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_recursive_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_function_or_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_empty_record(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tag_union(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_alias(_var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if is_builtin_number_alias(symbol) {
+            Ok(Descend(false))
+        } else {
+            Ok(Descend(true))
+        }
+    }
+
+    #[inline(always)]
+    fn visit_ranged_number(_var: Variable, _range: NumericRange) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+}
+
+struct DeriveEq;
+impl DerivableVisitor for DeriveEq {
+    const ABILITY: Symbol = Symbol::BOOL_EQ;
+
+    #[inline(always)]
+    fn is_derivable_builtin_opaque(symbol: Symbol) -> bool {
+        is_builtin_number_alias(symbol)
+    }
+
+    #[inline(always)]
+    fn visit_number_opaque(_var: Variable) -> Result<Descend, NotDerivable> {
+        // Every number, regardless of its precision, can be compared for equality.
+        Ok(Descend(false))
+    }
+
+    #[inline(always)]
+    fn visit_recursion(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_apply(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if matches!(
+            symbol,
+            Symbol::LIST_LIST | Symbol::STR_STR | Symbol::BOX_BOX_TYPE,
+        ) {
+            Ok(Descend(true))
+        } else {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        }
+    }
+
+    #[inline(always)]
+    fn visit_record(
+        _subs: &Subs,
+        _var: Variable,
+        _fields: RecordFields,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_recursive_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_function_or_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_empty_record(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tag_union(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_alias(_var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if is_builtin_number_alias(symbol) {
+            Ok(Descend(false))
+        } else {
+            Ok(Descend(true))
+        }
+    }
+
+    #[inline(always)]
+    fn visit_ranged_number(_var: Variable, _range: NumericRange) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+}
+
 /// Determines what type implements an ability member of a specialized signature, given the
 /// [MustImplementAbility] constraints of the signature.
 pub fn type_implementing_specialization(
@@ -26,7 +26,7 @@ use roc_region::all::Loc;
 use roc_solve_problem::TypeError;
 use roc_types::subs::{
     self, AliasVariables, Content, Descriptor, FlatType, GetSubsSlice, LambdaSet, Mark,
-    OptVariable, Rank, RecordFields, Subs, SubsIndex, SubsSlice, UlsOfVar, UnionLabels,
+    OptVariable, Rank, RecordFields, Subs, SubsIndex, SubsSlice, TupleElems, UlsOfVar, UnionLabels,
     UnionLambdas, UnionTags, Variable, VariableSubsSlice,
 };
 use roc_types::types::Type::{self, *};
@@ -340,12 +340,12 @@ impl Aliases {
 
         for OptAbleVar {
             var: rec_var,
-            opt_ability,
+            opt_abilities,
         } in delayed_variables
             .recursion_variables(&mut self.variables)
             .iter_mut()
         {
-            debug_assert!(opt_ability.is_none());
+            debug_assert!(opt_abilities.is_none());
             let new_var = subs.fresh_unnamed_flex_var();
             substitutions.insert(*rec_var, new_var);
 
@@ -362,7 +362,7 @@ impl Aliases {
             .iter_mut()
             .zip(new_lambda_set_variables)
         {
-            debug_assert!(old.opt_ability.is_none());
+            debug_assert!(old.opt_abilities.is_none());
             if old.var != *new {
                 substitutions.insert(old.var, *new);
 
@@ -2271,6 +2271,34 @@ fn type_to_variable<'a>(
                 register_with_known_var(subs, destination, rank, pools, content)
             }
 
+            Tuple(elems, ext) => {
+                let mut elem_vars = Vec::with_capacity_in(elems.len(), arena);
+
+                for (index, elem_type) in elems {
+                    elem_vars.push((*index, helper!(elem_type)));
+                }
+
+                let temp_ext_var = match ext {
+                    TypeExtension::Open(ext) => helper!(ext),
+                    TypeExtension::Closed => {
+                        register(subs, rank, pools, Content::Structure(FlatType::EmptyTuple))
+                    }
+                };
+
+                let (it, new_ext_var) =
+                    TupleElems::empty().sorted_iterator_and_ext(subs, temp_ext_var);
+
+                let it: std::vec::Vec<_> = it.collect();
+                elem_vars.extend(it);
+                elem_vars.sort_by_key(|(index, _)| *index);
+
+                let tuple_elems = TupleElems::insert_into_subs(subs, elem_vars);
+
+                let content = Content::Structure(FlatType::Tuple(tuple_elems, new_ext_var));
+
+                register_with_known_var(subs, destination, rank, pools, content)
+            }
+
             TagUnion(tags, ext) => {
                 // An empty tags is inefficient (but would be correct)
                 // If hit, try to turn the value into an EmptyTagUnion in canonicalization
@@ -2391,20 +2419,22 @@ fn type_to_variable<'a>(
                     let length = type_arguments.len() + lambda_set_variables.len();
                     let new_variables = VariableSubsSlice::reserve_into_subs(subs, length);
 
-                    for (target_index, OptAbleType { typ, opt_ability }) in
+                    for (target_index, OptAbleType { typ, opt_abilities }) in
                         (new_variables.indices()).zip(type_arguments)
                     {
-                        let copy_var = match opt_ability {
+                        let copy_var = match opt_abilities {
                             None => helper!(typ),
-                            Some(ability) => {
+                            Some(abilities) => {
                                 // If this type argument is marked as being bound to an ability, we must
                                 // now correctly instantiate it as so.
                                 match RegisterVariable::from_type(subs, rank, pools, arena, typ) {
                                     RegisterVariable::Direct(var) => {
                                         use Content::*;
                                         match *subs.get_content_without_compacting(var) {
-                                            FlexVar(opt_name) => subs
-                                                .set_content(var, FlexAbleVar(opt_name, *ability)),
+                                            FlexVar(opt_name) => {
+                                                let abilities = subs.push_abilities(abilities);
+                                                subs.set_content(var, FlexAbleVar(opt_name, abilities))
+                                            }
                                             RigidVar(..) => internal_error!("Rigid var in type arg for {:?} - this is a bug in the solver, or our understanding", actual),
                                             RigidAbleVar(..) | FlexAbleVar(..) => internal_error!("Able var in type arg for {:?} - this is a bug in the solver, or our understanding", actual),
                                             _ => {
@@ -2865,7 +2895,7 @@ fn create_union_lambda<'a>(
     let variable_slice = register_tag_arguments(subs, rank, pools, arena, stack, capture_types);
     let new_variable_slices = SubsSlice::extend_new(&mut subs.variable_slices, [variable_slice]);
 
-    let lambda_name_slice = SubsSlice::extend_new(&mut subs.closure_names, [closure]);
+    let lambda_name_slice = SubsSlice::extend_new(&mut subs.symbol_names, [closure]);
 
     UnionLambdas::from_slices(lambda_name_slice, new_variable_slices)
 }
@@ -3119,6 +3149,9 @@ fn adjust_rank_content(
                     group_rank
                 }
 
+                // Like empty records, the empty tuple is not a reason to force de-generalization
+                EmptyTuple => group_rank,
+
                 // THEORY: an empty tag never needs to get generalized
                 EmptyTagUnion => Rank::toplevel(),
 
@@ -3140,6 +3173,17 @@ fn adjust_rank_content(
                     rank
                 }
 
+                Tuple(elems, ext_var) => {
+                    let mut rank = adjust_rank(subs, young_mark, visit_mark, group_rank, *ext_var);
+
+                    for var_index in elems.iter_variables() {
+                        let var = subs[var_index];
+                        rank = rank.max(adjust_rank(subs, young_mark, visit_mark, group_rank, var));
+                    }
+
+                    rank
+                }
+
                 TagUnion(tags, ext_var) => {
                     let mut rank = adjust_rank(subs, young_mark, visit_mark, group_rank, *ext_var);
                     // For performance reasons, we only keep one representation of empty tag unions
@@ -3488,7 +3532,10 @@ fn deep_copy_var_help(
                         Func(new_arguments, new_closure_var, new_ret_var)
                     }
 
-                    same @ EmptyRecord | same @ EmptyTagUnion | same @ Erroneous(_) => same,
+                    same @ EmptyRecord
+                    | same @ EmptyTuple
+                    | same @ EmptyTagUnion
+                    | same @ Erroneous(_) => same,
 
                     Record(fields, ext_var) => {
                         let record_fields = {
@@ -3530,6 +3577,20 @@ fn deep_copy_var_help(
                         Record(record_fields, work!(ext_var))
                     }
 
+                    Tuple(elems, ext_var) => {
+                        let tuple_elems = {
+                            let new_variables = copy_sequence!(elems.len(), elems.iter_variables());
+
+                            TupleElems {
+                                length: elems.length,
+                                elem_index_start: elems.elem_index_start,
+                                variables_start: new_variables.start,
+                            }
+                        };
+
+                        Tuple(tuple_elems, work!(ext_var))
+                    }
+
                     TagUnion(tags, ext_var) => {
                         let union_tags = copy_union!(tags);
 
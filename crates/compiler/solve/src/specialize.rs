@@ -17,8 +17,9 @@ use roc_error_macros::{internal_error, todo_abilities};
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_types::{
     subs::{
-        get_member_lambda_sets_at_region, Content, Descriptor, GetSubsSlice, LambdaSet, Mark,
-        OptVariable, Rank, Subs, SubsSlice, UlsOfVar, Variable,
+        get_member_lambda_sets_at_region, instantiate_rigids, Content, Descriptor, FlatType,
+        GetSubsSlice, LambdaSet, Mark, OptVariable, Rank, Subs, SubsSlice, UlsOfVar, UnionLabels,
+        Variable,
     },
     types::{AliasKind, MemberImpl, Uls},
 };
@@ -542,7 +543,7 @@ fn compact_lambda_set<P: Phase>(
         Err(()) => {
             // Do nothing other than to remove the concrete lambda to drop from the lambda set,
             // which we already did in 1b above.
-            trace_compact!(3iter_end_skipped. subs, t_f1);
+            trace_compact!(3iter_end_skipped.subs, t_f1);
             return OneCompactionResult::Compacted {
                 new_obligations: Default::default(),
                 new_lambda_sets_to_specialize: Default::default(),
@@ -565,7 +566,7 @@ fn compact_lambda_set<P: Phase>(
         Err(()) => {
             // Do nothing other than to remove the concrete lambda to drop from the lambda set,
             // which we already did in 1b above.
-            trace_compact!(3iter_end_skipped. subs, t_f1);
+            trace_compact!(3iter_end_skipped.subs, t_f1);
             return OneCompactionResult::Compacted {
                 new_obligations: Default::default(),
                 new_lambda_sets_to_specialize: Default::default(),
@@ -578,7 +579,7 @@ fn compact_lambda_set<P: Phase>(
     let t_f2 = deep_copy_var_in(subs, target_rank, pools, t_f2, arena);
 
     // 3. Unify `t_f1 ~ t_f2`.
-    trace_compact!(3iter_start. subs, this_lambda_set, t_f1, t_f2);
+    trace_compact!(3iter_start.subs, this_lambda_set, t_f1, t_f2);
     let (vars, new_obligations, new_lambda_sets_to_specialize, _meta) = unify(
         &mut UEnv::new(subs),
         t_f1,
@@ -586,7 +587,7 @@ fn compact_lambda_set<P: Phase>(
         Mode::LAMBDA_SET_SPECIALIZATION,
     )
     .expect_success("ambient functions don't unify");
-    trace_compact!(3iter_end. subs, t_f1);
+    trace_compact!(3iter_end.subs, t_f1);
 
     introduce(subs, target_rank, pools, &vars);
 
@@ -601,6 +602,7 @@ enum SpecializationTypeKey {
     Opaque(Symbol),
     Derived(DeriveKey),
     Immediate(Symbol),
+    SingleLambdaSetImmediate(Symbol),
 }
 
 enum SpecializeDecision {
@@ -622,7 +624,11 @@ fn make_specialization_decision<P: Phase>(
     use Content::*;
     use SpecializationTypeKey::*;
     match subs.get_content_without_compacting(var) {
-        Alias(opaque, _, _, AliasKind::Opaque) if opaque.module_id() != ModuleId::NUM => {
+        Alias(opaque, _, _, AliasKind::Opaque)
+            if opaque.module_id() != ModuleId::NUM
+                && !(*opaque == Symbol::BOOL_BOOL
+                    && matches!(ability_member, Symbol::HASH_HASH | Symbol::GEN_ARBITRARY)) =>
+        {
             if P::IS_LATE {
                 SpecializeDecision::Specialize(Opaque(*opaque))
             } else {
@@ -666,6 +672,9 @@ fn make_specialization_decision<P: Phase>(
                     roc_derive_key::Derived::Immediate(imm) => {
                         SpecializeDecision::Specialize(Immediate(imm))
                     }
+                    roc_derive_key::Derived::SingleLambdaSetImmediate(imm) => {
+                        SpecializeDecision::Specialize(SingleLambdaSetImmediate(imm))
+                    }
                     roc_derive_key::Derived::Key(derive_key) => {
                         SpecializeDecision::Specialize(Derived(derive_key))
                     }
@@ -683,11 +692,14 @@ fn make_specialization_decision<P: Phase>(
             }
         }
         Error => SpecializeDecision::Drop,
+        RecursionVar { structure, .. } => {
+            // The recursion var stands for its recursive structure; specialize for that.
+            make_specialization_decision(subs, phase, *structure, ability_member)
+        }
         FlexAbleVar(_, _)
         | RigidAbleVar(..)
         | FlexVar(..)
         | RigidVar(..)
-        | RecursionVar { .. }
         | LambdaSet(..)
         | RangedNumber(..) => {
             internal_error!("unexpected")
@@ -785,5 +797,82 @@ fn get_specialization_lambda_set_ambient_function<P: Phase>(
 
             Ok(immediate_lambda_set_at_region)
         }
+
+        SpecializationTypeKey::SingleLambdaSetImmediate(imm) => {
+            // The immediate is a plain function, not an ability member, so its type has exactly
+            // one lambda set - its own - and that type is the ambient function we want.
+            debug_assert_eq!(lset_region, 1);
+
+            let module_id = imm.module_id();
+            debug_assert!(module_id.is_builtin());
+
+            if imm == Symbol::BOOL_STRUCTURAL_EQ {
+                return Ok(synth_structural_eq_type(subs));
+            }
+
+            let module_types = &derived_env
+                .exposed_types
+                .get(&module_id)
+                .unwrap()
+                .exposed_types_storage_subs;
+
+            // Functions like `Hash.hashList` are generic, so we need to instantiate them.
+            let storage_var = module_types.stored_vars_by_symbol.get(&imm).unwrap();
+            let imported = module_types
+                .storage_subs
+                .export_variable_to(subs, *storage_var);
+            let function_var = imported.variable;
+
+            // The stored lambda set of a low-level wrapper may name a different copy of the
+            // function type as its ambient function; point it back here.
+            if let Content::Structure(FlatType::Func(_, lambda_set, _)) =
+                *subs.get_content_without_compacting(function_var)
+            {
+                let mut lambda_set_content = subs.get_lambda_set(lambda_set);
+                lambda_set_content.ambient_function = function_var;
+                subs.set_content(lambda_set, Content::LambdaSet(lambda_set_content));
+            }
+
+            instantiate_rigids(subs, function_var);
+
+            Ok(function_var)
+        }
     }
 }
+
+/// `Bool.structuralEq` is not exposed, so user code can't reach it, and so its type isn't stored
+/// with Bool's exposed types either. The derived `Eq` of numbers and strings still calls it, so
+/// build its type here:
+///
+///   structuralEq : a, a -[[structuralEq]]-> Bool
+fn synth_structural_eq_type(subs: &mut Subs) -> Variable {
+    fn fresh(subs: &mut Subs, content: Content) -> Variable {
+        subs.fresh(Descriptor {
+            content,
+            rank: Rank::import(),
+            mark: Mark::NONE,
+            copy: OptVariable::NONE,
+        })
+    }
+
+    let a = fresh(subs, Content::FlexVar(None));
+    let function_var = fresh(subs, Content::Error);
+    let solved = UnionLabels::insert_into_subs(subs, [(Symbol::BOOL_STRUCTURAL_EQ, [])]);
+    let lambda_set = fresh(
+        subs,
+        Content::LambdaSet(LambdaSet {
+            solved,
+            recursion_var: OptVariable::NONE,
+            unspecialized: SubsSlice::default(),
+            ambient_function: function_var,
+        }),
+    );
+    let arguments = SubsSlice::extend_new(&mut subs.variables, [a, a]);
+
+    subs.set_content(
+        function_var,
+        Content::Structure(FlatType::Func(arguments, lambda_set, Variable::BOOL)),
+    );
+
+    function_var
+}
@@ -1453,6 +1453,50 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn tuple_literal() {
+        infer_eq("(5, \"hello\", 3.14)", "( Num *, Str, Float * )");
+    }
+
+    #[test]
+    fn tuple_literal_accessor() {
+        infer_eq("(5, \"hello\").1", "Str");
+    }
+
+    #[test]
+    fn tuple_arg() {
+        infer_eq("\\tup -> tup.1", "( _, a )* -> a");
+    }
+
+    #[test]
+    fn tuple_swap() {
+        infer_eq(
+            indoc!(
+                r#"
+                    swap : (a, b) -> (b, a)
+                    swap = \(x, y) -> (y, x)
+
+                    swap
+                "#
+            ),
+            "( a, b ) -> ( b, a )",
+        );
+    }
+
+    #[test]
+    fn tuple_destructure_def() {
+        infer_eq(
+            indoc!(
+                r#"
+                    (x, y) = ("hello", 42)
+
+                    x
+                "#
+            ),
+            "Str",
+        );
+    }
+
     #[test]
     fn using_type_signature() {
         infer_eq(
@@ -3469,7 +3513,7 @@ mod solve_expr {
                 Dict.insert
                 "#
             ),
-            "Dict k v, k, v -> Dict k v",
+            "Dict k v, k, v -> Dict k v | k has Hash & Eq",
         );
     }
 
@@ -3521,6 +3565,153 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn rotate_left_by() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.rotateLeftBy
+                "#
+            ),
+            "Int a, Int a -> Int a",
+        );
+    }
+
+    #[test]
+    fn count_leading_zero_bits() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.countLeadingZeroBits
+                "#
+            ),
+            "Int * -> U8",
+        );
+    }
+
+    #[test]
+    fn to_bytes_le() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.toBytesLe
+                "#
+            ),
+            "Int * -> List U8",
+        );
+    }
+
+    #[test]
+    fn from_bytes_be() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.fromBytesBe
+                "#
+            ),
+            "List U8, Nat -> Result (Int a) [OutOfBounds]",
+        );
+    }
+
+    #[test]
+    fn atan2() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.atan2
+                "#
+            ),
+            "Float a, Float a -> Float a",
+        );
+    }
+
+    #[test]
+    fn fma() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.fma
+                "#
+            ),
+            "Float a, Float a, Float a -> Float a",
+        );
+    }
+
+    #[test]
+    fn trunc() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.trunc
+                "#
+            ),
+            "Float * -> Int a",
+        );
+    }
+
+    #[test]
+    fn is_nan() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.isNaN
+                "#
+            ),
+            "Float * -> Bool",
+        );
+    }
+
+    #[test]
+    fn num_pi() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.pi
+                "#
+            ),
+            "Float *",
+        );
+    }
+
+    #[test]
+    fn num_e_f32() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                x : F32
+                x = Num.e
+
+                x
+                "#
+            ),
+            "F32",
+        );
+    }
+
+    #[test]
+    fn round_dec_to() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.roundDecTo
+                "#
+            ),
+            "Dec, U8, [HalfEven, HalfUp, TowardZero] -> Dec",
+        );
+    }
+
+    #[test]
+    fn from_scaled_i128_checked() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Num.fromScaledI128Checked
+                "#
+            ),
+            "I128, U8 -> Result Dec [Overflow]*",
+        );
+    }
+
     #[test]
     fn div() {
         infer_eq_without_problem(
@@ -3725,12 +3916,25 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn ranged_number_used_as_ability_bound_key() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                List.range 0 100
+                |> List.walk Dict.empty (\state, n -> Dict.insert state n (n * 2))
+                "#
+            ),
+            "Dict (Int a) (Int a)",
+        );
+    }
+
     #[test]
     fn reconstruct_path() {
         infer_eq_without_problem(
             indoc!(
                 r#"
-                reconstructPath : Dict position position, position -> List position
+                reconstructPath : Dict position position, position -> List position | position has Hash & Eq
                 reconstructPath = \cameFrom, goal ->
                     when Dict.get cameFrom goal is
                         Err KeyNotFound ->
@@ -3742,7 +3946,7 @@ mod solve_expr {
                 reconstructPath
                 "#
             ),
-            "Dict position position, position -> List position",
+            "Dict position position, position -> List position | position has Hash & Eq",
         );
     }
 
@@ -3777,7 +3981,7 @@ mod solve_expr {
 
                 Model position : { openSet : Set position }
 
-                cheapestOpen : Model position -> Result position [KeyNotFound]*
+                cheapestOpen : Model position -> Result position [KeyNotFound]* | position has Eq
                 cheapestOpen = \model ->
 
                     folder = \resSmallestSoFar, position ->
@@ -3792,14 +3996,14 @@ mod solve_expr {
                     Set.walk model.openSet (Ok { position: boom {}, cost: 0.0 }) folder
                         |> Result.map (\x -> x.position)
 
-                astar : Model position -> Result position [KeyNotFound]*
+                astar : Model position -> Result position [KeyNotFound]* | position has Eq
                 astar = \model -> cheapestOpen model
 
                 main =
                     astar
                 "#
             ),
-            "Model position -> Result position [KeyNotFound]*",
+            "Model position -> Result position [KeyNotFound]* | position has Eq",
         );
     }
 
@@ -4127,6 +4331,78 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn str_graphemes() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Str.graphemes
+                "#
+            ),
+            "Str -> List Str",
+        );
+    }
+
+    #[test]
+    fn str_split_at() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Str.splitAt
+                "#
+            ),
+            "Str, Nat -> { after : Str, before : Str }",
+        );
+    }
+
+    #[test]
+    fn str_to_nfc() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Str.toNfc
+                "#
+            ),
+            "Str -> Str",
+        );
+    }
+
+    #[test]
+    fn str_index_of() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Str.indexOf
+                "#
+            ),
+            "Str, Str -> Result Nat [NotFound]*",
+        );
+    }
+
+    #[test]
+    fn str_slice_utf8() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Str.sliceUtf8
+                "#
+            ),
+            "Str, { count : Nat, start : Nat } -> Result Str [NotCharBoundary, OutOfBounds]*",
+        );
+    }
+
+    #[test]
+    fn str_walk_utf8() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Str.walkUtf8
+                "#
+            ),
+            "Str, state, (state, U8 -> state) -> state",
+        );
+    }
+
     #[test]
     fn list_take_first() {
         infer_eq_without_problem(
@@ -5956,10 +6232,10 @@ mod solve_expr {
                 r#"
                 app "test" provides [hash] to "./platform"
 
-                Hash has hash : a -> U64 | a has Hash
+                MHash has hash : a -> U64 | a has MHash
                 "#
             ),
-            "a -> U64 | a has Hash",
+            "a -> U64 | a has MHash",
         )
     }
 
@@ -5970,14 +6246,14 @@ mod solve_expr {
                 r#"
                 app "test" provides [hash] to "./platform"
 
-                Hash has hash : a -> U64 | a has Hash
+                MHash has hash : a -> U64 | a has MHash
 
-                Id := U64 has [Hash {hash}]
+                Id := U64 has [MHash {hash}]
 
                 hash = \@Id n -> n
                 "#
             ),
-            [("Hash:hash", "Id")],
+            [("MHash:hash", "Id")],
         )
     }
 
@@ -5988,17 +6264,17 @@ mod solve_expr {
                 r#"
                 app "test" provides [hash, hash32] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
-                    hash32 : a -> U32 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
+                    hash32 : a -> U32 | a has MHash
 
-                Id := U64 has [Hash {hash, hash32}]
+                Id := U64 has [MHash {hash, hash32}]
 
                 hash = \@Id n -> n
                 hash32 = \@Id n -> Num.toU32 n
                 "#
             ),
-            [("Hash:hash", "Id"), ("Hash:hash32", "Id")],
+            [("MHash:hash", "Id"), ("MHash:hash32", "Id")],
         )
     }
 
@@ -6009,15 +6285,15 @@ mod solve_expr {
                 r#"
                 app "test" provides [hash, hash32, eq, le] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
-                    hash32 : a -> U32 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
+                    hash32 : a -> U32 | a has MHash
 
                 Ord has
                     eq : a, a -> Bool | a has Ord
                     le : a, a -> Bool | a has Ord
 
-                Id := U64 has [Hash {hash, hash32}, Ord {eq, le}]
+                Id := U64 has [MHash {hash, hash32}, Ord {eq, le}]
 
                 hash = \@Id n -> n
                 hash32 = \@Id n -> Num.toU32 n
@@ -6027,8 +6303,8 @@ mod solve_expr {
                 "#
             ),
             [
-                ("Hash:hash", "Id"),
-                ("Hash:hash32", "Id"),
+                ("MHash:hash", "Id"),
+                ("MHash:hash32", "Id"),
                 ("Ord:eq", "Id"),
                 ("Ord:le", "Id"),
             ],
@@ -6042,16 +6318,16 @@ mod solve_expr {
                 r#"
                 app "test" provides [hash] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
-                Id := U64 has [Hash {hash}]
+                Id := U64 has [MHash {hash}]
 
                 hash : Id -> U64
                 hash = \@Id n -> n
                 "#
             ),
-            [("Hash:hash", "Id")],
+            [("MHash:hash", "Id")],
         )
     }
 
@@ -6062,15 +6338,15 @@ mod solve_expr {
                 r#"
                 app "test" provides [hash] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
-                Id := U64 has [Hash {hash}]
+                Id := U64 has [MHash {hash}]
 
                 hash : Id -> U64
                 "#
             ),
-            [("Hash:hash", "Id")],
+            [("MHash:hash", "Id")],
         )
     }
 
@@ -6081,10 +6357,10 @@ mod solve_expr {
                 r#"
                 app "test" provides [zero] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
-                Id := U64 has [Hash {hash}]
+                Id := U64 has [MHash {hash}]
 
                 hash = \@Id n -> n
 
@@ -6102,15 +6378,15 @@ mod solve_expr {
                 r#"
                 app "test" provides [thething] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
                 thething =
                     itis = hash
                     itis
                 "#
             ),
-            "a -> U64 | a has Hash",
+            "a -> U64 | a has MHash",
         )
     }
 
@@ -6138,14 +6414,14 @@ mod solve_expr {
                 r#"
                 app "test" provides [hashEq] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
-                hashEq : a, a -> Bool | a has Hash
+                hashEq : a, a -> Bool | a has MHash
                 hashEq = \x, y -> hash x == hash y
                 "#
             ),
-            "a, a -> Bool | a has Hash",
+            "a, a -> Bool | a has MHash",
         )
     }
 
@@ -6156,13 +6432,13 @@ mod solve_expr {
                 r#"
                 app "test" provides [hashEq] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
                 hashEq = \x, y -> hash x == hash y
                 "#
             ),
-            "a, a1 -> Bool | a has Hash, a1 has Hash",
+            "a, a1 -> Bool | a has MHash, a1 has MHash",
         )
     }
 
@@ -6173,12 +6449,12 @@ mod solve_expr {
                 r#"
                 app "test" provides [result] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
                 hashEq = \x, y -> hash x == hash y
 
-                Id := U64 has [Hash {hash}]
+                Id := U64 has [MHash {hash}]
                 hash = \@Id n -> n
 
                 result = hashEq (@Id 100) (@Id 101)
@@ -6188,6 +6464,101 @@ mod solve_expr {
         )
     }
 
+    #[test]
+    fn ability_constrained_to_multiple_abilities_check() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [hashIfEq] to "./platform"
+
+                MHash has
+                    hash : a -> U64 | a has MHash
+
+                MEq has
+                    eq : a, a -> Bool | a has MEq
+
+                hashIfEq : a, a -> U64 | a has MHash & MEq
+                hashIfEq = \x, y -> if eq x y then hash x else 0
+                "#
+            ),
+            "a, a -> U64 | a has MHash & MEq",
+        )
+    }
+
+    #[test]
+    fn ability_constrained_to_multiple_abilities_infer() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [hashIfEq] to "./platform"
+
+                MHash has
+                    hash : a -> U64 | a has MHash
+
+                MEq has
+                    eq : a, a -> Bool | a has MEq
+
+                hashIfEq = \x, y -> if eq x y then hash x else 0
+                "#
+            ),
+            "a, a -> U64 | a has MHash & MEq",
+        )
+    }
+
+    #[test]
+    fn ability_constrained_to_multiple_abilities_usage() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [result] to "./platform"
+
+                MHash has
+                    hash : a -> U64 | a has MHash
+
+                MEq has
+                    eq : a, a -> Bool | a has MEq
+
+                hashIfEq : a, a -> U64 | a has MHash & MEq
+                hashIfEq = \x, y -> if eq x y then hash x else 0
+
+                Id := U64 has [MHash {hash}, MEq {eq}]
+                hash = \@Id n -> n
+                eq = \@Id m, @Id n -> m == n
+
+                result = hashIfEq (@Id 100) (@Id 100)
+                "#
+            ),
+            "U64",
+        )
+    }
+
+    #[test]
+    fn derive_multiple_abilities_for_open_tag_union() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                Dict.insert Dict.empty Origin 10
+                "#
+            ),
+            "Dict [Origin]a (Num *) | a has Hash & Eq",
+        )
+    }
+
+    #[test]
+    fn derive_multiple_abilities_for_closed_tag_union() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                dict : Dict [Origin, Point I64 I64] I64
+                dict = Dict.insert Dict.empty Origin 10
+
+                dict
+                "#
+            ),
+            "Dict [Origin, Point I64 I64] I64",
+        )
+    }
+
     #[test]
     fn ability_constrained_in_non_member_multiple_specializations() {
         infer_eq_without_problem(
@@ -6195,15 +6566,15 @@ mod solve_expr {
                 r#"
                 app "test" provides [result] to "./platform"
 
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
                 mulHashes = \x, y -> hash x * hash y
 
-                Id := U64 has [Hash { hash: hashId }]
+                Id := U64 has [MHash { hash: hashId }]
                 hashId = \@Id n -> n
 
-                Three := {} has [Hash { hash: hashThree }]
+                Three := {} has [MHash { hash: hashThree }]
                 hashThree = \@Three _ -> 3
 
                 result = mulHashes (@Id 100) (@Three {})
@@ -6527,7 +6898,7 @@ mod solve_expr {
                      # ^^^^^^^^^
                 "#
             ),
-            @"Encoding#toEncoder(2) : { a : Str } -[[#Derived.toEncoder_{a}(0)]]-> Encoder fmt | fmt has EncoderFormatting"
+            @"Encoding#toEncoder(2) : { a : Str } -[[#Derived.toEncoder_{a}(10)]]-> Encoder fmt | fmt has EncoderFormatting"
         )
     }
 
@@ -6547,7 +6918,7 @@ mod solve_expr {
                      # ^^^^^^^^^
                 "#
             ),
-            @"Encoding#toEncoder(2) : { a : A } -[[#Derived.toEncoder_{a}(0)]]-> Encoder fmt | fmt has EncoderFormatting"
+            @"Encoding#toEncoder(2) : { a : A } -[[#Derived.toEncoder_{a}(10)]]-> Encoder fmt | fmt has EncoderFormatting"
         )
     }
 
@@ -7823,4 +8194,111 @@ mod solve_expr {
             "Result Str [] -> Str",
         );
     }
+
+    #[test]
+    fn eq_constrains_to_eq_ability() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [eq] to "./platform"
+
+                eq = \x, y -> x == y
+                "#
+            ),
+            "a, a -> Bool | a has Eq",
+        )
+    }
+
+    #[test]
+    fn eq_dispatches_to_custom_impl() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [result] to "./platform"
+
+                Parity := U8 has [Eq { isEq: parityEq }]
+
+                parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+                result = @Parity 3 == @Parity 5
+                "#
+            ),
+            "Bool",
+        )
+    }
+
+    #[test]
+    fn eq_list_of_recursive_tag_union_payload() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [result] to "./platform"
+
+                Tree : [Node (List Tree)]
+
+                tree : Tree
+                tree = Node [Node []]
+
+                result =
+                    when tree is
+                        Node children -> children == [Node []]
+                "#
+            ),
+            "Bool",
+        )
+    }
+
+    #[test]
+    fn dbg_evaluates_to_its_argument() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                f = \x -> dbg { x, y: "hello" }
+
+                f 1
+                "#
+            ),
+            "{ x : Num *, y : Str }",
+        )
+    }
+
+    #[test]
+    fn crash_can_be_any_type() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                f = \x ->
+                    when x is
+                        Ok v -> v
+                        Err _ -> crash "not ok"
+
+                f
+                "#
+            ),
+            "[Err *, Ok a] -> a",
+        )
+    }
+
+    #[test]
+    fn record_builder_desugar() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                succeed = \f -> Box.box f
+
+                apply = \fnBox, valBox ->
+                    fn = Box.unbox fnBox
+                    val = Box.unbox valBox
+                    Box.box (fn val)
+
+                succeed {
+                    a: <- Box.box 1u8,
+                    b: "hello",
+                    c: <- Box.box Bool.true,
+                }
+                "#
+            ),
+            "Box { a : U8, b : Str, c : Bool }",
+        )
+    }
 }
@@ -1,7 +1,7 @@
 use roc_can::expected::{Expected, PExpected};
 use roc_module::{ident::Lowercase, symbol::Symbol};
 use roc_problem::can::CycleEntry;
-use roc_region::all::Region;
+use roc_region::all::{Loc, Region};
 
 use roc_types::types::{Category, ErrorType, PatternCategory};
 
@@ -31,6 +31,44 @@ pub enum TypeError {
     },
 }
 
+impl TypeError {
+    /// The region the error is reported at, if it has one.
+    pub fn region(&self) -> Option<Region> {
+        use roc_types::types::Problem;
+
+        match self {
+            TypeError::BadExpr(region, ..)
+            | TypeError::BadPattern(region, ..)
+            | TypeError::CircularType(region, ..)
+            | TypeError::BadExprMissingAbility(region, ..)
+            | TypeError::BadPatternMissingAbility(region, ..)
+            | TypeError::StructuralSpecialization { region, .. }
+            | TypeError::WrongSpecialization { region, .. } => Some(*region),
+            TypeError::CircularDef(cycle) => cycle.first().map(|entry| entry.symbol_region),
+            TypeError::BadType(problem) => match problem {
+                Problem::CircularType(_, _, region)
+                | Problem::CyclicAlias(_, region, _)
+                | Problem::Shadowed(_, Loc { region, .. })
+                | Problem::BadTypeArguments { region, .. }
+                | Problem::HasClauseIsNotAbility(region) => Some(*region),
+                Problem::CanonicalizationProblem
+                | Problem::UnrecognizedIdent(_)
+                | Problem::InvalidModule
+                | Problem::SolvedTypeError => None,
+            },
+            TypeError::UnfulfilledAbility(Unfulfilled::OpaqueUnderivable {
+                derive_region, ..
+            }) => Some(*derive_region),
+            TypeError::UnfulfilledAbility(_) | TypeError::UnexposedLookup(_) => None,
+            TypeError::Exhaustive(error) => match error {
+                roc_exhaustive::Error::Incomplete(region, ..) => Some(*region),
+                roc_exhaustive::Error::Redundant { branch_region, .. }
+                | roc_exhaustive::Error::Unmatchable { branch_region, .. } => Some(*branch_region),
+            },
+        }
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub enum Unfulfilled {
     /// No claimed implementation of an ability for an opaque type.
@@ -0,0 +1,297 @@
+#![cfg(test)]
+// Even with #[allow(non_snake_case)] on individual idents, rust-analyzer issues diagnostics.
+// See https://github.com/rust-lang/rust-analyzer/issues/6541.
+// For the `v!` macro we use uppercase variables when constructing tag unions.
+#![allow(non_snake_case)]
+
+use insta::assert_snapshot;
+
+use crate::{
+    test_key_eq, test_key_neq,
+    util::{check_derivable, check_underivable, derive_test},
+    v,
+};
+use roc_derive_key::{
+    arbitrary::FlatArbitraryKey, DeriveBuiltin::Arbitrary, DeriveError, DeriveKey,
+};
+use roc_module::symbol::Symbol;
+use roc_types::subs::{Subs, Variable};
+
+// {{{ arbitrary tests
+
+test_key_eq! {
+    Arbitrary,
+
+    same_record:
+        v!({ a: v!(U8), }), v!({ a: v!(U8), })
+    same_record_fields_diff_types:
+        v!({ a: v!(U8), }), v!({ a: v!(STR), })
+    same_record_fields_any_order:
+        v!({ a: v!(U8), b: v!(U8), c: v!(U8), }),
+        v!({ c: v!(U8), a: v!(U8), b: v!(U8), })
+    explicit_empty_record_and_implicit_empty_record:
+        v!(EMPTY_RECORD), v!({})
+
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+    explicit_empty_tag_union_and_implicit_empty_tag_union:
+        v!(EMPTY_TAG_UNION), v!([])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+
+    list_list_diff_types:
+        v!(Symbol::LIST_LIST v!(STR)), v!(Symbol::LIST_LIST v!(U8))
+    str_str:
+        v!(Symbol::STR_STR), v!(Symbol::STR_STR)
+
+    alias_eq_real_type:
+        v!(Symbol::UNDERSCORE => v!([ True, False ])), v!([False, True])
+}
+
+test_key_neq! {
+    Arbitrary,
+
+    different_record_fields:
+        v!({ a: v!(U8), }), v!({ b: v!(U8), })
+    record_empty_vs_nonempty:
+        v!(EMPTY_RECORD), v!({ a: v!(U8), })
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    tag_union_empty_vs_nonempty:
+        v!(EMPTY_TAG_UNION), v!([ B v!(U8) ])
+    different_recursive_tag_union_tags:
+        v!([ Nil, Cons v!(^lst) ] as lst), v!([ Nil, Next v!(^lst) ] as lst)
+
+    list_vs_str:
+        v!(Symbol::LIST_LIST v!(U8)), v!(Symbol::STR_STR)
+    different_numbers:
+        v!(U8), v!(I8)
+}
+
+// }}} arbitrary tests
+
+// {{{ deriver tests
+
+fn check_builtin<S>(synth: S, generator: Symbol)
+where
+    S: FnOnce(&mut Subs) -> Variable,
+{
+    check_derivable(
+        Arbitrary,
+        synth,
+        DeriveKey::Arbitrary(FlatArbitraryKey::Builtin(generator)),
+    )
+}
+
+#[test]
+fn builtins() {
+    check_builtin(v!(U8), Symbol::GEN_U8);
+    check_builtin(v!(I64), Symbol::GEN_I64);
+    check_builtin(v!(NAT), Symbol::GEN_NAT);
+    check_builtin(v!(DEC), Symbol::GEN_DEC);
+    check_builtin(v!(F64), Symbol::GEN_F64);
+    check_builtin(v!(BOOL), Symbol::GEN_BOOL);
+    check_builtin(v!(STR), Symbol::GEN_STR);
+}
+
+#[test]
+fn optional_record_field_underivable() {
+    check_underivable(Arbitrary, v!({ ?a: v!(U8), }), DeriveError::Underivable);
+}
+
+#[test]
+fn flex_var_unbound() {
+    check_underivable(Arbitrary, v!(*), DeriveError::UnboundVar);
+}
+
+#[test]
+fn derivable_record_ext_flex_var() {
+    check_derivable(
+        Arbitrary,
+        v!({ a: v!(STR), }* ),
+        DeriveKey::Arbitrary(FlatArbitraryKey::Record(vec!["a".into()])),
+    );
+}
+
+#[test]
+fn derivable_tag_with_tag_ext() {
+    check_derivable(
+        Arbitrary,
+        v!([ B v!(STR) v!(U8) ][ A v!(STR) ]),
+        DeriveKey::Arbitrary(FlatArbitraryKey::TagUnion(vec![
+            ("A".into(), 1),
+            ("B".into(), 2),
+        ])),
+    );
+}
+
+#[test]
+fn u8() {
+    derive_test(Arbitrary, v!(U8), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for U8
+        # Gen U8
+        # { budget : Nat, drawn : List U64, replay : List U64, state : U64 } -[[custom(2)]]-> { choices : { budget : Nat, drawn : List U64, replay : List U64, state : U64 }, value : U8 }
+        # Specialization lambda sets:
+        #   @<1>: [[custom(2)]]
+        #Derived.arbitrary_u8 =
+          Gen.custom \#Derived.choices -> Gen.generateWith #Derived.choices Gen.u8
+        "###
+        )
+    })
+}
+
+#[test]
+fn list() {
+    derive_test(Arbitrary, v!(Symbol::LIST_LIST v!(STR)), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for List Str
+        # Gen (List a) | a has Arbitrary
+        # { budget : Nat, drawn : List U64, replay : List U64, state : U64 } -[[custom(2)]]-> { choices : { budget : Nat, drawn : List U64, replay : List U64, state : U64 }, value : List a } | a has Arbitrary
+        # Specialization lambda sets:
+        #   @<1>: [[custom(2)]]
+        #Derived.arbitrary_list =
+          Gen.custom
+            \#Derived.choices ->
+              Gen.generateWith #Derived.choices (Gen.list Gen.arbitrary)
+        "###
+        )
+    })
+}
+
+#[test]
+fn empty_record() {
+    derive_test(Arbitrary, v!(EMPTY_RECORD), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for {}
+        # Gen {}
+        # { budget : Nat, drawn : List U64, replay : List U64, state : U64 } -[[custom(2)]]-> { choices : { budget : Nat, drawn : List U64, replay : List U64, state : U64 }, value : {} }
+        # Specialization lambda sets:
+        #   @<1>: [[custom(2)]]
+        #Derived.arbitrary_{} =
+          Gen.custom \#Derived.choices -> { value: {}, choices: #Derived.choices }
+        "###
+        )
+    })
+}
+
+#[test]
+fn two_field_record() {
+    derive_test(Arbitrary, v!({ a: v!(U8), b: v!(STR), }), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for { a : U8, b : Str }
+        # Gen { a : a, b : a1 } | a has Arbitrary, a1 has Arbitrary
+        # { budget : Nat, drawn : List U64, replay : List U64, state : U64 } -[[custom(4)]]-> { choices : { budget : Nat, drawn : List U64, replay : List U64, state : U64 }, value : { a : a, b : a1 } } | a has Arbitrary, a1 has Arbitrary
+        # Specialization lambda sets:
+        #   @<1>: [[custom(4)]]
+        #Derived.arbitrary_{a,b} =
+          Gen.custom
+            \#Derived.choices ->
+              when Gen.generateWith #Derived.choices Gen.arbitrary is
+                #Derived.generated ->
+                  when Gen.generateWith #Derived.generated.choices Gen.arbitrary is
+                    #Derived.generated2 ->
+                      {
+                        value: {
+                            b: #Derived.generated2.value,
+                            a: #Derived.generated.value
+                          },
+                        choices: #Derived.generated2.choices
+                      }
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_one_label_newtype() {
+    derive_test(Arbitrary, v!([A v!(U8) v!(STR)]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A U8 Str]
+        # Gen [A a a1] | a has Arbitrary, a1 has Arbitrary
+        # { budget : Nat, drawn : List U64, replay : List U64, state : U64 } -[[custom(4)]]-> { choices : { budget : Nat, drawn : List U64, replay : List U64, state : U64 }, value : [A a a1] } | a has Arbitrary, a1 has Arbitrary
+        # Specialization lambda sets:
+        #   @<1>: [[custom(4)]]
+        #Derived.arbitrary_[A 2] =
+          Gen.custom
+            \#Derived.choices ->
+              when Gen.generateWith #Derived.choices Gen.arbitrary is
+                #Derived.generated ->
+                  when Gen.generateWith #Derived.generated.choices Gen.arbitrary is
+                    #Derived.generated2 ->
+                      {
+                        value: A #Derived.generated.value #Derived.generated2.value,
+                        choices: #Derived.generated2.choices
+                      }
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_two_labels() {
+    derive_test(Arbitrary, v!([A v!(U8) v!(STR), B, C v!(STR)]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A U8 Str, B, C Str]
+        # Gen [A a a1, B, C a2] | a has Arbitrary, a1 has Arbitrary, a2 has Arbitrary
+        # { budget : Nat, drawn : List U64, replay : List U64, state : U64 } -[[custom(6)]]-> { choices : { budget : Nat, drawn : List U64, replay : List U64, state : U64 }, value : [A a a1, B, C a2] } | a has Arbitrary, a1 has Arbitrary, a2 has Arbitrary
+        # Specialization lambda sets:
+        #   @<1>: [[custom(6)]]
+        #Derived.arbitrary_[A 2,B 0,C 1] =
+          Gen.custom
+            \#Derived.choices ->
+              when Gen.generateWith #Derived.choices (Gen.natBelow 3) is
+                #Derived.tag ->
+                  when #Derived.tag.value is
+                    0 -> { value: B, choices: #Derived.tag.choices }
+                    1 ->
+                      when Gen.generateWith #Derived.tag.choices Gen.arbitrary is
+                        #Derived.generated ->
+                          {
+                            value: C #Derived.generated.value,
+                            choices: #Derived.generated.choices
+                          }
+                    _ ->
+                      when Gen.generateWith #Derived.tag.choices Gen.arbitrary is
+                        #Derived.generated2 ->
+                          when Gen.generateWith
+                              #Derived.generated2.choices
+                              Gen.arbitrary is
+                            #Derived.generated3 ->
+                              {
+                                value:
+                                  A #Derived.generated2.value #Derived.generated3.value,
+                                choices: #Derived.generated3.choices
+                              }
+        "###
+        )
+    })
+}
+
+#[test]
+fn empty_tag_union() {
+    derive_test(Arbitrary, v!(EMPTY_TAG_UNION), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for []
+        # Gen []
+        # { budget : Nat, drawn : List U64, replay : List U64, state : U64 } -[[custom(2)]]-> { choices : { budget : Nat, drawn : List U64, replay : List U64, state : U64 }, value : [] }
+        # Specialization lambda sets:
+        #   @<1>: [[custom(2)]]
+        #Derived.arbitrary_[] =
+          Gen.custom
+            \#Derived.choices ->
+              crash "There are no values of an empty tag union to generate"
+        "###
+        )
+    })
+}
+
+// }}} deriver tests
@@ -28,6 +28,20 @@ test_key_eq! {
     explicit_empty_record_and_implicit_empty_record:
         v!(EMPTY_RECORD), v!({})
 
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+    explicit_empty_tag_union_and_implicit_empty_tag_union:
+        v!(EMPTY_TAG_UNION), v!([])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+
     list_list_diff_types:
         v!(Symbol::LIST_LIST v!(STR)), v!(Symbol::LIST_LIST v!(U8))
     str_str:
@@ -41,6 +55,13 @@ test_key_neq! {
         v!({ a: v!(U8), }), v!({ b: v!(U8), })
     record_empty_vs_nonempty:
         v!(EMPTY_RECORD), v!({ a: v!(U8), })
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    tag_union_empty_vs_nonempty:
+        v!(EMPTY_TAG_UNION), v!([ B v!(U8) ])
+    different_tag_union_payload_sizes:
+        v!([ A v!(U8) ]), v!([ A v!(U8) v!(U8) ])
 }
 
 #[test]
@@ -93,6 +114,27 @@ fn derivable_record_with_record_ext() {
     );
 }
 
+#[test]
+fn derivable_tag_ext_flex_var() {
+    check_derivable(
+        Decoder,
+        v!([ A v!(STR) ]* ),
+        DeriveKey::Decoder(FlatDecodableKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn derivable_tag_with_tag_ext() {
+    check_derivable(
+        Decoder,
+        v!([ B v!(STR) v!(U8) ][ A v!(STR) ]),
+        DeriveKey::Decoder(FlatDecodableKey::TagUnion(vec![
+            ("A".into(), 1),
+            ("B".into(), 2),
+        ])),
+    );
+}
+
 #[test]
 fn list() {
     derive_test(Decoder, v!(Symbol::LIST_LIST v!(STR)), |golden| {
@@ -173,3 +215,119 @@ fn record_2_fields() {
         )
     })
 }
+
+#[test]
+fn tag_one_label_zero_args() {
+    derive_test(Decoder, v!([A]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A]
+        # Decoder [A] fmt | fmt has DecoderFormatting
+        # List U8, fmt -[[custom(10)]]-> { rest : List U8, result : [Err [TooShort], Ok [A]] } | fmt has DecoderFormatting
+        # Specialization lambda sets:
+        #   @<1>: [[custom(10)]]
+        #Derived.decoder_[A 0] =
+          Decode.custom
+            \#Derived.bytes, #Derived.fmt ->
+              Decode.decodeWith
+                #Derived.bytes
+                (Decode.tag
+                  { }
+                  \#Derived.stateRecord2, #Derived.tag2, #Derived.index ->
+                    when #Derived.tag2 is _ -> TooLong
+                  \#Derived.stateRecord, #Derived.tag ->
+                    when #Derived.tag is "A" -> Ok A _ -> Err TooShort)
+                #Derived.fmt
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_two_labels() {
+    derive_test(Decoder, v!([A v!(U8) v!(STR), B v!(STR)]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A U8 Str, B Str]
+        # Decoder [A val val1, B val1] fmt | fmt has DecoderFormatting, val has Decoding, val1 has Decoding
+        # List U8, fmt -[[custom(31)]]-> { rest : List U8, result : [Err [TooShort], Ok [A val val1, B val1]] } | fmt has DecoderFormatting, val has Decoding, val1 has Decoding
+        # Specialization lambda sets:
+        #   @<1>: [[custom(31)]]
+        #Derived.decoder_[A 2,B 1] =
+          Decode.custom
+            \#Derived.bytes4, #Derived.fmt4 ->
+              Decode.decodeWith
+                #Derived.bytes4
+                (Decode.tag
+                  { b_0: Err NoField, a_0: Err NoField, a_1: Err NoField }
+                  \#Derived.stateRecord2, #Derived.tag2, #Derived.index ->
+                    when #Derived.tag2 is
+                      "A" ->
+                        when #Derived.index is
+                          0 ->
+                            Next (Decode.custom
+                              \#Derived.bytes, #Derived.fmt ->
+                                when Decode.decodeWith
+                                    #Derived.bytes
+                                    Decode.decoder
+                                    #Derived.fmt is
+                                  #Derived.rec ->
+                                    {
+                                      result: when #Derived.rec.result is
+                                          Ok #Derived.val ->
+                                            Ok { stateRecord2 & a_0: Ok #Derived.val }
+                                          Err #Derived.err -> Err #Derived.err,
+                                      rest: #Derived.rec.rest
+                                    })
+                          1 ->
+                            Next (Decode.custom
+                              \#Derived.bytes2, #Derived.fmt2 ->
+                                when Decode.decodeWith
+                                    #Derived.bytes2
+                                    Decode.decoder
+                                    #Derived.fmt2 is
+                                  #Derived.rec2 ->
+                                    {
+                                      result: when #Derived.rec2.result is
+                                          Ok #Derived.val2 ->
+                                            Ok { stateRecord2 & a_1: Ok #Derived.val2 }
+                                          Err #Derived.err2 -> Err #Derived.err2,
+                                      rest: #Derived.rec2.rest
+                                    })
+                          _ -> TooLong
+                      "B" ->
+                        when #Derived.index is
+                          0 ->
+                            Next (Decode.custom
+                              \#Derived.bytes3, #Derived.fmt3 ->
+                                when Decode.decodeWith
+                                    #Derived.bytes3
+                                    Decode.decoder
+                                    #Derived.fmt3 is
+                                  #Derived.rec3 ->
+                                    {
+                                      result: when #Derived.rec3.result is
+                                          Ok #Derived.val3 ->
+                                            Ok { stateRecord2 & b_0: Ok #Derived.val3 }
+                                          Err #Derived.err3 -> Err #Derived.err3,
+                                      rest: #Derived.rec3.rest
+                                    })
+                          _ -> TooLong
+                      _ -> TooLong
+                  \#Derived.stateRecord, #Derived.tag ->
+                    when #Derived.tag is
+                      "A" ->
+                        when #Derived.stateRecord.a_0 is
+                          Ok #Derived.a_0 ->
+                            when #Derived.stateRecord.a_1 is
+                              Ok #Derived.a_1 -> Ok (A #Derived.a_0 #Derived.a_1)
+                              _ -> Err TooShort
+                          _ -> Err TooShort
+                      "B" ->
+                        when #Derived.stateRecord.b_0 is
+                          Ok #Derived.b_0 -> Ok (B #Derived.b_0)
+                          _ -> Err TooShort
+                      _ -> Err TooShort)
+                #Derived.fmt4
+        "###
+        )
+    })
+}
@@ -0,0 +1,337 @@
+#![cfg(test)]
+// Even with #[allow(non_snake_case)] on individual idents, rust-analyzer issues diagnostics.
+// See https://github.com/rust-lang/rust-analyzer/issues/6541.
+// For the `v!` macro we use uppercase variables when constructing tag unions.
+#![allow(non_snake_case)]
+
+use insta::assert_snapshot;
+
+use crate::{
+    test_key_eq, test_key_neq,
+    util::{check_derivable, check_single_lset_immediate, check_underivable, derive_test},
+    v,
+};
+use roc_derive_key::{eq::FlatEqKey, DeriveBuiltin::IsEq, DeriveError, DeriveKey};
+use roc_module::symbol::Symbol;
+use roc_types::subs::Variable;
+
+// {{{ eq tests
+
+test_key_eq! {
+    IsEq,
+
+    same_record:
+        v!({ a: v!(U8), }), v!({ a: v!(U8), })
+    same_record_fields_diff_types:
+        v!({ a: v!(U8), }), v!({ a: v!(STR), })
+    same_record_fields_any_order:
+        v!({ a: v!(U8), b: v!(U8), c: v!(U8), }),
+        v!({ c: v!(U8), a: v!(U8), b: v!(U8), })
+    explicit_empty_record_and_implicit_empty_record:
+        v!(EMPTY_RECORD), v!({})
+
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+    explicit_empty_tag_union_and_implicit_empty_tag_union:
+        v!(EMPTY_TAG_UNION), v!([])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+
+    list_list_diff_types:
+        v!(Symbol::LIST_LIST v!(STR)), v!(Symbol::LIST_LIST v!(U8))
+    str_str:
+        v!(Symbol::STR_STR), v!(Symbol::STR_STR)
+
+    alias_eq_real_type:
+        v!(Symbol::UNDERSCORE => v!([ True, False ])), v!([False, True])
+    diff_alias_same_real_type:
+        v!(Symbol::UNDERSCORE => v!([ True, False ])), v!(Symbol::UNDERSCORE => v!([False, True]))
+
+    opaque_eq_real_type:
+        v!(@Symbol::UNDERSCORE => v!([ True, False ])), v!([False, True])
+}
+
+test_key_neq! {
+    IsEq,
+
+    different_record_fields:
+        v!({ a: v!(U8), }), v!({ b: v!(U8), })
+    record_empty_vs_nonempty:
+        v!(EMPTY_RECORD), v!({ a: v!(U8), })
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    tag_union_empty_vs_nonempty:
+        v!(EMPTY_TAG_UNION), v!([ B v!(U8) ])
+    different_recursive_tag_union_tags:
+        v!([ Nil, Cons v!(^lst) ] as lst), v!([ Nil, Next v!(^lst) ] as lst)
+
+    list_of_records_vs_str:
+        v!(Symbol::LIST_LIST v!({ a: v!(U8), })), v!(Symbol::STR_STR)
+}
+
+// }}} eq tests
+
+// {{{ deriver tests
+
+#[test]
+fn immediates() {
+    // Numbers, strings, and lists of them are compared structurally.
+    check_single_lset_immediate(IsEq, v!(U8), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(U16), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(U32), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(U64), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(U128), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(I8), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(I16), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(I32), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(I64), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(I128), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(DEC), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(F32), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(F64), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(IsEq, v!(STR), Symbol::BOOL_STRUCTURAL_EQ);
+    check_single_lset_immediate(
+        IsEq,
+        v!(Symbol::LIST_LIST v!(U8)),
+        Symbol::BOOL_STRUCTURAL_EQ,
+    );
+    check_single_lset_immediate(
+        IsEq,
+        v!(Symbol::LIST_LIST v!(STR)),
+        Symbol::BOOL_STRUCTURAL_EQ,
+    );
+}
+
+#[test]
+fn list_of_structures_compares_elements() {
+    check_single_lset_immediate(
+        IsEq,
+        v!(Symbol::LIST_LIST v!({ a: v!(U8), })),
+        Symbol::LIST_IS_EQ,
+    );
+    check_single_lset_immediate(
+        IsEq,
+        v!(Symbol::LIST_LIST v!([ A v!(U8), B ])),
+        Symbol::LIST_IS_EQ,
+    );
+    check_single_lset_immediate(
+        IsEq,
+        v!(Symbol::LIST_LIST v!(Symbol::LIST_LIST v!(U8))),
+        Symbol::LIST_IS_EQ,
+    );
+}
+
+#[test]
+fn unbound_var_underivable() {
+    check_underivable(IsEq, v!(*), DeriveError::UnboundVar);
+}
+
+#[test]
+fn derivable_record_ext_flex_var() {
+    check_derivable(
+        IsEq,
+        v!({ a: v!(STR), }* ),
+        DeriveKey::IsEq(FlatEqKey::Record(vec!["a".into()])),
+    );
+}
+
+#[test]
+fn derivable_record_with_record_ext() {
+    check_derivable(
+        IsEq,
+        v!({ b: v!(STR), }{ a: v!(STR), } ),
+        DeriveKey::IsEq(FlatEqKey::Record(vec!["a".into(), "b".into()])),
+    );
+}
+
+#[test]
+fn derivable_tag_ext_flex_var() {
+    check_derivable(
+        IsEq,
+        v!([ A v!(STR) ]* ),
+        DeriveKey::IsEq(FlatEqKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn derivable_tag_with_tag_ext() {
+    check_derivable(
+        IsEq,
+        v!([ B v!(STR) v!(U8) ][ A v!(STR) ]),
+        DeriveKey::IsEq(FlatEqKey::TagUnion(vec![("A".into(), 1), ("B".into(), 2)])),
+    );
+}
+
+#[test]
+fn empty_record() {
+    derive_test(IsEq, v!(EMPTY_RECORD), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for {}
+        # {}, {} -[[isEq_{}(0)]]-> Bool
+        # {}, {} -[[isEq_{}(0)]]-> [False, True]
+        # Specialization lambda sets:
+        #   @<1>: [[isEq_{}(0)]]
+        #Derived.isEq_{} = \#Derived.rcd1, #Derived.rcd2 -> Bool.true
+        "###
+        )
+    })
+}
+
+#[test]
+fn one_field_record() {
+    derive_test(IsEq, v!({ a: v!(U8), }), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for { a : U8 }
+        # { a : a }, { a : a } -[[isEq_{a}(0)]]-> Bool | a has Eq
+        # { a : a }, { a : a } -[[isEq_{a}(0)]]-> [False, True] | a has Eq
+        # Specialization lambda sets:
+        #   @<1>: [[isEq_{a}(0)]]
+        #Derived.isEq_{a} =
+          \#Derived.rcd1, #Derived.rcd2 -> Bool.isEq #Derived.rcd1.a #Derived.rcd2.a
+        "###
+        )
+    })
+}
+
+#[test]
+fn two_field_record() {
+    derive_test(IsEq, v!({ a: v!(U8), b: v!(STR), }), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for { a : U8, b : Str }
+        # { a : a, b : a1 }, { a : a, b : a1 } -[[isEq_{a,b}(0)]]-> Bool | a has Eq, a1 has Eq
+        # { a : a, b : a1 }, { a : a, b : a1 } -[[isEq_{a,b}(0)]]-> [False, True] | a has Eq, a1 has Eq
+        # Specialization lambda sets:
+        #   @<1>: [[isEq_{a,b}(0)]]
+        #Derived.isEq_{a,b} =
+          \#Derived.rcd1, #Derived.rcd2 ->
+            if Bool.isEq #Derived.rcd1.a #Derived.rcd2.a
+            then Bool.isEq #Derived.rcd1.b #Derived.rcd2.b
+            else Bool.false
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_one_label_no_payloads() {
+    derive_test(IsEq, v!([A]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A]
+        # [A], [A] -[[isEq_[A 0](0)]]-> Bool
+        # [A], [A] -[[isEq_[A 0](0)]]-> [False, True]
+        # Specialization lambda sets:
+        #   @<1>: [[isEq_[A 0](0)]]
+        #Derived.isEq_[A 0] =
+          \#Derived.union1, #Derived.union2 ->
+            when #Derived.union1 is A -> when #Derived.union2 is A -> Bool.true
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_one_label_newtype() {
+    derive_test(IsEq, v!([A v!(U8) v!(STR)]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A U8 Str]
+        # [A a a1], [A a a1] -[[isEq_[A 2](0)]]-> Bool | a has Eq, a1 has Eq
+        # [A a a1], [A a a1] -[[isEq_[A 2](0)]]-> [False, True] | a has Eq, a1 has Eq
+        # Specialization lambda sets:
+        #   @<1>: [[isEq_[A 2](0)]]
+        #Derived.isEq_[A 2] =
+          \#Derived.union1, #Derived.union2 ->
+            when #Derived.union1 is
+              A #Derived.3 #Derived.4 ->
+                when #Derived.union2 is
+                  A #Derived.5 #Derived.6 ->
+                    if Bool.isEq #Derived.3 #Derived.5
+                    then Bool.isEq #Derived.4 #Derived.6
+                    else Bool.false
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_two_labels() {
+    derive_test(IsEq, v!([A v!(U8) v!(STR) v!(U16), B v!(STR)]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A U8 Str U16, B Str]
+        # [A a a1 a2, B a3]b, [A a a1 a2, B a3]b -[[isEq_[A 3,B 1](0)]]-> Bool | a has Eq, a1 has Eq, a2 has Eq, a3 has Eq
+        # [A a a1 a2, B a3]b, [A a a1 a2, B a3]b -[[isEq_[A 3,B 1](0)]]-> [False, True] | a has Eq, a1 has Eq, a2 has Eq, a3 has Eq
+        # Specialization lambda sets:
+        #   @<1>: [[isEq_[A 3,B 1](0)]]
+        #Derived.isEq_[A 3,B 1] =
+          \#Derived.union1, #Derived.union2 ->
+            when #Derived.union1 is
+              A #Derived.3 #Derived.4 #Derived.5 ->
+                when #Derived.union2 is
+                  A #Derived.6 #Derived.7 #Derived.8 ->
+                    if Bool.isEq #Derived.3 #Derived.6
+                    then if Bool.isEq #Derived.4 #Derived.7
+                      then Bool.isEq #Derived.5 #Derived.8
+                      else Bool.false
+                    else Bool.false
+                  _ -> Bool.false
+              B #Derived.9 ->
+                when #Derived.union2 is
+                  B #Derived.10 -> Bool.isEq #Derived.9 #Derived.10
+                  _ -> Bool.false
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_two_labels_no_payloads() {
+    derive_test(IsEq, v!([A, B]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A, B]
+        # [A, B]a, [A, B]a -[[isEq_[A 0,B 0](0)]]-> Bool
+        # [A, B]a, [A, B]a -[[isEq_[A 0,B 0](0)]]-> [False, True]
+        # Specialization lambda sets:
+        #   @<1>: [[isEq_[A 0,B 0](0)]]
+        #Derived.isEq_[A 0,B 0] =
+          \#Derived.union1, #Derived.union2 ->
+            when #Derived.union1 is
+              A -> when #Derived.union2 is A -> Bool.true _ -> Bool.false
+              B -> when #Derived.union2 is B -> Bool.true _ -> Bool.false
+        "###
+        )
+    })
+}
+
+#[test]
+fn recursive_tag_union() {
+    derive_test(IsEq, v!([Nil, Cons v!(U8) v!(^lst) ] as lst), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [Cons U8 $rec, Nil] as $rec
+        # [Cons a a1, Nil]b, [Cons a a1, Nil]b -[[isEq_[Cons 2,Nil 0](0)]]-> Bool | a has Eq, a1 has Eq
+        # [Cons a a1, Nil]b, [Cons a a1, Nil]b -[[isEq_[Cons 2,Nil 0](0)]]-> [False, True] | a has Eq, a1 has Eq
+        # Specialization lambda sets:
+        #   @<1>: [[isEq_[Cons 2,Nil 0](0)]]
+        #Derived.isEq_[Cons 2,Nil 0] =
+          \#Derived.union1, #Derived.union2 ->
+            when #Derived.union1 is
+              Cons #Derived.3 #Derived.4 ->
+                when #Derived.union2 is
+                  Cons #Derived.5 #Derived.6 ->
+                    if Bool.isEq #Derived.3 #Derived.5
+                    then Bool.isEq #Derived.4 #Derived.6
+                    else Bool.false
+                  _ -> Bool.false
+              Nil -> when #Derived.union2 is Nil -> Bool.true _ -> Bool.false
+        "###
+        )
+    })
+}
+
+// }}} deriver tests
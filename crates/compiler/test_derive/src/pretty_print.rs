@@ -202,6 +202,26 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
         } => expr(c, AppArg, f, &loc_expr.value)
             .append(f.text(format!(".{}", field.as_str())))
             .group(),
+        Tuple { elems, .. } => f
+            .reflow("(")
+            .append(
+                f.intersperse(
+                    elems
+                        .iter()
+                        .map(|(_, elem)| f.line().append(expr(c, Free, f, &elem.value))),
+                    f.reflow(","),
+                )
+                .nest(2)
+                .group(),
+            )
+            .append(f.line())
+            .append(f.text(")"))
+            .group(),
+        TupleAccess {
+            loc_expr, index, ..
+        } => expr(c, AppArg, f, &loc_expr.value)
+            .append(f.text(format!(".{}", index)))
+            .group(),
         OpaqueWrapFunction(OpaqueWrapFunctionData { opaque_name, .. }) => {
             f.text(format!("@{}", opaque_name.as_str(c.interns)))
         }
@@ -258,7 +278,28 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
         OpaqueRef { .. } => todo!(),
         Expect { .. } => todo!(),
         ExpectFx { .. } => todo!(),
+        Dbg {
+            loc_value,
+            variable: _,
+        } => maybe_paren!(
+            Free,
+            p,
+            f.text("dbg")
+                .append(f.line())
+                .append(expr(c, AppArg, f, &loc_value.value))
+                .group()
+                .nest(2)
+        ),
         TypedHole(_) => todo!(),
+        Crash { msg, ret_var: _ } => maybe_paren!(
+            Free,
+            p,
+            f.text("crash")
+                .append(f.line())
+                .append(expr(c, AppArg, f, &msg.value))
+                .group()
+                .nest(2)
+        ),
         RuntimeError(_) => todo!(),
     }
 }
@@ -362,6 +403,18 @@ fn pattern<'a>(
             )
             .append(f.text("}"))
             .group(),
+        TupleDestructure { destructs, .. } => f
+            .text("(")
+            .append(
+                f.intersperse(
+                    destructs
+                        .iter()
+                        .map(|l| pattern(c, Free, f, &l.value.typ.1.value)),
+                    f.text(", "),
+                ),
+            )
+            .append(f.text(")"))
+            .group(),
         NumLiteral(_, n, _, _) | IntLiteral(_, _, n, _, _) | FloatLiteral(_, _, n, _, _) => {
             f.text(&**n)
         }
@@ -1,7 +1,10 @@
 #![cfg(test)]
 
+mod arbitrary;
 mod decoding;
 mod encoding;
+mod eq;
+mod hash;
 
 mod pretty_print;
 mod util;
@@ -50,6 +50,21 @@ fn module_source_and_path(builtin: DeriveBuiltin) -> (ModuleId, &'static str, Pa
             module_source(ModuleId::DECODE),
             builtins_path.join("Decode.roc"),
         ),
+        DeriveBuiltin::Hash => (
+            ModuleId::HASH,
+            module_source(ModuleId::HASH),
+            builtins_path.join("Hash.roc"),
+        ),
+        DeriveBuiltin::IsEq => (
+            ModuleId::BOOL,
+            module_source(ModuleId::BOOL),
+            builtins_path.join("Bool.roc"),
+        ),
+        DeriveBuiltin::Arbitrary => (
+            ModuleId::GEN,
+            module_source(ModuleId::GEN),
+            builtins_path.join("Gen.roc"),
+        ),
     }
 }
 
@@ -154,12 +169,13 @@ macro_rules! v {
          |subs: &mut Subs| { roc_derive::synth_var(subs, Content::FlexVar(None)) }
      }};
      ($name:ident has $ability:path) => {{
-         use roc_types::subs::{Subs, SubsIndex,  Content};
+         use roc_types::subs::{Subs, SubsIndex, SubsSlice, Content};
          |subs: &mut Subs| {
              let name_index =
                  SubsIndex::push_new(&mut subs.field_names, stringify!($name).into());
+             let abilities = SubsSlice::extend_new(&mut subs.symbol_names, [$ability]);
 
-             roc_derive::synth_var(subs, Content::FlexAbleVar(Some(name_index), $ability))
+             roc_derive::synth_var(subs, Content::FlexAbleVar(Some(name_index), abilities))
          }
      }};
      (^$rec_var:ident) => {{
@@ -247,6 +263,18 @@ where
     assert_eq!(key, Ok(Derived::Immediate(immediate)));
 }
 
+pub(crate) fn check_single_lset_immediate<S>(builtin: DeriveBuiltin, synth: S, immediate: Symbol)
+where
+    S: FnOnce(&mut Subs) -> Variable,
+{
+    let mut subs = Subs::new();
+    let var = synth(&mut subs);
+
+    let key = Derived::builtin(builtin, &subs, var);
+
+    assert_eq!(key, Ok(Derived::SingleLambdaSetImmediate(immediate)));
+}
+
 #[allow(clippy::too_many_arguments)]
 fn assemble_derived_golden(
     subs: &mut Subs,
@@ -325,11 +353,19 @@ fn check_derived_typechecks_and_golden(
     // the derived implementation on stuff from the builtin module, so
     //   - we need to add those dependencies as imported on the constraint
     //   - we need to add the builtin ability info to a local abilities store
+    //   - the builtin module's own ability implementations (like `Bool.boolIsEq`) are already
+    //     resolved, so they must not be imported as values to be checked again
     let values_to_import_from_builtin_module = derive_builtin_env
         .exposed_types
         .stored_vars_by_symbol
         .keys()
         .copied()
+        .filter(|symbol| {
+            derive_builtin_env
+                .abilities_store
+                .specialization_info(*symbol)
+                .is_none()
+        })
         .collect::<VecSet<_>>();
     let pending_abilities = derive_builtin_env
         .abilities_store
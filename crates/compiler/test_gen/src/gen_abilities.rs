@@ -10,6 +10,8 @@ use indoc::indoc;
 #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
 use roc_std::RocList;
 #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+use roc_std::RocResult;
+#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
 use roc_std::RocStr;
 
 #[test]
@@ -20,10 +22,10 @@ fn hash_specialization() {
             r#"
             app "test" provides [main] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            Id := U64 has [Hash {hash}]
+            Id := U64 has [MHash {hash}]
 
             hash = \@Id n -> n
 
@@ -43,14 +45,14 @@ fn hash_specialization_multiple_add() {
             r#"
             app "test" provides [main] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            Id := U64 has [ Hash {hash: hashId} ]
+            Id := U64 has [ MHash {hash: hashId} ]
 
             hashId = \@Id n -> n
 
-            One := {} has [ Hash {hash: hashOne} ]
+            One := {} has [ MHash {hash: hashOne} ]
 
             hashOne = \@One _ -> 1
 
@@ -70,10 +72,10 @@ fn alias_member_specialization() {
             r#"
             app "test" provides [main] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            Id := U64 has [Hash {hash}]
+            Id := U64 has [MHash {hash}]
 
             hash = \@Id n -> n
 
@@ -95,13 +97,13 @@ fn ability_constrained_in_non_member_usage() {
             r#"
             app "test" provides [result] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            mulHashes : a, a -> U64 | a has Hash
+            mulHashes : a, a -> U64 | a has MHash
             mulHashes = \x, y -> hash x * hash y
 
-            Id := U64 has [Hash {hash}]
+            Id := U64 has [MHash {hash}]
             hash = \@Id n -> n
 
             result = mulHashes (@Id 5) (@Id 7)
@@ -120,12 +122,12 @@ fn ability_constrained_in_non_member_usage_inferred() {
             r#"
             app "test" provides [result] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
             mulHashes = \x, y -> hash x * hash y
 
-            Id := U64 has [Hash {hash}]
+            Id := U64 has [MHash {hash}]
             hash = \@Id n -> n
 
             result = mulHashes (@Id 5) (@Id 7)
@@ -144,16 +146,16 @@ fn ability_constrained_in_non_member_multiple_specializations() {
             r#"
             app "test" provides [result] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            mulHashes : a, b -> U64 | a has Hash, b has Hash
+            mulHashes : a, b -> U64 | a has MHash, b has MHash
             mulHashes = \x, y -> hash x * hash y
 
-            Id := U64 has [Hash { hash: hashId }]
+            Id := U64 has [MHash { hash: hashId }]
             hashId = \@Id n -> n
 
-            Three := {} has [Hash { hash: hashThree }]
+            Three := {} has [MHash { hash: hashThree }]
             hashThree = \@Three _ -> 3
 
             result = mulHashes (@Id 100) (@Three {})
@@ -172,15 +174,15 @@ fn ability_constrained_in_non_member_multiple_specializations_inferred() {
             r#"
             app "test" provides [result] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
             mulHashes = \x, y -> hash x * hash y
 
-            Id := U64 has [Hash { hash: hashId }]
+            Id := U64 has [MHash { hash: hashId }]
             hashId = \@Id n -> n
 
-            Three := {} has [Hash { hash: hashThree }]
+            Three := {} has [MHash { hash: hashThree }]
             hashThree = \@Three _ -> 3
 
             result = mulHashes (@Id 100) (@Three {})
@@ -199,16 +201,16 @@ fn ability_used_as_type_still_compiles() {
             r#"
             app "test" provides [result] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            mulHashes : Hash, Hash -> U64
+            mulHashes : MHash, MHash -> U64
             mulHashes = \x, y -> hash x * hash y
 
-            Id := U64 has [Hash { hash: hashId }]
+            Id := U64 has [MHash { hash: hashId }]
             hashId = \@Id n -> n
 
-            Three := {} has [Hash { hash: hashThree }]
+            Three := {} has [MHash { hash: hashThree }]
             hashThree = \@Three _ -> 3
 
             result = mulHashes (@Id 100) (@Three {})
@@ -1102,3 +1104,360 @@ fn decode_record_of_record() {
         RocStr
     )
 }
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_tag_union_with_payloads() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Json] provides [main] to "./platform"
+
+            Shape : [Circle F64, Rect F64 F64]
+
+            area : Shape -> F64
+            area = \shape ->
+                when shape is
+                    Circle r -> 3 * r * r
+                    Rect w h -> w * h
+
+            main =
+                when Str.toUtf8 "{\"Rect\":[2.5,4]}" |> Decode.fromBytes Json.fromUtf8 is
+                    Ok shape -> area shape
+                    _ -> -1
+            "#
+        ),
+        10.0,
+        f64
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_tag_union_without_payload() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Json] provides [main] to "./platform"
+
+            main =
+                when Str.toUtf8 "{\"Green\":[]}" |> Decode.fromBytes Json.fromUtf8 is
+                    Ok Red -> "red"
+                    Ok Green -> "green"
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("green"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_tag_union_unknown_tag() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Json] provides [main] to "./platform"
+
+            main =
+                when Str.toUtf8 "{\"Blue\":[]}" |> Decode.fromBytes Json.fromUtf8 is
+                    Ok Red -> "red"
+                    Ok Green -> "green"
+                    Err _ -> "not a color"
+            "#
+        ),
+        RocStr::from("not a color"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn eq_custom_impl() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Parity := U8 has [Eq { isEq: parityEq }]
+
+            parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+            main = (@Parity 3 == @Parity 5, @Parity 3 != @Parity 4)
+            "#
+        ),
+        (true, true),
+        (bool, bool)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn eq_derived_opaque() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Id := { name : Str, n : U8 } has [Eq]
+
+            main =
+                a = @Id { name: "one", n: 1 }
+                b = @Id { name: "two", n: 2 }
+
+                (a == a, a == b)
+            "#
+        ),
+        (true, false),
+        (bool, bool)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn eq_custom_impl_through_polymorphic_function() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Parity := U8 has [Eq { isEq: parityEq }]
+
+            parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+            allEq : List a -> Bool | a has Eq
+            allEq = \list ->
+                when List.first list is
+                    Ok first -> List.all list \x -> x == first
+                    Err _ -> Bool.true
+
+            main = allEq [@Parity 1, @Parity 3, @Parity 7]
+            "#
+        ),
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn eq_custom_impl_in_record() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Parity := U8 has [Eq { isEq: parityEq }]
+
+            parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+            main =
+                a = { name: "a", parity: @Parity 1 }
+                b = { name: "a", parity: @Parity 3 }
+                c = { name: "a", parity: @Parity 2 }
+
+                (a == b, a == c)
+            "#
+        ),
+        (true, false),
+        (bool, bool)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn eq_custom_impl_in_list() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Parity := U8 has [Eq { isEq: parityEq }]
+
+            parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+            main =
+                (
+                    [@Parity 1, @Parity 2] == [@Parity 3, @Parity 4],
+                    [@Parity 1, @Parity 2] == [@Parity 3, @Parity 5],
+                    [@Parity 1] == [@Parity 1, @Parity 1],
+                )
+            "#
+        ),
+        (true, false, false),
+        (bool, bool, bool)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn eq_custom_impl_in_tag_payload() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Parity := U8 has [Eq { isEq: parityEq }]
+
+            parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+            wrap : U8 -> [Some Parity, None]
+            wrap = \n -> if n == 0 then None else Some (@Parity n)
+
+            main =
+                (
+                    wrap 1 == wrap 3,
+                    wrap 1 == wrap 2,
+                    wrap 1 == wrap 0,
+                    wrap 0 == wrap 0,
+                )
+            "#
+        ),
+        (true, false, false, true),
+        (bool, bool, bool, bool)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn eq_derived_opaque_wrapping_custom_impl() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Parity := U8 has [Eq { isEq: parityEq }]
+
+            parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+            Counted := { parities : List Parity, count : U8 } has [Eq]
+
+            main =
+                a = @Counted { parities: [@Parity 1, @Parity 2], count: 2 }
+                b = @Counted { parities: [@Parity 5, @Parity 8], count: 2 }
+                c = @Counted { parities: [@Parity 5, @Parity 7], count: 2 }
+
+                (a == b, a == c)
+            "#
+        ),
+        (true, false),
+        (bool, bool)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn eq_list_of_recursive_tag_union_payload() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Tree : [Node (List Tree)]
+
+            tree : Tree
+            tree = Node [Node [], Node [Node []]]
+
+            main =
+                when tree is
+                    Node children ->
+                        (children == [Node [], Node [Node []]], children == [Node []])
+            "#
+        ),
+        (true, false),
+        (bool, bool)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn gen_check_passing_property() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Gen] provides [main] to "./platform"
+
+            main =
+                reversedTwice : List U8 -> Bool
+                reversedTwice = \elems -> List.reverse (List.reverse elems) == elems
+
+                Gen.checkWith Gen.arbitrary reversedTwice { runs: 100, seed: 7 }
+            "#
+        ),
+        RocResult::ok(()),
+        RocResult<(), RocList<u8>>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn gen_check_shrinks_number() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Gen] provides [main] to "./platform"
+
+            main = Gen.checkWith Gen.arbitrary (\n -> n < 10u8) { runs: 100, seed: 7 }
+            "#
+        ),
+        RocResult::err(10),
+        RocResult<(), u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn gen_check_shrinks_list() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Gen] provides [main] to "./platform"
+
+            property : List U8 -> Bool
+            property = \elems -> List.len elems < 3
+
+            main = Gen.checkWith Gen.arbitrary property { runs: 100, seed: 7 }
+            "#
+        ),
+        RocResult::err(RocList::from_slice(&[0, 0, 0])),
+        RocResult<(), RocList<u8>>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn gen_check_derived_tag_union_and_record() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Gen] provides [main] to "./platform"
+
+            Shape : [Circle { radius : U8 }, Square { side : U8 }]
+
+            area : Shape -> U16
+            area = \shape ->
+                when shape is
+                    Circle { radius } -> 3 * Num.toU16 radius * Num.toU16 radius
+                    Square { side } -> Num.toU16 side * Num.toU16 side
+
+            main =
+                when Gen.checkWith Gen.arbitrary (\shape -> area shape < 100) { runs: 100, seed: 7 } is
+                    Ok {} -> "none"
+                    Err (Counterexample (Circle { radius })) -> "circle \(Num.toStr radius)"
+                    Err (Counterexample (Square { side })) -> "square \(Num.toStr side)"
+            "#
+        ),
+        RocStr::from("circle 6"),
+        RocStr
+    );
+}
@@ -549,3 +549,201 @@ fn walk_sum_keys() {
         i64
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn record_keys() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict : Dict.Dict { x : I64, y : I64 } I64
+            dict =
+                Dict.empty
+                    |> Dict.insert { x: 1, y: 2 } 10
+                    |> Dict.insert { x: 2, y: 1 } 20
+                    |> Dict.insert { x: 1, y: 2 } 30
+
+            removed = Dict.remove dict { x: 1, y: 2 }
+
+            [
+                Dict.get dict { x: 1, y: 2 } |> Result.withDefault 0,
+                Dict.get dict { x: 2, y: 1 } |> Result.withDefault 0,
+                Dict.len dict |> Num.toI64,
+                Dict.get removed { x: 1, y: 2 } |> Result.withDefault -1,
+                Dict.len removed |> Num.toI64,
+            ]
+            "#
+        ),
+        RocList::from_slice(&[30, 20, 2, -1, 1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn tag_keys() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict : Dict.Dict [Origin, Point I64 I64] I64
+            dict =
+                Dict.empty
+                    |> Dict.insert Origin 10
+                    |> Dict.insert (Point 1 2) 20
+                    |> Dict.insert (Point 2 1) 30
+                    |> Dict.insert (Point 1 2) 40
+
+            removed = Dict.remove dict (Point 1 2)
+
+            [
+                Dict.get dict Origin |> Result.withDefault 0,
+                Dict.get dict (Point 1 2) |> Result.withDefault 0,
+                Dict.get dict (Point 2 1) |> Result.withDefault 0,
+                Dict.len dict |> Num.toI64,
+                Dict.get removed (Point 1 2) |> Result.withDefault -1,
+                Dict.len removed |> Num.toI64,
+            ]
+            "#
+        ),
+        RocList::from_slice(&[10, 40, 30, 3, -1, 2]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn custom_hash_opaque_keys() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Parity := U8 has [Hash { hash: hashParity }, Eq { isEq: parityEq }]
+
+            hashParity = \hasher, @Parity n -> Hash.addU8 hasher (n % 2)
+
+            parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+            main =
+                dict =
+                    Dict.empty
+                        |> Dict.insert (@Parity 1) 10
+                        |> Dict.insert (@Parity 2) 20
+                        |> Dict.insert (@Parity 3) 30
+
+                removed = Dict.remove dict (@Parity 4)
+
+                [
+                    Dict.get dict (@Parity 5) |> Result.withDefault 0,
+                    Dict.get dict (@Parity 0) |> Result.withDefault 0,
+                    Dict.len dict |> Num.toI64,
+                    Dict.get removed (@Parity 2) |> Result.withDefault -1,
+                    Dict.len removed |> Num.toI64,
+                ]
+            "#
+        ),
+        RocList::from_slice(&[30, 20, 2, -1, 1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn insert_grows_and_rehashes() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict : Dict.Dict I64 I64
+            dict =
+                List.range 0 999
+                    |> List.walk Dict.empty (\accum, k -> Dict.insert accum k (k * 2))
+
+            found =
+                List.range 0 999
+                    |> List.walk 0 (\count, k ->
+                        when Dict.get dict k is
+                            Ok v if v == k * 2 -> count + 1
+                            _ -> count)
+
+            [
+                Dict.len dict |> Num.toI64,
+                found,
+                Dict.get dict 1000 |> Result.withDefault -1,
+            ]
+            "#
+        ),
+        RocList::from_slice(&[1000, 1000, -1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn colliding_keys() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Collide := I64 has [Hash { hash: hashCollide }, Eq { isEq: collideEq }]
+
+            hashCollide = \hasher, @Collide _ -> Hash.addU8 hasher 0
+
+            collideEq = \@Collide a, @Collide b -> a == b
+
+            main =
+                dict =
+                    List.range 0 19
+                        |> List.walk Dict.empty (\accum, k -> Dict.insert accum (@Collide k) k)
+
+                removed =
+                    dict
+                        |> Dict.remove (@Collide 3)
+                        |> Dict.remove (@Collide 10)
+
+                [
+                    Dict.len dict |> Num.toI64,
+                    Dict.get dict (@Collide 19) |> Result.withDefault -1,
+                    Dict.len removed |> Num.toI64,
+                    Dict.get removed (@Collide 10) |> Result.withDefault -1,
+                    Dict.get removed (@Collide 11) |> Result.withDefault -1,
+                    Dict.get removed (@Collide 19) |> Result.withDefault -1,
+                ]
+            "#
+        ),
+        RocList::from_slice(&[20, 19, 18, -1, 11, 19]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn remove_moves_last_entry() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict : Dict.Dict I64 I64
+            dict =
+                List.range 1 5
+                    |> List.walk Dict.empty (\accum, k -> Dict.insert accum k (k * 10))
+
+            # Removing 2 moves the last entry, 5, into its slot.
+            removed = Dict.remove dict 2
+
+            reinserted =
+                removed
+                    |> Dict.insert 2 200
+                    |> Dict.remove 5
+
+            List.join [
+                Dict.keys removed,
+                [Dict.get removed 5 |> Result.withDefault -1],
+                Dict.keys reinserted,
+                [Dict.get reinserted 2 |> Result.withDefault -1],
+            ]
+            "#
+        ),
+        RocList::from_slice(&[1, 5, 3, 4, 50, 1, 2, 3, 4, 200]),
+        RocList<i64>
+    );
+}
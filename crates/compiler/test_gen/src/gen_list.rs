@@ -513,6 +513,214 @@ fn list_drop_at_shared() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_sublist_seamless_slice() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                list = List.range 0 100
+
+                List.sublist list { start: 90, len: 5 }
+            "#
+        ),
+        RocList::from_slice(&[90, 91, 92, 93, 94]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_sublist_of_seamless_slice() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                list = List.range 0 100
+                slice = List.sublist list { start: 10, len: 80 }
+
+                List.sublist slice { start: 5, len: 3 }
+            "#
+        ),
+        RocList::from_slice(&[15, 16, 17]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_sublist_shared() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+               list : List I64
+               list = [if Bool.true then 4 else 4, 5, 6, 7]
+
+               { slice: List.sublist list { start: 1, len: 2 }, original: list }
+               "#
+        ),
+        (
+            // original
+            RocList::from_slice(&[4, 5, 6, 7]),
+            // slice
+            RocList::from_slice(&[5, 6]),
+        ),
+        (RocList<i64>, RocList<i64>,)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_sublist_shared_strings() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+               strings = List.map (List.range 0 4) \n ->
+                   Str.concat "A long enough string to be heap-allocated " (Num.toStr n)
+
+               { slice: List.sublist strings { start: 1, len: 2 }, original: strings }
+               "#
+        ),
+        (
+            // original
+            RocList::from_slice(&[
+                RocStr::from("A long enough string to be heap-allocated 0"),
+                RocStr::from("A long enough string to be heap-allocated 1"),
+                RocStr::from("A long enough string to be heap-allocated 2"),
+                RocStr::from("A long enough string to be heap-allocated 3"),
+            ]),
+            // slice
+            RocList::from_slice(&[
+                RocStr::from("A long enough string to be heap-allocated 1"),
+                RocStr::from("A long enough string to be heap-allocated 2"),
+            ]),
+        ),
+        (RocList<RocStr>, RocList<RocStr>,)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_drop_at_shared_ends_and_middle() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+               list : List I64
+               list = [if Bool.true then 4 else 4, 5, 6, 7]
+
+               {
+                   first: List.dropFirst list,
+                   last: List.dropLast list,
+                   middle: List.dropAt list 1,
+                   original: list,
+               }
+               "#
+        ),
+        (
+            // first
+            RocList::from_slice(&[5, 6, 7]),
+            // last
+            RocList::from_slice(&[4, 5, 6]),
+            // middle
+            RocList::from_slice(&[4, 6, 7]),
+            // original
+            RocList::from_slice(&[4, 5, 6, 7]),
+        ),
+        (RocList<i64>, RocList<i64>, RocList<i64>, RocList<i64>)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_drop_first_repeatedly() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                sum : List I64, I64 -> I64
+                sum = \list, total ->
+                    when List.first list is
+                        Ok first -> sum (List.dropFirst list) (total + first)
+                        Err ListWasEmpty -> total
+
+                sum (List.range 0 1000) 0
+            "#
+        ),
+        499500,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_set_seamless_slice() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                slice = List.sublist (List.range 0 10) { start: 2, len: 5 }
+
+                List.set slice 0 100
+            "#
+        ),
+        RocList::from_slice(&[100, 3, 4, 5, 6]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_append_seamless_slice() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                slice = List.dropFirst (List.range 0 5)
+
+                slice
+                |> List.append 5
+                |> List.concat [6, 7]
+            "#
+        ),
+        RocList::from_slice(&[1, 2, 3, 4, 5, 6, 7]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_seamless_slice_of_strings() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                strings = List.map (List.range 0 5) \n ->
+                    Str.concat "A long enough string to be heap-allocated " (Num.toStr n)
+
+                strings
+                |> List.dropFirst
+                |> List.sublist { start: 1, len: 2 }
+            "#
+        ),
+        RocList::from_slice(&[
+            RocStr::from("A long enough string to be heap-allocated 2"),
+            RocStr::from("A long enough string to be heap-allocated 3"),
+        ]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_seamless_slice_from_utf8() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                bytes = Str.toUtf8 (Str.concat "xA long enough string " "to be heap-allocated")
+
+                Str.fromUtf8 (List.dropFirst bytes) |> Result.withDefault ""
+            "#
+        ),
+        RocStr::from("A long enough string to be heap-allocated"),
+        RocStr
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn list_drop_if_empty_list_of_int() {
@@ -11,7 +11,7 @@ use crate::helpers::wasm::assert_evals_to;
 #[allow(unused_imports)]
 use indoc::indoc;
 #[allow(unused_imports)]
-use roc_std::{RocDec, RocOrder, RocResult};
+use roc_std::{RocDec, RocList, RocOrder, RocResult};
 
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
@@ -1771,6 +1771,159 @@ fn atan() {
     assert_evals_to!("Num.atan 10", 1.4711276743037347, f64);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn atan2() {
+    assert_evals_to!("Num.atan2 0.0 -1.0", std::f64::consts::PI, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn exp() {
+    assert_evals_to!("Num.exp 0.0", 1.0, f64);
+    assert_evals_to!("Num.exp 1.0", std::f64::consts::E, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn exp2() {
+    assert_evals_to!("Num.exp2 10.0", 1024.0, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn log2() {
+    assert_evals_to!("Num.log2 1024.0", 10.0, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn log10() {
+    assert_evals_to!("Num.log10 1000.0", 3.0, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn hyperbolic() {
+    assert_evals_to!("Num.sinh 0.0", 0.0, f64);
+    assert_evals_to!("Num.cosh 0.0", 1.0, f64);
+    assert_evals_to!("Num.tanh 100.0", 1.0, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn hypot() {
+    assert_evals_to!("Num.hypot 3.0 4.0", 5.0, f64);
+    assert_evals_to!("Num.hypot (-1.0 / 0.0) (0.0 / 0.0)", f64::INFINITY, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn fma() {
+    assert_evals_to!("Num.fma 2.0 3.0 1.0", 7.0, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn copy_sign() {
+    assert_evals_to!("Num.copySign 3.0 -1.0", -3.0, f64);
+    assert_evals_to!("Num.copySign -3.0f32 1.0", 3.0, f32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn copy_sign_dec() {
+    assert_evals_to!(
+        "Num.copySign 3.5dec -1.0dec",
+        RocDec::from_str_to_i128_unsafe("-3.5"),
+        i128
+    );
+    assert_evals_to!(
+        "Num.copySign -3.5dec 0.0dec",
+        RocDec::from_str_to_i128_unsafe("3.5"),
+        i128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn atan2_dec() {
+    assert_evals_to!("Num.atan2 0.0dec 1.0dec", 0, i128);
+    // computed through f64, so only the first 16 or so digits are right
+    assert_evals_to!("Num.atan2 1.0dec -1.0dec", 2356194490192345088, i128);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn hypot_dec() {
+    assert_evals_to!(
+        "Num.hypot 3dec 4dec",
+        RocDec::from_str_to_i128_unsafe("5"),
+        i128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn trunc() {
+    assert_evals_to!("Num.trunc 2.7", 2, i64);
+    assert_evals_to!("Num.trunc -2.7", -2, i64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn trunc_unsigned() {
+    assert_evals_to!("Num.trunc 3000000000.5", 3_000_000_000, u32);
+    assert_evals_to!("Num.trunc 3000000000.5f32", 3_000_000_000, u32);
+    assert_evals_to!(
+        "Num.trunc 10000000000000000000.0",
+        10_000_000_000_000_000_000,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn trunc_128_bit() {
+    assert_evals_to!("Num.trunc -2.7", -2, i128);
+    assert_evals_to!(
+        "Num.trunc 1000000000000000000000000000000.0",
+        1_000_000_000_000_019_884_624_838_656,
+        i128
+    );
+    assert_evals_to!("Num.trunc 2.7", 2, u128);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn is_nan() {
+    assert_evals_to!("Num.isNaN (0.0 / 0.0)", true, bool);
+    assert_evals_to!("Num.isNaN (1.0 / 0.0)", false, bool);
+    assert_evals_to!("Num.isNaN 1.5f32", false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn is_infinite() {
+    assert_evals_to!("Num.isInfinite (-1.0 / 0.0)", true, bool);
+    assert_evals_to!("Num.isInfinite (0.0 / 0.0)", false, bool);
+    assert_evals_to!("Num.isInfinite 1.5f32", false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn is_nan_dec() {
+    assert_evals_to!("Num.isNaN 1.5dec", false, bool);
+    assert_evals_to!("Num.isInfinite 1.5dec", false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn e_and_pi() {
+    assert_evals_to!("Num.e", std::f64::consts::E, f64);
+    assert_evals_to!("Num.pi", std::f64::consts::PI, f64);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 #[should_panic(expected = r#"Roc failed with message: "integer addition overflowed!"#)]
@@ -2141,6 +2294,128 @@ fn shift_right_zf_by() {
     assert_evals_to!("Num.shiftRightZfBy 0b1000_0000u8 12", 0b0000_0000u8, u8);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn rotate_left_by() {
+    assert_evals_to!("Num.rotateLeftBy 0b1000_0001u8 1", 0b0000_0011u8, u8);
+    assert_evals_to!("Num.rotateLeftBy 0b0110_0000u8 9", 0b1100_0000u8, u8);
+    assert_evals_to!("Num.rotateLeftBy -128i8 1", 1i8, i8);
+    assert_evals_to!("Num.rotateLeftBy 0x8000u16 4", 0x0008u16, u16);
+    assert_evals_to!("Num.rotateLeftBy 0x1234i16 20", 0x2341i16, i16);
+    assert_evals_to!("Num.rotateLeftBy 0x8000_0001u32 1", 0x0000_0003u32, u32);
+    assert_evals_to!("Num.rotateLeftBy -2i32 1", -3i32, i32);
+    assert_evals_to!(
+        "Num.rotateLeftBy 0x0123_4567_89AB_CDEFu64 16",
+        0x4567_89AB_CDEF_0123u64,
+        u64
+    );
+    assert_evals_to!("Num.rotateLeftBy -2i64 65", -3i64, i64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn rotate_right_by() {
+    assert_evals_to!("Num.rotateRightBy 0b1000_0001u8 1", 0b1100_0000u8, u8);
+    assert_evals_to!("Num.rotateRightBy 0b0000_0110u8 9", 0b0000_0011u8, u8);
+    assert_evals_to!("Num.rotateRightBy 1i8 1", -128i8, i8);
+    assert_evals_to!("Num.rotateRightBy 0x0001u16 4", 0x1000u16, u16);
+    assert_evals_to!("Num.rotateRightBy 0x1234i16 20", 0x4123i16, i16);
+    assert_evals_to!("Num.rotateRightBy 0x1234_5678u32 8", 0x7812_3456u32, u32);
+    assert_evals_to!("Num.rotateRightBy -3i32 1", -2i32, i32);
+    assert_evals_to!(
+        "Num.rotateRightBy 0x0123_4567_89AB_CDEFu64 16",
+        0xCDEF_0123_4567_89ABu64,
+        u64
+    );
+    assert_evals_to!("Num.rotateRightBy 1i64 1", i64::MIN, i64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn count_leading_zero_bits() {
+    assert_evals_to!("Num.countLeadingZeroBits 0b0001_1100u8", 3, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0u8", 8, u8);
+    assert_evals_to!("Num.countLeadingZeroBits -1i8", 0, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 1u16", 15, u8);
+    assert_evals_to!("Num.countLeadingZeroBits -1i16", 0, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0u32", 32, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0x00FFi32", 24, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0x00FF_FFFFu64", 40, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0i64", 64, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn count_trailing_zero_bits() {
+    assert_evals_to!("Num.countTrailingZeroBits 0b0001_1100u8", 2, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u8", 8, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0i8", 8, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0x0100u16", 8, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0i16", 16, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0x8000_0000u32", 31, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0i32", 32, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u64", 64, u8);
+    assert_evals_to!("Num.countTrailingZeroBits -4i64", 2, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn count_one_bits() {
+    assert_evals_to!("Num.countOneBits 0b0001_1100u8", 3, u8);
+    assert_evals_to!("Num.countOneBits -1i8", 8, u8);
+    assert_evals_to!("Num.countOneBits 0xFFFFu16", 16, u8);
+    assert_evals_to!("Num.countOneBits -1i16", 16, u8);
+    assert_evals_to!("Num.countOneBits 0xF0F0_F0F0u32", 16, u8);
+    assert_evals_to!("Num.countOneBits -1i32", 32, u8);
+    assert_evals_to!("Num.countOneBits 0u64", 0, u8);
+    assert_evals_to!("Num.countOneBits -1i64", 64, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn swap_bytes() {
+    assert_evals_to!("Num.swapBytes 0x12u8", 0x12u8, u8);
+    assert_evals_to!("Num.swapBytes -1i8", -1i8, i8);
+    assert_evals_to!("Num.swapBytes 0x1234u16", 0x3412u16, u16);
+    assert_evals_to!("Num.swapBytes 0x00FFi16", -256i16, i16);
+    assert_evals_to!("Num.swapBytes 0x1234_5678u32", 0x7856_3412u32, u32);
+    assert_evals_to!("Num.swapBytes 0x0000_00FFi32", -16_777_216i32, i32);
+    assert_evals_to!(
+        "Num.swapBytes 0x0123_4567_89AB_CDEFu64",
+        0xEFCD_AB89_6745_2301u64,
+        u64
+    );
+    assert_evals_to!("Num.swapBytes 0x00FFi64", i64::MIN >> 7, i64);
+}
+
+// The dev backend can't load 128-bit literals yet
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn bit_manipulation_128() {
+    assert_evals_to!(
+        "Num.rotateLeftBy 0x8000_0000_0000_0000_0000_0000_0000_0001u128 1",
+        3u128,
+        u128
+    );
+    assert_evals_to!("Num.rotateRightBy 3i128 1", i128::MIN + 1, i128);
+    assert_evals_to!("Num.countLeadingZeroBits 1u128", 127, u8);
+    assert_evals_to!("Num.countLeadingZeroBits -1i128", 0, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u128", 128, u8);
+    assert_evals_to!(
+        "Num.countTrailingZeroBits 0x1_0000_0000_0000_0000i128",
+        64,
+        u8
+    );
+    assert_evals_to!("Num.countOneBits -1i128", 128, u8);
+    assert_evals_to!("Num.countOneBits 0xFFFF_0000_0000_0000_0000u128", 16, u8);
+    assert_evals_to!(
+        "Num.swapBytes 0x0123_4567_89AB_CDEFu128",
+        0xEFCD_AB89_6745_2301_0000_0000_0000_0000u128,
+        u128
+    );
+    assert_evals_to!("Num.swapBytes 0xFFi128", i128::MIN >> 7, i128);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn min_i128() {
@@ -2807,6 +3082,88 @@ fn bytes_to_u32_subtly_out_of_bounds() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn to_bytes_le() {
+    assert_evals_to!(
+        "Num.toBytesLe 0x1234u16",
+        RocList::from_slice(&[0x34, 0x12]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        "Num.toBytesLe -2i32",
+        RocList::from_slice(&[0xFE, 0xFF, 0xFF, 0xFF]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn to_bytes_be() {
+    assert_evals_to!(
+        "Num.toBytesBe 0x0102_0304_0506_0708u64",
+        RocList::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn from_bytes_le() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                result : Result I32 [OutOfBounds]
+                result = Num.fromBytesLe [0, 0xFE, 0xFF, 0xFF, 0xFF] 1
+
+                when result is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        -2,
+        i32
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn from_bytes_be() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                result : Result U16 [OutOfBounds]
+                result = Num.fromBytesBe [0x12, 0x34] 0
+
+                when result is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        0x1234,
+        u16
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn from_bytes_le_out_of_bounds() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                result : Result U32 [OutOfBounds]
+                result = Num.fromBytesLe [1, 2, 3, 4] 1
+
+                when result is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        1,
+        u32
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn bytes_to_u16_max_u8s() {
@@ -3606,6 +3963,118 @@ fn div_of_unsigned() {
     )
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn dec_to_str_fixed() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(r#"Num.toStrFixed 1.5dec 2"#, RocStr::from("1.50"), RocStr);
+    assert_evals_to!(r#"Num.toStrFixed 2.675dec 2"#, RocStr::from("2.68"), RocStr);
+    assert_evals_to!(r#"Num.toStrFixed 41.5dec 0"#, RocStr::from("42"), RocStr);
+    assert_evals_to!(
+        r#"Num.toStrFixed -0.001dec 2"#,
+        RocStr::from("0.00"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrFixed -12.5dec 1"#,
+        RocStr::from("-12.5"),
+        RocStr
+    );
+    assert_evals_to!(r#"Num.toStrFixed 0dec 3"#, RocStr::from("0.000"), RocStr);
+    assert_evals_to!(
+        r#"Num.toStrFixed 1dec 20"#,
+        RocStr::from("1.00000000000000000000"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn dec_round_to() {
+    assert_evals_to!(
+        "Num.roundDecTo 2.345dec 2 HalfEven",
+        RocDec::from_str_to_i128_unsafe("2.34"),
+        i128
+    );
+    assert_evals_to!(
+        "Num.roundDecTo 2.355dec 2 HalfEven",
+        RocDec::from_str_to_i128_unsafe("2.36"),
+        i128
+    );
+    assert_evals_to!(
+        "Num.roundDecTo -2.345dec 2 HalfUp",
+        RocDec::from_str_to_i128_unsafe("-2.35"),
+        i128
+    );
+    assert_evals_to!(
+        "Num.roundDecTo 2.349dec 2 TowardZero",
+        RocDec::from_str_to_i128_unsafe("2.34"),
+        i128
+    );
+    assert_evals_to!(
+        "Num.roundDecTo 2.349dec 20 TowardZero",
+        RocDec::from_str_to_i128_unsafe("2.349"),
+        i128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn dec_round_to_checked() {
+    assert_evals_to!(
+        "Num.roundDecToChecked 1.5dec 0 HalfEven == Ok 2dec",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+            max = Num.fromScaledI128 170141183460469231731687303715884105727 18
+
+            when Num.roundDecToChecked max 0 HalfUp is
+                Ok _ -> Bool.false
+                Err Overflow -> Bool.true
+            "#
+        ),
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn dec_to_scaled_i128() {
+    assert_evals_to!("Num.toScaledI128 12.345dec 2", 1234, i128);
+    assert_evals_to!("Num.toScaledI128 -12.345dec 2", -1234, i128);
+    assert_evals_to!("Num.toScaledI128 1.5dec 20", 150000000000000000000, i128);
+    assert_evals_to!(
+        "Num.toScaledI128Checked 1dec 60 == Err Overflow",
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn dec_from_scaled_i128() {
+    assert_evals_to!(
+        "Num.fromScaledI128 1234 2",
+        RocDec::from_str_to_i128_unsafe("12.34"),
+        i128
+    );
+    assert_evals_to!(
+        "Num.fromScaledI128 -150000000000000000000 20",
+        RocDec::from_str_to_i128_unsafe("-1.5"),
+        i128
+    );
+    assert_evals_to!(
+        "Num.fromScaledI128Checked 170141183460469231731687303715884105727 0 == Err Overflow",
+        true,
+        bool
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn dec_float_suffix() {
@@ -4067,3 +4067,58 @@ fn int_let_generalization() {
         RocStr
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn crash_branch_not_taken() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            x : U8
+            x = 42
+
+            if x == 0 then crash "x must not be zero" else x
+            "#
+        ),
+        42,
+        u8
+    );
+}
+
+// The dev backend's test host has a roc_panic that returns instead of unwinding,
+// so only llvm and wasm can check the message of a crash that is taken
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[should_panic(expected = r#"Roc failed with message: "hello crash""#)]
+fn crash_literal() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            x : U8
+            x = 42
+
+            if x == 42 then crash "hello crash" else x
+            "#
+        ),
+        42,
+        u8
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[should_panic(expected = r#"Roc failed with message: "x was 42""#)]
+fn crash_runtime_message() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            x : U8
+            x = 42
+
+            if x == 42 then crash "x was \(Num.toStr x)" else x
+            "#
+        ),
+        42,
+        u8
+    );
+}
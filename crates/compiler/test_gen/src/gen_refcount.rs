@@ -47,6 +47,43 @@ fn str_dealloc() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn str_seamless_slice_inc() {
+    assert_refcounts!(
+        indoc!(
+            r#"
+                s = Str.concat "A long enough string " "to be heap-allocated"
+                slice = Str.sliceUtf8 s { start: 2, count: 30 } |> Result.withDefault ""
+
+                [slice, slice]
+            "#
+        ),
+        RocList<RocStr>,
+        &[
+            Live(2), // s, shared by both slices
+            Live(1)  // result
+        ]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn str_seamless_slice_dealloc() {
+    assert_refcounts!(
+        indoc!(
+            r#"
+                s = Str.concat "A long enough string " "to be heap-allocated"
+                slice = Str.sliceUtf8 s { start: 2, count: 30 } |> Result.withDefault ""
+
+                Str.countUtf8Bytes slice
+            "#
+        ),
+        usize,
+        &[Deallocated]
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-wasm"))]
 fn list_int_inc() {
@@ -123,6 +160,89 @@ fn list_str_dealloc() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn list_seamless_slice_inc() {
+    assert_refcounts!(
+        indoc!(
+            r#"
+                s = Str.concat "A long enough string " "to be heap-allocated"
+                list = [s, s, s]
+                slice = List.dropFirst list
+
+                [slice, slice]
+            "#
+        ),
+        RocList<RocList<RocStr>>,
+        &[
+            Live(2), // s, minus the dropped element
+            Live(2), // list, shared by both slices
+            Live(1)  // result
+        ]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn list_sublist_of_shared_list() {
+    assert_refcounts!(
+        indoc!(
+            r#"
+                s = Str.concat "A long enough string " "to be heap-allocated"
+                list = [s, s, s, s]
+                slice = List.sublist list { start: 1, len: 2 }
+
+                { list, slice }
+            "#
+        ),
+        (RocList<RocStr>, RocList<RocStr>),
+        &[
+            Live(4), // s, still in every element of list
+            Live(2), // list, shared with the slice
+        ]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn list_drop_ends_of_shared_list() {
+    assert_refcounts!(
+        indoc!(
+            r#"
+                s = Str.concat "A long enough string " "to be heap-allocated"
+                list = [s, s, s]
+
+                { first: List.dropFirst list, last: List.dropLast list, list }
+            "#
+        ),
+        (RocList<RocStr>, RocList<RocStr>, RocList<RocStr>),
+        &[
+            Live(3), // s, still in every element of list
+            Live(3), // list, shared with both slices
+        ]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+fn list_seamless_slice_dealloc() {
+    assert_refcounts!(
+        indoc!(
+            r#"
+                s = Str.concat "A long enough string " "to be heap-allocated"
+                list = [s, s, s]
+
+                List.len (List.dropFirst list)
+            "#
+        ),
+        usize,
+        &[
+            Deallocated, // s
+            Deallocated  // list
+        ]
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-wasm"))]
 fn struct_inc() {
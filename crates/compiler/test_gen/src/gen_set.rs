@@ -280,3 +280,86 @@ fn from_list_result() {
         i64
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn record_elements() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            set =
+                Set.fromList [{ x: 1, y: 2 }, { x: 2, y: 1 }, { x: 1, y: 2 }]
+                    |> Set.insert { x: 3, y: 3 }
+                    |> Set.remove { x: 2, y: 1 }
+
+            [
+                Set.len set |> Num.toI64,
+                if Set.contains set { x: 1, y: 2 } then 1 else 0,
+                if Set.contains set { x: 2, y: 1 } then 1 else 0,
+                if Set.contains set { x: 3, y: 3 } then 1 else 0,
+            ]
+            "#
+        ),
+        RocList::from_slice(&[2, 1, 0, 1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn tag_elements() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            set : Set.Set [Origin, Point I64 I64]
+            set =
+                Set.fromList [Origin, Point 1 2, Point 2 1, Point 1 2]
+                    |> Set.remove (Point 2 1)
+
+            [
+                Set.len set |> Num.toI64,
+                if Set.contains set Origin then 1 else 0,
+                if Set.contains set (Point 1 2) then 1 else 0,
+                if Set.contains set (Point 2 1) then 1 else 0,
+            ]
+            "#
+        ),
+        RocList::from_slice(&[2, 1, 1, 0]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn custom_hash_opaque_elements() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Parity := U8 has [Hash { hash: hashParity }, Eq { isEq: parityEq }]
+
+            hashParity = \hasher, @Parity n -> Hash.addU8 hasher (n % 2)
+
+            parityEq = \@Parity a, @Parity b -> a % 2 == b % 2
+
+            main =
+                set =
+                    Set.fromList [@Parity 1, @Parity 3, @Parity 4]
+                        |> Set.insert (@Parity 5)
+
+                removed = Set.remove set (@Parity 7)
+
+                [
+                    Set.len set |> Num.toI64,
+                    if Set.contains set (@Parity 9) then 1 else 0,
+                    Set.len removed |> Num.toI64,
+                    if Set.contains removed (@Parity 9) then 1 else 0,
+                    if Set.contains removed (@Parity 0) then 1 else 0,
+                ]
+            "#
+        ),
+        RocList::from_slice(&[2, 1, 1, 0, 1]),
+        RocList<i64>
+    );
+}
@@ -1930,3 +1930,245 @@ fn when_on_strings() {
         i64
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_graphemes() {
+    assert_evals_to!(
+        r#"Str.graphemes "e\u(301)👩‍👩‍👦‍👦\r\nJ""#,
+        RocList::from_slice(&[
+            RocStr::from("e\u{301}"),
+            RocStr::from("👩‍👩‍👦‍👦"),
+            RocStr::from("\r\n"),
+            RocStr::from("J"),
+        ]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_graphemes_empty() {
+    assert_evals_to!(
+        r#"Str.graphemes """#,
+        RocList::<RocStr>::from_slice(&[]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_split_at() {
+    assert_evals_to!(
+        r#"Str.splitAt "Roc👩‍👩‍👦‍👦!" 4"#,
+        // the result is a { before, after } record, and because of
+        // alphabetic ordering the fields here are flipped
+        (RocStr::from("!"), RocStr::from("Roc👩‍👩‍👦‍👦")),
+        (RocStr, RocStr)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_split_at_out_of_bounds() {
+    assert_evals_to!(
+        r#"Str.splitAt "Roc" 10"#,
+        (RocStr::from(""), RocStr::from("Roc")),
+        (RocStr, RocStr)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_to_upper() {
+    assert_evals_to!(
+        r#"Str.toUpper "Straße, ǆ and ﬃ""#,
+        RocStr::from("STRASSE, Ǆ AND FFI"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_to_lower() {
+    assert_evals_to!(
+        r#"Str.toLower "HELLO, ΟΔΟΣ ΣΑΣ.""#,
+        RocStr::from("hello, οδος σας."),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_to_nfc() {
+    assert_evals_to!(
+        r#"Str.toNfc "e\u(301) \u(1100)\u(1161)\u(11A8) \u(212B)""#,
+        RocStr::from("é 각 Å"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_to_nfd() {
+    assert_evals_to!(
+        r#"Str.toNfd "é 각 Å""#,
+        RocStr::from("e\u{301} \u{1100}\u{1161}\u{11A8} A\u{30A}"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_normalized_forms_compare_equal() {
+    assert_evals_to!(
+        r#"Str.toNfc "Ame\u(301)lie" == Str.toNfc (Str.toNfd "Amélie")"#,
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_contains() {
+    assert_evals_to!(r#"Str.contains "hullabaloo" "lab""#, true, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_contains_not_found() {
+    assert_evals_to!(r#"Str.contains "hullabaloo" "bulb""#, false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_index_of() {
+    assert_evals_to!(
+        r#"Str.indexOf "a string so long that it is heap-allocated" "heap" |> Result.withDefault 0"#,
+        28,
+        usize
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_index_of_not_found() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            when Str.indexOf "foo/bar" "?" is
+                Ok _ -> "Ok"
+                Err NotFound -> "NotFound"
+            "#
+        ),
+        RocStr::from("NotFound"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_slice_utf8_small() {
+    assert_evals_to!(
+        r#"Str.sliceUtf8 "hello" { start: 1, count: 3 } |> Result.withDefault """#,
+        RocStr::from("ell"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_slice_utf8_big() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            original = "a string so long that it is heap-allocated, and so is this slice"
+
+            Str.sliceUtf8 original { start: 17, count: 41 } |> Result.withDefault ""
+            "#
+        ),
+        RocStr::from("that it is heap-allocated, and so is this"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_slice_utf8_of_slice() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            original = "a string so long that it is heap-allocated, and so is this slice"
+            slice = Str.sliceUtf8 original { start: 17, count: 41 } |> Result.withDefault ""
+
+            Str.sliceUtf8 slice { start: 3, count: 30 } |> Result.withDefault ""
+            "#
+        ),
+        RocStr::from("t it is heap-allocated, and so"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_slice_utf8_outlives_original() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            original = Str.concat "a string so long that it is " "heap-allocated, and so is this slice"
+
+            when Str.sliceUtf8 original { start: 0, count: 42 } is
+                Ok slice -> Str.concat slice (Str.concat " | " original)
+                Err _ -> ""
+            "#
+        ),
+        RocStr::from(
+            "a string so long that it is heap-allocated | a string so long that it is heap-allocated, and so is this slice"
+        ),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_slice_utf8_out_of_bounds() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            when Str.sliceUtf8 "hello" { start: 4, count: 2 } is
+                Ok _ -> "Ok"
+                Err OutOfBounds -> "OutOfBounds"
+                Err NotCharBoundary -> "NotCharBoundary"
+            "#
+        ),
+        RocStr::from("OutOfBounds"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_slice_utf8_not_char_boundary() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            when Str.sliceUtf8 "héllo" { start: 0, count: 2 } is
+                Ok _ -> "Ok"
+                Err OutOfBounds -> "OutOfBounds"
+                Err NotCharBoundary -> "NotCharBoundary"
+            "#
+        ),
+        RocStr::from("NotCharBoundary"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_walk_utf8() {
+    assert_evals_to!(
+        r#"Str.walkUtf8 "abc" 0 (\total, byte -> total + Num.toNat byte)"#,
+        294,
+        usize
+    );
+}
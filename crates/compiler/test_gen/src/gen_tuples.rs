@@ -0,0 +1,224 @@
+#[cfg(feature = "gen-llvm")]
+use crate::helpers::llvm::assert_evals_to;
+
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
+
+#[cfg(feature = "gen-wasm")]
+use crate::helpers::wasm::assert_evals_to;
+
+use indoc::indoc;
+
+#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+use roc_std::RocStr;
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn basic_tuple() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (15, 17, 19).0
+                "#
+        ),
+        15,
+        i64
+    );
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (15, 17, 19).1
+                "#
+        ),
+        17,
+        i64
+    );
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (15, 17, 19).2
+                "#
+        ),
+        19,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn twice_tuple_access() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                x = (0x2, 0x3)
+
+                x.0 + x.1
+                "#
+        ),
+        5,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn tuple_with_str() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                ("hello", 42).0
+                "#
+        ),
+        RocStr::from("hello"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn let_with_tuple_pattern() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (x, y) = (0x2, 0x3)
+
+                x * y
+                "#
+        ),
+        6,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn let_with_nested_tuple_pattern() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (x, (y, _)) = (1, (2, 3))
+
+                x + y
+                "#
+        ),
+        3,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn when_on_tuple() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when (1, 2) is
+                    (1, x) -> x + 10
+                    (_, _) -> 0
+                "#
+        ),
+        12,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn when_on_tuple_falls_through() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when (3, 2) is
+                    (1, x) -> x
+                    (a, b) -> a * b
+                "#
+        ),
+        6,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn tuple_argument() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f = \(a, b) -> a - b
+
+                f (10, 3)
+                "#
+        ),
+        7,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn tuple_accessor_on_argument() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                second = \t -> t.1
+
+                second (1, 2, 3)
+                "#
+        ),
+        2,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn return_tuple() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                x = 4
+                y = 3
+
+                (x, y)
+                "#
+        ),
+        (4, 3),
+        (i64, i64)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn return_tuple_from_function() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                swap : (a, b) -> (b, a)
+                swap = \(x, y) -> (y, x)
+
+                swap (1, 2)
+                "#
+        ),
+        (2, 1),
+        (i64, i64)
+    );
+}
+
+#[test]
+// The dev backend can't store struct fields smaller than 8 bytes on the stack yet, see
+// StorageManager::copy_symbol_to_stack_offset
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn alignment_in_tuple() {
+    // Elements are laid out by alignment, then by position, like record fields.
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (7u8, 32, 1 == 1)
+                "#
+        ),
+        (32i64, 7u8, true),
+        (i64, u8, bool)
+    );
+}
@@ -55,6 +55,7 @@ pub fn helper(
         render: roc_reporting::report::RenderTarget::ColorTerminal,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
+        cache_dir: None,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -62,6 +62,15 @@ impl FromWasm32Memory for RocStr {
         let big_length = str_words[Builtin::WRAPPER_LEN as usize] as usize;
         let big_capacity = str_words[Builtin::WRAPPER_CAPACITY as usize] as usize;
 
+        // A seamless slice sets the top bit of its length, and has no capacity of its own
+        let is_seamless_slice = big_length & 0x8000_0000 != 0;
+        let big_length = big_length & 0x7fff_ffff;
+        let big_capacity = if is_seamless_slice {
+            big_length
+        } else {
+            big_capacity
+        };
+
         let last_byte = str_bytes[11];
         let is_small_str = last_byte >= 0x80;
 
@@ -87,6 +96,13 @@ impl<T: FromWasm32Memory + Clone> FromWasm32Memory for RocList<T> {
         let capacity =
             <u32 as FromWasm32Memory>::decode(memory, offset + 4 * Builtin::WRAPPER_CAPACITY);
 
+        // A seamless slice sets the top bit of its capacity, and has no capacity of its own
+        let capacity = if capacity & 0x8000_0000 != 0 {
+            length
+        } else {
+            capacity
+        };
+
         let mut items = Vec::with_capacity(length as usize);
 
         for i in 0..length {
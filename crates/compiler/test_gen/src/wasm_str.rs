@@ -1327,3 +1327,62 @@ fn str_walk_scalars() {
         RocList<char>
     );
 }
+
+#[test]
+fn str_slice_utf8_small_result() {
+    // 11 bytes is the longest small string in 32-bit memory
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.sliceUtf8 "JJJJJJJJJJJJJJJJ there" { start: 5, count: 11 }
+                |> Result.withDefault ""
+            "#
+        ),
+        RocStr::from("JJJJJJJJJJJ"),
+        RocStr
+    );
+}
+
+#[test]
+fn str_slice_utf8_seamless_slice() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.sliceUtf8 "JJJJJJJJJJJJJJJJ there" { start: 5, count: 12 }
+                |> Result.withDefault ""
+            "#
+        ),
+        RocStr::from("JJJJJJJJJJJ "),
+        RocStr
+    );
+}
+
+#[test]
+fn str_slice_utf8_slices_are_independent() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            original = Str.concat "JJJJJJJJJJJJJJJJ" " there, and more"
+            first = Str.sliceUtf8 original { start: 0, count: 16 } |> Result.withDefault ""
+            second = Str.sliceUtf8 original { start: 10, count: 16 } |> Result.withDefault ""
+
+            Str.joinWith [Str.concat first "!", second] "|"
+            "#
+        ),
+        RocStr::from("JJJJJJJJJJJJJJJJ!|JJJJJJ there, an"),
+        RocStr
+    );
+}
+
+#[test]
+fn str_walk_utf8() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.walkUtf8 "abcd" [] List.append
+            "#
+        ),
+        RocList::from_slice(&[97u8, 98, 99, 100]),
+        RocList<u8>
+    );
+}
@@ -99,6 +99,7 @@ fn compiles_to_ir(test_name: &str, src: &str) {
         threading: Threading::Single,
         render: roc_reporting::report::RenderTarget::Generic,
         exec_mode: ExecutionMode::Executable,
+        cache_dir: None,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -370,6 +371,15 @@ fn dict() {
     "#
 }
 
+#[mono_test]
+fn dict_number_literal_key() {
+    r#"
+    Dict.empty
+    |> Dict.insert 42 32
+    |> Dict.get 42
+    "#
+}
+
 #[mono_test]
 fn list_append_closure() {
     r#"
@@ -1325,10 +1335,10 @@ fn specialize_ability_call() {
         r#"
         app "test" provides [main] to "./platform"
 
-        Hash has
-            hash : a -> U64 | a has Hash
+        MHash has
+            hash : a -> U64 | a has MHash
 
-        Id := U64 has [Hash {hash}]
+        Id := U64 has [MHash {hash}]
 
         hash : Id -> U64
         hash = \@Id n -> n
@@ -2000,3 +2010,121 @@ fn unreachable_branch_is_eliminated_but_produces_lambda_specializations() {
         "#
     )
 }
+
+#[mono_test]
+fn tuple_let_destructure_and_access() {
+    r#"
+    t = (1u8, "hello", 2u64)
+    (a, _, c) = t
+
+    a + 1 + t.0 == Num.toU8 c
+    "#
+}
+
+#[mono_test]
+fn tuple_pattern_match() {
+    r#"
+    when (1u8, 2u8) is
+        (1, x) -> x
+        (_, _) -> 0
+    "#
+}
+
+#[mono_test]
+fn dbg_in_function() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        f = \x -> dbg (x + 1)
+
+        main = f 41
+        "#
+    )
+}
+
+#[mono_test]
+fn crash_in_function() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        f : Str -> Str
+        f = \msg ->
+            if Str.isEmpty msg then
+                crash "no message"
+            else
+                msg
+
+        main = f "hello"
+        "#
+    )
+}
+
+#[mono_test]
+fn num_to_bytes_be() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = Num.toBytesBe 0x1234u16
+        "#
+    )
+}
+
+#[mono_test]
+fn dec_round_to() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = Num.roundDecTo 2.345dec 2 HalfEven
+        "#
+    )
+}
+
+#[mono_test]
+fn str_split_at() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = Str.splitAt "hello" 2
+        "#
+    )
+}
+
+#[mono_test]
+fn gen_check_derived_record() {
+    indoc!(
+        r#"
+        app "test"
+            imports [Gen]
+            provides [main] to "./platform"
+
+        main =
+            when Gen.check Gen.arbitrary (\{ a, b } -> a < 10u8 || b) is
+                Ok {} -> 0u8
+                Err (Counterexample { a }) -> a
+        "#
+    )
+}
+
+#[mono_test]
+fn ability_value_member_of_other_module_as_argument() {
+    indoc!(
+        r#"
+        app "test"
+            imports [Decode, Json]
+            provides [main] to "./platform"
+
+        main =
+            result : Result U8 _
+            result = (Decode.decodeWith [49] Decode.decoder Json.fromUtf8).result
+
+            when result is
+                Ok n -> n
+                Err _ -> 0
+        "#
+    )
+}
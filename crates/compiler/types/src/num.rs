@@ -1,8 +1,9 @@
 use crate::subs::Variable;
+use serde::{Deserialize, Serialize};
 
 /// A bound placed on a number because of its literal value.
 /// e.g. `-5` cannot be unsigned, and 300 does not fit in a U8
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NumericRange {
     IntAtLeastSigned(IntLitWidth),
     IntAtLeastEitherSign(IntLitWidth),
@@ -36,6 +37,32 @@ impl NumericRange {
         width.signedness_and_width().1 >= at_least_width.signedness_and_width().1
     }
 
+    /// The width a number with this range is compiled to when nothing else fixes its type:
+    /// I64 if the range says the number will fit, otherwise the next-largest integer.
+    pub fn default_compilation_width(&self) -> IntLitWidth {
+        use NumericRange::*;
+
+        let (candidates, is_negative): (&[IntLitWidth], _) = match self {
+            IntAtLeastSigned(_) | NumAtLeastSigned(_) => {
+                (&[IntLitWidth::I64, IntLitWidth::I128], true)
+            }
+            IntAtLeastEitherSign(_) | NumAtLeastEitherSign(_) => (
+                &[
+                    IntLitWidth::I64,
+                    IntLitWidth::U64,
+                    IntLitWidth::I128,
+                    IntLitWidth::U128,
+                ],
+                false,
+            ),
+        };
+
+        *candidates
+            .iter()
+            .find(|candidate| candidate.is_superset(&self.width(), is_negative))
+            .expect("if number doesn't fit, should have been a type error")
+    }
+
     fn width(&self) -> IntLitWidth {
         use NumericRange::*;
         match self {
@@ -129,7 +156,7 @@ enum IntSignedness {
     Signed,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum IntLitWidth {
     U8,
     U16,
@@ -283,14 +310,14 @@ impl IntLitWidth {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum FloatWidth {
     Dec,
     F32,
     F64,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum SignDemand {
     /// Can be signed or unsigned.
     NoDemand,
@@ -299,7 +326,7 @@ pub enum SignDemand {
 }
 
 /// Describes a bound on the width of an integer.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum IntBound {
     /// There is no bound on the width.
     None,
@@ -312,13 +339,13 @@ pub enum IntBound {
     },
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum FloatBound {
     None,
     Exact(FloatWidth),
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum NumBound {
     None,
     /// Must be an integer of a certain size, or any float.
@@ -10,6 +10,7 @@ use std::fmt::Write;
 
 pub static WILDCARD: &str = "*";
 static EMPTY_RECORD: &str = "{}";
+static EMPTY_TUPLE: &str = "()";
 static EMPTY_TAG_UNION: &str = "[]";
 
 /// Requirements for parentheses.
@@ -255,6 +256,28 @@ fn find_names_needed(
                 find_under_alias,
             );
         }
+        Structure(Tuple(elems, ext_var)) => {
+            for index in elems.iter_variables() {
+                let var = subs[index];
+                find_names_needed(
+                    var,
+                    subs,
+                    roots,
+                    root_appearances,
+                    names_taken,
+                    find_under_alias,
+                );
+            }
+
+            find_names_needed(
+                *ext_var,
+                subs,
+                roots,
+                root_appearances,
+                names_taken,
+                find_under_alias,
+            );
+        }
         Structure(TagUnion(tags, ext_var)) => {
             for slice_index in tags.variables() {
                 let slice = subs[slice_index];
@@ -402,7 +425,11 @@ fn find_names_needed(
                 find_under_alias,
             );
         }
-        Error | Structure(Erroneous(_)) | Structure(EmptyRecord) | Structure(EmptyTagUnion) => {
+        Error
+        | Structure(Erroneous(_))
+        | Structure(EmptyRecord)
+        | Structure(EmptyTuple)
+        | Structure(EmptyTagUnion) => {
             // Errors and empty records don't need names.
         }
     }
@@ -511,9 +538,9 @@ fn set_root_name(root: Variable, name: Lowercase, subs: &mut Subs) {
             let content = FlexVar(Some(name_index));
             subs.set_content(root, content);
         }
-        &FlexAbleVar(_, ability) => {
+        &FlexAbleVar(_, abilities) => {
             let name_index = SubsIndex::push_new(&mut subs.field_names, name);
-            let content = FlexAbleVar(Some(name_index), ability);
+            let content = FlexAbleVar(Some(name_index), abilities);
             subs.set_content(root, content);
         }
         RecursionVar {
@@ -541,7 +568,7 @@ fn set_root_name(root: Variable, name: Lowercase, subs: &mut Subs) {
 
 #[derive(Default)]
 struct Context<'a> {
-    able_variables: Vec<(&'a str, Symbol)>,
+    able_variables: Vec<(&'a str, &'a [Symbol])>,
     recursion_structs_to_expand: Vec<Variable>,
 }
 
@@ -568,11 +595,16 @@ fn content_to_string(
 
     ctx.able_variables.sort();
     ctx.able_variables.dedup();
-    for (i, (var, ability)) in ctx.able_variables.into_iter().enumerate() {
+    for (i, (var, abilities)) in ctx.able_variables.into_iter().enumerate() {
         buf.push_str(if i == 0 { " | " } else { ", " });
         buf.push_str(var);
         buf.push_str(" has ");
-        write_symbol(&env, ability, &mut buf);
+        for (j, ability) in abilities.iter().enumerate() {
+            if j > 0 {
+                buf.push_str(" & ");
+            }
+            write_symbol(&env, *ability, &mut buf);
+        }
     }
 
     buf
@@ -621,16 +653,18 @@ fn write_content<'a>(
             let name = &subs.field_names[name_index.index as usize];
             buf.push_str(name.as_str())
         }
-        FlexAbleVar(opt_name_index, ability) => {
+        FlexAbleVar(opt_name_index, abilities) => {
             let name = opt_name_index
                 .map(|name_index| subs.field_names[name_index.index as usize].as_str())
                 .unwrap_or(WILDCARD);
-            ctx.able_variables.push((name, *ability));
+            ctx.able_variables
+                .push((name, subs.get_subs_slice(*abilities)));
             buf.push_str(name);
         }
-        RigidAbleVar(name_index, ability) => {
+        RigidAbleVar(name_index, abilities) => {
             let name = subs.field_names[name_index.index as usize].as_str();
-            ctx.able_variables.push((name, *ability));
+            ctx.able_variables
+                .push((name, subs.get_subs_slice(*abilities)));
             buf.push_str(name);
         }
         RecursionVar {
@@ -1062,6 +1096,7 @@ fn write_flat_type<'a>(
             parens,
         ),
         EmptyRecord => buf.push_str(EMPTY_RECORD),
+        EmptyTuple => buf.push_str(EMPTY_TUPLE),
         EmptyTagUnion => buf.push_str(EMPTY_TAG_UNION),
         Func(args, closure, ret) => write_fn(
             env,
@@ -1137,6 +1172,54 @@ fn write_flat_type<'a>(
                 }
             }
         }
+        Tuple(elems, ext_var) => {
+            // If the `ext` has concrete elements (e.g. ( I64 )( Bool )), merge them
+            let (sorted_elems, ext_var) = elems.sorted_iterator_and_ext(subs, *ext_var);
+
+            buf.push_str("( ");
+
+            // An open tuple may only constrain some of its elements, e.g. the type of `t` in
+            // `t.1`; the unconstrained elements before the last known one are written as `_`
+            let mut next_index = 0;
+
+            for (index, var) in sorted_elems {
+                while next_index < index {
+                    if next_index > 0 {
+                        buf.push_str(", ");
+                    }
+
+                    buf.push('_');
+                    next_index += 1;
+                }
+
+                if index > 0 {
+                    buf.push_str(", ");
+                }
+
+                write_content(
+                    env,
+                    ctx,
+                    subs.get_content_without_compacting(var),
+                    subs,
+                    buf,
+                    Parens::Unnecessary,
+                );
+
+                next_index = index + 1;
+            }
+
+            buf.push_str(" )");
+
+            match subs.get_content_without_compacting(ext_var) {
+                Content::Structure(EmptyTuple) => {
+                    // This is a closed tuple. We're done!
+                }
+                content => {
+                    // This is an open tuple, so print the variable right after the ')'
+                    write_content(env, ctx, content, subs, buf, parens)
+                }
+            }
+        }
         TagUnion(tags, ext_var) => {
             buf.push('[');
 
@@ -1,12 +1,14 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 use crate::types::{
-    name_type_var, AliasKind, ErrorType, Problem, RecordField, RecordFieldsError, TypeExt, Uls,
+    name_type_var, AbilitySet, AliasKind, ErrorType, Problem, RecordField, RecordFieldsError,
+    TypeExt, Uls,
 };
 use roc_collections::all::{FnvMap, ImMap, ImSet, MutSet, SendMap};
 use roc_collections::{VecMap, VecSet};
 use roc_error_macros::internal_error;
 use roc_module::ident::{Lowercase, TagName, Uppercase};
 use roc_module::symbol::{ModuleId, Symbol};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::iter::{once, Iterator, Map};
 
@@ -19,6 +21,7 @@ roc_error_macros::assert_sizeof_all!(Descriptor, 5 * 8 + 4);
 roc_error_macros::assert_sizeof_all!(FlatType, 3 * 8);
 roc_error_macros::assert_sizeof_all!(UnionTags, 12);
 roc_error_macros::assert_sizeof_all!(RecordFields, 2 * 8);
+roc_error_macros::assert_sizeof_all!(TupleElems, 12);
 
 roc_error_macros::assert_sizeof_aarch64!(Problem, 6 * 8);
 roc_error_macros::assert_sizeof_wasm!(Problem, 32);
@@ -72,9 +75,10 @@ struct SubsHeader {
     utable: u64,
     variables: u64,
     tag_names: u64,
-    closure_names: u64,
+    symbol_names: u64,
     field_names: u64,
     record_fields: u64,
+    tuple_elem_indices: u64,
     variable_slices: u64,
     unspecialized_lambda_sets: u64,
     exposed_vars_by_symbol: u64,
@@ -90,9 +94,10 @@ impl SubsHeader {
             utable: subs.utable.len() as u64,
             variables: subs.variables.len() as u64,
             tag_names: subs.tag_names.len() as u64,
-            closure_names: subs.closure_names.len() as u64,
+            symbol_names: subs.symbol_names.len() as u64,
             field_names: subs.field_names.len() as u64,
             record_fields: subs.record_fields.len() as u64,
+            tuple_elem_indices: subs.tuple_elem_indices.len() as u64,
             variable_slices: subs.variable_slices.len() as u64,
             unspecialized_lambda_sets: subs.unspecialized_lambda_sets.len() as u64,
             exposed_vars_by_symbol: exposed_vars_by_symbol as u64,
@@ -139,9 +144,10 @@ impl Subs {
 
         written = Self::serialize_slice(&self.variables, writer, written)?;
         written = Self::serialize_tag_names(&self.tag_names, writer, written)?;
-        written = Self::serialize_slice(&self.closure_names, writer, written)?;
+        written = Self::serialize_slice(&self.symbol_names, writer, written)?;
         written = Self::serialize_field_names(&self.field_names, writer, written)?;
         written = Self::serialize_slice(&self.record_fields, writer, written)?;
+        written = Self::serialize_slice(&self.tuple_elem_indices, writer, written)?;
         written = Self::serialize_slice(&self.variable_slices, writer, written)?;
         written = Self::serialize_slice(&self.unspecialized_lambda_sets, writer, written)?;
         written = Self::serialize_slice(exposed_vars_by_symbol, writer, written)?;
@@ -219,12 +225,14 @@ impl Subs {
         let (variables, offset) = Self::deserialize_slice(bytes, header.variables as usize, offset);
         let (tag_names, offset) =
             Self::deserialize_tag_names(bytes, header.tag_names as usize, offset);
-        let (closure_names, offset) =
-            Self::deserialize_slice(bytes, header.closure_names as usize, offset);
+        let (symbol_names, offset) =
+            Self::deserialize_slice(bytes, header.symbol_names as usize, offset);
         let (field_names, offset) =
             Self::deserialize_field_names(bytes, header.field_names as usize, offset);
         let (record_fields, offset) =
             Self::deserialize_slice(bytes, header.record_fields as usize, offset);
+        let (tuple_elem_indices, offset) =
+            Self::deserialize_slice(bytes, header.tuple_elem_indices as usize, offset);
         let (variable_slices, offset) =
             Self::deserialize_slice(bytes, header.variable_slices as usize, offset);
         let (unspecialized_lambda_sets, offset) =
@@ -237,9 +245,10 @@ impl Subs {
                 utable,
                 variables: variables.to_vec(),
                 tag_names: tag_names.to_vec(),
-                closure_names: closure_names.to_vec(),
+                symbol_names: symbol_names.to_vec(),
                 field_names,
                 record_fields: record_fields.to_vec(),
+                tuple_elem_indices: tuple_elem_indices.to_vec(),
                 variable_slices: variable_slices.to_vec(),
                 unspecialized_lambda_sets: unspecialized_lambda_sets.to_vec(),
                 tag_name_cache: Default::default(),
@@ -386,9 +395,10 @@ pub struct Subs {
     utable: UnificationTable,
     pub variables: Vec<Variable>,
     pub tag_names: Vec<TagName>,
-    pub closure_names: Vec<Symbol>,
+    pub symbol_names: Vec<Symbol>,
     pub field_names: Vec<Lowercase>,
     pub record_fields: Vec<RecordField<()>>,
+    pub tuple_elem_indices: Vec<usize>,
     pub variable_slices: Vec<VariableSubsSlice>,
     pub unspecialized_lambda_sets: Vec<Uls>,
     pub tag_name_cache: TagNameCache,
@@ -481,13 +491,13 @@ impl std::ops::Index<SubsIndex<Symbol>> for Subs {
     type Output = Symbol;
 
     fn index(&self, index: SubsIndex<Symbol>) -> &Self::Output {
-        &self.closure_names[index.index as usize]
+        &self.symbol_names[index.index as usize]
     }
 }
 
 impl std::ops::IndexMut<SubsIndex<Symbol>> for Subs {
     fn index_mut(&mut self, index: SubsIndex<Symbol>) -> &mut Self::Output {
-        &mut self.closure_names[index.index as usize]
+        &mut self.symbol_names[index.index as usize]
     }
 }
 
@@ -525,6 +535,14 @@ impl std::ops::IndexMut<SubsIndex<RecordField<()>>> for Subs {
     }
 }
 
+impl std::ops::Index<SubsIndex<usize>> for Subs {
+    type Output = usize;
+
+    fn index(&self, index: SubsIndex<usize>) -> &Self::Output {
+        &self.tuple_elem_indices[index.index as usize]
+    }
+}
+
 impl std::ops::Index<SubsIndex<VariableSubsSlice>> for Subs {
     type Output = VariableSubsSlice;
 
@@ -736,6 +754,12 @@ impl GetSubsSlice<RecordField<()>> for Subs {
     }
 }
 
+impl GetSubsSlice<usize> for Subs {
+    fn get_subs_slice(&self, subs_slice: SubsSlice<usize>) -> &[usize] {
+        subs_slice.get_slice(&self.tuple_elem_indices)
+    }
+}
+
 impl GetSubsSlice<Lowercase> for Subs {
     fn get_subs_slice(&self, subs_slice: SubsSlice<Lowercase>) -> &[Lowercase] {
         subs_slice.get_slice(&self.field_names)
@@ -750,7 +774,7 @@ impl GetSubsSlice<TagName> for Subs {
 
 impl GetSubsSlice<Symbol> for Subs {
     fn get_subs_slice(&self, subs_slice: SubsSlice<Symbol>) -> &[Symbol] {
-        subs_slice.get_slice(&self.closure_names)
+        subs_slice.get_slice(&self.symbol_names)
     }
 }
 
@@ -809,15 +833,19 @@ fn subs_fmt_content(this: &Content, subs: &Subs, f: &mut fmt::Formatter) -> fmt:
             };
             write!(f, "Flex({})", name)
         }
-        Content::FlexAbleVar(name, symbol) => {
+        Content::FlexAbleVar(name, abilities) => {
             let name = match name {
                 Some(index) => subs[*index].as_str(),
                 None => "_",
             };
-            write!(f, "FlexAble({}, {:?})", name, symbol)
+            let abilities = subs.get_subs_slice(*abilities);
+            write!(f, "FlexAble({}, {:?})", name, abilities)
         }
         Content::RigidVar(name) => write!(f, "Rigid({:?})", name),
-        Content::RigidAbleVar(name, symbol) => write!(f, "RigidAble({:?}, {:?})", name, symbol),
+        Content::RigidAbleVar(name, abilities) => {
+            let abilities = subs.get_subs_slice(*abilities);
+            write!(f, "RigidAble({:?}, {:?})", name, abilities)
+        }
         Content::RecursionVar {
             structure,
             opt_name,
@@ -943,6 +971,21 @@ fn subs_fmt_flat_type(this: &FlatType, subs: &Subs, f: &mut fmt::Formatter) -> f
 
             write!(f, "}}<{:?}>", new_ext)
         }
+        FlatType::Tuple(elems, ext) => {
+            write!(f, "( ")?;
+
+            let (it, new_ext) = elems.sorted_iterator_and_ext(subs, *ext);
+            for (index, var) in it {
+                write!(
+                    f,
+                    "{:?}: {:?}, ",
+                    index,
+                    SubsFmtContent(subs.get_content_without_compacting(var), subs)
+                )?;
+            }
+
+            write!(f, ")<{:?}>", new_ext)
+        }
         FlatType::TagUnion(tags, ext) => {
             write!(f, "[")?;
 
@@ -983,6 +1026,7 @@ fn subs_fmt_flat_type(this: &FlatType, subs: &Subs, f: &mut fmt::Formatter) -> f
         }
         FlatType::Erroneous(e) => write!(f, "Erroneous({:?})", e),
         FlatType::EmptyRecord => write!(f, "EmptyRecord"),
+        FlatType::EmptyTuple => write!(f, "EmptyTuple"),
         FlatType::EmptyTagUnion => write!(f, "EmptyTagUnion"),
     }
 }
@@ -1050,7 +1094,7 @@ impl VarStore {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct OptVariable(u32);
 
 impl OptVariable {
@@ -1125,7 +1169,7 @@ impl From<OptVariable> for Option<Variable> {
 }
 
 /// Marks whether a when expression is exhaustive using a variable.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct ExhaustiveMark(Variable);
 
 impl ExhaustiveMark {
@@ -1149,7 +1193,7 @@ impl ExhaustiveMark {
 }
 
 /// Marks whether a when branch is redundant using a variable.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RedundantMark(Variable);
 
 impl RedundantMark {
@@ -1180,7 +1224,7 @@ pub fn new_marks(var_store: &mut VarStore) -> (RedundantMark, ExhaustiveMark) {
 }
 
 /// Marks whether a recursive let-cycle was determined to be illegal during solving.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IllegalCycleMark(OptVariable);
 
 impl IllegalCycleMark {
@@ -1209,7 +1253,7 @@ impl IllegalCycleMark {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Variable(u32);
 
 macro_rules! define_const_var {
@@ -1703,9 +1747,10 @@ impl Subs {
             utable: UnificationTable::default(),
             variables: Vec::new(),
             tag_names,
-            closure_names: Vec::new(),
+            symbol_names: Vec::new(),
             field_names: Vec::new(),
             record_fields: Vec::new(),
+            tuple_elem_indices: Vec::new(),
             // store an empty slice at the first position
             // used for "TagOrFunction"
             variable_slices: vec![VariableSubsSlice::default()],
@@ -1790,6 +1835,16 @@ impl Subs {
         self.fresh(Descriptor::from(unnamed_flex_var()))
     }
 
+    /// Stores a set of abilities, for use in a [Content::FlexAbleVar] or [Content::RigidAbleVar].
+    pub fn push_abilities(&mut self, abilities: &AbilitySet) -> SubsSlice<Symbol> {
+        SubsSlice::extend_new(&mut self.symbol_names, abilities.sorted_iter().copied())
+    }
+
+    /// Reads back a set of abilities stored with [Subs::push_abilities].
+    pub fn get_abilities(&self, abilities: SubsSlice<Symbol>) -> AbilitySet {
+        AbilitySet::from_iter(self.get_subs_slice(abilities).iter().copied())
+    }
+
     pub fn rigid_var(&mut self, var: Variable, name: Lowercase) {
         let name_index = SubsIndex::push_new(&mut self.field_names, name);
         let content = Content::RigidVar(name_index);
@@ -1798,9 +1853,10 @@ impl Subs {
         self.set(var, desc);
     }
 
-    pub fn rigid_able_var(&mut self, var: Variable, name: Lowercase, ability: Symbol) {
+    pub fn rigid_able_var(&mut self, var: Variable, name: Lowercase, abilities: &AbilitySet) {
         let name_index = SubsIndex::push_new(&mut self.field_names, name);
-        let content = Content::RigidAbleVar(name_index, ability);
+        let abilities = self.push_abilities(abilities);
+        let content = Content::RigidAbleVar(name_index, abilities);
         let desc = Descriptor::from(content);
 
         self.set(var, desc);
@@ -2248,12 +2304,14 @@ pub enum Content {
     FlexVar(Option<SubsIndex<Lowercase>>),
     /// name given in a user-written annotation
     RigidVar(SubsIndex<Lowercase>),
-    /// Like a [Self::FlexVar], but is also bound to an ability.
+    /// Like a [Self::FlexVar], but is also bound to one or more abilities.
     /// This can only happen when unified with a [Self::RigidAbleVar].
-    FlexAbleVar(Option<SubsIndex<Lowercase>>, Symbol),
-    /// Like a [Self::RigidVar], but is also bound to an ability.
-    /// For example, "a has Hash".
-    RigidAbleVar(SubsIndex<Lowercase>, Symbol),
+    /// The abilities are sorted and have no duplicates.
+    FlexAbleVar(Option<SubsIndex<Lowercase>>, SubsSlice<Symbol>),
+    /// Like a [Self::RigidVar], but is also bound to one or more abilities.
+    /// For example, "a has Hash & Eq".
+    /// The abilities are sorted and have no duplicates.
+    RigidAbleVar(SubsIndex<Lowercase>, SubsSlice<Symbol>),
     /// name given to a recursion variable
     RecursionVar {
         structure: Variable,
@@ -2423,11 +2481,13 @@ pub enum FlatType {
     Apply(Symbol, VariableSubsSlice),
     Func(VariableSubsSlice, Variable, Variable),
     Record(RecordFields, Variable),
+    Tuple(TupleElems, Variable),
     TagUnion(UnionTags, Variable),
     FunctionOrTagUnion(SubsIndex<TagName>, Symbol, Variable),
     RecursiveTagUnion(Variable, UnionTags, Variable),
     Erroneous(SubsIndex<Problem>),
     EmptyRecord,
+    EmptyTuple,
     EmptyTagUnion,
 }
 
@@ -2552,15 +2612,15 @@ impl Label for Symbol {
         subs.get_subs_slice(slice)
     }
     fn push_new(subs: &mut Subs, name: Self) -> SubsIndex<Self> {
-        SubsIndex::push_new(&mut subs.closure_names, name)
+        SubsIndex::push_new(&mut subs.symbol_names, name)
     }
     fn extend_new(subs: &mut Subs, slice: impl IntoIterator<Item = Self>) -> SubsSlice<Self> {
-        SubsSlice::extend_new(&mut subs.closure_names, slice)
+        SubsSlice::extend_new(&mut subs.symbol_names, slice)
     }
     fn reserve(subs: &mut Subs, size_hint: usize) -> u32 {
-        let closure_names_start = subs.closure_names.len() as u32;
-        subs.closure_names.reserve(size_hint);
-        closure_names_start
+        let symbol_names_start = subs.symbol_names.len() as u32;
+        subs.symbol_names.reserve(size_hint);
+        symbol_names_start
     }
 }
 
@@ -3102,6 +3162,140 @@ impl RecordFields {
     }
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct TupleElems {
+    pub length: u16,
+    pub elem_index_start: u32,
+    pub variables_start: u32,
+}
+
+impl TupleElems {
+    pub const fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            length: 0,
+            elem_index_start: 0,
+            variables_start: 0,
+        }
+    }
+
+    pub const fn variables(&self) -> SubsSlice<Variable> {
+        SubsSlice::new(self.variables_start, self.length)
+    }
+
+    pub const fn elem_indices(&self) -> SubsSlice<usize> {
+        SubsSlice::new(self.elem_index_start, self.length)
+    }
+
+    pub fn iter_variables(&self) -> impl Iterator<Item = SubsIndex<Variable>> {
+        self.variables().into_iter()
+    }
+
+    /// Elements must be given in order of their index
+    pub fn insert_into_subs<I>(subs: &mut Subs, input: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, Variable)>,
+    {
+        let elem_index_start = subs.tuple_elem_indices.len() as u32;
+        let variables_start = subs.variables.len() as u32;
+
+        let it = input.into_iter();
+        let size_hint = it.size_hint().0;
+
+        subs.variables.reserve(size_hint);
+        subs.tuple_elem_indices.reserve(size_hint);
+
+        let mut length = 0;
+        for (index, var) in it {
+            subs.tuple_elem_indices.push(index);
+            subs.variables.push(var);
+
+            length += 1;
+        }
+
+        TupleElems {
+            length,
+            elem_index_start,
+            variables_start,
+        }
+    }
+
+    /// get an iterator over the elements of this tuple type, sorted by index
+    ///
+    /// Like [RecordFields::sorted_iterator_and_ext], this reads the elements directly from Subs
+    /// when the tuple is closed, and otherwise chases the ext var.
+    pub fn sorted_iterator_and_ext<'a>(
+        &'_ self,
+        subs: &'a Subs,
+        ext: Variable,
+    ) -> (Box<dyn Iterator<Item = (usize, Variable)> + 'a>, Variable) {
+        if is_empty_tuple(subs, ext) {
+            (
+                Box::new(self.iter_all().map(move |(i1, i2)| (subs[i1], subs[i2]))),
+                ext,
+            )
+        } else {
+            let tuple_structure = crate::types::gather_tuple_elems(subs, *self, ext)
+                .expect("Something ended up weird in this tuple type");
+
+            (
+                Box::new(tuple_structure.elems.into_iter()),
+                tuple_structure.ext,
+            )
+        }
+    }
+
+    pub fn sorted_iterator<'a>(
+        &'_ self,
+        subs: &'a Subs,
+        ext: Variable,
+    ) -> Box<dyn Iterator<Item = (usize, Variable)> + 'a> {
+        self.sorted_iterator_and_ext(subs, ext).0
+    }
+
+    pub fn iter_all(&self) -> impl Iterator<Item = (SubsIndex<usize>, SubsIndex<Variable>)> {
+        let helper = |start| start..(start + self.length as u32);
+
+        let range1 = helper(self.elem_index_start);
+        let range2 = helper(self.variables_start);
+
+        range1
+            .zip(range2)
+            .map(|(i1, i2)| (SubsIndex::new(i1), SubsIndex::new(i2)))
+    }
+}
+
+fn is_empty_tuple(subs: &Subs, mut var: Variable) -> bool {
+    use crate::subs::Content::*;
+    use crate::subs::FlatType::*;
+
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Structure(EmptyTuple) => return true,
+            Structure(Tuple(sub_elems, sub_ext)) => {
+                if !sub_elems.is_empty() {
+                    return false;
+                }
+
+                var = *sub_ext;
+            }
+
+            Alias(_, _, actual_var, _) => {
+                var = *actual_var;
+            }
+
+            _ => return false,
+        }
+    }
+}
+
 fn is_empty_record(subs: &Subs, mut var: Variable) -> bool {
     use crate::subs::Content::*;
     use crate::subs::FlatType::*;
@@ -3169,6 +3363,10 @@ fn occurs(
                         let it = once(ext_var).chain(subs.get_subs_slice(slice).iter());
                         short_circuit(subs, root_var, &new_seen, it)
                     }
+                    Tuple(elems, ext_var) => {
+                        let it = once(ext_var).chain(subs.get_subs_slice(elems.variables()).iter());
+                        short_circuit(subs, root_var, &new_seen, it)
+                    }
                     TagUnion(tags, ext_var) => {
                         occurs_union(subs, root_var, &new_seen, tags)?;
 
@@ -3183,7 +3381,7 @@ fn occurs(
 
                         short_circuit_help(subs, root_var, &new_seen, *ext_var)
                     }
-                    EmptyRecord | EmptyTagUnion | Erroneous(_) => Ok(()),
+                    EmptyRecord | EmptyTuple | EmptyTagUnion | Erroneous(_) => Ok(()),
                 }
             }
             Alias(_, args, _, _) => {
@@ -3353,8 +3551,19 @@ fn explicit_substitute(
 
                         subs.set_content(in_var, Structure(Record(vars_by_field, new_ext_var)));
                     }
+                    Tuple(elems, ext_var) => {
+                        let new_ext_var = explicit_substitute(subs, from, to, ext_var, seen);
+
+                        for index in elems.iter_variables() {
+                            let var = subs[index];
+                            let new_var = explicit_substitute(subs, from, to, var, seen);
+                            subs[index] = new_var;
+                        }
 
-                    EmptyRecord | EmptyTagUnion | Erroneous(_) => {}
+                        subs.set_content(in_var, Structure(Tuple(elems, new_ext_var)));
+                    }
+
+                    EmptyRecord | EmptyTuple | EmptyTagUnion | Erroneous(_) => {}
                 }
 
                 in_var
@@ -3537,9 +3746,10 @@ fn get_var_names(
                     accum
                 }
 
-                FlatType::EmptyRecord | FlatType::EmptyTagUnion | FlatType::Erroneous(_) => {
-                    taken_names
-                }
+                FlatType::EmptyRecord
+                | FlatType::EmptyTuple
+                | FlatType::EmptyTagUnion
+                | FlatType::Erroneous(_) => taken_names,
 
                 FlatType::Record(vars_by_field, ext_var) => {
                     let mut accum = get_var_names(subs, ext_var, taken_names);
@@ -3552,6 +3762,17 @@ fn get_var_names(
 
                     accum
                 }
+                FlatType::Tuple(elems, ext_var) => {
+                    let mut accum = get_var_names(subs, ext_var, taken_names);
+
+                    for var_index in elems.iter_variables() {
+                        let elem_var = subs[var_index];
+
+                        accum = get_var_names(subs, elem_var, accum)
+                    }
+
+                    accum
+                }
                 FlatType::TagUnion(tags, ext_var) => {
                     let taken_names = get_var_names(subs, ext_var, taken_names);
                     get_var_names_union(subs, tags, taken_names)
@@ -3688,7 +3909,8 @@ fn content_to_err_type(
             ErrorType::RigidVar(name)
         }
 
-        FlexAbleVar(opt_name, ability) => {
+        FlexAbleVar(opt_name, abilities) => {
+            let abilities = subs.get_abilities(abilities);
             let name = match opt_name {
                 Some(name_index) => subs.field_names[name_index.index as usize].clone(),
                 None => {
@@ -3702,12 +3924,13 @@ fn content_to_err_type(
                 }
             };
 
-            ErrorType::FlexAbleVar(name, ability)
+            ErrorType::FlexAbleVar(name, abilities)
         }
 
-        RigidAbleVar(name_index, ability) => {
+        RigidAbleVar(name_index, abilities) => {
             let name = subs.field_names[name_index.index as usize].clone();
-            ErrorType::RigidAbleVar(name, ability)
+            let abilities = subs.get_abilities(abilities);
+            ErrorType::RigidAbleVar(name, abilities)
         }
 
         RecursionVar {
@@ -3816,8 +4039,36 @@ fn flat_type_to_err_type(
         }
 
         EmptyRecord => ErrorType::Record(SendMap::default(), TypeExt::Closed),
+        EmptyTuple => ErrorType::Tuple(Vec::new(), TypeExt::Closed),
         EmptyTagUnion => ErrorType::TagUnion(SendMap::default(), TypeExt::Closed),
 
+        Tuple(elems, ext_var) => {
+            let mut err_elems = Vec::with_capacity(elems.len());
+
+            for (i1, i2) in elems.iter_all() {
+                let index = subs[i1];
+                let var = subs[i2];
+
+                err_elems.push((index, var_to_err_type(subs, state, var)));
+            }
+
+            match var_to_err_type(subs, state, ext_var).unwrap_structural_alias() {
+                ErrorType::Tuple(sub_elems, sub_ext) => {
+                    err_elems.extend(sub_elems);
+                    err_elems.sort_by_key(|(index, _)| *index);
+
+                    ErrorType::Tuple(err_elems, sub_ext)
+                }
+
+                ErrorType::FlexVar(var) => ErrorType::Tuple(err_elems, TypeExt::FlexOpen(var)),
+
+                ErrorType::RigidVar(var) => ErrorType::Tuple(err_elems, TypeExt::RigidOpen(var)),
+
+                other =>
+                    panic!("Tried to convert a tuple extension to an error, but the tuple extension had the ErrorType of {:?}", other)
+            }
+        }
+
         Record(vars_by_field, ext_var) => {
             let mut err_fields = SendMap::default();
 
@@ -4011,9 +4262,10 @@ struct StorageSubsOffsets {
     utable: u32,
     variables: u32,
     tag_names: u32,
-    closure_names: u32,
+    symbol_names: u32,
     field_names: u32,
     record_fields: u32,
+    tuple_elem_indices: u32,
     variable_slices: u32,
     unspecialized_lambda_sets: u32,
     problems: u32,
@@ -4095,9 +4347,10 @@ impl StorageSubs {
             utable: self.subs.utable.len() as u32,
             variables: self.subs.variables.len() as u32,
             tag_names: self.subs.tag_names.len() as u32,
-            closure_names: self.subs.closure_names.len() as u32,
+            symbol_names: self.subs.symbol_names.len() as u32,
             field_names: self.subs.field_names.len() as u32,
             record_fields: self.subs.record_fields.len() as u32,
+            tuple_elem_indices: self.subs.tuple_elem_indices.len() as u32,
             variable_slices: self.subs.variable_slices.len() as u32,
             unspecialized_lambda_sets: self.subs.unspecialized_lambda_sets.len() as u32,
             problems: self.subs.problems.len() as u32,
@@ -4107,9 +4360,10 @@ impl StorageSubs {
             utable: (target.utable.len() - Variable::NUM_RESERVED_VARS) as u32,
             variables: target.variables.len() as u32,
             tag_names: target.tag_names.len() as u32,
-            closure_names: target.closure_names.len() as u32,
+            symbol_names: target.symbol_names.len() as u32,
             field_names: target.field_names.len() as u32,
             record_fields: target.record_fields.len() as u32,
+            tuple_elem_indices: target.tuple_elem_indices.len() as u32,
             variable_slices: target.variable_slices.len() as u32,
             unspecialized_lambda_sets: target.unspecialized_lambda_sets.len() as u32,
             problems: target.problems.len() as u32,
@@ -4155,9 +4409,12 @@ impl StorageSubs {
         );
 
         target.tag_names.extend(self.subs.tag_names);
-        target.closure_names.extend(self.subs.closure_names);
+        target.symbol_names.extend(self.subs.symbol_names);
         target.field_names.extend(self.subs.field_names);
         target.record_fields.extend(self.subs.record_fields);
+        target
+            .tuple_elem_indices
+            .extend(self.subs.tuple_elem_indices);
         target
             .unspecialized_lambda_sets
             .extend(self.subs.unspecialized_lambda_sets);
@@ -4174,8 +4431,8 @@ impl StorageSubs {
         );
 
         debug_assert_eq!(
-            target.closure_names.len(),
-            (self_offsets.closure_names + offsets.closure_names) as usize
+            target.symbol_names.len(),
+            (self_offsets.symbol_names + offsets.symbol_names) as usize
         );
 
         move |v| {
@@ -4198,6 +4455,10 @@ impl StorageSubs {
                 Self::offset_record_fields(offsets, *record_fields),
                 Self::offset_variable(offsets, *ext),
             ),
+            FlatType::Tuple(elems, ext) => FlatType::Tuple(
+                Self::offset_tuple_elems(offsets, *elems),
+                Self::offset_variable(offsets, *ext),
+            ),
             FlatType::TagUnion(union_tags, ext) => FlatType::TagUnion(
                 Self::offset_tag_union(offsets, *union_tags),
                 Self::offset_variable(offsets, *ext),
@@ -4216,6 +4477,7 @@ impl StorageSubs {
                 FlatType::Erroneous(Self::offset_problem(offsets, *problem))
             }
             FlatType::EmptyRecord => FlatType::EmptyRecord,
+            FlatType::EmptyTuple => FlatType::EmptyTuple,
             FlatType::EmptyTagUnion => FlatType::EmptyTagUnion,
         }
     }
@@ -4226,8 +4488,12 @@ impl StorageSubs {
         match content {
             FlexVar(opt_name) => FlexVar(*opt_name),
             RigidVar(name) => RigidVar(*name),
-            FlexAbleVar(opt_name, ability) => FlexAbleVar(*opt_name, *ability),
-            RigidAbleVar(name, ability) => RigidAbleVar(*name, *ability),
+            FlexAbleVar(opt_name, abilities) => {
+                FlexAbleVar(*opt_name, Self::offset_symbol_slice(offsets, *abilities))
+            }
+            RigidAbleVar(name, abilities) => {
+                RigidAbleVar(*name, Self::offset_symbol_slice(offsets, *abilities))
+            }
             RecursionVar {
                 structure,
                 opt_name,
@@ -4278,7 +4544,7 @@ impl StorageSubs {
         offsets: &StorageSubsOffsets,
         mut union_lambdas: UnionLambdas,
     ) -> UnionLambdas {
-        union_lambdas.labels_start += offsets.closure_names;
+        union_lambdas.labels_start += offsets.symbol_names;
         union_lambdas.variables_start += offsets.variable_slices;
 
         union_lambdas
@@ -4295,6 +4561,13 @@ impl StorageSubs {
         record_fields
     }
 
+    fn offset_tuple_elems(offsets: &StorageSubsOffsets, mut elems: TupleElems) -> TupleElems {
+        elems.elem_index_start += offsets.tuple_elem_indices;
+        elems.variables_start += offsets.variables;
+
+        elems
+    }
+
     fn offset_tag_name_index(
         offsets: &StorageSubsOffsets,
         mut tag_name: SubsIndex<TagName>,
@@ -4322,6 +4595,15 @@ impl StorageSubs {
         slice
     }
 
+    fn offset_symbol_slice(
+        offsets: &StorageSubsOffsets,
+        mut slice: SubsSlice<Symbol>,
+    ) -> SubsSlice<Symbol> {
+        slice.start += offsets.symbol_names;
+
+        slice
+    }
+
     fn offset_uls_slice(offsets: &StorageSubsOffsets, mut slice: SubsSlice<Uls>) -> SubsSlice<Uls> {
         slice.start += offsets.unspecialized_lambda_sets;
 
@@ -4498,7 +4780,40 @@ fn storage_copy_var_to_help(env: &mut StorageCopyVarToEnv<'_>, var: Variable) ->
                     Func(new_arguments, new_closure_var, new_ret_var)
                 }
 
-                same @ EmptyRecord | same @ EmptyTagUnion | same @ Erroneous(_) => same,
+                same @ EmptyRecord
+                | same @ EmptyTuple
+                | same @ EmptyTagUnion
+                | same @ Erroneous(_) => same,
+
+                Tuple(elems, ext_var) => {
+                    let new_elems = {
+                        let new_variables =
+                            VariableSubsSlice::reserve_into_subs(env.target, elems.len());
+
+                        let it = (new_variables.indices()).zip(elems.iter_variables());
+                        for (target_index, var_index) in it {
+                            let var = env.source[var_index];
+                            let copy_var = storage_copy_var_to_help(env, var);
+                            env.target.variables[target_index] = copy_var;
+                        }
+
+                        let elem_index_start = env.target.tuple_elem_indices.len() as u32;
+
+                        let elem_indices =
+                            &env.source.tuple_elem_indices[elems.elem_indices().indices()];
+                        env.target
+                            .tuple_elem_indices
+                            .extend(elem_indices.iter().copied());
+
+                        TupleElems {
+                            length: elems.length,
+                            elem_index_start,
+                            variables_start: new_variables.start,
+                        }
+                    };
+
+                    Tuple(new_elems, storage_copy_var_to_help(env, ext_var))
+                }
 
                 Record(fields, ext_var) => {
                     let record_fields = {
@@ -4606,24 +4921,32 @@ fn storage_copy_var_to_help(env: &mut StorageCopyVarToEnv<'_>, var: Variable) ->
             copy
         }
 
-        FlexAbleVar(opt_name_index, ability) => {
+        FlexAbleVar(opt_name_index, abilities) => {
             let new_name_index = opt_name_index.map(|name_index| {
                 let name = env.source.field_names[name_index.index as usize].clone();
                 SubsIndex::push_new(&mut env.target.field_names, name)
             });
+            let new_abilities = SubsSlice::extend_new(
+                &mut env.target.symbol_names,
+                env.source.get_subs_slice(abilities).iter().copied(),
+            );
 
-            let content = FlexAbleVar(new_name_index, ability);
+            let content = FlexAbleVar(new_name_index, new_abilities);
             env.target.set_content(copy, content);
 
             copy
         }
 
-        RigidAbleVar(name_index, ability) => {
+        RigidAbleVar(name_index, abilities) => {
             let name = env.source.field_names[name_index.index as usize].clone();
             let new_name_index = SubsIndex::push_new(&mut env.target.field_names, name);
+            let new_abilities = SubsSlice::extend_new(
+                &mut env.target.symbol_names,
+                env.source.get_subs_slice(abilities).iter().copied(),
+            );
             env.target.set(
                 copy,
-                make_descriptor(FlexAbleVar(Some(new_name_index), ability)),
+                make_descriptor(FlexAbleVar(Some(new_name_index), new_abilities)),
             );
 
             copy
@@ -4936,7 +5259,37 @@ fn copy_import_to_help(env: &mut CopyImportEnv<'_>, max_rank: Rank, var: Variabl
 
                 Erroneous(_) => internal_error!("I thought this was handled above"),
 
-                same @ EmptyRecord | same @ EmptyTagUnion => same,
+                same @ EmptyRecord | same @ EmptyTuple | same @ EmptyTagUnion => same,
+
+                Tuple(elems, ext_var) => {
+                    let new_elems = {
+                        let new_variables =
+                            VariableSubsSlice::reserve_into_subs(env.target, elems.len());
+
+                        let it = (new_variables.indices()).zip(elems.iter_variables());
+                        for (target_index, var_index) in it {
+                            let var = env.source[var_index];
+                            let copy_var = copy_import_to_help(env, max_rank, var);
+                            env.target.variables[target_index] = copy_var;
+                        }
+
+                        let elem_index_start = env.target.tuple_elem_indices.len() as u32;
+
+                        let elem_indices =
+                            &env.source.tuple_elem_indices[elems.elem_indices().indices()];
+                        env.target
+                            .tuple_elem_indices
+                            .extend(elem_indices.iter().copied());
+
+                        TupleElems {
+                            length: elems.length,
+                            elem_index_start,
+                            variables_start: new_variables.start,
+                        }
+                    };
+
+                    Tuple(new_elems, copy_import_to_help(env, max_rank, ext_var))
+                }
 
                 Record(fields, ext_var) => {
                     let record_fields = {
@@ -5023,12 +5376,16 @@ fn copy_import_to_help(env: &mut CopyImportEnv<'_>, max_rank: Rank, var: Variabl
             copy
         }
 
-        FlexAbleVar(opt_name_index, ability) => {
+        FlexAbleVar(opt_name_index, abilities) => {
             if let Some(name_index) = opt_name_index {
                 let name = env.source.field_names[name_index.index as usize].clone();
                 let new_name_index = SubsIndex::push_new(&mut env.target.field_names, name);
+                let new_abilities = SubsSlice::extend_new(
+                    &mut env.target.symbol_names,
+                    env.source.get_subs_slice(abilities).iter().copied(),
+                );
 
-                let content = FlexAbleVar(Some(new_name_index), ability);
+                let content = FlexAbleVar(Some(new_name_index), new_abilities);
                 env.target.set_content(copy, content);
             }
 
@@ -5059,12 +5416,16 @@ fn copy_import_to_help(env: &mut CopyImportEnv<'_>, max_rank: Rank, var: Variabl
             copy
         }
 
-        RigidAbleVar(name_index, ability) => {
+        RigidAbleVar(name_index, abilities) => {
             let name = env.source.field_names[name_index.index as usize].clone();
             let new_name_index = SubsIndex::push_new(&mut env.target.field_names, name);
+            let new_abilities = SubsSlice::extend_new(
+                &mut env.target.symbol_names,
+                env.source.get_subs_slice(abilities).iter().copied(),
+            );
 
             env.target
-                .set(copy, make_descriptor(RigidAbleVar(new_name_index, ability)));
+                .set(copy, make_descriptor(RigidAbleVar(new_name_index, new_abilities)));
 
             env.rigid_able.push(copy);
 
@@ -5216,11 +5577,11 @@ fn instantiate_rigids_help(subs: &mut Subs, max_rank: Rank, initial: Variable) {
                     }
                 })
             }
-            &RigidAbleVar(name, ability) => {
+            &RigidAbleVar(name, abilities) => {
                 // Same as `RigidVar` above
                 subs.modify(var, |d| {
                     *d = Descriptor {
-                        content: FlexAbleVar(Some(name), ability),
+                        content: FlexAbleVar(Some(name), abilities),
                         rank: max_rank,
                         mark: Mark::NONE,
                         copy: OptVariable::NONE,
@@ -5250,6 +5611,7 @@ fn instantiate_rigids_help(subs: &mut Subs, max_rank: Rank, initial: Variable) {
                 }
 
                 EmptyRecord => (),
+                EmptyTuple => (),
                 EmptyTagUnion => (),
 
                 Record(fields, ext_var) => {
@@ -5259,6 +5621,13 @@ fn instantiate_rigids_help(subs: &mut Subs, max_rank: Rank, initial: Variable) {
 
                     stack.push(ext_var);
                 }
+                Tuple(elems, ext_var) => {
+                    let elems = *elems;
+                    let ext_var = *ext_var;
+                    stack.extend(var_slice!(elems.variables()));
+
+                    stack.push(ext_var);
+                }
                 TagUnion(tags, ext_var) => {
                     let tags = *tags;
                     let ext_var = *ext_var;
@@ -5371,6 +5740,10 @@ pub fn get_member_lambda_sets_at_region(subs: &Subs, var: Variable, target_regio
                     stack.extend(subs.get_subs_slice(fields.variables()));
                     stack.push(*ext);
                 }
+                FlatType::Tuple(elems, ext) => {
+                    stack.extend(subs.get_subs_slice(elems.variables()));
+                    stack.push(*ext);
+                }
                 FlatType::TagUnion(tags, ext) => {
                     stack.extend(
                         subs.get_subs_slice(tags.variables())
@@ -5391,7 +5764,10 @@ pub fn get_member_lambda_sets_at_region(subs: &Subs, var: Variable, target_regio
                     );
                     stack.push(*ext);
                 }
-                FlatType::Erroneous(_) | FlatType::EmptyRecord | FlatType::EmptyTagUnion => {}
+                FlatType::Erroneous(_)
+                | FlatType::EmptyRecord
+                | FlatType::EmptyTuple
+                | FlatType::EmptyTagUnion => {}
             },
             Content::Alias(_, _, real_var, _) => {
                 stack.push(*real_var);
@@ -5443,6 +5819,10 @@ fn is_inhabited(subs: &Subs, var: Variable) -> bool {
                         stack.extend(field_vars)
                     }
                 }
+                FlatType::Tuple(elems, ext) => {
+                    stack.extend(subs.get_subs_slice(elems.variables()));
+                    stack.push(*ext);
+                }
                 FlatType::TagUnion(tags, ext) | FlatType::RecursiveTagUnion(_, tags, ext) => {
                     let mut is_uninhabited = true;
                     // If any tag is inhabited, the union is inhabited!
@@ -5460,6 +5840,7 @@ fn is_inhabited(subs: &Subs, var: Variable) -> bool {
                 FlatType::FunctionOrTagUnion(_, _, _) => {}
                 FlatType::Erroneous(_) => {}
                 FlatType::EmptyRecord => {}
+                FlatType::EmptyTuple => {}
                 FlatType::EmptyTagUnion => {
                     return false;
                 }
@@ -1,7 +1,7 @@
 use crate::num::NumericRange;
 use crate::pretty_print::Parens;
 use crate::subs::{
-    GetSubsSlice, RecordFields, Subs, UnionTags, VarStore, Variable, VariableSubsSlice,
+    GetSubsSlice, RecordFields, Subs, TupleElems, UnionTags, VarStore, Variable, VariableSubsSlice,
 };
 use roc_collections::all::{HumanIndex, ImMap, ImSet, MutMap, MutSet, SendMap};
 use roc_error_macros::internal_error;
@@ -10,6 +10,7 @@ use roc_module::ident::{ForeignSymbol, Ident, Lowercase, TagName};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_region::all::{Loc, Region};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Write;
 
@@ -33,7 +34,7 @@ const GREEK_LETTERS: &[char] = &[
 ///     Can unify with Required, but not with Demanded
 /// - RigidOptional: introduced by annotations, e.g. { x ? Str}
 ///     Can only unify with Optional, to prevent a required field being typed as Optional
-#[derive(PartialEq, Eq, Clone, Hash)]
+#[derive(PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
 pub enum RecordField<T> {
     Demanded(T),
     Required(T),
@@ -180,7 +181,7 @@ impl RecordField<Type> {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct LambdaSet(pub Type);
 
 impl LambdaSet {
@@ -206,50 +207,120 @@ impl LambdaSet {
     }
 }
 
-#[derive(PartialEq, Eq, Clone)]
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct AliasCommon {
     pub symbol: Symbol,
     pub type_arguments: Vec<Type>,
     pub lambda_set_variables: Vec<LambdaSet>,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// The abilities a type variable is bound to, like `Hash & Eq` in `a has Hash & Eq`.
+/// Always sorted, and never has duplicates.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Default, Serialize, Deserialize)]
+pub struct AbilitySet(Vec<Symbol>);
+
+impl AbilitySet {
+    pub fn with_capacity(cap: usize) -> Self {
+        Self(Vec::with_capacity(cap))
+    }
+
+    pub fn singleton(ability: Symbol) -> Self {
+        Self(vec![ability])
+    }
+
+    /// Adds an ability to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, ability: Symbol) -> bool {
+        match self.0.binary_search(&ability) {
+            Ok(_) => false,
+            Err(index) => {
+                self.0.insert(index, ability);
+                true
+            }
+        }
+    }
+
+    pub fn contains(&self, ability: &Symbol) -> bool {
+        self.0.binary_search(ability).is_ok()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn sorted_iter(&self) -> impl ExactSizeIterator<Item = &Symbol> {
+        self.0.iter()
+    }
+
+    pub fn into_sorted_iter(self) -> impl ExactSizeIterator<Item = Symbol> {
+        self.0.into_iter()
+    }
+}
+
+impl FromIterator<Symbol> for AbilitySet {
+    fn from_iter<T: IntoIterator<Item = Symbol>>(iter: T) -> Self {
+        let mut abilities: Vec<Symbol> = iter.into_iter().collect();
+        abilities.sort();
+        abilities.dedup();
+        Self(abilities)
+    }
+}
+
+impl fmt::Debug for AbilitySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.0.iter().peekable();
+        while let Some(ability) = it.next() {
+            write!(f, "{:?}", ability)?;
+            if it.peek().is_some() {
+                write!(f, " & ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OptAbleVar {
     pub var: Variable,
-    pub opt_ability: Option<Symbol>,
+    pub opt_abilities: Option<AbilitySet>,
 }
 
 impl OptAbleVar {
     pub fn unbound(var: Variable) -> Self {
         Self {
             var,
-            opt_ability: None,
+            opt_abilities: None,
         }
     }
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct OptAbleType {
     pub typ: Type,
-    pub opt_ability: Option<Symbol>,
+    pub opt_abilities: Option<AbilitySet>,
 }
 
 impl OptAbleType {
     pub fn unbound(typ: Type) -> Self {
         Self {
             typ,
-            opt_ability: None,
+            opt_abilities: None,
         }
     }
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
 pub enum Type {
     EmptyRec,
     EmptyTagUnion,
     /// A function. The types of its arguments, size of its closure, then the type of its return value.
     Function(Vec<Type>, Box<Type>, Box<Type>),
     Record(SendMap<Lowercase, RecordField<Type>>, TypeExtension),
+    /// A tuple, with its elements sorted by index
+    Tuple(Vec<(usize, Type)>, TypeExtension),
     TagUnion(Vec<(TagName, Vec<Type>)>, TypeExtension),
     FunctionOrTagUnion(TagName, Symbol, TypeExtension),
     /// A function name that is used in our defunctionalization algorithm. For example in
@@ -286,6 +357,7 @@ pub enum Type {
     Variable(Variable),
     RangedNumber(NumericRange),
     /// A type error, which will code gen to a runtime error
+    #[serde(skip)]
     Erroneous(Problem),
 }
 
@@ -297,7 +369,7 @@ pub enum Type {
 /// usage site. Unspecialized lambda sets aid us in recovering those lambda sets; when we
 /// instantiate `a` with a proper type `T`, we'll know to resolve the lambda set by extracting
 /// it at region "1" from the specialization of "default" for `T`.
-#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Uls(pub Variable, pub Symbol, pub u8);
 
 impl std::fmt::Debug for Uls {
@@ -331,6 +403,7 @@ impl Clone for Type {
                 Self::Function(arg0.clone(), arg1.clone(), arg2.clone())
             }
             Self::Record(arg0, arg1) => Self::Record(arg0.clone(), arg1.clone()),
+            Self::Tuple(arg0, arg1) => Self::Tuple(arg0.clone(), arg1.clone()),
             Self::TagUnion(arg0, arg1) => Self::TagUnion(arg0.clone(), arg1.clone()),
             Self::FunctionOrTagUnion(arg0, arg1, arg2) => {
                 Self::FunctionOrTagUnion(arg0.clone(), *arg1, arg2.clone())
@@ -390,12 +463,12 @@ impl Clone for OptAbleType {
         // This passes through `Type`, so defer to that to bump the clone counter.
         Self {
             typ: self.typ.clone(),
-            opt_ability: self.opt_ability,
+            opt_abilities: self.opt_abilities.clone(),
         }
     }
 }
 
-#[derive(PartialEq, Eq, Clone)]
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum TypeExtension {
     Open(Box<Type>),
     Closed,
@@ -536,8 +609,8 @@ impl fmt::Debug for Type {
 
                 for arg in type_arguments {
                     write!(f, " {:?}", &arg.typ)?;
-                    if let Some(ab) = arg.opt_ability {
-                        write!(f, ":{:?}", ab)?;
+                    if let Some(abs) = &arg.opt_abilities {
+                        write!(f, ":{:?}", abs)?;
                     }
                 }
 
@@ -617,6 +690,24 @@ impl fmt::Debug for Type {
                     }
                 }
             }
+            Type::Tuple(elems, ext) => {
+                write!(f, "(")?;
+
+                for (i, (index, elem_type)) in elems.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{}: {:?}", index, elem_type)?;
+                }
+
+                write!(f, ")")?;
+
+                match ext {
+                    TypeExtension::Closed => Ok(()),
+                    TypeExtension::Open(other) => other.fmt(f),
+                }
+            }
             Type::TagUnion(tags, ext) => {
                 write_tags(f, tags.iter())?;
 
@@ -787,6 +878,15 @@ impl Type {
                         stack.push(ext);
                     }
                 }
+                Tuple(elems, ext) => {
+                    for (_, x) in elems.iter_mut() {
+                        stack.push(x);
+                    }
+
+                    if let TypeExtension::Open(ext) = ext {
+                        stack.push(ext);
+                    }
+                }
                 Type::DelayedAlias(AliasCommon {
                     type_arguments,
                     lambda_set_variables,
@@ -909,6 +1009,14 @@ impl Type {
                         stack.push(ext);
                     }
                 }
+                Tuple(elems, ext) => {
+                    for (_, x) in elems.iter_mut() {
+                        stack.push(x);
+                    }
+                    if let TypeExtension::Open(ext) = ext {
+                        stack.push(ext);
+                    }
+                }
                 Type::DelayedAlias(AliasCommon {
                     type_arguments,
                     lambda_set_variables,
@@ -1015,6 +1123,16 @@ impl Type {
                     TypeExtension::Closed => Ok(()),
                 }
             }
+            Tuple(elems, ext) => {
+                for (_, x) in elems.iter_mut() {
+                    x.substitute_alias(rep_symbol, rep_args, actual)?;
+                }
+
+                match ext {
+                    TypeExtension::Open(ext) => ext.substitute_alias(rep_symbol, rep_args, actual),
+                    TypeExtension::Closed => Ok(()),
+                }
+            }
             DelayedAlias(AliasCommon {
                 type_arguments,
                 lambda_set_variables: _no_aliases_in_lambda_sets,
@@ -1096,6 +1214,10 @@ impl Type {
                 Self::contains_symbol_ext(ext, rep_symbol)
                     || fields.values().any(|arg| arg.contains_symbol(rep_symbol))
             }
+            Tuple(elems, ext) => {
+                Self::contains_symbol_ext(ext, rep_symbol)
+                    || elems.iter().any(|(_, arg)| arg.contains_symbol(rep_symbol))
+            }
             DelayedAlias(AliasCommon {
                 symbol,
                 type_arguments,
@@ -1166,6 +1288,12 @@ impl Type {
                         .values()
                         .any(|arg| arg.contains_variable(rep_variable))
             }
+            Tuple(elems, ext) => {
+                Self::contains_variable_ext(ext, rep_variable)
+                    || elems
+                        .iter()
+                        .any(|(_, arg)| arg.contains_variable(rep_variable))
+            }
             DelayedAlias(AliasCommon { .. }) => {
                 todo!()
             }
@@ -1250,6 +1378,15 @@ impl Type {
                     ext.instantiate_aliases(region, aliases, var_store, new_lambda_set_variables);
                 }
             }
+            Tuple(elems, ext) => {
+                for (_, x) in elems.iter_mut() {
+                    x.instantiate_aliases(region, aliases, var_store, new_lambda_set_variables);
+                }
+
+                if let TypeExtension::Open(ext) = ext {
+                    ext.instantiate_aliases(region, aliases, var_store, new_lambda_set_variables);
+                }
+            }
             DelayedAlias(AliasCommon {
                 type_arguments,
                 lambda_set_variables,
@@ -1361,7 +1498,7 @@ impl Type {
                                 value:
                                     AliasVar {
                                         var: placeholder,
-                                        opt_bound_ability,
+                                        opt_bound_abilities,
                                         ..
                                     },
                                 ..
@@ -1378,7 +1515,7 @@ impl Type {
                             );
                             named_args.push(OptAbleType {
                                 typ: filler.clone(),
-                                opt_ability: *opt_bound_ability,
+                                opt_abilities: opt_bound_abilities.clone(),
                             });
                             substitution.insert(*placeholder, filler);
                         }
@@ -1499,6 +1636,12 @@ impl Type {
                 }
                 TypeExtension::Closed => fields.values().all(|field| field.as_inner().is_narrow()),
             },
+            Type::Tuple(elems, ext) => match ext {
+                TypeExtension::Open(ext) => {
+                    elems.iter().all(|(_, elem)| elem.is_narrow()) && ext.is_narrow()
+                }
+                TypeExtension::Closed => elems.iter().all(|(_, elem)| elem.is_narrow()),
+            },
             Type::Function(args, clos, ret) => {
                 args.iter().all(|a| a.is_narrow()) && clos.is_narrow() && ret.is_narrow()
             }
@@ -1546,6 +1689,10 @@ fn symbols_help(initial: &Type) -> Vec<Symbol> {
                 stack.extend(ext);
                 stack.extend(fields.values().map(|field| field.as_inner()));
             }
+            Tuple(elems, ext) => {
+                stack.extend(ext);
+                stack.extend(elems.iter().map(|(_, elem)| elem));
+            }
             DelayedAlias(AliasCommon {
                 symbol,
                 type_arguments,
@@ -1626,6 +1773,15 @@ fn variables_help(tipe: &Type, accum: &mut ImSet<Variable>) {
                 variables_help(ext, accum);
             }
         }
+        Tuple(elems, ext) => {
+            for (_, elem) in elems {
+                variables_help(elem, accum);
+            }
+
+            if let TypeExtension::Open(ext) = ext {
+                variables_help(ext, accum);
+            }
+        }
         ClosureTag {
             name: _,
             captures,
@@ -1768,6 +1924,15 @@ fn variables_help_detailed(tipe: &Type, accum: &mut VariableDetail) {
                 variables_help_detailed(ext, accum);
             }
         }
+        Tuple(elems, ext) => {
+            for (_, elem) in elems {
+                variables_help_detailed(elem, accum);
+            }
+
+            if let TypeExtension::Open(ext) = ext {
+                variables_help_detailed(ext, accum);
+            }
+        }
         ClosureTag {
             name: _,
             captures,
@@ -1957,6 +2122,7 @@ pub enum Reason {
     },
     WhenGuard,
     ExpectCondition,
+    CrashArg,
     IfCondition,
     IfBranch {
         index: HumanIndex,
@@ -1968,6 +2134,8 @@ pub enum Reason {
     RecordUpdateValue(Lowercase),
     RecordUpdateKeys(Symbol, SendMap<Lowercase, Region>),
     RecordDefaultField(Lowercase),
+    /// The value of a `<-` field in a record builder, which is given to `apply`
+    RecordBuilderField,
     NumericLiteralSuffix,
     InvalidAbilityMemberSpecialization {
         member_name: Symbol,
@@ -2018,9 +2186,14 @@ pub enum Category {
     Access(Lowercase),
     DefaultValue(Lowercase), // for setting optional fields
 
+    // tuples
+    Tuple,
+    TupleAccess(usize),
+
     AbilityMemberSpecialization(Symbol),
 
     Expect,
+    Crash,
     Unknown,
 }
 
@@ -2028,6 +2201,7 @@ pub enum Category {
 pub enum PatternCategory {
     Record,
     EmptyRecord,
+    Tuple,
     PatternGuard,
     PatternDefault,
     Set,
@@ -2041,7 +2215,7 @@ pub enum PatternCategory {
     Character,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum AliasKind {
     /// A structural alias is something like
     ///   List a : [Nil, Cons a (List a)]
@@ -2063,12 +2237,12 @@ impl AliasKind {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AliasVar {
     pub name: Lowercase,
     pub var: Variable,
-    /// `Some` if this variable is bound to an ability; `None` otherwise.
-    pub opt_bound_ability: Option<Symbol>,
+    /// `Some` if this variable is bound to abilities; `None` otherwise.
+    pub opt_bound_abilities: Option<AbilitySet>,
 }
 
 impl AliasVar {
@@ -2076,7 +2250,7 @@ impl AliasVar {
         Self {
             name,
             var,
-            opt_bound_ability: None,
+            opt_bound_abilities: None,
         }
     }
 }
@@ -2085,12 +2259,12 @@ impl From<&AliasVar> for OptAbleVar {
     fn from(av: &AliasVar) -> OptAbleVar {
         OptAbleVar {
             var: av.var,
-            opt_ability: av.opt_bound_ability,
+            opt_abilities: av.opt_bound_abilities.clone(),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MemberImpl {
     /// The implementation is claimed to be at the given symbol.
     /// During solving we validate that the impl is really there.
@@ -2101,7 +2275,7 @@ pub enum MemberImpl {
     Error,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Alias {
     pub region: Region,
     pub type_variables: Vec<Loc<AliasVar>>,
@@ -2165,9 +2339,10 @@ pub enum ErrorType {
     Type(Symbol, Vec<ErrorType>),
     FlexVar(Lowercase),
     RigidVar(Lowercase),
-    FlexAbleVar(Lowercase, Symbol),
-    RigidAbleVar(Lowercase, Symbol),
+    FlexAbleVar(Lowercase, AbilitySet),
+    RigidAbleVar(Lowercase, AbilitySet),
     Record(SendMap<Lowercase, RecordField<ErrorType>>, TypeExt),
+    Tuple(Vec<(usize, ErrorType)>, TypeExt),
     TagUnion(SendMap<TagName, Vec<ErrorType>>, TypeExt),
     RecursiveTagUnion(Box<ErrorType>, SendMap<TagName, Vec<ErrorType>>, TypeExt),
     Function(Vec<ErrorType>, Box<ErrorType>, Box<ErrorType>),
@@ -2206,6 +2381,10 @@ impl ErrorType {
                     .for_each(|(_, t)| t.as_inner().add_names(taken));
                 ext.add_names(taken);
             }
+            Tuple(elems, ext) => {
+                elems.iter().for_each(|(_, t)| t.add_names(taken));
+                ext.add_names(taken);
+            }
             TagUnion(tags, ext) => {
                 tags.iter()
                     .for_each(|(_, ts)| ts.iter().for_each(|t| t.add_names(taken)));
@@ -2357,6 +2536,21 @@ fn write_error_type_help(
             buf.push('}');
             write_type_ext(ext, buf);
         }
+        Tuple(elems, ext) => {
+            buf.push('(');
+
+            let mut it = elems.into_iter().peekable();
+
+            while let Some((_, elem)) = it.next() {
+                write_error_type_help(home, interns, elem, buf, Parens::Unnecessary);
+                if it.peek().is_some() {
+                    buf.push_str(", ");
+                }
+            }
+
+            buf.push(')');
+            write_type_ext(ext, buf);
+        }
 
         other => todo!("cannot format {:?} yet", other),
     }
@@ -2376,13 +2570,13 @@ fn write_debug_error_type_help(error_type: ErrorType, buf: &mut String, parens:
         Infinite => buf.push('∞'),
         Error => buf.push('?'),
         FlexVar(name) | RigidVar(name) => buf.push_str(name.as_str()),
-        FlexAbleVar(name, symbol) | RigidAbleVar(name, symbol) => {
+        FlexAbleVar(name, abilities) | RigidAbleVar(name, abilities) => {
             let write_parens = parens == Parens::InTypeParam;
             if write_parens {
                 buf.push('(');
             }
             buf.push_str(name.as_str());
-            write!(buf, "has {:?}", symbol).unwrap();
+            write!(buf, "has {:?}", abilities).unwrap();
             if write_parens {
                 buf.push(')');
             }
@@ -2511,6 +2705,21 @@ fn write_debug_error_type_help(error_type: ErrorType, buf: &mut String, parens:
             buf.push('}');
             write_type_ext(ext, buf);
         }
+        Tuple(elems, ext) => {
+            buf.push('(');
+
+            let mut it = elems.into_iter().peekable();
+
+            while let Some((_, elem)) = it.next() {
+                write_debug_error_type_help(elem, buf, Parens::Unnecessary);
+                if it.peek().is_some() {
+                    buf.push_str(", ");
+                }
+            }
+
+            buf.push(')');
+            write_type_ext(ext, buf);
+        }
         TagUnion(tags, ext) => {
             buf.push('[');
 
@@ -2737,6 +2946,58 @@ pub fn gather_fields(
     })
 }
 
+pub struct TupleStructure {
+    /// Invariant: these should be sorted!
+    pub elems: Vec<(usize, Variable)>,
+    pub ext: Variable,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct TupleElemsError;
+
+pub fn gather_tuple_elems(
+    subs: &Subs,
+    other_elems: TupleElems,
+    mut var: Variable,
+) -> Result<TupleStructure, TupleElemsError> {
+    use crate::subs::Content::*;
+    use crate::subs::FlatType::*;
+
+    let mut stack = vec![other_elems];
+
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Structure(Tuple(sub_elems, sub_ext)) => {
+                stack.push(*sub_elems);
+                var = *sub_ext;
+            }
+
+            Alias(_, _, actual_var, _) => {
+                var = *actual_var;
+            }
+
+            Structure(EmptyTuple) => break,
+            FlexVar(_) | FlexAbleVar(..) => break,
+            RigidVar(_) | RigidAbleVar(..) => break,
+
+            // Stop on errors in the tuple
+            Error => break,
+
+            _ => return Err(TupleElemsError),
+        }
+    }
+
+    let mut elems: Vec<_> = stack
+        .into_iter()
+        .flat_map(|elems| elems.iter_all())
+        .map(|(i1, i2)| (subs[i1], subs[i2]))
+        .collect();
+
+    elems.sort_by_key(|(index, _)| *index);
+
+    Ok(TupleStructure { elems, ext: var })
+}
+
 #[derive(Debug)]
 pub enum GatherTagsError {
     NotATagUnion(Variable),
@@ -2898,6 +3159,10 @@ fn instantiate_lambda_sets_as_unspecialized(
                     stack.push(x.as_inner_mut());
                 }
             }
+            Type::Tuple(elems, ext) => {
+                stack.extend(ext.iter_mut());
+                stack.extend(elems.iter_mut().rev().map(|(_, elem)| elem));
+            }
             Type::TagUnion(tags, ext) | Type::RecursiveTagUnion(_, tags, ext) => {
                 stack.extend(ext.iter_mut());
                 for (_, ts) in tags {
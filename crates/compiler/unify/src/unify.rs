@@ -10,8 +10,8 @@ use roc_types::num::{FloatWidth, IntLitWidth, NumericRange};
 use roc_types::subs::Content::{self, *};
 use roc_types::subs::{
     AliasVariables, Descriptor, ErrorTypeContext, FlatType, GetSubsSlice, LambdaSet, Mark,
-    OptVariable, RecordFields, Subs, SubsIndex, SubsSlice, UlsOfVar, UnionLabels, UnionLambdas,
-    UnionTags, Variable, VariableSubsSlice,
+    OptVariable, RecordFields, Subs, SubsIndex, SubsSlice, TupleElems, UlsOfVar, UnionLabels,
+    UnionLambdas, UnionTags, Variable, VariableSubsSlice,
 };
 use roc_types::types::{AliasKind, DoesNotImplementAbility, ErrorType, Mismatch, RecordField, Uls};
 
@@ -68,7 +68,7 @@ macro_rules! mismatch {
             ..Outcome::default()
         }
     }};
-    (%not_able, $var:expr, $ability:expr, $msg:expr, $($arg:tt)*) => {{
+    (%not_able, $var:expr, $abilities:expr, $msg:expr, $($arg:tt)*) => {{
         dbg_do!(ROC_PRINT_MISMATCHES, {
             eprintln!(
                 "Mismatch in {} Line {} Column {}",
@@ -81,7 +81,13 @@ macro_rules! mismatch {
         });
 
         Outcome {
-            mismatches: vec![Mismatch::TypeMismatch, Mismatch::DoesNotImplementAbiity($var, $ability)],
+            mismatches: std::iter::once(Mismatch::TypeMismatch)
+                .chain(
+                    $abilities
+                        .into_iter()
+                        .map(|ability| Mismatch::DoesNotImplementAbiity($var, ability)),
+                )
+                .collect(),
             ..Outcome::default()
         }
     }}
@@ -504,8 +510,8 @@ fn unify_context<M: MetaCollector>(env: &mut Env, pool: &mut Pool, ctx: Context)
     #[allow(clippy::let_and_return)]
     let result = match &ctx.first_desc.content {
         FlexVar(opt_name) => unify_flex(env, &ctx, opt_name, &ctx.second_desc.content),
-        FlexAbleVar(opt_name, ability) => {
-            unify_flex_able(env, &ctx, opt_name, *ability, &ctx.second_desc.content)
+        FlexAbleVar(opt_name, abilities) => {
+            unify_flex_able(env, &ctx, opt_name, *abilities, &ctx.second_desc.content)
         }
         RecursionVar {
             opt_name,
@@ -519,8 +525,8 @@ fn unify_context<M: MetaCollector>(env: &mut Env, pool: &mut Pool, ctx: Context)
             &ctx.second_desc.content,
         ),
         RigidVar(name) => unify_rigid(env, &ctx, name, &ctx.second_desc.content),
-        RigidAbleVar(name, ability) => {
-            unify_rigid_able(env, &ctx, name, *ability, &ctx.second_desc.content)
+        RigidAbleVar(name, abilities) => {
+            unify_rigid_able(env, &ctx, name, *abilities, &ctx.second_desc.content)
         }
         Structure(flat_type) => {
             unify_structure(env, pool, &ctx, flat_type, &ctx.second_desc.content)
@@ -572,7 +578,18 @@ fn unify_ranged_number<M: MetaCollector>(
             // Int a vs Int <range>, the rigid wins
             merge(env, ctx, RigidVar(*name))
         }
-        RecursionVar { .. } | Alias(..) | Structure(..) | RigidAbleVar(..) | FlexAbleVar(..) => {
+        &FlexAbleVar(_, abilities) => {
+            // Ranged number wins, and must implement the abilities the flex var was bound to.
+            merge_flex_able_with_concrete(
+                env,
+                ctx,
+                ctx.second,
+                abilities,
+                RangedNumber(range_vars),
+                |_| Obligated::Adhoc(ctx.first),
+            )
+        }
+        RecursionVar { .. } | Alias(..) | Structure(..) | RigidAbleVar(..) => {
             check_and_merge_valid_range(env, pool, ctx, ctx.first, range_vars, ctx.second)
         }
         &RangedNumber(other_range_vars) => match range_vars.intersection(&other_range_vars) {
@@ -861,10 +878,20 @@ fn unify_alias<M: MetaCollector>(
 }
 
 #[inline(always)]
-fn opaque_obligation(opaque: Symbol, opaque_var: Variable) -> Obligated {
+fn opaque_obligation(opaque: Symbol, opaque_var: Variable, ability: Symbol) -> Obligated {
     match opaque.module_id() {
         // Numbers should be treated as ad-hoc obligations for ability checking.
         ModuleId::NUM => Obligated::Adhoc(opaque_var),
+        // Bool is hashed and generated by the builtin derivers, since the Bool module cannot
+        // depend on Hash or Gen.
+        ModuleId::BOOL
+            if matches!(
+                ability,
+                Symbol::HASH_HASH_ABILITY | Symbol::GEN_ARBITRARY_ABILITY
+            ) =>
+        {
+            Obligated::Adhoc(opaque_var)
+        }
         _ => Obligated::Opaque(opaque),
     }
 }
@@ -887,15 +914,15 @@ fn unify_opaque<M: MetaCollector>(
             // Alias wins
             merge(env, ctx, Alias(symbol, args, real_var, kind))
         }
-        FlexAbleVar(_, ability) => {
+        FlexAbleVar(_, abilities) => {
             // Opaque type wins
             merge_flex_able_with_concrete(
                 env,
                 ctx,
                 ctx.second,
-                *ability,
+                *abilities,
                 Alias(symbol, args, real_var, kind),
-                opaque_obligation(symbol, ctx.first),
+                |ability| opaque_obligation(symbol, ctx.first, ability),
             )
         }
         Alias(_, _, other_real_var, AliasKind::Structural) => {
@@ -946,15 +973,15 @@ fn unify_structure<M: MetaCollector>(
             // If the other is flex, Structure wins!
             merge(env, ctx, Structure(*flat_type))
         }
-        FlexAbleVar(_, ability) => {
+        FlexAbleVar(_, abilities) => {
             // Structure wins
             merge_flex_able_with_concrete(
                 env,
                 ctx,
                 ctx.second,
-                *ability,
+                *abilities,
                 Structure(*flat_type),
-                Obligated::Adhoc(ctx.first),
+                |_| Obligated::Adhoc(ctx.first),
             )
         }
         // _name has an underscore because it's unused in --release builds
@@ -966,9 +993,9 @@ fn unify_structure<M: MetaCollector>(
                 _name
             )
         }
-        RigidAbleVar(_, _ability) => {
+        RigidAbleVar(_, abilities) => {
             mismatch!(
-                %not_able, ctx.first, *_ability,
+                %not_able, ctx.first, env.subs.get_subs_slice(*abilities).to_vec(),
                 "trying to unify {:?} with RigidAble {:?}",
                 &flat_type,
                 &other
@@ -1794,6 +1821,86 @@ fn unify_record<M: MetaCollector>(
     }
 }
 
+fn unify_tuple<M: MetaCollector>(
+    env: &mut Env,
+    pool: &mut Pool,
+    ctx: &Context,
+    elems1: TupleElems,
+    ext1: Variable,
+    elems2: TupleElems,
+    ext2: Variable,
+) -> Outcome<M> {
+    let subs = &mut env.subs;
+
+    let (it1, ext1) = elems1.sorted_iterator_and_ext(subs, ext1);
+    let (it2, ext2) = elems2.sorted_iterator_and_ext(subs, ext2);
+
+    let separated = separate(it1.collect::<Vec<_>>(), it2.collect::<Vec<_>>());
+
+    let shared_elems = separated.in_both;
+
+    // the ext of a tuple receives the elements that only the other side has; both exts end up
+    // unified with a common remainder
+    let (ext, mut whole_outcome) = if separated.only_in_1.is_empty() {
+        if separated.only_in_2.is_empty() {
+            (ext1, unify_pool(env, pool, ext1, ext2, ctx.mode))
+        } else {
+            let only_in_2 = TupleElems::insert_into_subs(subs, separated.only_in_2);
+            let sub_tuple = fresh(env, pool, ctx, Structure(FlatType::Tuple(only_in_2, ext2)));
+
+            (sub_tuple, unify_pool(env, pool, ext1, sub_tuple, ctx.mode))
+        }
+    } else if separated.only_in_2.is_empty() {
+        let only_in_1 = TupleElems::insert_into_subs(subs, separated.only_in_1);
+        let sub_tuple = fresh(env, pool, ctx, Structure(FlatType::Tuple(only_in_1, ext1)));
+
+        (sub_tuple, unify_pool(env, pool, sub_tuple, ext2, ctx.mode))
+    } else {
+        let only_in_1 = TupleElems::insert_into_subs(subs, separated.only_in_1);
+        let only_in_2 = TupleElems::insert_into_subs(subs, separated.only_in_2);
+
+        let ext = fresh(env, pool, ctx, Content::FlexVar(None));
+        let sub1 = fresh(env, pool, ctx, Structure(FlatType::Tuple(only_in_1, ext)));
+        let sub2 = fresh(env, pool, ctx, Structure(FlatType::Tuple(only_in_2, ext)));
+
+        let mut outcome = unify_pool(env, pool, ext1, sub2, ctx.mode);
+        if outcome.mismatches.is_empty() {
+            outcome.union(unify_pool(env, pool, sub1, ext2, ctx.mode));
+        }
+
+        (ext, outcome)
+    };
+
+    if !whole_outcome.mismatches.is_empty() {
+        return whole_outcome;
+    }
+
+    let mut matching_elems = Vec::with_capacity(shared_elems.len());
+
+    for (index, (actual, expected)) in shared_elems {
+        let local_outcome = unify_pool(env, pool, actual, expected, ctx.mode);
+
+        if local_outcome.mismatches.is_empty() {
+            matching_elems.push((index, actual));
+        }
+
+        whole_outcome.union(local_outcome);
+    }
+
+    if !whole_outcome.mismatches.is_empty() {
+        return whole_outcome;
+    }
+
+    // pull elements in from the ext_var
+    let (ext_elems, new_ext_var) = TupleElems::empty().sorted_iterator_and_ext(env.subs, ext);
+    let all_elems = merge_sorted(matching_elems, ext_elems);
+    let elems = TupleElems::insert_into_subs(env.subs, all_elems);
+
+    let merge_outcome = merge(env, ctx, Structure(FlatType::Tuple(elems, new_ext_var)));
+    whole_outcome.union(merge_outcome);
+    whole_outcome
+}
+
 enum OtherFields {
     None,
     Other(RecordFields, RecordFields),
@@ -2582,6 +2689,12 @@ fn unify_flat_type<M: MetaCollector>(
             unify_record(env, pool, ctx, *fields1, *ext1, *fields2, *ext2)
         }
 
+        (EmptyTuple, EmptyTuple) => merge(env, ctx, Structure(*left)),
+
+        (Tuple(elems1, ext1), Tuple(elems2, ext2)) => {
+            unify_tuple(env, pool, ctx, *elems1, *ext1, *elems2, *ext2)
+        }
+
         (EmptyTagUnion, EmptyTagUnion) => merge(env, ctx, Structure(*left)),
 
         (TagUnion(tags, ext), EmptyTagUnion) if tags.is_empty() => {
@@ -2778,11 +2891,11 @@ fn unify_rigid<M: MetaCollector>(
             // If the other is flex, rigid wins!
             merge(env, ctx, RigidVar(*name))
         }
-        FlexAbleVar(_, other_ability) => {
+        FlexAbleVar(_, other_abilities) => {
             // Mismatch - Rigid can unify with FlexAble only when the Rigid has an ability
             // bound as well, otherwise the user failed to correctly annotate the bound.
             mismatch!(
-                %not_able, ctx.first, *other_ability,
+                %not_able, ctx.first, env.subs.get_subs_slice(*other_abilities).to_vec(),
                 "Rigid {:?} with FlexAble {:?}", ctx.first, other
             )
         }
@@ -2814,27 +2927,27 @@ fn unify_rigid_able<M: MetaCollector>(
     env: &mut Env,
     ctx: &Context,
     name: &SubsIndex<Lowercase>,
-    ability: Symbol,
+    abilities: SubsSlice<Symbol>,
     other: &Content,
 ) -> Outcome<M> {
     match other {
         FlexVar(_) => {
-            // If the other is flex, rigid wins!
-            merge(env, ctx, RigidVar(*name))
-        }
-        FlexAbleVar(_, other_ability) => {
-            if ability == *other_ability {
-                // The ability bounds are the same, so rigid wins!
-                merge(env, ctx, RigidAbleVar(*name, ability))
+            // If the other is flex, rigid wins, keeping its ability bound!
+            merge(env, ctx, RigidAbleVar(*name, abilities))
+        }
+        FlexAbleVar(_, other_abilities) => {
+            let missing = missing_abilities(env.subs, *other_abilities, abilities);
+            if missing.is_empty() {
+                // The rigid has every ability the flex is bound to, so rigid wins!
+                merge(env, ctx, RigidAbleVar(*name, abilities))
             } else {
-                // Mismatch for now.
                 // TODO check ability hierarchies.
                 mismatch!(
-                    %not_able, ctx.second, ability,
-                    "RigidAble {:?} with ability {:?} not compatible with ability {:?}",
+                    %not_able, ctx.first, missing,
+                    "RigidAble {:?} with abilities {:?} not compatible with abilities {:?}",
                     ctx.first,
-                    ability,
-                    other_ability
+                    abilities,
+                    other_abilities
                 )
             }
         }
@@ -2872,10 +2985,10 @@ fn unify_flex<M: MetaCollector>(
             merge(env, ctx, FlexVar(opt_name))
         }
 
-        FlexAbleVar(opt_other_name, ability) => {
+        FlexAbleVar(opt_other_name, abilities) => {
             // Prefer using right's name.
             let opt_name = (opt_other_name).or(*opt_name);
-            merge(env, ctx, FlexAbleVar(opt_name, *ability))
+            merge(env, ctx, FlexAbleVar(opt_name, *abilities))
         }
 
         RigidVar(_)
@@ -2899,89 +3012,110 @@ fn unify_flex_able<M: MetaCollector>(
     env: &mut Env,
     ctx: &Context,
     opt_name: &Option<SubsIndex<Lowercase>>,
-    ability: Symbol,
+    abilities: SubsSlice<Symbol>,
     other: &Content,
 ) -> Outcome<M> {
     match other {
         FlexVar(opt_other_name) => {
             // Prefer using right's name.
             let opt_name = (opt_other_name).or(*opt_name);
-            merge(env, ctx, FlexAbleVar(opt_name, ability))
+            merge(env, ctx, FlexAbleVar(opt_name, abilities))
         }
 
-        FlexAbleVar(opt_other_name, other_ability) => {
+        FlexAbleVar(opt_other_name, other_abilities) => {
             // Prefer the right's name when possible.
             let opt_name = (opt_other_name).or(*opt_name);
 
-            if ability == *other_ability {
-                merge(env, ctx, FlexAbleVar(opt_name, ability))
-            } else {
-                // Ability names differ; mismatch for now.
-                // TODO check ability hierarchies.
-                mismatch!(
-                    %not_able, ctx.second, ability,
-                    "FlexAble {:?} with ability {:?} not compatible with ability {:?}",
-                    ctx.first,
-                    ability,
-                    other_ability
-                )
-            }
+            // The unified variable must have the abilities of both sides.
+            let abilities = union_abilities(env.subs, abilities, *other_abilities);
+            merge(env, ctx, FlexAbleVar(opt_name, abilities))
         }
 
-        RigidAbleVar(_, other_ability) => {
-            if ability == *other_ability {
+        RigidAbleVar(_, other_abilities) => {
+            let missing = missing_abilities(env.subs, abilities, *other_abilities);
+            if missing.is_empty() {
                 merge(env, ctx, *other)
             } else {
-                mismatch!(%not_able, ctx.second, ability, "RigidAble {:?} vs {:?}", ability, other_ability)
+                mismatch!(%not_able, ctx.second, missing, "RigidAble {:?} vs {:?}", abilities, other_abilities)
             }
         }
 
         RigidVar(_) => mismatch!("FlexAble can never unify with non-able Rigid"),
-        RecursionVar { .. } => mismatch!("FlexAble with RecursionVar"),
+        RecursionVar { structure, .. } => {
+            // The recursion var stands for the recursive structure, which is what must
+            // implement the ability.
+            merge_flex_able_with_concrete(env, ctx, ctx.first, abilities, *other, |_| {
+                Obligated::Adhoc(*structure)
+            })
+        }
         LambdaSet(..) => mismatch!("FlexAble with LambdaSet"),
 
         Alias(name, _args, _real_var, AliasKind::Opaque) => {
             // Opaque type wins
-            merge_flex_able_with_concrete(
-                env,
-                ctx,
-                ctx.first,
-                ability,
-                *other,
-                opaque_obligation(*name, ctx.second),
-            )
+            merge_flex_able_with_concrete(env, ctx, ctx.first, abilities, *other, |ability| {
+                opaque_obligation(*name, ctx.second, ability)
+            })
         }
 
         Structure(_) | Alias(_, _, _, AliasKind::Structural) | RangedNumber(..) => {
             // Structural type wins.
-            merge_flex_able_with_concrete(
-                env,
-                ctx,
-                ctx.first,
-                ability,
-                *other,
-                Obligated::Adhoc(ctx.second),
-            )
+            merge_flex_able_with_concrete(env, ctx, ctx.first, abilities, *other, |_| {
+                Obligated::Adhoc(ctx.second)
+            })
         }
 
         Error => merge(env, ctx, Error),
     }
 }
 
+/// The abilities of `abilities` that `available` does not have.
+fn missing_abilities(
+    subs: &Subs,
+    abilities: SubsSlice<Symbol>,
+    available: SubsSlice<Symbol>,
+) -> Vec<Symbol> {
+    let available = subs.get_subs_slice(available);
+    subs.get_subs_slice(abilities)
+        .iter()
+        .filter(|ability| !available.contains(ability))
+        .copied()
+        .collect()
+}
+
+fn union_abilities(
+    subs: &mut Subs,
+    abilities1: SubsSlice<Symbol>,
+    abilities2: SubsSlice<Symbol>,
+) -> SubsSlice<Symbol> {
+    if missing_abilities(subs, abilities2, abilities1).is_empty() {
+        abilities1
+    } else if missing_abilities(subs, abilities1, abilities2).is_empty() {
+        abilities2
+    } else {
+        let mut union = subs.get_abilities(abilities1);
+        for &ability in subs.get_subs_slice(abilities2) {
+            union.insert(ability);
+        }
+        subs.push_abilities(&union)
+    }
+}
+
 fn merge_flex_able_with_concrete<M: MetaCollector>(
     env: &mut Env,
     ctx: &Context,
     flex_able_var: Variable,
-    ability: Symbol,
+    abilities: SubsSlice<Symbol>,
     concrete_content: Content,
-    concrete_obligation: Obligated,
+    concrete_obligation: impl Fn(Symbol) -> Obligated,
 ) -> Outcome<M> {
     let mut outcome = merge(env, ctx, concrete_content);
-    let must_implement_ability = MustImplementAbility {
-        typ: concrete_obligation,
-        ability,
-    };
-    outcome.must_implement_ability.push(must_implement_ability);
+    for &ability in env.subs.get_subs_slice(abilities) {
+        let must_implement_ability = MustImplementAbility {
+            typ: concrete_obligation(ability),
+            ability,
+        };
+        outcome.must_implement_ability.push(must_implement_ability);
+    }
 
     // Figure which, if any, lambda sets should be specialized thanks to the flex able var
     // being instantiated. Now as much as I would love to do that here, we don't, because we might
@@ -3035,7 +3169,19 @@ fn unify_recursion<M: MetaCollector>(
             mismatch!("RecursionVar {:?} with rigid {:?}", ctx.first, &other)
         }
 
-        FlexAbleVar(..) | RigidAbleVar(..) => {
+        FlexAbleVar(_, abilities) => merge_flex_able_with_concrete(
+            env,
+            ctx,
+            ctx.second,
+            *abilities,
+            RecursionVar {
+                structure,
+                opt_name: *opt_name,
+            },
+            |_| Obligated::Adhoc(structure),
+        ),
+
+        RigidAbleVar(..) => {
             mismatch!("RecursionVar {:?} with able var {:?}", ctx.first, &other)
         }
 
@@ -0,0 +1,28 @@
+// The host for `roc glue` specs. The spec is built as a dynamic library and
+// loaded by the `roc` CLI, which calls `makeGlueForHost` directly, so all
+// this needs to provide is the memory management Roc expects from its host.
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+void* roc_alloc(size_t size, unsigned int alignment) { return malloc(size); }
+
+void* roc_realloc(void* ptr, size_t new_size, size_t old_size,
+                  unsigned int alignment) {
+  return realloc(ptr, new_size);
+}
+
+void roc_dealloc(void* ptr, unsigned int alignment) { free(ptr); }
+
+void roc_panic(void* ptr, unsigned int alignment) {
+  char* msg = (char*)ptr;
+  fprintf(stderr, "The glue spec crashed with message\n\n    %s\n\n", msg);
+  exit(1);
+}
+
+void* roc_memcpy(void* dest, const void* src, size_t n) {
+  return memcpy(dest, src, n);
+}
+
+void* roc_memset(void* str, int c, size_t n) { return memset(str, c, n); }
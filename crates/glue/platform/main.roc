@@ -1,13 +1,17 @@
-platform "roc-lang/rbt"
-    requires {} { makeGlue : Str -> Types }
+platform "roc-lang/glue"
+    requires {} { makeGlue : List Types -> Result (List File) Str }
     exposes []
     packages {}
     imports []
     provides [makeGlueForHost]
 
-makeGlueForHost : Str -> Types
+makeGlueForHost : List Types -> Result (List File) Str
 makeGlueForHost = makeGlue
 
+## A file for `roc glue` to write into its output directory.
+## The name is relative to that directory.
+File : { name : Str, content : Str }
+
 # TODO move into separate Target.roc interface once glue works across interfaces.
 Target : {
     architecture: Architecture,
@@ -28,9 +32,9 @@ OperatingSystem : [
     Wasi,
 ]
 
-TypeId := Nat
+TypeId : Nat
 
-Types := {
+Types : {
     # These are all indexed by TypeId
     types: List RocType,
     sizes: List U32,
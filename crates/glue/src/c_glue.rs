@@ -0,0 +1,662 @@
+use crate::types::{RocNum, RocTagUnion, RocType, TypeId, Types};
+use indexmap::IndexMap;
+use roc_collections::MutSet;
+use roc_target::{Architecture, TargetInfo};
+use std::fmt::Write;
+
+pub static HEADER: &[u8] = include_bytes!("../templates/header.h");
+pub static FOOTER: &str = r#"
+#ifdef __cplusplus
+}
+#endif
+
+#endif // ROC_APP_H
+"#;
+const INDENT: &str = "    ";
+
+pub fn emit(types_and_targets: &[(Types, TargetInfo)]) -> String {
+    // Unlike Rust's #[cfg(...)], a C declaration has to come before anything that
+    // uses it, so we can't interleave per-target declarations the way rust_glue does.
+    // Instead, we generate each target's declarations in full, and then share the
+    // ones which came out identical between targets.
+    let mut bodies: IndexMap<String, Vec<TargetInfo>> = IndexMap::default();
+
+    for (types, target_info) in types_and_targets {
+        let body = emit_target(types, *target_info);
+
+        bodies.entry(body).or_default().push(*target_info);
+    }
+
+    let mut buf = String::new();
+
+    for (index, (body, targets)) in bodies.iter().enumerate() {
+        let directive = if index == 0 { "#if" } else { "#elif" };
+        let conditions: Vec<&str> = targets
+            .iter()
+            .map(|target_info| arch_to_condition(target_info.architecture))
+            .collect();
+
+        write!(
+            buf,
+            "\n{directive} {}\n{body}",
+            conditions.join(" \\\n    || ")
+        )
+        .unwrap();
+    }
+
+    if !bodies.is_empty() {
+        buf.push_str("\n#else\n#error \"roc glue did not generate declarations for this architecture\"\n#endif\n");
+    }
+
+    buf
+}
+
+fn emit_target(types: &Types, target_info: TargetInfo) -> String {
+    let mut buf = String::new();
+    let mut results = MutSet::default();
+
+    // Recursive tag unions are pointers to their heap-allocated payloads, and those
+    // payloads can refer back to the tag union itself. So, declare the pointers
+    // before anything else; C is fine with them pointing to incomplete types.
+    for id in types.ids() {
+        if let RocType::TagUnion(tag_union) = types.get_type(id) {
+            add_tag_union_pointer(tag_union, types, target_info, &mut buf);
+        }
+    }
+
+    // Types are added to Types only after everything they contain,
+    // so the order of their ids is an order in which C can declare them.
+    for id in types.ids() {
+        add_type(id, types, &mut results, &mut buf);
+    }
+
+    add_entry_points(types, &mut buf);
+
+    buf
+}
+
+fn add_type(id: TypeId, types: &Types, results: &mut MutSet<String>, buf: &mut String) {
+    match types.get_type(id) {
+        RocType::Struct { name, fields } => {
+            let fields: Vec<(String, TypeId)> = fields
+                .iter()
+                .map(|(label, field_id)| (escape_kw(label.clone()), *field_id))
+                .collect();
+
+            add_struct(name, &fields, Some(id), types, buf);
+        }
+        RocType::TagUnionPayload { name, fields } => {
+            // Tag union payloads have numbered fields, so we prefix them
+            // with an "f" because C doesn't allow struct fields to be numbers.
+            let fields: Vec<(String, TypeId)> = fields
+                .iter()
+                .map(|(label, field_id)| (format!("f{label}"), *field_id))
+                .collect();
+
+            // Types records a payload's size as its whole tag union's size,
+            // so there's no size to check here; the tag union checks it instead.
+            add_struct(name, &fields, None, types, buf);
+        }
+        RocType::TagUnion(RocTagUnion::SingleTagStruct {
+            name,
+            payload_fields,
+            ..
+        }) => {
+            // Store single-tag unions as structs, because they have only one alternative.
+            let fields: Vec<(String, TypeId)> = payload_fields
+                .iter()
+                .enumerate()
+                .map(|(index, field_id)| (format!("f{index}"), *field_id))
+                .collect();
+
+            add_struct(name, &fields, Some(id), types, buf);
+        }
+        RocType::TagUnion(RocTagUnion::Enumeration { name, tags, size }) => {
+            let name = escape_kw(name.clone());
+            let bits = size * 8;
+
+            // C enums are always the size of an int, so store the enumeration
+            // as an integer of the right size, and give its tags names.
+            write!(buf, "\ntypedef uint{bits}_t {name};\n\nenum {{\n").unwrap();
+
+            for (index, tag_name) in tags.iter().enumerate() {
+                writeln!(buf, "{INDENT}{name}_{tag_name} = {index},").unwrap();
+            }
+
+            buf.push_str("};\n");
+        }
+        RocType::TagUnion(RocTagUnion::NonRecursive {
+            name,
+            tags,
+            discriminant_size,
+            discriminant_offset,
+        }) => {
+            let name = escape_kw(name.clone());
+            let tag_names = tags.iter().map(|(tag_name, _)| tag_name.as_str());
+
+            add_discriminant(&name, tag_names, buf);
+
+            let has_payload = add_payload_union(&name, tags, types, buf);
+            let bits = discriminant_size * 8;
+
+            write!(buf, "\nstruct {name} {{\n").unwrap();
+
+            if has_payload {
+                writeln!(buf, "{INDENT}union union_{name} payload;").unwrap();
+            }
+
+            writeln!(buf, "{INDENT}uint{bits}_t discriminant;").unwrap();
+            buf.push_str("};\n\n");
+
+            add_size_assert(&name, id, types, buf);
+
+            writeln!(
+                buf,
+                "ROC_STATIC_ASSERT(offsetof(struct {name}, discriminant) == {discriminant_offset}, \"struct {name} has its discriminant in the wrong place\");"
+            )
+            .unwrap();
+        }
+        RocType::TagUnion(RocTagUnion::Recursive { name, tags, .. })
+        | RocType::TagUnion(RocTagUnion::NullableWrapped { name, tags, .. }) => {
+            // The pointer to this was already declared; all that's left is what it points to.
+            add_payload_union(&escape_kw(name.clone()), tags, types, buf);
+        }
+        RocType::RocResult(ok_id, err_id) => {
+            let name = result_name(*ok_id, *err_id, types);
+
+            // Many different Results can have the same C declaration, since
+            // e.g. a RocList is the same struct no matter what its elements are.
+            if results.insert(name.clone()) {
+                let tags = [
+                    ("Err".to_string(), Some(*err_id)),
+                    ("Ok".to_string(), Some(*ok_id)),
+                ];
+                let has_payload = add_payload_union(&name, &tags, types, buf);
+
+                write!(buf, "\nstruct {name} {{\n").unwrap();
+
+                if has_payload {
+                    writeln!(buf, "{INDENT}union union_{name} payload;").unwrap();
+                }
+
+                writeln!(buf, "{INDENT}uint8_t discriminant;").unwrap();
+                buf.push_str("};\n\n");
+
+                add_size_assert(&name, id, types, buf);
+            }
+        }
+        RocType::TagUnion(RocTagUnion::NullableUnwrapped { .. })
+        | RocType::TagUnion(RocTagUnion::NonNullableUnwrapped { .. }) => {
+            // These point directly to their payload's struct, so there's
+            // nothing more to declare than the pointer itself.
+        }
+        RocType::Unit
+        | RocType::EmptyTagUnion
+        | RocType::RecursivePointer { .. }
+        | RocType::RocStr
+        | RocType::Bool
+        | RocType::Num(_)
+        | RocType::RocList(_)
+        | RocType::RocDict(_, _)
+        | RocType::RocSet(_)
+        | RocType::RocBox(_) => {
+            // These types don't need to be declared in C; they are either
+            // zero-sized or declared in the header.
+        }
+        RocType::Function { .. } => {
+            // TODO actually generate glue functions.
+        }
+    }
+}
+
+/// Declares the pointer (and some inline functions for working with it)
+/// which represents a recursive tag union.
+fn add_tag_union_pointer(
+    tag_union: &RocTagUnion,
+    types: &Types,
+    target_info: TargetInfo,
+    buf: &mut String,
+) {
+    match tag_union {
+        RocTagUnion::Recursive { name, tags, .. }
+        | RocTagUnion::NullableWrapped { name, tags, .. } => {
+            let name = escape_kw(name.clone());
+            let tag_names = tags.iter().map(|(tag_name, _)| tag_name.as_str());
+            let opt_null_tag = match tag_union {
+                RocTagUnion::NullableWrapped {
+                    index_of_null_tag, ..
+                } => Some(tags[*index_of_null_tag as usize].0.as_str()),
+                _ => None,
+            };
+
+            add_discriminant(&name, tag_names, buf);
+
+            write!(
+                buf,
+                r#"
+// A pointer to a heap-allocated union_{name}, which is reference counted like
+// any other Roc allocation. Use {name}_payload to get at the payload itself.
+struct {name} {{
+    union union_{name}* pointer;
+}};
+"#
+            )
+            .unwrap();
+
+            // The null tag has no payload, so it doesn't need room in the pointer.
+            let non_null_tags = tags.len() - opt_null_tag.iter().count();
+
+            let mut body = String::new();
+
+            if let Some(null_tag) = opt_null_tag {
+                write!(
+                    body,
+                    "{INDENT}if (tag_union.pointer == NULL) {{\n{INDENT}{INDENT}return discriminant_{name}_{null_tag};\n{INDENT}}}\n\n"
+                )
+                .unwrap();
+            }
+
+            let payload_body;
+
+            if stores_discriminant_in_pointer(non_null_tags, target_info) {
+                let mask = tagged_pointer_bitmask(target_info.architecture);
+
+                write!(
+                    body,
+                    "{INDENT}return (enum discriminant_{name})((uintptr_t)tag_union.pointer & {mask:#x});"
+                )
+                .unwrap();
+
+                payload_body = format!(
+                    "(union union_{name}*)((uintptr_t)tag_union.pointer & ~(uintptr_t){mask:#x})"
+                );
+            } else {
+                let (discriminant_size, discriminant_offset) = match tag_union {
+                    RocTagUnion::Recursive {
+                        discriminant_size,
+                        discriminant_offset,
+                        ..
+                    }
+                    | RocTagUnion::NullableWrapped {
+                        discriminant_size,
+                        discriminant_offset,
+                        ..
+                    } => (discriminant_size, discriminant_offset),
+                    _ => unreachable!(),
+                };
+                let bits = discriminant_size * 8;
+
+                write!(
+                    body,
+                    "{INDENT}uint8_t* payload = (uint8_t*)tag_union.pointer;\n\n{INDENT}return (enum discriminant_{name})*(uint{bits}_t*)(payload + {discriminant_offset});"
+                )
+                .unwrap();
+
+                payload_body = "tag_union.pointer".to_string();
+            }
+
+            write!(
+                buf,
+                r#"
+static inline enum discriminant_{name} {name}_discriminant(struct {name} tag_union) {{
+{body}
+}}
+
+static inline union union_{name}* {name}_payload(struct {name} tag_union) {{
+    return {payload_body};
+}}
+"#
+            )
+            .unwrap();
+        }
+        RocTagUnion::NullableUnwrapped {
+            name,
+            null_tag,
+            non_null_tag,
+            non_null_payload,
+            ..
+        } => {
+            let name = escape_kw(name.clone());
+            let payload_type = type_name(*non_null_payload, types);
+            let mut tag_names = [null_tag.as_str(), non_null_tag.as_str()];
+
+            tag_names.sort_unstable();
+
+            add_discriminant(&name, tag_names.into_iter(), buf);
+
+            write!(
+                buf,
+                r#"
+// A pointer to a heap-allocated {payload_type}, which is reference
+// counted like any other Roc allocation. NULL means this is {null_tag}.
+struct {name} {{
+    {payload_type}* pointer;
+}};
+
+static inline enum discriminant_{name} {name}_discriminant(struct {name} tag_union) {{
+    if (tag_union.pointer == NULL) {{
+        return discriminant_{name}_{null_tag};
+    }} else {{
+        return discriminant_{name}_{non_null_tag};
+    }}
+}}
+"#
+            )
+            .unwrap();
+        }
+        RocTagUnion::NonNullableUnwrapped { name, payload, .. } => {
+            let name = escape_kw(name.clone());
+            let payload_type = type_name(*payload, types);
+
+            write!(
+                buf,
+                r#"
+// A pointer to a heap-allocated {payload_type}, which is
+// reference counted like any other Roc allocation.
+struct {name} {{
+    {payload_type}* pointer;
+}};
+"#
+            )
+            .unwrap();
+        }
+        RocTagUnion::Enumeration { .. }
+        | RocTagUnion::NonRecursive { .. }
+        | RocTagUnion::SingleTagStruct { .. } => {
+            // These aren't recursive, so they don't need a pointer.
+        }
+    }
+}
+
+/// The tag union's discriminant, e.g.
+///
+/// enum discriminant_MyTagUnion {
+///     discriminant_MyTagUnion_Bar = 0,
+///     discriminant_MyTagUnion_Foo = 1,
+/// };
+fn add_discriminant<'a, I: Iterator<Item = &'a str>>(name: &str, tag_names: I, buf: &mut String) {
+    write!(buf, "\nenum discriminant_{name} {{\n").unwrap();
+
+    for (index, tag_name) in tag_names.enumerate() {
+        writeln!(buf, "{INDENT}discriminant_{name}_{tag_name} = {index},").unwrap();
+    }
+
+    buf.push_str("};\n");
+}
+
+/// Declares a C union of the tag union's payloads, leaving out zero-sized ones.
+/// Returns false if they were all zero-sized, in which case no union was declared.
+fn add_payload_union(
+    name: &str,
+    tags: &[(String, Option<TypeId>)],
+    types: &Types,
+    buf: &mut String,
+) -> bool {
+    let mut payloads = String::new();
+
+    for (tag_name, opt_payload_id) in tags {
+        if let Some(payload_id) = opt_payload_id {
+            if types.size_ignoring_alignment(*payload_id) > 0 {
+                let payload_type = type_name(*payload_id, types);
+
+                writeln!(payloads, "{INDENT}{payload_type} {tag_name};").unwrap();
+            }
+        }
+    }
+
+    if payloads.is_empty() {
+        false
+    } else {
+        write!(buf, "\nunion union_{name} {{\n{payloads}}};\n").unwrap();
+
+        true
+    }
+}
+
+fn add_struct(
+    name: &str,
+    fields: &[(String, TypeId)],
+    opt_struct_id: Option<TypeId>,
+    types: &Types,
+    buf: &mut String,
+) {
+    let name = escape_kw(name.to_string());
+    let mut body = String::new();
+
+    for (label, field_id) in fields {
+        // C doesn't allow zero-sized fields, and they don't affect the layout anyway.
+        if types.size_ignoring_alignment(*field_id) > 0 {
+            let field_type = type_name(*field_id, types);
+
+            writeln!(body, "{INDENT}{field_type} {label};").unwrap();
+        }
+    }
+
+    // C doesn't allow empty structs either, and since this one is zero-sized,
+    // it will never be used as a field.
+    if !body.is_empty() {
+        write!(buf, "\nstruct {name} {{\n{body}}};\n").unwrap();
+
+        if let Some(struct_id) = opt_struct_id {
+            buf.push('\n');
+
+            add_size_assert(&name, struct_id, types, buf);
+        }
+    }
+}
+
+fn add_size_assert(name: &str, id: TypeId, types: &Types, buf: &mut String) {
+    let size = types.size_rounded_to_alignment(id);
+
+    writeln!(
+        buf,
+        "ROC_STATIC_ASSERT(sizeof(struct {name}) == {size}, \"struct {name} has the wrong size\");"
+    )
+    .unwrap();
+}
+
+/// Prototypes for the functions the platform exposes to the host, e.g.
+///
+/// void roc__mainForHost_1_exposed_generic(struct RocStr* ret, struct RocList* arg0);
+/// int64_t roc__mainForHost_size(void);
+fn add_entry_points(types: &Types, buf: &mut String) {
+    if types.entry_points().is_empty() {
+        return;
+    }
+
+    buf.push('\n');
+
+    for (name, id) in types.entry_points() {
+        let (arg_ids, ret_id) = match types.get_type(*id) {
+            RocType::Function { args, ret, .. } => (args.as_slice(), *ret),
+            _ => (&[] as &[TypeId], *id),
+        };
+
+        let mut params = vec![format!("{}* ret", ret_type_name(ret_id, types))];
+
+        for (index, arg_id) in arg_ids.iter().enumerate() {
+            // Zero-sized arguments aren't passed at all.
+            if types.size_ignoring_alignment(*arg_id) == 0 {
+                continue;
+            }
+
+            let arg_type = type_name(*arg_id, types);
+
+            // Strings and lists are passed by pointer; everything else by value.
+            match types.get_type(*arg_id) {
+                RocType::RocStr | RocType::RocList(_) => {
+                    params.push(format!("{arg_type}* arg{index}"));
+                }
+                _ => {
+                    params.push(format!("{arg_type} arg{index}"));
+                }
+            }
+        }
+
+        writeln!(
+            buf,
+            "void roc__{name}_1_exposed_generic({});",
+            params.join(", ")
+        )
+        .unwrap();
+        writeln!(buf, "int64_t roc__{name}_size(void);").unwrap();
+    }
+}
+
+fn ret_type_name(id: TypeId, types: &Types) -> String {
+    if types.size_ignoring_alignment(id) == 0 {
+        "void".to_string()
+    } else {
+        type_name(id, types)
+    }
+}
+
+fn type_name(id: TypeId, types: &Types) -> String {
+    match types.get_type(id) {
+        RocType::Unit | RocType::EmptyTagUnion => "void".to_string(),
+        RocType::RocStr => "struct RocStr".to_string(),
+        RocType::Bool => "bool".to_string(),
+        RocType::Num(RocNum::U8) => "uint8_t".to_string(),
+        RocType::Num(RocNum::U16) => "uint16_t".to_string(),
+        RocType::Num(RocNum::U32) => "uint32_t".to_string(),
+        RocType::Num(RocNum::U64) => "uint64_t".to_string(),
+        RocType::Num(RocNum::U128) => "struct RocU128".to_string(),
+        RocType::Num(RocNum::I8) => "int8_t".to_string(),
+        RocType::Num(RocNum::I16) => "int16_t".to_string(),
+        RocType::Num(RocNum::I32) => "int32_t".to_string(),
+        RocType::Num(RocNum::I64) => "int64_t".to_string(),
+        RocType::Num(RocNum::I128) => "struct RocI128".to_string(),
+        RocType::Num(RocNum::F32) => "float".to_string(),
+        RocType::Num(RocNum::F64) => "double".to_string(),
+        RocType::Num(RocNum::F128) => "long double".to_string(),
+        RocType::Num(RocNum::Dec) => "struct RocDec".to_string(),
+        RocType::RocDict(_, _) => "struct RocDict".to_string(),
+        RocType::RocSet(_) => "struct RocSet".to_string(),
+        RocType::RocList(_) => "struct RocList".to_string(),
+        RocType::RocBox(_) => "struct RocBox".to_string(),
+        RocType::RocResult(ok_id, err_id) => {
+            format!("struct {}", result_name(*ok_id, *err_id, types))
+        }
+        RocType::Struct { name, .. }
+        | RocType::TagUnionPayload { name, .. }
+        | RocType::TagUnion(RocTagUnion::NonRecursive { name, .. })
+        | RocType::TagUnion(RocTagUnion::Recursive { name, .. })
+        | RocType::TagUnion(RocTagUnion::NullableWrapped { name, .. })
+        | RocType::TagUnion(RocTagUnion::NullableUnwrapped { name, .. })
+        | RocType::TagUnion(RocTagUnion::NonNullableUnwrapped { name, .. })
+        | RocType::TagUnion(RocTagUnion::SingleTagStruct { name, .. }) => {
+            format!("struct {}", escape_kw(name.clone()))
+        }
+        RocType::TagUnion(RocTagUnion::Enumeration { name, .. }) => escape_kw(name.clone()),
+        RocType::RecursivePointer(content) => type_name(*content, types),
+        RocType::Function { name, .. } => escape_kw(name.clone()),
+    }
+}
+
+/// C has no generics, so each Result gets its own struct, e.g. RocResult_RocStr_uint8_t
+fn result_name(ok_id: TypeId, err_id: TypeId, types: &Types) -> String {
+    fn mangle(id: TypeId, types: &Types) -> String {
+        match types.get_type(id) {
+            RocType::Unit | RocType::EmptyTagUnion => "Unit".to_string(),
+            _ => {
+                let type_name = type_name(id, types);
+
+                type_name
+                    .strip_prefix("struct ")
+                    .unwrap_or(&type_name)
+                    .replace(' ', "_")
+            }
+        }
+    }
+
+    format!(
+        "RocResult_{}_{}",
+        mangle(ok_id, types),
+        mangle(err_id, types)
+    )
+}
+
+fn arch_to_condition(architecture: Architecture) -> &'static str {
+    match architecture {
+        Architecture::X86_64 => "defined(__x86_64__) || defined(_M_X64)",
+        Architecture::X86_32 => "defined(__i386__) || defined(_M_IX86)",
+        Architecture::Aarch64 => "defined(__aarch64__) || defined(_M_ARM64)",
+        Architecture::Aarch32 => "defined(__arm__) || defined(_M_ARM)",
+        Architecture::Wasm32 => "defined(__wasm32__)",
+    }
+}
+
+/// Whether the discriminant of a recursive tag union (with the given number
+/// of non-null tags) is stored in the unused low bits of its pointer,
+/// as opposed to in the heap allocation alongside the payload.
+fn stores_discriminant_in_pointer(non_null_tags: usize, target_info: TargetInfo) -> bool {
+    non_null_tags < target_info.ptr_width() as usize
+}
+
+fn tagged_pointer_bitmask(architecture: Architecture) -> u8 {
+    match architecture {
+        // On a 64-bit system, pointers have 3 bits that are unused
+        Architecture::X86_64 | Architecture::Aarch64 => 0b0000_0111,
+        // On a 32-bit system, pointers have 2 bits that are unused
+        Architecture::X86_32 | Architecture::Aarch32 | Architecture::Wasm32 => 0b0000_0011,
+    }
+}
+
+const RESERVED_KEYWORDS: &[&str] = &[
+    "auto",
+    "break",
+    "case",
+    "char",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extern",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "register",
+    "restrict",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "struct",
+    "switch",
+    "typedef",
+    "union",
+    "unsigned",
+    "void",
+    "volatile",
+    "while",
+    "bool",
+    "class",
+    "delete",
+    "new",
+    "namespace",
+    "private",
+    "protected",
+    "public",
+    "template",
+    "this",
+    "throw",
+    "try",
+    "virtual",
+];
+
+/// Escape a C (or C++) reserved keyword, if necessary.
+fn escape_kw(input: String) -> String {
+    if RESERVED_KEYWORDS.contains(&input.as_str()) {
+        format!("{input}_")
+    } else {
+        input
+    }
+}
@@ -1,3 +1,4 @@
+pub mod c_glue;
 pub mod enums;
 pub mod load;
 pub mod rust_glue;
@@ -1,3 +1,4 @@
+use crate::c_glue;
 use crate::rust_glue;
 use crate::types::{Env, Types};
 use bumpalo::Bump;
@@ -5,6 +6,7 @@ use roc_intern::GlobalInterner;
 use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
 use roc_reporting::report::RenderTarget;
 use roc_target::{Architecture, OperatingSystem, TargetInfo};
+use std::ffi::OsStr;
 use std::fs::File;
 use std::io::{self, ErrorKind, Write};
 use std::path::{Path, PathBuf};
@@ -17,7 +19,7 @@ pub struct IgnoreErrors {
 }
 
 impl IgnoreErrors {
-    const NONE: Self = IgnoreErrors { can: false };
+    pub const NONE: Self = IgnoreErrors { can: false };
 }
 
 pub fn generate(input_path: &Path, output_path: &Path) -> io::Result<i32> {
@@ -37,10 +39,26 @@ pub fn generate(input_path: &Path, output_path: &Path) -> io::Result<i32> {
                 process::exit(1);
             });
 
-            let mut buf = std::str::from_utf8(rust_glue::HEADER).unwrap().to_string();
-            let body = rust_glue::emit(&types_and_targets);
+            let buf = match output_path.extension().and_then(OsStr::to_str) {
+                // A .h file gets C glue (e.g. roc_app.h), and anything else gets Rust glue.
+                Some("h") => {
+                    let mut buf = std::str::from_utf8(c_glue::HEADER).unwrap().to_string();
+                    let body = c_glue::emit(&types_and_targets);
 
-            buf.push_str(&body);
+                    buf.push_str(&body);
+                    buf.push_str(c_glue::FOOTER);
+
+                    buf
+                }
+                _ => {
+                    let mut buf = std::str::from_utf8(rust_glue::HEADER).unwrap().to_string();
+                    let body = rust_glue::emit(&types_and_targets);
+
+                    buf.push_str(&body);
+
+                    buf
+                }
+            };
 
             file.write_all(buf.as_bytes()).unwrap_or_else(|err| {
                 eprintln!(
@@ -92,6 +110,7 @@ pub fn load_types(
         mut declarations_by_id,
         mut solved,
         interns,
+        exposed_to_host,
         ..
     } = roc_load::load_and_typecheck(
         arena,
@@ -102,6 +121,7 @@ pub fn load_types(
             render: RenderTarget::Generic,
             threading,
             exec_mode: ExecutionMode::Check,
+            cache_dir: None,
         },
     )
     .unwrap_or_else(|problem| match problem {
@@ -133,7 +153,9 @@ pub fn load_types(
         use roc_can::expr::DeclarationTag::*;
 
         match decls.declarations[index] {
-            Value | Function(_) | Recursive(_) | TailRecursive(_) => Some(decls.variables[index]),
+            Value | Function(_) | Recursive(_) | TailRecursive(_) => {
+                Some((decls.symbols[index].value, decls.variables[index]))
+            }
             Destructure(_) => {
                 // figure out if we need to export non-identifier defs - when would that
                 // happen?
@@ -163,7 +185,7 @@ pub fn load_types(
         let types = {
             let mut env = Env::new(arena, subs, &interns, layout_interner.fork(), target_info);
 
-            env.vars_to_types(variables.clone())
+            env.vars_to_types(variables.clone(), &exposed_to_host)
         };
 
         types_and_targets.push((types, target_info));
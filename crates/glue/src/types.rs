@@ -1,4 +1,5 @@
 use crate::enums::Enums;
+use crate::glue;
 use crate::structs::Structs;
 use bumpalo::Bump;
 use fnv::FnvHashMap;
@@ -6,7 +7,7 @@ use roc_builtins::bitcode::{
     FloatWidth::*,
     IntWidth::{self, *},
 };
-use roc_collections::VecMap;
+use roc_collections::{MutMap, VecMap};
 use roc_module::{
     ident::TagName,
     symbol::{Interns, Symbol},
@@ -18,7 +19,7 @@ use roc_mono::layout::{
 use roc_target::TargetInfo;
 use roc_types::{
     subs::{Content, FlatType, GetSubsSlice, Subs, UnionLabels, UnionTags, Variable},
-    types::{AliasKind, RecordField},
+    types::RecordField,
 };
 use std::fmt::Display;
 
@@ -49,6 +50,8 @@ pub struct Types {
     /// This is important for declaration order in C; we need to output a
     /// type declaration earlier in the file than where it gets referenced by another type.
     deps: VecMap<TypeId, Vec<TypeId>>,
+    /// The values the platform exposes to the host (e.g. `mainForHost`), sorted by name.
+    entry_points: Vec<(String, TypeId)>,
     target: TargetInfo,
 }
 
@@ -61,6 +64,7 @@ impl Types {
             sizes: Vec::new(),
             aligns: Vec::new(),
             deps: VecMap::with_capacity(cap),
+            entry_points: Vec::new(),
         }
     }
 
@@ -413,6 +417,18 @@ impl Types {
         id
     }
 
+    pub fn add_entry_point(&mut self, name: String, id: TypeId) {
+        let index = self
+            .entry_points
+            .partition_point(|(existing, _)| existing < &name);
+
+        self.entry_points.insert(index, (name, id));
+    }
+
+    pub fn entry_points(&self) -> &[(String, TypeId)] {
+        &self.entry_points
+    }
+
     pub fn depends(&mut self, id: TypeId, depends_on: TypeId) {
         self.deps.get_or_insert(id, Vec::new).push(depends_on);
     }
@@ -659,6 +675,216 @@ pub enum RocTagUnion {
     },
 }
 
+/// Convert to the representation glue specs receive; this is the `Types`
+/// record from platform/main.roc, laid out the way Roc expects it.
+impl From<&Types> for glue::Types {
+    fn from(types: &Types) -> Self {
+        let deps = types
+            .deps
+            .iter()
+            .map(|(id, deps)| (id.0 as _, deps.iter().map(|dep| dep.0 as _).collect()))
+            .collect();
+        let types_by_name = types
+            .types_by_name
+            .iter()
+            .map(|(name, id)| (name.as_str().into(), id.0 as _))
+            .collect();
+
+        glue::Types {
+            aligns: types.aligns.as_slice().into(),
+            deps,
+            sizes: types.sizes.as_slice().into(),
+            types: types.types.iter().map(glue::RocType::from).collect(),
+            typesByName: types_by_name,
+            target: types.target.into(),
+        }
+    }
+}
+
+impl From<&RocType> for glue::RocType {
+    fn from(rc: &RocType) -> Self {
+        match rc {
+            RocType::RocStr => glue::RocType::RocStr,
+            RocType::Bool => glue::RocType::Bool,
+            RocType::RocResult(ok, err) => glue::RocType::RocResult(ok.0 as _, err.0 as _),
+            RocType::Num(num) => glue::RocType::Num((*num).into()),
+            RocType::RocList(elem) => glue::RocType::RocList(elem.0 as _),
+            RocType::RocDict(key, value) => glue::RocType::RocDict(key.0 as _, value.0 as _),
+            RocType::RocSet(elem) => glue::RocType::RocSet(elem.0 as _),
+            RocType::RocBox(elem) => glue::RocType::RocBox(elem.0 as _),
+            RocType::TagUnion(union) => glue::RocType::TagUnion(union.into()),
+            RocType::EmptyTagUnion => glue::RocType::EmptyTagUnion,
+            RocType::Struct { name, fields } => glue::RocType::Struct(glue::R2 {
+                name: name.as_str().into(),
+                fields: fields
+                    .iter()
+                    .map(|(name, id)| glue::R3 {
+                        name: name.as_str().into(),
+                        r#type: id.0 as _,
+                    })
+                    .collect(),
+            }),
+            RocType::TagUnionPayload { name, fields } => {
+                glue::RocType::TagUnionPayload(glue::R14 {
+                    name: name.as_str().into(),
+                    fields: fields
+                        .iter()
+                        .map(|(discriminant, id)| glue::R15 {
+                            discriminant: *discriminant as _,
+                            r#type: id.0 as _,
+                        })
+                        .collect(),
+                })
+            }
+            RocType::RecursivePointer(id) => glue::RocType::RecursivePointer(id.0 as _),
+            RocType::Function { name, args, ret } => glue::RocType::Function(glue::R1 {
+                name: name.as_str().into(),
+                args: args.iter().map(|arg| arg.0 as _).collect(),
+                ret: ret.0 as _,
+            }),
+            RocType::Unit => glue::RocType::Unit,
+        }
+    }
+}
+
+impl From<RocNum> for glue::RocNum {
+    fn from(num: RocNum) -> Self {
+        match num {
+            RocNum::I8 => glue::RocNum::I8,
+            RocNum::U8 => glue::RocNum::U8,
+            RocNum::I16 => glue::RocNum::I16,
+            RocNum::U16 => glue::RocNum::U16,
+            RocNum::I32 => glue::RocNum::I32,
+            RocNum::U32 => glue::RocNum::U32,
+            RocNum::I64 => glue::RocNum::I64,
+            RocNum::U64 => glue::RocNum::U64,
+            RocNum::I128 => glue::RocNum::I128,
+            RocNum::U128 => glue::RocNum::U128,
+            RocNum::F32 => glue::RocNum::F32,
+            RocNum::F64 => glue::RocNum::F64,
+            RocNum::F128 => glue::RocNum::F128,
+            RocNum::Dec => glue::RocNum::Dec,
+        }
+    }
+}
+
+impl From<&RocTagUnion> for glue::RocTagUnion {
+    fn from(union: &RocTagUnion) -> Self {
+        fn tags(tags: &[(String, Option<TypeId>)]) -> roc_std::RocList<glue::R7> {
+            tags.iter()
+                .map(|(name, payload)| glue::R7 {
+                    name: name.as_str().into(),
+                    payload: match payload {
+                        Some(id) => glue::U1::Some(id.0 as _),
+                        None => glue::U1::None,
+                    },
+                })
+                .collect()
+        }
+
+        match union {
+            RocTagUnion::Enumeration { name, tags, size } => {
+                glue::RocTagUnion::Enumeration(glue::R4 {
+                    name: name.as_str().into(),
+                    tags: tags.iter().map(|tag| tag.as_str().into()).collect(),
+                    size: *size,
+                })
+            }
+            RocTagUnion::NonRecursive {
+                name,
+                tags: union_tags,
+                discriminant_size,
+                discriminant_offset,
+            } => glue::RocTagUnion::NonRecursive(glue::R6 {
+                name: name.as_str().into(),
+                tags: tags(union_tags),
+                discriminantSize: *discriminant_size,
+                discriminantOffset: *discriminant_offset,
+            }),
+            RocTagUnion::Recursive {
+                name,
+                tags: union_tags,
+                discriminant_size,
+                discriminant_offset,
+            } => glue::RocTagUnion::Recursive(glue::R6 {
+                name: name.as_str().into(),
+                tags: tags(union_tags),
+                discriminantSize: *discriminant_size,
+                discriminantOffset: *discriminant_offset,
+            }),
+            RocTagUnion::NonNullableUnwrapped {
+                name,
+                tag_name,
+                payload,
+            } => glue::RocTagUnion::NonNullableUnwrapped(glue::R5 {
+                name: name.as_str().into(),
+                tagName: tag_name.as_str().into(),
+                payload: payload.0 as _,
+            }),
+            RocTagUnion::SingleTagStruct {
+                name,
+                tag_name,
+                payload_fields,
+            } => glue::RocTagUnion::SingleTagStruct(glue::R13 {
+                name: name.as_str().into(),
+                tagName: tag_name.as_str().into(),
+                payloadFields: payload_fields.iter().map(|id| id.0 as _).collect(),
+            }),
+            RocTagUnion::NullableWrapped {
+                name,
+                index_of_null_tag,
+                tags: union_tags,
+                discriminant_size,
+                discriminant_offset,
+            } => glue::RocTagUnion::NullableWrapped(glue::R9 {
+                name: name.as_str().into(),
+                indexOfNullTag: *index_of_null_tag,
+                tags: tags(union_tags),
+                discriminantSize: *discriminant_size,
+                discriminantOffset: *discriminant_offset,
+            }),
+            RocTagUnion::NullableUnwrapped {
+                name,
+                null_tag,
+                non_null_tag,
+                non_null_payload,
+                null_represents_first_tag,
+            } => glue::RocTagUnion::NullableUnwrapped(glue::R8 {
+                name: name.as_str().into(),
+                nullTag: null_tag.as_str().into(),
+                nonNullTag: non_null_tag.as_str().into(),
+                nonNullPayload: non_null_payload.0 as _,
+                whichTagIsNull: if *null_represents_first_tag {
+                    glue::U2::FirstTagIsNull
+                } else {
+                    glue::U2::SecondTagIsNull
+                },
+            }),
+        }
+    }
+}
+
+impl From<TargetInfo> for glue::Target {
+    fn from(target: TargetInfo) -> Self {
+        use roc_target::{Architecture, OperatingSystem};
+
+        glue::Target {
+            architecture: match target.architecture {
+                Architecture::Aarch32 => glue::Architecture::Aarch32,
+                Architecture::Aarch64 => glue::Architecture::Aarch64,
+                Architecture::Wasm32 => glue::Architecture::Wasm32,
+                Architecture::X86_32 => glue::Architecture::X86x32,
+                Architecture::X86_64 => glue::Architecture::X86x64,
+            },
+            operatingSystem: match target.operating_system {
+                OperatingSystem::Windows => glue::OperatingSystem::Windows,
+                OperatingSystem::Unix => glue::OperatingSystem::Unix,
+                OperatingSystem::Wasi => glue::OperatingSystem::Wasi,
+            },
+        }
+    }
+}
+
 pub struct Env<'a> {
     arena: &'a Bump,
     subs: &'a Subs,
@@ -692,14 +918,22 @@ impl<'a> Env<'a> {
         }
     }
 
-    pub fn vars_to_types<I>(&mut self, variables: I) -> Types
+    pub fn vars_to_types<I>(
+        &mut self,
+        variables: I,
+        exposed_to_host: &MutMap<Symbol, Variable>,
+    ) -> Types
     where
-        I: Iterator<Item = Variable>,
+        I: Iterator<Item = (Symbol, Variable)>,
     {
         let mut types = Types::with_capacity(variables.size_hint().0, self.target);
 
-        for var in variables {
-            self.add_type(var, &mut types);
+        for (symbol, var) in variables {
+            let type_id = self.add_type(var, &mut types);
+
+            if exposed_to_host.contains_key(&symbol) {
+                types.add_entry_point(symbol.as_str(self.interns).to_string(), type_id);
+            }
         }
 
         self.resolve_pending_recursive_types(&mut types);
@@ -787,6 +1021,19 @@ fn add_type_help<'a>(
                 RocType::Struct { name, fields }
             })
         }
+        Content::Structure(FlatType::Tuple(elems, ext)) => {
+            let it = elems.sorted_iterator(subs, *ext);
+
+            let name = match opt_name {
+                Some(sym) => sym.as_str(env.interns).to_string(),
+                None => env.struct_names.get_name(var),
+            };
+
+            // tuples have positional fields, just like the payload of a tag
+            add_struct(env, name, it, types, layout, |name, fields| {
+                RocType::TagUnionPayload { name, fields }
+            })
+        }
         Content::Structure(FlatType::TagUnion(tags, ext_var)) => {
             debug_assert!(ext_var_is_empty_tag_union(subs, *ext_var));
 
@@ -865,7 +1112,7 @@ fn add_type_help<'a>(
             todo!()
         }
         Content::Structure(FlatType::Erroneous(_)) => todo!(),
-        Content::Structure(FlatType::EmptyRecord) => {
+        Content::Structure(FlatType::EmptyRecord | FlatType::EmptyTuple) => {
             types.add_anonymous(&env.layout_cache.interner, RocType::Unit, layout)
         }
         Content::Structure(FlatType::EmptyTagUnion) => {
@@ -932,6 +1179,54 @@ fn add_type_help<'a>(
                             }
                         }
                     }
+                    Layout::Struct { .. } if *name == Symbol::DICT_DICT => {
+                        let type_vars = env.subs.get_subs_slice(alias_vars.type_variables());
+
+                        debug_assert_eq!(type_vars.len(), 2);
+
+                        let key_var = type_vars[0];
+                        let key_layout =
+                            env.layout_cache.from_var(env.arena, key_var, subs).unwrap();
+                        let key_id = add_type_help(env, key_layout, key_var, None, types);
+
+                        let val_var = type_vars[1];
+                        let val_layout =
+                            env.layout_cache.from_var(env.arena, val_var, subs).unwrap();
+                        let val_id = add_type_help(env, val_layout, val_var, None, types);
+
+                        let dict_id = types.add_anonymous(
+                            &env.layout_cache.interner,
+                            RocType::RocDict(key_id, val_id),
+                            layout,
+                        );
+
+                        types.depends(dict_id, key_id);
+                        types.depends(dict_id, val_id);
+
+                        dict_id
+                    }
+                    Layout::Struct { .. } if *name == Symbol::SET_SET => {
+                        let type_vars = env.subs.get_subs_slice(alias_vars.type_variables());
+
+                        debug_assert_eq!(type_vars.len(), 1);
+
+                        let elem_var = type_vars[0];
+                        let elem_layout = env
+                            .layout_cache
+                            .from_var(env.arena, elem_var, subs)
+                            .unwrap();
+                        let elem_id = add_type_help(env, elem_layout, elem_var, None, types);
+
+                        let set_id = types.add_anonymous(
+                            &env.layout_cache.interner,
+                            RocType::RocSet(elem_id),
+                            layout,
+                        );
+
+                        types.depends(set_id, elem_id);
+
+                        set_id
+                    }
                     _ => {
                         unreachable!()
                     }
@@ -1070,91 +1365,6 @@ fn add_builtin_type<'a>(
 
             list_id
         }
-        (
-            Builtin::List(elem_layout),
-            Alias(Symbol::DICT_DICT, _alias_variables, alias_var, AliasKind::Opaque),
-        ) => {
-            match (
-                elem_layout,
-                env.subs.get_content_without_compacting(*alias_var),
-            ) {
-                (
-                    Layout::Struct { field_layouts, .. },
-                    Content::Structure(FlatType::Apply(Symbol::LIST_LIST, args_subs_slice)),
-                ) => {
-                    let (key_var, val_var) = {
-                        let args_tuple = env.subs.get_subs_slice(*args_subs_slice);
-
-                        debug_assert_eq!(args_tuple.len(), 1);
-
-                        match env.subs.get_content_without_compacting(args_tuple[0]) {
-                            Content::Structure(FlatType::TagUnion(union_tags, ext_var)) => {
-                                let (mut iter, _) = union_tags.sorted_iterator_and_ext(env.subs, *ext_var);
-                                let payloads = iter.next().unwrap().1;
-
-                                debug_assert_eq!(iter.next(), None);
-
-                                (payloads[0], payloads[1])
-                            }
-                            _ => {
-                                unreachable!()
-                            }
-                        }
-                    };
-
-                    debug_assert_eq!(field_layouts.len(), 2);
-
-                    let key_id = add_type_help(env, field_layouts[0], key_var, opt_name, types);
-                    let val_id = add_type_help(env, field_layouts[1], val_var, opt_name, types);
-                    let dict_id = types.add_anonymous(&env.layout_cache.interner,RocType::RocDict(key_id, val_id), layout);
-
-                    types.depends(dict_id, key_id);
-                    types.depends(dict_id, val_id);
-
-                    dict_id
-                }
-                (elem_layout, alias_content) => unreachable!(
-                    "Unrecognized List element for Dict. Layout was: {:?} and alias_content was: {:?}",
-                    elem_layout,
-                    alias_content
-                ),
-            }
-        }
-        (
-            Builtin::List(elem_layout),
-            Alias(Symbol::SET_SET, _alias_vars, alias_var, AliasKind::Opaque),
-        ) => {
-            match (
-                elem_layout,
-                env.subs.get_content_without_compacting(*alias_var),
-            ) {
-                (
-                    Layout::Struct { field_layouts, .. },
-                    Alias(Symbol::DICT_DICT, alias_args, _alias_var, AliasKind::Opaque),
-                ) => {
-                    let dict_type_vars = env.subs.get_subs_slice(alias_args.type_variables());
-
-                    debug_assert_eq!(dict_type_vars.len(), 2);
-
-                    // Sets only use the key of the Dict they wrap, not the value
-                    let elem_var = dict_type_vars[0];
-
-                    debug_assert_eq!(field_layouts.len(), 2);
-
-                    let elem_id = add_type_help(env, field_layouts[0], elem_var, opt_name, types);
-                    let set_id = types.add_anonymous(&env.layout_cache.interner,RocType::RocSet(elem_id), layout);
-
-                    types.depends(set_id, elem_id);
-
-                    set_id
-                }
-                (elem_layout, alias_content) => unreachable!(
-                    "Unrecognized List element for Set. Layout was: {:?} and alias_content was: {:?}",
-                    elem_layout,
-                    alias_content
-                ),
-            }
-        }
         (Builtin::List(elem_layout), alias) => {
             unreachable!(
                 "The type alias {:?} was not an Apply(Symbol::LIST_LIST) as expected, given that its builtin was Builtin::List({:?})",
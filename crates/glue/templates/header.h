@@ -0,0 +1,229 @@
+// ⚠️ GENERATED CODE ⚠️ - this entire file was generated by the `roc glue` CLI command
+
+#ifndef ROC_APP_H
+#define ROC_APP_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+
+#ifdef __cplusplus
+extern "C" {
+#define ROC_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#define ROC_ALIGNAS(n) alignas(n)
+#else
+#define ROC_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#define ROC_ALIGNAS(n) _Alignas(n)
+#endif
+
+// The host must provide these.
+void* roc_alloc(size_t size, unsigned int alignment);
+void* roc_realloc(void* ptr, size_t new_size, size_t old_size, unsigned int alignment);
+void roc_dealloc(void* ptr, unsigned int alignment);
+void roc_panic(void* msg, unsigned int tag_id);
+void* roc_memcpy(void* dest, const void* src, size_t n);
+void* roc_memset(void* str, int c, size_t n);
+
+// 128-bit numbers are always 16B-aligned, even on targets where the C compiler disagrees.
+struct RocI128 {
+    ROC_ALIGNAS(16) uint8_t bytes[16];
+};
+
+struct RocU128 {
+    ROC_ALIGNAS(16) uint8_t bytes[16];
+};
+
+// A fixed-point decimal: an I128 which is scaled by 10^18.
+struct RocDec {
+    ROC_ALIGNAS(16) uint8_t bytes[16];
+};
+
+// If capacity is negative (when interpreted as signed), this is a small string:
+// its bytes are stored inline in the struct itself, and its length is stored
+// in the last byte (with the high bit set).
+//
+// Otherwise, if len has its high bit set, this is a seamless slice: bytes points
+// into the middle of another string's allocation, and capacity holds that
+// allocation's data pointer shifted right by one. The slice shares the reference
+// count of that allocation.
+struct RocStr {
+    uint8_t* bytes;
+    size_t len;
+    size_t capacity;
+};
+
+// If capacity has its high bit set, this is a seamless slice: elements points
+// into the middle of another list's allocation, and the rest of capacity holds
+// that allocation's elements pointer shifted right by one. The slice shares the
+// reference count of that allocation.
+struct RocList {
+    void* elements;
+    size_t length;
+    size_t capacity;
+};
+
+struct RocBox {
+    void* pointer;
+};
+
+struct RocDict {
+    struct RocList buckets;
+    struct RocList data;
+    size_t max_bucket_capacity;
+    uint8_t shifts;
+};
+
+struct RocSet {
+    struct RocDict dict;
+};
+
+// The discriminant of every RocResult_* struct
+enum discriminant_RocResult {
+    discriminant_RocResult_Err = 0,
+    discriminant_RocResult_Ok = 1,
+};
+
+// Reference counting
+//
+// Every heap allocation Roc makes stores its reference count in the word just
+// before the data. A reference count of 0 means the allocation is readonly
+// (e.g. a string literal in the binary's static data), and must never be freed.
+
+#define ROC_REFCOUNT_ONE ((intptr_t)INTPTR_MIN)
+#define ROC_REFCOUNT_READONLY ((intptr_t)0)
+
+static inline intptr_t* roc_refcount_ptr(void* data) {
+    return ((intptr_t*)data) - 1;
+}
+
+static inline void roc_refcount_increment(void* data) {
+    intptr_t* refcount = roc_refcount_ptr(data);
+
+    if (*refcount != ROC_REFCOUNT_READONLY) {
+        *refcount += 1;
+    }
+}
+
+// Returns true iff this was the last reference, in which case the caller is
+// responsible for freeing the allocation (e.g. using roc_dealloc_refcounted).
+static inline bool roc_refcount_decrement(void* data) {
+    intptr_t* refcount = roc_refcount_ptr(data);
+
+    if (*refcount == ROC_REFCOUNT_READONLY) {
+        return false;
+    } else if (*refcount == ROC_REFCOUNT_ONE) {
+        return true;
+    } else {
+        *refcount -= 1;
+
+        return false;
+    }
+}
+
+// Frees an allocation whose data has the given alignment; the reference count
+// sits in front of the data, padded out to that alignment.
+static inline void roc_dealloc_refcounted(void* data, unsigned int alignment) {
+    size_t prefix = alignment > sizeof(intptr_t) ? alignment : sizeof(intptr_t);
+
+    roc_dealloc((uint8_t*)data - prefix, alignment);
+}
+
+// Set in a seamless slice's len if it is a RocStr, or in its capacity if it is a RocList
+#define ROC_SEAMLESS_SLICE_BIT ((size_t)INTPTR_MIN)
+
+static inline bool roc_str_is_small(struct RocStr str) {
+    return (intptr_t)str.capacity < 0;
+}
+
+static inline bool roc_str_is_seamless_slice(struct RocStr str) {
+    return !roc_str_is_small(str) && (str.len & ROC_SEAMLESS_SLICE_BIT) != 0;
+}
+
+static inline size_t roc_str_len(struct RocStr str) {
+    if (roc_str_is_small(str)) {
+        return ((uint8_t*)&str)[sizeof(struct RocStr) - 1] ^ 0x80;
+    } else {
+        return str.len & ~ROC_SEAMLESS_SLICE_BIT;
+    }
+}
+
+// The data pointer of the allocation whose reference count a big string uses.
+// For a seamless slice this is not str.bytes, which points into the middle of it.
+static inline uint8_t* roc_str_allocation(struct RocStr str) {
+    if (roc_str_is_seamless_slice(str)) {
+        return (uint8_t*)(uintptr_t)(str.capacity << 1);
+    } else {
+        return str.bytes;
+    }
+}
+
+static inline void roc_str_increment(struct RocStr str) {
+    if (!roc_str_is_small(str)) {
+        uint8_t* allocation = roc_str_allocation(str);
+
+        if (allocation != NULL) {
+            roc_refcount_increment(allocation);
+        }
+    }
+}
+
+static inline void roc_str_decrement(struct RocStr str) {
+    if (!roc_str_is_small(str)) {
+        uint8_t* allocation = roc_str_allocation(str);
+
+        if (allocation != NULL && roc_refcount_decrement(allocation)) {
+            roc_dealloc_refcounted(allocation, sizeof(size_t));
+        }
+    }
+}
+
+static inline bool roc_list_is_seamless_slice(struct RocList list) {
+    return (list.capacity & ROC_SEAMLESS_SLICE_BIT) != 0;
+}
+
+// The elements pointer of the allocation whose reference count a list uses.
+// For a seamless slice this is not list.elements, which points into the middle of it.
+static inline void* roc_list_allocation(struct RocList list) {
+    if (roc_list_is_seamless_slice(list)) {
+        return (void*)(uintptr_t)(list.capacity << 1);
+    } else {
+        return list.elements;
+    }
+}
+
+static inline void roc_list_increment(struct RocList list) {
+    void* allocation = roc_list_allocation(list);
+
+    if (allocation != NULL) {
+        roc_refcount_increment(allocation);
+    }
+}
+
+// Returns true iff this was the last reference to the list's elements.
+// If so, the caller should decrement the elements' own refcounts (if they have any)
+// and then free the list with roc_list_dealloc.
+static inline bool roc_list_decrement(struct RocList list) {
+    void* allocation = roc_list_allocation(list);
+
+    return allocation != NULL && roc_refcount_decrement(allocation);
+}
+
+static inline void roc_list_dealloc(struct RocList list, unsigned int element_alignment) {
+    roc_dealloc_refcounted(roc_list_allocation(list), element_alignment);
+}
+
+static inline void roc_box_increment(struct RocBox box) {
+    roc_refcount_increment(box.pointer);
+}
+
+// Returns true iff this was the last reference to the box's contents.
+// If so, the caller should decrement the contents' own refcounts (if they have any)
+// and then free the box with roc_box_dealloc.
+static inline bool roc_box_decrement(struct RocBox box) {
+    return roc_refcount_decrement(box.pointer);
+}
+
+static inline void roc_box_dealloc(struct RocBox box, unsigned int alignment) {
+    roc_dealloc_refcounted(box.pointer, alignment);
+}
@@ -0,0 +1,10 @@
+app "app"
+    packages { pf: "platform.roc" }
+    imports []
+    provides [main] to pf
+
+main =
+    # List.append copies the literal onto the heap, so the slice points into that allocation
+    list = List.append [1, 2, 3] 4
+
+    List.dropFirst list
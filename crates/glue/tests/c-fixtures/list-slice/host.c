@@ -0,0 +1,60 @@
+#include <inttypes.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "roc_app.h"
+
+void* roc_alloc(size_t size, unsigned int alignment) { return malloc(size); }
+
+void* roc_realloc(void* ptr, size_t new_size, size_t old_size, unsigned int alignment) {
+    return realloc(ptr, new_size);
+}
+
+void roc_dealloc(void* ptr, unsigned int alignment) { free(ptr); }
+
+void roc_panic(void* msg, unsigned int tag_id) {
+    fprintf(stderr, "Roc panicked: %s\n", (char*)msg);
+    exit(1);
+}
+
+void* roc_memcpy(void* dest, const void* src, size_t n) { return memcpy(dest, src, n); }
+
+void* roc_memset(void* str, int c, size_t n) { return memset(str, c, n); }
+
+int main() {
+    struct RocList list;
+
+    roc__mainForHost_1_exposed_generic(&list);
+
+    if (!roc_list_is_seamless_slice(list)) {
+        fprintf(stderr, "Expected List.dropFirst to return a seamless slice\n");
+        return 1;
+    }
+
+    // The refcount lives in front of the parent allocation, not in front of list.elements
+    roc_list_increment(list);
+
+    if (roc_list_decrement(list)) {
+        fprintf(stderr, "Releasing the extra reference freed the list\n");
+        return 1;
+    }
+
+    uint64_t* elements = list.elements;
+
+    printf("list was: [");
+
+    for (size_t i = 0; i < list.length; i++) {
+        printf(i == 0 ? "%" PRIu64 : ", %" PRIu64, elements[i]);
+    }
+
+    printf("]\n");
+
+    // This was the last reference, so this frees the parent allocation.
+    // Passing list.elements to free() instead would corrupt the heap.
+    if (roc_list_decrement(list)) {
+        roc_list_dealloc(list, sizeof(uint64_t));
+    }
+
+    return 0;
+}
@@ -0,0 +1,12 @@
+app "app"
+    packages { pf: "platform.roc" }
+    imports []
+    provides [main] to pf
+
+main =
+    # Str.concat puts the string on the heap, so the slice points into that allocation
+    string = Str.concat "This string is long enough" " to live on the heap"
+
+    when Str.sliceUtf8 string { start: 5, count: 29 } is
+        Ok slice -> slice
+        Err _ -> "Str.sliceUtf8 failed"
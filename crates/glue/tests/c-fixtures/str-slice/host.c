@@ -0,0 +1,47 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "roc_app.h"
+
+void* roc_alloc(size_t size, unsigned int alignment) { return malloc(size); }
+
+void* roc_realloc(void* ptr, size_t new_size, size_t old_size, unsigned int alignment) {
+    return realloc(ptr, new_size);
+}
+
+void roc_dealloc(void* ptr, unsigned int alignment) { free(ptr); }
+
+void roc_panic(void* msg, unsigned int tag_id) {
+    fprintf(stderr, "Roc panicked: %s\n", (char*)msg);
+    exit(1);
+}
+
+void* roc_memcpy(void* dest, const void* src, size_t n) { return memcpy(dest, src, n); }
+
+void* roc_memset(void* str, int c, size_t n) { return memset(str, c, n); }
+
+int main() {
+    struct RocStr str;
+
+    roc__mainForHost_1_exposed_generic(&str);
+
+    if (!roc_str_is_seamless_slice(str)) {
+        fprintf(stderr, "Expected Str.sliceUtf8 to return a seamless slice\n");
+        return 1;
+    }
+
+    // The refcount lives in front of the parent allocation, not in front of str.bytes
+    roc_str_increment(str);
+    roc_str_decrement(str);
+
+    size_t len = roc_str_len(str);
+
+    printf("slice was: \"%.*s\" (%zu bytes)\n", (int)len, (char*)str.bytes, len);
+
+    // This was the last reference, so this frees the parent allocation.
+    // Passing str.bytes to free() instead would corrupt the heap.
+    roc_str_decrement(str);
+
+    return 0;
+}
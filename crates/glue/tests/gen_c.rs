@@ -0,0 +1,111 @@
+#[macro_use]
+extern crate pretty_assertions;
+
+#[macro_use]
+extern crate indoc;
+
+mod helpers;
+
+#[cfg(test)]
+mod test_gen_c {
+    use crate::helpers::generate_c_bindings;
+
+    #[test]
+    fn basic_record_aliased() {
+        let module = indoc!(
+            r#"
+            MyRcd : { a : U64, b : I128 }
+
+            main : MyRcd
+            main = { a: 1u64, b: 2i128 }
+        "#
+        );
+
+        assert_eq!(
+            generate_c_bindings(module)
+                .strip_prefix('\n')
+                .unwrap_or_default(),
+            indoc!(
+                r#"
+                #if defined(__arm__) || defined(_M_ARM) \
+                    || defined(__aarch64__) || defined(_M_ARM64) \
+                    || defined(__wasm32__) \
+                    || defined(__i386__) || defined(_M_IX86) \
+                    || defined(__x86_64__) || defined(_M_X64)
+
+                struct MyRcd {
+                    struct RocI128 b;
+                    uint64_t a;
+                };
+
+                ROC_STATIC_ASSERT(sizeof(struct MyRcd) == 32, "struct MyRcd has the wrong size");
+
+                void roc__main_1_exposed_generic(struct MyRcd* ret);
+                int64_t roc__main_size(void);
+
+                #else
+                #error "roc glue did not generate declarations for this architecture"
+                #endif
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn cons_list_with_entry_point() {
+        let module = indoc!(
+            r#"
+            StrConsList : [Nil, Cons Str StrConsList]
+
+            main : Str -> StrConsList
+            main = \str -> Cons str Nil
+        "#
+        );
+
+        assert_eq!(
+            generate_c_bindings(module)
+                .strip_prefix('\n')
+                .unwrap_or_default(),
+            indoc!(
+                r#"
+                #if defined(__arm__) || defined(_M_ARM) \
+                    || defined(__aarch64__) || defined(_M_ARM64) \
+                    || defined(__wasm32__) \
+                    || defined(__i386__) || defined(_M_IX86) \
+                    || defined(__x86_64__) || defined(_M_X64)
+
+                enum discriminant_StrConsList {
+                    discriminant_StrConsList_Cons = 0,
+                    discriminant_StrConsList_Nil = 1,
+                };
+
+                // A pointer to a heap-allocated struct StrConsList_Cons, which is reference
+                // counted like any other Roc allocation. NULL means this is Nil.
+                struct StrConsList {
+                    struct StrConsList_Cons* pointer;
+                };
+
+                static inline enum discriminant_StrConsList StrConsList_discriminant(struct StrConsList tag_union) {
+                    if (tag_union.pointer == NULL) {
+                        return discriminant_StrConsList_Nil;
+                    } else {
+                        return discriminant_StrConsList_Cons;
+                    }
+                }
+
+                struct StrConsList_Cons {
+                    struct RocStr f0;
+                    struct StrConsList f1;
+                };
+
+                void roc__main_1_exposed_generic(struct StrConsList* ret, struct RocStr* arg0);
+                int64_t roc__main_size(void);
+
+                #else
+                #error "roc glue did not generate declarations for this architecture"
+                #endif
+            "#
+            )
+        );
+    }
+}
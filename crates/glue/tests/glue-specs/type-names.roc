@@ -0,0 +1,31 @@
+app "type-names"
+    packages { pf: "../../platform/main.roc" }
+    imports []
+    provides [makeGlue] to pf
+
+makeGlue = \typesByArch ->
+    content =
+        typesByArch
+        |> List.map typeNames
+        |> Str.joinWith "\n"
+
+    Ok [{ name: "type-names.txt", content }]
+
+typeNames = \{ types, target } ->
+    arch = archName target.architecture
+    structNames =
+        List.keepOks types \type ->
+            when type is
+                Struct { name } -> Ok name
+                _ -> Err NotAStruct
+    names = Str.joinWith structNames ", "
+
+    "\(arch): \(names)"
+
+archName = \arch ->
+    when arch is
+        Aarch32 -> "aarch32"
+        Aarch64 -> "aarch64"
+        Wasm32 -> "wasm32"
+        X86x32 -> "x86x32"
+        X86x64 -> "x86x64"
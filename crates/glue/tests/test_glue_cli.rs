@@ -11,10 +11,10 @@ mod helpers;
 
 #[cfg(test)]
 mod glue_cli_run {
-    use crate::helpers::fixtures_dir;
+    use crate::helpers::{c_fixtures_dir, fixtures_dir};
     use cli_utils::helpers::{run_glue, run_roc, Out};
     use std::fs;
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
 
     /// This macro does two things.
     ///
@@ -64,7 +64,48 @@ mod glue_cli_run {
                     all_fixtures.insert($fixture_dir.to_string());
                 )*
 
-                check_for_tests(&mut all_fixtures);
+                check_for_tests(fixtures_dir(""), &mut all_fixtures);
+            }
+        }
+    }
+
+    /// Like `fixtures!`, but for the platforms in c-fixtures/. Their hosts are
+    /// written in C against the roc_app.h header that `roc glue` generates.
+    macro_rules! c_fixtures {
+        ($($test_name:ident:$fixture_dir:expr => $ends_with:expr,)+) => {
+            $(
+                #[test]
+                #[allow(non_snake_case)]
+                fn $test_name() {
+                    let dir = c_fixtures_dir($fixture_dir);
+
+                    generate_c_glue_for(&dir);
+                    let out = run_app(&dir.join("app.roc"), std::iter::empty());
+
+                    assert!(out.status.success());
+                    let ignorable = "🔨 Rebuilding platform...\n";
+                    let stderr = out.stderr.replacen(ignorable, "", 1);
+                    assert_eq!(stderr, "");
+                    assert!(
+                        out.stdout.ends_with($ends_with),
+                        "Unexpected stdout ending\n\nexpected:\n\n{}\n\nbut stdout was:\n\n{}",
+                        $ends_with,
+                        out.stdout
+                    );
+                }
+            )*
+
+            #[test]
+            fn all_c_fixtures_have_tests() {
+                use roc_collections::VecSet;
+
+                let mut all_fixtures: VecSet<String> = VecSet::default();
+
+                $(
+                    all_fixtures.insert($fixture_dir.to_string());
+                )*
+
+                check_for_tests(c_fixtures_dir(""), &mut all_fixtures);
             }
         }
     }
@@ -129,10 +170,48 @@ mod glue_cli_run {
         "#),
     }
 
-    fn check_for_tests(all_fixtures: &mut roc_collections::VecSet<String>) {
+    c_fixtures! {
+        str_slice:"str-slice" => "slice was: \"string is long enough to live\" (29 bytes)\n",
+        list_slice:"list-slice" => "list was: [2, 3, 4]\n",
+    }
+
+    #[test]
+    fn glue_spec() {
+        let platform_module_path = fixtures_dir("basic-record").join("platform.roc");
+        let spec_path = fixtures_dir("")
+            .parent()
+            .unwrap()
+            .join("glue-specs")
+            .join("type-names.roc");
+        let out_dir = tempfile::tempdir().unwrap();
+
+        let glue_out = run_glue([
+            "glue",
+            platform_module_path.to_str().unwrap(),
+            spec_path.to_str().unwrap(),
+            out_dir.path().to_str().unwrap(),
+        ]);
+
+        assert!(glue_out.status.success(), "bad status {:?}", glue_out);
+
+        let content = fs::read_to_string(out_dir.path().join("type-names.txt")).unwrap();
+
+        assert_eq!(
+            content,
+            indoc!(
+                r#"
+                    aarch32: MyRcd
+                    aarch64: MyRcd
+                    wasm32: MyRcd
+                    x86x32: MyRcd
+                    x86x64: MyRcd"#
+            )
+        );
+    }
+
+    fn check_for_tests(fixtures: PathBuf, all_fixtures: &mut roc_collections::VecSet<String>) {
         use roc_collections::VecSet;
 
-        let fixtures = fixtures_dir("");
         let entries = std::fs::read_dir(fixtures.as_path()).unwrap_or_else(|err| {
             panic!(
                 "Error trying to read {} as a fixtures directory: {}",
@@ -207,6 +286,35 @@ mod glue_cli_run {
         glue_out
     }
 
+    fn generate_c_glue_for(platform_dir: &Path) -> Out {
+        let platform_module_path = platform_dir.join("platform.roc");
+        let header_file = platform_dir.join("roc_app.h");
+
+        // Delete the header to make sure we're actually regenerating it!
+        if header_file.exists() {
+            fs::remove_file(&header_file)
+                .expect("Unable to remove roc_app.h in order to regenerate it in the test");
+        }
+
+        // The .h extension is what makes `roc glue` generate C instead of Rust
+        let glue_out = run_glue([
+            "glue",
+            platform_module_path.to_str().unwrap(),
+            header_file.to_str().unwrap(),
+        ]);
+
+        let ignorable = "🔨 Rebuilding platform...\n";
+        let stderr = glue_out.stderr.replacen(ignorable, "", 1);
+        let is_reporting_runtime = stderr.starts_with("runtime: ") && stderr.ends_with("ms\n");
+        if !(stderr.is_empty() || is_reporting_runtime) {
+            panic!("`roc glue` command had unexpected stderr: {}", stderr);
+        }
+
+        assert!(glue_out.status.success(), "bad status {:?}", glue_out);
+
+        glue_out
+    }
+
     fn run_app<'a, I: IntoIterator<Item = &'a str>>(app_file: &'a Path, args: I) -> Out {
         // Generate test_glue.rs for this platform
         let compile_out = run_roc(
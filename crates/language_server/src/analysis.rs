@@ -0,0 +1,385 @@
+use bumpalo::Bump;
+use lsp_types::{
+    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, Hover, HoverContents,
+    Location, MarkupContent, MarkupKind, NumberOrString, Position, Range, Url,
+};
+use roc_can::expr::Declarations;
+use roc_can::pattern::Pattern;
+use roc_can::traverse::{
+    find_closest_type_at, find_declaration, find_symbol_at, walk_pattern, Visitor,
+};
+use roc_load::{LoadedModule, LoadingProblem};
+use roc_module::symbol::{ModuleId, Symbol};
+use roc_parse::{
+    module::{self, module_defs},
+    parser::Parser,
+    state::State,
+};
+use roc_region::all::{LineInfo, Region};
+use roc_reporting::report::{
+    can_problem, type_problem, CiWrite, RenderTarget, Report, RocDocAllocator, Severity,
+};
+use roc_target::TargetInfo;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
+use roc_types::subs::{Content, FlatType, Subs, Variable};
+use std::path::Path;
+use target_lexicon::Triple;
+
+use crate::convert::{to_offset, to_range};
+
+/// A document which loaded and type-checked (possibly with problems), along with the source it
+/// was checked against.
+pub struct Analysis {
+    source: String,
+    line_info: LineInfo,
+    loaded: LoadedModule,
+}
+
+/// Loads and type-checks the document at `path`, whose current contents are `source`. The modules
+/// it imports are read from disk.
+///
+/// Returns the problems to report, and the analysis if the document got as far as type-checking.
+pub fn analyze(path: &Path, source: &str) -> (Option<Analysis>, Vec<Diagnostic>) {
+    let arena = Bump::new();
+    let src_dir = path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .to_path_buf();
+
+    let loaded = roc_load::load_and_typecheck_str(
+        &arena,
+        path.to_path_buf(),
+        arena.alloc_str(source),
+        src_dir,
+        Default::default(),
+        TargetInfo::from(&Triple::host()),
+        RenderTarget::Generic,
+    );
+
+    match loaded {
+        Ok(mut loaded) => {
+            let analysis_source = source.to_string();
+            let line_info = LineInfo::new(&analysis_source);
+            let diagnostics = diagnostics(&analysis_source, &line_info, &mut loaded);
+            let analysis = Analysis {
+                source: analysis_source,
+                line_info,
+                loaded,
+            };
+
+            (Some(analysis), diagnostics)
+        }
+        Err(problem) => {
+            let message = match problem {
+                LoadingProblem::FormattedReport(report) => report,
+                LoadingProblem::FileProblem { filename, error } => {
+                    format!("Unable to read {}: {:?}", filename.display(), error)
+                }
+                other => format!("Unable to load this module: {:?}", other),
+            };
+
+            let range = syntax_error_region(source)
+                .map(|region| to_range(source, &LineInfo::new(source), region))
+                .unwrap_or_default();
+
+            let diagnostic = Diagnostic {
+                range,
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some("roc".to_string()),
+                message,
+                ..Default::default()
+            };
+
+            (None, vec![diagnostic])
+        }
+    }
+}
+
+/// Where the document fails to parse, if it does. A load can also fail because of a module the
+/// document imports, or a file that can't be read; those problems aren't at a place in the
+/// document.
+fn syntax_error_region(src: &str) -> Option<Region> {
+    let arena = Bump::new();
+
+    match module::parse_header(&arena, State::new(src.as_bytes())) {
+        Ok((_, state)) => match module_defs().parse(&arena, state) {
+            Ok(_) => None,
+            Err((_, fail, _)) => fail.get_region(),
+        },
+        Err(fail) => Some(fail.problem.get_region()),
+    }
+}
+
+/// The canonicalization and type problems of the home module.
+fn diagnostics(src: &str, line_info: &LineInfo, loaded: &mut LoadedModule) -> Vec<Diagnostic> {
+    let home = loaded.module_id;
+    let src_lines: Vec<&str> = src.split('\n').collect();
+    let alloc = RocDocAllocator::new(&src_lines, home, &loaded.interns);
+    let filename = loaded
+        .sources
+        .get(&home)
+        .map(|(path, _)| path.clone())
+        .unwrap_or_default();
+
+    let mut diagnostics = Vec::new();
+
+    for problem in loaded.can_problems.remove(&home).unwrap_or_default() {
+        let range = problem
+            .region()
+            .map(|region| to_range(src, line_info, region));
+        let report = can_problem(&alloc, line_info, filename.clone(), problem);
+
+        diagnostics.push(to_diagnostic(range, report));
+    }
+
+    for problem in loaded.type_problems.remove(&home).unwrap_or_default() {
+        let range = problem
+            .region()
+            .map(|region| to_range(src, line_info, region));
+
+        if let Some(report) = type_problem(&alloc, line_info, filename.clone(), problem) {
+            diagnostics.push(to_diagnostic(range, report));
+        }
+    }
+
+    diagnostics
+}
+
+fn to_diagnostic(range: Option<Range>, report: Report) -> Diagnostic {
+    let mut message = String::new();
+
+    // Only the body of the report goes in the message; its title becomes the diagnostic's code.
+    report
+        .doc
+        .1
+        .render_raw(70, &mut CiWrite::new(&mut message))
+        .expect("<buffer is not a utf-8 encoded string>");
+
+    let severity = match report.severity {
+        Severity::RuntimeError => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+    };
+
+    Diagnostic {
+        range: range.unwrap_or_default(),
+        severity: Some(severity),
+        code: Some(NumberOrString::String(report.title)),
+        source: Some("roc".to_string()),
+        message: message.trim().to_string(),
+        ..Default::default()
+    }
+}
+
+impl Analysis {
+    /// Whether this analysis was made from the given version of the document.
+    pub fn is_current(&self, source: &str) -> bool {
+        self.source == source
+    }
+
+    fn home(&self) -> ModuleId {
+        self.loaded.module_id
+    }
+
+    fn home_declarations(&self) -> Option<&Declarations> {
+        self.loaded.declarations_by_id.get(&self.home())
+    }
+
+    /// The type of the innermost expression or pattern at `position`.
+    pub fn hover(&mut self, position: Position) -> Option<Hover> {
+        let offset = to_offset(&self.source, position)?;
+        let (region, var) = find_closest_type_at(
+            roc_region::all::Position::new(offset),
+            self.home_declarations()?,
+        )?;
+
+        let home = self.home();
+        let LoadedModule {
+            solved, interns, ..
+        } = &mut self.loaded;
+        let typ = name_and_print_var(var, solved.inner_mut(), home, interns, DebugPrint::NOTHING);
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```roc\n{}\n```", typ),
+            }),
+            range: Some(to_range(&self.source, &self.line_info, region)),
+        })
+    }
+
+    /// Where the symbol at `position` is defined. `uri` is the document this analysis is of.
+    pub fn definition(&self, uri: &Url, position: Position) -> Option<Location> {
+        let offset = to_offset(&self.source, position)?;
+        let symbol = find_symbol_at(
+            roc_region::all::Position::new(offset),
+            self.home_declarations()?,
+        )?
+        .value;
+
+        let module_id = symbol.module_id();
+        let declarations = self.loaded.declarations_by_id.get(&module_id)?;
+        let region = find_declaration(symbol, declarations)?;
+
+        if module_id == self.home() {
+            Some(Location {
+                uri: uri.clone(),
+                range: to_range(&self.source, &self.line_info, region),
+            })
+        } else {
+            let (path, src) = self.loaded.sources.get(&module_id)?;
+
+            Some(Location {
+                uri: Url::from_file_path(path).ok()?,
+                range: to_range(src, &LineInfo::new(src), region),
+            })
+        }
+    }
+
+    /// Completions for the `Module.` or `record.` right before `offset` in `source`, which may be
+    /// a newer version of the document than this analysis was made from.
+    pub fn completion(&mut self, source: &str, offset: usize) -> Vec<CompletionItem> {
+        let before = &source[..offset];
+        let prefix_start = ident_start(before);
+        let prefix = &before[prefix_start..];
+
+        let qualifier = match before[..prefix_start].strip_suffix('.') {
+            Some(qualified) => &qualified[ident_start(qualified)..],
+            None => return Vec::new(),
+        };
+
+        let mut items = match qualifier.chars().next() {
+            Some(ch) if ch.is_uppercase() => self.module_exports(qualifier),
+            Some(ch) if ch.is_lowercase() => self.record_fields(qualifier, prefix_start as u32),
+            _ => Vec::new(),
+        };
+
+        items.retain(|item| item.label.starts_with(prefix));
+        items.sort_by(|a, b| a.label.cmp(&b.label));
+
+        items
+    }
+
+    /// The values the given (imported) module exposes.
+    fn module_exports(&self, module_name: &str) -> Vec<CompletionItem> {
+        let interns = &self.loaded.interns;
+        let exposed = interns
+            .module_ids
+            .get_id(&module_name.into())
+            .and_then(|module_id| self.loaded.dep_idents.get(&module_id));
+
+        match exposed {
+            Some(ident_ids) => ident_ids
+                .ident_strs()
+                .filter(|(_, name)| name.starts_with(|ch: char| ch.is_lowercase()))
+                .map(|(_, name)| CompletionItem {
+                    label: name.to_string(),
+                    kind: Some(CompletionItemKind::VALUE),
+                    ..Default::default()
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// The fields of the record named `name`, as introduced closest before `offset`.
+    fn record_fields(&mut self, name: &str, offset: u32) -> Vec<CompletionItem> {
+        let home = self.home();
+        let var = match self.home_declarations() {
+            Some(declarations) => {
+                let mut finder = IntroducedVariable {
+                    name,
+                    offset,
+                    interns: &self.loaded.interns,
+                    found: None,
+                };
+
+                finder.visit_decls(declarations);
+
+                match finder.found {
+                    Some((_, var)) => var,
+                    None => return Vec::new(),
+                }
+            }
+            None => return Vec::new(),
+        };
+
+        let LoadedModule {
+            solved, interns, ..
+        } = &mut self.loaded;
+        let subs = solved.inner_mut();
+
+        record_field_vars(subs, var)
+            .into_iter()
+            .map(|(field_name, field_var)| CompletionItem {
+                detail: Some(name_and_print_var(
+                    field_var,
+                    subs,
+                    home,
+                    interns,
+                    DebugPrint::NOTHING,
+                )),
+                label: field_name,
+                kind: Some(CompletionItemKind::FIELD),
+                ..Default::default()
+            })
+            .collect()
+    }
+}
+
+/// The byte offset where the identifier (possibly empty) at the end of `src` starts.
+fn ident_start(src: &str) -> usize {
+    src.char_indices()
+        .rev()
+        .find(|(_, ch)| !is_ident_char(*ch))
+        .map_or(0, |(index, ch)| index + ch.len_utf8())
+}
+
+fn is_ident_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+fn record_field_vars(subs: &Subs, mut var: Variable) -> Vec<(String, Variable)> {
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Content::Alias(_, _, real_var, _) => var = *real_var,
+            Content::Structure(FlatType::Record(fields, ext)) => {
+                return fields
+                    .sorted_iterator(subs, *ext)
+                    .map(|(name, field)| (name.to_string(), field.into_inner()))
+                    .collect();
+            }
+            _ => return Vec::new(),
+        }
+    }
+}
+
+/// Finds the variable of the last identifier named `name` that is introduced before `offset`.
+struct IntroducedVariable<'a> {
+    name: &'a str,
+    offset: u32,
+    interns: &'a roc_module::symbol::Interns,
+    found: Option<(Region, Variable)>,
+}
+
+impl Visitor for IntroducedVariable<'_> {
+    fn visit_pattern(&mut self, pattern: &Pattern, region: Region, opt_var: Option<Variable>) {
+        match (pattern, opt_var) {
+            (Pattern::Identifier(symbol) | Pattern::Shadowed(_, _, symbol), Some(var))
+                if self.matches(*symbol, region) =>
+            {
+                self.found = Some((region, var));
+            }
+            _ => walk_pattern(self, pattern),
+        }
+    }
+}
+
+impl IntroducedVariable<'_> {
+    fn matches(&self, symbol: Symbol, region: Region) -> bool {
+        region.start().offset <= self.offset
+            && symbol.as_str(self.interns) == self.name
+            && self
+                .found
+                .map_or(true, |(found, _)| found.start() <= region.start())
+    }
+}
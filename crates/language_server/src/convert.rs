@@ -0,0 +1,56 @@
+//! Conversions between byte offsets into a source file, which is what the compiler's regions
+//! are made of, and LSP positions, whose columns are counted in UTF-16 code units.
+
+use lsp_types::{Position, Range};
+use roc_region::all::{LineInfo, Region};
+
+pub fn to_range(src: &str, line_info: &LineInfo, region: Region) -> Range {
+    Range {
+        start: to_position(src, line_info, region.start().offset),
+        end: to_position(src, line_info, region.end().offset),
+    }
+}
+
+pub fn to_position(src: &str, line_info: &LineInfo, offset: u32) -> Position {
+    let line_column = line_info.convert_offset(offset);
+    let line_start = (offset - line_column.column) as usize;
+    let before = src.get(line_start..offset as usize).unwrap_or_default();
+
+    Position {
+        line: line_column.line,
+        character: before.encode_utf16().count() as u32,
+    }
+}
+
+/// The byte offset of the given position, if it is within the source. A position past the end of
+/// its line refers to the end of that line.
+pub fn to_offset(src: &str, position: Position) -> Option<u32> {
+    let mut line_start = 0;
+
+    for _ in 0..position.line {
+        line_start += src[line_start..].find('\n')? + 1;
+    }
+
+    let line = src[line_start..].split('\n').next().unwrap_or_default();
+    let mut utf16_column = 0;
+
+    for (index, ch) in line.char_indices() {
+        if utf16_column >= position.character {
+            return Some((line_start + index) as u32);
+        }
+
+        utf16_column += ch.len_utf16() as u32;
+    }
+
+    Some((line_start + line.len()) as u32)
+}
+
+/// The range spanning the whole source.
+pub fn full_range(src: &str) -> Range {
+    let line_info = LineInfo::new(src);
+
+    Range {
+        start: Position::new(0, 0),
+        end: to_position(src, &line_info, src.len() as u32),
+    }
+}
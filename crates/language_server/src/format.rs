@@ -0,0 +1,38 @@
+use bumpalo::Bump;
+use lsp_types::TextEdit;
+use roc_fmt::def::fmt_defs;
+use roc_fmt::module::fmt_module;
+use roc_fmt::{Ast, Buf};
+use roc_parse::{
+    module::{self, module_defs},
+    parser::Parser,
+    state::State,
+};
+
+use crate::convert::full_range;
+
+/// The edits which format the whole document, or `None` if it doesn't parse.
+pub fn format(src: &str) -> Option<Vec<TextEdit>> {
+    let arena = Bump::new();
+
+    let (module, state) = module::parse_header(&arena, State::new(src.as_bytes())).ok()?;
+    let (_, defs, _) = module_defs().parse(&arena, state).ok()?;
+    let ast = Ast { module, defs };
+
+    let mut buf = Buf::new_in(&arena);
+
+    fmt_module(&mut buf, &ast.module);
+    fmt_defs(&mut buf, &ast.defs, 0);
+    buf.fmt_end_of_file();
+
+    let formatted = buf.as_str();
+
+    if formatted == src {
+        Some(Vec::new())
+    } else {
+        Some(vec![TextEdit {
+            range: full_range(src),
+            new_text: formatted.to_string(),
+        }])
+    }
+}
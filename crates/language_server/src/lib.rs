@@ -0,0 +1,20 @@
+//! A language server for Roc, which editors talk to over the Language Server Protocol (LSP) on
+//! stdin and stdout. It publishes diagnostics, and supports hover, go-to-definition, formatting,
+//! and completion.
+mod analysis;
+mod convert;
+mod format;
+mod server;
+pub mod transport;
+
+use std::io;
+
+use server::Server;
+
+/// Run the language server over stdio until the client tells it to exit, returning the exit code.
+pub fn run() -> io::Result<i32> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    Server::default().serve(&mut stdin.lock(), &mut stdout.lock())
+}
@@ -0,0 +1,5 @@
+fn main() -> std::io::Result<()> {
+    let exit_code = roc_language_server::run()?;
+
+    std::process::exit(exit_code)
+}
@@ -0,0 +1,276 @@
+use lsp_types::{
+    CompletionOptions, CompletionParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DocumentFormattingParams, GotoDefinitionParams, HoverParams,
+    HoverProviderCapability, InitializeResult, OneOf, PublishDiagnosticsParams, ServerCapabilities,
+    ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{self, BufRead, ErrorKind, Write};
+
+use crate::analysis::{analyze, Analysis};
+use crate::convert::to_offset;
+use crate::format::format;
+use crate::transport::{read_message, write_message};
+
+/// JSON-RPC error codes
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INVALID_REQUEST: i64 = -32600;
+const PARSE_ERROR: i64 = -32700;
+
+struct Document {
+    text: String,
+    /// The last analysis that made it to type-checking, which may be of an older version of the
+    /// text if the current one doesn't parse.
+    analysis: Option<Analysis>,
+}
+
+#[derive(Default)]
+pub struct Server {
+    documents: HashMap<Url, Document>,
+    shutdown_requested: bool,
+}
+
+impl Server {
+    /// Serve requests until the client sends `exit`, returning the process's exit code.
+    pub fn serve(&mut self, input: &mut impl BufRead, output: &mut impl Write) -> io::Result<i32> {
+        loop {
+            let message = match read_message(input) {
+                Ok(Some(message)) => message,
+                Ok(None) => break,
+                // The id of a message that didn't parse is unknown, so JSON-RPC says to use null.
+                Err(err) if err.kind() == ErrorKind::InvalidData => {
+                    write_message(
+                        output,
+                        &json!({
+                            "jsonrpc": "2.0",
+                            "id": null,
+                            "error": { "code": PARSE_ERROR, "message": err.to_string() },
+                        }),
+                    )?;
+
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            let method = message.get("method").and_then(Value::as_str);
+            let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+            match (method, message.get("id")) {
+                (Some("exit"), None) => {
+                    return Ok(if self.shutdown_requested { 0 } else { 1 });
+                }
+                (Some(method), None) => self.notification(method, params, output)?,
+                (Some(method), Some(id)) => {
+                    let response = match self.request(method, params) {
+                        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                        Err((code, message)) => json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": { "code": code, "message": message },
+                        }),
+                    };
+
+                    write_message(output, &response)?;
+                }
+                // A response to a request we sent; we don't send any.
+                (None, _) => {}
+            }
+        }
+
+        // The client went away without telling us to exit.
+        Ok(1)
+    }
+
+    fn request(&mut self, method: &str, params: Value) -> Result<Value, (i64, String)> {
+        if self.shutdown_requested {
+            return Err((INVALID_REQUEST, "The server is shutting down".to_string()));
+        }
+
+        match method {
+            "initialize" => Ok(to_value(initialize_result())),
+            "shutdown" => {
+                self.shutdown_requested = true;
+
+                Ok(Value::Null)
+            }
+            "textDocument/hover" => {
+                let params: HoverParams = from_value(params)?;
+                let position = params.text_document_position_params;
+
+                Ok(to_value(
+                    self.current_analysis(&position.text_document.uri)
+                        .and_then(|analysis| analysis.hover(position.position)),
+                ))
+            }
+            "textDocument/definition" => {
+                let params: GotoDefinitionParams = from_value(params)?;
+                let position = params.text_document_position_params;
+                let uri = &position.text_document.uri;
+
+                Ok(to_value(self.current_analysis(uri).and_then(|analysis| {
+                    analysis.definition(uri, position.position)
+                })))
+            }
+            "textDocument/formatting" => {
+                let params: DocumentFormattingParams = from_value(params)?;
+
+                Ok(to_value(
+                    self.documents
+                        .get(&params.text_document.uri)
+                        .and_then(|document| format(&document.text)),
+                ))
+            }
+            "textDocument/completion" => {
+                let params: CompletionParams = from_value(params)?;
+                let position = params.text_document_position;
+
+                let items = match self.documents.get_mut(&position.text_document.uri) {
+                    Some(Document {
+                        text,
+                        analysis: Some(analysis),
+                    }) => match to_offset(text, position.position) {
+                        Some(offset) => analysis.completion(text, offset as usize),
+                        None => Vec::new(),
+                    },
+                    _ => Vec::new(),
+                };
+
+                Ok(to_value(items))
+            }
+            _ => Err((METHOD_NOT_FOUND, format!("Unhandled method {}", method))),
+        }
+    }
+
+    fn notification(
+        &mut self,
+        method: &str,
+        params: Value,
+        output: &mut impl Write,
+    ) -> io::Result<()> {
+        match method {
+            "textDocument/didOpen" => {
+                if let Ok(params) = from_value::<DidOpenTextDocumentParams>(params) {
+                    let document = params.text_document;
+
+                    self.update(document.uri, document.text, Some(document.version), output)?;
+                }
+            }
+            "textDocument/didChange" => {
+                if let Ok(params) = from_value::<DidChangeTextDocumentParams>(params) {
+                    // We only ask for full-text sync, so the last change is the whole document.
+                    if let Some(change) = params.content_changes.into_iter().last() {
+                        let document = params.text_document;
+
+                        self.update(document.uri, change.text, Some(document.version), output)?;
+                    }
+                }
+            }
+            "textDocument/didClose" => {
+                if let Ok(params) = from_value::<DidCloseTextDocumentParams>(params) {
+                    let uri = params.text_document.uri;
+
+                    self.documents.remove(&uri);
+                    publish_diagnostics(output, uri, Vec::new(), None)?;
+                }
+            }
+            // e.g. `initialized` and `$/cancelRequest`
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Re-analyze the document with its new text, and publish its diagnostics.
+    fn update(
+        &mut self,
+        uri: Url,
+        text: String,
+        version: Option<i32>,
+        output: &mut impl Write,
+    ) -> io::Result<()> {
+        let (analysis, diagnostics) = match uri.to_file_path() {
+            Ok(path) => analyze(&path, &text),
+            Err(()) => (None, Vec::new()),
+        };
+
+        let previous_analysis = self
+            .documents
+            .remove(&uri)
+            .and_then(|document| document.analysis);
+
+        self.documents.insert(
+            uri.clone(),
+            Document {
+                text,
+                analysis: analysis.or(previous_analysis),
+            },
+        );
+
+        publish_diagnostics(output, uri, diagnostics, version)
+    }
+
+    /// The analysis of the document, if it is up to date with the document's text.
+    fn current_analysis(&mut self, uri: &Url) -> Option<&mut Analysis> {
+        match self.documents.get_mut(uri)? {
+            Document {
+                text,
+                analysis: Some(analysis),
+            } if analysis.is_current(text) => Some(analysis),
+            _ => None,
+        }
+    }
+}
+
+fn initialize_result() -> InitializeResult {
+    InitializeResult {
+        capabilities: ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            document_formatting_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec![".".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        server_info: Some(ServerInfo {
+            name: "roc_language_server".to_string(),
+            version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        }),
+    }
+}
+
+fn publish_diagnostics(
+    output: &mut impl Write,
+    uri: Url,
+    diagnostics: Vec<lsp_types::Diagnostic>,
+    version: Option<i32>,
+) -> io::Result<()> {
+    let params = PublishDiagnosticsParams {
+        uri,
+        diagnostics,
+        version,
+    };
+
+    write_message(
+        output,
+        &json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": params,
+        }),
+    )
+}
+
+fn from_value<T: DeserializeOwned>(params: Value) -> Result<T, (i64, String)> {
+    serde_json::from_value(params).map_err(|err| (INVALID_PARAMS, err.to_string()))
+}
+
+fn to_value(value: impl serde::Serialize) -> Value {
+    serde_json::to_value(value).expect("LSP types always serialize to JSON")
+}
@@ -0,0 +1,72 @@
+//! The base protocol of the Language Server Protocol: JSON-RPC messages, each preceded by a
+//! `Content-Length` header.
+
+use serde_json::Value;
+use std::io::{self, BufRead, ErrorKind, Write};
+
+/// Reads the next message, or returns `None` if the input has been closed.
+///
+/// A message that can't be parsed is consumed (as far as its headers allow) and reported as an
+/// error of kind [`ErrorKind::InvalidData`], after which the next message can be read.
+pub fn read_message(input: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    let mut header_error = None;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let header = line.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(": ") {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                match value.parse::<usize>() {
+                    Ok(length) => content_length = Some(length),
+                    Err(_) => {
+                        header_error = Some(format!("Invalid Content-Length header: {:?}", header))
+                    }
+                }
+            }
+        }
+    }
+
+    // Without a valid length there is no telling where the content ends, so skip only the headers.
+    if let Some(message) = header_error {
+        return Err(invalid_data(message));
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| invalid_data("Missing Content-Length header".to_string()))?;
+    let mut content = vec![0; content_length];
+
+    input.read_exact(&mut content)?;
+
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|err| invalid_data(format!("Invalid JSON-RPC message: {}", err)))
+}
+
+pub fn write_message(output: &mut impl Write, message: &Value) -> io::Result<()> {
+    let content = message.to_string();
+
+    write!(
+        output,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+
+    output.flush()
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,421 @@
+//! Drives the language server binary over stdio, the way an editor would.
+
+#[cfg(test)]
+mod lsp {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use roc_language_server::transport::{read_message, write_message};
+    use serde_json::{json, Value};
+    use std::io::{BufReader, Write};
+    use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+    use tempfile::TempDir;
+
+    struct Client {
+        child: Child,
+        stdin: ChildStdin,
+        stdout: BufReader<ChildStdout>,
+        next_id: i64,
+        dir: TempDir,
+    }
+
+    impl Client {
+        fn start() -> Client {
+            let mut child = Command::new(env!("CARGO_BIN_EXE_roc_language_server"))
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .spawn()
+                .unwrap();
+
+            let mut client = Client {
+                stdin: child.stdin.take().unwrap(),
+                stdout: BufReader::new(child.stdout.take().unwrap()),
+                child,
+                next_id: 0,
+                dir: tempfile::tempdir().unwrap(),
+            };
+
+            let result = client.request("initialize", json!({ "capabilities": {} }));
+
+            assert_eq!(
+                result["capabilities"]["completionProvider"]["triggerCharacters"],
+                json!(["."])
+            );
+
+            client.notify("initialized", json!({}));
+
+            client
+        }
+
+        fn uri(&self, filename: &str) -> String {
+            let path = self.dir.path().join(filename);
+
+            format!("file://{}", path.display())
+        }
+
+        fn request(&mut self, method: &str, params: Value) -> Value {
+            self.next_id += 1;
+
+            let id = self.next_id;
+
+            write_message(
+                &mut self.stdin,
+                &json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }),
+            )
+            .unwrap();
+
+            loop {
+                let message = read_message(&mut self.stdout).unwrap().unwrap();
+
+                if message["id"] == json!(id) {
+                    assert_eq!(message.get("error"), None);
+
+                    return message["result"].clone();
+                }
+            }
+        }
+
+        fn notify(&mut self, method: &str, params: Value) {
+            write_message(
+                &mut self.stdin,
+                &json!({ "jsonrpc": "2.0", "method": method, "params": params }),
+            )
+            .unwrap();
+        }
+
+        /// Writes the file to disk (so that other modules can import it) and opens it.
+        fn open(&mut self, filename: &str, text: &str) -> Vec<Value> {
+            std::fs::write(self.dir.path().join(filename), text).unwrap();
+
+            let uri = self.uri(filename);
+
+            self.notify(
+                "textDocument/didOpen",
+                json!({
+                    "textDocument": { "uri": uri, "languageId": "roc", "version": 1, "text": text }
+                }),
+            );
+
+            self.diagnostics(&uri)
+        }
+
+        fn change(&mut self, filename: &str, version: i32, text: &str) -> Vec<Value> {
+            let uri = self.uri(filename);
+
+            self.notify(
+                "textDocument/didChange",
+                json!({
+                    "textDocument": { "uri": uri, "version": version },
+                    "contentChanges": [{ "text": text }],
+                }),
+            );
+
+            self.diagnostics(&uri)
+        }
+
+        fn diagnostics(&mut self, uri: &str) -> Vec<Value> {
+            loop {
+                let message = read_message(&mut self.stdout).unwrap().unwrap();
+
+                if message["method"] == "textDocument/publishDiagnostics"
+                    && message["params"]["uri"] == uri
+                {
+                    return message["params"]["diagnostics"].as_array().unwrap().clone();
+                }
+            }
+        }
+
+        fn at(&self, filename: &str, line: u32, character: u32) -> Value {
+            json!({
+                "textDocument": { "uri": self.uri(filename) },
+                "position": { "line": line, "character": character },
+            })
+        }
+
+        fn exit(mut self) -> Option<i32> {
+            assert_eq!(self.request("shutdown", Value::Null), Value::Null);
+
+            self.notify("exit", Value::Null);
+
+            self.child.wait().unwrap().code()
+        }
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Value {
+        json!({
+            "start": { "line": start.0, "character": start.1 },
+            "end": { "line": end.0, "character": end.1 },
+        })
+    }
+
+    const POINT: &str = indoc!(
+        r#"
+        interface Point
+            exposes [origin, sum]
+            imports []
+
+        origin = { x: 0, y: 0 }
+
+        sum = \point ->
+            point.x + point.y
+        "#
+    );
+
+    #[test]
+    fn diagnostics_are_published_and_cleared() {
+        let mut client = Client::start();
+
+        let diagnostics = client.open(
+            "Point.roc",
+            indoc!(
+                r#"
+                interface Point
+                    exposes [origin]
+                    imports []
+
+                origin : { x : I64, y : I64 }
+                origin = { x: 0, y: "zero" }
+                "#
+            ),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["code"], "TYPE MISMATCH");
+        assert_eq!(diagnostics[0]["severity"], 1);
+        assert_eq!(diagnostics[0]["source"], "roc");
+        assert_eq!(diagnostics[0]["range"]["start"]["line"], 5);
+        assert!(diagnostics[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Something is off with the body of the `origin` definition"));
+
+        assert_eq!(client.change("Point.roc", 2, POINT), Vec::<Value>::new());
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn syntax_errors_are_reported() {
+        let mut client = Client::start();
+
+        let diagnostics = client.open(
+            "Point.roc",
+            indoc!(
+                r#"
+                interface Point
+                    exposes [origin]
+                    imports []
+
+                origin = { x: 0, y: }
+                "#
+            ),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["severity"], 1);
+        assert_eq!(diagnostics[0]["range"]["start"]["line"], 4);
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn hover() {
+        let mut client = Client::start();
+
+        client.open("Point.roc", POINT);
+
+        // The parser doesn't track the region of the record in a field access, so the record
+        // `point` shares its region with `point.x`.
+        let hover = client.request("textDocument/hover", client.at("Point.roc", 7, 6));
+
+        assert_eq!(
+            hover["contents"]["value"],
+            "```roc\n{ x : Num a, y : Num a }*\n```"
+        );
+        assert_eq!(hover["range"], range((7, 4), (7, 11)));
+
+        let hover = client.request("textDocument/hover", client.at("Point.roc", 4, 2));
+
+        assert_eq!(
+            hover["contents"]["value"],
+            "```roc\n{ x : Num *, y : Num * }\n```"
+        );
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn definition() {
+        let mut client = Client::start();
+
+        client.open("Point.roc", POINT);
+
+        let location = client.request("textDocument/definition", client.at("Point.roc", 7, 15));
+
+        assert_eq!(location["uri"], client.uri("Point.roc"));
+        assert_eq!(location["range"], range((6, 7), (6, 12)));
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn definition_in_another_module() {
+        let mut client = Client::start();
+
+        client.open("Point.roc", POINT);
+        client.open(
+            "Main.roc",
+            indoc!(
+                r#"
+                interface Main
+                    exposes [total]
+                    imports [Point]
+
+                total = Point.sum Point.origin
+                "#
+            ),
+        );
+
+        let location = client.request("textDocument/definition", client.at("Main.roc", 4, 25));
+
+        assert_eq!(location["uri"], client.uri("Point.roc"));
+        assert_eq!(location["range"], range((4, 0), (4, 6)));
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn formatting() {
+        let mut client = Client::start();
+        let unformatted = POINT.replace("origin = { x: 0, y: 0 }", "origin = {x:0,y :0}");
+
+        client.open("Point.roc", &unformatted);
+
+        let params = json!({
+            "textDocument": { "uri": client.uri("Point.roc") },
+            "options": { "tabSize": 4, "insertSpaces": true },
+        });
+
+        let edits = client.request("textDocument/formatting", params.clone());
+
+        assert_eq!(
+            edits,
+            json!([{ "range": range((0, 0), (8, 0)), "newText": POINT }])
+        );
+
+        client.change("Point.roc", 2, POINT);
+
+        assert_eq!(client.request("textDocument/formatting", params), json!([]));
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn completion() {
+        let mut client = Client::start();
+
+        client.open("Point.roc", POINT);
+
+        let incomplete = POINT.replace("point.x + point.y", "point.x + point.y + point.");
+        client.change("Point.roc", 2, &incomplete);
+
+        let items = client.request("textDocument/completion", client.at("Point.roc", 7, 30));
+        let fields: Vec<_> = items
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| (item["label"].clone(), item["detail"].clone()))
+            .collect();
+
+        assert_eq!(
+            fields,
+            vec![(json!("x"), json!("Num a")), (json!("y"), json!("Num a")),]
+        );
+
+        let incomplete = POINT.replace("point.x + point.y", "Str.conc");
+        client.change("Point.roc", 3, &incomplete);
+
+        let items = client.request("textDocument/completion", client.at("Point.roc", 7, 12));
+        let labels: Vec<_> = items
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["label"].clone())
+            .collect();
+
+        assert_eq!(labels, vec![json!("concat")]);
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn completion_after_non_ascii_text() {
+        let mut client = Client::start();
+
+        client.open("Point.roc", POINT);
+
+        // `→` takes three bytes in UTF-8, but one UTF-16 code unit
+        let incomplete = POINT.replace("point.x + point.y", "\"→Str.conc\"");
+        client.change("Point.roc", 2, &incomplete);
+
+        let items = client.request("textDocument/completion", client.at("Point.roc", 7, 14));
+        let labels: Vec<_> = items
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["label"].clone())
+            .collect();
+
+        assert_eq!(labels, vec![json!("concat")]);
+
+        let incomplete = POINT.replace("point.x + point.y", "\"→\"");
+        client.change("Point.roc", 3, &incomplete);
+
+        let items = client.request("textDocument/completion", client.at("Point.roc", 7, 6));
+
+        assert_eq!(items, json!([]));
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn malformed_messages_are_skipped() {
+        let mut client = Client::start();
+
+        // Neither of these can be parsed, but the server should keep serving after each.
+        client
+            .stdin
+            .write_all(b"Content-Length: 9\r\n\r\n{\"id\": 1,")
+            .unwrap();
+        client
+            .stdin
+            .write_all(b"Content-Length: nine\r\n\r\n")
+            .unwrap();
+
+        for _ in 0..2 {
+            let message = read_message(&mut client.stdout).unwrap().unwrap();
+
+            assert_eq!(message["id"], Value::Null);
+            assert_eq!(message["error"]["code"], -32700);
+        }
+
+        client.open("Point.roc", POINT);
+
+        let hover = client.request("textDocument/hover", client.at("Point.roc", 4, 2));
+
+        assert_eq!(
+            hover["contents"]["value"],
+            "```roc\n{ x : Num *, y : Num * }\n```"
+        );
+
+        assert_eq!(client.exit(), Some(0));
+    }
+
+    #[test]
+    fn exit_without_shutdown() {
+        let mut client = Client::start();
+
+        client.notify("exit", Value::Null);
+
+        assert_eq!(client.child.wait().unwrap().code(), Some(1));
+    }
+}
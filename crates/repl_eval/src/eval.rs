@@ -17,7 +17,9 @@ use roc_parse::ast::{AssignedField, Collection, Expr, StrLiteral};
 use roc_region::all::{Loc, Region};
 use roc_std::RocDec;
 use roc_target::TargetInfo;
-use roc_types::subs::{Content, FlatType, GetSubsSlice, RecordFields, Subs, UnionTags, Variable};
+use roc_types::subs::{
+    Content, FlatType, GetSubsSlice, RecordFields, Subs, TupleElems, UnionTags, Variable,
+};
 
 use crate::{ReplApp, ReplAppMemory};
 
@@ -379,6 +381,9 @@ fn jit_to_ast_help<'a, A: ReplApp<'a>>(
                 Content::Structure(FlatType::EmptyRecord) => {
                     Ok(struct_to_ast(env, mem, addr, RecordFields::empty()))
                 }
+                Content::Structure(FlatType::Tuple(elems, ext)) => {
+                    Ok(tuple_to_ast(env, mem, addr, *elems, *ext))
+                }
                 Content::Structure(FlatType::TagUnion(tags, _)) => {
                     let (tag_name, payload_vars) = unpack_single_element_tag_union(env.subs, *tags);
 
@@ -566,6 +571,9 @@ fn addr_to_ast<'a, M: ReplAppMemory>(
             Content::Structure(FlatType::Record(fields, _)) => {
                 struct_to_ast(env, mem, addr, *fields)
             }
+            Content::Structure(FlatType::Tuple(elems, ext)) => {
+                tuple_to_ast(env, mem, addr, *elems, *ext)
+            }
             Content::Structure(FlatType::TagUnion(tags, _)) => {
                 debug_assert_eq!(tags.len(), 1);
 
@@ -1059,6 +1067,61 @@ fn struct_to_ast<'a, 'env, M: ReplAppMemory>(
     }
 }
 
+fn tuple_to_ast<'a, 'env, M: ReplAppMemory>(
+    env: &mut Env<'a, 'env>,
+    mem: &'a M,
+    addr: usize,
+    tuple_elems: TupleElems,
+    ext: Variable,
+) -> Expr<'a> {
+    let arena = env.arena;
+    let subs = env.subs;
+
+    let mut elems = std::vec::Vec::with_capacity(tuple_elems.len());
+    for (index, var) in tuple_elems.sorted_iterator(subs, ext) {
+        let elem_layout = env.layout_cache.from_var(arena, var, env.subs).unwrap();
+        elems.push((index, var, elem_layout));
+    }
+
+    // The elements are stored sorted by descending alignment (like record fields), so find the
+    // address of each element in that order first, and then render them in the order of the type.
+    let mut in_memory: std::vec::Vec<_> = elems.iter().collect();
+    in_memory.sort_by(|(index1, _, layout1), (index2, _, layout2)| {
+        layout::cmp_fields(
+            &env.layout_cache.interner,
+            index1,
+            layout1,
+            index2,
+            layout2,
+            env.target_info,
+        )
+    });
+
+    let mut elem_addrs = MutMap::default();
+    let mut elem_addr = addr;
+    for (index, _, elem_layout) in in_memory {
+        elem_addrs.insert(*index, elem_addr);
+        elem_addr += elem_layout.stack_size(&env.layout_cache.interner, env.target_info) as usize;
+    }
+
+    let mut output = Vec::with_capacity_in(elems.len(), arena);
+    for (index, var, elem_layout) in elems {
+        let content = subs.get_content_without_compacting(var);
+        let expr = addr_to_ast(
+            env,
+            mem,
+            elem_addrs[&index],
+            &elem_layout,
+            WhenRecursive::Unreachable,
+            content,
+        );
+
+        output.push(&*arena.alloc(Loc::at_zero(expr)));
+    }
+
+    Expr::Tuple(Collection::with_items(output.into_bump_slice()))
+}
+
 fn unpack_single_element_tag_union(subs: &Subs, tags: UnionTags) -> (&TagName, &[Variable]) {
     let (tag_name_index, payload_vars_index) = tags.iter_all().next().unwrap();
 
@@ -65,6 +65,7 @@ pub fn compile_to_mono<'a>(
             render: roc_reporting::report::RenderTarget::ColorTerminal,
             threading: Threading::Single,
             exec_mode: ExecutionMode::Executable,
+            cache_dir: None,
         },
     );
 
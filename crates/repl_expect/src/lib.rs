@@ -19,6 +19,8 @@ use {
 #[cfg(not(windows))]
 mod app;
 #[cfg(not(windows))]
+pub mod report;
+#[cfg(not(windows))]
 pub mod run;
 
 #[cfg(not(windows))]
@@ -100,6 +102,10 @@ mod test {
     use super::*;
 
     fn run_expect_test(source: &str, expected: &str) {
+        run_expect_test_with_options(source, expected, Default::default())
+    }
+
+    fn run_expect_test_with_options(source: &str, expected: &str, options: crate::run::RunOptions) {
         let arena = bumpalo::Bump::new();
         let arena = &arena;
 
@@ -120,6 +126,7 @@ mod test {
             render: RenderTarget::ColorTerminal,
             threading: Threading::Single,
             exec_mode: ExecutionMode::Test,
+            cache_dir: None,
         };
         let loaded = roc_load::load_and_monomorphize_from_str(
             arena,
@@ -160,7 +167,7 @@ mod test {
         unsafe { set_shared_buffer((shared_buffer.as_mut_ptr(), BUFFER_SIZE), &mut result) };
 
         let mut writer = Vec::with_capacity(1024);
-        let (_failed, _passed) = crate::run::run_expects_with_memory(
+        let _results = crate::run::run_expects_with_memory(
             &mut writer,
             RenderTarget::ColorTerminal,
             arena,
@@ -169,6 +176,7 @@ mod test {
             &lib,
             &mut expectations,
             expects,
+            options,
             &mut memory,
         )
         .unwrap();
@@ -234,6 +242,123 @@ mod test {
         );
     }
 
+    #[test]
+    fn equals_fail_isolated() {
+        run_expect_test_with_options(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = 0
+
+                expect 1 == 1
+
+                expect 1 == 2
+                "#
+            ),
+            indoc!(
+                r#"
+                This expectation failed:
+
+                7│  expect 1 == 2
+                    ^^^^^^^^^^^^^
+                "#
+            ),
+            crate::run::RunOptions {
+                isolate: true,
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn infinite_loop_times_out() {
+        run_expect_test_with_options(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = 0
+
+                spin : U64 -> U64
+                spin = \n -> if n == 0 then spin 1 else spin 0
+
+                expect spin 0 == 1
+                "#
+            ),
+            indoc!(
+                r#"
+                This expectation was stopped while running:
+
+                8│  expect spin 0 == 1
+                    ^^^^^^^^^^^^^^^^^^
+
+                It did not finish within 100 ms.
+                "#
+            ),
+            crate::run::RunOptions {
+                isolate: true,
+                timeout: Some(std::time::Duration::from_millis(100)),
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn isolated_message_cut_short() {
+        use crate::run::{parse_isolated_message, IsolatedResult};
+
+        assert_eq!(parse_isolated_message(&[]), None);
+        assert_eq!(parse_isolated_message(&[1, 0, 0]), None);
+
+        let mut message = vec![1];
+        message.extend(5u64.to_le_bytes());
+        message.extend(b"oops");
+        assert_eq!(parse_isolated_message(&message), None);
+
+        message.extend(b"!rendered");
+        assert_eq!(
+            parse_isolated_message(&message),
+            Some(IsolatedResult::Failed {
+                panic_message: Some("oops!".to_string()),
+                rendered: b"rendered",
+            })
+        );
+
+        assert_eq!(parse_isolated_message(&[0]), Some(IsolatedResult::Passed));
+    }
+
+    #[test]
+    fn doctest_fail() {
+        run_expect_test(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = 0
+
+                ## Add one.
+                ##
+                ## >>> addOne 1
+                ## == 2
+                ##
+                ## >>> addOne 2
+                ## == 4
+                addOne : I64 -> I64
+                addOne = \n -> n + 1
+                "#
+            ),
+            indoc!(
+                r#"
+                This expectation failed:
+
+                11│>  ## >>> addOne 2
+                12│>  ## == 4
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn lookup_integer() {
         run_expect_test(
@@ -934,4 +1059,266 @@ mod test {
             ),
         );
     }
+
+    #[test]
+    fn property_counterexample() {
+        run_expect_test_with_options(
+            indoc!(
+                r#"
+                app "test" imports [Gen] provides [main] to "./platform"
+
+                main = 0
+
+                expect
+                    result = Gen.check Gen.arbitrary \n -> n < 10u8
+
+                    result == Ok {}
+                "#
+            ),
+            indoc!(
+                r#"
+                This expectation failed:
+
+                5│>  expect
+                6│>      result = Gen.check Gen.arbitrary \n -> n < 10u8
+                7│>
+                8│>      result == Ok {}
+
+                When it failed, these variables had these values:
+
+                result : Result {} [Counterexample U8]*
+                result = Err (Counterexample 10)
+                "#
+            ),
+            crate::run::RunOptions {
+                seed: 42,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn test_name_of(source: &str) -> String {
+        use roc_parse::ast::ValueDef;
+        use roc_region::all::LineInfo;
+
+        let arena = bumpalo::Bump::new();
+        let mut defs = roc_parse::test_helpers::parse_defs_with(&arena, source).unwrap();
+        let skipped = roc_parse::doctest::push_doctests(&arena, source, &mut defs);
+        assert!(skipped.is_empty());
+
+        let expect = defs
+            .value_defs
+            .iter()
+            .find_map(|value_def| match value_def {
+                ValueDef::Expect {
+                    condition,
+                    preceding_comment,
+                    is_doctest,
+                } => Some(roc_load::ExpectSource {
+                    region: condition.region,
+                    preceding_comment: *preceding_comment,
+                    is_doctest: *is_doctest,
+                }),
+                _ => None,
+            })
+            .unwrap();
+
+        crate::run::expect_test_name("Test", source, &LineInfo::new(source), expect)
+    }
+
+    #[test]
+    fn test_name_from_comment() {
+        assert_eq!(
+            test_name_of(indoc!(
+                r#"
+                x = 1
+
+                # one is one
+                expect x == 1
+                "#
+            )),
+            "Test: one is one"
+        );
+    }
+
+    #[test]
+    fn test_name_from_multiline_comment() {
+        assert_eq!(
+            test_name_of(indoc!(
+                r#"
+                x = 1
+
+                ## one is one,
+                ## even on Tuesdays
+                expect x == 1
+                "#
+            )),
+            "Test: one is one, even on Tuesdays"
+        );
+    }
+
+    #[test]
+    fn test_name_ignores_detached_comment() {
+        assert_eq!(
+            test_name_of(indoc!(
+                r#"
+                x = 1
+
+                # not about the expect
+
+                expect x == 1
+                "#
+            )),
+            "Test: expect on line 5"
+        );
+    }
+
+    #[test]
+    fn test_name_of_doctest() {
+        assert_eq!(
+            test_name_of(indoc!(
+                r#"
+                x = 1
+                ## >>> x + 1
+                ## == 2
+                "#
+            )),
+            "Test: doctest on line 2"
+        );
+    }
+
+    #[test]
+    fn test_name_from_comment_that_looks_like_a_doctest() {
+        assert_eq!(
+            test_name_of(indoc!(
+                r#"
+                x = 1
+
+                ## >>> marks an example
+                expect x == 1
+                "#
+            )),
+            "Test: >>> marks an example"
+        );
+    }
+
+    #[test]
+    fn test_name_without_comment() {
+        assert_eq!(
+            test_name_of(indoc!(
+                r#"
+                x = 1
+                expect x == 1
+                "#
+            )),
+            "Test: expect on line 2"
+        );
+    }
+
+    fn report_records() -> Vec<crate::report::TestRecord<'static>> {
+        use crate::report::{TestOutcome, TestRecord};
+        use roc_region::all::{LineColumn, LineColumnRegion};
+        use std::{path::Path, time::Duration};
+
+        let region = |line| {
+            LineColumnRegion::new(
+                LineColumn { line, column: 0 },
+                LineColumn { line, column: 13 },
+            )
+        };
+
+        vec![
+            TestRecord {
+                module: "Parser",
+                name: "parses <digits>",
+                file: Path::new("Parser.roc"),
+                region: region(3),
+                duration: Duration::from_micros(1200),
+                outcome: TestOutcome::Passed,
+            },
+            TestRecord {
+                module: "Parser",
+                name: "expect on line 8",
+                file: Path::new("Parser.roc"),
+                region: region(7),
+                duration: Duration::from_micros(250),
+                outcome: TestOutcome::Failed {
+                    report: "This expectation failed:\n\n8│  expect 1 == 2",
+                },
+            },
+            TestRecord {
+                module: "Main",
+                name: "crashes",
+                file: Path::new("main.roc"),
+                region: region(0),
+                duration: Duration::from_millis(2),
+                outcome: TestOutcome::Panicked {
+                    message: "\"oops\"",
+                    report: "This expectation crashed",
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn junit_report() {
+        let mut buf = Vec::new();
+        crate::report::write_junit(
+            &mut buf,
+            &report_records(),
+            std::time::Duration::from_millis(5),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            indoc!(
+                r#"
+                <?xml version="1.0" encoding="UTF-8"?>
+                <testsuites name="roc test" tests="3" failures="1" errors="1" time="0.005">
+                  <testsuite name="Parser" tests="2" failures="1" errors="0" time="0.001">
+                    <testcase name="parses &lt;digits&gt;" classname="Parser" file="Parser.roc" line="4" time="0.001"/>
+                    <testcase name="expect on line 8" classname="Parser" file="Parser.roc" line="8" time="0.000">
+                      <failure message="expectation failed">This expectation failed:
+
+                8│  expect 1 == 2</failure>
+                    </testcase>
+                  </testsuite>
+                  <testsuite name="Main" tests="1" failures="0" errors="1" time="0.002">
+                    <testcase name="crashes" classname="Main" file="main.roc" line="1" time="0.002">
+                      <error message="&quot;oops&quot;">This expectation crashed</error>
+                    </testcase>
+                  </testsuite>
+                </testsuites>
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn json_report() {
+        let mut buf = Vec::new();
+        crate::report::write_json(
+            &mut buf,
+            &report_records()[1..],
+            std::time::Duration::from_millis(5),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            concat!(
+                r#"{"passed":0,"failed":1,"panicked":1,"duration_ms":5.000,"tests":["#,
+                r#"{"module":"Parser","name":"expect on line 8","file":"Parser.roc","#,
+                r#""region":{"start":{"line":8,"column":1},"end":{"line":8,"column":14}},"#,
+                r#""outcome":"failed","duration_ms":0.250,"panic_message":null,"#,
+                r#""report":"This expectation failed:\n\n8│  expect 1 == 2"},"#,
+                r#"{"module":"Main","name":"crashes","file":"main.roc","#,
+                r#""region":{"start":{"line":1,"column":1},"end":{"line":1,"column":14}},"#,
+                r#""outcome":"panicked","duration_ms":2.000,"panic_message":"\"oops\"","#,
+                r#""report":"This expectation crashed"}]}"#,
+                "\n"
+            )
+        );
+    }
 }
@@ -0,0 +1,327 @@
+//! Machine-readable reports of a `roc test` run, for CI systems to ingest.
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use roc_collections::VecMap;
+use roc_load::Expectations;
+use roc_module::symbol::{Interns, ModuleId};
+use roc_region::all::{LineColumnRegion, LineInfo};
+use roc_reporting::report::push_json_string;
+
+use crate::run::ExpectResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Junit,
+    Json,
+}
+
+impl ReportFormat {
+    /// Parse a `--report` argument like `junit=target/tests.xml`
+    pub fn parse_arg(arg: &str) -> Result<(Self, PathBuf), String> {
+        let (format, path) = match arg.split_once('=') {
+            Some((format, path)) if !path.is_empty() => (format, path),
+            _ => {
+                return Err(format!(
+                    "expected `junit=<path>` or `json=<path>`, got `{arg}`"
+                ))
+            }
+        };
+
+        match format {
+            "junit" => Ok((ReportFormat::Junit, PathBuf::from(path))),
+            "json" => Ok((ReportFormat::Json, PathBuf::from(path))),
+            other => Err(format!(
+                "unknown report format `{other}`; the supported formats are `junit` and `json`"
+            )),
+        }
+    }
+}
+
+/// Everything a report says about one expect
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestRecord<'a> {
+    pub module: &'a str,
+    pub name: &'a str,
+    pub file: &'a Path,
+    pub region: LineColumnRegion,
+    pub duration: Duration,
+    pub outcome: TestOutcome<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestOutcome<'a> {
+    Passed,
+    /// The expect ran to the end, but its condition was false
+    Failed {
+        report: &'a str,
+    },
+    /// The expect was stopped by a panic or a `crash`
+    Panicked {
+        message: &'a str,
+        report: &'a str,
+    },
+}
+
+impl<'a> TestOutcome<'a> {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TestOutcome::Passed => "passed",
+            TestOutcome::Failed { .. } => "failed",
+            TestOutcome::Panicked { .. } => "panicked",
+        }
+    }
+}
+
+/// Gather what the reports need from the results of [`crate::run::run_expects`].
+pub fn test_records<'r>(
+    interns: &'r Interns,
+    expectations: &'r VecMap<ModuleId, Expectations>,
+    results: &'r [ExpectResult<'r>],
+) -> Vec<TestRecord<'r>> {
+    let mut line_infos: VecMap<ModuleId, LineInfo> = VecMap::default();
+
+    results
+        .iter()
+        .map(|result| {
+            let module_id = result.expect.symbol.module_id();
+            let module = interns.module_name(module_id).as_str();
+
+            let (file, region) = match expectations.get(&module_id) {
+                Some(data) => {
+                    if !line_infos.contains_key(&module_id) {
+                        let source = std::fs::read_to_string(&data.path).unwrap_or_default();
+                        line_infos.insert(module_id, LineInfo::new(&source));
+                    }
+
+                    let line_info = line_infos.get(&module_id).unwrap();
+
+                    (
+                        data.path.as_path(),
+                        line_info.convert_region(result.expect.region),
+                    )
+                }
+                None => (Path::new(""), LineColumnRegion::zero()),
+            };
+
+            // the report already says which module the test is in
+            let name = result.expect.test_name;
+            let name = name
+                .strip_prefix(module)
+                .and_then(|name| name.strip_prefix(": "))
+                .unwrap_or(name);
+
+            let outcome = match &result.failure {
+                None => TestOutcome::Passed,
+                Some(failure) => match &failure.panic_message {
+                    None => TestOutcome::Failed {
+                        report: failure.report.trim(),
+                    },
+                    Some(message) => TestOutcome::Panicked {
+                        message: message.as_str(),
+                        report: failure.report.trim(),
+                    },
+                },
+            };
+
+            TestRecord {
+                module,
+                name,
+                file,
+                region,
+                duration: result.duration,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+/// Write a JUnit XML report, with one `<testsuite>` per module.
+pub fn write_junit(
+    writer: &mut impl std::io::Write,
+    records: &[TestRecord],
+    total_time: Duration,
+) -> std::io::Result<()> {
+    let (failures, errors) = count_failures(records.iter());
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<testsuites name="roc test" tests="{}" failures="{failures}" errors="{errors}" time="{}">"#,
+        records.len(),
+        seconds(total_time),
+    )?;
+
+    // group by module, keeping the modules in the order their expects ran
+    let mut modules: VecMap<&str, Vec<&TestRecord>> = VecMap::default();
+
+    for record in records {
+        match modules.get_mut(&record.module) {
+            Some(module_records) => module_records.push(record),
+            None => {
+                modules.insert(record.module, vec![record]);
+            }
+        }
+    }
+
+    for (module, module_records) in modules.iter() {
+        let (failures, errors) = count_failures(module_records.iter().copied());
+        let time: Duration = module_records.iter().map(|record| record.duration).sum();
+
+        writeln!(
+            writer,
+            r#"  <testsuite name="{}" tests="{}" failures="{failures}" errors="{errors}" time="{}">"#,
+            xml_escape(module),
+            module_records.len(),
+            seconds(time),
+        )?;
+
+        for record in module_records {
+            write!(
+                writer,
+                r#"    <testcase name="{}" classname="{}" file="{}" line="{}" time="{}""#,
+                xml_escape(record.name),
+                xml_escape(record.module),
+                xml_escape(&record.file.to_string_lossy()),
+                record.region.start.line + 1,
+                seconds(record.duration),
+            )?;
+
+            match &record.outcome {
+                TestOutcome::Passed => writeln!(writer, "/>")?,
+                TestOutcome::Failed { report } => {
+                    writeln!(writer, ">")?;
+                    writeln!(
+                        writer,
+                        r#"      <failure message="expectation failed">{}</failure>"#,
+                        xml_escape(report)
+                    )?;
+                    writeln!(writer, "    </testcase>")?;
+                }
+                TestOutcome::Panicked { message, report } => {
+                    writeln!(writer, ">")?;
+                    writeln!(
+                        writer,
+                        r#"      <error message="{}">{}</error>"#,
+                        xml_escape(message),
+                        xml_escape(report)
+                    )?;
+                    writeln!(writer, "    </testcase>")?;
+                }
+            }
+        }
+
+        writeln!(writer, "  </testsuite>")?;
+    }
+
+    writeln!(writer, "</testsuites>")
+}
+
+/// Write a JSON report: a summary of the run, and an object for every expect.
+pub fn write_json(
+    writer: &mut impl std::io::Write,
+    records: &[TestRecord],
+    total_time: Duration,
+) -> std::io::Result<()> {
+    let (failures, errors) = count_failures(records.iter());
+
+    let mut buf = String::new();
+
+    buf.push_str(&format!(
+        "{{\"passed\":{},\"failed\":{failures},\"panicked\":{errors},\"duration_ms\":{},\"tests\":[",
+        records.len() - failures - errors,
+        millis(total_time),
+    ));
+
+    for (index, record) in records.iter().enumerate() {
+        if index > 0 {
+            buf.push(',');
+        }
+
+        let LineColumnRegion { start, end } = record.region;
+
+        buf.push_str("{\"module\":");
+        push_json_string(&mut buf, record.module);
+        buf.push_str(",\"name\":");
+        push_json_string(&mut buf, record.name);
+        buf.push_str(",\"file\":");
+        push_json_string(&mut buf, &record.file.to_string_lossy());
+        buf.push_str(&format!(
+            ",\"region\":{{\"start\":{{\"line\":{},\"column\":{}}},\"end\":{{\"line\":{},\"column\":{}}}}}",
+            start.line + 1,
+            start.column + 1,
+            end.line + 1,
+            end.column + 1
+        ));
+        buf.push_str(",\"outcome\":");
+        push_json_string(&mut buf, record.outcome.as_str());
+        buf.push_str(&format!(",\"duration_ms\":{}", millis(record.duration)));
+
+        let (panic_message, report) = match &record.outcome {
+            TestOutcome::Passed => (None, None),
+            TestOutcome::Failed { report } => (None, Some(report)),
+            TestOutcome::Panicked { message, report } => (Some(message), Some(report)),
+        };
+
+        buf.push_str(",\"panic_message\":");
+        push_json_optional(&mut buf, panic_message.copied());
+        buf.push_str(",\"report\":");
+        push_json_optional(&mut buf, report.copied());
+        buf.push('}');
+    }
+
+    buf.push_str("]}");
+
+    writeln!(writer, "{buf}")
+}
+
+fn push_json_optional(buf: &mut String, string: Option<&str>) {
+    match string {
+        None => buf.push_str("null"),
+        Some(string) => push_json_string(buf, string),
+    }
+}
+
+/// The number of (failed, panicked) records
+fn count_failures<'r, 'a: 'r>(records: impl Iterator<Item = &'r TestRecord<'a>>) -> (usize, usize) {
+    let mut failures = 0;
+    let mut errors = 0;
+
+    for record in records {
+        match record.outcome {
+            TestOutcome::Passed => {}
+            TestOutcome::Failed { .. } => failures += 1,
+            TestOutcome::Panicked { .. } => errors += 1,
+        }
+    }
+
+    (failures, errors)
+}
+
+fn seconds(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64())
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0 can't represent most control characters, even escaped
+            '\n' | '\r' | '\t' => escaped.push(ch),
+            ch if (ch as u32) < 0x20 => {}
+            ch => escaped.push(ch),
+        }
+    }
+
+    escaped
+}
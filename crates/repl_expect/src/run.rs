@@ -1,4 +1,8 @@
-use std::{os::unix::process::parent_id, sync::Arc};
+use std::{
+    os::unix::process::parent_id,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump;
@@ -6,17 +10,21 @@ use inkwell::context::Context;
 use roc_build::link::llvm_module_to_dylib;
 use roc_collections::{MutSet, VecMap};
 use roc_gen_llvm::{
-    llvm::{build::LlvmBackendMode, externs::add_default_roc_externs},
+    llvm::{
+        build::{LlvmBackendMode, PanicTagId},
+        externs::add_default_roc_externs,
+    },
     run_roc::RocCallResult,
     run_roc_dylib,
 };
 use roc_intern::{GlobalInterner, SingleThreadedInterner};
-use roc_load::{EntryPoint, Expectations, MonomorphizedModule};
+use roc_load::{EntryPoint, ExpectSource, Expectations, MonomorphizedModule};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::{ir::OptLevel, layout::Layout};
-use roc_region::all::Region;
+use roc_region::all::{LineInfo, Region};
 use roc_reporting::{error::expect::Renderer, report::RenderTarget};
 use roc_target::TargetInfo;
+use roc_types::subs::Variable;
 use target_lexicon::Triple;
 
 pub(crate) struct ExpectMemory<'a> {
@@ -80,8 +88,61 @@ impl<'a> ExpectMemory<'a> {
     }
 }
 
+fn set_gen_seed(lib: &libloading::Library, seed: u64) {
+    let set_gen_seed = run_roc_dylib!(lib, "set_gen_seed", u64, ());
+    let mut result = RocCallResult::default();
+    unsafe { set_gen_seed(seed, &mut result) };
+}
+
+/// How [`run_expects`] reports on the expects it runs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Print a pass/fail line with a timing for every expect, rather than only
+    /// the reports of the ones that failed.
+    pub report_each: bool,
+    /// Run every pure expect in a forked process, so that a segfault, stack overflow
+    /// or hang fails only that expect instead of ending the whole run.
+    pub isolate: bool,
+    /// With `isolate`, how long an expect may run before it is killed and counted as failed
+    pub timeout: Option<Duration>,
+    /// The seed of the random values that `Gen.check` tries properties with
+    pub seed: u64,
+}
+
+/// The outcome of running one top-level expect
+#[derive(Debug, Clone)]
+pub struct ExpectResult<'a> {
+    pub expect: ToplevelExpect<'a>,
+    pub duration: Duration,
+    pub failure: Option<ExpectFailure>,
+}
+
+impl<'a> ExpectResult<'a> {
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExpectFailure {
+    /// The message of the panic or `crash` that stopped the expect, if it didn't run to the end
+    pub panic_message: Option<String>,
+    /// What was printed about the failure, without colors: the failing code and the values
+    /// its variables had
+    pub report: String,
+}
+
+impl ExpectFailure {
+    fn append_report(&mut self, rendered: &[u8]) -> std::io::Result<()> {
+        let plain = strip_ansi_escapes::strip(rendered)?;
+        self.report.push_str(&String::from_utf8_lossy(&plain));
+
+        Ok(())
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
-pub fn run_expects<'a, W: std::io::Write>(
+pub fn run_expects<'a, 'e, W: std::io::Write>(
     writer: &mut W,
     render_target: RenderTarget,
     arena: &'a Bump,
@@ -89,8 +150,9 @@ pub fn run_expects<'a, W: std::io::Write>(
     layout_interner: &Arc<GlobalInterner<'a, Layout<'a>>>,
     lib: &libloading::Library,
     expectations: &mut VecMap<ModuleId, Expectations>,
-    expects: ExpectFunctions<'_>,
-) -> std::io::Result<(usize, usize)> {
+    expects: ExpectFunctions<'e>,
+    options: RunOptions,
+) -> std::io::Result<Vec<ExpectResult<'e>>> {
     let shm_name = format!("/roc_expect_buffer_{}", std::process::id());
     let mut memory = ExpectMemory::create_or_reuse_mmap(&shm_name);
 
@@ -103,12 +165,13 @@ pub fn run_expects<'a, W: std::io::Write>(
         lib,
         expectations,
         expects,
+        options,
         &mut memory,
     )
 }
 
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
+pub(crate) fn run_expects_with_memory<'a, 'e, W: std::io::Write>(
     writer: &mut W,
     render_target: RenderTarget,
     arena: &'a Bump,
@@ -116,14 +179,19 @@ pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
     layout_interner: &Arc<GlobalInterner<'a, Layout<'a>>>,
     lib: &libloading::Library,
     expectations: &mut VecMap<ModuleId, Expectations>,
-    expects: ExpectFunctions<'_>,
+    expects: ExpectFunctions<'e>,
+    options: RunOptions,
     memory: &mut ExpectMemory,
-) -> std::io::Result<(usize, usize)> {
-    let mut failed = 0;
-    let mut passed = 0;
+) -> std::io::Result<Vec<ExpectResult<'e>>> {
+    let mut results = Vec::with_capacity(expects.len());
+
+    // forked processes inherit the seed, so it only needs to be set once
+    set_gen_seed(lib, options.seed);
 
     for expect in expects.fx {
-        let result = run_expect_fx(
+        let start_time = Instant::now();
+
+        let failure = run_expect_fx(
             writer,
             render_target,
             arena,
@@ -135,34 +203,88 @@ pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
             expect,
         )?;
 
-        match result {
-            true => passed += 1,
-            false => failed += 1,
+        let result = ExpectResult {
+            expect,
+            duration: start_time.elapsed(),
+            failure,
+        };
+
+        if options.report_each {
+            report_result(writer, render_target, &result)?;
         }
+
+        results.push(result);
     }
 
     memory.set_shared_buffer(lib);
 
     for expect in expects.pure {
-        let result = run_expect_pure(
-            writer,
-            render_target,
-            arena,
-            interns,
-            layout_interner,
-            lib,
-            expectations,
-            memory,
+        let start_time = Instant::now();
+
+        let failure = if options.isolate {
+            run_expect_pure_isolated(
+                writer,
+                render_target,
+                arena,
+                interns,
+                layout_interner,
+                lib,
+                expectations,
+                memory,
+                expect,
+                options.timeout,
+            )?
+        } else {
+            run_expect_pure(
+                writer,
+                render_target,
+                arena,
+                interns,
+                layout_interner,
+                lib,
+                expectations,
+                memory,
+                expect,
+            )?
+        };
+
+        let result = ExpectResult {
             expect,
-        )?;
+            duration: start_time.elapsed(),
+            failure,
+        };
 
-        match result {
-            true => passed += 1,
-            false => failed += 1,
+        if options.report_each {
+            report_result(writer, render_target, &result)?;
         }
+
+        results.push(result);
     }
 
-    Ok((failed, passed))
+    Ok(results)
+}
+
+fn report_result<W: std::io::Write>(
+    writer: &mut W,
+    render_target: RenderTarget,
+    result: &ExpectResult,
+) -> std::io::Result<()> {
+    let (status, color) = match result.passed() {
+        true => ("PASS", 32),  // green
+        false => ("FAIL", 31), // red
+    };
+
+    let status = match render_target {
+        RenderTarget::ColorTerminal => format!("\x1B[{color}m{status}\x1B[39m"),
+        RenderTarget::Generic | RenderTarget::Json => status.to_string(),
+    };
+
+    writeln!(
+        writer,
+        "{status} {} ({} ms)",
+        result.expect.test_name,
+        result.duration.as_millis()
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -176,12 +298,13 @@ fn run_expect_pure<'a, W: std::io::Write>(
     expectations: &mut VecMap<ModuleId, Expectations>,
     shared_memory: &mut ExpectMemory,
     expect: ToplevelExpect<'_>,
-) -> std::io::Result<bool> {
+) -> std::io::Result<Option<ExpectFailure>> {
     use roc_gen_llvm::try_run_jit_function;
 
     let sequence = ExpectSequence::new(shared_memory.ptr.cast());
 
-    let result: Result<(), String> = try_run_jit_function!(lib, expect.name, (), |v: ()| v);
+    let result: Result<(), (String, PanicTagId)> =
+        try_run_jit_function!(lib, expect.name, (), |v: ()| v);
 
     let shared_memory_ptr: *const u8 = shared_memory.ptr.cast();
 
@@ -195,14 +318,37 @@ fn run_expect_pure<'a, W: std::io::Write>(
 
         let renderer = Renderer::new(arena, interns, render_target, module_id, filename, &source);
 
-        if let Err(roc_panic_message) = result {
-            renderer.render_panic(writer, &roc_panic_message, expect.region)?;
+        // rendered into a buffer first, so the failure can also go into a test report
+        let mut buffer = Vec::new();
+        let mut failure = ExpectFailure::default();
+
+        if let Err((roc_crash_message, PanicTagId::UserCrash)) = &result {
+            // the crash replaced the buffer with a frame holding its location
+            let frame = ExpectFrame::at_offset(shared_memory_ptr, ExpectSequence::START_OFFSET);
+
+            if frame.module_id == module_id {
+                renderer.render_crash(
+                    &mut buffer,
+                    roc_crash_message,
+                    frame.region,
+                    Some(expect.region),
+                )?;
+            } else {
+                // the crash is in another module, so it can't be shown next to the expect
+                renderer.render_panic(&mut buffer, roc_crash_message, expect.region)?;
+            }
+
+            failure.panic_message = Some(roc_crash_message.clone());
+        } else if let Err((roc_panic_message, _)) = &result {
+            renderer.render_panic(&mut buffer, roc_panic_message, expect.region)?;
+
+            failure.panic_message = Some(roc_panic_message.clone());
         } else {
             let mut offset = ExpectSequence::START_OFFSET;
 
             for _ in 0..sequence.count_failures() {
                 offset += render_expect_failure(
-                    writer,
+                    &mut buffer,
                     &renderer,
                     arena,
                     Some(expect),
@@ -215,11 +361,286 @@ fn run_expect_pure<'a, W: std::io::Write>(
             }
         }
 
+        writer.write_all(&buffer)?;
         writeln!(writer)?;
 
-        Ok(false)
+        failure.append_report(&buffer)?;
+
+        Ok(Some(failure))
     } else {
-        Ok(true)
+        Ok(None)
+    }
+}
+
+/// Run a pure expect in a forked process. The child runs and renders the expect as usual,
+/// and sends the rendered failure (if any) back through a pipe. If the child dies or
+/// runs past the timeout, the expect is reported as aborted instead.
+#[allow(clippy::too_many_arguments)]
+fn run_expect_pure_isolated<'a, W: std::io::Write>(
+    writer: &mut W,
+    render_target: RenderTarget,
+    arena: &'a Bump,
+    interns: &'a Interns,
+    layout_interner: &Arc<GlobalInterner<'a, Layout<'a>>>,
+    lib: &libloading::Library,
+    expectations: &mut VecMap<ModuleId, Expectations>,
+    shared_memory: &mut ExpectMemory,
+    expect: ToplevelExpect<'_>,
+    timeout: Option<Duration>,
+) -> std::io::Result<Option<ExpectFailure>> {
+    use std::io::{Read, Write};
+    use std::os::unix::io::FromRawFd;
+
+    let mut fds = [0; 2];
+
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    let [read_fd, write_fd] = fds;
+
+    // anything still buffered would otherwise be written by the child too
+    writer.flush()?;
+
+    match unsafe { libc::fork() } {
+        0 => unsafe {
+            // we are the child
+            libc::close(read_fd);
+
+            // A panic must not unwind out of here: the child would carry on running the
+            // parent's code. Catch it, and exit like any other failure to produce a result.
+            let message = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                // the child shares the parent's expect buffer, so the roc code can keep using it
+                let mut rendered = Vec::new();
+                let failure = run_expect_pure(
+                    &mut rendered,
+                    render_target,
+                    arena,
+                    interns,
+                    layout_interner,
+                    lib,
+                    expectations,
+                    shared_memory,
+                    expect,
+                )
+                .ok()?;
+
+                // the message is a failed flag, then the panic message (if any) and the rendered failure
+                let mut message = Vec::new();
+
+                match failure {
+                    None => message.push(0),
+                    Some(failure) => {
+                        message.push(1);
+
+                        match failure.panic_message {
+                            None => message.extend(u64::MAX.to_le_bytes()),
+                            Some(panic_message) => {
+                                message.extend((panic_message.len() as u64).to_le_bytes());
+                                message.extend(panic_message.as_bytes());
+                            }
+                        }
+
+                        message.extend(rendered);
+                    }
+                }
+
+                Some(message)
+            }));
+
+            // skip the parent's exit handlers, which would e.g. flush its buffers a second time
+            match message {
+                Ok(Some(message)) => {
+                    let mut pipe = std::fs::File::from_raw_fd(write_fd);
+                    let _ = pipe.write_all(&message);
+                    drop(pipe);
+
+                    libc::_exit(0)
+                }
+                Ok(None) | Err(_) => libc::_exit(1),
+            }
+        },
+        -1 => {
+            let error = std::io::Error::last_os_error();
+
+            unsafe {
+                libc::close(read_fd);
+                libc::close(write_fd);
+            }
+
+            Err(error)
+        }
+        child_pid => {
+            unsafe { libc::close(write_fd) };
+
+            let mut pipe = unsafe { std::fs::File::from_raw_fd(read_fd) };
+            let mut message = Vec::new();
+
+            let deadline = timeout.map(|timeout| Instant::now() + timeout);
+            let mut timed_out = false;
+
+            loop {
+                if let Some(deadline) = deadline {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    let mut poll_fd = libc::pollfd {
+                        fd: read_fd,
+                        events: libc::POLLIN,
+                        revents: 0,
+                    };
+
+                    let ready = unsafe {
+                        libc::poll(
+                            &mut poll_fd,
+                            1,
+                            remaining.as_millis().min(i32::MAX as _) as _,
+                        )
+                    };
+
+                    if ready == 0 {
+                        timed_out = true;
+                        unsafe { libc::kill(child_pid, libc::SIGKILL) };
+                        break;
+                    } else if ready < 0 {
+                        let error = std::io::Error::last_os_error();
+
+                        match error.kind() {
+                            std::io::ErrorKind::Interrupted => continue,
+                            _ => return Err(error),
+                        }
+                    }
+                }
+
+                let mut chunk = [0u8; 4096];
+
+                match pipe.read(&mut chunk) {
+                    Ok(0) => break,
+                    Ok(n) => message.extend_from_slice(&chunk[..n]),
+                    Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+                    Err(error) => return Err(error),
+                }
+            }
+
+            let mut status = 0;
+            unsafe { libc::waitpid(child_pid, &mut status, 0) };
+
+            let result = parse_isolated_message(&message);
+
+            let reason = if timed_out {
+                let timeout = timeout.unwrap_or_default();
+                Some(format!(
+                    "It did not finish within {} ms.",
+                    timeout.as_millis()
+                ))
+            } else if libc::WIFSIGNALED(status) {
+                let signal = libc::WTERMSIG(status);
+                Some(format!("It was killed by {}.", signal_name(signal)))
+            } else if libc::WEXITSTATUS(status) != 0 {
+                Some(format!(
+                    "Its process exited with code {}.",
+                    libc::WEXITSTATUS(status)
+                ))
+            } else if result.is_none() {
+                Some("Its process exited without reporting a result.".to_string())
+            } else {
+                None
+            };
+
+            if let Some(reason) = reason {
+                let module_id = expect.symbol.module_id();
+                let data = expectations.get_mut(&module_id).unwrap();
+                let filename = data.path.to_owned();
+                let source = std::fs::read_to_string(&data.path).unwrap();
+
+                let renderer =
+                    Renderer::new(arena, interns, render_target, module_id, filename, &source);
+
+                let mut buffer = Vec::new();
+                renderer.render_aborted(&mut buffer, &reason, expect.region)?;
+
+                writer.write_all(&buffer)?;
+                writeln!(writer)?;
+
+                let mut failure = ExpectFailure {
+                    panic_message: Some(reason),
+                    report: String::new(),
+                };
+                failure.append_report(&buffer)?;
+
+                return Ok(Some(failure));
+            }
+
+            match result {
+                None | Some(IsolatedResult::Passed) => Ok(None),
+                Some(IsolatedResult::Failed {
+                    panic_message,
+                    rendered,
+                }) => {
+                    writer.write_all(rendered)?;
+
+                    let mut failure = ExpectFailure {
+                        panic_message,
+                        report: String::new(),
+                    };
+                    failure.append_report(rendered)?;
+
+                    Ok(Some(failure))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum IsolatedResult<'a> {
+    Passed,
+    Failed {
+        panic_message: Option<String>,
+        rendered: &'a [u8],
+    },
+}
+
+/// Reads the message that the child process of `run_expect_pure_isolated` wrote to the pipe.
+/// Returns `None` if it is empty or cut short, e.g. because the child died while writing it.
+pub(crate) fn parse_isolated_message(message: &[u8]) -> Option<IsolatedResult<'_>> {
+    let (failed, rest) = message.split_first()?;
+
+    if *failed == 0 {
+        return Some(IsolatedResult::Passed);
+    }
+
+    let length = rest.get(..8)?;
+    let rest = &rest[8..];
+
+    let (panic_message, rendered) = match u64::from_le_bytes(length.try_into().unwrap()) {
+        u64::MAX => (None, rest),
+        length => {
+            let length = usize::try_from(length)
+                .ok()
+                .filter(|len| *len <= rest.len())?;
+            let (panic_message, rendered) = rest.split_at(length);
+
+            (
+                Some(String::from_utf8_lossy(panic_message).into_owned()),
+                rendered,
+            )
+        }
+    };
+
+    Some(IsolatedResult::Failed {
+        panic_message,
+        rendered,
+    })
+}
+
+fn signal_name(signal: i32) -> String {
+    match signal {
+        libc::SIGSEGV => "SIGSEGV (a segmentation fault)".to_string(),
+        libc::SIGBUS => "SIGBUS (a bus error)".to_string(),
+        libc::SIGILL => "SIGILL (an illegal instruction)".to_string(),
+        libc::SIGFPE => "SIGFPE (an arithmetic error)".to_string(),
+        libc::SIGABRT => "SIGABRT (an abort, e.g. from a stack overflow)".to_string(),
+        libc::SIGKILL => "SIGKILL".to_string(),
+        other => format!("signal {other}"),
     }
 }
 
@@ -234,7 +655,7 @@ fn run_expect_fx<'a, W: std::io::Write>(
     expectations: &mut VecMap<ModuleId, Expectations>,
     parent_memory: &mut ExpectMemory,
     expect: ToplevelExpect<'_>,
-) -> std::io::Result<bool> {
+) -> std::io::Result<Option<ExpectFailure>> {
     use signal_hook::{consts::signal::SIGCHLD, consts::signal::SIGUSR1, iterator::Signals};
 
     let mut signals = Signals::new(&[SIGCHLD, SIGUSR1]).unwrap();
@@ -272,17 +693,16 @@ fn run_expect_fx<'a, W: std::io::Write>(
             std::process::exit(1)
         }
         1.. => {
-            let mut has_succeeded = true;
+            let mut failure: Option<ExpectFailure> = None;
 
             for sig in &mut signals {
                 match sig {
                     SIGCHLD => {
                         // done!
-                        return Ok(has_succeeded);
+                        return Ok(failure);
                     }
                     SIGUSR1 => {
                         // this is the signal we use for an expect failure. Let's see what the child told us
-                        has_succeeded = false;
 
                         let frame =
                             ExpectFrame::at_offset(parent_memory.ptr, ExpectSequence::START_OFFSET);
@@ -301,8 +721,10 @@ fn run_expect_fx<'a, W: std::io::Write>(
                             &source,
                         );
 
+                        let mut buffer = Vec::new();
+
                         render_expect_failure(
-                            writer,
+                            &mut buffer,
                             &renderer,
                             arena,
                             None,
@@ -312,12 +734,18 @@ fn run_expect_fx<'a, W: std::io::Write>(
                             parent_memory.ptr,
                             ExpectSequence::START_OFFSET,
                         )?;
+
+                        writer.write_all(&buffer)?;
+
+                        failure
+                            .get_or_insert_with(ExpectFailure::default)
+                            .append_report(&buffer)?;
                     }
                     _ => println!("received signal {}", sig),
                 }
             }
 
-            Ok(true)
+            Ok(failure)
         }
         _ => unreachable!(),
     }
@@ -360,6 +788,73 @@ pub fn roc_dev_expect<'a>(
     )
 }
 
+/// Render the frame that a `dbg` or a `crash` sent to `roc dev`
+pub fn roc_dev_dbg<'a>(
+    writer: &mut impl std::io::Write,
+    arena: &'a Bump,
+    expectations: &mut VecMap<ModuleId, Expectations>,
+    interns: &'a Interns,
+    layout_interner: &Arc<GlobalInterner<'a, Layout<'a>>>,
+    frame_ptr: *const u8,
+) -> std::io::Result<()> {
+    // we always run programs as the host
+    let target_info = (&target_lexicon::Triple::host()).into();
+
+    let frame = ExpectFrame::at_offset(frame_ptr, ExpectSequence::START_OFFSET);
+    let module_id = frame.module_id;
+
+    let data = match expectations.get_mut(&module_id) {
+        Some(data) => data,
+        None => {
+            // e.g. a module from a package, which we keep no sources for
+            return Ok(());
+        }
+    };
+    let filename = data.path.to_owned();
+    let source = std::fs::read_to_string(&data.path).unwrap();
+
+    let is_crash = data.crashes.contains(&frame.region);
+
+    let variable = match data.dbgs.get(&frame.region) {
+        Some(variable) => *variable,
+        None if is_crash => Variable::STR,
+        None => panic!("region {:?} not in list of dbgs or crashes", frame.region),
+    };
+    let subs = arena.alloc(&mut data.subs);
+
+    let (_, expressions) = crate::get_values(
+        target_info,
+        arena,
+        subs,
+        interns,
+        layout_interner,
+        frame_ptr,
+        frame.start_offset,
+        &[variable],
+    )
+    .unwrap();
+
+    let renderer = Renderer::new(
+        arena,
+        interns,
+        RenderTarget::ColorTerminal,
+        module_id,
+        filename,
+        &source,
+    );
+
+    if is_crash {
+        let message = match &expressions[0] {
+            roc_parse::ast::Expr::Str(roc_parse::ast::StrLiteral::PlainLine(message)) => message,
+            other => unreachable!("a crash message must be a Str, not {:?}", other),
+        };
+
+        renderer.render_crash(writer, message, frame.region, None)
+    } else {
+        renderer.render_dbg(writer, subs, variable, &expressions[0], frame.region)
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn render_expect_failure<'a>(
     writer: &mut impl std::io::Write,
@@ -473,6 +968,9 @@ pub struct ToplevelExpect<'a> {
     pub name: &'a str,
     pub symbol: Symbol,
     pub region: Region,
+    /// What `roc test` calls this expect, e.g. `Parser: parses negative numbers`.
+    /// See [`expect_test_name`].
+    pub test_name: &'a str,
 }
 
 #[derive(Debug)]
@@ -481,6 +979,73 @@ pub struct ExpectFunctions<'a> {
     pub fx: BumpVec<'a, ToplevelExpect<'a>>,
 }
 
+impl<'a> ExpectFunctions<'a> {
+    pub fn len(&self) -> usize {
+        self.pure.len() + self.fx.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The expects in the order they are run
+    pub fn iter(&self) -> impl Iterator<Item = &ToplevelExpect<'a>> {
+        self.fx.iter().chain(self.pure.iter())
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(&ToplevelExpect<'a>) -> bool) {
+        self.pure.retain(&mut keep);
+        self.fx.retain(keep);
+    }
+}
+
+/// A top-level expect is named by the comment directly above it, prefixed with the name
+/// of its module:
+///
+/// ```text
+/// # parses negative numbers
+/// expect parse "-1" == Ok -1
+/// ```
+///
+/// An expect without such a comment is named by the line it is on instead, and so is an
+/// example in a doc comment (a doctest).
+pub(crate) fn expect_test_name(
+    module_name: &str,
+    source: &str,
+    line_info: &LineInfo,
+    expect: ExpectSource,
+) -> String {
+    let line = line_info.convert_pos(expect.preceding_comment.end()).line;
+
+    if expect.is_doctest {
+        return format!("{module_name}: doctest on line {}", line + 1);
+    }
+
+    let preceding_comment = &source[expect.preceding_comment.start().offset as usize
+        ..expect.preceding_comment.end().offset as usize];
+
+    let mut comment = Vec::new();
+
+    for comment_line in preceding_comment.lines() {
+        let comment_line = comment_line.trim();
+
+        if comment_line.is_empty() {
+            // only the comment directly above the expect names it
+            comment.clear();
+        } else {
+            comment.push(comment_line.trim_start_matches('#').trim());
+        }
+    }
+
+    let label = comment.join(" ");
+
+    if label.is_empty() {
+        format!("{module_name}: expect on line {}", line + 1)
+    } else {
+        format!("{module_name}: {label}")
+    }
+}
+
 pub fn expect_mono_module_to_dylib<'a>(
     arena: &'a Bump,
     target: Triple,
@@ -503,6 +1068,7 @@ pub fn expect_mono_module_to_dylib<'a>(
         entry_point,
         interns,
         layout_interner,
+        sources,
         ..
     } = loaded;
 
@@ -559,15 +1125,37 @@ pub fn expect_mono_module_to_dylib<'a>(
         opt_entry_point,
     );
 
+    let mut line_infos = VecMap::default();
+    let mut test_name = |symbol: Symbol, expect| -> &'a str {
+        let module_id = symbol.module_id();
+        let module_name = env.interns.module_name(module_id).as_str();
+
+        let name = match sources.get(&module_id) {
+            Some((_, source)) => {
+                if !line_infos.contains_key(&module_id) {
+                    line_infos.insert(module_id, LineInfo::new(source));
+                }
+
+                let line_info = line_infos.get(&module_id).unwrap();
+
+                expect_test_name(module_name, source, line_info, expect)
+            }
+            None => format!("{module_name}: expect"),
+        };
+
+        arena.alloc_str(&name)
+    };
+
     let expects_fx = bumpalo::collections::Vec::from_iter_in(
         toplevel_expects
             .fx
             .into_iter()
             .zip(expect_names.iter().skip(toplevel_expects.pure.len()))
-            .map(|((symbol, region), name)| ToplevelExpect {
+            .map(|((symbol, expect), name)| ToplevelExpect {
                 symbol,
-                region,
+                region: expect.region,
                 name,
+                test_name: test_name(symbol, expect),
             }),
         env.arena,
     );
@@ -577,10 +1165,11 @@ pub fn expect_mono_module_to_dylib<'a>(
             .pure
             .into_iter()
             .zip(expect_names.iter())
-            .map(|((symbol, region), name)| ToplevelExpect {
+            .map(|((symbol, expect), name)| ToplevelExpect {
                 symbol,
-                region,
+                region: expect.region,
                 name,
+                test_name: test_name(symbol, expect),
             }),
         env.arena,
     );
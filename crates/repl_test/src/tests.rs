@@ -506,6 +506,26 @@ fn basic_3_field_record() {
     );
 }
 
+#[test]
+fn basic_2_element_tuple() {
+    expect_success("(\"hello\", 42)", "(\"hello\", 42) : ( Str, Num * )");
+}
+
+#[test]
+fn mixed_alignment_tuple() {
+    // The elements are reordered by alignment in memory, but the repl should
+    // still print them in the order they were written
+    expect_success("(7u8, 32u64, 4.1)", "(7, 32, 4.1) : ( U8, U64, Float * )");
+}
+
+#[test]
+fn nested_tuple() {
+    expect_success(
+        "(1, (2, \"three\"))",
+        "(1, (2, \"three\")) : ( Num *, ( Num *, Str ) )",
+    );
+}
+
 #[test]
 fn list_of_1_field_records() {
     // Even though these get unwrapped at runtime, the repl should still
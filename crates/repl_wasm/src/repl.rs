@@ -76,7 +76,8 @@ impl<'a> ReplAppMemory for WasmMemory<'a> {
             &self.copied_bytes[addr..][..len]
         } else {
             let chars_index = self.deref_usize(addr);
-            let len = self.deref_usize(addr + 4);
+            // the top bit of the length marks a seamless slice
+            let len = self.deref_usize(addr + 4) & 0x7fff_ffff;
             &self.copied_bytes[chars_index..][..len]
         };
 
@@ -38,6 +38,10 @@ const OPAQUE_NOT_DEFINED: &str = "OPAQUE TYPE NOT DEFINED";
 const OPAQUE_DECLARED_OUTSIDE_SCOPE: &str = "OPAQUE TYPE DECLARED OUTSIDE SCOPE";
 const OPAQUE_NOT_APPLIED: &str = "OPAQUE TYPE NOT APPLIED";
 const OPAQUE_OVER_APPLIED: &str = "OPAQUE TYPE APPLIED TO TOO MANY ARGS";
+const UNAPPLIED_CRASH: &str = "UNAPPLIED CRASH";
+const OVERAPPLIED_CRASH: &str = "OVERAPPLIED CRASH";
+const MULTIPLE_RECORD_BUILDERS: &str = "MULTIPLE RECORD BUILDERS";
+const UNAPPLIED_RECORD_BUILDER: &str = "UNAPPLIED RECORD BUILDER";
 const INVALID_EXTENSION_TYPE: &str = "INVALID_EXTENSION_TYPE";
 const ABILITY_HAS_TYPE_VARIABLES: &str = "ABILITY HAS TYPE VARIABLES";
 const HAS_CLAUSE_IS_NOT_AN_ABILITY: &str = "HAS CLAUSE IS NOT AN ABILITY";
@@ -57,6 +61,7 @@ const ABILITY_IMPLEMENTATION_NOT_IDENTIFIER: &str = "ABILITY IMPLEMENTATION NOT
 const DUPLICATE_IMPLEMENTATION: &str = "DUPLICATE IMPLEMENTATION";
 const UNNECESSARY_IMPLEMENTATIONS: &str = "UNNECESSARY IMPLEMENTATIONS";
 const INCOMPLETE_ABILITY_IMPLEMENTATION: &str = "INCOMPLETE ABILITY IMPLEMENTATION";
+const UNPARSABLE_DOCTEST: &str = "UNPARSABLE DOC TEST";
 
 pub fn can_problem<'b>(
     alloc: &'b RocDocAllocator<'b>,
@@ -605,6 +610,7 @@ pub fn can_problem<'b>(
                 ExtensionTypeKind::TagUnion => {
                     ("tag union", "a type variable or another tag union")
                 }
+                ExtensionTypeKind::Tuple => ("tuple", "a type variable or another tuple"),
             };
 
             doc = alloc.stack([
@@ -996,6 +1002,15 @@ pub fn can_problem<'b>(
             title = "OVERLOADED SPECIALIZATION".to_string();
             severity = Severity::Warning;
         }
+        Problem::UnparsableDoctest(region) => {
+            doc = alloc.stack([
+                alloc.reflow("The code in this doc comment example doesn't parse:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow("So `roc test` skips it. Fix its syntax to have it checked again."),
+            ]);
+            title = UNPARSABLE_DOCTEST.to_string();
+            severity = Severity::Warning;
+        }
     };
 
     Report {
@@ -1538,7 +1553,7 @@ fn pretty_runtime_error<'b>(
             } else {
                 let qualified_suggestions = suggestions
                     .into_iter()
-                    .map(|v| alloc.string(module_name.to_string() + "." + v.as_str()));
+                    .map(|v| alloc.suggestion(module_name.to_string() + "." + v.as_str()));
                 alloc.stack([
                     alloc.reflow("Did you mean one of these?"),
                     alloc.vcat(qualified_suggestions).indent(4),
@@ -2003,6 +2018,64 @@ fn pretty_runtime_error<'b>(
 
             title = OPAQUE_OVER_APPLIED;
         }
+        RuntimeError::CrashNotApplied(region) => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword("crash"),
+                    alloc.reflow(" doesn't have a message given to it:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.keyword("crash"),
+                    alloc.reflow(" must be passed a message to crash with at the exact place it's used. "),
+                    alloc.keyword("crash"),
+                    alloc.reflow(" can't be used as a value that's passed around, like functions can be - it must be applied immediately!"),
+                ]),
+            ]);
+
+            title = UNAPPLIED_CRASH;
+        }
+        RuntimeError::CrashAppliedToMultipleArgs(region) => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword("crash"),
+                    alloc.reflow(" has too many values given to it:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.keyword("crash"),
+                    alloc.reflow(" must be given exactly one message to crash with."),
+                ]),
+            ]);
+
+            title = OVERAPPLIED_CRASH;
+        }
+        RuntimeError::MultipleRecordBuilders(region) => {
+            doc = alloc.stack([
+                alloc.reflow("This function is applied to multiple record builders:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.note("Functions can only take at most one record builder!"),
+                alloc
+                    .tip()
+                    .append(alloc.reflow("You can combine them or apply them separately.")),
+            ]);
+
+            title = MULTIPLE_RECORD_BUILDERS;
+        }
+        RuntimeError::UnappliedRecordBuilder(region) => {
+            doc = alloc.stack([
+                alloc.reflow("This record builder was not applied to a function:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow("However, we need a function to construct the record."),
+                alloc.note(
+                    "Functions must be applied directly. The pipe operator (|>) cannot be used.",
+                ),
+            ]);
+
+            title = UNAPPLIED_RECORD_BUILDER;
+        }
         RuntimeError::DegenerateBranch(region) => {
             doc = alloc.stack([
                 alloc.reflow("This branch pattern does not bind all symbols its body needs:"),
@@ -2089,7 +2162,11 @@ fn not_found<'b>(
             alloc.stack([
                 yes_suggestion_details,
                 alloc
-                    .vcat(suggestions.into_iter().map(|v| alloc.string(v.to_string())))
+                    .vcat(
+                        suggestions
+                            .into_iter()
+                            .map(|v| alloc.suggestion(v.to_string())),
+                    )
                     .indent(4),
             ])
         }
@@ -2140,7 +2217,7 @@ fn module_not_found<'b>(
             alloc.stack([
                 alloc.reflow("Is there an import missing? Perhaps there is a typo. Did you mean one of these?"),
                 alloc
-                    .vcat(suggestions.into_iter().map(|v| alloc.string(v.to_string())))
+                    .vcat(suggestions.into_iter().map(|v| alloc.suggestion(v.to_string())))
                     .indent(4),
             ])
         }
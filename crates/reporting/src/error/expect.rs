@@ -154,6 +154,58 @@ impl<'a> Renderer<'a> {
 
         report.render(
             self.render_target,
+            Some(line_col_region),
+            &mut buf,
+            &self.alloc,
+            &crate::report::DEFAULT_PALETTE,
+        );
+
+        write!(writer, "{}", buf)
+    }
+
+    pub fn render_dbg<W>(
+        &self,
+        writer: &mut W,
+        subs: &mut Subs,
+        variable: Variable,
+        expr: &Expr<'_>,
+        dbg_region: Region,
+    ) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        use crate::error::r#type::error_type_to_doc;
+        use crate::report::Report;
+        use roc_fmt::annotation::Formattable;
+        use ven_pretty::DocAllocator;
+
+        let line_col_region = self.line_info.convert_region(dbg_region);
+        let (error_type, _) = subs.var_to_error_type(variable);
+
+        let mut buf = roc_fmt::Buf::new_in(self.arena);
+        expr.format(&mut buf, 0);
+
+        let doc = self.alloc.stack([
+            self.alloc.region(line_col_region),
+            self.alloc
+                .text(buf.into_bump_str())
+                .append(" : ")
+                .append(error_type_to_doc(&self.alloc, error_type)),
+        ]);
+
+        // a `dbg` is not a problem, so don't make it look like one
+        let report = Report {
+            title: "DBG".into(),
+            doc,
+            filename: self.filename.clone(),
+            severity: crate::report::Severity::Warning,
+        };
+
+        let mut buf = String::new();
+
+        report.render(
+            self.render_target,
+            Some(line_col_region),
             &mut buf,
             &self.alloc,
             &crate::report::DEFAULT_PALETTE,
@@ -194,6 +246,103 @@ impl<'a> Renderer<'a> {
 
         report.render(
             self.render_target,
+            Some(line_col_region),
+            &mut buf,
+            &self.alloc,
+            &crate::report::DEFAULT_PALETTE,
+        );
+
+        write!(writer, "{}", buf)
+    }
+
+    /// Render an expect that never finished, because its process died or ran out of time
+    pub fn render_aborted<W>(
+        &self,
+        writer: &mut W,
+        reason: &str,
+        expect_region: Region,
+    ) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        use crate::report::Report;
+        use ven_pretty::DocAllocator;
+
+        let line_col_region = self.line_info.convert_region(expect_region);
+
+        let doc = self.alloc.stack([
+            self.alloc
+                .text("This expectation was stopped while running:"),
+            self.alloc.region(line_col_region),
+            self.alloc.text(reason),
+        ]);
+
+        let report = Report {
+            title: "EXPECT ABORTED".into(),
+            doc,
+            filename: self.filename.clone(),
+            severity: crate::report::Severity::RuntimeError,
+        };
+
+        let mut buf = String::new();
+
+        report.render(
+            self.render_target,
+            Some(line_col_region),
+            &mut buf,
+            &self.alloc,
+            &crate::report::DEFAULT_PALETTE,
+        );
+
+        write!(writer, "{}", buf)
+    }
+
+    pub fn render_crash<W>(
+        &self,
+        writer: &mut W,
+        message: &str,
+        crash_region: Region,
+        expect_region: Option<Region>,
+    ) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        use crate::report::Report;
+        use ven_pretty::DocAllocator;
+
+        let crash_doc = self
+            .alloc
+            .region(self.line_info.convert_region(crash_region));
+
+        let header = match expect_region {
+            Some(expect_region) => self.alloc.stack([
+                self.alloc.text("This expectation crashed while running:"),
+                self.alloc
+                    .region(self.line_info.convert_region(expect_region)),
+                self.alloc.text("The crash happened here:"),
+            ]),
+            None => self.alloc.text("This crash happened while running:"),
+        };
+
+        let doc = self.alloc.stack([
+            header,
+            crash_doc,
+            self.alloc.text("The crash reported this message:"),
+            self.alloc.text(message).indent(4),
+        ]);
+
+        let report = Report {
+            title: "CRASH".into(),
+            doc,
+            filename: self.filename.clone(),
+            severity: crate::report::Severity::RuntimeError,
+        };
+
+        let mut buf = String::new();
+
+        report.render(
+            self.render_target,
+            Some(self.line_info.convert_region(crash_region)),
             &mut buf,
             &self.alloc,
             &crate::report::DEFAULT_PALETTE,
@@ -521,6 +521,50 @@ fn to_expr_report<'a>(
             }
         }
 
+        EExpr::RecordUpdateBuilder(region) => {
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing a record update, and I found a record builder field:"),
+                alloc.region(lines.convert_region(*region)),
+                alloc.concat([
+                    alloc.reflow("Record builders cannot be used in record updates. "),
+                    alloc.reflow("Did you mean to use a "),
+                    alloc.keyword(":"),
+                    alloc.reflow(" instead of a "),
+                    alloc.keyword("<-"),
+                    alloc.reflow("?"),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "RECORD UPDATE BUILDER".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
+
+        EExpr::OptionalValueInRecordBuilder(region) => {
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing a record builder, and I found an optional field:"),
+                alloc.region(lines.convert_region(*region)),
+                alloc.concat([
+                    alloc.reflow("Optional fields can only appear when you destructure a record. "),
+                    alloc.reflow("Did you mean to use a "),
+                    alloc.keyword(":"),
+                    alloc.reflow(" instead of a "),
+                    alloc.keyword("?"),
+                    alloc.reflow("?"),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "BAD OPTIONAL VALUE".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
+
         EExpr::Space(error, pos) => to_space_report(alloc, lines, filename, error, *pos),
 
         &EExpr::Number(ENumber::End, pos) => {
@@ -1007,6 +1051,29 @@ fn to_expr_in_parens_report<'a>(
                 severity: Severity::RuntimeError,
             }
         }
+        EInParens::Empty(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing an expression in parentheses, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([
+                    alloc.reflow(r"Empty parentheses are not an expression. A tuple looks like "),
+                    alloc.parser_suggestion("(1, \"hello\")"),
+                    alloc.reflow(r", and the empty record is written "),
+                    alloc.parser_suggestion("{}"),
+                    alloc.text("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "EMPTY PARENTHESES".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
         EInParens::Open(pos) | EInParens::IndentOpen(pos) => {
             let surroundings = Region::new(start, pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
@@ -1916,6 +1983,32 @@ fn to_pattern_in_parens_report<'a>(
             }
         }
 
+        PInParens::Empty(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"I am partway through parsing a pattern in parentheses, but I got stuck here:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([
+                    alloc.reflow(r"Empty parentheses are not a pattern. A tuple looks like "),
+                    alloc.parser_suggestion("(x, y)"),
+                    alloc.reflow(r", and the empty record is written "),
+                    alloc.parser_suggestion("{}"),
+                    alloc.text("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "EMPTY PARENTHESES".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
+
         PInParens::Pattern(pattern, pos) => to_pattern_report(alloc, lines, filename, pattern, pos),
 
         PInParens::IndentOpen(pos) => {
@@ -2692,6 +2785,32 @@ fn to_tinparens_report<'a>(
             }
         }
 
+        ETypeInParens::Empty(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"I am partway through parsing a type in parentheses, but I got stuck here:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([
+                    alloc.reflow(r"Empty parentheses are not a type. A tuple looks like "),
+                    alloc.parser_suggestion("(Str, U64)"),
+                    alloc.reflow(r", and the empty record is written "),
+                    alloc.parser_suggestion("{}"),
+                    alloc.text("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "EMPTY PARENTHESES".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
+
         ETypeInParens::End(pos) => {
             let surroundings = Region::new(start, pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
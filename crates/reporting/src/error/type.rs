@@ -14,7 +14,7 @@ use roc_solve_problem::{
 use roc_std::RocDec;
 use roc_types::pretty_print::{Parens, WILDCARD};
 use roc_types::types::{
-    AliasKind, Category, ErrorType, PatternCategory, Reason, RecordField, TypeExt,
+    AbilitySet, AliasKind, Category, ErrorType, PatternCategory, Reason, RecordField, TypeExt,
 };
 use std::path::PathBuf;
 use ven_pretty::DocAllocator;
@@ -367,6 +367,11 @@ fn underivable_hint<'b>(
         NotDerivableContext::Function => Some(alloc.note("").append(alloc.concat([
             alloc.symbol_unqualified(ability),
             alloc.reflow(" cannot be generated for functions."),
+            if ability == Symbol::BOOL_EQ {
+                alloc.reflow(" Functions can never be compared for equality.")
+            } else {
+                alloc.nil()
+            },
         ]))),
         NotDerivableContext::Opaque(symbol) => Some(alloc.tip().append(alloc.concat([
             alloc.symbol_unqualified(symbol),
@@ -848,6 +853,33 @@ fn to_expr_report<'b>(
                     // they don't know. ("Wait, what's truthiness?")
                 )
             }
+            Reason::CrashArg => {
+                let problem = alloc.concat([
+                    alloc.text("This value passed to "),
+                    alloc.keyword("crash"),
+                    alloc.text(" is not a string:"),
+                ]);
+
+                report_bad_type(
+                    alloc,
+                    lines,
+                    filename,
+                    &category,
+                    found,
+                    expected_type,
+                    region,
+                    Some(expr_region),
+                    problem,
+                    alloc.text("The value is"),
+                    alloc.concat([
+                        alloc.reflow("But I can only "),
+                        alloc.keyword("crash"),
+                        alloc.reflow(" with messages of type "),
+                        alloc.type_str("Str"),
+                        alloc.reflow("."),
+                    ]),
+                )
+            }
             Reason::IfCondition => {
                 let problem = alloc.concat([
                     alloc.text("This "),
@@ -1248,6 +1280,29 @@ fn to_expr_report<'b>(
                 )
             }
 
+            Reason::RecordBuilderField => report_mismatch(
+                alloc,
+                lines,
+                filename,
+                &category,
+                found,
+                expected_type,
+                region,
+                Some(expr_region),
+                alloc.concat([
+                    alloc.text("This "),
+                    alloc.keyword("<-"),
+                    alloc.text(" field of a record builder has an unexpected type:"),
+                ]),
+                alloc.text("The field's builder is"),
+                alloc.concat([
+                    alloc.text("But "),
+                    alloc.ident("apply".into()),
+                    alloc.text(" needs it to be:"),
+                ]),
+                None,
+            ),
+
             Reason::NumericLiteralSuffix => report_mismatch(
                 alloc,
                 lines,
@@ -1686,6 +1741,18 @@ fn format_category<'b>(
             alloc.text(" of type:"),
         ),
 
+        Tuple => (
+            alloc.concat([this_is, alloc.text(" a tuple")]),
+            alloc.text(" of type:"),
+        ),
+        TupleAccess(index) => (
+            alloc.concat([
+                alloc.text(format!("{}he value at index ", t)),
+                alloc.text(index.to_string()),
+            ]),
+            alloc.text(" is a:"),
+        ),
+
         Accessor(field) => (
             alloc.concat([
                 alloc.text(format!("{}his ", t)),
@@ -1758,6 +1825,10 @@ fn format_category<'b>(
             alloc.concat([this_is, alloc.text(" an expectation")]),
             alloc.text(" of type:"),
         ),
+        Crash => (
+            alloc.concat([this_is, alloc.text(" a "), alloc.keyword("crash")]),
+            alloc.text(" of type:"),
+        ),
     }
 }
 
@@ -1970,6 +2041,7 @@ fn add_pattern_category<'b>(
     let rest = match category {
         Record => alloc.reflow(" record values of type:"),
         EmptyRecord => alloc.reflow(" an empty record:"),
+        Tuple => alloc.reflow(" tuple values of type:"),
         PatternGuard => alloc.reflow(" a pattern guard of type:"),
         PatternDefault => alloc.reflow(" an optional field of type:"),
         Set => alloc.reflow(" sets of type:"),
@@ -2033,7 +2105,7 @@ pub enum Problem {
     FieldsMissing(Vec<Lowercase>),
     TagTypo(TagName, Vec<TagName>),
     TagsMissing(Vec<TagName>),
-    BadRigidVar(Lowercase, ErrorType, Option<Symbol>),
+    BadRigidVar(Lowercase, ErrorType, Option<AbilitySet>),
     OptionalRequiredMismatch(Lowercase),
     OpaqueComparedToNonOpaque,
 }
@@ -2181,6 +2253,24 @@ pub struct Diff<T> {
     right_able: AbleVariables,
 }
 
+/// The elements of a tuple type in index order, with `None` for the gaps an open tuple can have.
+fn tuple_elems_to_docs<D>(
+    elems: Vec<(usize, ErrorType)>,
+    mut elem_to_doc: impl FnMut(ErrorType) -> D,
+) -> Vec<Option<D>> {
+    let mut docs = Vec::with_capacity(elems.len());
+
+    for (index, elem) in elems {
+        while docs.len() < index {
+            docs.push(None);
+        }
+
+        docs.push(Some(elem_to_doc(elem)));
+    }
+
+    docs
+}
+
 fn ext_to_doc<'b>(alloc: &'b RocDocAllocator<'b>, ext: TypeExt) -> Option<RocDocBuilder<'b>> {
     use TypeExt::*;
 
@@ -2190,7 +2280,7 @@ fn ext_to_doc<'b>(alloc: &'b RocDocAllocator<'b>, ext: TypeExt) -> Option<RocDoc
     }
 }
 
-type AbleVariables = Vec<(Lowercase, Symbol)>;
+type AbleVariables = Vec<(Lowercase, AbilitySet)>;
 
 #[derive(Default)]
 struct Context {
@@ -2230,9 +2320,9 @@ fn to_doc_help<'b>(
         Error => alloc.text("?"),
 
         FlexVar(lowercase) | RigidVar(lowercase) => alloc.type_variable(lowercase),
-        FlexAbleVar(lowercase, ability) | RigidAbleVar(lowercase, ability) => {
+        FlexAbleVar(lowercase, abilities) | RigidAbleVar(lowercase, abilities) => {
             // TODO we should be putting able variables on the toplevel of the type, not here
-            ctx.able_variables.push((lowercase.clone(), ability));
+            ctx.able_variables.push((lowercase.clone(), abilities));
             alloc.type_variable(lowercase)
         }
 
@@ -2317,6 +2407,14 @@ fn to_doc_help<'b>(
             )
         }
 
+        Tuple(elems, ext) => report_text::tuple(
+            alloc,
+            tuple_elems_to_docs(elems, |elem| {
+                to_doc_help(ctx, alloc, Parens::Unnecessary, elem)
+            }),
+            ext_to_doc(alloc, ext),
+        ),
+
         TagUnion(tags_map, ext) => {
             let mut tags = tags_map
                 .into_iter()
@@ -2390,6 +2488,26 @@ fn same<'b>(
     }
 }
 
+/// "Hash ability", or "Hash and Eq abilities" and so on.
+fn ability_list<'b>(alloc: &'b RocDocAllocator<'b>, abilities: &AbilitySet) -> RocDocBuilder<'b> {
+    let count = abilities.len();
+    let mut doc = Vec::with_capacity(2 * count);
+    for (i, ability) in abilities.sorted_iter().enumerate() {
+        if i > 0 {
+            doc.push(alloc.reflow(if count == 2 {
+                " and "
+            } else if i == count - 1 {
+                ", and "
+            } else {
+                ", "
+            }));
+        }
+        doc.push(alloc.symbol_unqualified(*ability));
+    }
+    doc.push(alloc.reflow(if count == 1 { " ability" } else { " abilities" }));
+    alloc.concat(doc)
+}
+
 fn type_with_able_vars<'b>(
     alloc: &'b RocDocAllocator<'b>,
     typ: RocDocBuilder<'b>,
@@ -2400,16 +2518,29 @@ fn type_with_able_vars<'b>(
         return typ;
     }
 
-    let mut doc = Vec::with_capacity(1 + 6 * able.len());
+    // A variable appearing in several places of the type is only printed as bound once.
+    let mut unique_able = Vec::with_capacity(able.len());
+    for var_ability in able {
+        if !unique_able.contains(&var_ability) {
+            unique_able.push(var_ability);
+        }
+    }
+
+    let mut doc = Vec::with_capacity(1 + 6 * unique_able.len());
     doc.push(typ);
 
-    for (i, (var, ability)) in able.into_iter().enumerate() {
+    for (i, (var, abilities)) in unique_able.into_iter().enumerate() {
         doc.push(alloc.string(if i == 0 { " | " } else { ", " }.to_string()));
         doc.push(alloc.type_variable(var));
         doc.push(alloc.space());
         doc.push(alloc.keyword("has"));
-        doc.push(alloc.space());
-        doc.push(alloc.symbol_foreign_qualified(ability));
+        for (j, ability) in abilities.into_sorted_iter().enumerate() {
+            if j > 0 {
+                doc.push(alloc.string(" &".to_string()));
+            }
+            doc.push(alloc.space());
+            doc.push(alloc.symbol_foreign_qualified(ability));
+        }
     }
 
     alloc.concat(doc)
@@ -2480,14 +2611,14 @@ fn to_diff<'b>(
             }
         }
 
-        (RigidAbleVar(x, ab), other) | (other, RigidAbleVar(x, ab)) => {
+        (RigidAbleVar(x, abs), other) | (other, RigidAbleVar(x, abs)) => {
             let (left, left_able) = to_doc(alloc, Parens::InFn, type1);
             let (right, right_able) = to_doc(alloc, Parens::InFn, type2);
 
             Diff {
                 left,
                 right,
-                status: Status::Different(vec![Problem::BadRigidVar(x, other, Some(ab))]),
+                status: Status::Different(vec![Problem::BadRigidVar(x, other, Some(abs))]),
                 left_able,
                 right_able,
             }
@@ -2614,6 +2745,37 @@ fn to_diff<'b>(
             diff_record(alloc, fields1, ext1, fields2, ext2)
         }
 
+        (Tuple(elems1, ext1), Tuple(elems2, ext2))
+            if elems1.iter().map(|(i, _)| i).eq(elems2.iter().map(|(i, _)| i)) =>
+        {
+            let indices: Vec<_> = elems1.iter().map(|(i, _)| *i).collect();
+            let elem_diff = traverse(
+                alloc,
+                Parens::Unnecessary,
+                elems1.into_iter().map(|(_, t)| t).collect::<Vec<_>>(),
+                elems2.into_iter().map(|(_, t)| t).collect::<Vec<_>>(),
+            );
+
+            let to_elems = |docs: Vec<RocDocBuilder<'b>>| {
+                let mut docs = docs.into_iter();
+                tuple_elems_to_docs(indices.iter().map(|i| (*i, Error)).collect(), |_| {
+                    docs.next().unwrap()
+                })
+            };
+
+            let left = report_text::tuple(alloc, to_elems(elem_diff.left), ext_to_doc(alloc, ext1));
+            let right =
+                report_text::tuple(alloc, to_elems(elem_diff.right), ext_to_doc(alloc, ext2));
+
+            Diff {
+                left,
+                right,
+                status: elem_diff.status,
+                left_able: elem_diff.left_able,
+                right_able: elem_diff.right_able,
+            }
+        }
+
         (TagUnion(tags1, ext1), TagUnion(tags2, ext2)) => {
             diff_tag_union(alloc, &tags1, ext1, &tags2, ext2)
         }
@@ -3247,6 +3409,30 @@ mod report_text {
         }
     }
 
+    pub fn tuple<'b>(
+        alloc: &'b RocDocAllocator<'b>,
+        elems: Vec<Option<RocDocBuilder<'b>>>,
+        opt_ext: Option<RocDocBuilder<'b>>,
+    ) -> RocDocBuilder<'b> {
+        let ext_doc = if let Some(t) = opt_ext {
+            t
+        } else {
+            alloc.nil()
+        };
+
+        let starts =
+            std::iter::once(alloc.reflow("( ")).chain(std::iter::repeat(alloc.reflow(", ")));
+
+        let elems_doc = alloc.concat(
+            elems
+                .into_iter()
+                .zip(starts)
+                .map(|(elem, start)| start.append(elem.unwrap_or_else(|| alloc.text("_")))),
+        );
+
+        elems_doc.append(alloc.reflow(" )")).append(ext_doc)
+    }
+
     pub fn to_suggestion_record<'b>(
         alloc: &'b RocDocAllocator<'b>,
         f: (Lowercase, RecordField<ErrorType>),
@@ -3439,7 +3625,9 @@ fn type_problem_to_pretty<'b>(
                     let nearest_str = format!("{}", nearest);
 
                     let found = alloc.text(typo_str).annotate(Annotation::Typo);
-                    let suggestion = alloc.text(nearest_str).annotate(Annotation::TypoSuggestion);
+                    let suggestion = alloc
+                        .suggestion(nearest_str)
+                        .annotate(Annotation::TypoSuggestion);
 
                     let tip1 = alloc
                         .tip()
@@ -3490,7 +3678,9 @@ fn type_problem_to_pretty<'b>(
                     let nearest_str = format!("{}", nearest);
 
                     let found = alloc.text(typo_str).annotate(Annotation::Typo);
-                    let suggestion = alloc.text(nearest_str).annotate(Annotation::TypoSuggestion);
+                    let suggestion = alloc
+                        .suggestion(nearest_str)
+                        .annotate(Annotation::TypoSuggestion);
 
                     let tip1 = alloc
                         .tip()
@@ -3522,15 +3712,14 @@ fn type_problem_to_pretty<'b>(
             alloc.tip().append(line)
         }
 
-        (BadRigidVar(x, tipe, opt_ability), expectation) => {
+        (BadRigidVar(x, tipe, opt_abilities), expectation) => {
             use ErrorType::*;
 
             let bad_rigid_var = |name: Lowercase, a_thing| {
-                let kind_of_value = match opt_ability {
-                    Some(ability) => alloc.concat([
+                let kind_of_value = match &opt_abilities {
+                    Some(abilities) => alloc.concat([
                         alloc.reflow("any value implementing the "),
-                        alloc.symbol_unqualified(ability),
-                        alloc.reflow(" ability"),
+                        ability_list(alloc, abilities),
                     ]),
                     None => alloc.reflow("any type of value"),
                 };
@@ -3580,18 +3769,79 @@ fn type_problem_to_pretty<'b>(
                     .append(alloc.reflow(line))
             };
 
+            // The body needs abilities the annotation didn't bind the variable to.
+            let missing_bound = match (&tipe, &opt_abilities) {
+                (FlexAbleVar(_, needed), Some(annotated)) => {
+                    let missing: AbilitySet = needed
+                        .sorted_iter()
+                        .filter(|ability| !annotated.contains(ability))
+                        .copied()
+                        .collect();
+                    if missing.is_empty() {
+                        None
+                    } else {
+                        Some((annotated, missing))
+                    }
+                }
+                _ => None,
+            };
+
+            if let Some((annotated, missing)) = missing_bound {
+                let all: AbilitySet = annotated
+                    .sorted_iter()
+                    .chain(missing.sorted_iter())
+                    .copied()
+                    .collect();
+                let mut bound = vec![
+                    alloc.type_variable(x.clone()),
+                    alloc.space(),
+                    alloc.keyword("has"),
+                ];
+                for (i, ability) in all.into_sorted_iter().enumerate() {
+                    if i > 0 {
+                        bound.push(alloc.string(" &".to_string()));
+                    }
+                    bound.push(alloc.space());
+                    bound.push(alloc.symbol_foreign_qualified(ability));
+                }
+
+                return alloc
+                    .tip()
+                    .append(alloc.reflow("The type annotation says "))
+                    .append(alloc.type_variable(x))
+                    .append(alloc.reflow(" only has to implement the "))
+                    .append(ability_list(alloc, annotated))
+                    .append(alloc.reflow(", but the body also needs the "))
+                    .append(ability_list(alloc, &missing))
+                    .append(alloc.reflow(if missing.len() == 1 {
+                        ". Maybe add it to the annotation, as in "
+                    } else {
+                        ". Maybe add them to the annotation, as in "
+                    }))
+                    .append(alloc.inline_type_block(alloc.concat(bound)))
+                    .append(alloc.reflow("?"));
+            }
+
             match tipe {
                 Infinite | Error | FlexVar(_) => alloc.nil(),
-                FlexAbleVar(_, ability) => bad_rigid_var(
+                FlexAbleVar(_, abilities) if abilities.len() == 1 => bad_rigid_var(
                     x,
                     alloc.concat([
                         alloc.reflow("an instance of the ability "),
-                        alloc.symbol_unqualified(ability),
+                        alloc.symbol_unqualified(*abilities.sorted_iter().next().unwrap()),
+                    ]),
+                ),
+                FlexAbleVar(_, abilities) => bad_rigid_var(
+                    x,
+                    alloc.concat([
+                        alloc.reflow("an instance of the "),
+                        ability_list(alloc, &abilities),
                     ]),
                 ),
                 RigidVar(y) | RigidAbleVar(y, _) => bad_double_rigid(x, y),
                 Function(_, _, _) => bad_rigid_var(x, alloc.reflow("a function value")),
                 Record(_, _) => bad_rigid_var(x, alloc.reflow("a record value")),
+                Tuple(_, _) => bad_rigid_var(x, alloc.reflow("a tuple value")),
                 TagUnion(_, _) | RecursiveTagUnion(_, _, _) => {
                     bad_rigid_var(x, alloc.reflow("a tag value"))
                 }
@@ -3784,7 +4034,7 @@ fn report_record_field_typo<'b>(
                     f_doc,
                     alloc.reflow(" should be "),
                     alloc
-                        .text(format!("{}{}{}", field_prefix, f.0, field_suffix))
+                        .suggestion(format!("{}{}{}", field_prefix, f.0, field_suffix))
                         .annotate(Annotation::TypoSuggestion),
                     alloc.reflow(" instead?"),
                 ]),
@@ -4028,6 +4278,16 @@ fn pattern_to_doc_help<'b>(
                         .append(alloc.intersperse(arg_docs, alloc.reflow(", ")))
                         .append(" }")
                 }
+                RenderAs::Tuple => {
+                    let arg_docs = args
+                        .into_iter()
+                        .map(|v| pattern_to_doc_help(alloc, v, false));
+
+                    alloc
+                        .text("( ")
+                        .append(alloc.intersperse(arg_docs, alloc.reflow(", ")))
+                        .append(" )")
+                }
                 RenderAs::Tag | RenderAs::Opaque => {
                     let ctor = &union.alternatives[tag_id.0 as usize];
                     match &ctor.name {
@@ -115,6 +115,8 @@ pub enum Severity {
 pub enum RenderTarget {
     ColorTerminal,
     Generic,
+    /// A line of JSON per report, for tools to read. See [`Report::render_json`].
+    Json,
 }
 
 /// A textual report.
@@ -126,9 +128,12 @@ pub struct Report<'b> {
 }
 
 impl<'b> Report<'b> {
+    /// The region is only used by [`RenderTarget::Json`]; the other targets show the code
+    /// the report is about in the report's document.
     pub fn render(
         self,
         target: RenderTarget,
+        region: Option<LineColumnRegion>,
         buf: &'b mut String,
         alloc: &'b RocDocAllocator<'b>,
         palette: &'b Palette,
@@ -136,11 +141,12 @@ impl<'b> Report<'b> {
         match target {
             RenderTarget::Generic => self.render_ci(buf, alloc),
             RenderTarget::ColorTerminal => self.render_color_terminal(buf, alloc, palette),
+            RenderTarget::Json => self.render_json(buf, region),
         }
     }
 
     /// Render to CI console output, where no colors are available.
-    pub fn render_ci(self, buf: &'b mut String, alloc: &'b RocDocAllocator<'b>) {
+    pub fn render_ci(self, buf: &mut String, alloc: &'b RocDocAllocator<'b>) {
         let err_msg = "<buffer is not a utf-8 encoded string>";
 
         self.pretty(alloc)
@@ -164,6 +170,75 @@ impl<'b> Report<'b> {
             .expect(err_msg);
     }
 
+    /// Render as a single line of JSON (without a trailing newline), for editors and CI to read.
+    /// Fields may be added to this schema, but never removed or changed:
+    ///
+    /// ```text
+    /// {
+    ///   "severity": "error" | "warning",
+    ///   "title": string,           e.g. "TYPE MISMATCH"
+    ///   "file": string,            the path of the module the report is about
+    ///   "region": null | {         null if the report is not about a particular piece of code
+    ///     "start": { "line": number, "column": number },
+    ///     "end": { "line": number, "column": number }
+    ///   },
+    ///   "message": string,         the report as plain text, without its header
+    ///   "suggestions": [string]    replacements for the code in the region, e.g. for a typo
+    /// }
+    /// ```
+    ///
+    /// Lines and columns start at 1, columns count bytes, and `end` is just past the last
+    /// character of the region.
+    pub fn render_json(self, buf: &mut String, region: Option<LineColumnRegion>) {
+        let err_msg = "<buffer is not a utf-8 encoded string>";
+
+        let mut message = String::new();
+        let mut write = SuggestionWrite::new(CiWrite::new(&mut message));
+
+        self.doc.1.render_raw(70, &mut write).expect(err_msg);
+
+        let suggestions = write.suggestions;
+        let severity = match self.severity {
+            Severity::RuntimeError => "error",
+            Severity::Warning => "warning",
+        };
+
+        buf.push_str("{\"severity\":");
+        push_json_string(buf, severity);
+        buf.push_str(",\"title\":");
+        push_json_string(buf, &self.title);
+        buf.push_str(",\"file\":");
+        push_json_string(buf, &self.filename.to_string_lossy());
+        buf.push_str(",\"region\":");
+
+        match region {
+            None => buf.push_str("null"),
+            Some(LineColumnRegion { start, end }) => {
+                buf.push_str(&format!(
+                    "{{\"start\":{{\"line\":{},\"column\":{}}},\"end\":{{\"line\":{},\"column\":{}}}}}",
+                    start.line + 1,
+                    start.column + 1,
+                    end.line + 1,
+                    end.column + 1
+                ));
+            }
+        }
+
+        buf.push_str(",\"message\":");
+        push_json_string(buf, message.trim());
+        buf.push_str(",\"suggestions\":[");
+
+        for (index, suggestion) in suggestions.iter().enumerate() {
+            if index > 0 {
+                buf.push(',');
+            }
+
+            push_json_string(buf, suggestion);
+        }
+
+        buf.push_str("]}");
+    }
+
     pub fn pretty(self, alloc: &'b RocDocAllocator<'b>) -> RocDocBuilder<'b> {
         if self.title.is_empty() {
             self.doc
@@ -368,6 +443,11 @@ impl<'a> RocDocAllocator<'a> {
         self.text(string).annotate(Annotation::Keyword)
     }
 
+    /// A replacement for the code a report is about, e.g. a name that a typo probably meant
+    pub fn suggestion(&'a self, string: String) -> DocBuilder<'a, Self, Annotation> {
+        self.string(string).annotate(Annotation::Suggestion)
+    }
+
     pub fn parser_suggestion(&'a self, string: &'a str) -> DocBuilder<'a, Self, Annotation> {
         self.text(string).annotate(Annotation::ParserSuggestion)
     }
@@ -821,6 +901,9 @@ pub enum Annotation {
     Tip,
     Header,
     ParserSuggestion,
+    /// A replacement for the code a report is about. This is not styled, but JSON output lists
+    /// the text of each one.
+    Suggestion,
 }
 
 /// Render with minimal formatting
@@ -859,6 +942,100 @@ impl<'a, W> ColorWrite<'a, W> {
     }
 }
 
+/// Render like the wrapped writer, while collecting the text of every [`Annotation::Suggestion`]
+struct SuggestionWrite<W> {
+    upstream: W,
+    style_stack: Vec<Annotation>,
+    suggestions: Vec<String>,
+}
+
+impl<W> SuggestionWrite<W> {
+    fn new(upstream: W) -> SuggestionWrite<W> {
+        SuggestionWrite {
+            upstream,
+            style_stack: vec![],
+            suggestions: vec![],
+        }
+    }
+
+    fn in_suggestion(&self) -> bool {
+        self.style_stack
+            .iter()
+            .any(|annotation| matches!(annotation, Annotation::Suggestion))
+    }
+}
+
+impl<W> Render for SuggestionWrite<W>
+where
+    W: Render,
+{
+    type Error = W::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<usize, W::Error> {
+        self.write_str_all(s).map(|_| s.len())
+    }
+
+    fn write_str_all(&mut self, s: &str) -> Result<(), W::Error> {
+        if self.in_suggestion() {
+            if let Some(suggestion) = self.suggestions.last_mut() {
+                suggestion.push_str(s);
+            }
+        }
+
+        self.upstream.write_str_all(s)
+    }
+}
+
+impl<W> RenderAnnotated<Annotation> for SuggestionWrite<W>
+where
+    W: RenderAnnotated<Annotation>,
+{
+    fn push_annotation(&mut self, annotation: &Annotation) -> Result<(), Self::Error> {
+        if matches!(annotation, Annotation::Suggestion) && !self.in_suggestion() {
+            self.suggestions.push(String::new());
+        }
+
+        self.style_stack.push(*annotation);
+        self.upstream.push_annotation(annotation)
+    }
+
+    fn pop_annotation(&mut self) -> Result<(), Self::Error> {
+        let popped = self.style_stack.pop();
+
+        if matches!(popped, Some(Annotation::Suggestion)) && !self.in_suggestion() {
+            // Drop a suggestion that turned out to be empty, or that we've seen already.
+            if let Some(suggestion) = self.suggestions.pop() {
+                let suggestion = suggestion.trim().to_string();
+
+                if !suggestion.is_empty() && !self.suggestions.contains(&suggestion) {
+                    self.suggestions.push(suggestion);
+                }
+            }
+        }
+
+        self.upstream.pop_annotation()
+    }
+}
+
+/// Push `string` onto `buf` as a quoted JSON string, escaping it as needed
+pub fn push_json_string(buf: &mut String, string: &str) {
+    buf.push('"');
+
+    for ch in string.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => buf.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => buf.push(ch),
+        }
+    }
+
+    buf.push('"');
+}
+
 impl<W> Render for CiWrite<W>
 where
     W: fmt::Write,
@@ -1025,7 +1202,7 @@ where
             ParserSuggestion => {
                 self.write_str(self.palette.parser_suggestion)?;
             }
-            TypeBlock | InlineTypeBlock | Tag | RecordField => { /* nothing yet */ }
+            TypeBlock | InlineTypeBlock | Tag | RecordField | Suggestion => { /* nothing yet */ }
         }
         self.style_stack.push(*annotation);
         Ok(())
@@ -1043,7 +1220,9 @@ where
                     self.write_str(self.palette.reset)?;
                 }
 
-                TypeBlock | InlineTypeBlock | Tag | Opaque | RecordField => { /* nothing yet */ }
+                TypeBlock | InlineTypeBlock | Tag | Opaque | RecordField | Suggestion => {
+                    /* nothing yet */
+                }
             },
         }
         Ok(())
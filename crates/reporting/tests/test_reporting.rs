@@ -58,6 +58,7 @@ mod test_reporting {
         subdir: &str,
         arena: &'a Bump,
         src: &'a str,
+        render: RenderTarget,
     ) -> (String, Result<LoadedModule, LoadingProblem<'a>>) {
         use std::fs::File;
         use std::io::Write;
@@ -85,9 +86,10 @@ mod test_reporting {
             writeln!(file, "{}", module_src).unwrap();
             let load_config = LoadConfig {
                 target_info: roc_target::TargetInfo::default_x86_64(),
-                render: RenderTarget::Generic,
+                render,
                 threading: Threading::Single,
                 exec_mode: ExecutionMode::Check,
+                cache_dir: None,
             };
             let result =
                 roc_load::load_and_typecheck(arena, full_file_path, exposed_types, load_config);
@@ -114,7 +116,8 @@ mod test_reporting {
         ),
         LoadingProblem<'a>,
     > {
-        let (module_src, result) = run_load_and_infer(subdir, arena, expr_src);
+        let (module_src, result) =
+            run_load_and_infer(subdir, arena, expr_src, RenderTarget::Generic);
         let LoadedModule {
             module_id: home,
             mut can_problems,
@@ -767,6 +770,77 @@ mod test_reporting {
         );
     }
 
+    #[test]
+    fn report_json() {
+        let src: &str = indoc!(
+            r#"
+                isDisabled = \user -> user.isAdmin
+
+                theAdmin
+                    |> isDisabled
+            "#
+        );
+
+        let arena = Bump::new();
+        let (_type_problems, can_problems, home, interns) =
+            infer_expr_help(&arena, src).expect("parse error");
+
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let lines = LineInfo::new(src);
+        let alloc = RocDocAllocator::new(&src_lines, home, &interns);
+
+        let problem = can_problems.into_iter().next().expect("no problems");
+        let region = problem.region().map(|region| lines.convert_region(region));
+        let report = can_problem(
+            &alloc,
+            &lines,
+            filename_from_string(r"/code/proj/Main.roc"),
+            problem,
+        );
+
+        let mut buf = String::new();
+
+        report.render_json(&mut buf, region);
+
+        assert_eq!(
+            buf,
+            r#"{"severity":"error","title":"UNRECOGNIZED NAME","file":"/code/proj/Main.roc","region":{"start":{"line":3,"column":1},"end":{"line":3,"column":9}},"message":"Nothing is named `theAdmin` in this scope.\n\n3│  theAdmin\n    ^^^^^^^^\n\nDid you mean one of these?\n\n    Ok\n    List\n    Err\n    Box","suggestions":["Ok","List","Err","Box"]}"#
+        );
+    }
+
+    #[test]
+    fn report_json_syntax_error() {
+        let src: &str = indoc!(
+            r#"
+                app "test" provides [main] to "./platform"
+
+                main = { x: 0, y: }
+            "#
+        );
+
+        let arena = Bump::new();
+        let (_, result) =
+            run_load_and_infer("report_json_syntax_error", &arena, src, RenderTarget::Json);
+
+        let json = match result {
+            Err(LoadingProblem::FormattedReport(json)) => json,
+            Err(other) => panic!("expected a formatted report, got {:?}", other),
+            Ok(_) => panic!("expected a syntax error"),
+        };
+
+        assert!(
+            json.starts_with(r#"{"severity":"error","title":"#),
+            "{}",
+            json
+        );
+        // The error is in `main`'s record, on the 3rd line of the file.
+        assert!(
+            json.contains(r#","region":{"start":{"line":3,"column":"#),
+            "{}",
+            json
+        );
+    }
+
     test_report!(
         if_condition_not_bool,
         indoc!(
@@ -2098,7 +2172,7 @@ mod test_reporting {
         Ok
         U8
         Box
-        f
+        Eq
     "###
     );
 
@@ -3616,7 +3690,7 @@ mod test_reporting {
         Set
         List
         Dict
-        Result
+        Hash
 
     ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
 
@@ -4390,13 +4464,14 @@ mod test_reporting {
         @r###"
     ── UNFINISHED PARENTHESES ────────────────── tmp/type_in_parens_start/Test.roc ─
 
-    I just started parsing a type in parentheses, but I got stuck here:
+    I am partway through parsing a type in parentheses, but I got stuck
+    here:
 
     4│      f : (
                  ^
 
-    Tag unions look like [Many I64, None], so I was expecting to see a tag
-    name next.
+    I was expecting to see a parenthesis before this, so try adding a )
+    and see if that helps?
 
     Note: I may be confused by indentation
     "###
@@ -4416,12 +4491,12 @@ mod test_reporting {
     here:
 
     4│      f : ( I64
-                     ^
-
-    I was expecting to see a parenthesis before this, so try adding a )
-    and see if that helps?
+    5│
+    6│
+        ^
 
-    Note: I may be confused by indentation
+    I was expecting to see a closing parenthesis before this, so try
+    adding a ) and see if that helps?
     "###
     );
 
@@ -5378,8 +5453,22 @@ All branches in an `if` must have the same type!
 
         Num.sin
         Num.div
-        Num.abs
-        Num.neg
+        Num.e
+        Num.pi
+    "###
+    );
+
+    test_report!(
+        bool_structural_eq_not_exposed,
+        indoc!(
+            r#"
+            Bool.structuralEq 1 1
+            "#
+        ),
+        @r###"
+    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+
+    The Bool module does not expose anything by the name `structuralEq`.
     "###
     );
 
@@ -5450,14 +5539,19 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
 
-    I trying to parse a record field access here:
+    Nothing is named `foo` in this scope.
 
     4│      foo.100
-                ^
+            ^^^^^^^
 
-    So I expect to see a lowercase letter next, like .name or .height.
+    Did you mean one of these?
+
+        Box
+        Bool
+        U8
+        F64
     "###
     );
 
@@ -5912,12 +6006,12 @@ All branches in an `if` must have the same type!
     here:
 
     4│      \( a
-                ^
+    5│
+    6│
+        ^
 
     I was expecting to see a closing parenthesis before this, so try
     adding a ) and see if that helps?
-
-    Note: I may be confused by indentation
     "###
     );
 
@@ -5935,7 +6029,9 @@ All branches in an `if` must have the same type!
     here:
 
     4│      \( a,
-                ^
+    5│
+    6│
+        ^
 
     I was expecting to see a closing parenthesis before this, so try
     adding a ) and see if that helps?
@@ -5956,12 +6052,12 @@ All branches in an `if` must have the same type!
     here:
 
     4│      \( a
-                ^
+    5│
+    6│
+        ^
 
     I was expecting to see a closing parenthesis before this, so try
     adding a ) and see if that helps?
-
-    Note: I may be confused by indentation
     "###
     );
 
@@ -5974,17 +6070,15 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NEED MORE INDENTATION ─────────── tmp/pattern_in_parens_indent_end/Test.roc ─
+    ── UNFINISHED FUNCTION ───────────── tmp/pattern_in_parens_indent_end/Test.roc ─
 
-    I am partway through parsing a pattern in parentheses, but I got stuck
-    here:
+    I was partway through parsing a  function, but I got stuck here:
 
     4│      x = \( a
     5│      )
-            ^
+             ^
 
-    I need this parenthesis to be indented more. Try adding more spaces
-    before it!
+    I just saw a pattern, so I was expecting to see a -> next.
     "###
     );
 
@@ -5998,18 +6092,71 @@ All branches in an `if` must have the same type!
         @r###"
     ── UNFINISHED PARENTHESES ───────── tmp/pattern_in_parens_indent_open/Test.roc ─
 
-    I just started parsing a pattern in parentheses, but I got stuck here:
+    I am partway through parsing a pattern in parentheses, but I got stuck
+    here:
 
     4│      \(
               ^
 
-    Record pattern look like { name, age: currentAge }, so I was expecting
-    to see a field name next.
+    I was expecting to see a closing parenthesis before this, so try
+    adding a ) and see if that helps?
 
     Note: I may be confused by indentation
     "###
     );
 
+    test_report!(
+        empty_parens,
+        indoc!(
+            r#"
+            x = ()
+
+            x
+            "#
+        ),
+        @r###"
+    ── EMPTY PARENTHESES ─────────────────────────────── tmp/empty_parens/Test.roc ─
+
+    I am partway through parsing an expression in parentheses, but I got
+    stuck here:
+
+    4│      x = ()
+                ^
+
+    Empty parentheses are not an expression. A tuple looks like
+    (1, "hello"), and the empty record is written {}.
+    "###
+    );
+
+    test_report!(
+        tuple_elem_mismatch,
+        indoc!(
+            r#"
+            x : (Str, I64)
+            x = (1, "")
+
+            x
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    Something is off with the body of the `x` definition:
+
+    4│      x : (Str, I64)
+    5│      x = (1, "")
+                ^^^^^^^
+
+    The body is a tuple of type:
+
+        ( Num a, Str )
+
+    But the type annotation on `x` says it should be:
+
+        ( Str, I64 )
+    "###
+    );
+
     test_report!(
         outdented_in_parens,
         indoc!(
@@ -6022,18 +6169,15 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NEED MORE INDENTATION ──────────────────── tmp/outdented_in_parens/Test.roc ─
+    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
 
-    I am partway through parsing a type in parentheses, but I got stuck
-    here:
+    This alias has the same name as a builtin:
 
-    4│      Box : (
-    5│          Str
-    6│      )
-            ^
+    4│>      Box : (
+    5│>          Str
 
-    I need this parenthesis to be indented more. Try adding more spaces
-    before it!
+    All builtin aliases are in scope by default, so I need this alias to
+    have a different name!
     "###
     );
 
@@ -6259,7 +6403,7 @@ All branches in an `if` must have the same type!
         inference_var_conflict_in_rigid_links,
         indoc!(
             r#"
-            f : a -> (_ -> b)
+            f : a -> (_ -> b) | a has Eq
             f = \x -> \y -> if x == y then x else y
             f
             "#
@@ -6270,19 +6414,19 @@ All branches in an `if` must have the same type!
 
     Something is off with the body of the `f` definition:
 
-    4│      f : a -> (_ -> b)
+    4│      f : a -> (_ -> b) | a has Eq
     5│      f = \x -> \y -> if x == y then x else y
                       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
 
     The body is an anonymous function of type:
 
-        a -> a
+        a -> a | a has Eq
 
     But the type annotation on `f` says it should be:
 
-        a -> b
+        a -> b | a has Eq
 
-    Tip: Your type annotation uses `a` and `b` as separate type variables.
+    Tip: Your type annotation uses `b` and `a` as separate type variables.
     Your code seems to be saying they are the same though. Maybe they
     should be the same in your type annotation? Maybe your code uses them
     in a weird way?
@@ -7974,8 +8118,8 @@ All branches in an `if` must have the same type!
         ability_first_demand_not_indented_enough,
         indoc!(
             r#"
-            Eq has
-            eq : a, a -> U64 | a has Eq
+            MEq has
+            eq : a, a -> U64 | a has MEq
 
             1
             "#
@@ -7986,8 +8130,8 @@ All branches in an `if` must have the same type!
     I was partway through parsing an ability definition, but I got stuck
     here:
 
-    4│      Eq has
-    5│      eq : a, a -> U64 | a has Eq
+    4│      MEq has
+    5│      eq : a, a -> U64 | a has MEq
             ^
 
     I suspect this line is not indented enough (by 1 spaces)
@@ -7998,9 +8142,9 @@ All branches in an `if` must have the same type!
         ability_demands_not_indented_with_first,
         indoc!(
             r#"
-            Eq has
-                eq : a, a -> U64 | a has Eq
-                    neq : a, a -> U64 | a has Eq
+            MEq has
+                eq : a, a -> U64 | a has MEq
+                    neq : a, a -> U64 | a has MEq
 
             1
             "#
@@ -8011,8 +8155,8 @@ All branches in an `if` must have the same type!
         I was partway through parsing an ability definition, but I got stuck
         here:
 
-        5│          eq : a, a -> U64 | a has Eq
-        6│              neq : a, a -> U64 | a has Eq
+        5│          eq : a, a -> U64 | a has MEq
+        6│              neq : a, a -> U64 | a has MEq
                         ^
 
         I suspect this line is indented too much (by 4 spaces)"#
@@ -8022,8 +8166,8 @@ All branches in an `if` must have the same type!
         ability_demand_value_has_args,
         indoc!(
             r#"
-                Eq has
-                    eq b c : a, a -> U64 | a has Eq
+                MEq has
+                    eq b c : a, a -> U64 | a has MEq
 
                 1
                 "#
@@ -8034,7 +8178,7 @@ All branches in an `if` must have the same type!
         I was partway through parsing an ability definition, but I got stuck
         here:
 
-        5│          eq b c : a, a -> U64 | a has Eq
+        5│          eq b c : a, a -> U64 | a has MEq
                        ^
 
         I was expecting to see a : annotating the signature of this value
@@ -8045,7 +8189,7 @@ All branches in an `if` must have the same type!
         ability_non_signature_expression,
         indoc!(
             r#"
-            Eq has
+            MEq has
                 123
 
             1
@@ -8057,7 +8201,7 @@ All branches in an `if` must have the same type!
     I was partway through parsing an ability definition, but I got stuck
     here:
 
-    4│      Eq has
+    4│      MEq has
     5│          123
                 ^
 
@@ -8183,30 +8327,30 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [] to "./platform"
 
-            Hash a b c has
-              hash : a -> U64 | a has Hash
+            MHash a b c has
+              hash : a -> U64 | a has MHash
             "#
         ),
         @r#"
         ── ABILITY HAS TYPE VARIABLES ──────────────────────────── /code/proj/Main.roc ─
 
-        The definition of the `Hash` ability includes type variables:
+        The definition of the `MHash` ability includes type variables:
 
-        3│  Hash a b c has
-                 ^^^^^
+        3│  MHash a b c has
+                  ^^^^^
 
         Abilities cannot depend on type variables, but their member values
         can!
 
         ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
 
-        `Hash` is not used anywhere in your code.
+        `MHash` is not used anywhere in your code.
 
-        3│  Hash a b c has
-            ^^^^
+        3│  MHash a b c has
+            ^^^^^
 
-        If you didn't intend on using `Hash` then remove it so future readers of
-        your code don't wonder why it is there.
+        If you didn't intend on using `MHash` then remove it so future readers
+        of your code don't wonder why it is there.
         "#
     );
 
@@ -8216,7 +8360,7 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash] to "./platform"
 
-            Hash has hash : a, b -> Num.U64 | a has Hash, b has Bool.Bool
+            MHash has hash : a, b -> Num.U64 | a has MHash, b has Bool.Bool
             "#
         ),
         @r#"
@@ -8224,8 +8368,8 @@ All branches in an `if` must have the same type!
 
         The type referenced in this "has" clause is not an ability:
 
-        3│  Hash has hash : a, b -> Num.U64 | a has Hash, b has Bool.Bool
-                                                                ^^^^^^^^^
+        3│  MHash has hash : a, b -> Num.U64 | a has MHash, b has Bool.Bool
+                                                                  ^^^^^^^^^
         "#
     );
 
@@ -8328,23 +8472,23 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [] to "./platform"
 
-            Eq has eq : a, b -> Bool.Bool | a has Eq, b has Eq
+            MEq has eq : a, b -> Bool.Bool | a has MEq, b has MEq
             "#
         ),
         @r#"
         ── ABILITY MEMBER BINDS MULTIPLE VARIABLES ─────────────── /code/proj/Main.roc ─
 
         The definition of the ability member `eq` includes multiple variables
-        bound to the `Eq`` ability:`
+        bound to the `MEq`` ability:`
 
-        3│  Eq has eq : a, b -> Bool.Bool | a has Eq, b has Eq
-                                            ^^^^^^^^^^^^^^^^^^
+        3│  MEq has eq : a, b -> Bool.Bool | a has MEq, b has MEq
+                                             ^^^^^^^^^^^^^^^^^^^^
 
         Ability members can only bind one type variable to their parent
         ability. Otherwise, I wouldn't know what type implements an ability by
         looking at specializations!
 
-        Hint: Did you mean to only bind `a` to `Eq`?
+        Hint: Did you mean to only bind `a` to `MEq`?
         "#
     );
 
@@ -8354,9 +8498,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [f] to "./platform"
 
-            Hash has hash : (a | a has Hash) -> Num.U64
+            MHash has hash : (a | a has MHash) -> Num.U64
 
-            f : a -> Num.U64 | a has Hash
+            f : a -> Num.U64 | a has MHash
             "#
         ),
         @r#"
@@ -8364,23 +8508,23 @@ All branches in an `if` must have the same type!
 
         A `has` clause is not allowed here:
 
-        3│  Hash has hash : (a | a has Hash) -> Num.U64
-                                 ^^^^^^^^^^
+        3│  MHash has hash : (a | a has MHash) -> Num.U64
+                                  ^^^^^^^^^^^
 
         `has` clauses can only be specified on the top-level type annotations.
 
         ── ABILITY MEMBER MISSING HAS CLAUSE ───────────────────── /code/proj/Main.roc ─
 
         The definition of the ability member `hash` does not include a `has`
-        clause binding a type variable to the ability `Hash`:
+        clause binding a type variable to the ability `MHash`:
 
-        3│  Hash has hash : (a | a has Hash) -> Num.U64
-                     ^^^^
+        3│  MHash has hash : (a | a has MHash) -> Num.U64
+                      ^^^^
 
         Ability members must include a `has` clause binding a type variable to
         an ability, like
 
-            a has Hash
+            a has MHash
 
         Otherwise, the function does not need to be part of the ability!
         "#
@@ -8392,9 +8536,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash] to "./platform"
 
-            Hash has hash : a -> U64 | a has Hash
+            MHash has hash : a -> U64 | a has MHash
 
-            Id := U32 has [Hash {hash}]
+            Id := U32 has [MHash {hash}]
 
             hash = \@Id n -> n
             "#
@@ -8423,11 +8567,11 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [eq, le] to "./platform"
 
-            Eq has
-                eq : a, a -> Bool | a has Eq
-                le : a, a -> Bool | a has Eq
+            MEq has
+                eq : a, a -> Bool | a has MEq
+                le : a, a -> Bool | a has MEq
 
-            Id := U64 has [Eq {eq}]
+            Id := U64 has [MEq {eq}]
 
             eq = \@Id m, @Id n -> m == n
             "#
@@ -8435,10 +8579,10 @@ All branches in an `if` must have the same type!
         @r###"
     ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
 
-    This type does not fully implement the `Eq` ability:
+    This type does not fully implement the `MEq` ability:
 
-    7│  Id := U64 has [Eq {eq}]
-                       ^^^^^^^
+    7│  Id := U64 has [MEq {eq}]
+                       ^^^^^^^^
 
     The following necessary members are missing implementations:
 
@@ -8452,8 +8596,8 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
             hash = \_ -> 0u64
             "#
@@ -8477,11 +8621,11 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash, One, Two] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            One := {} has [Hash {hash}]
-            Two := {} has [Hash {hash}]
+            One := {} has [MHash {hash}]
+            Two := {} has [MHash {hash}]
 
             hash = \_ -> 0u64
             "#
@@ -8493,8 +8637,8 @@ All branches in an `if` must have the same type!
     This ability member specialization is already claimed to specialize
     another opaque type:
 
-    7│  Two := {} has [Hash {hash}]
-                             ^^^^
+    7│  Two := {} has [MHash {hash}]
+                              ^^^^
 
     Previously, we found it to specialize `hash` for `One`.
 
@@ -8514,7 +8658,7 @@ All branches in an `if` must have the same type!
 
     But the type annotation on `hash` says it must match:
 
-        a -> U64 | a has Hash
+        a -> U64 | a has MHash
 
     Note: The specialized type is too general, and does not provide a
     concrete type where a type variable is bound to an ability.
@@ -8531,11 +8675,11 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash, One, Two] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            One := {} has [Hash {hash}]
-            Two := {} has [Hash {hash}]
+            One := {} has [MHash {hash}]
+            Two := {} has [MHash {hash}]
 
             hash = \@One _ -> 0u64
             "#
@@ -8546,8 +8690,8 @@ All branches in an `if` must have the same type!
     This ability member specialization is already claimed to specialize
     another opaque type:
 
-    7│  Two := {} has [Hash {hash}]
-                             ^^^^
+    7│  Two := {} has [MHash {hash}]
+                              ^^^^
 
     Previously, we found it to specialize `hash` for `One`.
 
@@ -8562,10 +8706,10 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [eq] to "./platform"
 
-            Eq has
-                eq : a, a -> Bool | a has Eq
+            MEq has
+                eq : a, a -> Bool | a has MEq
 
-            You := {} has [Eq {eq}]
+            You := {} has [MEq {eq}]
             AndI := {}
 
             eq = \@You {}, @AndI {} -> False
@@ -8600,10 +8744,10 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            Id := U64 has [Hash {hash}]
+            Id := U64 has [MHash {hash}]
 
             hash : Id -> U32
             hash = \@Id n -> n
@@ -8649,10 +8793,10 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [noGoodVeryBadTerrible] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            Id := U64 has [Hash {hash}]
+            Id := U64 has [MHash {hash}]
 
             hash = \@Id n -> n
 
@@ -8673,7 +8817,7 @@ All branches in an `if` must have the same type!
     15│          notYet: hash (A 1),
                                ^^^
 
-    Roc can't generate an implementation of the `#UserApp.Hash` ability for
+    Roc can't generate an implementation of the `#UserApp.MHash` ability for
 
         [A (Num a)]b
 
@@ -8686,7 +8830,7 @@ All branches in an `if` must have the same type!
     14│          nope: hash (@User {}),
                              ^^^^^^^^
 
-    The type `User` does not fully implement the ability `Hash`.
+    The type `User` does not fully implement the ability `MHash`.
     "###
     );
 
@@ -8697,8 +8841,8 @@ All branches in an `if` must have the same type!
             app "test" provides [main] to "./platform"
 
             main =
-                Hash has
-                    hash : a -> U64 | a has Hash
+                MHash has
+                    hash : a -> U64 | a has MHash
 
                 123
             "#
@@ -8708,8 +8852,8 @@ All branches in an `if` must have the same type!
 
         This ability definition is not on the top-level of a module:
 
-        4│>      Hash has
-        5│>          hash : a -> U64 | a has Hash
+        4│>      MHash has
+        5│>          hash : a -> U64 | a has MHash
 
         Abilities can only be defined on the top-level of a Roc module.
         "#
@@ -8721,13 +8865,13 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash, hashable] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            Id := U64 has [Hash {hash}]
+            Id := U64 has [MHash {hash}]
             hash = \@Id n -> n
 
-            hashable : a | a has Hash
+            hashable : a | a has MHash
             hashable = @Id 15
             "#
         ),
@@ -8736,7 +8880,7 @@ All branches in an `if` must have the same type!
 
         Something is off with the body of the `hashable` definition:
 
-         9│  hashable : a | a has Hash
+         9│  hashable : a | a has MHash
         10│  hashable = @Id 15
                         ^^^^^^
 
@@ -8746,32 +8890,115 @@ All branches in an `if` must have the same type!
 
         But the type annotation on `hashable` says it should be:
 
-            a | a has Hash
+            a | a has MHash
 
         Tip: The type annotation uses the type variable `a` to say that this
-        definition can produce any value implementing the `Hash` ability. But in
-        the body I see that it will only produce a `Id` value of a single
+        definition can produce any value implementing the `MHash` ability. But
+        in the body I see that it will only produce a `Id` value of a single
         specific type. Maybe change the type annotation to be more specific?
         Maybe change the code to be more general?
         "#
     );
 
+    test_report!(
+        expression_generalization_to_multiple_abilities_is_an_error,
+        indoc!(
+            r#"
+            app "test" provides [hash, eq, hashable] to "./platform"
+
+            MHash has
+                hash : a -> U64 | a has MHash
+
+            MEq has
+                eq : a, a -> Bool | a has MEq
+
+            Id := U64 has [MHash {hash}, MEq {eq}]
+            hash = \@Id n -> n
+            eq = \@Id m, @Id n -> m == n
+
+            hashable : a | a has MHash & MEq
+            hashable = @Id 15
+            "#
+        ),
+        @r#"
+        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+        Something is off with the body of the `hashable` definition:
+
+        13│  hashable : a | a has MHash & MEq
+        14│  hashable = @Id 15
+                        ^^^^^^
+
+        This Id opaque wrapping has the type:
+
+            Id
+
+        But the type annotation on `hashable` says it should be:
+
+            a | a has MHash & MEq
+
+        Tip: The type annotation uses the type variable `a` to say that this
+        definition can produce any value implementing the `MHash` and `MEq`
+        abilities. But in the body I see that it will only produce a `Id` value
+        of a single specific type. Maybe change the type annotation to be more
+        specific? Maybe change the code to be more general?
+        "#
+    );
+
+    test_report!(
+        ability_bound_missing_from_annotation,
+        indoc!(
+            r#"
+            app "test" provides [hashIfEq] to "./platform"
+
+            MHash has
+                hash : a -> U64 | a has MHash
+
+            MEq has
+                eq : a, a -> Bool | a has MEq
+
+            hashIfEq : a, a -> U64 | a has MHash
+            hashIfEq = \x, y -> if eq x y then hash x else 0
+            "#
+        ),
+        @r#"
+        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+        This 1st argument to `eq` has an unexpected type:
+
+        10│  hashIfEq = \x, y -> if eq x y then hash x else 0
+                                       ^
+
+        This `x` value is a:
+
+            a | a has MHash
+
+        But `eq` needs its 1st argument to be:
+
+            a | a has MEq
+
+        Tip: The type annotation says `a` only has to implement the `MHash`
+        ability, but the body also needs the `MEq` ability. Maybe add it to the
+        annotation, as in `a has MHash & MEq`?
+        "#
+    );
+
     test_report!(
         ability_value_annotations_are_an_error,
         indoc!(
             r#"
             app "test" provides [result] to "./platform"
 
-            Hash has
-                hash : a -> U64 | a has Hash
+            MHash has
+                hash : a -> U64 | a has MHash
 
-            mulHashes : Hash, Hash -> U64
+            mulHashes : MHash, MHash -> U64
             mulHashes = \x, y -> hash x * hash y
 
-            Id := U64 has [Hash {hash: hashId}]
+            Id := U64 has [MHash {hash: hashId}]
             hashId = \@Id n -> n
 
-            Three := {} has [Hash {hash: hashThree}]
+            Three := {} has [MHash {hash: hashThree}]
             hashThree = \@Three _ -> 3
 
             result = mulHashes (@Id 100) (@Three {})
@@ -8780,31 +9007,31 @@ All branches in an `if` must have the same type!
         @r#"
         ── ABILITY USED AS TYPE ────────────────────────────────── /code/proj/Main.roc ─
 
-        You are attempting to use the ability `Hash` as a type directly:
+        You are attempting to use the ability `MHash` as a type directly:
 
-        6│  mulHashes : Hash, Hash -> U64
-                        ^^^^
+        6│  mulHashes : MHash, MHash -> U64
+                        ^^^^^
 
         Abilities can only be used in type annotations to constrain type
         variables.
 
         Hint: Perhaps you meant to include a `has` annotation, like
 
-            a has Hash
+            a has MHash
 
         ── ABILITY USED AS TYPE ────────────────────────────────── /code/proj/Main.roc ─
 
-        You are attempting to use the ability `Hash` as a type directly:
+        You are attempting to use the ability `MHash` as a type directly:
 
-        6│  mulHashes : Hash, Hash -> U64
-                              ^^^^
+        6│  mulHashes : MHash, MHash -> U64
+                               ^^^^^
 
         Abilities can only be used in type annotations to constrain type
         variables.
 
         Hint: Perhaps you meant to include a `has` annotation, like
 
-            b has Hash
+            b has MHash
         "#
     );
 
@@ -9112,6 +9339,87 @@ All branches in an `if` must have the same type!
         "#
     );
 
+    test_report!(
+        function_does_not_implement_eq,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main = (\x -> x) == (\x -> x)
+            "#
+        ),
+        @r#"
+        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+        This expression has a type that does not implement the abilities it's expected to:
+
+        3│  main = (\x -> x) == (\x -> x)
+                    ^^^^^^^
+
+        Roc can't generate an implementation of the `Bool.Eq` ability for
+
+            a -> a
+
+        Note: `Eq` cannot be generated for functions. Functions can never be
+        compared for equality.
+        "#
+    );
+
+    test_report!(
+        opaque_does_not_implement_eq,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            A := U8
+            main = @A 1 == @A 1
+            "#
+        ),
+        @r#"
+        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+        This expression has a type that does not implement the abilities it's expected to:
+
+        4│  main = @A 1 == @A 1
+                   ^^^^
+
+        The type `A` does not fully implement the ability `Eq`.
+        "#
+    );
+
+    test_report!(
+        nested_opaque_does_not_implement_eq,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            A := U8
+            main = [@A 1] == [@A 1]
+            "#
+        ),
+        @r#"
+        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+        This expression has a type that does not implement the abilities it's expected to:
+
+        4│  main = [@A 1] == [@A 1]
+                   ^^^^^^
+
+        Roc can't generate an implementation of the `Bool.Eq` ability for
+
+            List A
+
+        In particular, an implementation for
+
+            A
+
+        cannot be generated.
+
+        Tip: `A` does not implement `Eq`. Consider adding a custom implementation
+        or `has Bool.Eq` to the definition of `A`.
+        "#
+    );
+
     test_report!(
         nested_opaque_does_not_implement_encoding,
         indoc!(
@@ -9187,9 +9495,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [A] to "./platform"
 
-            Eq has eq : a, a -> U64 | a has Eq
+            MEq has eq : a, a -> U64 | a has MEq
 
-            A := U8 has [Eq {eq}]
+            A := U8 has [MEq {eq}]
             "#
         ),
         @r###"
@@ -9197,8 +9505,8 @@ All branches in an `if` must have the same type!
 
     An implementation of `eq` could not be found in this scope:
 
-    5│  A := U8 has [Eq {eq}]
-                         ^^
+    5│  A := U8 has [MEq {eq}]
+                          ^^
 
     Tip: consider adding a value of name `eq` in this scope, or using
     another variable that implements this ability member, like
@@ -9206,10 +9514,10 @@ All branches in an `if` must have the same type!
 
     ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
 
-    This type does not fully implement the `Eq` ability:
+    This type does not fully implement the `MEq` ability:
 
-    5│  A := U8 has [Eq {eq}]
-                     ^^^^^^^
+    5│  A := U8 has [MEq {eq}]
+                     ^^^^^^^^
 
     The following necessary members are missing implementations:
 
@@ -9223,9 +9531,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [A, myEq] to "./platform"
 
-            Eq has eq : a, a -> Bool | a has Eq
+            MEq has eq : a, a -> Bool | a has MEq
 
-            A := U8 has [ Eq {eq: aEq} ]
+            A := U8 has [ MEq {eq: aEq} ]
 
             myEq = \m, n -> m == n
             "#
@@ -9235,22 +9543,22 @@ All branches in an `if` must have the same type!
 
     Nothing is named `aEq` in this scope.
 
-    5│  A := U8 has [ Eq {eq: aEq} ]
-                              ^^^
+    5│  A := U8 has [ MEq {eq: aEq} ]
+                               ^^^
 
     Did you mean one of these?
 
         Eq
-        myEq
+        MEq
         eq
-        U8
+        myEq
 
     ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
 
-    This type does not fully implement the `Eq` ability:
+    This type does not fully implement the `MEq` ability:
 
-    5│  A := U8 has [ Eq {eq: aEq} ]
-                      ^^^^^^^^^^^^
+    5│  A := U8 has [ MEq {eq: aEq} ]
+                      ^^^^^^^^^^^^^
 
     The following necessary members are missing implementations:
 
@@ -9264,9 +9572,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [A, myEq] to "./platform"
 
-            Eq has eq : a, a -> Bool | a has Eq
+            MEq has eq : a, a -> Bool | a has MEq
 
-            A := U8 has [ Eq {eq ? aEq} ]
+            A := U8 has [ MEq {eq ? aEq} ]
 
             myEq = \m, n -> m == n
             "#
@@ -9276,8 +9584,8 @@ All branches in an `if` must have the same type!
 
     Ability implementations cannot be optional:
 
-    5│  A := U8 has [ Eq {eq ? aEq} ]
-                          ^^^^^^^^
+    5│  A := U8 has [ MEq {eq ? aEq} ]
+                           ^^^^^^^^
 
     Custom implementations must be supplied fully.
 
@@ -9285,10 +9593,10 @@ All branches in an `if` must have the same type!
 
     ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
 
-    This type does not fully implement the `Eq` ability:
+    This type does not fully implement the `MEq` ability:
 
-    5│  A := U8 has [ Eq {eq ? aEq} ]
-                      ^^^^^^^^^^^^^
+    5│  A := U8 has [ MEq {eq ? aEq} ]
+                      ^^^^^^^^^^^^^^
 
     The following necessary members are missing implementations:
 
@@ -9342,9 +9650,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [A] to "./platform"
 
-            Eq has eq : a, a -> Bool | a has Eq
+            MEq has eq : a, a -> Bool | a has MEq
 
-            A := U8 has [ Eq {eq : Bool.eq} ]
+            A := U8 has [ MEq {eq : Bool.eq} ]
             "#
         ),
         @r###"
@@ -9352,18 +9660,18 @@ All branches in an `if` must have the same type!
 
     This ability implementation is qualified:
 
-    5│  A := U8 has [ Eq {eq : Bool.eq} ]
-                               ^^^^^^^
+    5│  A := U8 has [ MEq {eq : Bool.eq} ]
+                                ^^^^^^^
 
     Custom implementations must be defined in the local scope, and
     unqualified.
 
     ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
 
-    This type does not fully implement the `Eq` ability:
+    This type does not fully implement the `MEq` ability:
 
-    5│  A := U8 has [ Eq {eq : Bool.eq} ]
-                      ^^^^^^^^^^^^^^^^^
+    5│  A := U8 has [ MEq {eq : Bool.eq} ]
+                      ^^^^^^^^^^^^^^^^^^
 
     The following necessary members are missing implementations:
 
@@ -9377,9 +9685,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [A] to "./platform"
 
-            Eq has eq : a, a -> Bool | a has Eq
+            MEq has eq : a, a -> Bool | a has MEq
 
-            A := U8 has [ Eq {eq : \m, n -> m == n} ]
+            A := U8 has [ MEq {eq : \m, n -> m == n} ]
             "#
         ),
         @r###"
@@ -9387,8 +9695,8 @@ All branches in an `if` must have the same type!
 
     This ability implementation is not an identifier:
 
-    5│  A := U8 has [ Eq {eq : \m, n -> m == n} ]
-                               ^^^^^^^^^^^^^^^
+    5│  A := U8 has [ MEq {eq : \m, n -> m == n} ]
+                                ^^^^^^^^^^^^^^^
 
     Custom ability implementations defined in this position can only be
     unqualified identifiers, not arbitrary expressions.
@@ -9397,10 +9705,10 @@ All branches in an `if` must have the same type!
 
     ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
 
-    This type does not fully implement the `Eq` ability:
+    This type does not fully implement the `MEq` ability:
 
-    5│  A := U8 has [ Eq {eq : \m, n -> m == n} ]
-                      ^^^^^^^^^^^^^^^^^^^^^^^^^
+    5│  A := U8 has [ MEq {eq : \m, n -> m == n} ]
+                      ^^^^^^^^^^^^^^^^^^^^^^^^^^
 
     The following necessary members are missing implementations:
 
@@ -9414,9 +9722,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [A] to "./platform"
 
-            Eq has eq : a, a -> Bool | a has Eq
+            MEq has eq : a, a -> Bool | a has MEq
 
-            A := U8 has [ Eq {eq: eqA, eq: eqA} ]
+            A := U8 has [ MEq {eq: eqA, eq: eqA} ]
 
             eqA = \@A m, @A n -> m == n
             "#
@@ -9426,13 +9734,13 @@ All branches in an `if` must have the same type!
 
     This ability member implementation is duplicate:
 
-    5│  A := U8 has [ Eq {eq: eqA, eq: eqA} ]
-                                   ^^^^^^^
+    5│  A := U8 has [ MEq {eq: eqA, eq: eqA} ]
+                                    ^^^^^^^
 
     The first implementation was defined here:
 
-    5│  A := U8 has [ Eq {eq: eqA, eq: eqA} ]
-                          ^^^^^^^
+    5│  A := U8 has [ MEq {eq: eqA, eq: eqA} ]
+                           ^^^^^^^
 
     Only one custom implementation can be defined for an ability member.
     "###
@@ -10090,7 +10398,7 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash, Id] to "./platform"
 
-            Hash has hash : a -> U64 | a has Hash
+            MHash has hash : a -> U64 | a has MHash
 
             Id := {}
 
@@ -10116,9 +10424,9 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [hash, Id, Id2] to "./platform"
 
-            Hash has hash : a -> U64 | a has Hash
+            MHash has hash : a -> U64 | a has MHash
 
-            Id := {} has [Hash {hash}]
+            Id := {} has [MHash {hash}]
             Id2 := {}
 
             hash = \@Id2 _ -> 0
@@ -10739,6 +11047,188 @@ All branches in an `if` must have the same type!
     "###
     );
 
+    test_report!(
+        crash_not_applied,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main : Str -> Str
+            main = \_ -> crash
+            "#
+        ),
+    @r###"
+    ── UNAPPLIED CRASH ─────────────────────────────────────── /code/proj/Main.roc ─
+
+    This `crash` doesn't have a message given to it:
+
+    4│  main = \_ -> crash
+                     ^^^^^
+
+    `crash` must be passed a message to crash with at the exact place it's
+    used. `crash` can't be used as a value that's passed around, like
+    functions can be - it must be applied immediately!
+    "###
+    );
+
+    test_report!(
+        crash_overapplied,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main : Str -> Str
+            main = \_ -> crash "oops" "more"
+            "#
+        ),
+    @r###"
+    ── OVERAPPLIED CRASH ───────────────────────────────────── /code/proj/Main.roc ─
+
+    This `crash` has too many values given to it:
+
+    4│  main = \_ -> crash "oops" "more"
+                     ^^^^^^^^^^^^^^^^^^^
+
+    `crash` must be given exactly one message to crash with.
+    "###
+    );
+
+    test_report!(
+        crash_given_non_str,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main : Str -> Str
+            main = \_ -> crash 42
+            "#
+        ),
+    @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This value passed to `crash` is not a string:
+
+    4│  main = \_ -> crash 42
+                           ^^
+
+    The value is a number of type:
+
+        Num a
+
+    But I can only `crash` with messages of type Str.
+    "###
+    );
+
+    test_report!(
+        unapplied_record_builder,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                { a: <- apply "a" }
+            "#
+        ),
+    @r###"
+    ── UNAPPLIED RECORD BUILDER ────────────────────────────── /code/proj/Main.roc ─
+
+    This record builder was not applied to a function:
+
+    4│      { a: <- apply "a" }
+            ^^^^^^^^^^^^^^^^^^^
+
+    However, we need a function to construct the record.
+
+    Note: Functions must be applied directly. The pipe operator (|>) cannot be used.
+    "###
+    );
+
+    test_report!(
+        multiple_record_builders,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                succeed { a: <- apply "a" } { b: <- apply "b" }
+            "#
+        ),
+    @r###"
+    ── MULTIPLE RECORD BUILDERS ────────────────────────────── /code/proj/Main.roc ─
+
+    This function is applied to multiple record builders:
+
+    4│      succeed { a: <- apply "a" } { b: <- apply "b" }
+            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    Note: Functions can only take at most one record builder!
+
+    Tip: You can combine them or apply them separately.
+    "###
+    );
+
+    test_report!(
+        record_builder_field_wrong_type,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            succeed : a -> List a
+            succeed = \x -> [x]
+
+            apply : List (a -> b), List a -> List b
+            apply = \fns, vals ->
+                List.map2 fns vals \fn, val -> fn val
+
+            main =
+                succeed {
+                    name: <- ["Alice", "Bob"],
+                    age: <- 42,
+                }
+            "#
+        ),
+    @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This `<-` field of a record builder has an unexpected type:
+
+    13│          age: <- 42,
+                         ^^
+
+    The field's builder is a number of type:
+
+        Num a
+
+    But `apply` needs it to be:
+
+        List a
+    "###
+    );
+
+    test_report!(
+        record_update_builder,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                { rec & a: <- apply "a" }
+            "#
+        ),
+    @r###"
+    ── RECORD UPDATE BUILDER ────────────────── tmp/record_update_builder/Test.roc ─
+
+    I am partway through parsing a record update, and I found a record
+    builder field:
+
+    4│      { rec & a: <- apply "a" }
+                    ^^^^^^^^^^^^^^^
+
+    Record builders cannot be used in record updates. Did you mean to use
+    a `:` instead of a `<-`?
+    "###
+    );
+
     test_report!(
         #[ignore = "https://github.com/roc-lang/roc/issues/4096"]
         unnecessary_builtin_module_import,
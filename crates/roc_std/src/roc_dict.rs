@@ -5,8 +5,11 @@ use core::{
     mem::{align_of, ManuallyDrop},
 };
 
-/// At the moment, Roc's Dict is just an association list. Its lookups are O(n) but
-/// we haven't grown such big programs that it's a problem yet!
+/// Roc's Dict is a hash table. Its entries are stored contiguously in insertion
+/// order, and a separate list of buckets maps hashes to indices into those entries.
+///
+/// Hashing happens in Roc, not in Rust. A dictionary built by the host only has its
+/// entries filled in, and Roc builds the buckets the first time it needs them.
 ///
 /// We do some things in this data structure that only make sense because the
 /// memory is managed in Roc:
@@ -15,51 +18,69 @@ use core::{
 ///    since Roc owns the memory, not rust.
 /// 2. We use a union for [`RocDictItem`] instead of just a struct. See the
 ///    comment on that data structure for why.
-#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(transparent)]
-pub struct RocDict<K, V>(RocList<RocDictItem<K, V>>);
+#[derive(Clone)]
+#[repr(C)]
+pub struct RocDict<K, V> {
+    buckets: RocList<Bucket>,
+    data: RocList<RocDictItem<K, V>>,
+    max_bucket_capacity: usize,
+    shifts: u8,
+}
+
+/// A bucket of the hash table. Roc sorts record fields alphabetically
+/// when they have the same alignment.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+struct Bucket {
+    data_index: u32,
+    dist_and_fingerprint: u32,
+}
+
+impl<K, V> Default for RocDict<K, V> {
+    fn default() -> Self {
+        Self::from_data(RocList::empty())
+    }
+}
 
 impl<K, V> RocDict<K, V> {
+    fn from_data(data: RocList<RocDictItem<K, V>>) -> Self {
+        Self {
+            buckets: RocList::empty(),
+            data,
+            max_bucket_capacity: 0,
+            shifts: 0,
+        }
+    }
+
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.data.len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.data.is_empty()
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(RocList::with_capacity(capacity))
+        Self::from_data(RocList::with_capacity(capacity))
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
-        self.0.iter().map(|item| (item.key(), item.value()))
+        self.data.iter().map(|item| (item.key(), item.value()))
     }
 
     pub fn iter_keys(&self) -> impl Iterator<Item = &K> {
-        self.0.iter().map(|item| item.key())
+        self.data.iter().map(|item| item.key())
     }
 
     pub fn iter_values(&self) -> impl Iterator<Item = &V> {
-        self.0.iter().map(|item| item.value())
+        self.data.iter().map(|item| item.value())
     }
 }
 
 impl<K: Hash, V> RocDict<K, V> {
+    /// The keys must be unique; this is not checked.
     pub fn from_iter<I: Iterator<Item = (K, V)>>(src: I) -> Self {
-        let mut ret = Self::with_capacity(src.size_hint().0);
-
-        for (key, val) in src {
-            unsafe {
-                ret.insert_unchecked(key, val);
-            }
-        }
-
-        ret
-    }
-
-    unsafe fn insert_unchecked(&mut self, _key: K, _val: V) {
-        todo!();
+        Self::from_data(src.map(|(key, val)| RocDictItem::new(key, val)).collect())
     }
 }
 
@@ -76,7 +97,7 @@ impl<'a, K, V> IntoIterator for &'a RocDict<K, V> {
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
             index: 0,
-            items: self.0.as_slice(),
+            items: self.data.as_slice(),
         }
     }
 }
@@ -107,6 +128,34 @@ impl<'a, K, V> Iterator for IntoIter<'a, K, V> {
     }
 }
 
+// Two dictionaries with the same entries can have different buckets (e.g. one
+// built by the host and one built by Roc), so only the entries are compared.
+impl<K: PartialEq, V: PartialEq> PartialEq for RocDict<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for RocDict<K, V> {}
+
+impl<K: PartialOrd, V: PartialOrd> PartialOrd for RocDict<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+impl<K: Ord, V: Ord> Ord for RocDict<K, V> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+
+impl<K: Hash, V: Hash> Hash for RocDict<K, V> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
 impl<K: Debug, V: Debug> Debug for RocDict<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("RocDict ")?;
@@ -152,6 +201,18 @@ struct ValueFirst<K, V> {
 }
 
 impl<K, V> RocDictItem<K, V> {
+    fn new(key: K, value: V) -> Self {
+        if align_of::<K>() >= align_of::<V>() {
+            Self {
+                key_first: ManuallyDrop::new(KeyFirst { key, value }),
+            }
+        } else {
+            Self {
+                value_first: ManuallyDrop::new(ValueFirst { value, key }),
+            }
+        }
+    }
+
     fn key(&self) -> &K {
         if align_of::<K>() >= align_of::<V>() {
             unsafe { &self.key_first.key }
@@ -9,7 +9,7 @@ use core::{
     intrinsics::copy_nonoverlapping,
     iter::FromIterator,
     mem::{self, ManuallyDrop},
-    ops::Deref,
+    ops::{Deref, Range},
     ptr::{self, NonNull},
 };
 
@@ -24,6 +24,9 @@ use serde::{
     Deserialize, Serialize,
 };
 
+/// A list is a seamless slice when the top bit of its capacity is set. A seamless slice
+/// points into the allocation of another list and shares its reference count; the rest
+/// of its capacity is the elements pointer of that allocation, shifted right by one.
 #[repr(C)]
 pub struct RocList<T> {
     elements: Option<NonNull<ManuallyDrop<T>>>,
@@ -32,6 +35,8 @@ pub struct RocList<T> {
 }
 
 impl<T> RocList<T> {
+    const SEAMLESS_SLICE_BIT: usize = isize::MIN as usize;
+
     #[inline(always)]
     fn alloc_alignment() -> u32 {
         mem::align_of::<T>().max(mem::align_of::<Storage>()) as u32
@@ -96,7 +101,15 @@ impl<T> RocList<T> {
     }
 
     pub fn capacity(&self) -> usize {
-        self.capacity
+        if self.is_seamless_slice() {
+            self.length
+        } else {
+            self.capacity
+        }
+    }
+
+    pub fn is_seamless_slice(&self) -> bool {
+        self.capacity & Self::SEAMLESS_SLICE_BIT != 0
     }
 
     pub fn is_empty(&self) -> bool {
@@ -128,6 +141,24 @@ impl<T> RocList<T> {
             .map(|(_, storage)| storage.get())
     }
 
+    /// Wrap an existing allocation without touching its reference count.
+    ///
+    /// # Safety
+    ///
+    /// `elements` must point to the first element of a live RocList allocation,
+    /// which holds at least `length` initialized elements.
+    pub(crate) unsafe fn from_raw_parts(
+        elements: NonNull<T>,
+        length: usize,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            elements: Some(elements.cast()),
+            length,
+            capacity,
+        }
+    }
+
     /// Useful for doing memcpy on the elements. Returns NULL if list is empty.
     pub(crate) unsafe fn ptr_to_first_elem(&self) -> *const T {
         unsafe { core::mem::transmute(self.elements) }
@@ -135,11 +166,13 @@ impl<T> RocList<T> {
 
     /// Useful for doing memcpy on the underlying allocation. Returns NULL if list is empty.
     pub(crate) unsafe fn ptr_to_allocation(&self) -> *mut c_void {
-        unsafe {
-            self.ptr_to_first_elem()
-                .cast::<u8>()
-                .sub(Self::alloc_alignment() as usize) as *mut _
-        }
+        let first_elem_of_allocation = if self.is_seamless_slice() {
+            (self.capacity << 1) as *const u8
+        } else {
+            unsafe { self.ptr_to_first_elem().cast::<u8>() }
+        };
+
+        unsafe { first_elem_of_allocation.sub(Self::alloc_alignment() as usize) as *mut _ }
     }
 
     unsafe fn elem_ptr_from_alloc_ptr(alloc_ptr: *mut c_void) -> *mut c_void {
@@ -168,55 +201,7 @@ where
             return;
         }
 
-        let new_len = self.len() + slice.len();
-        let non_null_elements = if let Some((elements, storage)) = self.elements_and_storage() {
-            // Decrement the list's refence count.
-            let mut copy = storage.get();
-            let is_unique = copy.decrease();
-
-            if is_unique {
-                // If we have enough capacity, we can add to the existing elements in-place.
-                if self.capacity() >= slice.len() {
-                    elements
-                } else {
-                    // There wasn't enough capacity, so we need a new allocation.
-                    // Since this is a unique RocList, we can use realloc here.
-                    let new_ptr = unsafe {
-                        roc_realloc(
-                            storage.as_ptr().cast(),
-                            Self::alloc_bytes(new_len),
-                            Self::alloc_bytes(self.capacity),
-                            Self::alloc_alignment(),
-                        )
-                    };
-
-                    self.capacity = new_len;
-
-                    Self::elems_from_allocation(NonNull::new(new_ptr).unwrap_or_else(|| {
-                        todo!("Reallocation failed");
-                    }))
-                }
-            } else {
-                if !copy.is_readonly() {
-                    // Write the decremented reference count back.
-                    storage.set(copy);
-                }
-
-                // Allocate new memory.
-                let new_elements = Self::elems_with_capacity(slice.len());
-
-                // Copy the old elements to the new allocation.
-                unsafe {
-                    copy_nonoverlapping(elements.as_ptr(), new_elements.as_ptr(), self.length);
-                }
-
-                new_elements
-            }
-        } else {
-            Self::elems_with_capacity(slice.len())
-        };
-
-        self.elements = Some(non_null_elements);
+        self.reserve(slice.len());
 
         let elements = self.elements.unwrap().as_ptr();
 
@@ -236,8 +221,39 @@ where
             // a incrementing the reference count panics.
             self.length += 1;
         }
+    }
+}
 
-        self.capacity = self.length
+impl<T> RocList<T>
+where
+    T: Copy,
+{
+    /// Returns the given range of this list as a seamless slice, which shares the
+    /// allocation (and reference count) of this list instead of copying its elements.
+    ///
+    /// This is limited to `Copy` elements, because when the last list sharing an
+    /// allocation is dropped, only the elements in that list's own range are dropped.
+    pub fn slice_range(&self, range: Range<usize>) -> Self {
+        let slice = &self.as_slice()[range];
+
+        if slice.is_empty() {
+            return Self::empty();
+        }
+
+        let capacity = if self.is_seamless_slice() {
+            self.capacity
+        } else {
+            ((self.elements.unwrap().as_ptr() as usize) >> 1) | Self::SEAMLESS_SLICE_BIT
+        };
+
+        // The slice holds on to the allocation in place of this clone.
+        mem::forget(self.clone());
+
+        Self {
+            elements: Some(NonNull::from(&slice[0]).cast()),
+            length: slice.len(),
+            capacity,
+        }
     }
 }
 
@@ -252,7 +268,10 @@ impl<T> RocList<T> {
 
         match self.elements_and_storage() {
             Some((elements, storage)) => {
-                if storage.get().is_unique() {
+                if self.capacity() >= new_len && storage.get().is_unique() {
+                    // There's already enough room.
+                    return;
+                } else if storage.get().is_unique() && !self.is_seamless_slice() {
                     unsafe {
                         let old_alloc = self.ptr_to_allocation();
 
@@ -266,6 +285,8 @@ impl<T> RocList<T> {
 
                         if new_alloc == old_alloc {
                             // We successfully reallocated in-place; we're done!
+                            self.capacity = new_len;
+
                             return;
                         } else {
                             // We got back a different allocation; copy the existing elements
@@ -283,7 +304,8 @@ impl<T> RocList<T> {
                         // so we don't need to call roc_dealloc here.
                     }
                 } else {
-                    // Make a new allocation
+                    // Make a new allocation. This is also how a unique seamless slice grows,
+                    // since its elements don't start at the beginning of the allocation.
                     new_elems = Self::elems_with_capacity(new_len);
                     old_elements_ptr = elements.as_ptr();
 
@@ -13,8 +13,8 @@ use core::{
     fmt,
     hash::{self, Hash},
     mem::{self, size_of, ManuallyDrop},
-    ops::{Deref, DerefMut},
-    ptr,
+    ops::{Deref, DerefMut, Range},
+    ptr::{self, NonNull},
 };
 
 #[cfg(feature = "std")]
@@ -83,9 +83,15 @@ impl RocStr {
         unsafe { self.0.small_string.is_small_str() }
     }
 
+    fn is_seamless_slice(&self) -> bool {
+        !self.is_small_str() && unsafe { self.0.seamless_slice.is_seamless_slice() }
+    }
+
     fn as_enum_ref(&self) -> RocStrInnerRef {
         if self.is_small_str() {
             unsafe { RocStrInnerRef::SmallString(&self.0.small_string) }
+        } else if self.is_seamless_slice() {
+            unsafe { RocStrInnerRef::SeamlessSlice(&self.0.seamless_slice) }
         } else {
             unsafe { RocStrInnerRef::HeapAllocated(&self.0.heap_allocated) }
         }
@@ -94,6 +100,7 @@ impl RocStr {
     pub fn capacity(&self) -> usize {
         match self.as_enum_ref() {
             RocStrInnerRef::HeapAllocated(roc_list) => roc_list.capacity(),
+            RocStrInnerRef::SeamlessSlice(slice) => slice.len(),
             RocStrInnerRef::SmallString(_) => SmallString::CAPACITY,
         }
     }
@@ -101,6 +108,7 @@ impl RocStr {
     pub fn len(&self) -> usize {
         match self.as_enum_ref() {
             RocStrInnerRef::HeapAllocated(h) => h.len(),
+            RocStrInnerRef::SeamlessSlice(slice) => slice.len(),
             RocStrInnerRef::SmallString(s) => s.len(),
         }
     }
@@ -122,6 +130,39 @@ impl RocStr {
         &*self
     }
 
+    /// Returns the part of this string in the given byte range. A heap-allocated result
+    /// shares this string's allocation instead of copying its bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or does not start and end on `char` boundaries.
+    pub fn slice_range(&self, range: Range<usize>) -> Self {
+        let slice = &self.as_str()[range.clone()];
+
+        if self.is_small_str() || slice.len() <= SmallString::CAPACITY {
+            return RocStr::from(slice);
+        }
+
+        let allocation = match self.as_enum_ref() {
+            RocStrInnerRef::HeapAllocated(roc_list) => unsafe {
+                roc_list.ptr_to_first_elem() as usize
+            },
+            RocStrInnerRef::SeamlessSlice(seamless_slice) => seamless_slice.allocation_ptr(),
+            RocStrInnerRef::SmallString(_) => unreachable!(),
+        };
+
+        // The slice holds a reference to the allocation of its own.
+        mem::forget(self.clone());
+
+        RocStr(RocStrInner {
+            seamless_slice: SeamlessSlice {
+                elements: NonNull::from(slice.as_bytes()).cast(),
+                length: slice.len() | SeamlessSlice::BIT,
+                allocation: allocation >> 1,
+            },
+        })
+    }
+
     /// Create an empty RocStr with enough space preallocated to store
     /// the requested number of bytes.
     pub fn with_capacity(bytes: usize) -> Self {
@@ -155,6 +196,15 @@ impl RocStr {
                     heap_allocated: ManuallyDrop::new(roc_list),
                 });
             }
+        } else if self.is_seamless_slice() {
+            // The bytes after the end of a slice belong to someone else, so copy it.
+            let mut roc_list = RocList::from_slice(self.as_bytes());
+
+            roc_list.reserve(bytes);
+
+            *self = RocStr(RocStrInner {
+                heap_allocated: ManuallyDrop::new(roc_list),
+            });
         } else {
             let mut roc_list = unsafe { ManuallyDrop::take(&mut self.0.heap_allocated) };
 
@@ -173,6 +223,7 @@ impl RocStr {
     fn first_nul_byte(&self) -> Option<usize> {
         match self.as_enum_ref() {
             RocStrInnerRef::HeapAllocated(roc_list) => roc_list.iter().position(|byte| *byte == 0),
+            RocStrInnerRef::SeamlessSlice(slice) => slice.iter().position(|byte| *byte == 0),
             RocStrInnerRef::SmallString(small_string) => small_string.first_nul_byte(),
         }
     }
@@ -289,6 +340,17 @@ impl RocStr {
                     }
                 }
             }
+            RocStrInnerRef::SeamlessSlice(slice) => {
+                let len = slice.len();
+
+                // The bytes after the end of a slice belong to someone else, so we
+                // can't write the terminator in-place.
+                with_stack_bytes(len + 1, |alloc_ptr: *mut u8| unsafe {
+                    ptr::copy_nonoverlapping(slice.as_ptr(), alloc_ptr, len);
+
+                    terminate(alloc_ptr, len)
+                })
+            }
             RocStrInnerRef::SmallString(small_str) => {
                 let mut bytes = small_str.bytes;
 
@@ -504,6 +566,10 @@ impl RocStr {
                     }
                 }
             }
+            RocStrInnerRef::SeamlessSlice(_) => {
+                // The bytes after the end of a slice belong to someone else.
+                fallback(self.as_str())
+            }
             RocStrInnerRef::SmallString(small_str) => {
                 let len = small_str.len();
 
@@ -528,6 +594,7 @@ impl Deref for RocStr {
     fn deref(&self) -> &Self::Target {
         match self.as_enum_ref() {
             RocStrInnerRef::HeapAllocated(h) => unsafe { core::str::from_utf8_unchecked(&*h) },
+            RocStrInnerRef::SeamlessSlice(s) => unsafe { core::str::from_utf8_unchecked(&*s) },
             RocStrInnerRef::SmallString(s) => &*s,
         }
     }
@@ -612,6 +679,12 @@ impl Clone for RocStr {
             RocStrInnerRef::HeapAllocated(h) => Self(RocStrInner {
                 heap_allocated: ManuallyDrop::new(h.clone()),
             }),
+            RocStrInnerRef::SeamlessSlice(s) => {
+                // Increment the reference count of the allocation
+                mem::forget(RocList::clone(&s.allocation()));
+
+                Self(RocStrInner { seamless_slice: *s })
+            }
             RocStrInnerRef::SmallString(s) => Self(RocStrInner { small_string: *s }),
         }
     }
@@ -619,7 +692,11 @@ impl Clone for RocStr {
 
 impl Drop for RocStr {
     fn drop(&mut self) {
-        if !self.is_small_str() {
+        if self.is_seamless_slice() {
+            unsafe {
+                ManuallyDrop::drop(&mut self.0.seamless_slice.allocation());
+            }
+        } else if !self.is_small_str() {
             unsafe {
                 ManuallyDrop::drop(&mut self.0.heap_allocated);
             }
@@ -630,14 +707,61 @@ impl Drop for RocStr {
 #[repr(C)]
 union RocStrInner {
     heap_allocated: ManuallyDrop<RocList<u8>>,
+    seamless_slice: SeamlessSlice,
     small_string: SmallString,
 }
 
 enum RocStrInnerRef<'a> {
     HeapAllocated(&'a RocList<u8>),
+    SeamlessSlice(&'a SeamlessSlice),
     SmallString(&'a SmallString),
 }
 
+/// A heap-allocated string that points into the allocation of another heap-allocated
+/// string, and shares its reference count. The top bit of the length marks a slice;
+/// the capacity is replaced by the elements pointer of the allocation, shifted right
+/// by one so that it can't be mistaken for a small string.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+struct SeamlessSlice {
+    elements: NonNull<u8>,
+    length: usize,
+    allocation: usize,
+}
+
+impl SeamlessSlice {
+    const BIT: usize = isize::MIN as usize;
+
+    fn is_seamless_slice(&self) -> bool {
+        self.length & Self::BIT != 0
+    }
+
+    fn len(&self) -> usize {
+        self.length & !Self::BIT
+    }
+
+    fn allocation_ptr(&self) -> usize {
+        self.allocation << 1
+    }
+
+    /// The allocation this slice points into, as an empty list.
+    fn allocation(&self) -> ManuallyDrop<RocList<u8>> {
+        unsafe {
+            let elements = NonNull::new_unchecked(self.allocation_ptr() as *mut u8);
+
+            ManuallyDrop::new(RocList::from_raw_parts(elements, 0, 0))
+        }
+    }
+}
+
+impl Deref for SeamlessSlice {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::slice::from_raw_parts(self.elements.as_ptr(), self.len()) }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 #[repr(C)]
 struct SmallString {
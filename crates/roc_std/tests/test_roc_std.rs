@@ -138,6 +138,40 @@ mod test_roc_std {
         assert_eq!(roc_str.capacity(), 5000);
     }
 
+    #[test]
+    fn slice_range_small_str() {
+        let roc_str = RocStr::from("hello");
+
+        assert_eq!(roc_str.slice_range(1..4), RocStr::from("ell"));
+    }
+
+    #[test]
+    fn slice_range_big_str() {
+        let original = "a string so long that it is heap-allocated, and so is this slice";
+        let roc_str = RocStr::from(original);
+
+        let slice = roc_str.slice_range(17..58);
+        let slice_of_slice = slice.slice_range(3..34);
+        drop(roc_str);
+
+        assert_eq!(slice.as_str(), &original[17..58]);
+        assert_eq!(slice.capacity(), slice.len());
+        assert_eq!(slice_of_slice.as_str(), &original[20..51]);
+        assert_eq!(slice_of_slice.clone(), slice_of_slice);
+    }
+
+    #[test]
+    fn reserve_big_str_slice() {
+        let roc_str = RocStr::from("a string so long that it is heap-allocated");
+        let mut slice = roc_str.slice_range(2..40);
+
+        slice.reserve(10);
+
+        assert_eq!(slice.as_str(), "string so long that it is heap-allocat");
+        assert_eq!(slice.capacity(), 48);
+        assert_eq!(roc_str.len(), 42);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn str_short_serde_roundtrip() {
@@ -179,6 +213,33 @@ mod test_roc_std {
         assert_eq!(roc_list.capacity(), 5000);
     }
 
+    #[test]
+    fn slice_range_list() {
+        let roc_list = RocList::from_iter(0..100_u64);
+
+        let slice = roc_list.slice_range(10..90);
+        let slice_of_slice = slice.slice_range(5..15);
+        drop(roc_list);
+
+        assert!(slice.is_seamless_slice());
+        assert_eq!(slice.as_slice(), (10..90).collect::<Vec<u64>>());
+        assert_eq!(slice.capacity(), 80);
+        assert_eq!(slice_of_slice.as_slice(), (15..25).collect::<Vec<u64>>());
+        assert_eq!(slice_of_slice.clone(), slice_of_slice);
+    }
+
+    #[test]
+    fn reserve_list_slice() {
+        let roc_list = RocList::from_iter(0..100_u64);
+        let mut slice = roc_list.slice_range(50..100);
+
+        slice.extend_from_slice(&[100, 101]);
+
+        assert!(!slice.is_seamless_slice());
+        assert_eq!(slice.as_slice(), (50..102).collect::<Vec<u64>>());
+        assert_eq!(roc_list.len(), 100);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn short_list_roundtrip() {
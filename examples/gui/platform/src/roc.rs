@@ -34,6 +34,12 @@ pub unsafe extern "C" fn roc_panic(c_ptr: *mut c_void, tag_id: u32) {
             eprintln!("Roc hit a panic: {}", string);
             std::process::exit(1);
         }
+        1 => {
+            let slice = CStr::from_ptr(c_ptr as *const c_char);
+            let string = slice.to_str().unwrap();
+            eprintln!("Roc crashed with: {}", string);
+            std::process::exit(1);
+        }
         _ => todo!(),
     }
 }